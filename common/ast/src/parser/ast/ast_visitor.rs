@@ -16,6 +16,7 @@ use common_exception::Result;
 
 use crate::parser::ast::BinaryOperator;
 use crate::parser::ast::ColumnDefinition;
+use crate::parser::ast::DateTimeField;
 use crate::parser::ast::Expr;
 use crate::parser::ast::Identifier;
 use crate::parser::ast::Indirection;
@@ -71,6 +72,9 @@ pub trait AstVisitor {
             } => self.visit_case(operand, conditions, results, else_result),
             Expr::Exists(query) => self.visit_exists(query),
             Expr::Subquery(query) => self.visit_query(query),
+            Expr::Array { exprs } => self.visit_array(exprs),
+            Expr::Subscript { expr, index } => self.visit_subscript(expr, index),
+            Expr::Extract { field, expr } => self.visit_extract(field, expr),
         }
     }
 
@@ -142,6 +146,19 @@ pub trait AstVisitor {
         self.visit_expr(else_result.as_ref().unwrap())
     }
 
+    fn visit_array(&mut self, exprs: &[Expr]) -> Result<()> {
+        self.visit_exprs(exprs)
+    }
+
+    fn visit_subscript(&mut self, expr: &Expr, index: &Expr) -> Result<()> {
+        self.visit_expr(expr)?;
+        self.visit_expr(index)
+    }
+
+    fn visit_extract(&mut self, _field: &DateTimeField, expr: &Expr) -> Result<()> {
+        self.visit_expr(expr)
+    }
+
     fn visit_exists(&mut self, query: &Query) -> Result<()> {
         self.visit_query(query)
     }
@@ -156,7 +173,13 @@ pub trait AstVisitor {
     fn visit_query(&mut self, query: &Query) -> Result<()> {
         self.visit_set_expr(&query.body)?;
         self.visit_order_by_exprs(&query.order_by)?;
-        self.visit_expr(query.limit.as_ref().unwrap())
+        if let Some(limit) = &query.limit {
+            self.visit_expr(limit)?;
+        }
+        if let Some(offset) = &query.offset {
+            self.visit_expr(offset)?;
+        }
+        Ok(())
     }
 
     fn visit_set_expr(&mut self, set_expr: &SetExpr) -> Result<()> {
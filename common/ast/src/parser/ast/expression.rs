@@ -85,6 +85,26 @@ pub enum Expr {
     Exists(Box<Query>),
     /// Scalar subquery, which will only return a single row with a single column.
     Subquery(Box<Query>),
+    /// Array literal, like `[1, 2, 3]`
+    Array { exprs: Vec<Expr> },
+    /// Element access by an index or a key, like `arr[0]` or `map['key']`
+    Subscript { expr: Box<Expr>, index: Box<Expr> },
+    /// `EXTRACT(field FROM expr)` expression
+    Extract {
+        field: DateTimeField,
+        expr: Box<Expr>,
+    },
+}
+
+/// The part of a date or datetime in `EXTRACT(field FROM expr)`
+#[derive(Debug, Clone, PartialEq)]
+pub enum DateTimeField {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -136,10 +156,20 @@ pub enum BinaryOperator {
     Lte,
     Eq,
     NotEq,
+    // `<=>` operator, NULL-safe equal
+    NullSafeEq,
+    IsDistinctFrom,
+    IsNotDistinctFrom,
     And,
     Or,
     Like,
     NotLike,
+    ILike,
+    NotILike,
+    Regexp,
+    NotRegexp,
+    RLike,
+    NotRLike,
     BitwiseOr,
     BitwiseAnd,
     BitwiseXor,
@@ -210,6 +240,15 @@ impl Display for BinaryOperator {
             BinaryOperator::NotEq => {
                 write!(f, "<>")
             }
+            BinaryOperator::NullSafeEq => {
+                write!(f, "<=>")
+            }
+            BinaryOperator::IsDistinctFrom => {
+                write!(f, "IS DISTINCT FROM")
+            }
+            BinaryOperator::IsNotDistinctFrom => {
+                write!(f, "IS NOT DISTINCT FROM")
+            }
             BinaryOperator::And => {
                 write!(f, "AND")
             }
@@ -222,6 +261,24 @@ impl Display for BinaryOperator {
             BinaryOperator::NotLike => {
                 write!(f, "NOT LIKE")
             }
+            BinaryOperator::ILike => {
+                write!(f, "ILIKE")
+            }
+            BinaryOperator::NotILike => {
+                write!(f, "NOT ILIKE")
+            }
+            BinaryOperator::Regexp => {
+                write!(f, "REGEXP")
+            }
+            BinaryOperator::NotRegexp => {
+                write!(f, "NOT REGEXP")
+            }
+            BinaryOperator::RLike => {
+                write!(f, "RLIKE")
+            }
+            BinaryOperator::NotRLike => {
+                write!(f, "NOT RLIKE")
+            }
             BinaryOperator::BitwiseOr => {
                 write!(f, "|")
             }
@@ -315,6 +372,19 @@ impl Display for TypeName {
     }
 }
 
+impl Display for DateTimeField {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DateTimeField::Year => write!(f, "YEAR"),
+            DateTimeField::Month => write!(f, "MONTH"),
+            DateTimeField::Day => write!(f, "DAY"),
+            DateTimeField::Hour => write!(f, "HOUR"),
+            DateTimeField::Minute => write!(f, "MINUTE"),
+            DateTimeField::Second => write!(f, "SECOND"),
+        }
+    }
+}
+
 impl Display for Literal {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -471,6 +541,22 @@ impl Display for Expr {
             Expr::Subquery(subquery) => {
                 write!(f, "({})", subquery)?;
             }
+            Expr::Array { exprs } => {
+                write!(f, "[")?;
+                for i in 0..exprs.len() {
+                    write!(f, "{}", exprs[i])?;
+                    if i != exprs.len() - 1 {
+                        write!(f, ", ")?;
+                    }
+                }
+                write!(f, "]")?;
+            }
+            Expr::Subscript { expr, index } => {
+                write!(f, "{}[{}]", expr, index)?;
+            }
+            Expr::Extract { field, expr } => {
+                write!(f, "EXTRACT({} FROM {})", field, expr)?;
+            }
         }
 
         Ok(())
@@ -28,8 +28,10 @@ pub struct Query {
     // The following clauses can only appear in top level of a subquery/query
     // `ORDER BY` clause
     pub order_by: Vec<OrderByExpr>,
-    // `LIMIT` clause
+    // `LIMIT` clause, `FETCH FIRST n ROWS ONLY` is transformed into it
     pub limit: Option<Expr>,
+    // `OFFSET` clause
+    pub offset: Option<Expr>,
 }
 
 // A relational set expression, like `SELECT ... FROM ... {UNION|EXCEPT|INTERSECT} SELECT ... FROM ...`
@@ -404,6 +406,11 @@ impl Display for Query {
             write!(f, " LIMIT {}", limit)?;
         }
 
+        // OFFSET clause
+        if let Some(offset) = &self.offset {
+            write!(f, " OFFSET {}", offset)?;
+        }
+
         Ok(())
     }
 }
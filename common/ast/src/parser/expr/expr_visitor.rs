@@ -23,6 +23,7 @@ use sqlparser::ast::Function;
 use sqlparser::ast::FunctionArg;
 use sqlparser::ast::FunctionArgExpr;
 use sqlparser::ast::Ident;
+use sqlparser::ast::ObjectName;
 use sqlparser::ast::Query;
 use sqlparser::ast::UnaryOperator;
 use sqlparser::ast::Value;
@@ -53,6 +54,9 @@ pub trait ExprVisitor: Sized + Send {
             Expr::IsNotNull(expr) => self.visit_simple_function(expr, "isnotnull").await,
             Expr::UnaryOp { op, expr } => self.visit_unary_expr(op, expr).await,
             Expr::BinaryOp { left, op, right } => self.visit_binary_expr(left, op, right).await,
+            Expr::IsDistinctFrom(left, right) | Expr::IsNotDistinctFrom(left, right) => {
+                self.visit_distinct_from(left, right).await
+            }
             Expr::Exists(subquery) => self.visit_exists(subquery),
             Expr::Subquery(subquery) => self.visit_subquery(subquery),
             Expr::Function(function) => self.visit_function(function).await,
@@ -79,6 +83,16 @@ pub trait ExprVisitor: Sized + Send {
             Expr::Tuple(exprs) => self.visit_tuple(exprs).await,
             Expr::InList { expr, list, .. } => self.visit_inlist(expr, list).await,
             Expr::Extract { field, expr } => self.visit_extract(field, expr).await,
+            Expr::MapAccess { column, keys } => self.visit_map_access(column, keys).await,
+            Expr::Case {
+                operand,
+                conditions,
+                results,
+                else_result,
+            } => {
+                self.visit_case(operand, conditions, results, else_result)
+                    .await
+            }
             other => Result::Err(ErrorCode::SyntaxException(format!(
                 "Unsupported expression: {}, type: {:?}",
                 expr, other
@@ -186,6 +200,11 @@ pub trait ExprVisitor: Sized + Send {
         ExprTraverser::accept(right, self).await
     }
 
+    async fn visit_distinct_from(&mut self, left: &Expr, right: &Expr) -> Result<()> {
+        ExprTraverser::accept(left, self).await?;
+        ExprTraverser::accept(right, self).await
+    }
+
     async fn visit_between(
         &mut self,
         expr: &Expr,
@@ -225,4 +244,61 @@ pub trait ExprVisitor: Sized + Send {
     async fn visit_extract(&mut self, _field: &DateTimeField, expr: &Expr) -> Result<()> {
         ExprTraverser::accept(expr, self).await
     }
+
+    /// `column[key1][key2]` is visited as `get(get(column, key1), key2)`.
+    async fn visit_map_access(&mut self, column: &Expr, keys: &[Value]) -> Result<()> {
+        let expr = keys.iter().fold(column.clone(), |expr, key| {
+            Expr::Function(Function {
+                name: ObjectName(vec![Ident::new("get")]),
+                params: vec![],
+                args: vec![
+                    FunctionArg::Unnamed(FunctionArgExpr::Expr(expr)),
+                    FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(key.clone()))),
+                ],
+                over: None,
+                distinct: false,
+            })
+        });
+        ExprTraverser::accept(&expr, self).await
+    }
+
+    /// `CASE [operand] WHEN c1 THEN r1 ... ELSE e END` is visited as `multi_if(c1, r1, ..., e)`,
+    /// the conditions are `operand = c` with an operand and the else result defaults to NULL.
+    async fn visit_case(
+        &mut self,
+        operand: &Option<Box<Expr>>,
+        conditions: &[Expr],
+        results: &[Expr],
+        else_result: &Option<Box<Expr>>,
+    ) -> Result<()> {
+        let mut args = Vec::with_capacity(conditions.len() * 2 + 1);
+        for (condition, result) in conditions.iter().zip(results.iter()) {
+            let condition = match operand {
+                Some(operand) => Expr::BinaryOp {
+                    left: operand.clone(),
+                    op: BinaryOperator::Eq,
+                    right: Box::new(condition.clone()),
+                },
+                None => condition.clone(),
+            };
+            args.push(condition);
+            args.push(result.clone());
+        }
+        args.push(match else_result {
+            Some(else_result) => *else_result.clone(),
+            None => Expr::Value(Value::Null),
+        });
+
+        let expr = Expr::Function(Function {
+            name: ObjectName(vec![Ident::new("multi_if")]),
+            params: vec![],
+            args: args
+                .into_iter()
+                .map(|arg| FunctionArg::Unnamed(FunctionArgExpr::Expr(arg)))
+                .collect(),
+            over: None,
+            distinct: false,
+        });
+        ExprTraverser::accept(&expr, self).await
+    }
 }
@@ -25,6 +25,7 @@ use pratt::PrattParser;
 use pratt::Precedence;
 
 use crate::parser::ast::BinaryOperator;
+use crate::parser::ast::DateTimeField;
 use crate::parser::ast::Expr;
 use crate::parser::ast::Identifier;
 use crate::parser::ast::Literal;
@@ -70,7 +71,30 @@ pub fn subexpr(min_precedence: u32) -> impl FnMut(Input) -> IResult<Expr> {
 
         let (i, expr_elements) = rule! { #expr_element_limited* }(i)?;
 
-        let mut iter = expr_elements.into_iter();
+        // `[...]` right after an operand is a subscript like `arr[0]`, otherwise it's an array literal.
+        let mut elements: Vec<WithSpan> = Vec::with_capacity(expr_elements.len());
+        for elem in expr_elements {
+            let follows_operand = elements.last().map_or(false, |prev| {
+                matches!(
+                    PrattParser::<std::iter::Once<_>>::query(&mut ExprParser, prev).unwrap(),
+                    Affix::Nilfix | Affix::Postfix(_)
+                )
+            });
+            let elem = match elem.elem {
+                ExprElement::Array { mut exprs } if follows_operand && exprs.len() == 1 => {
+                    WithSpan {
+                        elem: ExprElement::Subscript {
+                            index: exprs.remove(0),
+                        },
+                        span: elem.span,
+                    }
+                }
+                _ => elem,
+            };
+            elements.push(elem);
+        }
+
+        let mut iter = elements.into_iter();
         let expr = ExprParser
             .parse(&mut iter)
             .map_err(|err| {
@@ -187,6 +211,12 @@ pub enum ExprElement {
     Subquery(Query),
     /// An expression between parentheses
     Group(Expr),
+    /// Array literal, like `[1, 2, 3]`
+    Array { exprs: Vec<Expr> },
+    /// Element access by an index or a key, like `[0]` in `arr[0]`
+    Subscript { index: Expr },
+    /// `EXTRACT(field FROM expr)` expression
+    Extract { field: DateTimeField, expr: Expr },
 }
 
 struct ExprParser;
@@ -202,6 +232,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a>>> PrattParser<I> for ExprParser {
             ExprElement::Between { .. } => Affix::Postfix(Precedence(BETWEEN_PREC)),
             ExprElement::InList { .. } => Affix::Postfix(Precedence(20)),
             ExprElement::InSubquery { .. } => Affix::Postfix(Precedence(20)),
+            ExprElement::Subscript { .. } => Affix::Postfix(Precedence(60)),
             ExprElement::UnaryOp { op } => match op {
                 UnaryOperator::Not => Affix::Prefix(Precedence(15)),
 
@@ -215,12 +246,23 @@ impl<'a, I: Iterator<Item = WithSpan<'a>>> PrattParser<I> for ExprParser {
 
                 BinaryOperator::Eq => Affix::Infix(Precedence(20), Associativity::Right),
                 BinaryOperator::NotEq => Affix::Infix(Precedence(20), Associativity::Left),
+                BinaryOperator::NullSafeEq => Affix::Infix(Precedence(20), Associativity::Left),
+                BinaryOperator::IsDistinctFrom => Affix::Infix(Precedence(20), Associativity::Left),
+                BinaryOperator::IsNotDistinctFrom => {
+                    Affix::Infix(Precedence(20), Associativity::Left)
+                }
                 BinaryOperator::Gt => Affix::Infix(Precedence(20), Associativity::Left),
                 BinaryOperator::Lt => Affix::Infix(Precedence(20), Associativity::Left),
                 BinaryOperator::Gte => Affix::Infix(Precedence(20), Associativity::Left),
                 BinaryOperator::Lte => Affix::Infix(Precedence(20), Associativity::Left),
                 BinaryOperator::Like => Affix::Infix(Precedence(20), Associativity::Left),
                 BinaryOperator::NotLike => Affix::Infix(Precedence(20), Associativity::Left),
+                BinaryOperator::ILike => Affix::Infix(Precedence(20), Associativity::Left),
+                BinaryOperator::NotILike => Affix::Infix(Precedence(20), Associativity::Left),
+                BinaryOperator::Regexp => Affix::Infix(Precedence(20), Associativity::Left),
+                BinaryOperator::NotRegexp => Affix::Infix(Precedence(20), Associativity::Left),
+                BinaryOperator::RLike => Affix::Infix(Precedence(20), Associativity::Left),
+                BinaryOperator::NotRLike => Affix::Infix(Precedence(20), Associativity::Left),
 
                 BinaryOperator::BitwiseOr => Affix::Infix(Precedence(22), Associativity::Left),
                 BinaryOperator::BitwiseAnd => Affix::Infix(Precedence(22), Associativity::Left),
@@ -282,6 +324,11 @@ impl<'a, I: Iterator<Item = WithSpan<'a>>> PrattParser<I> for ExprParser {
             ExprElement::Exists(subquery) => Expr::Exists(Box::new(subquery)),
             ExprElement::Subquery(subquery) => Expr::Subquery(Box::new(subquery)),
             ExprElement::Group(expr) => expr,
+            ExprElement::Array { exprs } => Expr::Array { exprs },
+            ExprElement::Extract { field, expr } => Expr::Extract {
+                field,
+                expr: Box::new(expr),
+            },
             _ => unreachable!(),
         };
         Ok(expr)
@@ -332,6 +379,10 @@ impl<'a, I: Iterator<Item = WithSpan<'a>>> PrattParser<I> for ExprParser {
                 high: Box::new(high),
                 not,
             },
+            ExprElement::Subscript { index } => Expr::Subscript {
+                expr: Box::new(lhs),
+                index: Box::new(index),
+            },
             _ => unreachable!(),
         };
         Ok(expr)
@@ -405,6 +456,12 @@ pub fn expr_element(i: Input) -> IResult<WithSpan> {
         },
         |(_, _, expr, _, target_type, _)| ExprElement::Cast { expr, target_type },
     );
+    let extract = map(
+        rule! {
+            EXTRACT ~ "(" ~ #cut(date_time_field) ~ FROM ~ #cut(subexpr(0)) ~ ")"
+        },
+        |(_, _, field, _, expr, _)| ExprElement::Extract { field, expr },
+    );
     let count_all = value(ExprElement::CountAll, rule! {
         COUNT ~ "(" ~ "*" ~ ")"
     });
@@ -486,6 +543,21 @@ pub fn expr_element(i: Input) -> IResult<WithSpan> {
     let group = map(rule! { "(" ~ #cut(subexpr(0)) ~ ")" }, |(_, expr, _)| {
         ExprElement::Group(expr)
     });
+    let array = map(
+        rule! {
+            "[" ~ (#subexpr(0) ~ ("," ~ #cut(subexpr(0)))*)? ~ "]"
+        },
+        |(_, exprs, _)| {
+            let exprs = exprs
+                .map(|(head, tail)| {
+                    let mut exprs = vec![head];
+                    exprs.extend(tail.into_iter().map(|(_, expr)| expr));
+                    exprs
+                })
+                .unwrap_or_default();
+            ExprElement::Array { exprs }
+        },
+    );
     let binary_op = map(binary_op, |op| ExprElement::BinaryOp { op });
     let unary_op = map(unary_op, |op| ExprElement::UnaryOp { op });
     let literal = map(literal, ExprElement::Literal);
@@ -499,6 +571,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan> {
         | #binary_op : "<operator>"
         | #unary_op : "<operator>"
         | #cast : "`CAST(... AS ...)` expression"
+        | #extract : "`EXTRACT(... FROM ...)` expression"
         | #count_all : "COUNT(*)"
         | #literal : "<literal>"
         | #function_call_with_param : "<function>"
@@ -507,6 +580,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan> {
         | #exists : "`EXISTS (SELECT ...)` expression"
         | #subquery : "`(SELECT ...)` expression"
         | #group : "expression between `(...)`"
+        | #array : "`[...]` expression"
     )(i)?;
 
     let input_ptr = i.as_ptr();
@@ -526,7 +600,7 @@ pub fn unary_op(i: Input) -> IResult<UnaryOperator> {
 }
 
 pub fn binary_op(i: Input) -> IResult<BinaryOperator> {
-    alt((
+    let arithmetic = alt((
         value(BinaryOperator::Plus, rule! { Plus }),
         value(BinaryOperator::Minus, rule! { Minus }),
         value(BinaryOperator::Multiply, rule! { Multiply }),
@@ -539,14 +613,37 @@ pub fn binary_op(i: Input) -> IResult<BinaryOperator> {
         value(BinaryOperator::Lte, rule! { Lte }),
         value(BinaryOperator::Eq, rule! { Eq }),
         value(BinaryOperator::NotEq, rule! { NotEq }),
+        value(BinaryOperator::NullSafeEq, rule! { Spaceship }),
         value(BinaryOperator::And, rule! { AND }),
         value(BinaryOperator::Or, rule! { OR }),
+    ));
+    let pattern = alt((
         value(BinaryOperator::NotLike, rule! { NOT ~ LIKE }),
         value(BinaryOperator::Like, rule! { LIKE }),
+        value(BinaryOperator::NotILike, rule! { NOT ~ ILIKE }),
+        value(BinaryOperator::ILike, rule! { ILIKE }),
+        value(BinaryOperator::NotRegexp, rule! { NOT ~ REGEXP }),
+        value(BinaryOperator::Regexp, rule! { REGEXP }),
+        value(BinaryOperator::NotRLike, rule! { NOT ~ RLIKE }),
+        value(BinaryOperator::RLike, rule! { RLIKE }),
+    ));
+    let distinct = alt((
+        value(
+            BinaryOperator::IsDistinctFrom,
+            rule! { IS ~ DISTINCT ~ FROM },
+        ),
+        value(
+            BinaryOperator::IsNotDistinctFrom,
+            rule! { IS ~ NOT ~ DISTINCT ~ FROM },
+        ),
+    ));
+    let bitwise = alt((
         value(BinaryOperator::BitwiseOr, rule! { "|" }),
         value(BinaryOperator::BitwiseAnd, rule! { "&" }),
         value(BinaryOperator::BitwiseXor, rule! { "^" }),
-    ))(i)
+    ));
+
+    alt((arithmetic, pattern, distinct, bitwise))(i)
 }
 
 pub fn literal(i: Input) -> IResult<Literal> {
@@ -577,6 +674,17 @@ pub fn literal(i: Input) -> IResult<Literal> {
     )(i)
 }
 
+pub fn date_time_field(i: Input) -> IResult<DateTimeField> {
+    alt((
+        value(DateTimeField::Year, rule! { YEAR }),
+        value(DateTimeField::Month, rule! { MONTH }),
+        value(DateTimeField::Day, rule! { DAY }),
+        value(DateTimeField::Hour, rule! { HOUR }),
+        value(DateTimeField::Minute, rule! { MINUTE }),
+        value(DateTimeField::Second, rule! { SECOND }),
+    ))(i)
+}
+
 pub fn type_name(i: Input) -> IResult<TypeName> {
     let ty_char = map(
         rule! { CHAR ~ ("(" ~ #cut(literal_u64) ~ ")")? },
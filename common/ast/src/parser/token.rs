@@ -759,6 +759,8 @@ pub enum TokenKind {
     REFERENCING,
     #[token("REGCLASS", ignore(ascii_case))]
     REGCLASS,
+    #[token("REGEXP", ignore(ascii_case))]
+    REGEXP,
     #[token("REGR_AVGX", ignore(ascii_case))]
     REGR_AVGX,
     #[token("REGR_AVGY", ignore(ascii_case))]
@@ -799,6 +801,8 @@ pub enum TokenKind {
     REVOKE,
     #[token("RIGHT", ignore(ascii_case))]
     RIGHT,
+    #[token("RLIKE", ignore(ascii_case))]
+    RLIKE,
     #[token("ROLLBACK", ignore(ascii_case))]
     ROLLBACK,
     #[token("ROLLUP", ignore(ascii_case))]
@@ -16,7 +16,9 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use sqlparser::ast::BinaryOperator as SqlparserBinaryOperator;
 use sqlparser::ast::DataType as SqlparserDataType;
+use sqlparser::ast::DateTimeField as SqlparserDateTimeField;
 use sqlparser::ast::Expr as SqlparserExpr;
+use sqlparser::ast::Fetch;
 use sqlparser::ast::FunctionArg;
 use sqlparser::ast::FunctionArgExpr;
 use sqlparser::ast::Ident;
@@ -282,18 +284,45 @@ impl TransformerSqlparser {
                 })
             })
             .collect::<Result<_>>()?;
-        let limit = orig_ast
-            .limit
+        let limit = match &orig_ast.fetch {
+            None => orig_ast
+                .limit
+                .as_ref()
+                .map(|expr| self.transform_expr(expr))
+                .transpose()?,
+            Some(fetch) => Some(self.transform_fetch(orig_ast, fetch)?),
+        };
+        let offset = orig_ast
+            .offset
             .as_ref()
-            .map(|expr| self.transform_expr(expr))
+            .map(|offset| self.transform_expr(&offset.value))
             .transpose()?;
         Ok(Query {
             body,
             order_by,
             limit,
+            offset,
         })
     }
 
+    // FETCH { FIRST | NEXT } [n] { ROW | ROWS } ONLY is the standard spelling of LIMIT n.
+    fn transform_fetch(&self, orig_ast: &SqlparserQuery, fetch: &Fetch) -> Result<Expr> {
+        if orig_ast.limit.is_some() {
+            return Err(ErrorCode::SyntaxException(
+                "Cannot use both LIMIT and FETCH in the same query",
+            ));
+        }
+        if fetch.with_ties || fetch.percent {
+            return Err(ErrorCode::SyntaxException(
+                "FETCH with PERCENT or WITH TIES is unsupported",
+            ));
+        }
+        match &fetch.quantity {
+            Some(quantity) => self.transform_expr(quantity),
+            None => Ok(Expr::Literal(Literal::Number("1".to_string()))),
+        }
+    }
+
     fn transform_set_expr(&self, orig_ast: &SqlparserSetExpr) -> Result<SetExpr> {
         match orig_ast {
             SqlparserSetExpr::SetOperation {
@@ -668,6 +697,16 @@ impl TransformerSqlparser {
                 high: Box::new(self.transform_expr(high)?),
                 not: *negated,
             }),
+            SqlparserExpr::IsDistinctFrom(left, right) => Ok(Expr::BinaryOp {
+                op: BinaryOperator::IsDistinctFrom,
+                left: Box::new(self.transform_expr(left)?),
+                right: Box::new(self.transform_expr(right)?),
+            }),
+            SqlparserExpr::IsNotDistinctFrom(left, right) => Ok(Expr::BinaryOp {
+                op: BinaryOperator::IsNotDistinctFrom,
+                left: Box::new(self.transform_expr(left)?),
+                right: Box::new(self.transform_expr(right)?),
+            }),
             SqlparserExpr::BinaryOp { left, op, right } => Ok(Expr::BinaryOp {
                 op: self.transform_binary_operator(op)?,
                 left: Box::new(self.transform_expr(left)?),
@@ -681,21 +720,15 @@ impl TransformerSqlparser {
                 expr: Box::new(self.transform_expr(expr)?),
                 target_type: self.transform_data_type(data_type)?,
             }),
-            SqlparserExpr::Value(literal) => {
-                let lit = match literal {
-                    Value::Number(str, _) => Ok(Literal::Number(str.to_owned())),
-                    Value::SingleQuotedString(str) => Ok(Literal::String(str.to_owned())),
-                    Value::NationalStringLiteral(str) => Ok(Literal::String(str.to_owned())),
-                    Value::HexStringLiteral(str) => Ok(Literal::String(str.to_owned())),
-                    Value::DoubleQuotedString(str) => Ok(Literal::String(str.to_owned())),
-                    Value::Boolean(v) => Ok(Literal::Boolean(v.to_owned())),
-                    Value::Null => Ok(Literal::Null),
-                    _ => Err(ErrorCode::SyntaxException(std::format!(
-                        "Unsupported SQL statement: {}",
-                        self.orig_stmt
-                    ))),
-                }?;
-                Ok(Expr::Literal(lit))
+            SqlparserExpr::Value(literal) => Ok(Expr::Literal(self.transform_value(literal)?)),
+            SqlparserExpr::MapAccess { column, keys } => {
+                keys.iter()
+                    .try_fold(self.transform_expr(column)?, |expr, key| {
+                        Ok(Expr::Subscript {
+                            expr: Box::new(expr),
+                            index: Box::new(Expr::Literal(self.transform_value(key)?)),
+                        })
+                    })
             }
             SqlparserExpr::Function(func)
                 if func.name.0.get(0).map(|ident| ident.value.to_uppercase())
@@ -764,6 +797,26 @@ impl TransformerSqlparser {
             SqlparserExpr::Subquery(subquery) => {
                 Ok(Expr::Subquery(Box::new(self.transform_query(subquery)?)))
             }
+            SqlparserExpr::Extract { field, expr } => Ok(Expr::Extract {
+                field: self.transform_date_time_field(field),
+                expr: Box::new(self.transform_expr(expr)?),
+            }),
+            _ => Err(ErrorCode::SyntaxException(std::format!(
+                "Unsupported SQL statement: {}",
+                self.orig_stmt
+            ))),
+        }
+    }
+
+    fn transform_value(&self, value: &Value) -> Result<Literal> {
+        match value {
+            Value::Number(str, _) => Ok(Literal::Number(str.to_owned())),
+            Value::SingleQuotedString(str) => Ok(Literal::String(str.to_owned())),
+            Value::NationalStringLiteral(str) => Ok(Literal::String(str.to_owned())),
+            Value::HexStringLiteral(str) => Ok(Literal::String(str.to_owned())),
+            Value::DoubleQuotedString(str) => Ok(Literal::String(str.to_owned())),
+            Value::Boolean(v) => Ok(Literal::Boolean(v.to_owned())),
+            Value::Null => Ok(Literal::Null),
             _ => Err(ErrorCode::SyntaxException(std::format!(
                 "Unsupported SQL statement: {}",
                 self.orig_stmt
@@ -786,6 +839,7 @@ impl TransformerSqlparser {
             SqlparserBinaryOperator::LtEq => Ok(BinaryOperator::Lte),
             SqlparserBinaryOperator::Eq => Ok(BinaryOperator::Eq),
             SqlparserBinaryOperator::NotEq => Ok(BinaryOperator::NotEq),
+            SqlparserBinaryOperator::Spaceship => Ok(BinaryOperator::NullSafeEq),
             SqlparserBinaryOperator::And => Ok(BinaryOperator::And),
             SqlparserBinaryOperator::Or => Ok(BinaryOperator::Or),
             SqlparserBinaryOperator::Like => Ok(BinaryOperator::Like),
@@ -812,6 +866,17 @@ impl TransformerSqlparser {
         }
     }
 
+    fn transform_date_time_field(&self, field: &SqlparserDateTimeField) -> DateTimeField {
+        match field {
+            SqlparserDateTimeField::Year => DateTimeField::Year,
+            SqlparserDateTimeField::Month => DateTimeField::Month,
+            SqlparserDateTimeField::Day => DateTimeField::Day,
+            SqlparserDateTimeField::Hour => DateTimeField::Hour,
+            SqlparserDateTimeField::Minute => DateTimeField::Minute,
+            SqlparserDateTimeField::Second => DateTimeField::Second,
+        }
+    }
+
     fn transform_data_type(&self, data_type: &SqlparserDataType) -> Result<TypeName> {
         match data_type {
             SqlparserDataType::Char(length) => Ok(TypeName::Char(length.to_owned())),
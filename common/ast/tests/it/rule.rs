@@ -101,6 +101,11 @@ fn test_expr() {
             AND p_size BETWEEN CAST (1 AS smallint) AND CAST (5 AS smallint)
             AND l_shipmode IN ('AIR', 'AIR REG')
             AND l_shipinstruct = 'DELIVER IN PERSON'",
+        "a[0][1] + [1, 2]",
+        "a REGEXP '^b' AND c NOT RLIKE 'd'",
+        "a NOT ILIKE '%B%'",
+        "a <=> NULL AND b IS NOT DISTINCT FROM c OR a IS DISTINCT FROM 1",
+        "EXTRACT(YEAR FROM a) + extract(day from b)",
     ];
 
     for case in cases {
@@ -31,6 +31,10 @@ fn test_parse_with_sqlparser() {
         "select * from a right outer join b using(a);",
         "select * from a full outer join b using(a);",
         "select * from a inner join b using(a);",
+        "select a from t order by a limit 10 offset 5;",
+        "select a from t offset 5;",
+        "select a from t offset 2 rows fetch first 3 rows only;",
+        "select a from t fetch next row only;",
     ];
     let stmts: Vec<Statement> = sqls
         .into_iter()
@@ -53,6 +57,10 @@ fn test_parse_with_sqlparser() {
         r#"SELECT * FROM a RIGHT OUTER JOIN b USING(a)"#,
         r#"SELECT * FROM a FULL OUTER JOIN b USING(a)"#,
         r#"SELECT * FROM a INNER JOIN b USING(a)"#,
+        r#"SELECT a FROM t ORDER BY a LIMIT 10 OFFSET 5"#,
+        r#"SELECT a FROM t OFFSET 5"#,
+        r#"SELECT a FROM t LIMIT 3 OFFSET 2"#,
+        r#"SELECT a FROM t LIMIT 1"#,
     ];
     for (stmt, expect) in stmts.iter().zip(expected) {
         assert_eq!(format!("{}", stmt), expect);
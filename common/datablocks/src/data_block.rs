@@ -79,12 +79,27 @@ impl DataBlock {
         self.columns.len()
     }
 
-    /// Data Block physical memory size
+    /// Data Block physical memory size, the bytes referenced by its rows.
     #[inline]
     pub fn memory_size(&self) -> usize {
         self.columns.iter().map(|x| x.memory_size()).sum()
     }
 
+    /// The bytes kept alive by the block, larger than memory_size if the columns are
+    /// slices of bigger buffers.
+    #[inline]
+    pub fn retained_memory_size(&self) -> usize {
+        self.columns.iter().map(|x| x.retained_memory_size()).sum()
+    }
+
+    /// The (referenced, retained) memory size of each column.
+    pub fn columns_memory_size(&self) -> Vec<(usize, usize)> {
+        self.columns
+            .iter()
+            .map(|x| (x.memory_size(), x.retained_memory_size()))
+            .collect()
+    }
+
     #[inline]
     pub fn column(&self, index: usize) -> &ColumnRef {
         &self.columns[index]
@@ -33,7 +33,10 @@ impl DataBlock {
     ) -> Result<HashMethodKind> {
         if column_names.len() == 1 {
             let typ = block.try_column_by_name(&column_names[0])?;
-            if typ.data_type_id() == TypeID::String {
+            if matches!(
+                typ.data_type_id(),
+                TypeID::String | TypeID::Binary | TypeID::Uuid | TypeID::IPv6
+            ) {
                 return Ok(HashMethodKind::SingleString(
                     HashMethodSingleString::default(),
                 ));
@@ -197,6 +197,12 @@ impl HashMethod for HashMethodSingleString {
     ) -> Result<Vec<&'a [u8]>> {
         debug_assert!(group_columns.len() == 1);
         let column = group_columns[0];
+        if let Some(dictionary) = column.as_any().downcast_ref::<DictionaryColumn>() {
+            // borrow the keys from the distinct values instead of decoding the column
+            let values = dictionary.values();
+            let keys = dictionary.keys().iter();
+            return Ok(keys.map(|key| values.get_data(*key as usize)).collect());
+        }
         let str_column: &StringColumn = Series::check_get(column)?;
 
         let mut values = Vec::with_capacity(rows);
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_datavalues::prelude::*;
 use common_exception::Result;
 
 use crate::DataBlock;
@@ -26,7 +27,7 @@ impl DataBlock {
         let mut scattered_columns = Vec::with_capacity(scatter_size);
 
         for column_index in 0..columns_size {
-            let column = block.column(column_index).scatter(indices, scatter_size);
+            let column = Series::scatter(block.column(column_index), indices, scatter_size)?;
             scattered_columns.push(column);
         }
 
@@ -30,6 +30,60 @@ pub struct SortColumnDescription {
     pub column_name: String,
     pub asc: bool,
     pub nulls_first: bool,
+    /// Compare string values case insensitively, set by a `_ci` collation.
+    pub case_insensitive: bool,
+}
+
+impl SortColumnDescription {
+    // The column the comparisons run on: case insensitive keys are compared
+    // by their lowercased values, floats by their canonical values so that all NaNs
+    // are equal and sort after +Infinity, and -0.0 equals 0.0. The block itself keeps
+    // the original values.
+    fn sort_key(&self, block: &DataBlock) -> Result<ColumnRef> {
+        let column = block.try_column_by_name(&self.column_name)?;
+
+        if column.is_nullable() && !column.is_const() {
+            let nullable: &NullableColumn = Series::check_get(column)?;
+            if let Some(key) = self.normalize(nullable.inner()) {
+                let validity = nullable.ensure_validity().clone();
+                return Ok(NullableColumn::new(key, validity).arc());
+            }
+        } else if let Some(key) = self.normalize(column) {
+            return Ok(key);
+        }
+
+        Ok(column.clone())
+    }
+
+    fn normalize(&self, column: &ColumnRef) -> Option<ColumnRef> {
+        // Collations only apply to strings.
+        if self.case_insensitive {
+            if let Ok(column) = Series::check_get::<StringColumn>(column) {
+                return Some(column.to_lowercase().arc());
+            }
+        }
+
+        if let Ok(column) = Series::check_get::<Float64Column>(column) {
+            return canonical_floats(column, |v| v.to_bits() == v.canonical().to_bits());
+        }
+        if let Ok(column) = Series::check_get::<Float32Column>(column) {
+            return canonical_floats(column, |v| v.to_bits() == v.canonical().to_bits());
+        }
+        None
+    }
+}
+
+fn canonical_floats<T: PrimitiveType>(
+    column: &PrimitiveColumn<T>,
+    is_canonical: impl Fn(&T) -> bool,
+) -> Option<ColumnRef> {
+    // Most columns hold neither NaN nor -0.0, they can be compared as they are.
+    if column.values().iter().all(is_canonical) {
+        return None;
+    }
+
+    let values = column.values().iter().map(|v| v.canonical());
+    Some(Arc::new(PrimitiveColumn::<T>::from_owned_iterator(values)))
 }
 
 impl DataBlock {
@@ -38,27 +92,24 @@ impl DataBlock {
         sort_columns_descriptions: &[SortColumnDescription],
         limit: Option<usize>,
     ) -> Result<DataBlock> {
-        let order_columns = sort_columns_descriptions
-            .iter()
-            .map(|f| Ok(block.try_column_by_name(&f.column_name)?.as_arrow_array()))
-            .collect::<Result<Vec<_>>>()?;
+        if sort_columns_descriptions.is_empty() {
+            let rows = limit.unwrap_or(usize::MAX).min(block.num_rows());
+            return Ok(block.slice(0, rows));
+        }
 
-        let order_arrays = sort_columns_descriptions
+        let sort_keys = sort_columns_descriptions
             .iter()
-            .zip(order_columns.iter())
-            .map(|(f, array)| {
-                Ok(arrow_sort::SortColumn {
-                    values: array.as_ref(),
-                    options: Some(arrow_sort::SortOptions {
-                        descending: !f.asc,
-                        nulls_first: f.nulls_first,
-                    }),
+            .map(|f| {
+                Ok(SortKey {
+                    column: f.sort_key(block)?,
+                    descending: !f.asc,
+                    nulls_first: f.nulls_first,
                 })
             })
             .collect::<Result<Vec<_>>>()?;
 
-        let indices = arrow_sort::lexsort_to_indices(&order_arrays, limit)?;
-        DataBlock::block_take_by_indices(block, indices.values())
+        let indices = Series::lexsort(&sort_keys, limit)?;
+        DataBlock::block_take_by_indices(block, &indices)
     }
 
     pub fn merge_sort_block(
@@ -78,13 +129,10 @@ impl DataBlock {
         let sort_arrays = sort_columns_descriptions
             .iter()
             .map(|f| {
-                let left = lhs.try_column_by_name(&f.column_name)?.clone();
-                let left = left.as_arrow_array();
-
-                let right = rhs.try_column_by_name(&f.column_name)?.clone();
-                let right = right.as_arrow_array();
-
-                Ok(vec![left, right])
+                Ok(vec![
+                    f.sort_key(lhs)?.as_arrow_array(),
+                    f.sort_key(rhs)?.as_arrow_array(),
+                ])
             })
             .collect::<Result<Vec<_>>>()?;
 
@@ -110,7 +158,14 @@ impl DataBlock {
             })
             .collect::<Vec<_>>();
 
-        let comparator = build_comparator(&sort_options_with_array)?;
+        // Arrow can't compare some types, e.g. arrays, they are merged by sorting both blocks.
+        let comparator = match build_comparator(&sort_options_with_array) {
+            Ok(comparator) => comparator,
+            Err(_) => {
+                let block = DataBlock::concat_blocks(&[lhs.clone(), rhs.clone()])?;
+                return DataBlock::sort_block(&block, sort_columns_descriptions, limit);
+            }
+        };
         let lhs_indices = (0, 0, lhs.num_rows());
         let rhs_indices = (1, 0, rhs.num_rows());
         let slices = merge_sort_slices(once(&lhs_indices), once(&rhs_indices), &comparator);
@@ -73,3 +73,27 @@ fn test_data_block_convert() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_data_block_memory_size() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i64::to_data_type()),
+        DataField::new("b", Vu8::to_data_type()),
+    ]);
+
+    let a = Series::from_data(vec![1i64, 2, 3, 4]);
+    let b = Series::from_data(vec!["aa", "bb", "cc", "dd"]);
+    let block = DataBlock::create(schema.clone(), vec![a.clone(), b.clone()]);
+    // 4 * 8 + (8 + 5 * 8)
+    assert_eq!(80, block.memory_size());
+    assert_eq!(80, block.retained_memory_size());
+
+    let sliced = DataBlock::create(schema, vec![a.slice(1, 2), b.slice(1, 2)]);
+    // 2 * 8 + (4 + 3 * 8)
+    assert_eq!(44, sliced.memory_size());
+    // the values of the sliced string column are shared with the whole column
+    assert_eq!(52, sliced.retained_memory_size());
+    assert_eq!(vec![(16, 16), (28, 32)], sliced.columns_memory_size());
+
+    Ok(())
+}
@@ -70,3 +70,24 @@ fn test_data_block_group_by() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_data_block_group_by_non_finite_floats() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", f64::to_data_type())]);
+
+    // All NaNs fall into one group, so do 0.0 and -0.0.
+    let block = DataBlock::create(schema, vec![Series::from_data(vec![
+        f64::NAN,
+        -f64::NAN,
+        0.0,
+        -0.0,
+        f64::INFINITY,
+    ])]);
+
+    let columns = &["a".to_string()];
+    let table = DataBlock::group_by_blocks(&block, columns)?;
+    let mut sizes = table.iter().map(|b| b.num_rows()).collect::<Vec<_>>();
+    sizes.sort_unstable();
+    assert_eq!(sizes, vec![1, 2, 2]);
+    Ok(())
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_datablocks::*;
 use common_datavalues::prelude::*;
 use common_exception::Result;
@@ -33,6 +35,7 @@ fn test_data_block_sort() -> Result<()> {
             column_name: "a".to_owned(),
             asc: true,
             nulls_first: false,
+            case_insensitive: false,
         }];
         let results = DataBlock::sort_block(&raw, &options, Some(3))?;
         assert_eq!(raw.schema(), results.schema());
@@ -54,6 +57,7 @@ fn test_data_block_sort() -> Result<()> {
             column_name: "a".to_owned(),
             asc: false,
             nulls_first: false,
+            case_insensitive: false,
         }];
         let results = DataBlock::sort_block(&raw, &options, Some(3))?;
         assert_eq!(raw.schema(), results.schema());
@@ -94,6 +98,7 @@ fn test_data_block_merge_sort() -> Result<()> {
             column_name: "a".to_owned(),
             asc: true,
             nulls_first: false,
+            case_insensitive: false,
         }];
         let results = DataBlock::merge_sort_block(&raw1, &raw2, &options, None)?;
 
@@ -116,3 +121,156 @@ fn test_data_block_merge_sort() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_data_block_sort_nulls_and_collation() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new(
+        "s",
+        wrap_nullable(&Vu8::to_data_type()),
+    )]);
+
+    let raw = DataBlock::create(schema.clone(), vec![Series::from_data(vec![
+        Some("b"),
+        None,
+        Some("C"),
+        Some("a"),
+    ])]);
+
+    {
+        let options = vec![SortColumnDescription {
+            column_name: "s".to_owned(),
+            asc: true,
+            nulls_first: true,
+            case_insensitive: true,
+        }];
+        let results = DataBlock::sort_block(&raw, &options, None)?;
+        assert_eq!(raw.schema(), results.schema());
+
+        let expected = vec![
+            "+------+", //
+            "| s    |", "+------+", "| NULL |", "| a    |", "| b    |", "| C    |", "+------+",
+        ];
+        common_datablocks::assert_blocks_eq(expected, &[results]);
+    }
+
+    {
+        let options = vec![SortColumnDescription {
+            column_name: "s".to_owned(),
+            asc: true,
+            nulls_first: false,
+            case_insensitive: false,
+        }];
+        let results = DataBlock::sort_block(&raw, &options, None)?;
+
+        let expected = vec![
+            "+------+", //
+            "| s    |", "+------+", "| C    |", "| a    |", "| b    |", "| NULL |", "+------+",
+        ];
+        common_datablocks::assert_blocks_eq(expected, &[results]);
+    }
+
+    {
+        let other = DataBlock::create(schema, vec![Series::from_data(vec![Some("B0"), Some("d")])]);
+        let left = DataBlock::sort_block(
+            &raw,
+            &[SortColumnDescription {
+                column_name: "s".to_owned(),
+                asc: true,
+                nulls_first: false,
+                case_insensitive: true,
+            }],
+            None,
+        )?;
+
+        let options = vec![SortColumnDescription {
+            column_name: "s".to_owned(),
+            asc: true,
+            nulls_first: false,
+            case_insensitive: true,
+        }];
+        let results = DataBlock::merge_sort_block(&left, &other, &options, None)?;
+
+        let expected = vec![
+            "+------+", //
+            "| s    |", "+------+", "| a    |", "| b    |", "| B0   |", "| C    |", "| d    |",
+            "| NULL |", "+------+",
+        ];
+        common_datablocks::assert_blocks_eq(expected, &[results]);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_data_block_sort_non_finite_floats() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", f64::to_data_type())]);
+
+    let raw = DataBlock::create(schema, vec![Series::from_data(vec![
+        1.0,
+        f64::NAN,
+        f64::NEG_INFINITY,
+        -f64::NAN,
+        f64::INFINITY,
+        -1.0,
+    ])]);
+
+    // NaNs are equal whatever their sign and sort after +Infinity.
+    let options = vec![SortColumnDescription {
+        column_name: "a".to_owned(),
+        asc: true,
+        nulls_first: false,
+        case_insensitive: false,
+    }];
+    let results = DataBlock::sort_block(&raw, &options, None)?;
+    let expected = vec![
+        "+------+", "| a    |", "+------+", "| -inf |", "| -1   |", "| 1    |", "| inf  |",
+        "| NaN  |", "| NaN  |", "+------+",
+    ];
+    common_datablocks::assert_blocks_eq(expected, &[results]);
+    Ok(())
+}
+
+#[test]
+fn test_data_block_sort_array_column() -> Result<()> {
+    let array_type = ArrayType::create(i64::to_data_type());
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", Arc::new(array_type)),
+        DataField::new("b", u8::to_data_type()),
+    ]);
+
+    let block = |arrays: Vec<Vec<i64>>, b: Vec<u8>| {
+        DataBlock::create(schema.clone(), vec![
+            Series::from_data(arrays),
+            Series::from_data(b),
+        ])
+    };
+
+    let options = vec![SortColumnDescription {
+        column_name: "a".to_owned(),
+        asc: true,
+        nulls_first: false,
+        case_insensitive: false,
+    }];
+
+    let raw = block(vec![vec![2], vec![1, 2], vec![], vec![1]], vec![0, 1, 2, 3]);
+    let results = DataBlock::sort_block(&raw, &options, None)?;
+    let b = results.try_column_by_name("b")?;
+    assert_eq!(b.to_values(), vec![
+        DataValue::UInt64(2),
+        DataValue::UInt64(3),
+        DataValue::UInt64(1),
+        DataValue::UInt64(0)
+    ]);
+
+    // arrow can't compare arrays, the blocks are merged by sorting them together
+    let lhs = block(vec![vec![], vec![3]], vec![0, 1]);
+    let rhs = block(vec![vec![1], vec![2]], vec![2, 3]);
+    let results = DataBlock::merge_sort_block(&lhs, &rhs, &options, Some(3))?;
+    let b = results.try_column_by_name("b")?;
+    assert_eq!(b.to_values(), vec![
+        DataValue::UInt64(0),
+        DataValue::UInt64(2),
+        DataValue::UInt64(3)
+    ]);
+    Ok(())
+}
@@ -12,12 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod mutable;
+
 use std::sync::Arc;
 
 use common_arrow::arrow::array::*;
 use common_arrow::arrow::buffer::Buffer;
 use common_arrow::arrow::datatypes::DataType as ArrowType;
 use common_arrow::arrow::types::Index;
+pub use mutable::*;
 
 use crate::prelude::*;
 
@@ -32,19 +35,23 @@ pub struct ArrayColumn {
 
 impl ArrayColumn {
     pub fn new(array: LargeListArray) -> Self {
-        let ty = array.data_type();
-
-        let data_type = if let ArrowType::LargeList(f) = ty {
-            let ty = from_arrow_field(f);
-            Arc::new(ArrayType::create(ty))
-        } else {
-            unreachable!()
+        // the list may be an extension type, e.g. a map
+        let (data_type, values) = match array.data_type().to_logical_type() {
+            ArrowType::LargeList(f) if f.is_nullable => (
+                from_arrow_type(array.data_type()),
+                array.values().clone().into_nullable_column(),
+            ),
+            ArrowType::LargeList(_) => (
+                from_arrow_type(array.data_type()),
+                array.values().clone().into_column(),
+            ),
+            _ => unreachable!(),
         };
 
         Self {
             data_type,
             offsets: array.offsets().clone(),
-            values: array.values().clone().into_column(),
+            values,
         }
     }
 
@@ -80,6 +87,11 @@ impl ArrayColumn {
     pub fn offsets(&self) -> &[i64] {
         self.offsets.as_slice()
     }
+
+    fn from_values(&self, values: &[DataValue]) -> ColumnRef {
+        // the values are taken from this column, so they always match the type
+        self.data_type.create_column(values).unwrap()
+    }
 }
 
 impl Column for ArrayColumn {
@@ -96,7 +108,18 @@ impl Column for ArrayColumn {
     }
 
     fn memory_size(&self) -> usize {
-        self.values.memory_size() + self.offsets.len() * std::mem::size_of::<i64>()
+        let start = self.offsets[0] as usize;
+        let end = self.offsets[self.offsets.len() - 1] as usize;
+        let values = if start == 0 && end == self.values.len() {
+            self.values.memory_size()
+        } else {
+            self.values.slice(start, end - start).memory_size()
+        };
+        values + self.offsets.len() * std::mem::size_of::<i64>()
+    }
+
+    fn retained_memory_size(&self) -> usize {
+        self.values.retained_memory_size() + self.offsets.len() * std::mem::size_of::<i64>()
     }
 
     fn as_arrow_array(&self) -> ArrayRef {
@@ -125,12 +148,26 @@ impl Column for ArrayColumn {
         }
     }
 
-    fn scatter(&self, _indices: &[usize], _scattered_size: usize) -> Vec<ColumnRef> {
-        todo!()
+    fn scatter(&self, indices: &[usize], scattered_size: usize) -> Vec<ColumnRef> {
+        let mut values = vec![Vec::new(); scattered_size];
+        for (row, index) in indices.iter().enumerate() {
+            values[*index].push(self.get(row));
+        }
+        values
+            .iter()
+            .map(|values| self.from_values(values))
+            .collect()
     }
 
-    fn filter(&self, _filter: &BooleanColumn) -> ColumnRef {
-        todo!()
+    fn filter(&self, filter: &BooleanColumn) -> ColumnRef {
+        let values = filter
+            .values()
+            .iter()
+            .enumerate()
+            .filter(|(_, selected)| *selected)
+            .map(|(row, _)| self.get(row))
+            .collect::<Vec<_>>();
+        self.from_values(&values)
     }
 
     fn replicate(&self, offsets: &[usize]) -> ColumnRef {
@@ -139,9 +176,17 @@ impl Column for ArrayColumn {
             "Size of offsets must match size of column"
         );
 
-        // match datatypes
-        // TODO: see https://github.com/ClickHouse/ClickHouse/blob/340b53ef853348758c9042b16a8599120ebc8d22/src/Columns/ColumnArray.cpp
-        todo!()
+        // TODO: replicate the offsets and the values directly, see https://github.com/ClickHouse/ClickHouse/blob/340b53ef853348758c9042b16a8599120ebc8d22/src/Columns/ColumnArray.cpp
+        let mut values = Vec::with_capacity(offsets.last().copied().unwrap_or_default());
+        let mut previous_offset = 0;
+        for (row, offset) in offsets.iter().enumerate() {
+            let value = self.get(row);
+            for _ in previous_offset..*offset {
+                values.push(value.clone());
+            }
+            previous_offset = *offset;
+        }
+        self.from_values(&values)
     }
 
     fn convert_full_column(&self) -> ColumnRef {
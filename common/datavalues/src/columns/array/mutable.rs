@@ -0,0 +1,79 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::columns::mutable::MutableColumn;
+use crate::prelude::*;
+
+/// Builds an array (or map) column from the values of its rows.
+pub struct MutableArrayColumn {
+    data_type: DataTypePtr,
+    values: Vec<DataValue>,
+}
+
+impl MutableArrayColumn {
+    pub fn with_capacity(data_type: DataTypePtr, capacity: usize) -> Self {
+        Self {
+            data_type,
+            values: Vec::with_capacity(capacity),
+        }
+    }
+}
+
+impl MutableColumn for MutableArrayColumn {
+    fn data_type(&self) -> DataTypePtr {
+        self.data_type.clone()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn append_default(&mut self) {
+        self.values.push(DataValue::Array(vec![]));
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.values.shrink_to_fit();
+    }
+
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    fn to_column(&mut self) -> ColumnRef {
+        let values = std::mem::take(&mut self.values);
+        // the values are checked when they are appended
+        self.data_type.create_column(&values).unwrap()
+    }
+
+    fn append_data_value(&mut self, value: DataValue) -> Result<()> {
+        match value {
+            DataValue::Array(_) => {
+                self.values.push(value);
+                Ok(())
+            }
+            other => Err(ErrorCode::BadDataValueType(format!(
+                "Unexpected type:{:?}, expect to be array",
+                other.value_type()
+            ))),
+        }
+    }
+}
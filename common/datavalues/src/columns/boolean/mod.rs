@@ -64,6 +64,51 @@ impl BooleanColumn {
     pub fn values(&self) -> &Bitmap {
         &self.values
     }
+
+    /// The number of true values, the rows unset in `validity` are not counted.
+    pub fn count_true(&self, validity: Option<&Bitmap>) -> usize {
+        let validity = match validity {
+            None => return self.values.len() - self.values.null_count(),
+            Some(validity) => validity,
+        };
+
+        let (value_slice, value_offset, length) = self.values.as_slice();
+        let (valid_slice, valid_offset, _) = validity.as_slice();
+        if value_offset != 0 || valid_offset != 0 {
+            let valid_values = &self.values & validity;
+            return valid_values.len() - valid_values.null_count();
+        }
+
+        let mut chunks = BitChunksExact::<u64>::new(value_slice, length);
+        let mut valid_chunks = BitChunksExact::<u64>::new(valid_slice, length);
+
+        let count: usize = chunks
+            .by_ref()
+            .zip(valid_chunks.by_ref())
+            .map(|(chunk, valid)| (chunk & valid).count_ones() as usize)
+            .sum();
+
+        count
+            + chunks
+                .remainder_iter()
+                .zip(valid_chunks.remainder_iter())
+                .filter(|(value, valid)| *value && *valid)
+                .count()
+    }
+
+    /// If all the rows set in `validity` are true, true for an empty column.
+    pub fn all_true(&self, validity: Option<&Bitmap>) -> bool {
+        let valid_rows = match validity {
+            None => self.len(),
+            Some(validity) => validity.len() - validity.null_count(),
+        };
+        self.count_true(validity) == valid_rows
+    }
+
+    /// If any of the rows set in `validity` is true.
+    pub fn any_true(&self, validity: Option<&Bitmap>) -> bool {
+        self.count_true(validity) > 0
+    }
 }
 
 impl Column for BooleanColumn {
@@ -110,8 +155,19 @@ impl Column for BooleanColumn {
             return Arc::new(self.clone());
         }
         let mut bitmap = MutableBitmap::with_capacity(selected);
-        let (value_slice, _, value_length) = self.values().as_slice();
-        let (slice, _, length) = filter.values().as_slice();
+        let (value_slice, value_offset, value_length) = self.values().as_slice();
+        let (slice, offset, length) = filter.values().as_slice();
+
+        // the chunks are only aligned if the bitmaps start at a byte boundary
+        if value_offset != 0 || offset != 0 {
+            let values = self.values.iter().zip(filter.values().iter());
+            values
+                .filter(|(_, is_selected)| *is_selected)
+                .for_each(|(value, _)| bitmap.push(value));
+            return Arc::new(BooleanColumn {
+                values: bitmap.into(),
+            });
+        }
 
         let mut chunks = BitChunksExact::<u64>::new(value_slice, value_length);
         let mut mask_chunks = BitChunksExact::<u64>::new(slice, length);
@@ -51,6 +51,11 @@ pub trait Column: Send + Sync {
         false
     }
 
+    /// If the column is a dictionary encoded string column.
+    fn is_dictionary(&self) -> bool {
+        false
+    }
+
     fn len(&self) -> usize;
     /// whether the array is empty
     fn is_empty(&self) -> bool {
@@ -71,7 +76,15 @@ pub trait Column: Send + Sync {
         (false, None)
     }
 
+    /// The bytes referenced by the rows of the column, a sliced column only counts its own rows.
     fn memory_size(&self) -> usize;
+
+    /// The bytes kept alive by the column, including the parts of the shared buffers
+    /// that are outside of the slice.
+    fn retained_memory_size(&self) -> usize {
+        self.memory_size()
+    }
+
     fn arc(&self) -> ColumnRef;
     fn as_arrow_array(&self) -> ArrayRef;
     fn slice(&self, offset: usize, length: usize) -> ColumnRef;
@@ -165,17 +178,21 @@ where A: AsRef<dyn Array>
             Boolean => Arc::new(BooleanColumn::from_arrow_array(self.as_ref())),
             UInt8 => Arc::new(UInt8Column::from_arrow_array(self.as_ref())),
             UInt16 | Date16 => Arc::new(UInt16Column::from_arrow_array(self.as_ref())),
-            UInt32 | DateTime32 => Arc::new(UInt32Column::from_arrow_array(self.as_ref())),
+            UInt32 | DateTime32 | IPv4 => Arc::new(UInt32Column::from_arrow_array(self.as_ref())),
             UInt64 => Arc::new(UInt64Column::from_arrow_array(self.as_ref())),
             Int8 => Arc::new(Int8Column::from_arrow_array(self.as_ref())),
             Int16 => Arc::new(Int16Column::from_arrow_array(self.as_ref())),
             Int32 | Date32 => Arc::new(Int32Column::from_arrow_array(self.as_ref())),
-            Int64 | Interval | DateTime64 => Arc::new(Int64Column::from_arrow_array(self.as_ref())),
+            Int64 | Interval | DateTime64 | Decimal => {
+                Arc::new(Int64Column::from_arrow_array(self.as_ref()))
+            }
             Float32 => Arc::new(Float32Column::from_arrow_array(self.as_ref())),
             Float64 => Arc::new(Float64Column::from_arrow_array(self.as_ref())),
-            Array => Arc::new(ArrayColumn::from_arrow_array(self.as_ref())),
+            Array | Map => Arc::new(ArrayColumn::from_arrow_array(self.as_ref())),
             Struct => Arc::new(StructColumn::from_arrow_array(self.as_ref())),
-            String => Arc::new(StringColumn::from_arrow_array(self.as_ref())),
+            String | Binary | Uuid | IPv6 | Bitmap => {
+                Arc::new(StringColumn::from_arrow_array(self.as_ref()))
+            }
             Variant => Arc::new(JsonColumn::from_arrow_array(self.as_ref())),
             VariantArray => Arc::new(JsonColumn::from_arrow_array(self.as_ref())),
             VariantObject => Arc::new(JsonColumn::from_arrow_array(self.as_ref())),
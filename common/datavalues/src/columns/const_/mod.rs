@@ -82,6 +82,10 @@ impl Column for ConstColumn {
         self.column.memory_size()
     }
 
+    fn retained_memory_size(&self) -> usize {
+        self.column.retained_memory_size()
+    }
+
     fn as_arrow_array(&self) -> ArrayRef {
         let column = self.column.replicate(&[self.length]);
         column.as_arrow_array()
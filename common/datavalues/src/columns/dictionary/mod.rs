@@ -0,0 +1,200 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_arrow::arrow::array::*;
+use common_arrow::arrow::buffer::Buffer;
+
+use crate::prelude::*;
+
+/// A low-cardinality string column, each row is a code into a dictionary of the distinct values.
+/// It has the String data type, the functions that can't work on the codes decode it
+/// with `convert_full_column`.
+#[derive(Clone)]
+pub struct DictionaryColumn {
+    keys: Buffer<u32>,
+    values: StringColumn,
+}
+
+impl DictionaryColumn {
+    pub fn new(keys: Buffer<u32>, values: StringColumn) -> Self {
+        debug_assert!(
+            keys.iter().all(|k| (*k as usize) < values.len()),
+            "The keys must be smaller than the size of the dictionary"
+        );
+        Self { keys, values }
+    }
+
+    /// Encodes the column, returns None if it has more than `max_ndv` distinct values.
+    pub fn try_encode(column: &StringColumn, max_ndv: usize) -> Option<Self> {
+        let mut codes: HashMap<&[u8], u32> = HashMap::with_capacity(max_ndv);
+        let mut values = MutableStringColumn::with_capacity(max_ndv);
+        let mut keys = Vec::with_capacity(column.len());
+
+        for value in column.iter() {
+            let code = match codes.get(value) {
+                Some(code) => *code,
+                None => {
+                    if codes.len() == max_ndv {
+                        return None;
+                    }
+                    let code = codes.len() as u32;
+                    codes.insert(value, code);
+                    values.append_value(value);
+                    code
+                }
+            };
+            keys.push(code);
+        }
+
+        Some(Self {
+            keys: keys.into(),
+            values: values.finish(),
+        })
+    }
+
+    pub fn keys(&self) -> &[u32] {
+        self.keys.as_slice()
+    }
+
+    /// The distinct values of the column.
+    pub fn values(&self) -> &StringColumn {
+        &self.values
+    }
+
+    /// Builds a new column with the dictionary values at the given keys.
+    pub fn decode(&self) -> StringColumn {
+        let mut builder = MutableStringColumn::with_values_capacity(
+            self.values.values().len() * self.len() / self.values.len().max(1),
+            self.len(),
+        );
+        for key in self.keys.iter() {
+            // soundness: the keys are smaller than the size of the dictionary
+            builder.append_value(unsafe { self.values.value_unchecked(*key as usize) });
+        }
+        builder.finish()
+    }
+
+    fn with_keys(&self, keys: Vec<u32>) -> Self {
+        Self {
+            keys: keys.into(),
+            values: self.values.clone(),
+        }
+    }
+}
+
+impl Column for DictionaryColumn {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn data_type(&self) -> DataTypePtr {
+        StringType::arc()
+    }
+
+    fn is_dictionary(&self) -> bool {
+        true
+    }
+
+    fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    fn memory_size(&self) -> usize {
+        self.keys.len() * std::mem::size_of::<u32>() + self.values.memory_size()
+    }
+
+    fn retained_memory_size(&self) -> usize {
+        self.keys.len() * std::mem::size_of::<u32>() + self.values.retained_memory_size()
+    }
+
+    fn as_arrow_array(&self) -> ArrayRef {
+        self.decode().as_arrow_array()
+    }
+
+    fn arc(&self) -> ColumnRef {
+        Arc::new(self.clone())
+    }
+
+    fn slice(&self, offset: usize, length: usize) -> ColumnRef {
+        Arc::new(Self {
+            keys: self.keys.clone().slice(offset, length),
+            values: self.values.clone(),
+        })
+    }
+
+    fn filter(&self, filter: &BooleanColumn) -> ColumnRef {
+        let length = filter.values().len() - filter.values().null_count();
+        if length == self.len() {
+            return Arc::new(self.clone());
+        }
+
+        let keys = self
+            .keys
+            .iter()
+            .zip(filter.values().iter())
+            .filter(|(_, selected)| *selected)
+            .map(|(key, _)| *key)
+            .collect();
+        Arc::new(self.with_keys(keys))
+    }
+
+    fn scatter(&self, indices: &[usize], scattered_size: usize) -> Vec<ColumnRef> {
+        let mut keys = vec![Vec::with_capacity(self.len() / scattered_size); scattered_size];
+        indices
+            .iter()
+            .zip(self.keys.iter())
+            .for_each(|(index, key)| keys[*index].push(*key));
+
+        keys.into_iter()
+            .map(|keys| Arc::new(self.with_keys(keys)) as ColumnRef)
+            .collect()
+    }
+
+    fn replicate(&self, offsets: &[usize]) -> ColumnRef {
+        debug_assert!(
+            offsets.len() == self.len(),
+            "Size of offsets must match size of column"
+        );
+
+        let mut keys = Vec::with_capacity(offsets.last().copied().unwrap_or_default());
+        let mut previous_offset = 0;
+        for (key, offset) in self.keys.iter().zip(offsets.iter()) {
+            keys.extend(std::iter::repeat(*key).take(*offset - previous_offset));
+            previous_offset = *offset;
+        }
+        Arc::new(self.with_keys(keys))
+    }
+
+    fn convert_full_column(&self) -> ColumnRef {
+        Arc::new(self.decode())
+    }
+
+    fn get(&self, index: usize) -> DataValue {
+        self.values.get(self.keys[index] as usize)
+    }
+}
+
+impl std::fmt::Debug for DictionaryColumn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let iter = self.keys.iter().map(|key| {
+            // soundness: the keys are smaller than the size of the dictionary
+            String::from_utf8_lossy(unsafe { self.values.value_unchecked(*key as usize) })
+        });
+        let head = "DictionaryColumn";
+        display_fmt(iter, head, self.len(), self.data_type_id(), f)
+    }
+}
@@ -92,7 +92,11 @@ impl Series {
         vec: &mut Vec<SmallVu8>,
         nulls: Option<Bitmap>,
     ) -> Result<()> {
-        let column = column.convert_full_column();
+        // keep the dictionary encoding, the values are serialized once per distinct value
+        let column = match column.is_const() {
+            true => column.convert_full_column(),
+            false => column.clone(),
+        };
 
         if column.data_type().is_nullable() {
             let (_, validity) = column.validity();
@@ -101,6 +105,11 @@ impl Series {
             return Series::serialize(&column, vec, bitmap);
         }
 
+        if column.is_dictionary() {
+            let col: &DictionaryColumn = unsafe { Series::static_cast(&column) };
+            return GroupHash::serialize(col, vec, nulls);
+        }
+
         let column = Series::remove_nullable(&column);
         let type_id = column.data_type_id().to_physical_type();
 
@@ -159,8 +168,9 @@ where
                 for (value, valid) in self.iter().zip(bitmap.iter()) {
                     unsafe {
                         if valid {
+                            let value = value.canonical();
                             std::ptr::copy_nonoverlapping(
-                                value as *const T as *const u8,
+                                &value as *const T as *const u8,
                                 ptr,
                                 std::mem::size_of::<T>(),
                             );
@@ -174,9 +184,10 @@ where
             }
             _ => {
                 for value in self.iter() {
+                    let value = value.canonical();
                     unsafe {
                         std::ptr::copy_nonoverlapping(
-                            value as *const T as *const u8,
+                            &value as *const T as *const u8,
                             ptr,
                             std::mem::size_of::<T>(),
                         );
@@ -197,13 +208,13 @@ where
                 for ((value, valid), vec) in self.iter().zip(bitmap.iter()).zip(vec) {
                     BinaryWrite::write_scalar(vec, &valid)?;
                     if valid {
-                        BinaryWrite::write_scalar(vec, value)?;
+                        BinaryWrite::write_scalar(vec, &value.canonical())?;
                     }
                 }
             }
             _ => {
                 for (value, vec) in self.iter().zip(vec) {
-                    BinaryWrite::write_scalar(vec, value)?;
+                    BinaryWrite::write_scalar(vec, &value.canonical())?;
                 }
             }
         }
@@ -294,5 +305,37 @@ impl GroupHash for StringColumn {
     }
 }
 
+impl GroupHash for DictionaryColumn {
+    fn serialize(&self, vec: &mut Vec<SmallVu8>, nulls: Option<Bitmap>) -> Result<()> {
+        assert_eq!(vec.len(), self.len());
+
+        let mut serialized = Vec::with_capacity(self.values().len());
+        for value in self.values().iter() {
+            let mut bytes = SmallVu8::new();
+            BinaryWrite::write_binary(&mut bytes, value)?;
+            serialized.push(bytes);
+        }
+
+        let keys = self.keys().iter();
+        match nulls {
+            Some(bitmap) => {
+                for ((key, valid), vec) in keys.zip(bitmap.iter()).zip(vec) {
+                    BinaryWrite::write_scalar(vec, &valid)?;
+                    if valid {
+                        vec.extend_from_slice(&serialized[*key as usize]);
+                    }
+                }
+            }
+            None => {
+                for (key, vec) in keys.zip(vec) {
+                    vec.extend_from_slice(&serialized[*key as usize]);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
 // TODO(b41sh): implement GroupHash for JsonColumn
 impl GroupHash for JsonColumn {}
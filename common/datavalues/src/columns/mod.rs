@@ -20,6 +20,7 @@ mod boolean;
 mod column;
 mod column_with_field;
 mod const_;
+mod dictionary;
 mod eq;
 #[allow(clippy::ptr_arg)]
 mod group_hash;
@@ -29,6 +30,7 @@ mod nullable;
 mod object;
 mod primitive;
 pub mod series;
+mod sort;
 mod string;
 mod struct_;
 mod take;
@@ -39,6 +41,7 @@ pub use builder::*;
 pub use column::*;
 pub use column_with_field::*;
 pub use const_::*;
+pub use dictionary::*;
 pub use group_hash::GroupHash;
 pub use mutable::*;
 pub use null::*;
@@ -46,6 +49,7 @@ pub use nullable::*;
 pub use object::*;
 pub use primitive::*;
 pub use series::*;
+pub use sort::*;
 pub use string::*;
 pub use struct_::*;
 pub use take::*;
@@ -92,7 +92,11 @@ impl Column for NullableColumn {
     }
 
     fn memory_size(&self) -> usize {
-        self.column.memory_size()
+        self.column.memory_size() + self.validity.as_slice().0.len()
+    }
+
+    fn retained_memory_size(&self) -> usize {
+        self.column.retained_memory_size() + self.validity.as_slice().0.len()
     }
 
     fn as_arrow_array(&self) -> ArrayRef {
@@ -96,6 +96,87 @@ impl Series {
         arr
     }
 
+    /// Downcasts a full, non nullable column of the primitive type T.
+    pub fn as_primitive<T: PrimitiveType>(column: &ColumnRef) -> Result<&PrimitiveColumn<T>> {
+        Self::check_get_full(column)
+    }
+
+    /// Downcasts a full, non nullable String column.
+    pub fn as_string(column: &ColumnRef) -> Result<&StringColumn> {
+        Self::check_get_full(column)
+    }
+
+    /// Downcasts a full, non nullable Boolean column.
+    pub fn as_boolean(column: &ColumnRef) -> Result<&BooleanColumn> {
+        Self::check_get_full(column)
+    }
+
+    fn check_get_full<T: 'static + Column>(column: &ColumnRef) -> Result<&T> {
+        column.as_any().downcast_ref::<T>().ok_or_else(|| {
+            let hint = match column.is_const() || column.is_nullable() {
+                true => ", use Series::iter_opt for the nullable or constant columns",
+                false => "",
+            };
+            ErrorCode::UnknownColumn(format!(
+                "downcast column error, column type: {:?}, expected column: {:?}{}",
+                column.data_type(),
+                std::any::type_name::<T>(),
+                hint,
+            ))
+        })
+    }
+
+    /// Iterates the values of any column of the scalar type T as Option,
+    /// the nullable and constant columns included.
+    pub fn iter_opt<T: Scalar>(column: &ColumnRef) -> Result<OptionViewerIter<'_, T::Viewer<'_>>> {
+        let viewer = T::try_create_viewer(column)?;
+        Ok(viewer.iter_opt())
+    }
+
+    /// Like iter_opt, but yields the values in chunks of `chunk_size` rows, the last one may be shorter.
+    pub fn iter_opt_chunks<T: Scalar>(
+        column: &ColumnRef,
+        chunk_size: usize,
+    ) -> Result<impl Iterator<Item = Vec<Option<T::RefType<'_>>>> + '_> {
+        assert!(chunk_size > 0, "The chunk size must be positive");
+        let mut iter = Self::iter_opt::<T>(column)?;
+        Ok(std::iter::from_fn(move || {
+            let chunk = iter.by_ref().take(chunk_size).collect::<Vec<_>>();
+            match chunk.is_empty() {
+                true => None,
+                false => Some(chunk),
+            }
+        }))
+    }
+
+    /// Builds a column of the scalar type T, the capacity is reserved up front.
+    pub fn from_iter_with_capacity<'a, T: Scalar>(
+        iter: impl Iterator<Item = T::RefType<'a>>,
+        capacity: usize,
+    ) -> ColumnRef {
+        let mut builder = ColumnBuilder::<T>::with_capacity(capacity);
+        for value in iter {
+            builder.append(value);
+        }
+        Arc::new(builder.build_column())
+    }
+
+    /// Builds a nullable column of the scalar type T, None is a null row.
+    pub fn from_opt_iter_with_capacity<'a, T: Scalar>(
+        iter: impl Iterator<Item = Option<T::RefType<'a>>>,
+        capacity: usize,
+    ) -> ColumnRef {
+        let mut builder = NullableColumnBuilder::<T>::with_capacity(capacity);
+        for value in iter {
+            match value {
+                Some(value) => builder.append(value, true),
+                None => builder.append_null(),
+            }
+        }
+        let rows = builder.len();
+        builder.build(rows)
+    }
+
     pub fn remove_nullable(column: &ColumnRef) -> ColumnRef {
         if column.is_nullable() {
             //constant nullable ?
@@ -112,9 +193,53 @@ impl Series {
         }
     }
 
+    /// If the column or the inner column of a nullable column is dictionary encoded.
+    pub fn has_dictionary(column: &ColumnRef) -> bool {
+        if column.is_nullable() && !column.is_const() {
+            let col: &NullableColumn = unsafe { Self::static_cast(column) };
+            return col.inner().is_dictionary();
+        }
+        column.is_dictionary()
+    }
+
+    /// Decodes the dictionary encoded string columns, the other columns are returned as they are.
+    pub fn remove_dictionary(column: &ColumnRef) -> ColumnRef {
+        if column.is_dictionary() {
+            return column.convert_full_column();
+        }
+        if column.is_nullable() && !column.is_const() {
+            let col: &NullableColumn = unsafe { Self::static_cast(column) };
+            if col.inner().is_dictionary() {
+                let inner = col.inner().convert_full_column();
+                return NullableColumn::new(inner, col.ensure_validity().clone()).arc();
+            }
+        }
+        column.clone()
+    }
+
+    /// Dictionary encodes a (nullable) string column if it has at most `max_ndv` distinct values.
+    pub fn try_encode_dictionary(column: &ColumnRef, max_ndv: usize) -> Option<ColumnRef> {
+        if column.is_const() || column.is_dictionary() {
+            return None;
+        }
+        if column.is_nullable() {
+            let col: &NullableColumn = unsafe { Self::static_cast(column) };
+            let inner = Self::try_encode_dictionary(col.inner(), max_ndv)?;
+            return Some(NullableColumn::new(inner, col.ensure_validity().clone()).arc());
+        }
+        let col = column.as_any().downcast_ref::<StringColumn>()?;
+        DictionaryColumn::try_encode(col, max_ndv).map(|c| c.arc())
+    }
+
+    /// Concatenates the columns into one, a single column is returned without a copy.
+    /// The result is nullable if any of the columns is.
     pub fn concat(columns: &[ColumnRef]) -> Result<ColumnRef> {
         debug_assert!(!columns.is_empty());
-        let is_nullable = columns[0].is_nullable();
+        if columns.len() == 1 {
+            return Ok(columns[0].clone());
+        }
+
+        let is_nullable = columns.iter().any(|c| c.is_nullable());
         let arrays = columns
             .iter()
             .map(|c| c.as_arrow_array())
@@ -191,6 +316,27 @@ impl SeriesFrom<Vec<String>, Vec<String>> for Series {
     }
 }
 
+macro_rules! impl_from_nested_vec {
+    ($( $S: ident ),*) => {
+        $(
+            impl SeriesFrom<Vec<Vec<$S>>, Vec<Vec<$S>>> for Series {
+                fn from_data(v: Vec<Vec<$S>>) -> ColumnRef {
+                    let values = v
+                        .into_iter()
+                        .map(|v| DataValue::Array(v.into_iter().map(DataValue::from).collect()))
+                        .collect::<Vec<_>>();
+                    ArrayType::create($S::to_data_type())
+                        .create_column(&values)
+                        .unwrap()
+                }
+            }
+        )*
+    };
+}
+
+// `Vec<Vec<u8>>` is a string column.
+impl_from_nested_vec! { i8, i16, i32, i64, u16, u32, u64, f32, f64, bool, Vu8 }
+
 impl SeriesFrom<Vec<JsonValue>, Vec<JsonValue>> for Series {
     fn from_data(v: Vec<JsonValue>) -> ColumnRef {
         JsonColumn::new_from_vec(v).arc()
@@ -0,0 +1,202 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::prelude::*;
+
+/// A column to sort by and its order.
+#[derive(Clone, Debug)]
+pub struct SortKey {
+    pub column: ColumnRef,
+    pub descending: bool,
+    pub nulls_first: bool,
+}
+
+// Compares two rows of a column in the ascending order, nulls excluded.
+type RowComparator = Box<dyn Fn(usize, usize) -> Ordering + Send + Sync>;
+
+impl Series {
+    /// The indices of the rows in the order of the column, the first `limit` ones if any.
+    /// Equal rows keep their original order.
+    pub fn argsort(
+        column: &ColumnRef,
+        descending: bool,
+        nulls_first: bool,
+        limit: Option<usize>,
+    ) -> Result<Vec<u32>> {
+        let key = SortKey {
+            column: column.clone(),
+            descending,
+            nulls_first,
+        };
+        Self::lexsort(&[key], limit)
+    }
+
+    /// The indices of the rows in the lexicographical order of the keys, the first `limit` ones if any.
+    /// Equal rows keep their original order.
+    pub fn lexsort(keys: &[SortKey], limit: Option<usize>) -> Result<Vec<u32>> {
+        let rows = keys.first().map(|key| key.column.len()).unwrap_or(0);
+        if let Some(key) = keys.iter().find(|key| key.column.len() != rows) {
+            return Err(ErrorCode::BadArguments(format!(
+                "The sort keys must have the same number of rows, got {} and {}",
+                rows,
+                key.column.len()
+            )));
+        }
+
+        let comparators = keys
+            .iter()
+            .map(Self::key_comparator)
+            .collect::<Result<Vec<_>>>()?;
+
+        let compare = |a: &u32, b: &u32| {
+            let (a, b) = (*a as usize, *b as usize);
+            comparators
+                .iter()
+                .map(|comparator| comparator(a, b))
+                .find(|ordering| *ordering != Ordering::Equal)
+                .unwrap_or_else(|| a.cmp(&b))
+        };
+
+        let mut indices = (0..rows as u32).collect::<Vec<_>>();
+        match limit {
+            Some(limit) if limit < rows => {
+                if limit > 0 {
+                    indices.select_nth_unstable_by(limit - 1, compare);
+                }
+                indices.truncate(limit);
+            }
+            _ => {}
+        }
+        // The row index breaks the ties, the unstable sort is stable.
+        indices.sort_unstable_by(compare);
+        Ok(indices)
+    }
+
+    fn key_comparator(key: &SortKey) -> Result<RowComparator> {
+        let column = &key.column;
+        let (descending, nulls_first) = (key.descending, key.nulls_first);
+
+        let ordered = move |ordering: Ordering| match descending {
+            true => ordering.reverse(),
+            false => ordering,
+        };
+
+        if column.is_const() || column.only_null() {
+            return Ok(Box::new(|_, _| Ordering::Equal));
+        }
+
+        if column.is_nullable() {
+            let nullable: &NullableColumn = Series::check_get(column)?;
+            let validity = nullable.ensure_validity().clone();
+            let inner = Self::row_comparator(nullable.inner())?;
+
+            return Ok(Box::new(move |a, b| {
+                match (validity.get_bit(a), validity.get_bit(b)) {
+                    (true, true) => ordered(inner(a, b)),
+                    (false, false) => Ordering::Equal,
+                    (false, true) if nulls_first => Ordering::Less,
+                    (false, true) => Ordering::Greater,
+                    (true, false) if nulls_first => Ordering::Greater,
+                    (true, false) => Ordering::Less,
+                }
+            }));
+        }
+
+        let inner = Self::row_comparator(column)?;
+        Ok(Box::new(move |a, b| ordered(inner(a, b))))
+    }
+
+    // Dispatches on the physical type, the date and time types compare as their integers.
+    fn row_comparator(column: &ColumnRef) -> Result<RowComparator> {
+        if column.is_dictionary() || column.is_const() {
+            return Self::row_comparator(&column.convert_full_column());
+        }
+
+        macro_rules! ord_comparator {
+            ($T:ty) => {{
+                let col = Series::check_get_scalar::<$T>(column)?.clone();
+                Ok(Box::new(move |a, b| col.get_data(a).cmp(&col.get_data(b))))
+            }};
+        }
+
+        macro_rules! float_comparator {
+            ($T:ty) => {{
+                let col = Series::check_get_scalar::<$T>(column)?.clone();
+                Ok(Box::new(move |a, b| {
+                    let (a, b) = (col.values()[a].canonical(), col.values()[b].canonical());
+                    // NaN sorts after +Infinity.
+                    a.partial_cmp(&b)
+                        .unwrap_or_else(|| a.is_nan().cmp(&b.is_nan()))
+                }))
+            }};
+        }
+
+        match column.data_type_id().to_physical_type() {
+            PhysicalTypeID::Boolean => ord_comparator!(bool),
+            PhysicalTypeID::String => ord_comparator!(Vu8),
+            PhysicalTypeID::Int8 => ord_comparator!(i8),
+            PhysicalTypeID::Int16 => ord_comparator!(i16),
+            PhysicalTypeID::Int32 => ord_comparator!(i32),
+            PhysicalTypeID::Int64 => ord_comparator!(i64),
+            PhysicalTypeID::UInt8 => ord_comparator!(u8),
+            PhysicalTypeID::UInt16 => ord_comparator!(u16),
+            PhysicalTypeID::UInt32 => ord_comparator!(u32),
+            PhysicalTypeID::UInt64 => ord_comparator!(u64),
+            PhysicalTypeID::Float32 => float_comparator!(f32),
+            PhysicalTypeID::Float64 => float_comparator!(f64),
+            PhysicalTypeID::Null => Ok(Box::new(|_, _| Ordering::Equal)),
+            // Array, struct and variant columns compare by their values.
+            _ => {
+                let column = column.clone();
+                Ok(Box::new(move |a, b| {
+                    compare_values(&column.get(a), &column.get(b))
+                }))
+            }
+        }
+    }
+}
+
+// Nulls first, then the values of the same kind in their natural order,
+// arrays and structs lexicographically.
+fn compare_values(lhs: &DataValue, rhs: &DataValue) -> Ordering {
+    match (lhs, rhs) {
+        (DataValue::Null, DataValue::Null) => Ordering::Equal,
+        (DataValue::Null, _) => Ordering::Less,
+        (_, DataValue::Null) => Ordering::Greater,
+        (DataValue::Boolean(l), DataValue::Boolean(r)) => l.cmp(r),
+        (DataValue::Int64(l), DataValue::Int64(r)) => l.cmp(r),
+        (DataValue::UInt64(l), DataValue::UInt64(r)) => l.cmp(r),
+        (DataValue::Float64(l), DataValue::Float64(r)) => {
+            let (l, r) = (l.canonical(), r.canonical());
+            l.partial_cmp(&r)
+                .unwrap_or_else(|| l.is_nan().cmp(&r.is_nan()))
+        }
+        (DataValue::String(l), DataValue::String(r)) => l.cmp(r),
+        (DataValue::Array(l), DataValue::Array(r))
+        | (DataValue::Struct(l), DataValue::Struct(r)) => l
+            .iter()
+            .zip(r.iter())
+            .map(|(l, r)| compare_values(l, r))
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or_else(|| l.len().cmp(&r.len())),
+        (DataValue::Json(l), DataValue::Json(r)) => l.to_string().cmp(&r.to_string()),
+        // Values of different kinds only meet in the variant columns.
+        (l, r) => format!("{:?}", l).cmp(&format!("{:?}", r)),
+    }
+}
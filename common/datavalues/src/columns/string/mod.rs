@@ -22,7 +22,6 @@ use common_arrow::arrow::array::*;
 use common_arrow::arrow::bitmap::utils::BitChunkIterExact;
 use common_arrow::arrow::bitmap::utils::BitChunksExact;
 use common_arrow::arrow::buffer::Buffer;
-use common_arrow::arrow::compute::cast::binary_to_large_binary;
 use common_arrow::arrow::datatypes::DataType as ArrowType;
 use common_arrow::arrow::types::Index;
 pub use iterator::*;
@@ -54,33 +53,41 @@ impl StringColumn {
         }
     }
 
+    /// Accepts the arrow binary and utf8 arrays with either offset width, the values are shared
+    /// with the array and the i32 offsets are widened, so the column can grow past i32::MAX bytes.
     pub fn from_arrow_array(array: &dyn Array) -> Self {
-        let arrow_type = array.data_type();
-        if arrow_type == &ArrowType::Binary {
-            let arr = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
-            let arr = binary_to_large_binary(arr, ArrowType::LargeBinary);
-            return Self::new(arr);
-        }
-
-        if arrow_type == &ArrowType::Utf8 {
-            let arr = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
-            let iter = arr.values_iter();
-            return Self::new_from_iter(iter);
+        match array.data_type() {
+            ArrowType::Binary => {
+                let arr = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+                Self::from_small_offsets(arr.offsets(), arr.values())
+            }
+            ArrowType::Utf8 => {
+                let arr = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+                Self::from_small_offsets(arr.offsets(), arr.values())
+            }
+            ArrowType::LargeUtf8 => {
+                let arr = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+                Self {
+                    offsets: arr.offsets().clone(),
+                    values: arr.values().clone(),
+                }
+            }
+            _ => Self::new(
+                array
+                    .as_any()
+                    .downcast_ref::<LargeBinaryArray>()
+                    .unwrap()
+                    .clone(),
+            ),
         }
+    }
 
-        if arrow_type == &ArrowType::LargeUtf8 {
-            let arr = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
-            let iter = arr.values_iter();
-            return Self::new_from_iter(iter);
+    fn from_small_offsets(offsets: &Buffer<i32>, values: &Buffer<u8>) -> Self {
+        let offsets: Vec<i64> = offsets.iter().map(|offset| *offset as i64).collect();
+        Self {
+            offsets: offsets.into(),
+            values: values.clone(),
         }
-
-        Self::new(
-            array
-                .as_any()
-                .downcast_ref::<LargeBinaryArray>()
-                .unwrap()
-                .clone(),
-        )
     }
 
     /// construct StringColumn from unchecked data
@@ -148,6 +155,11 @@ impl Column for StringColumn {
     }
 
     fn memory_size(&self) -> usize {
+        let values = self.offsets[self.offsets.len() - 1] - self.offsets[0];
+        values as usize + self.offsets.len() * std::mem::size_of::<i64>()
+    }
+
+    fn retained_memory_size(&self) -> usize {
         self.values.len() + self.offsets.len() * std::mem::size_of::<i64>()
     }
 
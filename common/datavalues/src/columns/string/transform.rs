@@ -57,4 +57,18 @@ impl StringColumn {
             ))
         }
     }
+
+    /// Lowercase every value, this is the sort key of case insensitive collations.
+    /// Values that are not valid UTF-8 are only folded in the ASCII range.
+    pub fn to_lowercase(&self) -> StringColumn {
+        let mut builder =
+            MutableStringColumn::with_values_capacity(self.values().len(), self.len());
+        for value in self.iter() {
+            match std::str::from_utf8(value) {
+                Ok(v) => builder.append_value(v.to_lowercase()),
+                Err(_) => builder.append_value(value.to_ascii_lowercase()),
+            }
+        }
+        builder.finish()
+    }
 }
@@ -79,6 +79,10 @@ impl Column for StructColumn {
         self.values.iter().map(|v| v.memory_size()).sum()
     }
 
+    fn retained_memory_size(&self) -> usize {
+        self.values.iter().map(|v| v.retained_memory_size()).sum()
+    }
+
     fn as_arrow_array(&self) -> ArrayRef {
         let arrow_type = self.data_type().arrow_type();
         let arrays = self.values.iter().map(|v| v.as_arrow_array()).collect();
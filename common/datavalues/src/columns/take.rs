@@ -22,9 +22,18 @@ use common_exception::Result;
 use crate::prelude::*;
 
 impl Series {
+    /// Gathers the rows at `indices` into a new column of the same type,
+    /// a run of consecutive indices is returned as a zero-copy slice.
     pub fn take<I: Index>(column: &ColumnRef, indices: &[I]) -> Result<ColumnRef> {
         if column.is_const() {
             Ok(column.slice(0, indices.len()))
+        } else if let Some(offset) = Self::consecutive_offset(column, indices) {
+            Ok(column.slice(offset, indices.len()))
+        } else if column.is_dictionary() {
+            let col: &DictionaryColumn = unsafe { Series::static_cast(column) };
+            let keys = col.keys();
+            let keys: Vec<u32> = indices.iter().map(|i| keys[i.to_usize()]).collect();
+            Ok(DictionaryColumn::new(keys.into(), col.values().clone()).arc())
         } else if column.is_nullable() {
             let nullable_c: &NullableColumn = unsafe { Series::static_cast(column) };
             let inner_result = Self::take(nullable_c.inner(), indices)?;
@@ -50,11 +59,59 @@ impl Series {
                 Ok(Arc::new(result))
             },
             {
-                Err(ErrorCode::BadDataValueType(format!(
-                    "Column with type: {:?} does not support take",
-                    type_id
-                )))
+                // Array, struct and variant columns
+                let values = indices
+                    .iter()
+                    .map(|index| column.get(index.to_usize()))
+                    .collect::<Vec<_>>();
+                column.data_type().create_column(&values)
             })
         }
     }
+
+    /// Splits the rows into `scattered_size` columns, the row `i` goes to the column `indices[i]`.
+    /// A column that gets all the rows is the input itself, without a copy.
+    pub fn scatter(
+        column: &ColumnRef,
+        indices: &[usize],
+        scattered_size: usize,
+    ) -> Result<Vec<ColumnRef>> {
+        if indices.len() != column.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Scatter needs an index per row, got {} indices for {} rows",
+                indices.len(),
+                column.len()
+            )));
+        }
+
+        if let Some(index) = indices.iter().find(|index| **index >= scattered_size) {
+            return Err(ErrorCode::BadArguments(format!(
+                "Scatter index {} is out of the {} scattered columns",
+                index, scattered_size
+            )));
+        }
+
+        match indices.first() {
+            Some(first) if indices.iter().all(|index| index == first) => {
+                let mut scattered = vec![column.slice(0, 0); scattered_size];
+                scattered[*first] = column.clone();
+                Ok(scattered)
+            }
+            _ => Ok(column.scatter(indices, scattered_size)),
+        }
+    }
+
+    // The offset of the first row if the indices are consecutive and in bounds.
+    fn consecutive_offset<I: Index>(column: &ColumnRef, indices: &[I]) -> Option<usize> {
+        let offset = indices.first()?.to_usize();
+        let consecutive = indices
+            .iter()
+            .enumerate()
+            .all(|(i, index)| index.to_usize() == offset + i);
+
+        match consecutive && offset + indices.len() <= column.len() {
+            true => Some(offset),
+            false => None,
+        }
+    }
 }
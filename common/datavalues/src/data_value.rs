@@ -302,7 +302,12 @@ impl DFTryFrom<&DataValue> for JsonValue {
             DataValue::Int64(v) => Ok((*v as i64).into()),
             DataValue::UInt64(v) => Ok((*v as u64).into()),
             DataValue::Float64(v) => Ok((*v as f64).into()),
-            DataValue::String(v) => Ok(String::from_utf8(v.to_vec()).unwrap().into()),
+            DataValue::String(v) => match String::from_utf8(v.to_vec()) {
+                Ok(s) => Ok(s.into()),
+                Err(_) => Err(ErrorCode::BadBytes(
+                    "Invalid UTF-8 sequence, cannot convert the value to a JSON string",
+                )),
+            },
             DataValue::Array(v) => Ok(json!(*v)),
             DataValue::Struct(v) => Ok(json!(*v)),
             DataValue::Json(v) => Ok(v.to_owned()),
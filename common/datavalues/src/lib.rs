@@ -43,6 +43,7 @@ pub use data_schema::*;
 pub use data_value::*;
 pub use data_value_operator::*;
 pub use prelude::*;
+pub use roaring;
 pub use scalars::*;
 pub use types::*;
 pub use utils::*;
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::iter::TrustedLen;
+use std::marker::PhantomData;
 
 use crate::prelude::*;
 
@@ -142,3 +143,52 @@ where T: Scalar<Viewer<'a> = Self> + ObjectType
 
 unsafe impl<'a, T> TrustedLen for ObjectViewer<'a, T> where T: Scalar<Viewer<'a> = Self> + ObjectType
 {}
+
+/// Iterator of a viewer that yields None for the null rows.
+#[derive(Clone)]
+pub struct OptionViewerIter<'a, V: ScalarViewer<'a>> {
+    viewer: V,
+    pos: usize,
+    _phantom: PhantomData<&'a ()>,
+}
+
+impl<'a, V: ScalarViewer<'a>> OptionViewerIter<'a, V> {
+    pub fn new(viewer: V) -> Self {
+        Self {
+            viewer,
+            pos: 0,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, V: ScalarViewer<'a>> Iterator for OptionViewerIter<'a, V> {
+    type Item = Option<<V::ScalarItem as Scalar>::RefType<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.viewer.size() {
+            return None;
+        }
+
+        let old = self.pos;
+        self.pos += 1;
+
+        match self.viewer.valid_at(old) {
+            true => Some(Some(self.viewer.value_at(old))),
+            false => Some(None),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.viewer.size() - self.pos;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, V: ScalarViewer<'a>> ExactSizeIterator for OptionViewerIter<'a, V> {
+    fn len(&self) -> usize {
+        self.viewer.size() - self.pos
+    }
+}
+
+unsafe impl<'a, V: ScalarViewer<'a>> TrustedLen for OptionViewerIter<'a, V> {}
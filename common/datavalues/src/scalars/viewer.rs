@@ -44,6 +44,11 @@ pub trait ScalarViewer<'a>: Clone + Sized {
     }
 
     fn iter(&self) -> Self::Iterator;
+
+    /// Iterates the values as Option, None for the null rows.
+    fn iter_opt(&self) -> OptionViewerIter<'a, Self> {
+        OptionViewerIter::new(self.clone())
+    }
 }
 
 #[derive(Clone)]
@@ -281,7 +286,7 @@ where T: Scalar<Viewer<'a> = Self> + ObjectType
 fn try_extract_inner(column: &ColumnRef) -> Result<(&ColumnRef, Bitmap)> {
     let (column, validity) = if column.is_const() {
         let mut bitmap = MutableBitmap::with_capacity(1);
-        bitmap.push(true);
+        bitmap.push(!column.null_at(0));
 
         let c: &ConstColumn = unsafe { Series::static_cast(column) };
         (c.inner(), bitmap.into())
@@ -140,6 +140,15 @@ pub fn from_arrow_type(dt: &ArrowType) -> DataTypePtr {
             "Variant" => Arc::new(VariantType::default()),
             "VariantArray" => Arc::new(VariantArrayType::default()),
             "VariantObject" => Arc::new(VariantObjectType::default()),
+            // The storage of a map that isn't a list of the key and value entries
+            // is read as the type it's stored as, instead of failing the whole schema.
+            "Map" => match dt.to_logical_type() {
+                ArrowType::LargeList(entries) => match MapType::try_from_arrow_entries(entries) {
+                    Ok(map_type) => Arc::new(map_type),
+                    Err(_) => from_arrow_type(dt.to_logical_type()),
+                },
+                storage => from_arrow_type(storage),
+            },
             _ => unimplemented!("data_type: {:?}", dt),
         },
 
@@ -167,6 +176,16 @@ pub fn from_arrow_field(f: &ArrowField) -> DataTypePtr {
                 None => return DateTime64Type::arc(3, None),
             },
             "Interval" => return IntervalType::arc(metadata.unwrap().into()),
+            "Decimal" => {
+                let meta = metadata.unwrap();
+                let (precision, scale) = meta.split_once(',').unwrap();
+                return DecimalType::arc(precision.parse().unwrap(), scale.parse().unwrap());
+            }
+            "Binary" => return BinaryType::arc(),
+            "UUID" => return UuidType::arc(),
+            "IPv4" => return IPv4Type::arc(),
+            "IPv6" => return IPv6Type::arc(),
+            "Bitmap" => return BitmapType::arc(),
             "Variant" => return VariantType::arc(),
             "VariantArray" => return VariantArrayType::arc(),
             "VariantObject" => return VariantObjectType::arc(),
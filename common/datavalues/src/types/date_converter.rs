@@ -17,8 +17,16 @@ use chrono::DateTime;
 use chrono::Duration;
 use chrono::TimeZone;
 use chrono_tz::Tz;
+use common_exception::ErrorCode;
+use common_exception::Result;
 use num::cast::AsPrimitive;
 
+/// The timezone of the IANA name, e.g. `Asia/Shanghai`.
+pub fn parse_timezone(tz: &str) -> Result<Tz> {
+    tz.parse::<Tz>()
+        .map_err(|_| ErrorCode::BadArguments(format!("Unknown timezone: {:?}", tz)))
+}
+
 pub trait DateConverter {
     fn to_date(&self, tz: &Tz) -> Date<Tz>;
     fn to_date_time(&self, tz: &Tz) -> DateTime<Tz>;
@@ -0,0 +1,73 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+
+use crate::prelude::*;
+
+pub struct DecimalDeserializer {
+    pub builder: MutablePrimitiveColumn<i64>,
+    pub data_type: DecimalType,
+}
+
+impl TypeDeserializer for DecimalDeserializer {
+    fn de_binary(&mut self, reader: &mut &[u8]) -> Result<()> {
+        let value: i64 = reader.read_scalar()?;
+        self.builder.append_value(value);
+        Ok(())
+    }
+
+    fn de_default(&mut self) {
+        self.builder.append_value(0);
+    }
+
+    fn de_fixed_binary_batch(&mut self, reader: &[u8], step: usize, rows: usize) -> Result<()> {
+        for row in 0..rows {
+            let mut reader = &reader[step * row..];
+            let value: i64 = reader.read_scalar()?;
+            self.builder.append_value(value);
+        }
+        Ok(())
+    }
+
+    fn de_json(&mut self, value: &serde_json::Value) -> Result<()> {
+        match value {
+            serde_json::Value::Number(v) => self.de_text(v.to_string().as_bytes()),
+            serde_json::Value::String(v) => self.de_text(v.as_bytes()),
+            _ => Err(ErrorCode::BadBytes(
+                "Incorrect json value, must be number or string",
+            )),
+        }
+    }
+
+    fn de_text(&mut self, reader: &[u8]) -> Result<()> {
+        match self.data_type.parse(reader) {
+            Some(value) => {
+                self.builder.append_value(value);
+                Ok(())
+            }
+            None => Err(ErrorCode::BadBytes(format!(
+                "Cannot parse value {:?} to {:?}",
+                String::from_utf8_lossy(reader),
+                self.data_type
+            ))),
+        }
+    }
+
+    fn finish_to_column(&mut self) -> ColumnRef {
+        self.builder.to_column()
+    }
+}
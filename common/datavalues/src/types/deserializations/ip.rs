@@ -0,0 +1,104 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+
+use crate::prelude::*;
+
+pub struct IPv4Deserializer {
+    pub builder: MutablePrimitiveColumn<u32>,
+}
+
+impl TypeDeserializer for IPv4Deserializer {
+    fn de_binary(&mut self, reader: &mut &[u8]) -> Result<()> {
+        let value: u32 = reader.read_scalar()?;
+        self.builder.append_value(value);
+        Ok(())
+    }
+
+    fn de_default(&mut self) {
+        self.builder.append_value(0);
+    }
+
+    fn de_fixed_binary_batch(&mut self, reader: &[u8], step: usize, rows: usize) -> Result<()> {
+        for row in 0..rows {
+            let mut reader = &reader[step * row..];
+            let value: u32 = reader.read_scalar()?;
+            self.builder.append_value(value);
+        }
+        Ok(())
+    }
+
+    fn de_json(&mut self, value: &serde_json::Value) -> Result<()> {
+        match value {
+            serde_json::Value::String(s) => self.de_text(s.as_bytes()),
+            _ => Err(ErrorCode::BadBytes("Incorrect json value, must be string")),
+        }
+    }
+
+    fn de_text(&mut self, reader: &[u8]) -> Result<()> {
+        let value = IPv4Type::try_parse(reader)?;
+        self.builder.append_value(value);
+        Ok(())
+    }
+
+    fn finish_to_column(&mut self) -> ColumnRef {
+        self.builder.to_column()
+    }
+}
+
+pub struct IPv6Deserializer {
+    pub inner: StringDeserializer,
+}
+
+impl IPv6Deserializer {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: StringDeserializer::binary_with_capacity(capacity),
+        }
+    }
+}
+
+impl TypeDeserializer for IPv6Deserializer {
+    fn de_binary(&mut self, reader: &mut &[u8]) -> Result<()> {
+        self.inner.de_binary(reader)
+    }
+
+    fn de_default(&mut self) {
+        self.inner.builder.append_value(&[0u8; IPV6_SIZE]);
+    }
+
+    fn de_fixed_binary_batch(&mut self, reader: &[u8], step: usize, rows: usize) -> Result<()> {
+        self.inner.de_fixed_binary_batch(reader, step, rows)
+    }
+
+    fn de_json(&mut self, value: &serde_json::Value) -> Result<()> {
+        match value {
+            serde_json::Value::String(s) => self.de_text(s.as_bytes()),
+            _ => Err(ErrorCode::BadBytes("Incorrect json value, must be string")),
+        }
+    }
+
+    fn de_text(&mut self, reader: &[u8]) -> Result<()> {
+        let bytes = IPv6Type::try_parse(reader)?;
+        self.inner.builder.append_value(&bytes);
+        Ok(())
+    }
+
+    fn finish_to_column(&mut self) -> ColumnRef {
+        self.inner.finish_to_column()
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde_json::Value;
+
+use crate::prelude::*;
+
+/// Maps are read from JSON objects, the text of a map is a JSON object as well.
+pub struct MapDeserializer {
+    pub key: Box<dyn TypeDeserializer>,
+    pub value: Box<dyn TypeDeserializer>,
+    pub offsets: Vec<i64>,
+    pub data_type: DataTypePtr,
+    pub entries_type: DataTypePtr,
+}
+
+impl MapDeserializer {
+    fn push_offset(&mut self, entries: usize) {
+        let last = *self.offsets.last().unwrap();
+        self.offsets.push(last + entries as i64);
+    }
+}
+
+impl TypeDeserializer for MapDeserializer {
+    fn de_binary(&mut self, _reader: &mut &[u8]) -> Result<()> {
+        Err(ErrorCode::UnImplement(
+            "Map can't be deserialized from the binary format",
+        ))
+    }
+
+    fn de_default(&mut self) {
+        self.push_offset(0);
+    }
+
+    fn de_fixed_binary_batch(&mut self, _reader: &[u8], _step: usize, _rows: usize) -> Result<()> {
+        Err(ErrorCode::UnImplement(
+            "Map can't be deserialized from the binary format",
+        ))
+    }
+
+    fn de_text(&mut self, reader: &[u8]) -> Result<()> {
+        let value: Value = serde_json::from_slice(reader)?;
+        self.de_json(&value)
+    }
+
+    fn de_json(&mut self, value: &Value) -> Result<()> {
+        match value {
+            Value::Null => {
+                self.de_default();
+                Ok(())
+            }
+            Value::Object(object) => {
+                for (key, value) in object {
+                    self.key.de_text(key.as_bytes())?;
+                    self.value.de_json(value)?;
+                }
+                self.push_offset(object.len());
+                Ok(())
+            }
+            other => Err(ErrorCode::BadBytes(format!(
+                "Incorrect json value {}, a map must be an object",
+                other
+            ))),
+        }
+    }
+
+    fn finish_to_column(&mut self) -> ColumnRef {
+        let entries = StructColumn::from_data(
+            vec![self.key.finish_to_column(), self.value.finish_to_column()],
+            self.entries_type.clone(),
+        );
+        let offsets = std::mem::replace(&mut self.offsets, vec![0]);
+        Arc::new(ArrayColumn::from_data(
+            self.data_type.clone(),
+            offsets.into(),
+            Arc::new(entries),
+        ))
+    }
+}
@@ -23,6 +23,8 @@ use crate::prelude::*;
 pub struct StringDeserializer {
     pub buffer: Vec<u8>,
     pub builder: MutableStringColumn,
+    /// String values must be valid UTF-8, Binary values are arbitrary bytes.
+    pub check_utf8: bool,
 }
 
 impl StringDeserializer {
@@ -30,8 +32,28 @@ impl StringDeserializer {
         Self {
             buffer: Vec::new(),
             builder: MutableStringColumn::with_capacity(capacity),
+            check_utf8: true,
         }
     }
+
+    pub fn binary_with_capacity(capacity: usize) -> Self {
+        Self {
+            buffer: Vec::new(),
+            builder: MutableStringColumn::with_capacity(capacity),
+            check_utf8: false,
+        }
+    }
+
+    // Only text input comes from outside, binary input is produced by ourselves.
+    #[inline]
+    fn check_value(&self, value: &[u8]) -> Result<()> {
+        if self.check_utf8 && std::str::from_utf8(value).is_err() {
+            return Err(ErrorCode::BadBytes(
+                "Invalid UTF-8 sequence in String value, use Binary type to store arbitrary bytes",
+            ));
+        }
+        Ok(())
+    }
 }
 
 impl TypeDeserializer for StringDeserializer {
@@ -74,6 +96,7 @@ impl TypeDeserializer for StringDeserializer {
     }
 
     fn de_text(&mut self, reader: &[u8]) -> Result<()> {
+        self.check_value(reader)?;
         self.builder.append_value(reader);
         Ok(())
     }
@@ -15,19 +15,26 @@
 pub mod arithmetics_type;
 pub mod data_type;
 pub mod type_array;
+pub mod type_binary;
+pub mod type_bitmap;
 pub mod type_boolean;
 pub mod type_coercion;
 pub mod type_date16;
 pub mod type_date32;
 pub mod type_datetime32;
 pub mod type_datetime64;
+pub mod type_decimal;
 pub mod type_interval;
+pub mod type_ipv4;
+pub mod type_ipv6;
+pub mod type_map;
 pub mod type_null;
 pub mod type_nullable;
 pub mod type_primitive;
 pub mod type_string;
 pub mod type_struct;
 pub mod type_traits;
+pub mod type_uuid;
 pub mod type_variant;
 pub mod type_variant_array;
 pub mod type_variant_object;
@@ -43,25 +50,31 @@ mod type_factory;
 pub use arithmetics_type::*;
 pub use data_type::*;
 pub use date_converter::*;
-pub use date_converter::*;
 pub use deserializations::*;
 pub use eq::*;
 pub use serializations::*;
 pub use type_array::*;
+pub use type_binary::*;
+pub use type_bitmap::*;
 pub use type_boolean::*;
 pub use type_date16::*;
 pub use type_date32::*;
 pub use type_datetime32::*;
 pub use type_datetime64::*;
+pub use type_decimal::*;
 pub use type_factory::*;
 pub use type_id::*;
 pub use type_interval::*;
+pub use type_ipv4::*;
+pub use type_ipv6::*;
+pub use type_map::*;
 pub use type_null::*;
 pub use type_nullable::*;
 pub use type_primitive::*;
 pub use type_string::*;
 pub use type_struct::*;
 pub use type_traits::*;
+pub use type_uuid::*;
 pub use type_variant::*;
 pub use type_variant_array::*;
 pub use type_variant_object::*;
@@ -61,8 +61,15 @@ impl TypeSerializer for ArraySerializer {
         Ok(result)
     }
 
-    fn serialize_json(&self, _column: &ColumnRef) -> Result<Vec<Value>> {
-        todo!()
+    fn serialize_json(&self, column: &ColumnRef) -> Result<Vec<Value>> {
+        let column: &ArrayColumn = Series::check_get(column)?;
+        let values = self.inner.serialize_json(column.values())?;
+        let result = column
+            .offsets()
+            .windows(2)
+            .map(|w| Value::Array(values[w[0] as usize..w[1] as usize].to_vec()))
+            .collect();
+        Ok(result)
     }
 
     fn serialize_clickhouse_format(
@@ -0,0 +1,52 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use opensrv_clickhouse::types::column::ArcColumnWrapper;
+use opensrv_clickhouse::types::column::ColumnFrom;
+use serde_json::Value;
+
+use crate::prelude::*;
+
+pub struct BitmapSerializer {}
+
+impl TypeSerializer for BitmapSerializer {
+    fn serialize_value(&self, value: &DataValue) -> Result<String> {
+        Ok(BitmapType::format(value.as_string()?.as_slice()))
+    }
+
+    fn serialize_column(&self, column: &ColumnRef) -> Result<Vec<String>> {
+        let column: &StringColumn = Series::check_get(column)?;
+        let result: Vec<String> = column.iter().map(BitmapType::format).collect();
+        Ok(result)
+    }
+
+    fn serialize_json(&self, column: &ColumnRef) -> Result<Vec<Value>> {
+        let result: Vec<Value> = self
+            .serialize_column(column)?
+            .into_iter()
+            .map(Value::String)
+            .collect();
+        Ok(result)
+    }
+
+    fn serialize_clickhouse_format(
+        &self,
+        column: &ColumnRef,
+    ) -> Result<opensrv_clickhouse::types::column::ArcColumnData> {
+        let strings = self.serialize_column(column)?;
+        let values: Vec<&[u8]> = strings.iter().map(|v| v.as_bytes()).collect();
+        Ok(Vec::column_from::<ArcColumnWrapper>(values))
+    }
+}
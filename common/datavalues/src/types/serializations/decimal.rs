@@ -0,0 +1,62 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use opensrv_clickhouse::types::column::ArcColumnWrapper;
+use opensrv_clickhouse::types::column::ColumnFrom;
+use serde_json::Value;
+
+use crate::prelude::*;
+
+/// Decimals are written with all the digits of their scale, and as strings in JSON
+/// to keep their exact value.
+pub struct DecimalSerializer {
+    data_type: DecimalType,
+}
+
+impl DecimalSerializer {
+    pub fn create(data_type: DecimalType) -> Self {
+        Self { data_type }
+    }
+}
+
+impl TypeSerializer for DecimalSerializer {
+    fn serialize_value(&self, value: &DataValue) -> Result<String> {
+        Ok(self.data_type.format(value.as_i64()?))
+    }
+
+    fn serialize_column(&self, column: &ColumnRef) -> Result<Vec<String>> {
+        let column: &PrimitiveColumn<i64> = Series::check_get(column)?;
+        let result: Vec<String> = column.iter().map(|v| self.data_type.format(*v)).collect();
+        Ok(result)
+    }
+
+    fn serialize_json(&self, column: &ColumnRef) -> Result<Vec<Value>> {
+        let result: Vec<Value> = self
+            .serialize_column(column)?
+            .into_iter()
+            .map(Value::String)
+            .collect();
+        Ok(result)
+    }
+
+    fn serialize_clickhouse_format(
+        &self,
+        column: &ColumnRef,
+    ) -> Result<opensrv_clickhouse::types::column::ArcColumnData> {
+        let strings = self.serialize_column(column)?;
+        let values: Vec<&[u8]> = strings.iter().map(|v| v.as_bytes()).collect();
+        Ok(Vec::column_from::<ArcColumnWrapper>(values))
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use opensrv_clickhouse::types::column::ArcColumnData;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::prelude::*;
+
+/// Maps are written as `{key: value, ...}`, and as objects in JSON.
+pub struct MapSerializer {
+    pub key: Box<dyn TypeSerializer>,
+    pub value: Box<dyn TypeSerializer>,
+    pub key_type: DataTypePtr,
+    pub value_type: DataTypePtr,
+}
+
+impl MapSerializer {
+    fn serialize_item(
+        serializer: &dyn TypeSerializer,
+        typ: &DataTypePtr,
+        value: &DataValue,
+    ) -> Result<String> {
+        let s = serializer.serialize_value(value)?;
+        match typ.data_type_id().is_quoted() && !value.is_null() {
+            true => Ok(format!("'{}'", s)),
+            false => Ok(s),
+        }
+    }
+}
+
+impl TypeSerializer for MapSerializer {
+    fn serialize_value(&self, value: &DataValue) -> Result<String> {
+        if let DataValue::Array(entries) = value {
+            let mut res = String::new();
+            res.push('{');
+            let mut first = true;
+            for entry in entries {
+                let (key, value) = match entry {
+                    DataValue::Struct(kv) if kv.len() == 2 => (&kv[0], &kv[1]),
+                    _ => return Err(ErrorCode::BadBytes("Incorrect Map entry")),
+                };
+                if !first {
+                    res.push_str(", ");
+                }
+                first = false;
+
+                res.push_str(&Self::serialize_item(
+                    self.key.as_ref(),
+                    &self.key_type,
+                    key,
+                )?);
+                res.push(':');
+                res.push_str(&Self::serialize_item(
+                    self.value.as_ref(),
+                    &self.value_type,
+                    value,
+                )?);
+            }
+            res.push('}');
+            Ok(res)
+        } else {
+            Err(ErrorCode::BadBytes("Incorrect Map value"))
+        }
+    }
+
+    fn serialize_column(&self, column: &ColumnRef) -> Result<Vec<String>> {
+        let column: &ArrayColumn = Series::check_get(column)?;
+        let mut result = Vec::with_capacity(column.len());
+        for i in 0..column.len() {
+            let val = column.get(i);
+            let s = self.serialize_value(&val)?;
+            result.push(s);
+        }
+        Ok(result)
+    }
+
+    fn serialize_json(&self, column: &ColumnRef) -> Result<Vec<Value>> {
+        let column: &ArrayColumn = Series::check_get(column)?;
+        let entries: &StructColumn = Series::check_get(column.values())?;
+        let keys = self.key.serialize_column(&entries.values()[0])?;
+        let values = self.value.serialize_json(&entries.values()[1])?;
+
+        let offsets = column.offsets();
+        let result = offsets
+            .windows(2)
+            .map(|w| {
+                let (start, end) = (w[0] as usize, w[1] as usize);
+                let object = keys[start..end]
+                    .iter()
+                    .cloned()
+                    .zip(values[start..end].iter().cloned())
+                    .collect::<Map<String, Value>>();
+                Value::Object(object)
+            })
+            .collect();
+        Ok(result)
+    }
+
+    fn serialize_clickhouse_format(&self, _column: &ColumnRef) -> Result<ArcColumnData> {
+        Err(ErrorCode::BadDataValueType(
+            "Map is not supported by the ClickHouse format",
+        ))
+    }
+}
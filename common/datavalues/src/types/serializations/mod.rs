@@ -20,25 +20,35 @@ use serde_json::Value;
 
 use crate::prelude::*;
 mod array;
+mod bitmap;
 mod boolean;
 mod date;
 mod date_time;
+mod decimal;
+mod ip;
+mod map;
 mod null;
 mod nullable;
 mod number;
 mod string;
 mod struct_;
+mod uuid;
 mod variant;
 
 pub use array::*;
+pub use bitmap::*;
 pub use boolean::*;
 pub use date::*;
 pub use date_time::*;
+pub use decimal::*;
+pub use ip::*;
+pub use map::*;
 pub use null::*;
 pub use nullable::*;
 pub use number::*;
 pub use string::*;
 pub use struct_::*;
+pub use uuid::*;
 pub use variant::*;
 
 pub trait TypeSerializer: Send + Sync {
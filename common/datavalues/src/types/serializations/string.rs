@@ -33,7 +33,8 @@ impl TypeSerializer for StringSerializer {
     }
 
     fn serialize_column(&self, column: &ColumnRef) -> Result<Vec<String>> {
-        let column: &StringColumn = Series::check_get(column)?;
+        let column = Series::remove_dictionary(column);
+        let column: &StringColumn = Series::check_get(&column)?;
         let result: Vec<String> = column
             .iter()
             .map(|v| String::from_utf8_lossy(v).to_string())
@@ -42,7 +43,8 @@ impl TypeSerializer for StringSerializer {
     }
 
     fn serialize_json(&self, column: &ColumnRef) -> Result<Vec<Value>> {
-        let column: &StringColumn = Series::check_get(column)?;
+        let column = Series::remove_dictionary(column);
+        let column: &StringColumn = Series::check_get(&column)?;
         let result: Vec<Value> = column
             .iter()
             .map(|x| serde_json::to_value(String::from_utf8_lossy(x).to_string()).unwrap())
@@ -54,7 +56,8 @@ impl TypeSerializer for StringSerializer {
         &self,
         column: &ColumnRef,
     ) -> Result<opensrv_clickhouse::types::column::ArcColumnData> {
-        let column: &StringColumn = Series::check_get(column)?;
+        let column = Series::remove_dictionary(column);
+        let column: &StringColumn = Series::check_get(&column)?;
         let values: Vec<&[u8]> = column.iter().collect();
         Ok(Vec::column_from::<ArcColumnWrapper>(values))
     }
@@ -64,7 +67,8 @@ impl TypeSerializer for StringSerializer {
         column: &ColumnRef,
         valids: Option<&Bitmap>,
     ) -> Result<Vec<Value>> {
-        let column: &StringColumn = Series::check_get(column)?;
+        let column = Series::remove_dictionary(column);
+        let column: &StringColumn = Series::check_get(&column)?;
         let mut result: Vec<Value> = Vec::new();
         for (i, v) in column.iter().enumerate() {
             if let Some(valids) = valids {
@@ -98,7 +102,8 @@ impl TypeSerializer for StringSerializer {
         &self,
         column: &ColumnRef,
     ) -> Result<Vec<Option<Value>>> {
-        let column: &StringColumn = Series::check_get(column)?;
+        let column = Series::remove_dictionary(column);
+        let column: &StringColumn = Series::check_get(&column)?;
         let result: Vec<Option<Value>> = column
             .iter()
             .map(|v| match std::str::from_utf8(v) {
@@ -38,6 +38,10 @@ impl ArrayType {
         }
     }
 
+    pub fn arc(inner: DataTypePtr) -> DataTypePtr {
+        Arc::new(Self::create(inner))
+    }
+
     pub fn inner_type(&self) -> &DataTypePtr {
         &self.inner
     }
@@ -110,7 +114,11 @@ impl DataType for ArrayType {
     }
 
     fn arrow_type(&self) -> ArrowType {
-        let field = Field::new("list".to_string(), self.inner.arrow_type(), false);
+        let field = Field::new(
+            "list".to_string(),
+            self.inner.arrow_type(),
+            self.inner.is_nullable(),
+        );
         ArrowType::LargeList(Box::new(field))
     }
 
@@ -125,8 +133,11 @@ impl DataType for ArrayType {
         todo!()
     }
 
-    fn create_mutable(&self, _capacity: usize) -> Box<dyn MutableColumn> {
-        todo!()
+    fn create_mutable(&self, capacity: usize) -> Box<dyn MutableColumn> {
+        Box::new(MutableArrayColumn::with_capacity(
+            Arc::new(self.clone()),
+            capacity,
+        ))
     }
 }
 
@@ -0,0 +1,102 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+
+use super::data_type::DataType;
+use super::data_type::ARROW_EXTENSION_NAME;
+use super::type_id::TypeID;
+use crate::prelude::*;
+
+/// BinaryType stores arbitrary bytes, it shares the physical layout of StringType
+/// but its values are never required to be valid UTF-8.
+#[derive(Default, Clone, serde::Deserialize, serde::Serialize)]
+pub struct BinaryType {}
+
+impl BinaryType {
+    pub fn arc() -> DataTypePtr {
+        Arc::new(Self {})
+    }
+}
+
+#[typetag::serde]
+impl DataType for BinaryType {
+    fn data_type_id(&self) -> TypeID {
+        TypeID::Binary
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "Binary"
+    }
+
+    fn aliases(&self) -> &[&str] {
+        &["VarBinary", "Blob"]
+    }
+
+    fn default_value(&self) -> DataValue {
+        DataValue::String(vec![])
+    }
+
+    fn create_constant_column(
+        &self,
+        data: &DataValue,
+        size: usize,
+    ) -> common_exception::Result<ColumnRef> {
+        let value = data.as_string()?;
+        let bytes = value.as_slice();
+
+        let column = Series::from_data(&[bytes]);
+        Ok(Arc::new(ConstColumn::new(column, size)))
+    }
+
+    fn arrow_type(&self) -> ArrowType {
+        ArrowType::LargeBinary
+    }
+
+    fn custom_arrow_meta(&self) -> Option<BTreeMap<String, String>> {
+        let mut mp = BTreeMap::new();
+        mp.insert(ARROW_EXTENSION_NAME.to_string(), "Binary".to_string());
+        Some(mp)
+    }
+
+    fn create_serializer(&self) -> Box<dyn TypeSerializer> {
+        Box::new(StringSerializer {})
+    }
+
+    fn create_deserializer(&self, capacity: usize) -> Box<dyn TypeDeserializer> {
+        Box::new(StringDeserializer::binary_with_capacity(capacity))
+    }
+
+    fn create_mutable(&self, capacity: usize) -> Box<dyn MutableColumn> {
+        Box::new(MutableStringColumn::with_capacity(capacity))
+    }
+
+    fn create_column(&self, data: &[DataValue]) -> common_exception::Result<ColumnRef> {
+        StringType::arc().create_column(data)
+    }
+}
+
+impl std::fmt::Debug for BinaryType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
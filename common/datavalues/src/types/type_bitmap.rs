@@ -0,0 +1,142 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use roaring::RoaringTreemap;
+
+use super::data_type::DataType;
+use super::data_type::ARROW_EXTENSION_NAME;
+use super::type_id::TypeID;
+use crate::prelude::*;
+
+/// BitmapType stores sets of unsigned 64-bit integers as roaring bitmaps, it's physical type
+/// is String holding the serialized bitmap. It's written as the comma separated integers
+/// in ascending order, e.g. `1,2,3`.
+#[derive(Default, Clone, serde::Deserialize, serde::Serialize)]
+pub struct BitmapType {}
+
+impl BitmapType {
+    pub fn arc() -> DataTypePtr {
+        Arc::new(Self {})
+    }
+
+    /// The serialized bytes of a bitmap.
+    pub fn serialize(bitmap: &RoaringTreemap) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(bitmap.serialized_size());
+        // writing into a vector never fails
+        bitmap.serialize_into(&mut bytes).unwrap();
+        bytes
+    }
+
+    pub fn deserialize(bytes: &[u8]) -> Result<RoaringTreemap> {
+        RoaringTreemap::deserialize_from(bytes)
+            .map_err(|e| ErrorCode::BadBytes(format!("Invalid bitmap: {}", e)))
+    }
+
+    /// The comma separated integers of the serialized bytes of a bitmap.
+    pub fn format(bytes: &[u8]) -> String {
+        match Self::deserialize(bytes) {
+            Ok(bitmap) => bitmap
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+            Err(_) => String::from_utf8_lossy(bytes).to_string(),
+        }
+    }
+
+    /// Parse the comma separated integers of a bitmap, the empty text is the empty bitmap.
+    pub fn parse(text: &[u8]) -> Option<RoaringTreemap> {
+        let text = std::str::from_utf8(text).ok()?.trim();
+        let mut bitmap = RoaringTreemap::new();
+        if !text.is_empty() {
+            for v in text.split(',') {
+                bitmap.insert(v.trim().parse::<u64>().ok()?);
+            }
+        }
+        Some(bitmap)
+    }
+
+    pub fn try_parse(text: &[u8]) -> Result<RoaringTreemap> {
+        Self::parse(text).ok_or_else(|| {
+            ErrorCode::BadBytes(format!(
+                "Cannot parse value {:?} to Bitmap",
+                String::from_utf8_lossy(text)
+            ))
+        })
+    }
+}
+
+#[typetag::serde]
+impl DataType for BitmapType {
+    fn data_type_id(&self) -> TypeID {
+        TypeID::Bitmap
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "Bitmap"
+    }
+
+    fn default_value(&self) -> DataValue {
+        DataValue::String(Self::serialize(&RoaringTreemap::new()))
+    }
+
+    fn create_constant_column(&self, data: &DataValue, size: usize) -> Result<ColumnRef> {
+        let value = data.as_string()?;
+        let column = Series::from_data(&[value.as_slice()]);
+        Ok(Arc::new(ConstColumn::new(column, size)))
+    }
+
+    fn arrow_type(&self) -> ArrowType {
+        ArrowType::LargeBinary
+    }
+
+    fn custom_arrow_meta(&self) -> Option<BTreeMap<String, String>> {
+        let mut mp = BTreeMap::new();
+        mp.insert(ARROW_EXTENSION_NAME.to_string(), "Bitmap".to_string());
+        Some(mp)
+    }
+
+    fn create_serializer(&self) -> Box<dyn TypeSerializer> {
+        Box::new(BitmapSerializer {})
+    }
+
+    fn create_deserializer(&self, capacity: usize) -> Box<dyn TypeDeserializer> {
+        Box::new(BitmapDeserializer::with_capacity(capacity))
+    }
+
+    fn create_mutable(&self, capacity: usize) -> Box<dyn MutableColumn> {
+        Box::new(MutableStringColumn::with_capacity(capacity))
+    }
+
+    fn create_column(&self, data: &[DataValue]) -> Result<ColumnRef> {
+        StringType::arc().create_column(data)
+    }
+}
+
+impl std::fmt::Debug for BitmapType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
@@ -228,12 +228,56 @@ pub fn numerical_unary_arithmetic_coercion(
     }
 }
 
+/// The max count of decimal digits of an integer type.
+fn integer_digits(type_id: TypeID) -> usize {
+    match type_id {
+        Int8 | UInt8 => 3,
+        Int16 | UInt16 => 5,
+        Int32 | UInt32 => 10,
+        Int64 => 19,
+        _ => 20,
+    }
+}
+
+/// Coercion rule for decimals: decimals and integers are coerced to a decimal that holds the
+/// integer digits and the scale of both sides, the precision is capped at `MAX_DECIMAL_PRECISION`.
+/// Decimals and floats are coerced to Float64.
+pub fn decimal_coercion(lhs_type: &DataTypePtr, rhs_type: &DataTypePtr) -> Result<DataTypePtr> {
+    let lhs_id = lhs_type.data_type_id();
+    let rhs_id = rhs_type.data_type_id();
+
+    if lhs_id.is_floating() || rhs_id.is_floating() {
+        return Ok(Float64Type::arc());
+    }
+
+    let digits = |data_type: &DataTypePtr| match data_type.data_type_id() {
+        Decimal => {
+            let decimal: &DecimalType = data_type.as_any().downcast_ref().unwrap();
+            Ok((decimal.precision() - decimal.scale(), decimal.scale()))
+        }
+        type_id if type_id.is_integer() => Ok((integer_digits(type_id), 0)),
+        _ => Err(ErrorCode::IllegalDataType(format!(
+            "Can't construct decimal type from {:?} and {:?}",
+            lhs_type, rhs_type
+        ))),
+    };
+
+    let (lhs_integer, lhs_scale) = digits(lhs_type)?;
+    let (rhs_integer, rhs_scale) = digits(rhs_type)?;
+    let scale = cmp::max(lhs_scale, rhs_scale);
+    let precision = cmp::min(
+        cmp::max(lhs_integer, rhs_integer) + scale,
+        MAX_DECIMAL_PRECISION,
+    );
+    Ok(DecimalType::arc(precision, scale))
+}
+
 // coercion rules for compare operations. This is a superset of all numerical coercion rules.
 pub fn compare_coercion(lhs_type: &DataTypePtr, rhs_type: &DataTypePtr) -> Result<DataTypePtr> {
     let lhs_id = lhs_type.data_type_id();
     let rhs_id = rhs_type.data_type_id();
 
-    if lhs_type.eq(rhs_type) {
+    if lhs_type.eq(rhs_type) && timestamp_timezone(lhs_type) == timestamp_timezone(rhs_type) {
         // same type => equality is possible
         return Ok(lhs_type.clone());
     }
@@ -242,6 +286,12 @@ pub fn compare_coercion(lhs_type: &DataTypePtr, rhs_type: &DataTypePtr) -> Resul
         return numerical_coercion(lhs_type, rhs_type, true);
     }
 
+    if (lhs_id.is_decimal() && (rhs_id.is_numeric() || rhs_id.is_decimal()))
+        || (rhs_id.is_decimal() && lhs_id.is_numeric())
+    {
+        return decimal_coercion(lhs_type, rhs_type);
+    }
+
     //  one of is nothing
     {
         if lhs_id == TypeID::Null {
@@ -253,11 +303,45 @@ pub fn compare_coercion(lhs_type: &DataTypePtr, rhs_type: &DataTypePtr) -> Resul
         }
     }
 
+    // one of is String and other is UUID, parse the string as a UUID
+    if (lhs_id.is_string() && rhs_id.is_uuid()) || (lhs_id.is_uuid() && rhs_id.is_string()) {
+        return Ok(UuidType::arc());
+    }
+
+    // one of is String and other is an IP, parse the string as the IP
+    if lhs_id.is_string() && rhs_id.is_ip() {
+        return Ok(rhs_type.clone());
+    }
+    if lhs_id.is_ip() && rhs_id.is_string() {
+        return Ok(lhs_type.clone());
+    }
+
+    // one of is IPv4 and other is IPv6, compare them as IPv6
+    if lhs_id.is_ip() && rhs_id.is_ip() {
+        return Ok(IPv6Type::arc());
+    }
+
+    // one of is String and other is Binary, compare them as bytes
+    if (lhs_id.is_string() && rhs_id.is_binary()) || (lhs_id.is_binary() && rhs_id.is_string()) {
+        return Ok(BinaryType::arc());
+    }
+
     // one of is String and other is number
     if (lhs_id.is_numeric() && rhs_id.is_string()) || (rhs_id.is_numeric() && lhs_id.is_string()) {
         return Ok(Float64Type::arc());
     }
 
+    // one of is decimal and other is string
+    {
+        if lhs_id.is_string() && rhs_id.is_decimal() {
+            return Ok(rhs_type.clone());
+        }
+
+        if rhs_id.is_string() && lhs_id.is_decimal() {
+            return Ok(lhs_type.clone());
+        }
+    }
+
     // one of is datetime and other is number or string
     {
         if (lhs_id.is_numeric() || lhs_id.is_string()) && rhs_id.is_date_or_date_time() {
@@ -269,19 +353,39 @@ pub fn compare_coercion(lhs_type: &DataTypePtr, rhs_type: &DataTypePtr) -> Resul
         }
     }
 
+    // intervals are compared in the smaller unit, months can't be compared with days
+    if lhs_id.is_interval() && rhs_id.is_interval() {
+        let lhs: &IntervalType = lhs_type.as_any().downcast_ref().unwrap();
+        let rhs: &IntervalType = rhs_type.as_any().downcast_ref().unwrap();
+        if lhs.kind().is_month_based() == rhs.kind().is_month_based() {
+            return match lhs.kind().unit() < rhs.kind().unit() {
+                true => Ok(lhs_type.clone()),
+                false => Ok(rhs_type.clone()),
+            };
+        }
+    }
+
     if lhs_id.is_date_or_date_time() && rhs_id.is_date_or_date_time() {
+        let tz = common_timezone(lhs_type, rhs_type);
         return match (lhs_id, rhs_id) {
             (TypeID::Date16, _) => Ok(rhs_type.clone()),
             (_, TypeID::Date16) => Ok(lhs_type.clone()),
-            (TypeID::Date32, TypeID::DateTime32) => Ok(DateTime64Type::arc(0, None)),
-            (TypeID::DateTime32, TypeID::Date32) => Ok(DateTime64Type::arc(0, None)),
-            (TypeID::Date32 | TypeID::DateTime32, TypeID::DateTime64) => Ok(rhs_type.clone()),
-            (TypeID::DateTime64, TypeID::Date32 | TypeID::DateTime32) => Ok(lhs_type.clone()),
+            (TypeID::Date32, TypeID::DateTime32) => Ok(DateTime64Type::arc(0, tz)),
+            (TypeID::DateTime32, TypeID::Date32) => Ok(DateTime64Type::arc(0, tz)),
+            (TypeID::DateTime32, TypeID::DateTime32) => Ok(DateTime32Type::arc(tz)),
+            (TypeID::Date32 | TypeID::DateTime32, TypeID::DateTime64) => {
+                let rhs: &DateTime64Type = rhs_type.as_any().downcast_ref().unwrap();
+                Ok(DateTime64Type::arc(rhs.precision(), tz))
+            }
+            (TypeID::DateTime64, TypeID::Date32 | TypeID::DateTime32) => {
+                let lhs: &DateTime64Type = lhs_type.as_any().downcast_ref().unwrap();
+                Ok(DateTime64Type::arc(lhs.precision(), tz))
+            }
             (TypeID::DateTime64, TypeID::DateTime64) => {
                 let lhs: &DateTime64Type = lhs_type.as_any().downcast_ref().unwrap();
                 let rhs: &DateTime64Type = rhs_type.as_any().downcast_ref().unwrap();
                 let precision = cmp::max(lhs.precision(), rhs.precision());
-                Ok(DateTime64Type::arc(precision, None))
+                Ok(DateTime64Type::arc(precision, tz))
             }
             _ => unreachable!(),
         };
@@ -293,6 +397,30 @@ pub fn compare_coercion(lhs_type: &DataTypePtr, rhs_type: &DataTypePtr) -> Resul
     )))
 }
 
+// The timezone of a timestamp type, None if it's not a timestamp.
+fn timestamp_timezone(data_type: &DataTypePtr) -> Option<Option<String>> {
+    if let Some(t) = data_type.as_any().downcast_ref::<DateTime32Type>() {
+        return Some(t.tz().cloned());
+    }
+    if let Some(t) = data_type.as_any().downcast_ref::<DateTime64Type>() {
+        return Some(t.tz().cloned());
+    }
+    None
+}
+
+// The timestamps are instants since the epoch in UTC, the timezone only changes how they are
+// shown and how the dates are converted to them. So both sides of a comparison are converted
+// to one timezone: the timezone of the timestamps if they have the same one, UTC otherwise.
+// The dates are converted in the timezone of the timestamp they are compared with.
+fn common_timezone(lhs_type: &DataTypePtr, rhs_type: &DataTypePtr) -> Option<String> {
+    match (timestamp_timezone(lhs_type), timestamp_timezone(rhs_type)) {
+        (Some(lhs), Some(rhs)) if lhs == rhs => lhs,
+        (Some(_), Some(_)) => None,
+        (Some(tz), None) | (None, Some(tz)) => tz,
+        (None, None) => None,
+    }
+}
+
 // aggregate_types aggregates data types for a multi-argument function.
 #[inline]
 pub fn aggregate_types(args: &[DataTypePtr]) -> Result<DataTypePtr> {
@@ -329,6 +457,10 @@ pub fn merge_types(lhs_type: &DataTypePtr, rhs_type: &DataTypePtr) -> Result<Dat
             let typ = merge_types(a.inner_type(), b.inner_type())?;
             Ok(Arc::new(ArrayType::create(typ)))
         }
+        (String, Binary) | (Binary, String) => Ok(BinaryType::arc()),
+        (String, Uuid) | (Uuid, String) => Ok(UuidType::arc()),
+        (String, IPv4) | (IPv4, String) => Ok(IPv4Type::arc()),
+        (String, IPv6) | (IPv6, String) | (IPv4, IPv6) | (IPv6, IPv4) => Ok(IPv6Type::arc()),
         (Struct, Struct) => {
             let a = lhs_type.as_any().downcast_ref::<StructType>().unwrap();
             let b = rhs_type.as_any().downcast_ref::<StructType>().unwrap();
@@ -347,12 +479,24 @@ pub fn merge_types(lhs_type: &DataTypePtr, rhs_type: &DataTypePtr) -> Result<Dat
 
             Ok(Arc::new(StructType::create(a.names().clone(), types)))
         }
+        (Map, Map) => {
+            let a = lhs_type.as_any().downcast_ref::<MapType>().unwrap();
+            let b = rhs_type.as_any().downcast_ref::<MapType>().unwrap();
+
+            let key = merge_types(a.key_type(), b.key_type())?;
+            let value = merge_types(a.value_type(), b.value_type())?;
+            Ok(MapType::arc(key, value))
+        }
         _ => {
-            if lhs_id == rhs_id {
+            if lhs_id == rhs_id && lhs_id != Decimal {
                 return Ok(lhs_type.clone());
             }
             if lhs_id.is_numeric() && rhs_id.is_numeric() {
                 numerical_coercion(lhs_type, rhs_type, false)
+            } else if (lhs_id.is_decimal() || lhs_id.is_numeric())
+                && (rhs_id.is_decimal() || rhs_id.is_numeric())
+            {
+                decimal_coercion(lhs_type, rhs_type)
             } else {
                 Result::Err(ErrorCode::BadDataValueType(format!(
                     "Can't merge types from {:?} and {:?}",
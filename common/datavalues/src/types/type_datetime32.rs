@@ -38,9 +38,24 @@ impl DateTime32Type {
     pub fn arc(tz: Option<String>) -> DataTypePtr {
         Arc::new(DateTime32Type { tz })
     }
+    /// Create the type with a checked timezone, the name must be a known IANA timezone.
+    pub fn try_create(tz: Option<String>) -> Result<Self> {
+        if let Some(tz) = &tz {
+            parse_timezone(tz)?;
+        }
+        Ok(DateTime32Type { tz })
+    }
     pub fn tz(&self) -> Option<&String> {
         self.tz.as_ref()
     }
+
+    /// The timezone of the values, UTC if it's not specified.
+    pub fn timezone(&self) -> Result<Tz> {
+        match &self.tz {
+            Some(tz) => parse_timezone(tz),
+            None => Ok(Tz::UTC),
+        }
+    }
 }
 
 #[typetag::serde]
@@ -97,18 +112,15 @@ impl DataType for DateTime32Type {
     }
 
     fn create_serializer(&self) -> Box<dyn TypeSerializer> {
-        let tz = self.tz.clone().unwrap_or_else(|| "UTC".to_string());
-        Box::new(DateTimeSerializer::<u32>::create(
-            tz.parse::<Tz>().unwrap(),
-            0,
-        ))
+        // The timezone is checked when it's set, values are shown in UTC otherwise.
+        let tz = self.timezone().unwrap_or(Tz::UTC);
+        Box::new(DateTimeSerializer::<u32>::create(tz, 0))
     }
 
     fn create_deserializer(&self, capacity: usize) -> Box<dyn TypeDeserializer> {
-        let tz = self.tz.clone().unwrap_or_else(|| "UTC".to_string());
         Box::new(DateTimeDeserializer::<u32> {
             builder: MutablePrimitiveColumn::<u32>::with_capacity(capacity),
-            tz: tz.parse::<Tz>().unwrap(),
+            tz: self.timezone().unwrap_or(Tz::UTC),
         })
     }
 
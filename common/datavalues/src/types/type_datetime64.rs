@@ -49,6 +49,14 @@ impl DateTime64Type {
         Arc::new(DateTime64Type { precision, tz })
     }
 
+    /// Create the type with a checked timezone, the name must be a known IANA timezone.
+    pub fn try_create(precision: usize, tz: Option<String>) -> Result<Self> {
+        if let Some(tz) = &tz {
+            parse_timezone(tz)?;
+        }
+        Ok(DateTime64Type { precision, tz })
+    }
+
     pub fn tz(&self) -> Option<&String> {
         self.tz.as_ref()
     }
@@ -57,6 +65,14 @@ impl DateTime64Type {
         self.precision
     }
 
+    /// The timezone of the values, UTC if it's not specified.
+    pub fn timezone(&self) -> Result<Tz> {
+        match &self.tz {
+            Some(tz) => parse_timezone(tz),
+            None => Ok(Tz::UTC),
+        }
+    }
+
     #[inline]
     pub fn utc_timestamp(&self, v: i64) -> DateTime<Utc> {
         let v = v * 10_i64.pow(9 - self.precision as u32);
@@ -147,18 +163,15 @@ impl DataType for DateTime64Type {
     }
 
     fn create_serializer(&self) -> Box<dyn TypeSerializer> {
-        let tz = self.tz.clone().unwrap_or_else(|| "UTC".to_string());
-        Box::new(DateTimeSerializer::<i64>::create(
-            tz.parse::<Tz>().unwrap(),
-            self.precision as u32,
-        ))
+        // The timezone is checked when it's set, values are shown in UTC otherwise.
+        let tz = self.timezone().unwrap_or(Tz::UTC);
+        Box::new(DateTimeSerializer::<i64>::create(tz, self.precision as u32))
     }
 
     fn create_deserializer(&self, capacity: usize) -> Box<dyn TypeDeserializer> {
-        let tz = self.tz.clone().unwrap_or_else(|| "UTC".to_string());
         Box::new(DateTimeDeserializer::<i64> {
             builder: MutablePrimitiveColumn::<i64>::with_capacity(capacity),
-            tz: tz.parse::<Tz>().unwrap(),
+            tz: self.timezone().unwrap_or(Tz::UTC),
         })
     }
 
@@ -0,0 +1,250 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::data_type::DataType;
+use super::type_id::TypeID;
+use crate::prelude::*;
+
+/// The max precision of a decimal, the unscaled value is stored as Int64.
+pub const MAX_DECIMAL_PRECISION: usize = 18;
+
+/// An exact number with `precision` digits, `scale` of them after the decimal point,
+/// e.g. 123.45 is a Decimal(5, 2), it's physical type is Int64 holding 12345.
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct DecimalType {
+    precision: usize,
+    scale: usize,
+    /// The name with the precision and scale, e.g. `Decimal(5, 2)`
+    name: String,
+}
+
+impl DecimalType {
+    pub fn try_create(precision: usize, scale: usize) -> Result<Self> {
+        if precision == 0 || precision > MAX_DECIMAL_PRECISION {
+            return Err(ErrorCode::BadArguments(format!(
+                "Decimal precision must be between 1 and {}, but got {}",
+                MAX_DECIMAL_PRECISION, precision
+            )));
+        }
+
+        if scale > precision {
+            return Err(ErrorCode::BadArguments(format!(
+                "Decimal scale must be between 0 and the precision {}, but got {}",
+                precision, scale
+            )));
+        }
+
+        Ok(Self::create(precision, scale))
+    }
+
+    fn create(precision: usize, scale: usize) -> Self {
+        DecimalType {
+            precision,
+            scale,
+            name: format!("Decimal({}, {})", precision, scale),
+        }
+    }
+
+    /// The precision and scale must be valid, see `try_create`.
+    pub fn arc(precision: usize, scale: usize) -> DataTypePtr {
+        Arc::new(Self::create(precision, scale))
+    }
+
+    pub fn precision(&self) -> usize {
+        self.precision
+    }
+
+    pub fn scale(&self) -> usize {
+        self.scale
+    }
+
+    #[inline]
+    fn scale_factor(scale: usize) -> i128 {
+        10_i128.pow(scale as u32)
+    }
+
+    /// The unscaled value if it fits in the precision.
+    #[inline]
+    pub fn check_value(&self, v: i128) -> Option<i64> {
+        match v.abs() < Self::scale_factor(self.precision) {
+            true => Some(v as i64),
+            false => None,
+        }
+    }
+
+    /// Rescale an unscaled value of `from_scale` to this type, rounding half away from zero.
+    pub fn rescale(&self, v: i64, from_scale: usize) -> Option<i64> {
+        let v = v as i128;
+        let v = match self.scale >= from_scale {
+            true => v * Self::scale_factor(self.scale - from_scale),
+            false => {
+                let factor = Self::scale_factor(from_scale - self.scale);
+                let (quotient, remainder) = (v / factor, v % factor);
+                match remainder.abs() * 2 >= factor {
+                    true => quotient + v.signum(),
+                    false => quotient,
+                }
+            }
+        };
+        self.check_value(v)
+    }
+
+    pub fn from_f64(&self, v: f64) -> Option<i64> {
+        let v = (v * Self::scale_factor(self.scale) as f64).round();
+        match v.is_finite() && v.abs() < Self::scale_factor(self.precision) as f64 {
+            true => Some(v as i64),
+            false => None,
+        }
+    }
+
+    pub fn to_f64(&self, v: i64) -> f64 {
+        v as f64 / Self::scale_factor(self.scale) as f64
+    }
+
+    /// Parse a number like `-12.345`, the extra fraction digits are rounded.
+    pub fn parse(&self, s: &[u8]) -> Option<i64> {
+        let s = std::str::from_utf8(s).ok()?.trim();
+        let (negative, s) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+
+        let (integer, fraction) = match s.split_once('.') {
+            Some((integer, fraction)) => (integer, fraction),
+            None => (s, ""),
+        };
+        let all_digits = |s: &str| s.bytes().all(|c| c.is_ascii_digit());
+        if (integer.is_empty() && fraction.is_empty())
+            || !all_digits(integer)
+            || !all_digits(fraction)
+        {
+            return None;
+        }
+
+        let mut v: i128 = 0;
+        for c in integer.bytes().chain(fraction.bytes().take(self.scale)) {
+            v = v.checked_mul(10)?.checked_add((c - b'0') as i128)?;
+        }
+        for _ in fraction.len()..self.scale {
+            v = v.checked_mul(10)?;
+        }
+        if matches!(fraction.as_bytes().get(self.scale), Some(c) if *c >= b'5') {
+            v += 1;
+        }
+
+        self.check_value(if negative { -v } else { v })
+    }
+
+    pub fn format(&self, v: i64) -> String {
+        let factor = Self::scale_factor(self.scale);
+        let v = v as i128;
+        let sign = if v < 0 { "-" } else { "" };
+        match self.scale {
+            0 => v.to_string(),
+            scale => format!(
+                "{}{}.{:0width$}",
+                sign,
+                v.abs() / factor,
+                v.abs() % factor,
+                width = scale
+            ),
+        }
+    }
+}
+
+#[typetag::serde]
+impl DataType for DecimalType {
+    fn data_type_id(&self) -> TypeID {
+        TypeID::Decimal
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn aliases(&self) -> &[&str] {
+        // the default precision and scale of DECIMAL in SQL
+        match (self.precision, self.scale) {
+            (10, 0) => &["Decimal"],
+            _ => &[],
+        }
+    }
+
+    fn default_value(&self) -> DataValue {
+        DataValue::Int64(0)
+    }
+
+    fn create_constant_column(&self, data: &DataValue, size: usize) -> Result<ColumnRef> {
+        let value = data.as_i64()?;
+        let column = Series::from_data(&[value]);
+        Ok(Arc::new(ConstColumn::new(column, size)))
+    }
+
+    fn create_column(&self, data: &[DataValue]) -> Result<ColumnRef> {
+        let value = data
+            .iter()
+            .map(|v| v.as_i64())
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Series::from_data(&value))
+    }
+
+    fn arrow_type(&self) -> ArrowType {
+        ArrowType::Int64
+    }
+
+    fn custom_arrow_meta(&self) -> Option<BTreeMap<String, String>> {
+        let mut mp = BTreeMap::new();
+        mp.insert(ARROW_EXTENSION_NAME.to_string(), "Decimal".to_string());
+        mp.insert(
+            ARROW_EXTENSION_META.to_string(),
+            format!("{},{}", self.precision, self.scale),
+        );
+        Some(mp)
+    }
+
+    fn create_serializer(&self) -> Box<dyn TypeSerializer> {
+        Box::new(DecimalSerializer::create(self.clone()))
+    }
+
+    fn create_deserializer(&self, capacity: usize) -> Box<dyn TypeDeserializer> {
+        Box::new(DecimalDeserializer {
+            builder: MutablePrimitiveColumn::<i64>::with_capacity(capacity),
+            data_type: self.clone(),
+        })
+    }
+
+    fn create_mutable(&self, capacity: usize) -> Box<dyn MutableColumn> {
+        Box::new(MutablePrimitiveColumn::<i64>::with_capacity(capacity))
+    }
+}
+
+impl std::fmt::Debug for DecimalType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
@@ -31,6 +31,11 @@ static TYPE_FACTORY: Lazy<Arc<TypeFactory>> = Lazy::new(|| {
     type_factory.register(NullType::arc());
     type_factory.register(BooleanType::arc());
     type_factory.register(StringType::arc());
+    type_factory.register(BinaryType::arc());
+    type_factory.register(UuidType::arc());
+    type_factory.register(IPv4Type::arc());
+    type_factory.register(IPv6Type::arc());
+    type_factory.register(BitmapType::arc());
 
     type_factory.register(UInt8Type::arc());
     type_factory.register(UInt16Type::arc());
@@ -59,6 +64,13 @@ static TYPE_FACTORY: Lazy<Arc<TypeFactory>> = Lazy::new(|| {
         type_factory.register(DateTime64Type::arc(9, None));
     }
 
+    // Decimal is a special case too, the name has the precision and scale
+    for precision in 1..=MAX_DECIMAL_PRECISION {
+        for scale in 0..=precision {
+            type_factory.register(DecimalType::arc(precision, scale));
+        }
+    }
+
     type_factory.add_array_wrapper();
     type_factory.add_nullable_wrapper();
 
@@ -45,6 +45,20 @@ pub enum TypeID {
     Float64,
 
     String,
+    /// Arbitrary bytes, unlike String the value is not required to be valid UTF-8,
+    /// it's physical type is String
+    Binary,
+    /// A 128-bit universally unique identifier, it's physical type is String
+    /// with every value exactly 16 bytes long
+    Uuid,
+    /// An IPv4 address, it's physical type is UInt32
+    IPv4,
+    /// An IPv6 address, it's physical type is String with every value exactly
+    /// 16 bytes long in network byte order
+    IPv6,
+    /// A set of unsigned 64-bit integers, it's physical type is String holding
+    /// the serialized roaring bitmap
+    Bitmap,
 
     /// A 32-bit date representing the elapsed time since UNIX epoch (1970-01-01)
     /// in days (16 bits), it's physical type is UInt16
@@ -65,8 +79,14 @@ pub enum TypeID {
     /// Underneath Interval is stored as int64, so it supports negative values.
     Interval,
 
+    /// An exact number with fixed precision and scale, the unscaled value is stored as int64,
+    /// so the precision is at most 18.
+    Decimal,
+
     Array,
     Struct,
+    /// A map from keys to values, it's physical type is an Array of the (key, value) entries
+    Map,
 
     /// Variant is a tagged universal type, which can store values of any other type,
     /// including Object and Array, up to a maximum size of 16 MB.
@@ -86,6 +106,26 @@ impl TypeID {
         matches!(self, TypeID::String)
     }
 
+    #[inline]
+    pub fn is_binary(&self) -> bool {
+        matches!(self, TypeID::Binary)
+    }
+
+    #[inline]
+    pub fn is_uuid(&self) -> bool {
+        matches!(self, TypeID::Uuid)
+    }
+
+    #[inline]
+    pub fn is_ip(&self) -> bool {
+        matches!(self, TypeID::IPv4 | TypeID::IPv6)
+    }
+
+    #[inline]
+    pub fn is_bitmap(&self) -> bool {
+        matches!(self, TypeID::Bitmap)
+    }
+
     #[inline]
     pub fn is_null(&self) -> bool {
         matches!(self, TypeID::Null)
@@ -160,6 +200,11 @@ impl TypeID {
         matches!(self, TypeID::Interval)
     }
 
+    #[inline]
+    pub fn is_decimal(&self) -> bool {
+        matches!(self, TypeID::Decimal)
+    }
+
     #[inline]
     pub fn is_array(&self) -> bool {
         matches!(self, TypeID::Array)
@@ -170,11 +215,20 @@ impl TypeID {
         matches!(self, TypeID::Struct)
     }
 
+    #[inline]
+    pub fn is_map(&self) -> bool {
+        matches!(self, TypeID::Map)
+    }
+
     #[inline]
     pub fn is_quoted(&self) -> bool {
         matches!(
             self,
             TypeID::String
+                | TypeID::Uuid
+                | TypeID::IPv4
+                | TypeID::IPv6
+                | TypeID::Bitmap
                 | TypeID::Date16
                 | TypeID::Date32
                 | TypeID::DateTime32
@@ -206,17 +260,17 @@ impl TypeID {
             Int16 => PhysicalTypeID::Int16,
 
             Int32 | Date32 => PhysicalTypeID::Int32,
-            Int64 | Interval | DateTime64 => PhysicalTypeID::Int64,
+            Int64 | Interval | DateTime64 | Decimal => PhysicalTypeID::Int64,
 
             UInt8 => PhysicalTypeID::UInt8,
             Date16 | UInt16 => PhysicalTypeID::UInt16,
-            DateTime32 | UInt32 => PhysicalTypeID::UInt32,
+            DateTime32 | IPv4 | UInt32 => PhysicalTypeID::UInt32,
             UInt64 => PhysicalTypeID::UInt64,
             Float32 => PhysicalTypeID::Float32,
             Float64 => PhysicalTypeID::Float64,
 
-            String => PhysicalTypeID::String,
-            Array => PhysicalTypeID::Array,
+            String | Binary | Uuid | IPv6 | Bitmap => PhysicalTypeID::String,
+            Array | Map => PhysicalTypeID::Array,
             Struct => PhysicalTypeID::Struct,
             Variant | VariantArray | VariantObject => PhysicalTypeID::Variant,
         }
@@ -51,6 +51,25 @@ impl fmt::Display for IntervalKind {
     }
 }
 
+impl IntervalKind {
+    /// Whether the interval is a number of months, whose length in seconds varies.
+    pub fn is_month_based(&self) -> bool {
+        matches!(self, IntervalKind::Year | IntervalKind::Month)
+    }
+
+    /// The length of the unit, in months for the month based kinds, in seconds for the others.
+    pub fn unit(&self) -> i64 {
+        match self {
+            IntervalKind::Year => 12,
+            IntervalKind::Month => 1,
+            IntervalKind::Day => 24 * 3600,
+            IntervalKind::Hour => 3600,
+            IntervalKind::Minute => 60,
+            IntervalKind::Second => 1,
+        }
+    }
+}
+
 impl From<String> for IntervalKind {
     fn from(s: String) -> Self {
         match s.as_str() {
@@ -121,11 +140,11 @@ impl DataType for IntervalType {
     }
 
     fn create_serializer(&self) -> Box<dyn TypeSerializer> {
-        Box::new(DateSerializer::<i64>::default())
+        Box::new(NumberSerializer::<i64>::default())
     }
 
     fn create_deserializer(&self, capacity: usize) -> Box<dyn TypeDeserializer> {
-        Box::new(DateDeserializer::<i64> {
+        Box::new(NumberDeserializer::<i64> {
             builder: MutablePrimitiveColumn::<i64>::with_capacity(capacity),
         })
     }
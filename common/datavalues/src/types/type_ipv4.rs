@@ -0,0 +1,122 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::net::Ipv4Addr;
+use std::sync::Arc;
+
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::data_type::DataType;
+use super::data_type::ARROW_EXTENSION_NAME;
+use super::type_id::TypeID;
+use crate::prelude::*;
+
+/// IPv4Type stores IPv4 addresses, it's physical type is UInt32 holding the address
+/// in host byte order, so the addresses sort in the numeric order. It's written as
+/// the dotted decimal text `a.b.c.d`.
+#[derive(Default, Clone, serde::Deserialize, serde::Serialize)]
+pub struct IPv4Type {}
+
+impl IPv4Type {
+    pub fn arc() -> DataTypePtr {
+        Arc::new(Self {})
+    }
+
+    /// The dotted decimal text of an IPv4 address.
+    pub fn format(v: u32) -> String {
+        Ipv4Addr::from(v).to_string()
+    }
+
+    /// Parse the dotted decimal text of an IPv4 address.
+    pub fn parse(text: &[u8]) -> Option<u32> {
+        let text = std::str::from_utf8(text).ok()?;
+        text.trim().parse::<Ipv4Addr>().ok().map(u32::from)
+    }
+
+    pub fn try_parse(text: &[u8]) -> Result<u32> {
+        Self::parse(text).ok_or_else(|| {
+            ErrorCode::BadBytes(format!(
+                "Cannot parse value {:?} to IPv4",
+                String::from_utf8_lossy(text)
+            ))
+        })
+    }
+}
+
+#[typetag::serde]
+impl DataType for IPv4Type {
+    fn data_type_id(&self) -> TypeID {
+        TypeID::IPv4
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "IPv4"
+    }
+
+    fn default_value(&self) -> DataValue {
+        DataValue::UInt64(0)
+    }
+
+    fn create_constant_column(&self, data: &DataValue, size: usize) -> Result<ColumnRef> {
+        let value = data.as_u64()?;
+        let column = Series::from_data(&[value as u32]);
+        Ok(Arc::new(ConstColumn::new(column, size)))
+    }
+
+    fn create_column(&self, data: &[DataValue]) -> Result<ColumnRef> {
+        let value = data
+            .iter()
+            .map(|v| v.as_u64().map(|v| v as u32))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Series::from_data(&value))
+    }
+
+    fn arrow_type(&self) -> ArrowType {
+        ArrowType::UInt32
+    }
+
+    fn custom_arrow_meta(&self) -> Option<BTreeMap<String, String>> {
+        let mut mp = BTreeMap::new();
+        mp.insert(ARROW_EXTENSION_NAME.to_string(), "IPv4".to_string());
+        Some(mp)
+    }
+
+    fn create_serializer(&self) -> Box<dyn TypeSerializer> {
+        Box::new(IPv4Serializer {})
+    }
+
+    fn create_deserializer(&self, capacity: usize) -> Box<dyn TypeDeserializer> {
+        Box::new(IPv4Deserializer {
+            builder: MutablePrimitiveColumn::<u32>::with_capacity(capacity),
+        })
+    }
+
+    fn create_mutable(&self, capacity: usize) -> Box<dyn MutableColumn> {
+        Box::new(MutablePrimitiveColumn::<u32>::with_capacity(capacity))
+    }
+}
+
+impl std::fmt::Debug for IPv4Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
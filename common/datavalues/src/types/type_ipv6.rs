@@ -0,0 +1,141 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::net::Ipv4Addr;
+use std::net::Ipv6Addr;
+use std::sync::Arc;
+
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::data_type::DataType;
+use super::data_type::ARROW_EXTENSION_NAME;
+use super::type_id::TypeID;
+use crate::prelude::*;
+
+/// The number of bytes of an IPv6 value.
+pub const IPV6_SIZE: usize = 16;
+
+const IPV4_MAPPED_PREFIX: [u8; 12] = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff];
+
+/// IPv6Type stores IPv6 addresses, it's physical type is String with every value
+/// exactly 16 bytes long in network byte order, so the addresses sort in the byte order.
+/// An IPv4 address is stored as the IPv4-mapped address `::ffff:a.b.c.d`.
+#[derive(Default, Clone, serde::Deserialize, serde::Serialize)]
+pub struct IPv6Type {}
+
+impl IPv6Type {
+    pub fn arc() -> DataTypePtr {
+        Arc::new(Self {})
+    }
+
+    /// The text of the 16 bytes of an IPv6 address, in the compressed form.
+    pub fn format(bytes: &[u8]) -> String {
+        match <[u8; IPV6_SIZE]>::try_from(bytes) {
+            Ok(octets) => Ipv6Addr::from(octets).to_string(),
+            Err(_) => String::from_utf8_lossy(bytes).to_string(),
+        }
+    }
+
+    /// The IPv4-mapped bytes of an IPv4 address.
+    pub fn from_ipv4(v: u32) -> [u8; IPV6_SIZE] {
+        Ipv4Addr::from(v).to_ipv6_mapped().octets()
+    }
+
+    /// The IPv4 address of the bytes of an IPv4-mapped address.
+    pub fn to_ipv4(bytes: &[u8]) -> Option<u32> {
+        match bytes.len() == IPV6_SIZE && bytes[..12] == IPV4_MAPPED_PREFIX {
+            true => Some(u32::from_be_bytes(bytes[12..].try_into().ok()?)),
+            false => None,
+        }
+    }
+
+    /// Parse the text of an IPv6 address, or the dotted decimal text of an IPv4 address.
+    pub fn parse(text: &[u8]) -> Option<[u8; IPV6_SIZE]> {
+        let text = std::str::from_utf8(text).ok()?.trim();
+        match text.parse::<Ipv6Addr>() {
+            Ok(addr) => Some(addr.octets()),
+            Err(_) => IPv4Type::parse(text.as_bytes()).map(Self::from_ipv4),
+        }
+    }
+
+    pub fn try_parse(text: &[u8]) -> Result<[u8; IPV6_SIZE]> {
+        Self::parse(text).ok_or_else(|| {
+            ErrorCode::BadBytes(format!(
+                "Cannot parse value {:?} to IPv6",
+                String::from_utf8_lossy(text)
+            ))
+        })
+    }
+}
+
+#[typetag::serde]
+impl DataType for IPv6Type {
+    fn data_type_id(&self) -> TypeID {
+        TypeID::IPv6
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "IPv6"
+    }
+
+    fn default_value(&self) -> DataValue {
+        DataValue::String(vec![0; IPV6_SIZE])
+    }
+
+    fn create_constant_column(&self, data: &DataValue, size: usize) -> Result<ColumnRef> {
+        let value = data.as_string()?;
+        let column = Series::from_data(&[value.as_slice()]);
+        Ok(Arc::new(ConstColumn::new(column, size)))
+    }
+
+    fn arrow_type(&self) -> ArrowType {
+        ArrowType::LargeBinary
+    }
+
+    fn custom_arrow_meta(&self) -> Option<BTreeMap<String, String>> {
+        let mut mp = BTreeMap::new();
+        mp.insert(ARROW_EXTENSION_NAME.to_string(), "IPv6".to_string());
+        Some(mp)
+    }
+
+    fn create_serializer(&self) -> Box<dyn TypeSerializer> {
+        Box::new(IPv6Serializer {})
+    }
+
+    fn create_deserializer(&self, capacity: usize) -> Box<dyn TypeDeserializer> {
+        Box::new(IPv6Deserializer::with_capacity(capacity))
+    }
+
+    fn create_mutable(&self, capacity: usize) -> Box<dyn MutableColumn> {
+        Box::new(MutableStringColumn::with_capacity(capacity))
+    }
+
+    fn create_column(&self, data: &[DataValue]) -> Result<ColumnRef> {
+        StringType::arc().create_column(data)
+    }
+}
+
+impl std::fmt::Debug for IPv6Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
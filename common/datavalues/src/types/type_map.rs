@@ -0,0 +1,176 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_arrow::arrow::datatypes::Field;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::data_type::DataType;
+use super::data_type::DataTypePtr;
+use super::type_id::TypeID;
+use crate::prelude::*;
+
+/// A map from keys to values, stored as an array of the `(key, value)` entries of each row,
+/// so a map value is a `DataValue::Array` of `DataValue::Struct([key, value])`.
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct MapType {
+    name: String,
+    key: DataTypePtr,
+    value: DataTypePtr,
+}
+
+impl MapType {
+    pub fn create(key: DataTypePtr, value: DataTypePtr) -> Self {
+        MapType {
+            name: format!("Map({}, {})", key.name(), value.name()),
+            key,
+            value,
+        }
+    }
+
+    pub fn arc(key: DataTypePtr, value: DataTypePtr) -> DataTypePtr {
+        Arc::new(Self::create(key, value))
+    }
+
+    pub fn key_type(&self) -> &DataTypePtr {
+        &self.key
+    }
+
+    pub fn value_type(&self) -> &DataTypePtr {
+        &self.value
+    }
+
+    /// The type of the entries, a struct of the key and the value.
+    pub fn entries_type(&self) -> DataTypePtr {
+        Arc::new(StructType::create(
+            vec!["key".to_owned(), "value".to_owned()],
+            vec![self.key.clone(), self.value.clone()],
+        ))
+    }
+
+    /// The map of the arrow entries field, which must be a struct of the key and the value.
+    pub fn try_from_arrow_entries(entries: &Field) -> Result<Self> {
+        match from_arrow_field(entries)
+            .as_any()
+            .downcast_ref::<StructType>()
+        {
+            Some(entries) if entries.types().len() == 2 => Ok(Self::create(
+                entries.types()[0].clone(),
+                entries.types()[1].clone(),
+            )),
+            _ => Err(ErrorCode::IllegalDataType(format!(
+                "The entries of a map must be a struct of the key and the value, but got {:?}",
+                entries.data_type()
+            ))),
+        }
+    }
+}
+
+#[typetag::serde]
+impl DataType for MapType {
+    fn data_type_id(&self) -> TypeID {
+        TypeID::Map
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn default_value(&self) -> DataValue {
+        DataValue::Array(vec![])
+    }
+
+    fn can_inside_nullable(&self) -> bool {
+        false
+    }
+
+    fn create_constant_column(&self, data: &DataValue, size: usize) -> Result<ColumnRef> {
+        let column = self.create_column(&[data.clone()])?;
+        Ok(Arc::new(ConstColumn::new(column, size)))
+    }
+
+    fn create_column(&self, data: &[DataValue]) -> Result<ColumnRef> {
+        let mut entries: Vec<DataValue> = vec![];
+        let mut offsets: Vec<i64> = vec![0];
+        for v in data.iter() {
+            if let DataValue::Array(value) = v {
+                offsets.push(offsets.last().unwrap() + value.len() as i64);
+                entries.extend_from_slice(value);
+            } else {
+                return Result::Err(ErrorCode::BadDataValueType(format!(
+                    "Unexpected type:{:?} to generate map column",
+                    v.value_type()
+                )));
+            }
+        }
+
+        let entries = self.entries_type().create_column(&entries)?;
+        Ok(Arc::new(ArrayColumn::from_data(
+            Arc::new(self.clone()),
+            offsets.into(),
+            entries,
+        )))
+    }
+
+    fn arrow_type(&self) -> ArrowType {
+        let entries = self.entries_type().to_arrow_field("entries");
+        ArrowType::Extension(
+            "Map".to_owned(),
+            Box::new(ArrowType::LargeList(Box::new(entries))),
+            None,
+        )
+    }
+
+    fn create_serializer(&self) -> Box<dyn TypeSerializer> {
+        Box::new(MapSerializer {
+            key: self.key.create_serializer(),
+            value: self.value.create_serializer(),
+            key_type: self.key.clone(),
+            value_type: self.value.clone(),
+        })
+    }
+
+    fn create_deserializer(&self, capacity: usize) -> Box<dyn TypeDeserializer> {
+        let mut offsets = Vec::with_capacity(capacity + 1);
+        offsets.push(0);
+        Box::new(MapDeserializer {
+            key: self.key.create_deserializer(capacity),
+            value: self.value.create_deserializer(capacity),
+            offsets,
+            data_type: Arc::new(self.clone()),
+            entries_type: self.entries_type(),
+        })
+    }
+
+    fn create_mutable(&self, capacity: usize) -> Box<dyn MutableColumn> {
+        Box::new(MutableArrayColumn::with_capacity(
+            Arc::new(self.clone()),
+            capacity,
+        ))
+    }
+}
+
+impl std::fmt::Debug for MapType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
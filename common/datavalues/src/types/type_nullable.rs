@@ -104,8 +104,6 @@ impl DataType for NullableType {
         data: &DataValue,
         size: usize,
     ) -> common_exception::Result<ColumnRef> {
-        let mut bitmap = MutableBitmap::with_capacity(1);
-
         if self.inner.data_type_id() == TypeID::Null {
             return Ok(Arc::new(NullColumn::new(size)));
         }
@@ -114,15 +112,9 @@ impl DataType for NullableType {
                 "Nullable type can't be inside nullable type".to_string(),
             ));
         }
-        let data = if data.is_null() {
-            bitmap.extend_constant(size, false);
-            self.inner.default_value()
-        } else {
-            bitmap.extend_constant(size, true);
-            data.clone()
-        };
-        let column = self.inner.create_constant_column(&data, size)?;
-        Ok(Arc::new(NullableColumn::new(column, bitmap.into())))
+        // const(nullable) of a single row, the validity is not materialized either
+        let column = self.create_column(std::slice::from_ref(data))?;
+        Ok(Arc::new(ConstColumn::new(column, size)))
     }
 
     fn create_column(&self, data: &[DataValue]) -> common_exception::Result<ColumnRef> {
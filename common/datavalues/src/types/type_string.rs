@@ -45,7 +45,7 @@ impl DataType for StringType {
     }
 
     fn aliases(&self) -> &[&str] {
-        &["Binary", "Varchar", "Char", "Text", "Blob"]
+        &["Varchar", "Char", "Text"]
     }
 
     fn default_value(&self) -> DataValue {
@@ -75,7 +75,7 @@ impl DataType for StructType {
             let cols = value
                 .iter()
                 .zip(self.types.iter())
-                .map(|(v, typ)| typ.create_constant_column(v, size))
+                .map(|(v, typ)| typ.create_column(std::slice::from_ref(v)))
                 .collect::<Result<Vec<_>>>()?;
             let struct_column = StructColumn::from_data(cols, Arc::new(self.clone()));
             return Ok(Arc::new(ConstColumn::new(Arc::new(struct_column), size)));
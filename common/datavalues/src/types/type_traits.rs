@@ -43,15 +43,43 @@ pub trait PrimitiveType:
     const SIGN: bool;
     const FLOATING: bool;
     const SIZE: usize;
+
+    /// The value used to hash, group and sort by, equal values share the same representation.
+    /// For floats all NaNs become the positive quiet NaN and -0.0 becomes 0.0.
+    fn canonical(&self) -> Self;
 }
 
 macro_rules! impl_primitive {
-    ($ca:ident, $lg: ident, $sign: expr, $floating: expr, $size: expr) => {
+    ($ca:ident, $lg: ident, $sign: expr, false, $size: expr) => {
         impl PrimitiveType for $ca {
             type LargestType = $lg;
             const SIGN: bool = $sign;
-            const FLOATING: bool = $floating;
+            const FLOATING: bool = false;
             const SIZE: usize = $size;
+
+            #[inline]
+            fn canonical(&self) -> Self {
+                *self
+            }
+        }
+    };
+    ($ca:ident, $lg: ident, $sign: expr, true, $size: expr) => {
+        impl PrimitiveType for $ca {
+            type LargestType = $lg;
+            const SIGN: bool = $sign;
+            const FLOATING: bool = true;
+            const SIZE: usize = $size;
+
+            #[inline]
+            fn canonical(&self) -> Self {
+                if self.is_nan() {
+                    $ca::NAN
+                } else if *self == 0.0 {
+                    0.0
+                } else {
+                    *self
+                }
+            }
         }
     };
 }
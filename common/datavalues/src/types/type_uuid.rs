@@ -0,0 +1,140 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::data_type::DataType;
+use super::data_type::ARROW_EXTENSION_NAME;
+use super::type_id::TypeID;
+use crate::prelude::*;
+
+/// The number of bytes of a UUID value.
+pub const UUID_SIZE: usize = 16;
+
+/// UuidType stores 128-bit universally unique identifiers, it's physical type is String
+/// with every value exactly 16 bytes long, and it's written as the hyphenated text
+/// `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`.
+#[derive(Default, Clone, serde::Deserialize, serde::Serialize)]
+pub struct UuidType {}
+
+impl UuidType {
+    pub fn arc() -> DataTypePtr {
+        Arc::new(Self {})
+    }
+
+    /// The hyphenated lower case text of the 16 bytes of a UUID.
+    pub fn format(bytes: &[u8]) -> String {
+        let mut s = String::with_capacity(36);
+        for (i, b) in bytes.iter().enumerate() {
+            if matches!(i, 4 | 6 | 8 | 10) {
+                s.push('-');
+            }
+            s.push_str(&format!("{:02x}", b));
+        }
+        s
+    }
+
+    /// Parse the hyphenated text of a UUID, or the 32 hex digits without the hyphens.
+    pub fn parse(text: &[u8]) -> Option<[u8; UUID_SIZE]> {
+        let digits = match text.len() {
+            32 => text.to_vec(),
+            36 if [8, 13, 18, 23].iter().all(|i| text[*i] == b'-') => text
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| !matches!(i, 8 | 13 | 18 | 23))
+                .map(|(_, c)| *c)
+                .collect(),
+            _ => return None,
+        };
+
+        let mut bytes = [0u8; UUID_SIZE];
+        for (i, pair) in digits.chunks(2).enumerate() {
+            let pair = std::str::from_utf8(pair).ok()?;
+            bytes[i] = u8::from_str_radix(pair, 16).ok()?;
+        }
+        Some(bytes)
+    }
+
+    pub fn try_parse(text: &[u8]) -> Result<[u8; UUID_SIZE]> {
+        Self::parse(text).ok_or_else(|| {
+            ErrorCode::BadBytes(format!(
+                "Cannot parse value {:?} to UUID",
+                String::from_utf8_lossy(text)
+            ))
+        })
+    }
+}
+
+#[typetag::serde]
+impl DataType for UuidType {
+    fn data_type_id(&self) -> TypeID {
+        TypeID::Uuid
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "UUID"
+    }
+
+    fn default_value(&self) -> DataValue {
+        DataValue::String(vec![0; UUID_SIZE])
+    }
+
+    fn create_constant_column(&self, data: &DataValue, size: usize) -> Result<ColumnRef> {
+        let value = data.as_string()?;
+        let column = Series::from_data(&[value.as_slice()]);
+        Ok(Arc::new(ConstColumn::new(column, size)))
+    }
+
+    fn arrow_type(&self) -> ArrowType {
+        ArrowType::LargeBinary
+    }
+
+    fn custom_arrow_meta(&self) -> Option<BTreeMap<String, String>> {
+        let mut mp = BTreeMap::new();
+        mp.insert(ARROW_EXTENSION_NAME.to_string(), "UUID".to_string());
+        Some(mp)
+    }
+
+    fn create_serializer(&self) -> Box<dyn TypeSerializer> {
+        Box::new(UuidSerializer {})
+    }
+
+    fn create_deserializer(&self, capacity: usize) -> Box<dyn TypeDeserializer> {
+        Box::new(UuidDeserializer::with_capacity(capacity))
+    }
+
+    fn create_mutable(&self, capacity: usize) -> Box<dyn MutableColumn> {
+        Box::new(MutableStringColumn::with_capacity(capacity))
+    }
+
+    fn create_column(&self, data: &[DataValue]) -> Result<ColumnRef> {
+        StringType::arc().create_column(data)
+    }
+}
+
+impl std::fmt::Debug for UuidType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
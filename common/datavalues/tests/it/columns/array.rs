@@ -0,0 +1,60 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+#[test]
+fn test_array_column() -> Result<()> {
+    let column = Series::from_data(vec![vec![1i64, 2], vec![], vec![3]]);
+    assert_eq!(column.data_type().name(), "Array(Int64)");
+    assert_eq!(column.len(), 3);
+
+    let array: &ArrayColumn = Series::check_get(&column)?;
+    assert_eq!(array.offsets(), &[0, 2, 2, 3]);
+    assert_eq!(
+        column.get(0),
+        DataValue::Array(vec![DataValue::Int64(1), DataValue::Int64(2)])
+    );
+    assert_eq!(column.get(1), DataValue::Array(vec![]));
+
+    let slice = column.slice(1, 2);
+    assert_eq!(slice.get(1), DataValue::Array(vec![DataValue::Int64(3)]));
+
+    let filter = BooleanColumn::from_slice(&[true, false, true]);
+    let filtered = column.filter(&filter);
+    assert_eq!(filtered.len(), 2);
+    assert_eq!(filtered.get(1), DataValue::Array(vec![DataValue::Int64(3)]));
+    Ok(())
+}
+
+#[test]
+fn test_array_column_arrow_round_trip() -> Result<()> {
+    let data_type = ArrayType::create(wrap_nullable(&i32::to_data_type()));
+    let column = data_type.create_column(&[
+        DataValue::Array(vec![DataValue::Int64(1), DataValue::Null]),
+        DataValue::Array(vec![DataValue::Int64(2)]),
+    ])?;
+
+    let round_trip = column.as_arrow_array().into_column();
+    assert_eq!(round_trip.data_type().name(), "Array(Nullable(Int32))");
+    assert_eq!(
+        round_trip.get(0),
+        DataValue::Array(vec![DataValue::Int64(1), DataValue::Null])
+    );
+
+    let strings = Series::from_data(vec![vec!["a".as_bytes().to_vec()]]);
+    assert_eq!(strings.data_type().name(), "Array(String)");
+    Ok(())
+}
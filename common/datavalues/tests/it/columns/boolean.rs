@@ -95,3 +95,45 @@ fn test_filter_column() {
         );
     }
 }
+
+#[test]
+fn test_boolean_column_count_true() {
+    const N: usize = 200;
+    let column = BooleanColumn::from_iterator((0..N).map(|i| i % 3 == 0));
+    let validity: Bitmap = MutableBitmap::from_iter((0..N).map(|i| i % 2 == 0)).into();
+
+    assert_eq!(column.count_true(None), 67);
+    assert_eq!(column.count_true(Some(&validity)), 34);
+    assert!(column.any_true(None));
+    assert!(!column.all_true(None));
+
+    // sliced columns and validities are not aligned to the byte
+    let sliced = column.slice(3, 100);
+    let sliced: &BooleanColumn = sliced.as_any().downcast_ref().unwrap();
+    let sliced_validity = validity.clone().slice(3, 100);
+    let expect = (3..103).filter(|i| i % 3 == 0 && i % 2 == 0).count();
+    assert_eq!(sliced.count_true(Some(&sliced_validity)), expect);
+
+    let all_true = BooleanColumn::from_slice(&[true, false, true]);
+    let validity: Bitmap = MutableBitmap::from_iter([true, false, true]).into();
+    assert!(all_true.all_true(Some(&validity)));
+    assert!(!all_true.all_true(None));
+
+    let empty = BooleanColumn::from_slice(&[]);
+    assert!(empty.all_true(None));
+    assert!(!empty.any_true(None));
+}
+
+#[test]
+fn test_filter_sliced_column() {
+    let column = BooleanColumn::from_iterator((0..20).map(|i| i % 2 == 0));
+    let sliced = column.slice(1, 10);
+    let sliced: &BooleanColumn = sliced.as_any().downcast_ref().unwrap();
+    let filter = BooleanColumn::from_iterator((0..10).map(|i| i < 4));
+
+    let res = sliced.filter(&filter);
+    let res: &BooleanColumn = res.as_any().downcast_ref().unwrap();
+    assert_eq!(res.iter().collect::<Vec<_>>(), vec![
+        false, true, false, true
+    ]);
+}
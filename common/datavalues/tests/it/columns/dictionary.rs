@@ -0,0 +1,89 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+
+#[test]
+fn test_dictionary_encode() {
+    let column = StringColumn::new_from_slice(&["a", "bb", "a", "ccc", "bb", "a"]);
+    assert!(DictionaryColumn::try_encode(&column, 2).is_none());
+
+    let dictionary = DictionaryColumn::try_encode(&column, 3).unwrap();
+    assert_eq!(dictionary.keys(), &[0, 1, 0, 2, 1, 0]);
+    assert_eq!(dictionary.values().len(), 3);
+    assert_eq!(dictionary.len(), 6);
+    assert_eq!(dictionary.data_type_id(), TypeID::String);
+    assert_eq!(dictionary.get(3), DataValue::String(b"ccc".to_vec()));
+
+    let decoded = dictionary.convert_full_column();
+    assert_eq!(decoded.as_ref(), &column as &dyn Column);
+    assert_eq!(dictionary.arc().as_ref(), &column as &dyn Column);
+}
+
+#[test]
+fn test_dictionary_column_operations() {
+    let column = StringColumn::new_from_slice(&["x", "y", "x", "z"]);
+    let dictionary = DictionaryColumn::try_encode(&column, 16).unwrap().arc();
+
+    let sliced = dictionary.slice(1, 2);
+    assert!(sliced.is_dictionary());
+    assert_eq!(sliced.to_values(), vec![
+        DataValue::String(b"y".to_vec()),
+        DataValue::String(b"x".to_vec())
+    ]);
+
+    let filter = BooleanColumn::from_slice(&[true, false, false, true]);
+    let filtered = dictionary.filter(&filter);
+    assert_eq!(filtered.to_values(), vec![
+        DataValue::String(b"x".to_vec()),
+        DataValue::String(b"z".to_vec())
+    ]);
+
+    let taken = Series::take(&dictionary, &[3u32, 0]).unwrap();
+    assert!(taken.is_dictionary());
+    assert_eq!(taken.to_values(), filtered.to_values());
+
+    let replicated = dictionary.replicate(&[1, 1, 3, 4]);
+    assert_eq!(replicated.len(), 4);
+    assert_eq!(replicated.get(2), DataValue::String(b"x".to_vec()));
+
+    let scattered = dictionary.scatter(&[0, 1, 0, 1], 2);
+    assert_eq!(scattered[0].to_values(), vec![
+        DataValue::String(b"x".to_vec()),
+        DataValue::String(b"x".to_vec())
+    ]);
+
+    // the dictionary is shared by the keys of all the slices
+    assert_eq!(
+        sliced.memory_size(),
+        2 * 4 + dictionary.memory_size() - 4 * 4
+    );
+}
+
+#[test]
+fn test_dictionary_serialize() {
+    let column = StringColumn::new_from_slice(&["x", "y", "x", "z"]).arc();
+    let dictionary = Series::try_encode_dictionary(&column, 16).unwrap();
+
+    let mut expected = vec![SmallVu8::new(); 4];
+    Series::serialize(&column, &mut expected, None).unwrap();
+    let mut serialized = vec![SmallVu8::new(); 4];
+    Series::serialize(&dictionary, &mut serialized, None).unwrap();
+    assert_eq!(serialized, expected);
+
+    assert!(Series::has_dictionary(&dictionary));
+    let decoded = Series::remove_dictionary(&dictionary);
+    assert!(!decoded.is_dictionary());
+    assert_eq!(decoded.as_ref(), column.as_ref());
+}
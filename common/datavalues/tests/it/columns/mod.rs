@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod array;
 mod boolean;
 mod builder;
+mod dictionary;
 mod object;
 mod primitive;
+mod series;
 mod string;
@@ -0,0 +1,222 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+#[test]
+fn test_series_typed_accessors() {
+    let column = Series::from_data(vec![1i32, 2, 3]);
+    let primitive = Series::as_primitive::<i32>(&column).unwrap();
+    assert_eq!(primitive.values(), &[1, 2, 3]);
+    assert!(Series::as_primitive::<i64>(&column).is_err());
+    assert!(Series::as_string(&column).is_err());
+
+    let column = Series::from_data(vec!["a", "b"]);
+    assert_eq!(Series::as_string(&column).unwrap().len(), 2);
+
+    let column = Series::from_data(vec![true, false]);
+    assert!(Series::as_boolean(&column).unwrap().get_data(0));
+
+    let nullable = Series::from_data(vec![Some(1i32), None]);
+    let err = Series::as_primitive::<i32>(&nullable).unwrap_err();
+    assert!(err.message().contains("Series::iter_opt"));
+}
+
+#[test]
+fn test_series_iter_opt() {
+    let column = Series::from_data(vec![Some(1i32), None, Some(3)]);
+    let values = Series::iter_opt::<i32>(&column)
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(values, vec![Some(1), None, Some(3)]);
+
+    let column = Series::from_data(vec![Some("x"), None]);
+    let values = Series::iter_opt::<Vu8>(&column)
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(values, vec![Some("x".as_bytes()), None]);
+
+    let column = ConstColumn::new(Series::from_data(vec![7u8]), 3).arc();
+    let iter = Series::iter_opt::<u8>(&column).unwrap();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![Some(7), Some(7), Some(7)]);
+
+    let column = Series::from_data(vec![1u64, 2, 3, 4, 5]);
+    let chunks = Series::iter_opt_chunks::<u64>(&column, 2)
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(chunks, vec![
+        vec![Some(1), Some(2)],
+        vec![Some(3), Some(4)],
+        vec![Some(5)]
+    ]);
+}
+
+#[test]
+fn test_series_from_iter_with_capacity() {
+    let column = Series::from_iter_with_capacity::<i64>((0..4).map(|v| v * 2), 4);
+    assert_eq!(
+        column.as_ref(),
+        Series::from_data(vec![0i64, 2, 4, 6]).as_ref()
+    );
+
+    let values = vec![Some(1.5f64), None];
+    let column = Series::from_opt_iter_with_capacity::<f64>(values.into_iter(), 2);
+    assert!(column.is_nullable());
+    assert_eq!(column.len(), 2);
+    assert!(column.null_at(1));
+    assert_eq!(column.get(0), DataValue::Float64(1.5));
+
+    let strings = ["a", "bc"];
+    let column =
+        Series::from_opt_iter_with_capacity::<Vu8>(strings.iter().map(|s| Some(s.as_bytes())), 2);
+    assert_eq!(Series::iter_opt::<Vu8>(&column).unwrap().count(), 2);
+}
+
+#[test]
+fn test_series_take() -> Result<()> {
+    let column = Series::from_data(vec![1i32, 2, 3, 4]);
+
+    // consecutive indices are a slice over the same buffer
+    let taken = Series::take(&column, &[1u32, 2, 3])?;
+    let values = Series::as_primitive::<i32>(&taken)?.values();
+    assert_eq!(values, &[2, 3, 4]);
+    assert_eq!(
+        values.as_ptr(),
+        Series::as_primitive::<i32>(&column)?.values()[1..].as_ptr()
+    );
+
+    let taken = Series::take(&column, &[3u32, 0, 0])?;
+    assert_eq!(Series::as_primitive::<i32>(&taken)?.values(), &[4, 1, 1]);
+
+    let column = Series::from_data(vec![vec![1i64, 2], vec![], vec![3]]);
+    let taken = Series::take(&column, &[2u32, 0])?;
+    assert_eq!(taken.data_type().name(), "Array(Int64)");
+    assert_eq!(taken.get(0), DataValue::Array(vec![DataValue::Int64(3)]));
+    assert_eq!(
+        taken.get(1),
+        DataValue::Array(vec![DataValue::Int64(1), DataValue::Int64(2)])
+    );
+    Ok(())
+}
+
+#[test]
+fn test_series_scatter() -> Result<()> {
+    let column = Series::from_data(vec![Some("a"), None, Some("c")]);
+    let scattered = Series::scatter(&column, &[1, 0, 1], 2)?;
+    assert_eq!(scattered[0].len(), 1);
+    assert!(scattered[0].null_at(0));
+    assert_eq!(
+        scattered[1].get(1),
+        DataValue::String("c".as_bytes().to_vec())
+    );
+
+    // all the rows go to the same column
+    let column = Series::from_data(vec![1i64, 2, 3]);
+    let scattered = Series::scatter(&column, &[2, 2, 2], 3)?;
+    assert!(scattered[0].is_empty());
+    assert!(scattered[1].is_empty());
+    assert_eq!(
+        Series::as_primitive::<i64>(&scattered[2])?
+            .values()
+            .as_ptr(),
+        Series::as_primitive::<i64>(&column)?.values().as_ptr()
+    );
+
+    let err = Series::scatter(&column, &[0, 1], 2).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::BadArgumentsCode());
+    let err = Series::scatter(&column, &[0, 1, 2], 2).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::BadArgumentsCode());
+    Ok(())
+}
+
+#[test]
+fn test_series_concat() -> Result<()> {
+    let column = Series::from_data(vec![1u8, 2]);
+    let concatenated = Series::concat(&[column.clone()])?;
+    assert_eq!(
+        Series::as_primitive::<u8>(&concatenated)?.values().as_ptr(),
+        Series::as_primitive::<u8>(&column)?.values().as_ptr()
+    );
+
+    let nullable = Series::from_data(vec![None, Some(3u8)]);
+    let concatenated = Series::concat(&[column, nullable])?;
+    assert!(concatenated.is_nullable());
+    assert_eq!(concatenated.len(), 4);
+    assert!(concatenated.null_at(2));
+    assert_eq!(concatenated.get(3), DataValue::UInt64(3));
+    Ok(())
+}
+
+#[test]
+fn test_series_argsort() -> Result<()> {
+    let column = Series::from_data(vec![Some(3i32), None, Some(1), Some(3), None]);
+    assert_eq!(Series::argsort(&column, false, false, None)?, vec![
+        2, 0, 3, 1, 4
+    ]);
+    assert_eq!(Series::argsort(&column, true, true, None)?, vec![
+        1, 4, 0, 3, 2
+    ]);
+    assert_eq!(Series::argsort(&column, false, true, Some(3))?, vec![
+        1, 4, 2
+    ]);
+    assert!(Series::argsort(&column, false, true, Some(0))?.is_empty());
+
+    // NaN sorts after +Infinity and -0.0 equals 0.0
+    let column = Series::from_data(vec![f64::NAN, f64::INFINITY, 0.0, -0.0, -1.0]);
+    assert_eq!(Series::argsort(&column, false, false, None)?, vec![
+        4, 2, 3, 1, 0
+    ]);
+
+    // the date types sort as their integers
+    let column = Date32Type::arc().create_column(&[
+        DataValue::Int64(19000),
+        DataValue::Int64(-1),
+        DataValue::Int64(0),
+    ])?;
+    assert_eq!(Series::argsort(&column, false, false, None)?, vec![1, 2, 0]);
+
+    let column = Series::from_data(vec![vec![1i64, 2], vec![], vec![1]]);
+    assert_eq!(Series::argsort(&column, false, false, None)?, vec![1, 2, 0]);
+    Ok(())
+}
+
+#[test]
+fn test_series_lexsort() -> Result<()> {
+    let keys = vec![
+        SortKey {
+            column: Series::from_data(vec!["b", "a", "b", "a"]),
+            descending: false,
+            nulls_first: false,
+        },
+        SortKey {
+            column: Series::from_data(vec![1u8, 2, 3, 4]),
+            descending: true,
+            nulls_first: false,
+        },
+    ];
+    assert_eq!(Series::lexsort(&keys, None)?, vec![3, 1, 2, 0]);
+    assert_eq!(Series::lexsort(&keys, Some(2))?, vec![3, 1]);
+
+    let keys = vec![keys[0].clone(), SortKey {
+        column: Series::from_data(vec![1u8, 2]),
+        descending: false,
+        nulls_first: false,
+    }];
+    let err = Series::lexsort(&keys, None).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::BadArgumentsCode());
+    Ok(())
+}
@@ -12,6 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::BinaryArray;
+use common_arrow::arrow::array::Utf8Array;
+use common_arrow::arrow::bitmap::MutableBitmap;
 use common_datavalues::prelude::*;
 
 #[test]
@@ -107,3 +111,65 @@ fn test_string_column() {
         );
     }
 }
+
+#[test]
+fn test_to_lowercase_column() {
+    let values: Vec<&[u8]> = vec![b"Hello", b"WORLD", b"\xc3\x84pfel", b"\xffABC", b""];
+    let column = StringColumn::new_from_slice(&values);
+    let lowered = column.to_lowercase();
+
+    let expected: Vec<&[u8]> = vec![b"hello", b"world", b"\xc3\xa4pfel", b"\xffabc", b""];
+    assert_eq!(lowered.len(), expected.len());
+    for (value, expected) in lowered.iter().zip(expected.iter()) {
+        assert_eq!(value, *expected);
+    }
+}
+
+#[test]
+fn test_string_column_memory_size() {
+    let column = StringColumn::new_from_slice(&["abc", "de", "fghi"]);
+    // 9 bytes of values and 4 offsets
+    assert_eq!(9 + 4 * 8, column.memory_size());
+    assert_eq!(9 + 4 * 8, column.retained_memory_size());
+
+    let sliced = column.slice(1, 1);
+    assert_eq!(2 + 2 * 8, sliced.memory_size());
+    assert_eq!(9 + 2 * 8, sliced.retained_memory_size());
+
+    // the validity bitmap takes one byte
+    let mut validity = MutableBitmap::with_capacity(1);
+    validity.push(true);
+    let nullable = NullableColumn::new(sliced, validity.into());
+    assert_eq!(2 + 2 * 8 + 1, nullable.memory_size());
+}
+
+#[test]
+fn test_string_column_from_arrow_array() {
+    let values = ["你好", "", "hello"];
+    let expected = values
+        .iter()
+        .map(|v| v.as_bytes().to_vec())
+        .collect::<Vec<_>>();
+
+    let arrays: Vec<Box<dyn Array>> = vec![
+        Box::new(BinaryArray::<i32>::from_slice(&values)),
+        Box::new(BinaryArray::<i64>::from_slice(&values)),
+        Box::new(Utf8Array::<i32>::from_slice(&values)),
+        Box::new(Utf8Array::<i64>::from_slice(&values)),
+    ];
+    for array in arrays {
+        let column = StringColumn::from_arrow_array(array.as_ref());
+        assert_eq!(column.len(), 3);
+        let actual = column.iter().map(|v| v.to_vec()).collect::<Vec<_>>();
+        assert_eq!(actual, expected, "{:?}", array.data_type());
+        // the offsets are always 64 bits wide
+        assert_eq!(column.data_type().data_type_id(), TypeID::String);
+        assert_eq!(column.offsets().len(), 4);
+    }
+
+    // sliced arrays keep sharing the values of the array
+    let array = Utf8Array::<i32>::from_slice(&values).slice(1, 2);
+    let column = StringColumn::from_arrow_array(&array);
+    let actual = column.iter().map(|v| v.to_vec()).collect::<Vec<_>>();
+    assert_eq!(actual, expected[1..].to_vec());
+}
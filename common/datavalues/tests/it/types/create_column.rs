@@ -169,7 +169,9 @@ fn test_create_constant() -> Result<()> {
 
             assert_eq!(full_column, full_column2, "case: {:#?}", test.name);
         } else {
-            let c: &NullableColumn = Series::check_get(&column).unwrap();
+            let c: &ConstColumn = Series::check_get(&column).unwrap();
+            assert!(c.inner().is_nullable(), "case: {:#?}", test.name);
+            assert_eq!(c.inner().len(), 1, "case: {:#?}", test.name);
             let full_column = c.convert_full_column();
 
             assert_eq!(full_column, test.column_expected, "case: {:#?}", test.name);
@@ -0,0 +1,218 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_string_and_binary_deserializers() -> Result<()> {
+    let invalid_utf8: &[u8] = &[0x61, 0xff, 0xfe];
+
+    let mut deserializer = StringType::arc().create_deserializer(2);
+    deserializer.de_text(b"databend")?;
+    let result = deserializer.de_text(invalid_utf8);
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().code(), 1046);
+
+    let mut deserializer = BinaryType::arc().create_deserializer(2);
+    deserializer.de_text(b"databend")?;
+    deserializer.de_text(invalid_utf8)?;
+    let column = deserializer.finish_to_column();
+    let column: &StringColumn = Series::check_get(&column)?;
+    assert_eq!(column.get_data(1), invalid_utf8);
+
+    Ok(())
+}
+
+#[test]
+fn test_binary_type() -> Result<()> {
+    let factory = TypeFactory::instance();
+    for name in ["Binary", "VarBinary", "Blob"] {
+        let data_type = factory.get(name)?;
+        assert_eq!(data_type.data_type_id(), TypeID::Binary);
+    }
+    assert_eq!(factory.get("Varchar")?.data_type_id(), TypeID::String);
+
+    // The binary type survives a round trip through arrow.
+    let field = BinaryType::arc().to_arrow_field("b");
+    assert_eq!(from_arrow_field(&field).data_type_id(), TypeID::Binary);
+    assert_eq!(TypeID::Binary.to_physical_type(), PhysicalTypeID::String);
+
+    Ok(())
+}
+
+#[test]
+fn test_decimal_type() -> Result<()> {
+    assert!(DecimalType::try_create(19, 2).is_err());
+    assert!(DecimalType::try_create(5, 6).is_err());
+
+    let data_type = DecimalType::try_create(5, 2)?;
+    assert_eq!(data_type.parse(b"123.45"), Some(12345));
+    assert_eq!(data_type.parse(b"-1.005"), Some(-101));
+    assert_eq!(data_type.parse(b"7"), Some(700));
+    assert_eq!(data_type.parse(b"1000"), None);
+    assert_eq!(data_type.parse(b"1.2.3"), None);
+    assert_eq!(data_type.rescale(123456, 4), Some(1235));
+    assert_eq!(data_type.rescale(-1, 0), Some(-100));
+
+    let mut deserializer = DecimalType::arc(5, 2).create_deserializer(2);
+    deserializer.de_text(b"-0.5")?;
+    deserializer.de_json(&serde_json::json!("12.34"))?;
+    assert_eq!(deserializer.de_text(b"abc").unwrap_err().code(), 1046);
+    let column = deserializer.finish_to_column();
+    let column: &Int64Column = Series::check_get(&column)?;
+    assert_eq!(column.values(), &[-50, 1234]);
+
+    // The precision and scale survive a round trip through arrow.
+    let field = DecimalType::arc(5, 2).to_arrow_field("d");
+    assert_eq!(from_arrow_field(&field), DecimalType::arc(5, 2));
+
+    Ok(())
+}
+
+#[test]
+fn test_uuid_type() -> Result<()> {
+    let text = "936da01f-9abd-4d9d-80c7-02af85c822a8";
+    let bytes = UuidType::parse(text.as_bytes()).unwrap();
+    assert_eq!(UuidType::format(&bytes), text);
+    assert_eq!(
+        UuidType::parse(text.replace('-', "").as_bytes()),
+        Some(bytes)
+    );
+    assert!(UuidType::parse(b"936da01f-9abd-4d9d-80c7-02af85c822").is_none());
+    assert!(UuidType::parse(b"936da01f+9abd-4d9d-80c7-02af85c822a8").is_none());
+
+    let data_type = TypeFactory::instance().get("UUID")?;
+    assert_eq!(data_type.data_type_id(), TypeID::Uuid);
+    let field = data_type.to_arrow_field("u");
+    assert_eq!(from_arrow_field(&field).data_type_id(), TypeID::Uuid);
+
+    let mut deserializer = data_type.create_deserializer(2);
+    deserializer.de_text(text.as_bytes())?;
+    deserializer.de_default();
+    assert!(deserializer.de_text(b"databend").is_err());
+    let column = deserializer.finish_to_column();
+    let string_column: &StringColumn = Series::check_get(&column)?;
+    assert_eq!(string_column.get_data(0), bytes.as_slice());
+
+    let serializer = data_type.create_serializer();
+    assert_eq!(serializer.serialize_column(&column)?, vec![
+        text.to_string(),
+        "00000000-0000-0000-0000-000000000000".to_string()
+    ]);
+
+    Ok(())
+}
+
+#[test]
+fn test_ip_types() -> Result<()> {
+    assert_eq!(IPv4Type::parse(b"192.168.1.1"), Some(3232235777));
+    assert_eq!(IPv4Type::format(3232235777), "192.168.1.1");
+    assert!(IPv4Type::parse(b"192.168.1.256").is_none());
+    assert!(IPv4Type::parse(b"192.168.1").is_none());
+
+    let mapped = IPv6Type::parse(b"192.168.1.1").unwrap();
+    assert_eq!(mapped, IPv6Type::from_ipv4(3232235777));
+    assert_eq!(IPv6Type::to_ipv4(&mapped), Some(3232235777));
+    assert_eq!(IPv6Type::format(&mapped), "::ffff:192.168.1.1");
+    let bytes = IPv6Type::parse(b"2001:DB8::8a2e:370:7334").unwrap();
+    assert_eq!(IPv6Type::format(&bytes), "2001:db8::8a2e:370:7334");
+    assert_eq!(IPv6Type::to_ipv4(&bytes), None);
+    assert!(IPv6Type::parse(b"2001:db8::g").is_none());
+
+    // the addresses sort in the order of the values
+    assert!(IPv4Type::parse(b"9.0.0.1") < IPv4Type::parse(b"10.0.0.1"));
+    assert!(IPv6Type::parse(b"::ffff:9.0.0.1") < IPv6Type::parse(b"::ffff:10.0.0.1"));
+
+    for (name, type_id) in [("IPv4", TypeID::IPv4), ("IPv6", TypeID::IPv6)] {
+        let data_type = TypeFactory::instance().get(name)?;
+        assert_eq!(data_type.data_type_id(), type_id);
+        let field = data_type.to_arrow_field("ip");
+        assert_eq!(from_arrow_field(&field).data_type_id(), type_id);
+
+        let mut deserializer = data_type.create_deserializer(2);
+        deserializer.de_text(b"10.0.0.1")?;
+        deserializer.de_default();
+        assert!(deserializer.de_text(b"databend").is_err());
+        let column = deserializer.finish_to_column();
+
+        let serializer = data_type.create_serializer();
+        let expected = match type_id {
+            TypeID::IPv4 => vec!["10.0.0.1".to_string(), "0.0.0.0".to_string()],
+            _ => vec!["::ffff:10.0.0.1".to_string(), "::".to_string()],
+        };
+        assert_eq!(serializer.serialize_column(&column)?, expected);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_bitmap_type() -> Result<()> {
+    let bitmap = BitmapType::parse(b"3, 1,2,3").unwrap();
+    assert_eq!(bitmap.len(), 3);
+    let bytes = BitmapType::serialize(&bitmap);
+    assert_eq!(BitmapType::deserialize(&bytes)?, bitmap);
+    assert_eq!(BitmapType::format(&bytes), "1,2,3");
+    assert_eq!(BitmapType::parse(b"").unwrap().len(), 0);
+    assert!(BitmapType::parse(b"1,-2").is_none());
+    assert!(BitmapType::deserialize(b"databend").is_err());
+
+    let data_type = TypeFactory::instance().get("Bitmap")?;
+    assert_eq!(data_type.data_type_id(), TypeID::Bitmap);
+    let field = data_type.to_arrow_field("b");
+    assert_eq!(from_arrow_field(&field).data_type_id(), TypeID::Bitmap);
+
+    let mut deserializer = data_type.create_deserializer(2);
+    deserializer.de_text(b"5,4")?;
+    deserializer.de_default();
+    assert!(deserializer.de_text(b"a,b").is_err());
+    let column = deserializer.finish_to_column();
+
+    let serializer = data_type.create_serializer();
+    assert_eq!(serializer.serialize_column(&column)?, vec![
+        "4,5".to_string(),
+        "".to_string()
+    ]);
+
+    Ok(())
+}
+
+#[test]
+fn test_map_deserializer() -> Result<()> {
+    let data_type = MapType::arc(StringType::arc(), Int64Type::arc());
+
+    let mut deserializer = data_type.create_deserializer(3);
+    deserializer.de_json(&serde_json::json!({"a": 1, "b": 2}))?;
+    deserializer.de_default();
+    deserializer.de_text(br#"{"c": 3}"#)?;
+    assert!(deserializer.de_json(&serde_json::json!([1, 2])).is_err());
+    assert!(deserializer.de_binary(&mut &b"databend"[..]).is_err());
+    let column = deserializer.finish_to_column();
+
+    let serializer = data_type.create_serializer();
+    assert_eq!(serializer.serialize_column(&column)?, vec![
+        "{'a':1, 'b':2}".to_string(),
+        "{}".to_string(),
+        "{'c':3}".to_string(),
+    ]);
+
+    // A map extension type which isn't stored as the entries is read as its storage.
+    let storage = ArrowType::Extension("Map".to_owned(), Box::new(ArrowType::Int64), None);
+    assert_eq!(from_arrow_type(&storage).data_type_id(), TypeID::Int64);
+
+    Ok(())
+}
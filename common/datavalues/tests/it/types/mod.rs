@@ -13,5 +13,7 @@
 // limitations under the License.
 
 mod create_column;
+mod deserializations;
 mod serializations;
+mod type_coercion;
 mod viewer;
@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_datavalues::prelude::*;
+use common_datavalues::Tz;
 use common_exception::Result;
 use pretty_assertions::assert_eq;
 use serde_json::json;
@@ -59,6 +60,18 @@ fn test_serializers() -> Result<()> {
                 "1970-01-01 00:00:01".to_owned(),
             ],
         },
+        Test {
+            name: "datetime32_timezone",
+            data_type: DateTime32Type::arc(Some("Asia/Shanghai".to_string())),
+            value: DataValue::UInt64(1630320462),
+            column: Series::from_data(vec![1630320462u32, 1637117572u32, 1]),
+            val_str: "2021-08-30 18:47:42",
+            col_str: vec![
+                "2021-08-30 18:47:42".to_owned(),
+                "2021-11-17 10:52:52".to_owned(),
+                "1970-01-01 08:00:01".to_owned(),
+            ],
+        },
         Test {
             name: "date32",
             data_type: Date32Type::arc(),
@@ -71,6 +84,14 @@ fn test_serializers() -> Result<()> {
                 "1970-01-02".to_owned(),
             ],
         },
+        Test {
+            name: "decimal",
+            data_type: DecimalType::arc(10, 2),
+            value: DataValue::Int64(-12345),
+            column: Series::from_data(vec![12345i64, -5, 0]),
+            val_str: "-123.45",
+            col_str: vec!["123.45".to_owned(), "-0.05".to_owned(), "0.00".to_owned()],
+        },
         Test {
             name: "string",
             data_type: StringType::arc(),
@@ -122,6 +143,23 @@ fn test_serializers() -> Result<()> {
                 "('1970-01-02', 3)".to_owned(),
             ],
         },
+        Test {
+            name: "map",
+            data_type: MapType::arc(StringType::arc(), Int64Type::arc()),
+            value: DataValue::Array(vec![DataValue::Struct(vec![
+                DataValue::String(b"a".to_vec()),
+                DataValue::Int64(1),
+            ])]),
+            column: MapType::create(StringType::arc(), Int64Type::arc()).create_column(&[
+                DataValue::Array(vec![
+                    DataValue::Struct(vec![DataValue::String(b"a".to_vec()), DataValue::Int64(1)]),
+                    DataValue::Struct(vec![DataValue::String(b"b".to_vec()), DataValue::Int64(2)]),
+                ]),
+                DataValue::Array(vec![]),
+            ])?,
+            val_str: "{'a':1}",
+            col_str: vec!["{'a':1, 'b':2}".to_owned(), "{}".to_owned()],
+        },
         Test {
             name: "variant",
             data_type: VariantType::arc(),
@@ -180,6 +218,17 @@ fn test_serializers() -> Result<()> {
         assert_eq!(&result, expect);
     }
 
+    {
+        let data_type = MapType::create(StringType::arc(), Int64Type::arc());
+        let column =
+            data_type.create_column(&[DataValue::Array(vec![DataValue::Struct(vec![
+                DataValue::String(b"a".to_vec()),
+                DataValue::Int64(1),
+            ])])])?;
+        let result = data_type.create_serializer().serialize_json(&column)?;
+        assert_eq!(result, vec![json!({"a": 1})]);
+    }
+
     Ok(())
 }
 
@@ -191,3 +240,20 @@ fn test_convert_arrow() {
 
     assert_eq!(new_t.name(), t.name())
 }
+
+#[test]
+fn test_datetime_timezone() -> Result<()> {
+    let t = DateTime32Type::try_create(Some("Asia/Shanghai".to_string()))?;
+    assert_eq!(t.timezone()?, Tz::Asia__Shanghai);
+    assert_eq!(DateTime32Type::try_create(None)?.timezone()?, Tz::UTC);
+
+    let result = DateTime32Type::try_create(Some("Mars/Olympus".to_string()));
+    assert_eq!(result.unwrap_err().code(), 1006);
+    let result = DateTime64Type::try_create(3, Some("Mars/Olympus".to_string()));
+    assert_eq!(result.unwrap_err().code(), 1006);
+
+    // The timezone of a type which isn't checked is reported when it's used.
+    let t = DateTime32Type::create(Some("Mars/Olympus".to_string()));
+    assert_eq!(t.timezone().unwrap_err().code(), 1006);
+    Ok(())
+}
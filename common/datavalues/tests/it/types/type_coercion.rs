@@ -0,0 +1,102 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use pretty_assertions::assert_eq;
+
+fn timezone_of(data_type: &DataTypePtr) -> Option<String> {
+    if let Some(t) = data_type.as_any().downcast_ref::<DateTime32Type>() {
+        return t.tz().cloned();
+    }
+    if let Some(t) = data_type.as_any().downcast_ref::<DateTime64Type>() {
+        return t.tz().cloned();
+    }
+    None
+}
+
+#[test]
+fn test_timestamp_compare_coercion() -> Result<()> {
+    let shanghai = Some("Asia/Shanghai".to_string());
+    let tokyo = Some("Asia/Tokyo".to_string());
+
+    let tests: Vec<(DataTypePtr, DataTypePtr, TypeID, Option<String>)> = vec![
+        // the same timezone is kept
+        (
+            DateTime32Type::arc(shanghai.clone()),
+            DateTime32Type::arc(shanghai.clone()),
+            TypeID::DateTime32,
+            shanghai.clone(),
+        ),
+        // different timezones are compared in UTC, whichever side comes first
+        (
+            DateTime32Type::arc(shanghai.clone()),
+            DateTime32Type::arc(tokyo.clone()),
+            TypeID::DateTime32,
+            None,
+        ),
+        (
+            DateTime32Type::arc(tokyo.clone()),
+            DateTime32Type::arc(shanghai.clone()),
+            TypeID::DateTime32,
+            None,
+        ),
+        (
+            DateTime32Type::arc(shanghai.clone()),
+            DateTime32Type::arc(None),
+            TypeID::DateTime32,
+            None,
+        ),
+        (
+            DateTime64Type::arc(3, shanghai.clone()),
+            DateTime64Type::arc(6, tokyo),
+            TypeID::DateTime64,
+            None,
+        ),
+        // the dates are converted in the timezone of the timestamp
+        (
+            Date16Type::arc(),
+            DateTime32Type::arc(shanghai.clone()),
+            TypeID::DateTime32,
+            shanghai.clone(),
+        ),
+        (
+            DateTime32Type::arc(shanghai.clone()),
+            Date32Type::arc(),
+            TypeID::DateTime64,
+            shanghai.clone(),
+        ),
+        (
+            Date32Type::arc(),
+            DateTime64Type::arc(3, shanghai.clone()),
+            TypeID::DateTime64,
+            shanghai.clone(),
+        ),
+        // the strings are parsed in the timezone of the timestamp
+        (
+            StringType::arc(),
+            DateTime32Type::arc(shanghai.clone()),
+            TypeID::DateTime32,
+            shanghai,
+        ),
+    ];
+
+    for (lhs, rhs, type_id, tz) in tests {
+        let data_type = compare_coercion(&lhs, &rhs)?;
+        assert_eq!(data_type.data_type_id(), type_id, "{:?} {:?}", lhs, rhs);
+        assert_eq!(timezone_of(&data_type), tz, "{:?} {:?}", lhs, rhs);
+    }
+
+    Ok(())
+}
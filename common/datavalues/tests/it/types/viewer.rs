@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_datavalues::prelude::*;
 use common_exception::Result;
 use pretty_assertions::assert_eq;
@@ -68,3 +70,22 @@ fn test_constant_viewer() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_nullable_constant_viewer() -> Result<()> {
+    let ty: DataTypePtr = Arc::new(NullableType::create(Int16Type::arc()));
+    let column = ty.create_constant_column(&DataValue::Null, 1024)?;
+    assert!(column.is_const());
+    assert!(column.is_nullable());
+
+    let viewer = i16::try_create_viewer(&column)?;
+    assert_eq!(viewer.size(), 1024);
+    assert!(viewer.null_at(0));
+    assert!(viewer.null_at(1023));
+
+    let column = ty.create_constant_column(&DataValue::Int64(7), 1024)?;
+    let viewer = i16::try_create_viewer(&column)?;
+    assert!(!viewer.null_at(99));
+    assert_eq!(viewer.value_at(99), 7i16);
+    Ok(())
+}
@@ -139,6 +139,12 @@ build_exceptions! {
     // Tenant error codes.
     TenantIsEmpty(1101),
     IndexOutOfBounds(1102),
+
+    // Prepared statement error codes.
+    UnknownPreparedStatement(1103),
+
+    // Query governor error codes.
+    QueryScanLimitExceeded(1104),
 }
 
 // Metasvr errors [2001, 3000].
@@ -90,6 +90,32 @@ impl AggregateFunction for AggregateFunctionBasicAdaptor {
         self.inner.accumulate_row(place, columns, row)
     }
 
+    fn accumulate_sorted(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        if self.inner.convert_const_to_full() && columns.iter().any(|c| c.is_const()) {
+            let columns: Vec<ColumnRef> = columns.iter().map(|c| c.convert_full_column()).collect();
+            self.inner
+                .accumulate_sorted(place, &columns, validity, input_rows)
+        } else {
+            self.inner
+                .accumulate_sorted(place, columns, validity, input_rows)
+        }
+    }
+
+    fn support_retract(&self) -> bool {
+        self.inner.support_retract()
+    }
+
+    #[inline]
+    fn retract_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        self.inner.retract_row(place, columns, row)
+    }
+
     #[inline]
     fn serialize(&self, place: StateAddr, writer: &mut bytes::BytesMut) -> Result<()> {
         self.inner.serialize(place, writer)
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::alloc::Layout;
+use std::cmp;
 use std::fmt;
 use std::sync::Arc;
 
@@ -27,6 +28,8 @@ use crate::aggregates::AggregateFunction;
 use crate::aggregates::AggregateFunctionRef;
 use crate::aggregates::StateAddr;
 
+/// The prefix of the state counts the non null rows if the result is nullable, so the result
+/// becomes null again once all of them are retracted from a window frame.
 #[derive(Clone)]
 pub struct AggregateNullUnaryAdaptor<const NULLABLE_RESULT: bool> {
     nested: AggregateFunctionRef,
@@ -37,7 +40,7 @@ impl<const NULLABLE_RESULT: bool> AggregateNullUnaryAdaptor<NULLABLE_RESULT> {
     pub fn create(nested: AggregateFunctionRef) -> AggregateFunctionRef {
         let prefix_size = if NULLABLE_RESULT {
             let layout = nested.state_layout();
-            layout.align()
+            cmp::max(layout.align(), Layout::new::<u64>().align())
         } else {
             0
         };
@@ -48,28 +51,36 @@ impl<const NULLABLE_RESULT: bool> AggregateNullUnaryAdaptor<NULLABLE_RESULT> {
     }
 
     #[inline]
-    pub fn set_flag(place: StateAddr) {
+    pub fn add_rows(place: StateAddr, rows: u64) {
         if NULLABLE_RESULT {
-            let c = place.get::<u8>();
-            *c = 1;
+            let c = place.get::<u64>();
+            *c += rows;
+        }
+    }
+
+    #[inline]
+    pub fn remove_row(place: StateAddr) {
+        if NULLABLE_RESULT {
+            let c = place.get::<u64>();
+            *c -= 1;
         }
     }
 
     #[inline]
     pub fn init_flag(place: StateAddr) {
         if NULLABLE_RESULT {
-            let c = place.get::<u8>();
+            let c = place.get::<u64>();
             *c = 0;
         }
     }
 
     #[inline]
-    pub fn get_flag(place: StateAddr) -> u8 {
+    pub fn has_value(place: StateAddr) -> bool {
         if NULLABLE_RESULT {
-            let c = place.get::<u8>();
-            *c
+            let c = place.get::<u64>();
+            *c > 0
         } else {
-            1
+            true
         }
     }
 
@@ -100,7 +111,8 @@ impl<const NULLABLE_RESULT: bool> AggregateFunction for AggregateNullUnaryAdapto
     #[inline]
     fn state_layout(&self) -> Layout {
         let layout = self.nested.state_layout();
-        Layout::from_size_align(layout.size() + self.prefix_size, layout.align()).unwrap()
+        let align = cmp::max(layout.align(), self.prefix_size);
+        Layout::from_size_align(layout.size() + self.prefix_size, align).unwrap()
     }
 
     #[inline]
@@ -111,30 +123,17 @@ impl<const NULLABLE_RESULT: bool> AggregateFunction for AggregateNullUnaryAdapto
         validity: Option<&Bitmap>,
         input_rows: usize,
     ) -> Result<()> {
-        let mut validity = validity.cloned();
-        let col = &columns[0];
-        let (all_null, v) = col.validity();
-        validity = combine_validities(validity.as_ref(), v);
-        let not_null_columns = Series::remove_nullable(col);
-
-        self.nested.accumulate(
-            self.nested_place(place),
-            &[not_null_columns],
-            validity.as_ref(),
-            input_rows,
-        )?;
+        self.accumulate_with(place, columns, validity, input_rows, false)
+    }
 
-        if !all_null {
-            match validity {
-                Some(v) => {
-                    if v.null_count() != input_rows {
-                        Self::set_flag(place);
-                    }
-                }
-                None => Self::set_flag(place),
-            }
-        }
-        Ok(())
+    fn accumulate_sorted(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        self.accumulate_with(place, columns, validity, input_rows, true)
     }
 
     #[inline]
@@ -161,25 +160,50 @@ impl<const NULLABLE_RESULT: bool> AggregateFunction for AggregateNullUnaryAdapto
             match validity {
                 Some(v) => v.iter().zip(places.iter()).for_each(|(valid, place)| {
                     if valid {
-                        Self::set_flag(place.next(offset));
+                        Self::add_rows(place.next(offset), 1);
                     }
                 }),
                 None => places
                     .iter()
-                    .for_each(|place| Self::set_flag(place.next(offset))),
+                    .for_each(|place| Self::add_rows(place.next(offset), 1)),
             }
         }
         Ok(())
     }
 
-    /// we already have accumulate_keys, so we don't need to implement this
-    fn accumulate_row(&self, _place: StateAddr, _columns: &[ColumnRef], _row: usize) -> Result<()> {
-        unreachable!()
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let col = &columns[0];
+        if col.null_at(row) {
+            return Ok(());
+        }
+
+        let not_null_column = Series::remove_nullable(col);
+        self.nested
+            .accumulate_row(self.nested_place(place), &[not_null_column], row)?;
+        Self::add_rows(place, 1);
+        Ok(())
+    }
+
+    fn support_retract(&self) -> bool {
+        self.nested.support_retract()
+    }
+
+    fn retract_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let col = &columns[0];
+        if col.null_at(row) {
+            return Ok(());
+        }
+
+        let not_null_column = Series::remove_nullable(col);
+        self.nested
+            .retract_row(self.nested_place(place), &[not_null_column], row)?;
+        Self::remove_row(place);
+        Ok(())
     }
 
     fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
         if NULLABLE_RESULT {
-            let flag = Self::get_flag(place) == 1;
+            let flag = Self::has_value(place);
             writer.write_scalar(&flag)?;
         }
         self.nested.serialize(self.nested_place(place), writer)
@@ -189,7 +213,7 @@ impl<const NULLABLE_RESULT: bool> AggregateFunction for AggregateNullUnaryAdapto
         if NULLABLE_RESULT {
             let flag: bool = reader.read_scalar()?;
             if flag {
-                Self::set_flag(place);
+                Self::add_rows(place, 1);
             }
         }
 
@@ -197,13 +221,13 @@ impl<const NULLABLE_RESULT: bool> AggregateFunction for AggregateNullUnaryAdapto
     }
 
     fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
-        if Self::get_flag(place) == 0 {
+        if !Self::has_value(place) {
             // initial the state to remove the dirty stats
             self.init_state(place);
         }
 
-        if Self::get_flag(rhs) == 1 {
-            Self::set_flag(place);
+        if NULLABLE_RESULT {
+            Self::add_rows(place, *rhs.get::<u64>());
         }
         self.nested
             .merge(self.nested_place(place), self.nested_place(rhs))
@@ -212,7 +236,7 @@ impl<const NULLABLE_RESULT: bool> AggregateFunction for AggregateNullUnaryAdapto
     fn merge_result(&self, place: StateAddr, column: &mut dyn MutableColumn) -> Result<()> {
         if NULLABLE_RESULT {
             let builder: &mut MutableNullableColumn = Series::check_get_mutable_column(column)?;
-            if Self::get_flag(place) == 1 {
+            if Self::has_value(place) {
                 let inner = builder.inner_mut();
                 self.nested
                     .merge_result(self.nested_place(place), inner.as_mut())?;
@@ -230,6 +254,47 @@ impl<const NULLABLE_RESULT: bool> AggregateFunction for AggregateNullUnaryAdapto
     }
 }
 
+impl<const NULLABLE_RESULT: bool> AggregateNullUnaryAdaptor<NULLABLE_RESULT> {
+    fn accumulate_with(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+        sorted: bool,
+    ) -> Result<()> {
+        let mut validity = validity.cloned();
+        let col = &columns[0];
+        let (all_null, v) = col.validity();
+        validity = combine_validities(validity.as_ref(), v);
+        let not_null_columns = [Series::remove_nullable(col)];
+
+        let nested_place = self.nested_place(place);
+        match sorted {
+            true => self.nested.accumulate_sorted(
+                nested_place,
+                &not_null_columns,
+                validity.as_ref(),
+                input_rows,
+            )?,
+            false => self.nested.accumulate(
+                nested_place,
+                &not_null_columns,
+                validity.as_ref(),
+                input_rows,
+            )?,
+        }
+
+        if !all_null {
+            match validity {
+                Some(v) => Self::add_rows(place, (input_rows - v.null_count()) as u64),
+                None => Self::add_rows(place, input_rows as u64),
+            }
+        }
+        Ok(())
+    }
+}
+
 impl<const NULLABLE_RESULT: bool> fmt::Display for AggregateNullUnaryAdaptor<NULLABLE_RESULT> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "AggregateNullUnaryAdaptor")
@@ -55,6 +55,13 @@ where T: std::ops::AddAssign + PrimitiveType
         self.count += count;
     }
 
+    #[inline(always)]
+    fn sub_assume(&mut self, value: T)
+    where T: std::ops::SubAssign {
+        self.value -= value;
+        self.count -= 1;
+    }
+
     #[inline(always)]
     fn merge(&mut self, other: &Self) {
         self.value += other.value;
@@ -73,7 +80,7 @@ pub struct AggregateAvgFunction<T, SumT> {
 impl<T, SumT> AggregateFunction for AggregateAvgFunction<T, SumT>
 where
     T: PrimitiveType + AsPrimitive<SumT>,
-    SumT: PrimitiveType + std::ops::AddAssign,
+    SumT: PrimitiveType + std::ops::AddAssign + std::ops::SubAssign,
 {
     fn name(&self) -> &str {
         "AggregateAvgFunction"
@@ -150,6 +157,18 @@ where
         Ok(())
     }
 
+    fn support_retract(&self) -> bool {
+        true
+    }
+
+    fn retract_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let array: &PrimitiveColumn<T> = unsafe { Series::static_cast(&columns[0]) };
+        let v = unsafe { array.value_unchecked(row) };
+        let state = place.get::<AggregateAvgState<SumT>>();
+        state.sub_assume(v.as_());
+        Ok(())
+    }
+
     fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
         let state = place.get::<AggregateAvgState<SumT>>();
         serialize_into_buf(writer, state)
@@ -189,7 +208,7 @@ impl<T, SumT> fmt::Display for AggregateAvgFunction<T, SumT> {
 impl<T, SumT> AggregateAvgFunction<T, SumT>
 where
     T: PrimitiveType + AsPrimitive<SumT>,
-    SumT: PrimitiveType + std::ops::AddAssign,
+    SumT: PrimitiveType + std::ops::AddAssign + std::ops::SubAssign,
 {
     pub fn try_create(
         display_name: &str,
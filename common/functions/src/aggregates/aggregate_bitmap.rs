@@ -0,0 +1,185 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_datavalues::roaring::RoaringTreemap;
+use common_datavalues::with_match_primitive_type_id;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use num::ToPrimitive;
+
+use super::aggregate_function::AggregateFunction;
+use super::aggregate_function::AggregateFunctionRef;
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::StateAddr;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+
+#[derive(Default)]
+struct AggregateBitmapState {
+    bitmap: RoaringTreemap,
+}
+
+/// `bitmap_agg(x)` collects the integers of a group into a bitmap, negative integers are an error.
+#[derive(Clone)]
+pub struct AggregateBitmapAggFunction {
+    display_name: String,
+}
+
+impl AggregateBitmapAggFunction {
+    pub fn try_create(
+        display_name: &str,
+        _params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        assert_unary_arguments(display_name, arguments.len())?;
+        let data_type = arguments[0].data_type();
+        if !data_type.data_type_id().is_integer() {
+            return Err(ErrorCode::BadDataValueType(format!(
+                "{} does not support type '{:?}', expect an integer",
+                display_name, data_type
+            )));
+        }
+
+        Ok(Arc::new(AggregateBitmapAggFunction {
+            display_name: display_name.to_owned(),
+        }))
+    }
+
+    pub fn desc() -> AggregateFunctionDescription {
+        AggregateFunctionDescription::creator(Box::new(Self::try_create))
+    }
+
+    /// The integers of the argument column as u64.
+    fn values(&self, column: &ColumnRef) -> Result<Vec<u64>> {
+        let column = Series::remove_nullable(&column.convert_full_column());
+        with_match_primitive_type_id!(column.data_type_id(), |$T| {
+            let column: &PrimitiveColumn<$T> = Series::check_get(&column)?;
+            column
+                .iter()
+                .map(|v| {
+                    v.to_u64().ok_or_else(|| {
+                        ErrorCode::BadDataValueType(format!(
+                            "{} can not add the negative value {}",
+                            self.display_name, v
+                        ))
+                    })
+                })
+                .collect()
+        }, {
+            Err(ErrorCode::BadDataValueType(format!(
+                "{} does not support type '{:?}', expect an integer",
+                self.display_name,
+                column.data_type()
+            )))
+        })
+    }
+}
+
+impl AggregateFunction for AggregateBitmapAggFunction {
+    fn name(&self) -> &str {
+        "AggregateBitmapAggFunction"
+    }
+
+    fn return_type(&self) -> Result<DataTypePtr> {
+        Ok(BitmapType::arc())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregateBitmapState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateBitmapState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        let values = self.values(&columns[0])?;
+        match validity {
+            Some(validity) => {
+                for (value, valid) in values.into_iter().zip(validity.iter()) {
+                    if valid {
+                        state.bitmap.insert(value);
+                    }
+                }
+            }
+            None => state.bitmap.extend(values),
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[ColumnRef],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let values = self.values(&columns[0])?;
+        for (value, place) in values.into_iter().zip(places.iter()) {
+            let state = place.next(offset).get::<AggregateBitmapState>();
+            state.bitmap.insert(value);
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        let values = self.values(&columns[0].slice(row, 1))?;
+        state.bitmap.extend(values);
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        serialize_into_buf(writer, &BitmapType::serialize(&state.bitmap))
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        let bytes: Vec<u8> = deserialize_from_slice(reader)?;
+        state.bitmap = BitmapType::deserialize(&bytes)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        let rhs = rhs.get::<AggregateBitmapState>();
+        state.bitmap |= &rhs.bitmap;
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, array: &mut dyn MutableColumn) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        array.append_data_value(DataValue::String(BitmapType::serialize(&state.bitmap)))
+    }
+}
+
+impl fmt::Display for AggregateBitmapAggFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,232 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use bytes::BytesMut;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+
+use super::aggregate_function::AggregateFunction;
+use super::aggregate_function::AggregateFunctionRef;
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::StateAddr;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+
+struct AggregateBoolState {
+    value: bool,
+    has_value: bool,
+}
+
+impl AggregateBoolState {
+    #[inline]
+    fn reduce<const IS_AND: bool>(&mut self, value: bool) {
+        match IS_AND {
+            true => self.value &= value,
+            false => self.value |= value,
+        }
+        self.has_value = true;
+    }
+}
+
+/// bool_and if IS_AND, otherwise bool_or. The blocks are reduced with the bitmap kernels
+/// of the boolean column, so the function handles the nulls of its argument itself
+/// instead of being wrapped by the null adaptor.
+#[derive(Clone)]
+pub struct AggregateBoolFunction<const IS_AND: bool> {
+    display_name: String,
+    nullable: bool,
+}
+
+impl<const IS_AND: bool> AggregateBoolFunction<IS_AND> {
+    pub fn try_create(
+        display_name: &str,
+        _params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<Arc<dyn AggregateFunction>> {
+        assert_unary_arguments(display_name, arguments.len())?;
+        check_boolean_argument(display_name, &arguments[0])?;
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            nullable: false,
+        }))
+    }
+
+    pub fn desc() -> AggregateFunctionDescription {
+        AggregateFunctionDescription::creator(Box::new(Self::try_create))
+    }
+}
+
+pub(crate) fn check_boolean_argument(display_name: &str, argument: &DataField) -> Result<()> {
+    let data_type = remove_nullable(argument.data_type());
+    if !matches!(data_type.data_type_id(), TypeID::Boolean | TypeID::Null) {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "{} does not support type '{:?}'",
+            display_name, data_type
+        )));
+    }
+    Ok(())
+}
+
+/// The values of a boolean argument and the rows to aggregate, the nulls of a nullable
+/// argument are merged into the validity.
+pub(crate) fn boolean_argument<'a>(
+    column: &'a ColumnRef,
+    validity: Option<&Bitmap>,
+) -> Result<(&'a BooleanColumn, Option<Bitmap>)> {
+    let (_, nulls) = column.validity();
+    let validity = combine_validities(validity, nulls);
+    let column = match column.is_nullable() {
+        true => {
+            let column: &NullableColumn = Series::check_get(column)?;
+            Series::as_boolean(column.inner())?
+        }
+        false => Series::as_boolean(column)?,
+    };
+    Ok((column, validity))
+}
+
+impl<const IS_AND: bool> AggregateFunction for AggregateBoolFunction<IS_AND> {
+    fn name(&self) -> &str {
+        "AggregateBoolFunction"
+    }
+
+    fn return_type(&self) -> Result<DataTypePtr> {
+        match self.nullable {
+            true => Ok(wrap_nullable(&bool::to_data_type())),
+            false => Ok(bool::to_data_type()),
+        }
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateBoolState {
+            value: IS_AND,
+            has_value: false,
+        });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateBoolState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        let (column, validity) = boolean_argument(&columns[0], validity)?;
+        if validity.as_ref().map(|v| v.null_count()).unwrap_or(0) == input_rows {
+            return Ok(());
+        }
+
+        let value = match IS_AND {
+            true => column.all_true(validity.as_ref()),
+            false => column.any_true(validity.as_ref()),
+        };
+        place.get::<AggregateBoolState>().reduce::<IS_AND>(value);
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[ColumnRef],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let (column, validity) = boolean_argument(&columns[0], None)?;
+        let values = column.values().iter().zip(places.iter());
+        match validity {
+            Some(validity) => {
+                for ((value, place), valid) in values.zip(validity.iter()) {
+                    if valid {
+                        let state = place.next(offset).get::<AggregateBoolState>();
+                        state.reduce::<IS_AND>(value);
+                    }
+                }
+            }
+            None => {
+                for (value, place) in values {
+                    let state = place.next(offset).get::<AggregateBoolState>();
+                    state.reduce::<IS_AND>(value);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        if columns[0].null_at(row) {
+            return Ok(());
+        }
+        let (column, _) = boolean_argument(&columns[0], None)?;
+        let state = place.get::<AggregateBoolState>();
+        state.reduce::<IS_AND>(column.get_data(row));
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateBoolState>();
+        serialize_into_buf(writer, &state.value)?;
+        serialize_into_buf(writer, &state.has_value)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateBoolState>();
+        state.value = deserialize_from_slice(reader)?;
+        state.has_value = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let rhs = rhs.get::<AggregateBoolState>();
+        if rhs.has_value {
+            let state = place.get::<AggregateBoolState>();
+            state.reduce::<IS_AND>(rhs.value);
+        }
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, array: &mut dyn MutableColumn) -> Result<()> {
+        let state = place.get::<AggregateBoolState>();
+        match self.nullable && !state.has_value {
+            true => array.append_data_value(DataValue::Null),
+            false => array.append_data_value(DataValue::Boolean(state.value)),
+        }
+    }
+
+    fn get_own_null_adaptor(
+        &self,
+        _nested_function: AggregateFunctionRef,
+        _params: Vec<DataValue>,
+        _arguments: Vec<DataField>,
+    ) -> Result<Option<AggregateFunctionRef>> {
+        let mut f = self.clone();
+        f.nullable = true;
+        Ok(Some(Arc::new(f)))
+    }
+}
+
+impl<const IS_AND: bool> fmt::Display for AggregateBoolFunction<IS_AND> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
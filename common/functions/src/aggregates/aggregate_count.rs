@@ -82,12 +82,16 @@ impl AggregateFunction for AggregateCountFunction {
     fn accumulate(
         &self,
         place: StateAddr,
-        _columns: &[ColumnRef],
+        columns: &[ColumnRef],
         validity: Option<&Bitmap>,
         input_rows: usize,
     ) -> Result<()> {
         let state = place.get::<AggregateCountState>();
 
+        let validity = match (self.nullable, columns.first()) {
+            (true, Some(column)) => combine_validities(validity, column.validity().1),
+            _ => validity.cloned(),
+        };
         let nulls = match validity {
             Some(b) => b.null_count(),
             None => 0,
@@ -123,7 +127,7 @@ impl AggregateFunction for AggregateCountFunction {
             }
             None => {
                 for place in places {
-                    let state = place.get::<AggregateCountState>();
+                    let state = place.next(offset).get::<AggregateCountState>();
                     state.count += 1;
                 }
             }
@@ -132,12 +136,28 @@ impl AggregateFunction for AggregateCountFunction {
         Ok(())
     }
 
-    fn accumulate_row(&self, place: StateAddr, _columns: &[ColumnRef], _row: usize) -> Result<()> {
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        if columns.first().map_or(false, |c| c.null_at(row)) {
+            return Ok(());
+        }
         let state = place.get::<AggregateCountState>();
         state.count += 1;
         Ok(())
     }
 
+    fn support_retract(&self) -> bool {
+        true
+    }
+
+    fn retract_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        if columns.first().map_or(false, |c| c.null_at(row)) {
+            return Ok(());
+        }
+        let state = place.get::<AggregateCountState>();
+        state.count -= 1;
+        Ok(())
+    }
+
     fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
         let state = place.get::<AggregateCountState>();
         serialize_into_buf(writer, &state.count)
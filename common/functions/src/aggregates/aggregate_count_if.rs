@@ -0,0 +1,186 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use bytes::BytesMut;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_io::prelude::*;
+
+use super::aggregate_bool::boolean_argument;
+use super::aggregate_bool::check_boolean_argument;
+use super::aggregate_function::AggregateFunction;
+use super::aggregate_function::AggregateFunctionRef;
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::aggregate_function_factory::AggregateFunctionFeatures;
+use super::StateAddr;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+
+struct AggregateCountIfState {
+    count: u64,
+}
+
+/// Counts the true values of the boolean argument with a popcount over the bitmaps of each block,
+/// NULL is never counted.
+#[derive(Clone)]
+pub struct AggregateCountIfFunction {
+    display_name: String,
+}
+
+impl AggregateCountIfFunction {
+    pub fn try_create(
+        display_name: &str,
+        _params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<Arc<dyn AggregateFunction>> {
+        assert_unary_arguments(display_name, arguments.len())?;
+        check_boolean_argument(display_name, &arguments[0])?;
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> AggregateFunctionDescription {
+        let features = AggregateFunctionFeatures {
+            returns_default_when_only_null: true,
+            ..Default::default()
+        };
+        AggregateFunctionDescription::creator_with_features(Box::new(Self::try_create), features)
+    }
+}
+
+impl AggregateFunction for AggregateCountIfFunction {
+    fn name(&self) -> &str {
+        "AggregateCountIfFunction"
+    }
+
+    fn return_type(&self) -> Result<DataTypePtr> {
+        Ok(u64::to_data_type())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateCountIfState { count: 0 });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateCountIfState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let (column, validity) = boolean_argument(&columns[0], validity)?;
+        let state = place.get::<AggregateCountIfState>();
+        state.count += column.count_true(validity.as_ref()) as u64;
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[ColumnRef],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let (column, validity) = boolean_argument(&columns[0], None)?;
+        let values = column.values().iter().zip(places.iter());
+        match validity {
+            Some(validity) => {
+                for ((value, place), valid) in values.zip(validity.iter()) {
+                    let state = place.next(offset).get::<AggregateCountIfState>();
+                    state.count += (value && valid) as u64;
+                }
+            }
+            None => {
+                for (value, place) in values {
+                    let state = place.next(offset).get::<AggregateCountIfState>();
+                    state.count += value as u64;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        if columns[0].null_at(row) {
+            return Ok(());
+        }
+        let (column, _) = boolean_argument(&columns[0], None)?;
+        let state = place.get::<AggregateCountIfState>();
+        state.count += column.get_data(row) as u64;
+        Ok(())
+    }
+
+    fn support_retract(&self) -> bool {
+        true
+    }
+
+    fn retract_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        if columns[0].null_at(row) {
+            return Ok(());
+        }
+        let (column, _) = boolean_argument(&columns[0], None)?;
+        let state = place.get::<AggregateCountIfState>();
+        state.count -= column.get_data(row) as u64;
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateCountIfState>();
+        serialize_into_buf(writer, &state.count)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateCountIfState>();
+        state.count = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateCountIfState>();
+        let rhs = rhs.get::<AggregateCountIfState>();
+        state.count += rhs.count;
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, array: &mut dyn MutableColumn) -> Result<()> {
+        let builder: &mut MutablePrimitiveColumn<u64> = Series::check_get_mutable_column(array)?;
+        let state = place.get::<AggregateCountIfState>();
+        builder.append_value(state.count);
+        Ok(())
+    }
+
+    fn get_own_null_adaptor(
+        &self,
+        _nested_function: AggregateFunctionRef,
+        _params: Vec<DataValue>,
+        _arguments: Vec<DataField>,
+    ) -> Result<Option<AggregateFunctionRef>> {
+        Ok(Some(Arc::new(self.clone())))
+    }
+}
+
+impl fmt::Display for AggregateCountIfFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
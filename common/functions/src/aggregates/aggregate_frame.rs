@@ -0,0 +1,92 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bumpalo::Bump;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::AggregateFunctionRef;
+use super::StateAddr;
+
+/// Evaluates an aggregate function over the window frames of the rows of a partition.
+/// The frames only move forward, the rows entering a frame are accumulated and the rows leaving
+/// it are retracted, a function that can't retract rows is re-accumulated from the frame start.
+pub struct AggregateFrameEvaluator {
+    function: AggregateFunctionRef,
+    columns: Vec<ColumnRef>,
+    arena: Bump,
+    place: StateAddr,
+    // the frame accumulated into the state
+    start: usize,
+    end: usize,
+}
+
+impl AggregateFrameEvaluator {
+    pub fn create(function: AggregateFunctionRef, columns: &[ColumnRef]) -> Self {
+        let arena = Bump::new();
+        let place: StateAddr = arena.alloc_layout(function.state_layout()).into();
+        function.init_state(place);
+
+        Self {
+            function,
+            columns: columns.iter().map(|c| c.convert_full_column()).collect(),
+            arena,
+            place,
+            start: 0,
+            end: 0,
+        }
+    }
+
+    /// Moves the frame to the rows `[start, end)` and appends the result of the function.
+    pub fn evaluate(
+        &mut self,
+        start: usize,
+        end: usize,
+        result: &mut dyn MutableColumn,
+    ) -> Result<()> {
+        let rows = self.columns.first().map_or(end, |c| c.len());
+        if start < self.start || end < self.end || start > end || end > rows {
+            return Err(ErrorCode::LogicalError(format!(
+                "Invalid window frame [{}, {}) after [{}, {}) of {} rows",
+                start, end, self.start, self.end, rows
+            )));
+        }
+
+        if start >= self.end || (start > self.start && !self.function.support_retract()) {
+            // nothing of the state is kept, accumulate the frame from scratch
+            self.place = self.arena.alloc_layout(self.function.state_layout()).into();
+            self.function.init_state(self.place);
+            self.end = start;
+        } else {
+            for row in self.start..start {
+                self.function.retract_row(self.place, &self.columns, row)?;
+            }
+        }
+        self.start = start;
+
+        if end > self.end {
+            let rows = end - self.end;
+            let columns = self
+                .columns
+                .iter()
+                .map(|c| c.slice(self.end, rows))
+                .collect::<Vec<_>>();
+            self.function.accumulate(self.place, &columns, None, rows)?;
+            self.end = end;
+        }
+
+        self.function.merge_result(self.place, result)
+    }
+}
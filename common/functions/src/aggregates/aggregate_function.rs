@@ -19,6 +19,7 @@ use std::sync::Arc;
 use bytes::BytesMut;
 use common_arrow::arrow::bitmap::Bitmap;
 use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
 
 use super::StateAddr;
@@ -60,6 +61,32 @@ pub trait AggregateFunction: fmt::Display + Sync + Send {
 
     fn accumulate_row(&self, _place: StateAddr, _columns: &[ColumnRef], _row: usize) -> Result<()>;
 
+    // accumulate the rows that the caller has sorted by the first argument, e.g. the rows of a
+    // window ordered by it, so a function keeping its rows sorted can skip sorting them again
+    fn accumulate_sorted(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        self.accumulate(place, columns, validity, input_rows)
+    }
+
+    // whether the function can remove the rows it accumulated with `retract_row`, so a sliding
+    // window frame is evaluated incrementally instead of re-accumulating every frame
+    fn support_retract(&self) -> bool {
+        false
+    }
+
+    // remove a row previously accumulated into the state
+    fn retract_row(&self, _place: StateAddr, _columns: &[ColumnRef], _row: usize) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "Aggregate function {} doesn't support retracting rows",
+            self.name()
+        )))
+    }
+
     // serialize  the state into binary array
     fn serialize(&self, _place: StateAddr, _writer: &mut BytesMut) -> Result<()>;
 
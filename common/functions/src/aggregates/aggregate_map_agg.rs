@@ -0,0 +1,185 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::aggregate_function::AggregateFunction;
+use super::aggregate_function::AggregateFunctionRef;
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::StateAddr;
+use crate::aggregates::assert_binary_arguments;
+
+#[derive(Default, Serialize, Deserialize)]
+struct AggregateMapAggState {
+    entries: Vec<(DataValue, DataValue)>,
+    /// The position of every key in the entries, rebuilt after deserialization.
+    #[serde(skip)]
+    positions: HashMap<DataGroupValue, usize>,
+}
+
+impl AggregateMapAggState {
+    fn add(&mut self, key: DataValue, value: DataValue) -> Result<()> {
+        let group_key = DataGroupValue::try_from(&key)?;
+        match self.positions.get(&group_key) {
+            Some(position) => self.entries[*position].1 = value,
+            None => {
+                self.positions.insert(group_key, self.entries.len());
+                self.entries.push((key, value));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `map_agg(key, value)` collects the keys and values of a group into a map, the value of a
+/// repeated key is the last one, rows with a NULL key or value are ignored.
+#[derive(Clone)]
+pub struct AggregateMapAggFunction {
+    display_name: String,
+    return_type: DataTypePtr,
+}
+
+impl AggregateMapAggFunction {
+    pub fn try_create(
+        display_name: &str,
+        _params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        assert_binary_arguments(display_name, arguments.len())?;
+        let key_type = arguments[0].data_type();
+        if !matches!(
+            key_type.data_type_id().to_physical_type(),
+            PhysicalTypeID::Boolean
+                | PhysicalTypeID::String
+                | PhysicalTypeID::Int8
+                | PhysicalTypeID::Int16
+                | PhysicalTypeID::Int32
+                | PhysicalTypeID::Int64
+                | PhysicalTypeID::UInt8
+                | PhysicalTypeID::UInt16
+                | PhysicalTypeID::UInt32
+                | PhysicalTypeID::UInt64
+                | PhysicalTypeID::Float32
+                | PhysicalTypeID::Float64
+        ) {
+            return Err(ErrorCode::BadDataValueType(format!(
+                "{} does not support keys of type '{:?}'",
+                display_name, key_type
+            )));
+        }
+
+        let return_type = MapType::arc(key_type.clone(), arguments[1].data_type().clone());
+        Ok(Arc::new(AggregateMapAggFunction {
+            display_name: display_name.to_owned(),
+            return_type,
+        }))
+    }
+
+    pub fn desc() -> AggregateFunctionDescription {
+        AggregateFunctionDescription::creator(Box::new(Self::try_create))
+    }
+}
+
+impl AggregateFunction for AggregateMapAggFunction {
+    fn name(&self) -> &str {
+        "AggregateMapAggFunction"
+    }
+
+    fn return_type(&self) -> Result<DataTypePtr> {
+        Ok(self.return_type.clone())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregateMapAggState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateMapAggState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateMapAggState>();
+        for row in 0..input_rows {
+            if validity.map(|v| v.get_bit(row)).unwrap_or(true) {
+                state.add(columns[0].get(row), columns[1].get(row))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let state = place.get::<AggregateMapAggState>();
+        state.add(columns[0].get(row), columns[1].get(row))
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateMapAggState>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateMapAggState>();
+        let entries: Vec<(DataValue, DataValue)> =
+            deserialize_from_slice::<AggregateMapAggState>(reader)?.entries;
+
+        *state = AggregateMapAggState::default();
+        for (key, value) in entries {
+            state.add(key, value)?;
+        }
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateMapAggState>();
+        let rhs = rhs.get::<AggregateMapAggState>();
+        for (key, value) in rhs.entries.iter() {
+            state.add(key.clone(), value.clone())?;
+        }
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, array: &mut dyn MutableColumn) -> Result<()> {
+        let state = place.get::<AggregateMapAggState>();
+        let entries = state
+            .entries
+            .iter()
+            .map(|(key, value)| DataValue::Struct(vec![key.clone(), value.clone()]))
+            .collect();
+        array.append_data_value(DataValue::Array(entries))
+    }
+}
+
+impl fmt::Display for AggregateMapAggFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -108,6 +108,26 @@ where
         Ok(())
     }
 
+    // the min and the max of the sorted rows are the first or the last valid one
+    fn accumulate_sorted(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        let col: &<S as Scalar>::ColumnType = unsafe { Series::static_cast(&columns[0]) };
+        let valid = |row: &usize| validity.map_or(true, |v| v.get_bit(*row));
+
+        let state = place.get::<State>();
+        if let Some(first) = (0..input_rows).find(valid) {
+            let last = (first..input_rows).rev().find(valid).unwrap();
+            state.add(col.get_data(first));
+            state.add(col.get_data(last));
+        }
+        Ok(())
+    }
+
     fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
         let state = place.get::<State>();
         state.serialize(writer)
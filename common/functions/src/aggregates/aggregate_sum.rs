@@ -47,6 +47,12 @@ where T: std::ops::AddAssign + Serialize + DeserializeOwned + Copy + Clone
         self.value += other;
     }
 
+    #[inline(always)]
+    fn sub(&mut self, other: T)
+    where T: std::ops::SubAssign {
+        self.value -= other;
+    }
+
     fn serialize(&self, writer: &mut BytesMut) -> Result<()> {
         serialize_into_buf(writer, &self.value)
     }
@@ -61,6 +67,7 @@ where T: std::ops::AddAssign + Serialize + DeserializeOwned + Copy + Clone
 pub struct AggregateSumFunction<T, SumT> {
     display_name: String,
     _arguments: Vec<DataField>,
+    return_type: DataTypePtr,
     t: PhantomData<T>,
     sum_t: PhantomData<SumT>,
 }
@@ -68,14 +75,14 @@ pub struct AggregateSumFunction<T, SumT> {
 impl<T, SumT> AggregateFunction for AggregateSumFunction<T, SumT>
 where
     T: PrimitiveType + AsPrimitive<SumT>,
-    SumT: PrimitiveType + ToDataType + std::ops::AddAssign,
+    SumT: PrimitiveType + ToDataType + std::ops::AddAssign + std::ops::SubAssign,
 {
     fn name(&self) -> &str {
         "AggregateSumFunction"
     }
 
     fn return_type(&self) -> Result<DataTypePtr> {
-        Ok(SumT::to_data_type())
+        Ok(self.return_type.clone())
     }
 
     fn init_state(&self, place: StateAddr) {
@@ -154,6 +161,22 @@ where
         Ok(())
     }
 
+    fn support_retract(&self) -> bool {
+        true
+    }
+
+    fn retract_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let column = match columns[0].data_type().data_type_id() {
+            TypeID::Boolean => default_column_cast(&columns[0], &u8::to_data_type())?,
+            _ => columns[0].clone(),
+        };
+        let column: &PrimitiveColumn<T> = unsafe { Series::static_cast(&column) };
+        let state = place.get::<AggregateSumState<SumT>>();
+        let v: SumT = unsafe { column.value_unchecked(row).as_() };
+        state.sub(v);
+        Ok(())
+    }
+
     fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
         let state = place.get::<AggregateSumState<SumT>>();
         state.serialize(writer)
@@ -189,15 +212,25 @@ impl<T, SumT> fmt::Display for AggregateSumFunction<T, SumT> {
 impl<T, SumT> AggregateSumFunction<T, SumT>
 where
     T: PrimitiveType + AsPrimitive<SumT>,
-    SumT: PrimitiveType + ToDataType + std::ops::AddAssign,
+    SumT: PrimitiveType + ToDataType + std::ops::AddAssign + std::ops::SubAssign,
 {
     pub fn try_create(
         display_name: &str,
         arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        Self::try_create_with_return_type(display_name, arguments, SumT::to_data_type())
+    }
+
+    /// The return type must have the physical type of `SumT`.
+    pub fn try_create_with_return_type(
+        display_name: &str,
+        arguments: Vec<DataField>,
+        return_type: DataTypePtr,
     ) -> Result<AggregateFunctionRef> {
         Ok(Arc::new(Self {
             display_name: display_name.to_owned(),
             _arguments: arguments,
+            return_type,
             t: PhantomData,
             sum_t: PhantomData,
         }))
@@ -215,6 +248,15 @@ pub fn try_create_aggregate_sum_function(
     if data_type.data_type_id() == TypeID::Boolean {
         return AggregateSumFunction::<u8, u64>::try_create(display_name, arguments);
     }
+    if let Some(decimal) = data_type.as_any().downcast_ref::<DecimalType>() {
+        // the sum keeps the scale and takes the max precision
+        let return_type = DecimalType::arc(MAX_DECIMAL_PRECISION, decimal.scale());
+        return AggregateSumFunction::<i64, i64>::try_create_with_return_type(
+            display_name,
+            arguments,
+            return_type,
+        );
+    }
     with_match_primitive_type_id!(data_type.data_type_id(), |$T| {
         AggregateSumFunction::<$T, <$T as PrimitiveType>::LargestType>::try_create(
              display_name,
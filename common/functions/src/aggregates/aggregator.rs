@@ -22,9 +22,13 @@ use super::aggregate_min_max::aggregate_max_function_desc;
 use super::aggregate_min_max::aggregate_min_function_desc;
 use super::aggregate_stddev_pop::aggregate_stddev_pop_function_desc;
 use super::aggregate_window_funnel::aggregate_window_funnel_function_desc;
+use super::AggregateBitmapAggFunction;
+use super::AggregateBoolFunction;
 use super::AggregateCountFunction;
+use super::AggregateCountIfFunction;
 use super::AggregateFunctionFactory;
 use super::AggregateIfCombinator;
+use super::AggregateMapAggFunction;
 use crate::aggregates::aggregate_sum::aggregate_sum_function_desc;
 
 pub struct Aggregators;
@@ -34,6 +38,7 @@ impl Aggregators {
         // DatabendQuery always uses lowercase function names to get functions.
         factory.register("sum", aggregate_sum_function_desc());
         factory.register("count", AggregateCountFunction::desc());
+        factory.register("count_if", AggregateCountIfFunction::desc());
         factory.register("avg", aggregate_avg_function_desc());
         factory.register("min", aggregate_min_function_desc());
         factory.register("max", aggregate_max_function_desc());
@@ -49,7 +54,13 @@ impl Aggregators {
         factory.register("covar_pop", aggregate_covariance_population_desc());
 
         factory.register("windowFunnel", aggregate_window_funnel_function_desc());
+        factory.register("map_agg", AggregateMapAggFunction::desc());
         factory.register("uniq", AggregateDistinctCombinator::uniq_desc());
+
+        factory.register("bool_and", AggregateBoolFunction::<true>::desc());
+        factory.register("bool_or", AggregateBoolFunction::<false>::desc());
+
+        factory.register("bitmap_agg", AggregateBitmapAggFunction::desc());
     }
 
     pub fn register_combinator(factory: &mut AggregateFunctionFactory) {
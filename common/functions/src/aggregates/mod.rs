@@ -28,10 +28,15 @@ mod adaptors;
 mod macros;
 mod aggregate_arg_min_max;
 mod aggregate_avg;
+mod aggregate_bitmap;
+mod aggregate_bool;
 mod aggregate_combinator;
 mod aggregate_combinator_distinct;
 mod aggregate_combinator_if;
+mod aggregate_count_if;
 mod aggregate_covariance;
+mod aggregate_frame;
+mod aggregate_map_agg;
 mod aggregate_min_max;
 mod aggregate_null_result;
 mod aggregate_scalar_state;
@@ -41,16 +46,21 @@ mod aggregate_window_funnel;
 pub use adaptors::*;
 pub use aggregate_arg_min_max::AggregateArgMinMaxFunction;
 pub use aggregate_avg::AggregateAvgFunction;
+pub use aggregate_bitmap::AggregateBitmapAggFunction;
+pub use aggregate_bool::AggregateBoolFunction;
 pub use aggregate_combinator_distinct::AggregateDistinctCombinator;
 pub use aggregate_combinator_if::AggregateIfCombinator;
 pub use aggregate_count::AggregateCountFunction;
+pub use aggregate_count_if::AggregateCountIfFunction;
 pub use aggregate_covariance::AggregateCovarianceFunction;
+pub use aggregate_frame::AggregateFrameEvaluator;
 pub use aggregate_function::AggregateFunction;
 pub use aggregate_function::AggregateFunctionRef;
 pub use aggregate_function_factory::AggregateFunctionFactory;
 pub use aggregate_function_state::get_layout_offsets;
 pub use aggregate_function_state::StateAddr;
 pub use aggregate_function_state::StateAddrs;
+pub use aggregate_map_agg::AggregateMapAggFunction;
 pub use aggregate_min_max::AggregateMinMaxFunction;
 pub use aggregate_null_result::AggregateNullResultFunction;
 pub use aggregate_stddev_pop::AggregateStddevPopFunction;
@@ -0,0 +1,186 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp;
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::cast_column_field;
+use crate::scalars::scalar_binary_op;
+use crate::scalars::ArithmeticDivFunction;
+use crate::scalars::ArithmeticMinusFunction;
+use crate::scalars::ArithmeticMulFunction;
+use crate::scalars::ArithmeticPlusFunction;
+use crate::scalars::EvalContext;
+use crate::scalars::Function;
+use crate::scalars::FunctionFactory;
+use crate::scalars::Monotonicity;
+
+/// Arithmetic on decimals, plus, minus and multiply are exact and fail on overflow,
+/// the other operations and the ones with a float argument are evaluated on Float64.
+#[derive(Clone)]
+pub struct DecimalArithmeticFunction {
+    op: DataValueBinaryOperator,
+    /// The types the arguments are casted to before the evaluation.
+    arg_types: [DataTypePtr; 2],
+    result_type: DataTypePtr,
+    /// Evaluates the arguments casted to Float64, None for the exact decimal operations.
+    float_function: Option<Box<dyn Function>>,
+}
+
+impl DecimalArithmeticFunction {
+    pub fn try_create_func(
+        op: DataValueBinaryOperator,
+        args: &[&DataTypePtr],
+    ) -> Result<Box<dyn Function>> {
+        let common_type = decimal_coercion(args[0], args[1])?;
+        let function = match (op.clone(), common_type.data_type_id()) {
+            (DataValueBinaryOperator::Plus | DataValueBinaryOperator::Minus, TypeID::Decimal) => {
+                let common: &DecimalType = common_type.as_any().downcast_ref().unwrap();
+                let precision = cmp::min(common.precision() + 1, MAX_DECIMAL_PRECISION);
+                let result_type = DecimalType::arc(precision, common.scale());
+                Self {
+                    op,
+                    arg_types: [common_type.clone(), common_type.clone()],
+                    result_type,
+                    float_function: None,
+                }
+            }
+            (DataValueBinaryOperator::Mul, TypeID::Decimal) => {
+                // the decimal with the digits of each argument
+                let lhs_type = decimal_coercion(args[0], args[0])?;
+                let rhs_type = decimal_coercion(args[1], args[1])?;
+                let lhs: &DecimalType = lhs_type.as_any().downcast_ref().unwrap();
+                let rhs: &DecimalType = rhs_type.as_any().downcast_ref().unwrap();
+
+                let scale = lhs.scale() + rhs.scale();
+                if scale > MAX_DECIMAL_PRECISION {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "The scale of ({:?}) {} ({:?}) exceeds the max precision {}",
+                        args[0], op, args[1], MAX_DECIMAL_PRECISION
+                    )));
+                }
+                let precision = cmp::min(lhs.precision() + rhs.precision(), MAX_DECIMAL_PRECISION);
+                Self {
+                    op,
+                    arg_types: [lhs_type.clone(), rhs_type.clone()],
+                    result_type: DecimalType::arc(cmp::max(precision, scale), scale),
+                    float_function: None,
+                }
+            }
+            _ => {
+                let float_type = Float64Type::arc();
+                let float_function =
+                    FunctionFactory::instance().get(op.to_string(), &[&float_type, &float_type])?;
+                Self {
+                    op,
+                    arg_types: [float_type.clone(), float_type.clone()],
+                    result_type: float_function.return_type(&[&float_type, &float_type])?,
+                    float_function: Some(float_function),
+                }
+            }
+        };
+        Ok(Box::new(function))
+    }
+}
+
+#[inline]
+fn check_decimal(result: &DecimalType, v: Option<i128>, ctx: &mut EvalContext) -> i64 {
+    match v.and_then(|v| result.check_value(v)) {
+        Some(v) => v,
+        None => {
+            ctx.set_error(ErrorCode::Overflow(format!(
+                "Decimal overflow, the result doesn't fit in {:?}",
+                result
+            )));
+            0
+        }
+    }
+}
+
+impl Function for DecimalArithmeticFunction {
+    fn name(&self) -> &str {
+        "DecimalArithmeticFunction"
+    }
+
+    fn return_type(&self, _args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        Ok(self.result_type.clone())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let columns = columns
+            .iter()
+            .zip(self.arg_types.iter())
+            .map(|(column, arg_type)| {
+                let casted = match column.data_type() == arg_type {
+                    true => column.column().clone(),
+                    false => cast_column_field(column, arg_type)?,
+                };
+                Ok(ColumnWithField::new(
+                    casted,
+                    DataField::new(column.field().name(), arg_type.clone()),
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if let Some(float_function) = &self.float_function {
+            return float_function.eval(&columns, input_rows);
+        }
+
+        let result: &DecimalType = self.result_type.as_any().downcast_ref().unwrap();
+        let (lhs, rhs) = (columns[0].column(), columns[1].column());
+        let ctx = &mut EvalContext::default();
+        let col = match self.op {
+            DataValueBinaryOperator::Plus => scalar_binary_op::<i64, i64, i64, _>(
+                lhs,
+                rhs,
+                |l, r, ctx| check_decimal(result, (l as i128).checked_add(r as i128), ctx),
+                ctx,
+            ),
+            DataValueBinaryOperator::Minus => scalar_binary_op::<i64, i64, i64, _>(
+                lhs,
+                rhs,
+                |l, r, ctx| check_decimal(result, (l as i128).checked_sub(r as i128), ctx),
+                ctx,
+            ),
+            _ => scalar_binary_op::<i64, i64, i64, _>(
+                lhs,
+                rhs,
+                |l, r, ctx| check_decimal(result, (l as i128).checked_mul(r as i128), ctx),
+                ctx,
+            ),
+        }?;
+        Ok(Arc::new(col))
+    }
+
+    fn get_monotonicity(&self, args: &[Monotonicity]) -> Result<Monotonicity> {
+        match self.op {
+            DataValueBinaryOperator::Plus => ArithmeticPlusFunction::get_monotonicity(args),
+            DataValueBinaryOperator::Minus => ArithmeticMinusFunction::get_monotonicity(args),
+            DataValueBinaryOperator::Mul => ArithmeticMulFunction::get_monotonicity(args),
+            DataValueBinaryOperator::Div => ArithmeticDivFunction::get_monotonicity(args),
+            _ => Ok(Monotonicity::default()),
+        }
+    }
+}
+
+impl fmt::Display for DecimalArithmeticFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.op)
+    }
+}
@@ -24,6 +24,7 @@ use num::traits::AsPrimitive;
 use num_traits::WrappingSub;
 
 use crate::scalars::BinaryArithmeticFunction;
+use crate::scalars::DecimalArithmeticFunction;
 use crate::scalars::EvalContext;
 use crate::scalars::Function;
 use crate::scalars::FunctionFactory;
@@ -49,6 +50,62 @@ where
     l.as_().wrapping_sub(&r.as_())
 }
 
+#[inline]
+fn is_date_time(type_id: TypeID) -> bool {
+    matches!(type_id, TypeID::DateTime32 | TypeID::DateTime64)
+}
+
+// The nanoseconds of a tick of the timestamp type.
+fn nanos_per_tick(data_type: &DataTypePtr) -> i128 {
+    match data_type.as_any().downcast_ref::<DateTime64Type>() {
+        Some(datetime) => 10_i128.pow(9 - datetime.precision() as u32),
+        None => 1_000_000_000,
+    }
+}
+
+#[inline]
+fn seconds_between(l: i64, l_nanos: i128, r: i64, r_nanos: i128) -> i64 {
+    ((l as i128 * l_nanos - r as i128 * r_nanos) / 1_000_000_000) as i64
+}
+
+// timestamp - timestamp, the result is an interval of seconds
+fn datetime_diff_function(
+    op: DataValueBinaryOperator,
+    args: &[&DataTypePtr],
+) -> Result<Box<dyn Function>> {
+    let result_type = IntervalType::arc(IntervalKind::Second);
+    let (l_nanos, r_nanos) = (nanos_per_tick(args[0]), nanos_per_tick(args[1]));
+
+    match (args[0].data_type_id(), args[1].data_type_id()) {
+        (TypeID::DateTime32, TypeID::DateTime32) => {
+            BinaryArithmeticFunction::<u32, u32, i64, _>::try_create_func(
+                op,
+                result_type,
+                |l: u32, r: u32, _ctx: &mut EvalContext| l as i64 - r as i64,
+            )
+        }
+        (TypeID::DateTime32, _) => BinaryArithmeticFunction::<u32, i64, i64, _>::try_create_func(
+            op,
+            result_type,
+            move |l: u32, r: i64, _ctx: &mut EvalContext| {
+                seconds_between(l as i64, l_nanos, r, r_nanos)
+            },
+        ),
+        (_, TypeID::DateTime32) => BinaryArithmeticFunction::<i64, u32, i64, _>::try_create_func(
+            op,
+            result_type,
+            move |l: i64, r: u32, _ctx: &mut EvalContext| {
+                seconds_between(l, l_nanos, r as i64, r_nanos)
+            },
+        ),
+        _ => BinaryArithmeticFunction::<i64, i64, i64, _>::try_create_func(
+            op,
+            result_type,
+            move |l: i64, r: i64, _ctx: &mut EvalContext| seconds_between(l, l_nanos, r, r_nanos),
+        ),
+    }
+}
+
 pub struct ArithmeticMinusFunction;
 
 impl ArithmeticMinusFunction {
@@ -57,9 +114,18 @@ impl ArithmeticMinusFunction {
         args: &[&DataTypePtr],
     ) -> Result<Box<dyn Function>> {
         let op = DataValueBinaryOperator::Minus;
+
+        if args[0].data_type_id().is_decimal() || args[1].data_type_id().is_decimal() {
+            return DecimalArithmeticFunction::try_create_func(op, args);
+        }
+
         let left_type = args[0].data_type_id();
         let right_type = args[1].data_type_id();
 
+        if is_date_time(left_type) && is_date_time(right_type) {
+            return datetime_diff_function(op, args);
+        }
+
         if left_type.is_date_or_date_time() {
             return with_match_date_type_error!(left_type, |$T| {
                 with_match_primitive_type_id!(right_type, |$D| {
@@ -0,0 +1,43 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::array_creator::ArrayCreatorFunction;
+use crate::scalars::FunctionFactory;
+
+pub struct ArrayFunction;
+
+impl ArrayFunction {
+    pub fn register(factory: &mut FunctionFactory) {
+        factory.register("array", ArrayCreatorFunction::desc());
+    }
+}
+
+pub(crate) fn check_array_type<'a>(
+    name: &str,
+    data_type: &'a DataTypePtr,
+) -> Result<&'a ArrayType> {
+    data_type
+        .as_any()
+        .downcast_ref::<ArrayType>()
+        .ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "Illegal type {:?} of the first argument of function {}, must be an array",
+                data_type, name
+            ))
+        })
+}
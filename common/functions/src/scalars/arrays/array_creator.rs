@@ -0,0 +1,81 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::cast_column_field;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// `array(e1, e2, ...)`, also written as `[e1, e2, ...]`, creates an array of the common
+/// type of the elements.
+#[derive(Clone)]
+pub struct ArrayCreatorFunction {
+    display_name: String,
+}
+
+impl ArrayCreatorFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayCreatorFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .disable_passthrough_null()
+                .variadic_arguments(1, usize::MAX),
+        )
+    }
+}
+
+impl Function for ArrayCreatorFunction {
+    fn name(&self) -> &str {
+        &self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        let types = args.iter().map(|t| (*t).clone()).collect::<Vec<_>>();
+        Ok(ArrayType::arc(aggregate_types(&types)?))
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let types = columns
+            .iter()
+            .map(|c| c.data_type().clone())
+            .collect::<Vec<_>>();
+        let inner_type = aggregate_types(&types)?;
+        let elements = columns
+            .iter()
+            .map(|c| cast_column_field(c, &inner_type))
+            .collect::<Result<Vec<_>>>()?;
+
+        let arrays = (0..input_rows)
+            .map(|row| DataValue::Array(elements.iter().map(|c| c.get(row)).collect()))
+            .collect::<Vec<_>>();
+        ArrayType::create(inner_type).create_column(&arrays)
+    }
+}
+
+impl fmt::Display for ArrayCreatorFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use super::array::check_array_type;
+use crate::scalars::Function;
+
+/// `get(array, index)`, also written as `array[index]`, returns the element at the index
+/// starting from 0, or NULL if the index is out of range. It's registered through
+/// `GetFunction` like the map and variant lookups.
+#[derive(Clone)]
+pub struct ArrayGetFunction {
+    display_name: String,
+}
+
+impl ArrayGetFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayGetFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+}
+
+impl Function for ArrayGetFunction {
+    fn name(&self) -> &str {
+        &self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        let array_type = check_array_type(&self.display_name, args[0])?;
+        Ok(wrap_nullable(array_type.inner_type()))
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let column = columns[0].column().convert_full_column();
+        let array: &ArrayColumn = Series::check_get(&column)?;
+        let offsets = array.offsets();
+        let indexes = columns[1].column();
+
+        let result_type = self.return_type(&[columns[0].data_type()])?;
+        let missing = match result_type.is_nullable() {
+            true => DataValue::Null,
+            false => result_type.default_value(),
+        };
+        let values = (0..input_rows)
+            .map(|row| {
+                let index = match indexes.get(row) {
+                    DataValue::UInt64(v) => Some(v as usize),
+                    DataValue::Int64(v) if v >= 0 => Some(v as usize),
+                    _ => None,
+                };
+                let (start, end) = (offsets[row] as usize, offsets[row + 1] as usize);
+                match index {
+                    Some(index) if start + index < end => array.values().get(start + index),
+                    _ => missing.clone(),
+                }
+            })
+            .collect::<Vec<_>>();
+        result_type.create_column(&values)
+    }
+}
+
+impl fmt::Display for ArrayGetFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -0,0 +1,42 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::bitmap_count::BitmapCountFunction;
+use super::bitmap_logic::BitmapAndFunction;
+use super::bitmap_logic::BitmapOrFunction;
+use crate::scalars::FunctionFactory;
+
+pub struct BitmapFunction;
+
+impl BitmapFunction {
+    pub fn register(factory: &mut FunctionFactory) {
+        factory.register("bitmap_count", BitmapCountFunction::desc());
+        factory.register("bitmap_and", BitmapAndFunction::desc());
+        factory.register("bitmap_or", BitmapOrFunction::desc());
+    }
+}
+
+pub(crate) fn check_bitmap_type(name: &str, data_type: &DataTypePtr) -> Result<()> {
+    match data_type.data_type_id() {
+        TypeID::Bitmap => Ok(()),
+        _ => Err(ErrorCode::IllegalDataType(format!(
+            "Illegal type {:?} of argument of function {}, must be a bitmap",
+            data_type, name
+        ))),
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_datavalues::roaring::RoaringTreemap;
+use common_exception::Result;
+
+use super::bitmap::check_bitmap_type;
+use crate::scalars::scalar_binary_op;
+use crate::scalars::EvalContext;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// The set operation of two bitmaps.
+#[derive(Clone, Copy)]
+enum BitmapLogicOp {
+    And,
+    Or,
+}
+
+/// `bitmap_and(a, b)` and `bitmap_or(a, b)` return the intersection and the union of two bitmaps.
+#[derive(Clone)]
+pub struct BitmapLogicFunction {
+    display_name: String,
+    op: BitmapLogicOp,
+}
+
+impl BitmapLogicFunction {
+    fn desc(op: BitmapLogicOp) -> FunctionDescription {
+        FunctionDescription::creator(Box::new(move |display_name: &str| {
+            let function: Box<dyn Function> = Box::new(BitmapLogicFunction {
+                display_name: display_name.to_string(),
+                op,
+            });
+            Ok(function)
+        }))
+        .features(FunctionFeatures::default().deterministic().num_arguments(2))
+    }
+
+    fn apply(&self, lhs: &[u8], rhs: &[u8]) -> Result<Vec<u8>> {
+        let lhs = BitmapType::deserialize(lhs)?;
+        let rhs = BitmapType::deserialize(rhs)?;
+        let bitmap: RoaringTreemap = match self.op {
+            BitmapLogicOp::And => lhs & rhs,
+            BitmapLogicOp::Or => lhs | rhs,
+        };
+        Ok(BitmapType::serialize(&bitmap))
+    }
+}
+
+pub struct BitmapAndFunction;
+
+impl BitmapAndFunction {
+    pub fn desc() -> FunctionDescription {
+        BitmapLogicFunction::desc(BitmapLogicOp::And)
+    }
+}
+
+pub struct BitmapOrFunction;
+
+impl BitmapOrFunction {
+    pub fn desc() -> FunctionDescription {
+        BitmapLogicFunction::desc(BitmapLogicOp::Or)
+    }
+}
+
+impl Function for BitmapLogicFunction {
+    fn name(&self) -> &str {
+        &self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        check_bitmap_type(&self.display_name, args[0])?;
+        check_bitmap_type(&self.display_name, args[1])?;
+        Ok(BitmapType::arc())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
+        let col = scalar_binary_op::<Vu8, Vu8, Vu8, _>(
+            columns[0].column(),
+            columns[1].column(),
+            |lhs, rhs, ctx| match self.apply(lhs, rhs) {
+                Ok(bytes) => bytes,
+                Err(e) => {
+                    ctx.set_error(e);
+                    Vec::new()
+                }
+            },
+            &mut EvalContext::default(),
+        )?;
+        Ok(Arc::new(col))
+    }
+}
+
+impl fmt::Display for BitmapLogicFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -31,20 +31,27 @@ use crate::scalars::assert_string;
 use crate::scalars::cast_column_field;
 use crate::scalars::primitive_simd_op_boolean;
 use crate::scalars::scalar_binary_op;
+use crate::scalars::ComparisonBetweenFunction;
 use crate::scalars::ComparisonEqFunction;
 use crate::scalars::ComparisonGtEqFunction;
 use crate::scalars::ComparisonGtFunction;
+use crate::scalars::ComparisonILikeFunction;
+use crate::scalars::ComparisonIsDistinctFromFunction;
 use crate::scalars::ComparisonLikeFunction;
 use crate::scalars::ComparisonLtEqFunction;
 use crate::scalars::ComparisonLtFunction;
+use crate::scalars::ComparisonNotBetweenFunction;
 use crate::scalars::ComparisonNotEqFunction;
+use crate::scalars::ComparisonNotILikeFunction;
 use crate::scalars::ComparisonNotLikeFunction;
 use crate::scalars::ComparisonNotRegexpFunction;
+use crate::scalars::ComparisonNullSafeEqFunction;
 use crate::scalars::ComparisonRegexpFunction;
 use crate::scalars::EvalContext;
 use crate::scalars::Function;
 use crate::scalars::FunctionFactory;
 use crate::scalars::FunctionFeatures;
+use crate::scalars::TupleComparisonFunction;
 use crate::scalars::TypedFunctionDescription;
 
 #[derive(Clone)]
@@ -62,8 +69,24 @@ impl ComparisonFunction {
         factory.register_typed(">=", ComparisonGtEqFunction::desc("<"));
         factory.register_typed("!=", ComparisonNotEqFunction::desc("="));
         factory.register_typed("<>", ComparisonNotEqFunction::desc("="));
+        factory.register_typed(
+            "<=>",
+            ComparisonNullSafeEqFunction::desc("is distinct from"),
+        );
+        factory.register_typed(
+            "is not distinct from",
+            ComparisonNullSafeEqFunction::desc("is distinct from"),
+        );
+        factory.register_typed(
+            "is distinct from",
+            ComparisonIsDistinctFromFunction::desc("<=>"),
+        );
+        factory.register_typed("between", ComparisonBetweenFunction::desc("not between"));
+        factory.register_typed("not between", ComparisonNotBetweenFunction::desc("between"));
         factory.register_typed("like", ComparisonLikeFunction::desc("not like"));
         factory.register_typed("not like", ComparisonNotLikeFunction::desc("like"));
+        factory.register_typed("ilike", ComparisonILikeFunction::desc("not ilike"));
+        factory.register_typed("not ilike", ComparisonNotILikeFunction::desc("ilike"));
         factory.register_typed("regexp", ComparisonRegexpFunction::desc("not regexp"));
         factory.register_typed("not regexp", ComparisonNotRegexpFunction::desc("regexp"));
         factory.register_typed("rlike", ComparisonRegexpFunction::desc("not regexp"));
@@ -108,14 +131,22 @@ pub struct ComparisonFunctionCreator<T> {
 
 impl<T: ComparisonImpl> ComparisonFunctionCreator<T> {
     pub fn try_create_func(display_name: &str, args: &[&DataTypePtr]) -> Result<Box<dyn Function>> {
-        // expect array & struct
-        let has_array_struct = args
-            .iter()
-            .any(|arg| matches!(arg.data_type_id(), TypeID::Struct | TypeID::Array));
+        // (a, b) < (1, 2)
+        if args.iter().all(|arg| arg.data_type_id() == TypeID::Struct) {
+            return TupleComparisonFunction::try_create_func(display_name, args);
+        }
+
+        // expect array & struct & map
+        let has_array_struct = args.iter().any(|arg| {
+            matches!(
+                arg.data_type_id(),
+                TypeID::Struct | TypeID::Array | TypeID::Map
+            )
+        });
 
         if has_array_struct {
             return Err(ErrorCode::BadArguments(format!(
-                "Illegal types {:?} of argument of function {}, can not be struct or array",
+                "Illegal types {:?} of argument of function {}, can not be struct, array or map",
                 args, display_name
             )));
         }
@@ -133,7 +164,7 @@ impl<T: ComparisonImpl> ComparisonFunctionCreator<T> {
                         let func = Arc::new(ComparisonBooleanImpl::<T::BooleanSimd>::new());
                         ComparisonFunction::try_create_func(display_name, func)
                     },
-                    TypeID::String => {
+                    TypeID::String | TypeID::Binary | TypeID::Uuid | TypeID::IPv6 => {
                         let func = Arc::new(ComparisonScalarImpl::<Vu8, Vu8, _>::new(T::eval_binary));
                         ComparisonFunction::try_create_func(display_name, func)
                     },
@@ -155,6 +186,12 @@ impl<T: ComparisonImpl> ComparisonFunctionCreator<T> {
         }
 
         let least_supertype = compare_coercion(args[0], args[1])?;
+        if least_supertype.data_type_id().to_physical_type() == PhysicalTypeID::String {
+            // e.g. a UUID or an IP compared with a string, compare the bytes of the parsed values
+            let func = Arc::new(ComparisonBytesImpl::new(least_supertype, T::eval_binary));
+            return ComparisonFunction::try_create_func(display_name, func);
+        }
+
         with_match_physical_primitive_type_error!(least_supertype.data_type_id().to_physical_type(), |$T| {
             let func = Arc::new(ComparisonPrimitiveImpl::<$T, _>::new(least_supertype, true, T::eval_simd::<$T>));
             ComparisonFunction::try_create_func(display_name, func)
@@ -259,6 +296,44 @@ where
     }
 }
 
+/// Compares the bytes of the arguments after casting them to the type they are compared in.
+pub struct ComparisonBytesImpl<F> {
+    least_supertype: DataTypePtr,
+    func: F,
+}
+
+impl<F> ComparisonBytesImpl<F>
+where F: Fn(&[u8], &[u8], &mut EvalContext) -> bool
+{
+    pub fn new(least_supertype: DataTypePtr, func: F) -> Self {
+        Self {
+            least_supertype,
+            func,
+        }
+    }
+}
+
+impl<F> ComparisonExpression for ComparisonBytesImpl<F>
+where F: Fn(&[u8], &[u8], &mut EvalContext) -> bool + Send + Sync + Clone
+{
+    fn eval(&self, l: &ColumnWithField, r: &ColumnWithField) -> Result<BooleanColumn> {
+        let lhs = match l.data_type() == &self.least_supertype {
+            true => l.column().clone(),
+            false => cast_column_field(l, &self.least_supertype)?,
+        };
+        let rhs = match r.data_type() == &self.least_supertype {
+            true => r.column().clone(),
+            false => cast_column_field(r, &self.least_supertype)?,
+        };
+        scalar_binary_op::<Vu8, Vu8, _, _>(
+            &lhs,
+            &rhs,
+            self.func.clone(),
+            &mut EvalContext::default(),
+        )
+    }
+}
+
 pub struct ComparisonPrimitiveImpl<T: PrimitiveType, F> {
     least_supertype: DataTypePtr,
     need_cast: bool,
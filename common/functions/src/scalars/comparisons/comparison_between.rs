@@ -0,0 +1,153 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_datavalues::type_coercion::compare_coercion;
+use common_datavalues::with_match_physical_primitive_type;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::cast_column_field;
+use crate::scalars::Function;
+use crate::scalars::FunctionFeatures;
+use crate::scalars::TypedFunctionDescription;
+
+pub type ComparisonBetweenFunction = ComparisonBetweenImpl<false>;
+pub type ComparisonNotBetweenFunction = ComparisonBetweenImpl<true>;
+
+/// `x BETWEEN low AND high`, it's `low <= x AND x <= high` with both bounds checked in one
+/// pass over the columns. Negated, it's `x NOT BETWEEN low AND high`.
+#[derive(Clone)]
+pub struct ComparisonBetweenImpl<const NEGATED: bool> {
+    display_name: String,
+    /// The type the arguments are compared in.
+    least_supertype: DataTypePtr,
+}
+
+impl<const NEGATED: bool> ComparisonBetweenImpl<NEGATED> {
+    pub fn try_create_func(display_name: &str, args: &[&DataTypePtr]) -> Result<Box<dyn Function>> {
+        let least_supertype = compare_coercion(args[0], args[1])?;
+        let least_supertype = compare_coercion(&least_supertype, args[2])?;
+        if matches!(
+            least_supertype.data_type_id().to_physical_type(),
+            PhysicalTypeID::Null
+                | PhysicalTypeID::Nullable
+                | PhysicalTypeID::Array
+                | PhysicalTypeID::Struct
+                | PhysicalTypeID::Variant
+        ) {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Can not compare {:?} between {:?} and {:?}",
+                args[0], args[1], args[2]
+            )));
+        }
+
+        Ok(Box::new(Self {
+            display_name: display_name.to_string(),
+            least_supertype,
+        }))
+    }
+
+    pub fn desc(negative_name: &str) -> TypedFunctionDescription {
+        TypedFunctionDescription::creator(Box::new(Self::try_create_func)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .negative_function(negative_name)
+                .bool_function()
+                .num_arguments(3),
+        )
+    }
+}
+
+impl<const NEGATED: bool> Function for ComparisonBetweenImpl<NEGATED> {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn return_type(&self, _args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        Ok(BooleanType::arc())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let columns = columns
+            .iter()
+            .map(|column| match column.data_type() == &self.least_supertype {
+                true => Ok(column.column().clone()),
+                false => cast_column_field(column, &self.least_supertype),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let col = with_match_physical_primitive_type!(self.least_supertype.data_type_id().to_physical_type(), |$T| {
+            eval_between::<$T, _, NEGATED>(&columns, input_rows, |a, b| a <= b)
+        }, {
+            match self.least_supertype.data_type_id().to_physical_type() {
+                PhysicalTypeID::Boolean => {
+                    eval_between::<bool, _, NEGATED>(&columns, input_rows, |a, b| a <= b)
+                }
+                _ => eval_between::<Vu8, _, NEGATED>(&columns, input_rows, |a, b| a <= b),
+            }
+        })?;
+        Ok(Arc::new(col))
+    }
+}
+
+impl<const NEGATED: bool> fmt::Display for ComparisonBetweenImpl<NEGATED> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+fn eval_between<T: Scalar, F, const NEGATED: bool>(
+    columns: &[ColumnRef],
+    input_rows: usize,
+    le: F,
+) -> Result<BooleanColumn>
+where
+    F: Fn(T::RefType<'_>, T::RefType<'_>) -> bool,
+{
+    let low = T::try_create_viewer(&columns[1])?;
+    let high = T::try_create_viewer(&columns[2])?;
+
+    if columns[0].is_const() {
+        let value = T::try_create_viewer(&columns[0])?;
+        let it = (0..input_rows).map(|row| {
+            let v = value.value_at(row);
+            (le(low.value_at(row), v) && le(v, high.value_at(row))) != NEGATED
+        });
+        return Ok(BooleanColumn::from_owned_iterator(it));
+    }
+
+    let column: &<T as Scalar>::ColumnType = unsafe { Series::static_cast(&columns[0]) };
+    let result = match (columns[1].is_const(), columns[2].is_const()) {
+        // the common case, e.g. `a BETWEEN 1 AND 10`
+        (true, true) => {
+            let (low, high) = (low.value_at(0), high.value_at(0));
+            let it = column
+                .scalar_iter()
+                .map(|v| (le(low, v) && le(v, high)) != NEGATED);
+            BooleanColumn::from_owned_iterator(it)
+        }
+        _ => {
+            let it = column
+                .scalar_iter()
+                .enumerate()
+                .map(|(row, v)| (le(low.value_at(row), v) && le(v, high.value_at(row))) != NEGATED);
+            BooleanColumn::from_owned_iterator(it)
+        }
+    };
+    Ok(result)
+}
@@ -14,14 +14,18 @@
 
 use std::collections::HashMap;
 
+use bstr::Finder;
 use common_datavalues::prelude::*;
 use regex::bytes::Regex as BytesRegex;
+use regex::bytes::RegexBuilder as BytesRegexBuilder;
 
 use super::comparison::StringSearchCreator;
 use super::utils::StringSearchImpl;
 
 pub type ComparisonLikeFunction = StringSearchCreator<false, StringSearchLike>;
 pub type ComparisonNotLikeFunction = StringSearchCreator<true, StringSearchLike>;
+pub type ComparisonILikeFunction = StringSearchCreator<false, StringSearchILike>;
+pub type ComparisonNotILikeFunction = StringSearchCreator<true, StringSearchILike>;
 
 #[derive(Clone)]
 pub struct StringSearchLike;
@@ -33,26 +37,7 @@ impl StringSearchImpl for StringSearchLike {
         rhs: &StringColumn,
         op: impl Fn(bool) -> bool,
     ) -> BooleanColumn {
-        let mut map = HashMap::new();
-
-        let mut builder: ColumnBuilder<bool> = ColumnBuilder::with_capacity(lhs.len());
-
-        for (lhs_value, rhs_value) in lhs.scalar_iter().zip(rhs.scalar_iter()) {
-            let pattern = if let Some(pattern) = map.get(rhs_value) {
-                pattern
-            } else {
-                let pattern_str = simdutf8::basic::from_utf8(rhs_value)
-                    .expect("Unable to convert the LIKE pattern to string: {}");
-                let re_pattern = like_pattern_to_regex(pattern_str);
-                let re = BytesRegex::new(&re_pattern)
-                    .expect("Unable to build regex from LIKE pattern: {}");
-                map.insert(rhs_value, re);
-                map.get(rhs_value).unwrap()
-            };
-
-            builder.append(op(pattern.is_match(lhs_value)));
-        }
-        builder.build_column()
+        like_vector_vector(lhs, rhs, op, false)
     }
 
     /// QUOTE: (From arrow2::arrow::compute::like::a_like_binary_scalar)
@@ -73,18 +58,101 @@ impl StringSearchImpl for StringSearchLike {
                 let ends_with = &rhs[1..];
                 BooleanColumn::from_iterator(lhs.scalar_iter().map(|x| op(x.ends_with(ends_with))))
             }
+            PatternType::SurroundByPercent => {
+                // fast path, can use the simd substring search
+                let finder = Finder::new(&rhs[1..rhs.len() - 1]);
+                BooleanColumn::from_iterator(
+                    lhs.scalar_iter().map(|x| op(finder.find(x).is_some())),
+                )
+            }
             PatternType::PatternStr => {
-                let pattern = simdutf8::basic::from_utf8(rhs)
-                    .expect("Unable to convert the LIKE pattern to string: {}");
-                let re_pattern = like_pattern_to_regex(pattern);
-                let re = BytesRegex::new(&re_pattern)
-                    .expect("Unable to build regex from LIKE pattern: {}");
+                let re = build_like_regex(rhs, false);
+                BooleanColumn::from_iterator(lhs.scalar_iter().map(|x| op(re.is_match(x))))
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct StringSearchILike;
+
+impl StringSearchImpl for StringSearchILike {
+    fn vector_vector(
+        lhs: &StringColumn,
+        rhs: &StringColumn,
+        op: impl Fn(bool) -> bool,
+    ) -> BooleanColumn {
+        like_vector_vector(lhs, rhs, op, true)
+    }
+
+    fn vector_const(lhs: &StringColumn, rhs: &[u8], op: impl Fn(bool) -> bool) -> BooleanColumn {
+        // The fast paths fold the case of ASCII only, the other patterns go to the regex
+        // which folds the case of unicode.
+        if !rhs.is_ascii() {
+            let re = build_like_regex(rhs, true);
+            return BooleanColumn::from_iterator(lhs.scalar_iter().map(|x| op(re.is_match(x))));
+        }
+
+        match check_pattern_type(rhs, false) {
+            PatternType::OrdinalStr => BooleanColumn::from_iterator(
+                lhs.scalar_iter().map(|x| op(x.eq_ignore_ascii_case(rhs))),
+            ),
+            PatternType::EndOfPercent => {
+                let prefix = &rhs[..rhs.len() - 1];
+                BooleanColumn::from_iterator(lhs.scalar_iter().map(|x| {
+                    op(x.len() >= prefix.len() && x[..prefix.len()].eq_ignore_ascii_case(prefix))
+                }))
+            }
+            PatternType::StartOfPercent => {
+                let suffix = &rhs[1..];
+                BooleanColumn::from_iterator(lhs.scalar_iter().map(|x| {
+                    op(x.len() >= suffix.len()
+                        && x[x.len() - suffix.len()..].eq_ignore_ascii_case(suffix))
+                }))
+            }
+            PatternType::SurroundByPercent | PatternType::PatternStr => {
+                let re = build_like_regex(rhs, true);
                 BooleanColumn::from_iterator(lhs.scalar_iter().map(|x| op(re.is_match(x))))
             }
         }
     }
 }
 
+fn like_vector_vector(
+    lhs: &StringColumn,
+    rhs: &StringColumn,
+    op: impl Fn(bool) -> bool,
+    case_insensitive: bool,
+) -> BooleanColumn {
+    let mut map = HashMap::new();
+
+    let mut builder: ColumnBuilder<bool> = ColumnBuilder::with_capacity(lhs.len());
+
+    for (lhs_value, rhs_value) in lhs.scalar_iter().zip(rhs.scalar_iter()) {
+        let pattern = if let Some(pattern) = map.get(rhs_value) {
+            pattern
+        } else {
+            let re = build_like_regex(rhs_value, case_insensitive);
+            map.insert(rhs_value, re);
+            map.get(rhs_value).unwrap()
+        };
+
+        builder.append(op(pattern.is_match(lhs_value)));
+    }
+    builder.build_column()
+}
+
+#[inline]
+fn build_like_regex(pattern: &[u8], case_insensitive: bool) -> BytesRegex {
+    let pattern = simdutf8::basic::from_utf8(pattern)
+        .expect("Unable to convert the LIKE pattern to string: {}");
+    let re_pattern = like_pattern_to_regex(pattern);
+    BytesRegexBuilder::new(&re_pattern)
+        .case_insensitive(case_insensitive)
+        .build()
+        .expect("Unable to build regex from LIKE pattern: {}")
+}
+
 #[inline]
 fn is_like_pattern_escape(c: u8) -> bool {
     c == b'%' || c == b'_' || c == b'\\'
@@ -100,6 +168,8 @@ pub enum PatternType {
     StartOfPercent,
     // e.g. 'Arro%'
     EndOfPercent,
+    // e.g. '%rro%'
+    SurroundByPercent,
 }
 
 /// Check the like pattern type.
@@ -136,8 +206,11 @@ pub fn check_pattern_type(pattern: &[u8], is_pruning: bool) -> PatternType {
         match pattern[index] {
             b'_' => return PatternType::PatternStr,
             b'%' => {
-                if index == len - 1 && !start_percent {
-                    return PatternType::EndOfPercent;
+                if index == len - 1 {
+                    return match start_percent {
+                        true => PatternType::SurroundByPercent,
+                        false => PatternType::EndOfPercent,
+                    };
                 }
                 return PatternType::PatternStr;
             }
@@ -0,0 +1,112 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::Function;
+use crate::scalars::FunctionFactory;
+use crate::scalars::FunctionFeatures;
+use crate::scalars::TypedFunctionDescription;
+
+pub type ComparisonNullSafeEqFunction = ComparisonNullSafeEqImpl<false>;
+pub type ComparisonIsDistinctFromFunction = ComparisonNullSafeEqImpl<true>;
+
+/// `a <=> b` or `a IS NOT DISTINCT FROM b`, it's `=` except that two NULLs are equal and
+/// a NULL is not equal to any value, so the result is never NULL.
+/// Negated, it's `a IS DISTINCT FROM b`.
+#[derive(Clone)]
+pub struct ComparisonNullSafeEqImpl<const NEGATED: bool> {
+    display_name: String,
+    /// `=` of the arguments without their NULLs, None if an argument is always NULL.
+    eq_function: Option<Box<dyn Function>>,
+}
+
+impl<const NEGATED: bool> ComparisonNullSafeEqImpl<NEGATED> {
+    pub fn try_create_func(display_name: &str, args: &[&DataTypePtr]) -> Result<Box<dyn Function>> {
+        let eq_function = match args[0].is_null() || args[1].is_null() {
+            true => None,
+            false => {
+                let lhs = remove_nullable(args[0]);
+                let rhs = remove_nullable(args[1]);
+                Some(FunctionFactory::instance().get("=", &[&lhs, &rhs])?)
+            }
+        };
+
+        Ok(Box::new(Self {
+            display_name: display_name.to_string(),
+            eq_function,
+        }))
+    }
+
+    pub fn desc(negative_name: &str) -> TypedFunctionDescription {
+        TypedFunctionDescription::creator(Box::new(Self::try_create_func)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .negative_function(negative_name)
+                .bool_function()
+                .disable_passthrough_null()
+                .num_arguments(2),
+        )
+    }
+}
+
+impl<const NEGATED: bool> Function for ComparisonNullSafeEqImpl<NEGATED> {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn return_type(&self, _args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        Ok(BooleanType::arc())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        // the values under the NULLs are compared too, their results are overridden below
+        let eq = match &self.eq_function {
+            Some(eq_function) => {
+                let columns = columns
+                    .iter()
+                    .map(|c| {
+                        let field =
+                            DataField::new(c.field().name(), remove_nullable(c.data_type()));
+                        ColumnWithField::new(Series::remove_nullable(c.column()), field)
+                    })
+                    .collect::<Vec<_>>();
+                Some(eq_function.eval(&columns, input_rows)?)
+            }
+            None => None,
+        };
+        let eq = eq.as_ref().map(bool::try_create_viewer).transpose()?;
+
+        let (lhs, rhs) = (columns[0].column(), columns[1].column());
+        let mut builder = ColumnBuilder::<bool>::with_capacity(input_rows);
+        for row in 0..input_rows {
+            let equal = match (lhs.null_at(row), rhs.null_at(row)) {
+                (true, true) => true,
+                (false, false) => eq.as_ref().map_or(false, |eq| eq.value_at(row)),
+                _ => false,
+            };
+            builder.append(equal != NEGATED);
+        }
+        Ok(builder.build(input_rows))
+    }
+}
+
+impl<const NEGATED: bool> fmt::Display for ComparisonNullSafeEqImpl<NEGATED> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
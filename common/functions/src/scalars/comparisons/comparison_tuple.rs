@@ -0,0 +1,232 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::Function;
+use crate::scalars::FunctionFactory;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum TupleComparisonOp {
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+/// Compares two tuples field by field, `=` and `<>` hold when all fields are equal or
+/// some field differs, the ordering comparisons are lexicographic: the first field
+/// which is not equal decides the result.
+#[derive(Clone)]
+pub struct TupleComparisonFunction {
+    display_name: String,
+    op: TupleComparisonOp,
+    /// `=` of each field, used to fall through to the next field.
+    eq_functions: Vec<Box<dyn Function>>,
+    /// The comparison of each field deciding the result.
+    cmp_functions: Vec<Box<dyn Function>>,
+    result_type: DataTypePtr,
+}
+
+impl TupleComparisonFunction {
+    pub fn try_create_func(display_name: &str, args: &[&DataTypePtr]) -> Result<Box<dyn Function>> {
+        let op = match display_name {
+            "=" => TupleComparisonOp::Eq,
+            "<>" | "!=" => TupleComparisonOp::NotEq,
+            "<" => TupleComparisonOp::Lt,
+            "<=" => TupleComparisonOp::LtEq,
+            ">" => TupleComparisonOp::Gt,
+            ">=" => TupleComparisonOp::GtEq,
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Illegal types {:?} of argument of function {}, can not be struct",
+                    args, display_name
+                )))
+            }
+        };
+
+        let lhs: &StructType = args[0].as_any().downcast_ref().unwrap();
+        let rhs: &StructType = args[1].as_any().downcast_ref().unwrap();
+        if lhs.types().len() != rhs.types().len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Cannot compare tuples of different sizes: {} and {}",
+                lhs.types().len(),
+                rhs.types().len()
+            )));
+        }
+
+        let factory = FunctionFactory::instance();
+        let fields = lhs.types().len();
+        let mut eq_functions = Vec::with_capacity(fields);
+        let mut cmp_functions = Vec::with_capacity(fields);
+        let mut nullable = false;
+        for (i, (l, r)) in lhs.types().iter().zip(rhs.types().iter()).enumerate() {
+            let cmp_name = match op {
+                TupleComparisonOp::Eq => "=",
+                TupleComparisonOp::NotEq => "<>",
+                _ if i + 1 == fields => display_name,
+                TupleComparisonOp::Lt | TupleComparisonOp::LtEq => "<",
+                TupleComparisonOp::Gt | TupleComparisonOp::GtEq => ">",
+            };
+            let eq_function = factory.get("=", &[l, r])?;
+            let cmp_function = factory.get(cmp_name, &[l, r])?;
+            nullable |= may_be_null(&eq_function.return_type(&[l, r])?)
+                || may_be_null(&cmp_function.return_type(&[l, r])?);
+            eq_functions.push(eq_function);
+            cmp_functions.push(cmp_function);
+        }
+
+        let result_type = match nullable {
+            true => Arc::new(NullableType::create(BooleanType::arc())),
+            false => BooleanType::arc(),
+        };
+        Ok(Box::new(Self {
+            display_name: display_name.to_string(),
+            op,
+            eq_functions,
+            cmp_functions,
+            result_type,
+        }))
+    }
+}
+
+#[inline]
+fn may_be_null(data_type: &DataTypePtr) -> bool {
+    data_type.is_nullable() || data_type.data_type_id() == TypeID::Null
+}
+
+fn tuple_fields(column: &ColumnWithField) -> Result<Vec<ColumnWithField>> {
+    let full_column = column.column().convert_full_column();
+    let tuple: &StructColumn = Series::check_get(&full_column)?;
+    let tuple_type: &StructType = column.data_type().as_any().downcast_ref().unwrap();
+    Ok(tuple
+        .values()
+        .iter()
+        .zip(tuple_type.names().iter())
+        .map(|(value, name)| {
+            ColumnWithField::new(value.clone(), DataField::new(name, value.data_type()))
+        })
+        .collect())
+}
+
+fn eval_field(
+    function: &dyn Function,
+    lhs: &ColumnWithField,
+    rhs: &ColumnWithField,
+    input_rows: usize,
+) -> Result<Vec<Option<bool>>> {
+    let column = function.eval(&[lhs.clone(), rhs.clone()], input_rows)?;
+    if column.data_type().data_type_id() == TypeID::Null {
+        return Ok(vec![None; input_rows]);
+    }
+    let viewer = bool::try_create_viewer(&column)?;
+    Ok(viewer
+        .iter()
+        .enumerate()
+        .map(|(row, v)| viewer.valid_at(row).then(|| v))
+        .collect())
+}
+
+#[inline]
+fn kleene_and(lhs: Option<bool>, rhs: Option<bool>) -> Option<bool> {
+    match (lhs, rhs) {
+        (Some(false), _) | (_, Some(false)) => Some(false),
+        (Some(true), Some(true)) => Some(true),
+        _ => None,
+    }
+}
+
+#[inline]
+fn kleene_or(lhs: Option<bool>, rhs: Option<bool>) -> Option<bool> {
+    match (lhs, rhs) {
+        (Some(true), _) | (_, Some(true)) => Some(true),
+        (Some(false), Some(false)) => Some(false),
+        _ => None,
+    }
+}
+
+impl Function for TupleComparisonFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn return_type(&self, _args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        Ok(self.result_type.clone())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let lhs = tuple_fields(&columns[0])?;
+        let rhs = tuple_fields(&columns[1])?;
+
+        // fold from the last field, which decides the result when all the others are equal
+        let last = lhs.len() - 1;
+        let mut result = eval_field(
+            self.cmp_functions[last].as_ref(),
+            &lhs[last],
+            &rhs[last],
+            input_rows,
+        )?;
+        for i in (0..last).rev() {
+            let cmp = eval_field(self.cmp_functions[i].as_ref(), &lhs[i], &rhs[i], input_rows)?;
+            result = match self.op {
+                TupleComparisonOp::Eq => cmp
+                    .into_iter()
+                    .zip(result)
+                    .map(|(c, r)| kleene_and(c, r))
+                    .collect(),
+                TupleComparisonOp::NotEq => cmp
+                    .into_iter()
+                    .zip(result)
+                    .map(|(c, r)| kleene_or(c, r))
+                    .collect(),
+                _ => {
+                    let eq =
+                        eval_field(self.eq_functions[i].as_ref(), &lhs[i], &rhs[i], input_rows)?;
+                    cmp.into_iter()
+                        .zip(eq)
+                        .zip(result)
+                        .map(|((c, e), r)| kleene_or(c, kleene_and(e, r)))
+                        .collect()
+                }
+            };
+        }
+
+        if self.result_type.is_nullable() {
+            let mut builder = NullableColumnBuilder::<bool>::with_capacity(input_rows);
+            for v in result {
+                builder.append(v.unwrap_or_default(), v.is_some());
+            }
+            Ok(builder.build(input_rows))
+        } else {
+            let mut builder = ColumnBuilder::<bool>::with_capacity(input_rows);
+            for v in result {
+                builder.append(v.unwrap_or_default());
+            }
+            Ok(builder.build(input_rows))
+        }
+    }
+}
+
+impl fmt::Display for TupleComparisonFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
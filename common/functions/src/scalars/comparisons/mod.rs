@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod comparison;
+mod comparison_between;
 mod comparison_eq;
 mod comparison_gt;
 mod comparison_gt_eq;
@@ -20,10 +21,15 @@ mod comparison_like;
 mod comparison_lt;
 mod comparison_lt_eq;
 mod comparison_not_eq;
+mod comparison_null_safe_eq;
 mod comparison_regexp;
+mod comparison_tuple;
 mod utils;
 
 pub use comparison::ComparisonFunction;
+pub use comparison_between::ComparisonBetweenFunction;
+pub use comparison_between::ComparisonBetweenImpl;
+pub use comparison_between::ComparisonNotBetweenFunction;
 pub use comparison_eq::ComparisonEqFunction;
 pub use comparison_gt::ComparisonGtFunction;
 pub use comparison_gt_eq::ComparisonGtEqFunction;
@@ -31,5 +37,9 @@ pub use comparison_like::*;
 pub use comparison_lt::ComparisonLtFunction;
 pub use comparison_lt_eq::ComparisonLtEqFunction;
 pub use comparison_not_eq::ComparisonNotEqFunction;
+pub use comparison_null_safe_eq::ComparisonIsDistinctFromFunction;
+pub use comparison_null_safe_eq::ComparisonNullSafeEqFunction;
+pub use comparison_null_safe_eq::ComparisonNullSafeEqImpl;
 pub use comparison_regexp::ComparisonNotRegexpFunction;
 pub use comparison_regexp::ComparisonRegexpFunction;
+pub use comparison_tuple::TupleComparisonFunction;
@@ -0,0 +1,131 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_datavalues::remove_nullable;
+use common_datavalues::with_match_scalar_type;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::ArgumentType;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// `coalesce(a, b, ...)` returns the first non null argument, the arguments are casted to their
+/// common type by the function adapter.
+#[derive(Clone)]
+pub struct CoalesceFunction {
+    display_name: String,
+}
+
+impl CoalesceFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(CoalesceFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .disable_passthrough_null()
+                .variadic_arguments(1, 1024)
+                .variadic_argument_types(&[ArgumentType::Common]),
+        )
+    }
+
+    /// `ifNull(a, b)` is `coalesce(a, b)`.
+    pub fn if_null_desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .disable_passthrough_null()
+                .num_arguments(2)
+                .argument_types(&[ArgumentType::Common, ArgumentType::Common]),
+        )
+    }
+}
+
+impl Function for CoalesceFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        let non_null = args.iter().filter(|v| !v.is_null()).collect::<Vec<_>>();
+        match non_null.first() {
+            None => Ok(NullType::arc()),
+            // the result is null only if all the arguments are
+            Some(first) => match non_null.iter().all(|v| v.is_nullable()) {
+                true => Ok(wrap_nullable(first)),
+                false => Ok(remove_nullable(first)),
+            },
+        }
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let columns = columns
+            .iter()
+            .filter(|v| !v.data_type().is_null())
+            .collect::<Vec<_>>();
+
+        let data_type = match columns.first() {
+            None => return Ok(Arc::new(NullColumn::new(input_rows))),
+            Some(first) => remove_nullable(first.data_type()),
+        };
+        let nullable = columns.iter().all(|v| v.data_type().is_nullable());
+
+        with_match_scalar_type!(data_type.data_type_id().to_physical_type(), |$T| {
+            let viewers = columns
+                .iter()
+                .map(|v| $T::try_create_viewer(v.column()))
+                .collect::<Result<Vec<_>>>()?;
+
+            if nullable {
+                let mut builder = NullableColumnBuilder::<$T>::with_capacity(input_rows);
+                for row in 0..input_rows {
+                    match viewers.iter().find(|v| v.valid_at(row)) {
+                        Some(viewer) => builder.append(viewer.value_at(row), true),
+                        None => builder.append_null(),
+                    }
+                }
+                return Ok(builder.build(input_rows));
+            }
+
+            let mut builder = ColumnBuilder::<$T>::with_capacity(input_rows);
+            for row in 0..input_rows {
+                // a non nullable argument is always valid
+                let viewer = viewers.iter().find(|v| v.valid_at(row)).unwrap();
+                builder.append(viewer.value_at(row));
+            }
+            Ok(builder.build(input_rows))
+        }, {
+            Err(ErrorCode::BadDataValueType(format!(
+                "Unsupported type {:?} for function {}",
+                data_type, self.display_name
+            )))
+        })
+    }
+}
+
+impl fmt::Display for CoalesceFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
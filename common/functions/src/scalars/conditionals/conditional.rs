@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::CoalesceFunction;
 use super::IsNotNullFunction;
 use super::IsNullFunction;
+use super::MultiIfFunction;
+use super::NullIfFunction;
 use crate::scalars::FunctionFactory;
 use crate::scalars::IfFunction;
 
@@ -25,5 +28,9 @@ impl ConditionalFunction {
         factory.register("if", IfFunction::desc());
         factory.register("isNull", IsNullFunction::desc());
         factory.register("isNotNull", IsNotNullFunction::desc());
+        factory.register("coalesce", CoalesceFunction::desc());
+        factory.register("ifNull", CoalesceFunction::if_null_desc());
+        factory.register_typed("multi_if", MultiIfFunction::desc());
+        factory.register_typed("nullIf", NullIfFunction::desc());
     }
 }
@@ -12,12 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod coalesce;
 mod conditional;
 mod r#if;
 mod is_not_null;
 mod is_null;
+mod multi_if;
+mod nullif;
 
+pub use coalesce::CoalesceFunction;
 pub use conditional::ConditionalFunction;
 pub use is_not_null::IsNotNullFunction;
 pub use is_null::IsNullFunction;
+pub use multi_if::MultiIfFunction;
+pub use nullif::NullIfFunction;
 pub use r#if::IfFunction;
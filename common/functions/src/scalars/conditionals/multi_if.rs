@@ -0,0 +1,106 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::Function;
+use crate::scalars::FunctionFactory;
+use crate::scalars::FunctionFeatures;
+use crate::scalars::TypedFunctionDescription;
+
+/// `multi_if(cond1, then1, cond2, then2, ..., else)` returns the result of the first true
+/// condition, or the else result if none is, it's the function of `CASE WHEN`.
+/// The expression executor evaluates each result only on the rows selected by its condition,
+/// this evaluation over the full columns is used when the arguments are computed already.
+#[derive(Clone)]
+pub struct MultiIfFunction {
+    display_name: String,
+    /// The `if` of each branch from the last one, the else argument of each is the result of
+    /// the previous one.
+    if_functions: Vec<Box<dyn Function>>,
+    return_type: DataTypePtr,
+}
+
+impl MultiIfFunction {
+    pub fn try_create_func(display_name: &str, args: &[&DataTypePtr]) -> Result<Box<dyn Function>> {
+        if args.len() % 2 == 0 {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "Function {} expects the pairs of conditions and results and an else result, but got {} arguments",
+                display_name,
+                args.len()
+            )));
+        }
+
+        let factory = FunctionFactory::instance();
+        let mut return_type = args[args.len() - 1].clone();
+        let mut if_functions = Vec::with_capacity(args.len() / 2);
+        for branch in args[..args.len() - 1].chunks(2).rev() {
+            let if_args = [branch[0], branch[1], &return_type];
+            let if_function = factory.get("if", &if_args)?;
+            return_type = if_function.return_type(&if_args)?;
+            if_functions.push(if_function);
+        }
+
+        Ok(Box::new(MultiIfFunction {
+            display_name: display_name.to_string(),
+            if_functions,
+            return_type,
+        }))
+    }
+
+    pub fn desc() -> TypedFunctionDescription {
+        TypedFunctionDescription::creator(Box::new(Self::try_create_func)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .disable_passthrough_null()
+                .variadic_arguments(3, usize::MAX - 1),
+        )
+    }
+}
+
+impl Function for MultiIfFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, _args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        Ok(self.return_type.clone())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let mut result = columns[columns.len() - 1].clone();
+        let branches = columns[..columns.len() - 1].chunks(2).rev();
+        for (branch, if_function) in branches.zip(self.if_functions.iter()) {
+            let if_args = [branch[0].clone(), branch[1].clone(), result.clone()];
+            let data_type = if_function.return_type(&[
+                if_args[0].data_type(),
+                if_args[1].data_type(),
+                if_args[2].data_type(),
+            ])?;
+            let column = if_function.eval(&if_args, input_rows)?;
+            result = ColumnWithField::new(column, DataField::new(&self.display_name, data_type));
+        }
+        Ok(result.column().clone())
+    }
+}
+
+impl fmt::Display for MultiIfFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::Function;
+use crate::scalars::FunctionFactory;
+use crate::scalars::FunctionFeatures;
+use crate::scalars::TypedFunctionDescription;
+
+/// `nullif(a, b)` returns NULL if `a = b`, otherwise `a`.
+#[derive(Clone)]
+pub struct NullIfFunction {
+    display_name: String,
+    /// `=` of the arguments without their NULLs, None if an argument is always NULL.
+    eq_function: Option<Box<dyn Function>>,
+}
+
+impl NullIfFunction {
+    pub fn try_create_func(display_name: &str, args: &[&DataTypePtr]) -> Result<Box<dyn Function>> {
+        let eq_function = match args[0].is_null() || args[1].is_null() {
+            true => None,
+            false => {
+                let lhs = remove_nullable(args[0]);
+                let rhs = remove_nullable(args[1]);
+                Some(FunctionFactory::instance().get("=", &[&lhs, &rhs])?)
+            }
+        };
+
+        Ok(Box::new(Self {
+            display_name: display_name.to_string(),
+            eq_function,
+        }))
+    }
+
+    pub fn desc() -> TypedFunctionDescription {
+        TypedFunctionDescription::creator(Box::new(Self::try_create_func)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .disable_passthrough_null()
+                .num_arguments(2),
+        )
+    }
+}
+
+impl Function for NullIfFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        match args[0].is_null() {
+            true => Ok(NullType::arc()),
+            false => Ok(wrap_nullable(args[0])),
+        }
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let lhs = columns[0].column().convert_full_column();
+        let eq_function = match &self.eq_function {
+            Some(eq_function) => eq_function,
+            // NULL is never equal to the other argument
+            None if columns[0].data_type().is_null() => return Ok(lhs),
+            None => {
+                let validity =
+                    MutableBitmap::from_iter((0..input_rows).map(|row| !lhs.null_at(row)));
+                let inner = Series::remove_nullable(&lhs);
+                return Ok(NullableColumn::new(inner, validity.into()).arc());
+            }
+        };
+
+        // the values under the NULLs are compared too, their results are ignored below
+        let eq_columns = columns
+            .iter()
+            .map(|c| {
+                let field = DataField::new(c.field().name(), remove_nullable(c.data_type()));
+                ColumnWithField::new(Series::remove_nullable(c.column()), field)
+            })
+            .collect::<Vec<_>>();
+        let eq = eq_function.eval(&eq_columns, input_rows)?;
+        let eq = bool::try_create_viewer(&eq)?;
+
+        let rhs = columns[1].column();
+        let validity = MutableBitmap::from_iter(
+            (0..input_rows).map(|row| !lhs.null_at(row) && (rhs.null_at(row) || !eq.value_at(row))),
+        );
+        let inner = Series::remove_nullable(&lhs);
+        Ok(NullableColumn::new(inner, validity.into()).arc())
+    }
+}
+
+impl fmt::Display for NullIfFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -15,6 +15,7 @@
 use crate::scalars::CurrentUserFunction;
 use crate::scalars::DatabaseFunction;
 use crate::scalars::FunctionFactory;
+use crate::scalars::LastQueryIdFunction;
 use crate::scalars::VersionFunction;
 
 #[derive(Clone)]
@@ -25,5 +26,6 @@ impl ContextFunction {
         factory.register("database", DatabaseFunction::desc());
         factory.register("version", VersionFunction::desc());
         factory.register("current_user", CurrentUserFunction::desc());
+        factory.register("last_query_id", LastQueryIdFunction::desc());
     }
 }
@@ -32,6 +32,7 @@ impl CurrentUserFunction {
     pub fn desc() -> FunctionDescription {
         FunctionDescription::creator(Box::new(Self::try_create)).features(
             FunctionFeatures::default()
+                .stable()
                 .context_function()
                 .num_arguments(1),
         )
@@ -33,6 +33,7 @@ impl DatabaseFunction {
     pub fn desc() -> FunctionDescription {
         FunctionDescription::creator(Box::new(Self::try_create)).features(
             FunctionFeatures::default()
+                .stable()
                 .context_function()
                 .num_arguments(1),
         )
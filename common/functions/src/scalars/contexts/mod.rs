@@ -15,9 +15,11 @@
 mod context;
 mod current_user;
 mod database;
+mod last_query_id;
 mod version;
 
 pub use context::ContextFunction;
 pub use current_user::CurrentUserFunction;
 pub use database::DatabaseFunction;
+pub use last_query_id::LastQueryIdFunction;
 pub use version::VersionFunction;
@@ -36,6 +36,7 @@ impl VersionFunction {
     pub fn desc() -> FunctionDescription {
         FunctionDescription::creator(Box::new(Self::try_create)).features(
             FunctionFeatures::default()
+                .stable()
                 .context_function()
                 .num_arguments(1),
         )
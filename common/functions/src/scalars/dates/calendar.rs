@@ -0,0 +1,105 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Calendar arithmetic on the days and seconds since 1970-01-01 UTC.
+//!
+//! These are plain integer operations without branches on the data, so the loops over
+//! the date columns stay simple enough to be vectorized, unlike the per-row conversions
+//! to chrono's `DateTime`.
+
+use common_datavalues::chrono::NaiveDateTime;
+use common_datavalues::chrono::Offset;
+use common_datavalues::chrono::TimeZone;
+use common_datavalues::Tz;
+
+pub const SECONDS_PER_MINUTE: i64 = 60;
+pub const SECONDS_PER_HOUR: i64 = 60 * SECONDS_PER_MINUTE;
+pub const SECONDS_PER_DAY: i64 = 24 * SECONDS_PER_HOUR;
+
+// The days from 0000-03-01 to 1970-01-01, the years are counted from March
+// so the leap day is the last day of a year.
+const DAYS_BEFORE_EPOCH: i64 = 719468;
+const DAYS_PER_ERA: i64 = 146097;
+
+/// The days since the epoch of the seconds since the epoch.
+#[inline]
+pub fn days_of_seconds(seconds: i64) -> i64 {
+    seconds.div_euclid(SECONDS_PER_DAY)
+}
+
+/// The seconds since the start of the day.
+#[inline]
+pub fn seconds_of_day(seconds: i64) -> i64 {
+    seconds.rem_euclid(SECONDS_PER_DAY)
+}
+
+/// The (year, month, day of month) of the days since the epoch, in the proleptic Gregorian calendar.
+#[inline]
+pub fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + DAYS_BEFORE_EPOCH;
+    let era = z.div_euclid(DAYS_PER_ERA);
+    // [0, 146096]
+    let day_of_era = z - era * DAYS_PER_ERA;
+    // [0, 399]
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    // [0, 365], counted from March 1st
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    // [0, 11], counted from March
+    let month_from_march = (5 * day_of_year + 2) / 153;
+
+    let day = (day_of_year - (153 * month_from_march + 2) / 5 + 1) as u32;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    } as u32;
+    let year = year_of_era + era * 400 + (month <= 2) as i64;
+    (year, month, day)
+}
+
+/// The days since the epoch of the date, the inverse of `civil_from_days`.
+#[inline]
+pub fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = year - (month <= 2) as i64;
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let month_from_march = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * month_from_march + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * DAYS_PER_ERA + day_of_era - DAYS_BEFORE_EPOCH
+}
+
+/// The day of week of the days since the epoch, from 1 for Monday to 7 for Sunday.
+#[inline]
+pub fn day_of_week(days: i64) -> u32 {
+    // 1970-01-01 is a Thursday.
+    ((days + 3).rem_euclid(7) + 1) as u32
+}
+
+/// The day of year of the days since the epoch, from 1 to 366.
+#[inline]
+pub fn day_of_year(days: i64) -> u32 {
+    let (year, _, _) = civil_from_days(days);
+    (days - days_from_civil(year, 1, 1) + 1) as u32
+}
+
+/// The seconds since the epoch of the wall clock time of the timestamp in the timezone,
+/// so the functions above give the local dates and times of the timestamp.
+#[inline]
+pub fn local_seconds(seconds: i64, tz: &Tz) -> i64 {
+    let utc = NaiveDateTime::from_timestamp(seconds, 0);
+    seconds + tz.offset_from_utc_datetime(&utc).fix().local_minus_utc() as i64
+}
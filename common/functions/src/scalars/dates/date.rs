@@ -21,6 +21,7 @@ use super::AddDaysFunction;
 use super::AddMonthsFunction;
 use super::AddTimesFunction;
 use super::AddYearsFunction;
+use super::DateTruncFunction;
 use super::RoundFunction;
 use super::ToDayOfMonthFunction;
 use super::ToDayOfWeekFunction;
@@ -68,6 +69,8 @@ impl DateFunction {
         factory.register("yesterday", YesterdayFunction::desc());
         factory.register("tomorrow", TomorrowFunction::desc());
         factory.register("now", NowFunction::desc());
+        factory.register("current_timestamp", NowFunction::desc());
+        factory.register("now_per_row", NowFunction::per_row_desc());
         factory.register("toYYYYMM", ToYYYYMMFunction::desc());
         factory.register("toYYYYMMDD", ToYYYYMMDDFunction::desc());
         factory.register("toYYYYMMDDhhmmss", ToYYYYMMDDhhmmssFunction::desc());
@@ -100,6 +103,21 @@ impl DateFunction {
         factory.register("toStartOfDay", Self::round_function_creator(60 * 60 * 24));
 
         factory.register("toStartOfWeek", ToStartOfWeekFunction::desc());
+        factory.register("date_trunc", DateTruncFunction::desc());
+
+        // the snake case names of the functions above
+        factory.register("to_year", ToYearFunction::desc());
+        factory.register("to_month", ToMonthFunction::desc());
+        factory.register("to_day_of_year", ToDayOfYearFunction::desc());
+        factory.register("to_day_of_month", ToDayOfMonthFunction::desc());
+        factory.register("to_day_of_week", ToDayOfWeekFunction::desc());
+        factory.register("to_hour", ToHourFunction::desc());
+        factory.register("to_minute", ToMinuteFunction::desc());
+        factory.register("to_second", ToSecondFunction::desc());
+        factory.register("to_start_of_year", ToStartOfYearFunction::desc());
+        factory.register("to_start_of_quarter", ToStartOfQuarterFunction::desc());
+        factory.register("to_start_of_month", ToStartOfMonthFunction::desc());
+        factory.register("to_start_of_week", ToStartOfWeekFunction::desc());
 
         //interval functions
         factory.register_typed("addYears", AddYearsFunction::desc(1));
@@ -0,0 +1,188 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::calendar::civil_from_days;
+use super::calendar::day_of_week;
+use super::calendar::days_from_civil;
+use super::calendar::days_of_seconds;
+use super::calendar::SECONDS_PER_DAY;
+use super::calendar::SECONDS_PER_HOUR;
+use super::calendar::SECONDS_PER_MINUTE;
+use crate::scalars::assert_date_or_datetime;
+use crate::scalars::assert_string;
+use crate::scalars::scalar_unary_op;
+use crate::scalars::EvalContext;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+use crate::scalars::Monotonicity;
+
+#[derive(Clone, Copy, Debug)]
+enum TruncUnit {
+    Year,
+    Quarter,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+impl TruncUnit {
+    fn try_from_name(name: &str) -> Option<Self> {
+        let unit = match name.to_lowercase().as_str() {
+            "year" => TruncUnit::Year,
+            "quarter" => TruncUnit::Quarter,
+            "month" => TruncUnit::Month,
+            "week" => TruncUnit::Week,
+            "day" => TruncUnit::Day,
+            "hour" => TruncUnit::Hour,
+            "minute" => TruncUnit::Minute,
+            "second" => TruncUnit::Second,
+            _ => return None,
+        };
+        Some(unit)
+    }
+
+    // Truncates the seconds since the epoch, the weeks start on Monday.
+    #[inline]
+    fn truncate(self, seconds: i64) -> i64 {
+        let days = days_of_seconds(seconds);
+        let start_day = match self {
+            TruncUnit::Hour => return seconds - seconds.rem_euclid(SECONDS_PER_HOUR),
+            TruncUnit::Minute => return seconds - seconds.rem_euclid(SECONDS_PER_MINUTE),
+            TruncUnit::Second => return seconds,
+            TruncUnit::Day => days,
+            TruncUnit::Week => days - day_of_week(days) as i64 + 1,
+            TruncUnit::Month => {
+                let (_, _, day) = civil_from_days(days);
+                days - day as i64 + 1
+            }
+            TruncUnit::Quarter => {
+                let (year, month, _) = civil_from_days(days);
+                days_from_civil(year, (month - 1) / 3 * 3 + 1, 1)
+            }
+            TruncUnit::Year => {
+                let (year, _, _) = civil_from_days(days);
+                days_from_civil(year, 1, 1)
+            }
+        };
+        start_day * SECONDS_PER_DAY
+    }
+}
+
+/// `date_trunc(unit, expr)` truncates a date or datetime to the start of the year, quarter, month,
+/// week, day, hour, minute or second it is in, and returns it as a datetime.
+#[derive(Clone)]
+pub struct DateTruncFunction {
+    display_name: String,
+}
+
+impl DateTruncFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(DateTruncFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .monotonicity()
+                .num_arguments(2),
+        )
+    }
+
+    fn unit(&self, column: &ColumnWithField, input_rows: usize) -> Result<TruncUnit> {
+        if input_rows != 1 && !column.column().is_const() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Expected constant column for the first argument of function {}, the unit to truncate to",
+                self.display_name
+            )));
+        }
+
+        let name = column.column().get_string(0)?;
+        let name = String::from_utf8_lossy(&name);
+        TruncUnit::try_from_name(&name).ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "Unknown unit '{}' of function {}, expects one of year, quarter, month, week, day, hour, minute and second",
+                name, self.display_name
+            ))
+        })
+    }
+}
+
+impl Function for DateTruncFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        assert_string(args[0])?;
+        assert_date_or_datetime(args[1])?;
+        match args[1].data_type_id() {
+            TypeID::DateTime32 => Ok(args[1].clone()),
+            _ => Ok(DateTime32Type::arc(None)),
+        }
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let unit = self.unit(&columns[0], input_rows)?;
+        let column = columns[1].column();
+        let ctx = &mut EvalContext::default();
+
+        let col = match columns[1].data_type().data_type_id() {
+            TypeID::Date16 => scalar_unary_op::<u16, u32, _>(
+                column,
+                |v: u16, _ctx: &mut EvalContext| unit.truncate(v as i64 * SECONDS_PER_DAY) as u32,
+                ctx,
+            )?,
+            TypeID::Date32 => scalar_unary_op::<i32, u32, _>(
+                column,
+                |v: i32, _ctx: &mut EvalContext| unit.truncate(v as i64 * SECONDS_PER_DAY) as u32,
+                ctx,
+            )?,
+            TypeID::DateTime32 => scalar_unary_op::<u32, u32, _>(
+                column,
+                |v: u32, _ctx: &mut EvalContext| unit.truncate(v as i64) as u32,
+                ctx,
+            )?,
+            other => {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Illegal type {:?} of argument of function {}, should be a date16/date32 or a datetime32",
+                    other, self.display_name
+                )))
+            }
+        };
+        Ok(col.arc())
+    }
+
+    fn get_monotonicity(&self, args: &[Monotonicity]) -> Result<Monotonicity> {
+        Ok(Monotonicity::clone_without_range(&args[1]))
+    }
+}
+
+impl fmt::Display for DateTruncFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -35,6 +35,7 @@ use crate::scalars::EvalContext;
 use crate::scalars::FactoryCreatorWithTypes;
 use crate::scalars::Function;
 use crate::scalars::FunctionFeatures;
+use crate::scalars::Monotonicity;
 use crate::scalars::TypedFunctionDescription;
 
 pub struct IntervalFunctionCreator<T> {
@@ -98,8 +99,12 @@ where T: IntervalArithmeticImpl + Send + Sync + Clone + 'static
         let function_creator: FactoryCreatorWithTypes =
             Box::new(move |display_name, args| Self::try_create_func(display_name, factor, args));
 
-        TypedFunctionDescription::creator(function_creator)
-            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+        TypedFunctionDescription::creator(function_creator).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .monotonicity()
+                .num_arguments(2),
+        )
     }
 }
 
@@ -153,6 +158,19 @@ where
         Ok(self.result_type.clone())
     }
 
+    fn get_monotonicity(&self, args: &[Monotonicity]) -> Result<Monotonicity> {
+        // Adding a constant interval keeps the order of the dates, e.g. ts + INTERVAL 1 DAY.
+        if args[1].is_constant {
+            return Ok(Monotonicity::create(
+                args[0].is_monotonic,
+                args[0].is_positive,
+                args[0].is_constant,
+            ));
+        }
+
+        Ok(Monotonicity::default())
+    }
+
     fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
         // Todo(zhyass): define the ctx out of the eval.
         let mut ctx = EvalContext::new(self.factor, self.precision, None);
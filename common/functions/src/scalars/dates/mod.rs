@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod calendar;
 mod date;
+mod date_trunc;
 mod interval_function;
 mod now;
 mod number_function;
@@ -23,6 +25,7 @@ mod week_date;
 mod macros;
 
 pub use date::DateFunction;
+pub use date_trunc::DateTruncFunction;
 pub use interval_function::AddDaysFunction;
 pub use interval_function::AddMonthsFunction;
 pub use interval_function::AddTimesFunction;
@@ -24,7 +24,9 @@ use crate::scalars::function_factory::FunctionDescription;
 use crate::scalars::Function;
 use crate::scalars::FunctionFeatures;
 
-// TODO: try move it to simple function?
+// `now()` is bound to the start time of the query as its first argument, so that it gives
+// the same value for all rows and all the stages of a distributed query.
+// `now_per_row()` reads the clock for every row instead.
 #[derive(Clone)]
 pub struct NowFunction {
     display_name: String,
@@ -38,8 +40,17 @@ impl NowFunction {
     }
 
     pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .stable()
+                .context_function()
+                .num_arguments(1),
+        )
+    }
+
+    pub fn per_row_desc() -> FunctionDescription {
         FunctionDescription::creator(Box::new(Self::try_create))
-            .features(FunctionFeatures::default())
+            .features(FunctionFeatures::default().volatile())
     }
 }
 impl Function for NowFunction {
@@ -56,11 +67,18 @@ impl Function for NowFunction {
 
     fn eval(
         &self,
-        _columns: &common_datavalues::ColumnsWithField,
+        columns: &common_datavalues::ColumnsWithField,
         input_rows: usize,
     ) -> Result<common_datavalues::ColumnRef> {
-        let utc: DateTime<Utc> = Utc::now();
-        let value = (utc.timestamp_millis() / 1000) as u32;
+        if columns.is_empty() {
+            let values = (0..input_rows).map(|_| {
+                let utc: DateTime<Utc> = Utc::now();
+                (utc.timestamp_millis() / 1000) as u32
+            });
+            return Ok(UInt32Column::from_owned_iterator(values).arc());
+        }
+
+        let value = columns[0].column().get_u64(0)? as u32;
         let column = Series::from_data(&[value as u32]);
         Ok(Arc::new(ConstColumn::new(column, input_rows)))
     }
@@ -68,6 +86,6 @@ impl Function for NowFunction {
 
 impl fmt::Display for NowFunction {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "now()")
+        write!(f, "{}()", self.display_name)
     }
 }
@@ -18,13 +18,23 @@ use std::marker::PhantomData;
 use common_datavalues::chrono::DateTime;
 use common_datavalues::chrono::Datelike;
 use common_datavalues::chrono::TimeZone;
-use common_datavalues::chrono::Timelike;
 use common_datavalues::chrono::Utc;
 use common_datavalues::prelude::*;
 use common_datavalues::Date16Type;
+use common_datavalues::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use super::calendar::civil_from_days;
+use super::calendar::day_of_week;
+use super::calendar::day_of_year;
+use super::calendar::days_from_civil;
+use super::calendar::days_of_seconds;
+use super::calendar::local_seconds;
+use super::calendar::seconds_of_day;
+use super::calendar::SECONDS_PER_DAY;
+use super::calendar::SECONDS_PER_HOUR;
+use super::calendar::SECONDS_PER_MINUTE;
 use crate::scalars::function_factory::FunctionDescription;
 use crate::scalars::scalar_unary_op;
 use crate::scalars::CastFunction;
@@ -45,7 +55,8 @@ pub struct NumberFunction<T, R> {
 pub trait NumberOperator<R> {
     const IS_DETERMINISTIC: bool;
 
-    fn to_number(_value: DateTime<Utc>) -> R;
+    /// Evaluates on the seconds since 1970-01-01 UTC, the dates are at the start of their days.
+    fn to_number(_value: i64) -> R;
 
     // Used to check the monotonicity of the function.
     // For example, ToDayOfYear is monotonous only when the time range is the same year.
@@ -67,8 +78,9 @@ pub struct ToYYYYMM;
 impl NumberOperator<u32> for ToYYYYMM {
     const IS_DETERMINISTIC: bool = true;
 
-    fn to_number(value: DateTime<Utc>) -> u32 {
-        value.year() as u32 * 100 + value.month()
+    fn to_number(value: i64) -> u32 {
+        let (year, month, _) = civil_from_days(days_of_seconds(value));
+        year as u32 * 100 + month
     }
 }
 
@@ -78,8 +90,9 @@ pub struct ToYYYYMMDD;
 impl NumberOperator<u32> for ToYYYYMMDD {
     const IS_DETERMINISTIC: bool = true;
 
-    fn to_number(value: DateTime<Utc>) -> u32 {
-        value.year() as u32 * 10000 + value.month() * 100 + value.day()
+    fn to_number(value: i64) -> u32 {
+        let (year, month, day) = civil_from_days(days_of_seconds(value));
+        year as u32 * 10000 + month * 100 + day
     }
 }
 
@@ -89,13 +102,15 @@ pub struct ToYYYYMMDDhhmmss;
 impl NumberOperator<u64> for ToYYYYMMDDhhmmss {
     const IS_DETERMINISTIC: bool = true;
 
-    fn to_number(value: DateTime<Utc>) -> u64 {
-        value.year() as u64 * 10000000000
-            + value.month() as u64 * 100000000
-            + value.day() as u64 * 1000000
-            + value.hour() as u64 * 10000
-            + value.minute() as u64 * 100
-            + value.second() as u64
+    fn to_number(value: i64) -> u64 {
+        let (year, month, day) = civil_from_days(days_of_seconds(value));
+        let seconds = seconds_of_day(value) as u64;
+        year as u64 * 10000000000
+            + month as u64 * 100000000
+            + day as u64 * 1000000
+            + seconds / 3600 * 10000
+            + seconds % 3600 / 60 * 100
+            + seconds % 60
     }
 }
 
@@ -105,9 +120,9 @@ pub struct ToStartOfYear;
 impl NumberOperator<u16> for ToStartOfYear {
     const IS_DETERMINISTIC: bool = true;
 
-    fn to_number(value: DateTime<Utc>) -> u16 {
-        let end: DateTime<Utc> = Utc.ymd(value.year(), 1, 1).and_hms(0, 0, 0);
-        get_day(end) as u16
+    fn to_number(value: i64) -> u16 {
+        let (year, _, _) = civil_from_days(days_of_seconds(value));
+        days_from_civil(year, 1, 1) as u16
     }
 
     fn return_type() -> Option<common_datavalues::DataTypePtr> {
@@ -121,7 +136,8 @@ pub struct ToStartOfISOYear;
 impl NumberOperator<u16> for ToStartOfISOYear {
     const IS_DETERMINISTIC: bool = true;
 
-    fn to_number(value: DateTime<Utc>) -> u16 {
+    fn to_number(value: i64) -> u16 {
+        let value = Utc.timestamp(value, 0_u32);
         let week_day = value.weekday().num_days_from_monday();
         let iso_week = value.iso_week();
         let iso_week_num = iso_week.week();
@@ -142,10 +158,9 @@ pub struct ToStartOfQuarter;
 impl NumberOperator<u16> for ToStartOfQuarter {
     const IS_DETERMINISTIC: bool = true;
 
-    fn to_number(value: DateTime<Utc>) -> u16 {
-        let new_month = value.month0() / 3 * 3 + 1;
-        let date = Utc.ymd(value.year(), new_month, 1).and_hms(0, 0, 0);
-        get_day(date) as u16
+    fn to_number(value: i64) -> u16 {
+        let (year, month, _) = civil_from_days(days_of_seconds(value));
+        days_from_civil(year, (month - 1) / 3 * 3 + 1, 1) as u16
     }
 
     fn return_type() -> Option<common_datavalues::DataTypePtr> {
@@ -159,9 +174,10 @@ pub struct ToStartOfMonth;
 impl NumberOperator<u16> for ToStartOfMonth {
     const IS_DETERMINISTIC: bool = true;
 
-    fn to_number(value: DateTime<Utc>) -> u16 {
-        let date = Utc.ymd(value.year(), value.month(), 1).and_hms(0, 0, 0);
-        get_day(date) as u16
+    fn to_number(value: i64) -> u16 {
+        let days = days_of_seconds(value);
+        let (_, _, day) = civil_from_days(days);
+        (days - day as i64 + 1) as u16
     }
 
     fn return_type() -> Option<common_datavalues::DataTypePtr> {
@@ -175,8 +191,9 @@ pub struct ToMonth;
 impl NumberOperator<u8> for ToMonth {
     const IS_DETERMINISTIC: bool = true;
 
-    fn to_number(value: DateTime<Utc>) -> u8 {
-        value.month() as u8
+    fn to_number(value: i64) -> u8 {
+        let (_, month, _) = civil_from_days(days_of_seconds(value));
+        month as u8
     }
 
     // ToMonth is NOT a monotonic function in general, unless the time range is within the same year.
@@ -192,8 +209,8 @@ pub struct ToDayOfYear;
 impl NumberOperator<u16> for ToDayOfYear {
     const IS_DETERMINISTIC: bool = true;
 
-    fn to_number(value: DateTime<Utc>) -> u16 {
-        value.ordinal() as u16
+    fn to_number(value: i64) -> u16 {
+        day_of_year(days_of_seconds(value)) as u16
     }
 
     // ToDayOfYear is NOT a monotonic function in general, unless the time range is within the same year.
@@ -209,8 +226,9 @@ pub struct ToDayOfMonth;
 impl NumberOperator<u8> for ToDayOfMonth {
     const IS_DETERMINISTIC: bool = true;
 
-    fn to_number(value: DateTime<Utc>) -> u8 {
-        value.day() as u8
+    fn to_number(value: i64) -> u8 {
+        let (_, _, day) = civil_from_days(days_of_seconds(value));
+        day as u8
     }
 
     // ToDayOfMonth is not a monotonic function in general, unless the time range is within the same month.
@@ -226,8 +244,8 @@ pub struct ToDayOfWeek;
 impl NumberOperator<u8> for ToDayOfWeek {
     const IS_DETERMINISTIC: bool = true;
 
-    fn to_number(value: DateTime<Utc>) -> u8 {
-        value.weekday().number_from_monday() as u8
+    fn to_number(value: i64) -> u8 {
+        day_of_week(days_of_seconds(value)) as u8
     }
 
     // ToDayOfWeek is NOT a monotonic function in general, unless the time range is within the same week.
@@ -242,8 +260,8 @@ pub struct ToHour;
 impl NumberOperator<u8> for ToHour {
     const IS_DETERMINISTIC: bool = true;
 
-    fn to_number(value: DateTime<Utc>) -> u8 {
-        value.hour() as u8
+    fn to_number(value: i64) -> u8 {
+        (seconds_of_day(value) / SECONDS_PER_HOUR) as u8
     }
 
     // ToHour is NOT a monotonic function in general, unless the time range is within the same day.
@@ -258,8 +276,8 @@ pub struct ToMinute;
 impl NumberOperator<u8> for ToMinute {
     const IS_DETERMINISTIC: bool = true;
 
-    fn to_number(value: DateTime<Utc>) -> u8 {
-        value.minute() as u8
+    fn to_number(value: i64) -> u8 {
+        (value.rem_euclid(SECONDS_PER_HOUR) / SECONDS_PER_MINUTE) as u8
     }
 
     // ToMinute is NOT a monotonic function in general, unless the time range is within the same hour.
@@ -274,8 +292,8 @@ pub struct ToSecond;
 impl NumberOperator<u8> for ToSecond {
     const IS_DETERMINISTIC: bool = true;
 
-    fn to_number(value: DateTime<Utc>) -> u8 {
-        value.second() as u8
+    fn to_number(value: i64) -> u8 {
+        value.rem_euclid(SECONDS_PER_MINUTE) as u8
     }
 
     // ToSecond is NOT a monotonic function in general, unless the time range is within the same minute.
@@ -290,9 +308,9 @@ pub struct ToMonday;
 impl NumberOperator<u16> for ToMonday {
     const IS_DETERMINISTIC: bool = true;
 
-    fn to_number(value: DateTime<Utc>) -> u16 {
-        let weekday = value.weekday();
-        (get_day(value) - weekday.num_days_from_monday()) as u16
+    fn to_number(value: i64) -> u16 {
+        let days = days_of_seconds(value);
+        (days - day_of_week(days) as i64 + 1) as u16
     }
 }
 
@@ -302,8 +320,9 @@ pub struct ToYear;
 impl NumberOperator<u16> for ToYear {
     const IS_DETERMINISTIC: bool = true;
 
-    fn to_number(value: DateTime<Utc>) -> u16 {
-        value.year() as u16
+    fn to_number(value: i64) -> u16 {
+        let (year, _, _) = civil_from_days(days_of_seconds(value));
+        year as u16
     }
 }
 
@@ -359,28 +378,32 @@ where
 
         let number_array= match type_id {
             TypeID::Date16 => {
-                let func = |v: u16, _ctx: &mut EvalContext| {
-                    let date_time = Utc.timestamp(v as i64 * 24 * 3600, 0_u32);
-                    T::to_number(date_time)
-                };
+                let func = |v: u16, _ctx: &mut EvalContext| T::to_number(v as i64 * SECONDS_PER_DAY);
                 let col = scalar_unary_op::<u16, R, _>(columns[0].column(), func, &mut EvalContext::default())?;
                 Ok(col.arc())
 
             },
             TypeID::Date32 => {
-                let func = |v:i32, _ctx: &mut EvalContext| {
-                    let date_time = Utc.timestamp(v as i64 * 24 * 3600, 0_u32);
-                    T::to_number(date_time)
-                };
+                let func = |v: i32, _ctx: &mut EvalContext| T::to_number(v as i64 * SECONDS_PER_DAY);
                 let col = scalar_unary_op::<i32, R, _>(columns[0].column(), func, &mut EvalContext::default())?;
                 Ok(col.arc())
             },
             TypeID::DateTime32 => {
-                let func = |v:u32, _ctx: &mut EvalContext| {
-                    let date_time = Utc.timestamp(v as i64 , 0_u32);
-                    T::to_number(date_time)
+                // The timestamps are evaluated on their wall clock time in their timezone.
+                let tz = match columns[0].field().data_type().as_any().downcast_ref::<DateTime32Type>() {
+                    Some(datetime) => datetime.timezone()?,
+                    None => Tz::UTC,
+                };
+                let col = match tz {
+                    Tz::UTC => {
+                        let func = |v: u32, _ctx: &mut EvalContext| T::to_number(v as i64);
+                        scalar_unary_op::<u32, R, _>(columns[0].column(), func, &mut EvalContext::default())?
+                    }
+                    tz => {
+                        let func = |v: u32, _ctx: &mut EvalContext| T::to_number(local_seconds(v as i64, &tz));
+                        scalar_unary_op::<u32, R, _>(columns[0].column(), func, &mut EvalContext::default())?
+                    }
                 };
-                let col = scalar_unary_op::<u32, R, _>(columns[0].column(), func, &mut EvalContext::default())?;
                 Ok(col.arc())
             },
             other => Result::Err(ErrorCode::IllegalDataType(format!(
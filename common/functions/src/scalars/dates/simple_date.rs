@@ -18,7 +18,9 @@ use std::ops::Sub;
 use std::sync::Arc;
 
 use common_datavalues::chrono::Date;
+use common_datavalues::chrono::DateTime;
 use common_datavalues::chrono::NaiveDate;
+use common_datavalues::chrono::TimeZone;
 use common_datavalues::chrono::Utc;
 use common_datavalues::prelude::*;
 use common_exception::Result;
@@ -33,16 +35,18 @@ pub struct SimpleFunction<T> {
     t: PhantomData<T>,
 }
 
+// The date functions are bound to the start time of the query as their first argument,
+// as `now()` does.
 pub trait NoArgDateFunction {
-    fn execute() -> u16;
+    fn execute(now: DateTime<Utc>) -> u16;
 }
 
 #[derive(Clone)]
 pub struct Today;
 
 impl NoArgDateFunction for Today {
-    fn execute() -> u16 {
-        let utc: Date<Utc> = Utc::now().date();
+    fn execute(now: DateTime<Utc>) -> u16 {
+        let utc: Date<Utc> = now.date();
         let epoch = NaiveDate::from_ymd(1970, 1, 1);
 
         let duration = utc.naive_utc().sub(epoch);
@@ -54,8 +58,8 @@ impl NoArgDateFunction for Today {
 pub struct Yesterday;
 
 impl NoArgDateFunction for Yesterday {
-    fn execute() -> u16 {
-        let utc: Date<Utc> = Utc::now().date();
+    fn execute(now: DateTime<Utc>) -> u16 {
+        let utc: Date<Utc> = now.date();
         let epoch = NaiveDate::from_ymd(1970, 1, 1);
 
         let duration = utc.naive_utc().sub(epoch);
@@ -67,8 +71,8 @@ impl NoArgDateFunction for Yesterday {
 pub struct Tomorrow;
 
 impl NoArgDateFunction for Tomorrow {
-    fn execute() -> u16 {
-        let utc: Date<Utc> = Utc::now().date();
+    fn execute(now: DateTime<Utc>) -> u16 {
+        let utc: Date<Utc> = now.date();
         let epoch = NaiveDate::from_ymd(1970, 1, 1);
 
         let duration = utc.naive_utc().sub(epoch);
@@ -87,8 +91,12 @@ where T: NoArgDateFunction + Clone + Sync + Send + 'static
     }
 
     pub fn desc() -> FunctionDescription {
-        FunctionDescription::creator(Box::new(Self::try_create))
-            .features(FunctionFeatures::default())
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .stable()
+                .context_function()
+                .num_arguments(1),
+        )
     }
 }
 
@@ -108,10 +116,11 @@ where T: NoArgDateFunction + Clone + Sync + Send + 'static
 
     fn eval(
         &self,
-        _columns: &common_datavalues::ColumnsWithField,
+        columns: &common_datavalues::ColumnsWithField,
         input_rows: usize,
     ) -> Result<common_datavalues::ColumnRef> {
-        let value = T::execute();
+        let now = Utc.timestamp(columns[0].column().get_u64(0)? as i64, 0);
+        let value = T::execute(now);
         let column = Series::from_data(&[value as u16]);
         Ok(Arc::new(ConstColumn::new(column, input_rows)))
     }
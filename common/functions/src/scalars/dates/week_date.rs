@@ -14,17 +14,14 @@
 
 use std::fmt;
 use std::marker::PhantomData;
-use std::ops::Sub;
 
-use common_datavalues::chrono::DateTime;
-use common_datavalues::chrono::Datelike;
-use common_datavalues::chrono::Duration;
-use common_datavalues::chrono::TimeZone;
-use common_datavalues::chrono::Utc;
 use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use super::calendar::day_of_week;
+use super::calendar::days_of_seconds;
+use super::calendar::SECONDS_PER_DAY;
 use crate::scalars::assert_date_or_datetime;
 use crate::scalars::assert_numeric;
 use crate::scalars::Function;
@@ -44,7 +41,8 @@ pub trait WeekResultFunction<R> {
     const IS_DETERMINISTIC: bool;
 
     fn return_type() -> Result<DataTypePtr>;
-    fn to_number(_value: DateTime<Utc>, mode: u64) -> R;
+    /// Evaluates on the seconds since 1970-01-01 UTC, the dates are at the start of their days.
+    fn to_number(_value: i64, mode: u64) -> R;
     fn factor_function() -> Option<Box<dyn Function>> {
         None
     }
@@ -59,15 +57,14 @@ impl WeekResultFunction<u32> for ToStartOfWeek {
     fn return_type() -> Result<DataTypePtr> {
         Ok(Date16Type::arc())
     }
-    fn to_number(value: DateTime<Utc>, week_mode: u64) -> u32 {
-        let mut weekday = value.weekday().number_from_sunday();
+    fn to_number(value: i64, week_mode: u64) -> u32 {
+        let days = days_of_seconds(value);
+        // The days since Sunday, or since Monday for the odd modes.
+        let mut weekday = day_of_week(days) % 7;
         if week_mode & 1 == 1 {
-            weekday = value.weekday().number_from_monday();
+            weekday = day_of_week(days) - 1;
         }
-        weekday -= 1;
-        let duration = Duration::days(weekday as i64);
-        let result = value.sub(duration);
-        get_day(result)
+        (days - weekday as i64) as u32
     }
 }
 
@@ -144,35 +141,23 @@ where
             TypeID::Date16 => {
 
                     let col: &UInt16Column = Series::check_get(columns[0].column())?;
-                    let iter = col.scalar_iter().map(|v| {
-                            let date_time = Utc.timestamp(v as i64 * 24 * 3600, 0_u32);
-                            T::to_number(date_time, mode)
-                    });
+                    let iter = col.scalar_iter().map(|v| T::to_number(v as i64 * SECONDS_PER_DAY, mode));
                     Ok(PrimitiveColumn::<R>::from_owned_iterator(iter).arc())
             },
             TypeID::Date32 => {
                     let col: &Int32Column = Series::check_get(columns[0].column())?;
-                    let iter = col.scalar_iter().map(|v| {
-                           let date_time = Utc.timestamp(v as i64 * 24 * 3600, 0_u32);
-                            T::to_number(date_time, mode)
-                    });
+                    let iter = col.scalar_iter().map(|v| T::to_number(v as i64 * SECONDS_PER_DAY, mode));
                     Ok(PrimitiveColumn::<R>::from_owned_iterator(iter).arc())
             },
             TypeID::DateTime32 => {
                     let col: &UInt32Column = Series::check_get(columns[0].column())?;
-                    let iter = col.scalar_iter().map(|v| {
-                            let date_time = Utc.timestamp(v as i64, 0_u32);
-                            T::to_number(date_time, mode)
-                    });
+                    let iter = col.scalar_iter().map(|v| T::to_number(v as i64, mode));
                     Ok(PrimitiveColumn::<R>::from_owned_iterator(iter).arc())
             },
 
             TypeID::DateTime64 => {
                     let col: &Int64Column = Series::check_get(columns[0].column())?;
-                    let iter = col.scalar_iter().map(|v| {
-                            let date_time = Utc.timestamp(v as i64, 0_u32);
-                            T::to_number(date_time, mode)
-                    });
+                    let iter = col.scalar_iter().map(|v| T::to_number(v, mode));
                     Ok(PrimitiveColumn::<R>::from_owned_iterator(iter).arc())
             },
             other => Result::Err(ErrorCode::IllegalDataType(format!(
@@ -210,10 +195,4 @@ impl<T, R> fmt::Display for WeekFunction<T, R> {
     }
 }
 
-fn get_day(date: DateTime<Utc>) -> u32 {
-    let start: DateTime<Utc> = Utc.ymd(1970, 1, 1).and_hms(0, 0, 0);
-    let duration = date.signed_duration_since(start);
-    duration.num_days() as u32
-}
-
 pub type ToStartOfWeekFunction = WeekFunction<ToStartOfWeek, u32>;
@@ -20,6 +20,7 @@ use common_exception::Result;
 
 use super::cast_with_type::cast_column_field;
 use crate::scalars::function::Function;
+use crate::scalars::Monotonicity;
 
 #[derive(Clone)]
 pub struct CastFunction {
@@ -67,11 +68,64 @@ impl Function for CastFunction {
         Ok(self.cast_type.clone())
     }
 
+    fn get_monotonicity(&self, args: &[Monotonicity]) -> Result<Monotonicity> {
+        // for constant value, just return clone
+        if args[0].is_constant {
+            return Ok(args[0].clone());
+        }
+
+        // the type of the argument is only known from the boundaries
+        let from_type = match (&args[0].left, &args[0].right) {
+            (Some(boundary), _) | (_, Some(boundary)) => boundary.field().data_type(),
+            _ => return Ok(Monotonicity::default()),
+        };
+
+        match is_order_preserving(from_type, &self.cast_type) {
+            true => Ok(Monotonicity::create(
+                args[0].is_monotonic,
+                args[0].is_positive,
+                false,
+            )),
+            false => Ok(Monotonicity::default()),
+        }
+    }
+
     fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
         cast_column_field(&columns[0], &self.cast_type)
     }
 }
 
+/// Whether the cast keeps the order of the values, e.g. widening an integer or truncating a
+/// DateTime to a Date, but not narrowing an integer, which may wrap around.
+fn is_order_preserving(from_type: &DataTypePtr, to_type: &DataTypePtr) -> bool {
+    let from = remove_nullable(from_type).data_type_id();
+    let to = remove_nullable(to_type).data_type_id();
+
+    if from == to || (from.is_date_or_date_time() && to.is_date_or_date_time()) {
+        return true;
+    }
+
+    if !from.is_numeric() || !to.is_numeric() {
+        return false;
+    }
+
+    let (from_size, to_size) = match (from.numeric_byte_size(), to.numeric_byte_size()) {
+        (Ok(from_size), Ok(to_size)) => (from_size, to_size),
+        _ => return false,
+    };
+
+    match (from.is_floating(), to.is_floating()) {
+        (false, true) => true,
+        (true, true) => to_size >= from_size,
+        (true, false) => false,
+        (false, false) if from.is_signed_integer() == to.is_signed_integer() => {
+            to_size >= from_size
+        }
+        // only an unsigned integer fits in a wider signed one
+        (false, false) => from.is_unsigned_integer() && to_size > from_size,
+    }
+}
+
 impl fmt::Display for CastFunction {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "CAST")
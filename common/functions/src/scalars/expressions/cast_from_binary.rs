@@ -0,0 +1,73 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use super::cast_from_string::cast_from_string;
+use super::cast_with_type::new_mutable_bitmap;
+use super::cast_with_type::CastOptions;
+
+pub fn cast_from_binary(
+    column: &ColumnRef,
+    from_type: &DataTypePtr,
+    data_type: &DataTypePtr,
+    cast_options: &CastOptions,
+) -> Result<(ColumnRef, Option<Bitmap>)> {
+    match data_type.data_type_id() {
+        TypeID::Binary => Ok((Series::remove_nullable(column), None)),
+        // Only valid UTF-8 bytes can be turned into a string, the others become null.
+        TypeID::String => {
+            let binary_column = Series::remove_nullable(column);
+            let binary_column: &StringColumn = Series::check_get(&binary_column)?;
+            let size = binary_column.len();
+            let mut bitmap = new_mutable_bitmap(size, true);
+            let mut builder = MutableStringColumn::with_capacity(size);
+
+            for (row, v) in binary_column.iter().enumerate() {
+                if simdutf8::basic::from_utf8(v).is_ok() {
+                    builder.append_value(v);
+                } else {
+                    builder.append_default();
+                    bitmap.set(row, false);
+                }
+            }
+            Ok((builder.to_column(), Some(bitmap.into())))
+        }
+        // the 16 bytes of a uuid, or its text
+        TypeID::Uuid => {
+            let binary_column = Series::remove_nullable(column);
+            let binary_column: &StringColumn = Series::check_get(&binary_column)?;
+            let size = binary_column.len();
+            let mut bitmap = new_mutable_bitmap(size, true);
+            let mut builder = MutableStringColumn::with_capacity(size);
+
+            for (row, v) in binary_column.iter().enumerate() {
+                match v.len() == UUID_SIZE {
+                    true => builder.append_value(v),
+                    false => match UuidType::parse(v) {
+                        Some(uuid) => builder.append_value(uuid),
+                        None => {
+                            builder.append_value([0u8; UUID_SIZE]);
+                            bitmap.set(row, false);
+                        }
+                    },
+                }
+            }
+            Ok((builder.to_column(), Some(bitmap.into())))
+        }
+        _ => cast_from_string(column, from_type, data_type, cast_options),
+    }
+}
@@ -16,9 +16,14 @@ use std::sync::Arc;
 
 use common_arrow::arrow::bitmap::Bitmap;
 use common_datavalues::chrono::DateTime;
+use common_datavalues::chrono::Duration;
+use common_datavalues::chrono::NaiveDate;
+use common_datavalues::chrono::NaiveDateTime;
+use common_datavalues::chrono::Offset;
 use common_datavalues::chrono::TimeZone;
 use common_datavalues::chrono::Utc;
 use common_datavalues::prelude::*;
+use common_datavalues::Tz;
 use common_exception::Result;
 
 use super::cast_with_type::arrow_cast_compute;
@@ -38,7 +43,7 @@ pub fn cast_from_date16(
     let size = c.len();
 
     match data_type.data_type_id() {
-        TypeID::String => {
+        TypeID::String | TypeID::Binary => {
             let mut builder = ColumnBuilder::<Vu8>::with_capacity(size);
 
             for v in c.iter() {
@@ -49,16 +54,19 @@ pub fn cast_from_date16(
         }
 
         TypeID::DateTime32 => {
-            let it = c.iter().map(|v| *v as u32 * 24 * 3600);
+            let datetime = data_type.as_any().downcast_ref::<DateTime32Type>().unwrap();
+            let tz = datetime.timezone()?;
+            let it = c.iter().map(|v| local_midnight(*v as i64, &tz) as u32);
             let result = Arc::new(UInt32Column::from_iterator(it));
             Ok((result, None))
         }
 
         TypeID::DateTime64 => {
             let datetime = data_type.as_any().downcast_ref::<DateTime64Type>().unwrap();
-            let it = c
-                .iter()
-                .map(|v| datetime.from_nano_seconds(*v as i64 * 24 * 3600 * 1_000_000_000));
+            let tz = datetime.timezone()?;
+            let it = c.iter().map(|v| {
+                datetime.from_nano_seconds(local_midnight(*v as i64, &tz) * 1_000_000_000)
+            });
             let result = Arc::new(Int64Column::from_iterator(it));
             Ok((result, None))
         }
@@ -78,7 +86,7 @@ pub fn cast_from_date32(
     let size = c.len();
 
     match data_type.data_type_id() {
-        TypeID::String => {
+        TypeID::String | TypeID::Binary => {
             let mut builder = ColumnBuilder::<Vu8>::with_capacity(size);
 
             for v in c.iter() {
@@ -89,16 +97,19 @@ pub fn cast_from_date32(
         }
 
         TypeID::DateTime32 => {
-            let it = c.iter().map(|v| *v as u32 * 24 * 3600);
+            let datetime = data_type.as_any().downcast_ref::<DateTime32Type>().unwrap();
+            let tz = datetime.timezone()?;
+            let it = c.iter().map(|v| local_midnight(*v as i64, &tz) as u32);
             let result = Arc::new(UInt32Column::from_iterator(it));
             Ok((result, None))
         }
 
         TypeID::DateTime64 => {
             let datetime = data_type.as_any().downcast_ref::<DateTime64Type>().unwrap();
-            let it = c
-                .iter()
-                .map(|v| datetime.from_nano_seconds(*v as i64 * 24 * 3600 * 1_000_000_000));
+            let tz = datetime.timezone()?;
+            let it = c.iter().map(|v| {
+                datetime.from_nano_seconds(local_midnight(*v as i64, &tz) * 1_000_000_000)
+            });
             let result = Arc::new(Int64Column::from_iterator(it));
             Ok((result, None))
         }
@@ -117,25 +128,31 @@ pub fn cast_from_datetime32(
     let c: &UInt32Column = Series::check_get(&c)?;
     let size = c.len();
 
+    let tz = from_type
+        .as_any()
+        .downcast_ref::<DateTime32Type>()
+        .unwrap()
+        .timezone()?;
+
     match data_type.data_type_id() {
-        TypeID::String => {
+        TypeID::String | TypeID::Binary => {
             let mut builder = ColumnBuilder::<Vu8>::with_capacity(size);
 
             for v in c.iter() {
-                let s = datetime_to_string(Utc.timestamp(*v as i64, 0_u32), TIME_FMT);
+                let s = datetime_to_string((*v).to_date_time(&tz), TIME_FMT);
                 builder.append(s.as_bytes());
             }
             Ok((builder.build(size), None))
         }
 
         TypeID::Date16 => {
-            let it = c.iter().map(|v| (*v as i64 / 24 / 3600) as u16);
+            let it = c.iter().map(|v| local_days(*v as i64, &tz) as u16);
             let result = Arc::new(UInt16Column::from_iterator(it));
             Ok((result, None))
         }
 
         TypeID::Date32 => {
-            let it = c.iter().map(|v| (*v as i64 / 24 / 3600) as i32);
+            let it = c.iter().map(|v| local_days(*v as i64, &tz) as i32);
             let result = Arc::new(Int32Column::from_iterator(it));
             Ok((result, None))
         }
@@ -164,13 +181,14 @@ pub fn cast_from_datetime64(
     let size = c.len();
 
     let date_time64 = from_type.as_any().downcast_ref::<DateTime64Type>().unwrap();
+    let tz = date_time64.timezone()?;
 
     match data_type.data_type_id() {
-        TypeID::String => {
+        TypeID::String | TypeID::Binary => {
             let mut builder = MutableStringColumn::with_capacity(size);
             for v in c.iter() {
                 let s = datetime_to_string(
-                    date_time64.utc_timestamp(*v),
+                    date_time64.utc_timestamp(*v).with_timezone(&tz),
                     date_time64.format_string().as_str(),
                 );
                 builder.append_value(s.as_bytes());
@@ -181,7 +199,7 @@ pub fn cast_from_datetime64(
         TypeID::Date16 => {
             let it = c
                 .iter()
-                .map(|v| (date_time64.to_seconds(*v) / 24 / 3600) as u16);
+                .map(|v| local_days(date_time64.to_seconds(*v), &tz) as u16);
             let result = Arc::new(UInt16Column::from_iterator(it));
             Ok((result, None))
         }
@@ -189,7 +207,7 @@ pub fn cast_from_datetime64(
         TypeID::Date32 => {
             let it = c
                 .iter()
-                .map(|v| (date_time64.to_seconds(*v) / 24 / 3600) as i32);
+                .map(|v| local_days(date_time64.to_seconds(*v), &tz) as i32);
             let result = Arc::new(Int32Column::from_iterator(it));
             Ok((result, None))
         }
@@ -205,6 +223,26 @@ pub fn cast_from_datetime64(
 }
 
 #[inline]
-fn datetime_to_string(date: DateTime<Utc>, fmt: &str) -> String {
+fn datetime_to_string<T: TimeZone>(date: DateTime<T>, fmt: &str) -> String
+where T::Offset: std::fmt::Display {
     date.format(fmt).to_string()
 }
+
+/// The days since the epoch of the local date of the timestamp in the timezone.
+#[inline]
+fn local_days(seconds: i64, tz: &Tz) -> i64 {
+    let utc = NaiveDateTime::from_timestamp(seconds, 0);
+    let offset = tz.offset_from_utc_datetime(&utc).fix().local_minus_utc() as i64;
+    (seconds + offset).div_euclid(24 * 3600)
+}
+
+/// The timestamp in seconds of the local midnight of the date in the timezone.
+#[inline]
+fn local_midnight(days: i64, tz: &Tz) -> i64 {
+    let date = NaiveDate::from_ymd(1970, 1, 1) + Duration::days(days);
+    match tz.from_local_datetime(&date.and_hms(0, 0, 0)).earliest() {
+        Some(midnight) => midnight.timestamp(),
+        // the midnight is skipped by a DST transition
+        None => days * 24 * 3600,
+    }
+}
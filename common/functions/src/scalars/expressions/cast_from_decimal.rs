@@ -0,0 +1,134 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::cast_with_type::arrow_cast_compute;
+use super::cast_with_type::new_mutable_bitmap;
+use super::cast_with_type::CastOptions;
+
+/// Cast numbers, strings and decimals to a decimal, the values that can't be parsed or
+/// don't fit in the precision of the decimal are marked as invalid.
+pub fn cast_to_decimal(
+    column: &ColumnRef,
+    from_type: &DataTypePtr,
+    data_type: &DataTypePtr,
+    cast_options: &CastOptions,
+) -> Result<(ColumnRef, Option<Bitmap>)> {
+    let decimal: &DecimalType = data_type.as_any().downcast_ref().unwrap();
+    let column = Series::remove_nullable(column);
+    let size = column.len();
+    let mut bitmap = new_mutable_bitmap(size, true);
+    let mut builder = ColumnBuilder::<i64>::with_capacity(size);
+
+    let mut append = |row: usize, value: Option<i64>| match value {
+        Some(v) => builder.append(v),
+        None => {
+            builder.append(0);
+            bitmap.set(row, false);
+        }
+    };
+
+    let from_id = from_type.data_type_id();
+    match from_id {
+        TypeID::Decimal => {
+            let from: &DecimalType = from_type.as_any().downcast_ref().unwrap();
+            let c: &Int64Column = Series::check_get(&column)?;
+            for (row, v) in c.iter().enumerate() {
+                append(row, decimal.rescale(*v, from.scale()));
+            }
+        }
+        TypeID::String | TypeID::Binary => {
+            let c: &StringColumn = Series::check_get(&column)?;
+            for (row, v) in c.iter().enumerate() {
+                append(row, decimal.parse(v));
+            }
+        }
+        _ if from_id.is_floating() => {
+            let (c, valids) =
+                arrow_cast_compute(&column, from_type, &Float64Type::arc(), cast_options)?;
+            let c: &Float64Column = Series::check_get(&c)?;
+            for (row, v) in c.iter().enumerate() {
+                let valid = valids.as_ref().map(|b| b.get_bit(row)).unwrap_or(true);
+                append(row, decimal.from_f64(*v).filter(|_| valid));
+            }
+        }
+        _ if from_id.is_integer() || from_id == TypeID::Boolean => {
+            let (c, valids) =
+                arrow_cast_compute(&column, from_type, &Int64Type::arc(), cast_options)?;
+            let c: &Int64Column = Series::check_get(&c)?;
+            for (row, v) in c.iter().enumerate() {
+                let valid = valids.as_ref().map(|b| b.get_bit(row)).unwrap_or(true);
+                append(row, decimal.rescale(*v, 0).filter(|_| valid));
+            }
+        }
+        _ => {
+            return Err(ErrorCode::BadDataValueType(format!(
+                "Unsupported cast from {:?} to {:?}",
+                from_type, data_type
+            )))
+        }
+    }
+
+    Ok((builder.build(size), Some(bitmap.into())))
+}
+
+/// Cast decimals to strings, numbers and booleans, the fraction digits are truncated
+/// when casting to an integer.
+pub fn cast_from_decimal(
+    column: &ColumnRef,
+    from_type: &DataTypePtr,
+    data_type: &DataTypePtr,
+    cast_options: &CastOptions,
+) -> Result<(ColumnRef, Option<Bitmap>)> {
+    let decimal: &DecimalType = from_type.as_any().downcast_ref().unwrap();
+    let c = Series::remove_nullable(column);
+    let c: &Int64Column = Series::check_get(&c)?;
+    let size = c.len();
+
+    let data_type_id = data_type.data_type_id();
+    match data_type_id {
+        TypeID::String | TypeID::Binary => {
+            let mut builder = ColumnBuilder::<Vu8>::with_capacity(size);
+            for v in c.iter() {
+                builder.append(decimal.format(*v).as_bytes());
+            }
+            Ok((builder.build(size), None))
+        }
+        TypeID::Boolean => {
+            let it = c.iter().map(|v| *v != 0);
+            Ok((Arc::new(BooleanColumn::from_iterator(it)), None))
+        }
+        _ if data_type_id.is_floating() => {
+            let it = c.iter().map(|v| decimal.to_f64(*v));
+            let result: ColumnRef = Arc::new(Float64Column::from_iterator(it));
+            arrow_cast_compute(&result, &Float64Type::arc(), data_type, cast_options)
+        }
+        _ if data_type_id.is_integer() => {
+            let factor = 10_i64.pow(decimal.scale() as u32);
+            let it = c.iter().map(|v| *v / factor);
+            let result: ColumnRef = Arc::new(Int64Column::from_iterator(it));
+            arrow_cast_compute(&result, &Int64Type::arc(), data_type, cast_options)
+        }
+        _ => Err(ErrorCode::BadDataValueType(format!(
+            "Unsupported cast from {:?} to {:?}",
+            from_type, data_type
+        ))),
+    }
+}
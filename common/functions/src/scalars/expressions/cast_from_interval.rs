@@ -0,0 +1,53 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::cast_with_type::arrow_cast_compute;
+use super::cast_with_type::CastOptions;
+
+pub fn cast_from_interval(
+    column: &ColumnRef,
+    from_type: &DataTypePtr,
+    data_type: &DataTypePtr,
+    cast_options: &CastOptions,
+) -> Result<(ColumnRef, Option<Bitmap>)> {
+    if data_type.data_type_id() != TypeID::Interval {
+        return arrow_cast_compute(column, from_type, data_type, cast_options);
+    }
+
+    let from: &IntervalType = from_type.as_any().downcast_ref().unwrap();
+    let to: &IntervalType = data_type.as_any().downcast_ref().unwrap();
+    if from.kind().is_month_based() != to.kind().is_month_based() {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "Cast error happens in casting from {:?} to {:?}",
+            from_type, data_type
+        )));
+    }
+
+    let c = Series::remove_nullable(column);
+    let c: &Int64Column = Series::check_get(&c)?;
+
+    let (from_unit, to_unit) = (from.kind().unit(), to.kind().unit());
+    let result = match from_unit >= to_unit {
+        true => Int64Column::from_iterator(c.iter().map(|v| v * (from_unit / to_unit))),
+        false => Int64Column::from_iterator(c.iter().map(|v| v / (to_unit / from_unit))),
+    };
+    Ok((Arc::new(result), None))
+}
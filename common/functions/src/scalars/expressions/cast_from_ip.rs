@@ -0,0 +1,101 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use super::cast_from_string::cast_from_string;
+use super::cast_with_type::arrow_cast_compute;
+use super::cast_with_type::new_mutable_bitmap;
+use super::cast_with_type::CastOptions;
+
+pub fn cast_from_ipv4(
+    column: &ColumnRef,
+    from_type: &DataTypePtr,
+    data_type: &DataTypePtr,
+    cast_options: &CastOptions,
+) -> Result<(ColumnRef, Option<Bitmap>)> {
+    let ip_column = Series::remove_nullable(column);
+    let ip_column: &UInt32Column = Series::check_get(&ip_column)?;
+
+    match data_type.data_type_id() {
+        TypeID::IPv4 => Ok((Series::remove_nullable(column), None)),
+        TypeID::IPv6 => {
+            let mut builder = MutableStringColumn::with_capacity(ip_column.len());
+            for v in ip_column.iter() {
+                builder.append_value(IPv6Type::from_ipv4(*v));
+            }
+            Ok((builder.to_column(), None))
+        }
+        TypeID::String => {
+            let mut builder = MutableStringColumn::with_capacity(ip_column.len());
+            for v in ip_column.iter() {
+                builder.append_value(IPv4Type::format(*v));
+            }
+            Ok((builder.to_column(), None))
+        }
+        // the numbers are casted from the address as an UInt32
+        _ if data_type.data_type_id().is_numeric() => {
+            arrow_cast_compute(column, &UInt32Type::arc(), data_type, cast_options)
+        }
+        _ => {
+            let string_column =
+                cast_from_ipv4(column, from_type, &StringType::arc(), cast_options)?.0;
+            cast_from_string(&string_column, &StringType::arc(), data_type, cast_options)
+        }
+    }
+}
+
+pub fn cast_from_ipv6(
+    column: &ColumnRef,
+    from_type: &DataTypePtr,
+    data_type: &DataTypePtr,
+    cast_options: &CastOptions,
+) -> Result<(ColumnRef, Option<Bitmap>)> {
+    let ip_column = Series::remove_nullable(column);
+    let ip_column: &StringColumn = Series::check_get(&ip_column)?;
+
+    match data_type.data_type_id() {
+        TypeID::IPv6 | TypeID::Binary => Ok((Series::remove_nullable(column), None)),
+        // only the IPv4-mapped addresses can be casted to IPv4
+        TypeID::IPv4 => {
+            let size = ip_column.len();
+            let mut bitmap = new_mutable_bitmap(size, true);
+            let mut builder = ColumnBuilder::<u32>::with_capacity(size);
+            for (row, v) in ip_column.iter().enumerate() {
+                match IPv6Type::to_ipv4(v) {
+                    Some(ipv4) => builder.append(u32::from(ipv4)),
+                    None => {
+                        builder.append(0);
+                        bitmap.set(row, false);
+                    }
+                }
+            }
+            Ok((builder.build(size), Some(bitmap.into())))
+        }
+        TypeID::String => {
+            let mut builder = MutableStringColumn::with_capacity(ip_column.len());
+            for v in ip_column.iter() {
+                builder.append_value(IPv6Type::format(v));
+            }
+            Ok((builder.to_column(), None))
+        }
+        _ => {
+            let string_column =
+                cast_from_ipv6(column, from_type, &StringType::arc(), cast_options)?.0;
+            cast_from_string(&string_column, &StringType::arc(), data_type, cast_options)
+        }
+    }
+}
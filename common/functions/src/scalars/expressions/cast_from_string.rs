@@ -14,10 +14,12 @@
 
 use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::temporal_conversions::EPOCH_DAYS_FROM_CE;
+use common_datavalues::chrono::DateTime;
 use common_datavalues::chrono::Datelike;
 use common_datavalues::chrono::NaiveDate;
-use common_datavalues::chrono::NaiveDateTime;
+use common_datavalues::chrono::TimeZone;
 use common_datavalues::prelude::*;
+use common_datavalues::Tz;
 use common_exception::Result;
 
 use super::cast_with_type::arrow_cast_compute;
@@ -36,6 +38,65 @@ pub fn cast_from_string(
     let mut bitmap = new_mutable_bitmap(size, true);
 
     match data_type.data_type_id() {
+        // a valid string is always a valid binary
+        TypeID::Binary => Ok((Series::remove_nullable(column), None)),
+
+        TypeID::Uuid => {
+            let mut builder = MutableStringColumn::with_capacity(size);
+            for (row, v) in str_column.iter().enumerate() {
+                match UuidType::parse(v) {
+                    Some(uuid) => builder.append_value(uuid),
+                    None => {
+                        builder.append_value([0u8; UUID_SIZE]);
+                        bitmap.set(row, false);
+                    }
+                }
+            }
+            Ok((builder.to_column(), Some(bitmap.into())))
+        }
+
+        TypeID::Bitmap => {
+            let mut builder = MutableStringColumn::with_capacity(size);
+            for (row, v) in str_column.iter().enumerate() {
+                match BitmapType::parse(v) {
+                    Some(bitmap) => builder.append_value(BitmapType::serialize(&bitmap)),
+                    None => {
+                        builder.append_default();
+                        bitmap.set(row, false);
+                    }
+                }
+            }
+            Ok((builder.to_column(), Some(bitmap.into())))
+        }
+
+        TypeID::IPv4 => {
+            let mut builder = ColumnBuilder::<u32>::with_capacity(size);
+            for (row, v) in str_column.iter().enumerate() {
+                match IPv4Type::parse(v) {
+                    Some(ip) => builder.append(ip),
+                    None => {
+                        builder.append(0);
+                        bitmap.set(row, false);
+                    }
+                }
+            }
+            Ok((builder.build(size), Some(bitmap.into())))
+        }
+
+        TypeID::IPv6 => {
+            let mut builder = MutableStringColumn::with_capacity(size);
+            for (row, v) in str_column.iter().enumerate() {
+                match IPv6Type::parse(v) {
+                    Some(ip) => builder.append_value(ip),
+                    None => {
+                        builder.append_value([0u8; IPV6_SIZE]);
+                        bitmap.set(row, false);
+                    }
+                }
+            }
+            Ok((builder.to_column(), Some(bitmap.into())))
+        }
+
         TypeID::Date16 => {
             let mut builder = ColumnBuilder::<u16>::with_capacity(size);
 
@@ -66,9 +127,11 @@ pub fn cast_from_string(
 
         TypeID::DateTime32 => {
             let mut builder = ColumnBuilder::<u32>::with_capacity(size);
+            let datetime = data_type.as_any().downcast_ref::<DateTime32Type>().unwrap();
+            let tz = datetime.timezone()?;
 
             for (row, v) in str_column.iter().enumerate() {
-                match string_to_datetime(v) {
+                match string_to_datetime(v, &tz) {
                     Some(t) => {
                         builder.append(t.timestamp() as u32);
                     }
@@ -80,9 +143,10 @@ pub fn cast_from_string(
         TypeID::DateTime64 => {
             let mut builder = ColumnBuilder::<i64>::with_capacity(size);
             let datetime = data_type.as_any().downcast_ref::<DateTime64Type>().unwrap();
+            let tz = datetime.timezone()?;
 
             for (row, v) in str_column.iter().enumerate() {
-                match string_to_datetime64(v) {
+                match string_to_datetime64(v, &tz) {
                     Some(d) => {
                         builder.append(datetime.from_nano_seconds(d.timestamp_nanos()));
                     }
@@ -96,19 +160,17 @@ pub fn cast_from_string(
     }
 }
 
-// currently use UTC by default
-// TODO support timezone
+// the string is the local time in the timezone
 #[inline]
-fn string_to_datetime(date_str: impl AsRef<[u8]>) -> Option<NaiveDateTime> {
+fn string_to_datetime(date_str: impl AsRef<[u8]>, tz: &Tz) -> Option<DateTime<Tz>> {
     let s = std::str::from_utf8(date_str.as_ref()).ok();
-    s.and_then(|c| NaiveDateTime::parse_from_str(c, "%Y-%m-%d %H:%M:%S").ok())
+    s.and_then(|c| tz.datetime_from_str(c, "%Y-%m-%d %H:%M:%S").ok())
 }
 
-// TODO support timezone
 #[inline]
-fn string_to_datetime64(date_str: impl AsRef<[u8]>) -> Option<NaiveDateTime> {
+fn string_to_datetime64(date_str: impl AsRef<[u8]>, tz: &Tz) -> Option<DateTime<Tz>> {
     let s = std::str::from_utf8(date_str.as_ref()).ok();
-    s.and_then(|c| NaiveDateTime::parse_from_str(c, "%Y-%m-%d %H:%M:%S%.9f").ok())
+    s.and_then(|c| tz.datetime_from_str(c, "%Y-%m-%d %H:%M:%S%.9f").ok())
 }
 
 #[inline]
@@ -0,0 +1,45 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use super::cast_from_string::cast_from_string;
+use super::cast_with_type::CastOptions;
+
+pub fn cast_from_uuid(
+    column: &ColumnRef,
+    from_type: &DataTypePtr,
+    data_type: &DataTypePtr,
+    cast_options: &CastOptions,
+) -> Result<(ColumnRef, Option<Bitmap>)> {
+    match data_type.data_type_id() {
+        TypeID::Uuid | TypeID::Binary => Ok((Series::remove_nullable(column), None)),
+        TypeID::String => {
+            let uuid_column = Series::remove_nullable(column);
+            let uuid_column: &StringColumn = Series::check_get(&uuid_column)?;
+            let mut builder = MutableStringColumn::with_capacity(uuid_column.len());
+            for v in uuid_column.iter() {
+                builder.append_value(UuidType::format(v));
+            }
+            Ok((builder.to_column(), None))
+        }
+        _ => {
+            let string_column =
+                cast_from_uuid(column, from_type, &StringType::arc(), cast_options)?.0;
+            cast_from_string(&string_column, &StringType::arc(), data_type, cast_options)
+        }
+    }
+}
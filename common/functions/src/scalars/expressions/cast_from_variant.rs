@@ -0,0 +1,64 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use serde_json::Value as JsonValue;
+
+use super::cast_from_string::cast_from_string;
+use super::cast_with_type::arrow_cast_compute;
+use super::cast_with_type::new_mutable_bitmap;
+use super::cast_with_type::CastOptions;
+
+pub fn cast_from_variant(
+    column: &ColumnRef,
+    from_type: &DataTypePtr,
+    data_type: &DataTypePtr,
+    cast_options: &CastOptions,
+) -> Result<(ColumnRef, Option<Bitmap>)> {
+    if matches!(
+        data_type.data_type_id(),
+        TypeID::Variant | TypeID::VariantArray | TypeID::VariantObject
+    ) {
+        return arrow_cast_compute(column, from_type, data_type, cast_options);
+    }
+
+    // JSON strings are casted without the quotes, other values by their JSON text,
+    // and JSON nulls become nulls.
+    let json_column = Series::remove_nullable(column);
+    let json_column: &ObjectColumn<JsonValue> = Series::check_get(&json_column)?;
+    let size = json_column.len();
+    let mut bitmap = new_mutable_bitmap(size, true);
+    let mut builder = MutableStringColumn::with_capacity(size);
+
+    for (row, v) in json_column.iter().enumerate() {
+        match v {
+            JsonValue::Null => {
+                builder.append_default();
+                bitmap.set(row, false);
+            }
+            JsonValue::String(s) => builder.append_value(s),
+            v => builder.append_value(v.to_string()),
+        }
+    }
+    let str_column = builder.to_column();
+    if data_type.data_type_id() == TypeID::String {
+        return Ok((str_column, Some(bitmap.into())));
+    }
+
+    let (result, valids) =
+        cast_from_string(&str_column, &StringType::arc(), data_type, cast_options)?;
+    Ok((result, combine_validities_2(Some(bitmap.into()), valids)))
+}
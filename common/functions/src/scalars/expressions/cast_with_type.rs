@@ -24,9 +24,18 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use serde_json::Value as JsonValue;
 
+use super::cast_from_binary::cast_from_binary;
+use super::cast_from_bitmap::cast_from_bitmap;
 use super::cast_from_datetimes::cast_from_date16;
 use super::cast_from_datetimes::cast_from_date32;
+use super::cast_from_decimal::cast_from_decimal;
+use super::cast_from_decimal::cast_to_decimal;
+use super::cast_from_interval::cast_from_interval;
+use super::cast_from_ip::cast_from_ipv4;
+use super::cast_from_ip::cast_from_ipv6;
 use super::cast_from_string::cast_from_string;
+use super::cast_from_uuid::cast_from_uuid;
+use super::cast_from_variant::cast_from_variant;
 use crate::scalars::expressions::cast_from_datetimes::cast_from_datetime32;
 use crate::scalars::expressions::cast_from_datetimes::cast_from_datetime64;
 
@@ -93,8 +102,16 @@ pub fn cast_with_type(
     data_type: &DataTypePtr,
     cast_options: &CastOptions,
 ) -> Result<ColumnRef> {
-    // they are pyhsically the same type
-    if &column.data_type() == data_type {
+    // they are pyhsically the same type, but a binary must be checked before turning into a string,
+    // a uuid, an ip or a bitmap must be formatted into a string, and a decimal must be scaled before turning into an integer
+    let from_type_id = remove_nullable(from_type).data_type_id();
+    if &column.data_type() == data_type
+        && !from_type_id.is_binary()
+        && !from_type_id.is_uuid()
+        && !from_type_id.is_ip()
+        && !from_type_id.is_bitmap()
+        && !from_type_id.is_decimal()
+    {
         return Ok(column.clone());
     }
 
@@ -125,9 +142,27 @@ pub fn cast_with_type(
     let nonull_data_type = remove_nullable(data_type);
 
     let (result, valids) = match nonull_from_type.data_type_id() {
+        _ if nonull_data_type.data_type_id() == TypeID::Decimal => {
+            cast_to_decimal(column, &nonull_from_type, &nonull_data_type, cast_options)
+        }
+        TypeID::Decimal => {
+            cast_from_decimal(column, &nonull_from_type, &nonull_data_type, cast_options)
+        }
         TypeID::String => {
             cast_from_string(column, &nonull_from_type, &nonull_data_type, cast_options)
         }
+        TypeID::Binary => {
+            cast_from_binary(column, &nonull_from_type, &nonull_data_type, cast_options)
+        }
+        TypeID::Uuid => cast_from_uuid(column, &nonull_from_type, &nonull_data_type, cast_options),
+        TypeID::IPv4 => cast_from_ipv4(column, &nonull_from_type, &nonull_data_type, cast_options),
+        TypeID::IPv6 => cast_from_ipv6(column, &nonull_from_type, &nonull_data_type, cast_options),
+        TypeID::Bitmap => {
+            cast_from_bitmap(column, &nonull_from_type, &nonull_data_type, cast_options)
+        }
+        TypeID::Variant | TypeID::VariantArray | TypeID::VariantObject => {
+            cast_from_variant(column, &nonull_from_type, &nonull_data_type, cast_options)
+        }
         TypeID::Date16 => {
             cast_from_date16(column, &nonull_from_type, &nonull_data_type, cast_options)
         }
@@ -140,7 +175,9 @@ pub fn cast_with_type(
         TypeID::DateTime64 => {
             cast_from_datetime64(column, &nonull_from_type, &nonull_data_type, cast_options)
         }
-        // TypeID::Interval => arrow_cast_compute(column, &nonull_data_type, cast_options),
+        TypeID::Interval => {
+            cast_from_interval(column, &nonull_from_type, &nonull_data_type, cast_options)
+        }
         _ => arrow_cast_compute(column, &nonull_from_type, &nonull_data_type, cast_options),
     }?;
 
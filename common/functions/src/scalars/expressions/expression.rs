@@ -60,6 +60,11 @@ impl ToCastFunction {
             "Date16",
             "Date32",
             "String",
+            "Binary",
+            "UUID",
+            "IPv4",
+            "IPv6",
+            "Bitmap",
             "Date",
             "DateTime",
             "DateTime32",
@@ -71,5 +76,12 @@ impl ToCastFunction {
 
             factory.register(&to_name, Self::cast_function_creator(name).unwrap());
         }
+
+        factory.register("to_string", Self::cast_function_creator("String").unwrap());
+        factory.register("to_binary", Self::cast_function_creator("Binary").unwrap());
+        factory.register("to_uuid", Self::cast_function_creator("UUID").unwrap());
+        factory.register("to_ipv4", Self::cast_function_creator("IPv4").unwrap());
+        factory.register("to_ipv6", Self::cast_function_creator("IPv6").unwrap());
+        factory.register("to_bitmap", Self::cast_function_creator("Bitmap").unwrap());
     }
 }
@@ -27,6 +27,7 @@ use common_datavalues::ColumnsWithField;
 use common_datavalues::ConstColumn;
 use common_datavalues::DataField;
 use common_datavalues::DataTypePtr;
+use common_datavalues::DictionaryColumn;
 use common_datavalues::NullColumn;
 use common_datavalues::NullType;
 use common_datavalues::NullableColumn;
@@ -34,7 +35,10 @@ use common_datavalues::Series;
 use common_datavalues::TypeID;
 use common_exception::Result;
 
+use super::cast_column_field;
 use super::Function;
+use super::FunctionFeatures;
+use super::FunctionSignature;
 use super::Monotonicity;
 use super::TypedFunctionDescription;
 
@@ -42,6 +46,9 @@ use super::TypedFunctionDescription;
 pub struct FunctionAdapter {
     inner: Option<Box<dyn Function>>,
     passthrough_null: bool,
+    /// The types the arguments are casted to before the evaluation, from the argument types
+    /// declared in the features of the function. None if the argument is not casted.
+    casts: Vec<Option<DataTypePtr>>,
 }
 
 impl FunctionAdapter {
@@ -49,6 +56,7 @@ impl FunctionAdapter {
         Box::new(Self {
             inner: Some(inner),
             passthrough_null,
+            casts: vec![],
         })
     }
 
@@ -59,9 +67,25 @@ impl FunctionAdapter {
         Box::new(Self {
             inner,
             passthrough_null,
+            casts: vec![],
         })
     }
 
+    /// Checks the arguments against the signatures declared in the features and
+    /// casts them before the evaluation.
+    pub fn try_create(
+        inner: Box<dyn Function>,
+        name: &str,
+        features: &FunctionFeatures,
+        args: &[&DataTypePtr],
+    ) -> Result<Box<dyn Function>> {
+        Ok(Box::new(Self {
+            inner: Some(inner),
+            passthrough_null: features.passthrough_null,
+            casts: FunctionSignature::resolve(name, &features.signatures, args)?,
+        }))
+    }
+
     pub fn try_create_by_typed(
         desc: &TypedFunctionDescription,
         name: &str,
@@ -69,19 +93,106 @@ impl FunctionAdapter {
     ) -> Result<Box<dyn Function>> {
         let passthrough_null = desc.features.passthrough_null;
 
-        let inner = if passthrough_null {
-            // one is null, result is null
-            if args.iter().any(|v| v.data_type_id() == TypeID::Null) {
-                return Ok(Self::create_some(None, true));
+        // one is null, result is null
+        if passthrough_null && args.iter().any(|v| v.data_type_id() == TypeID::Null) {
+            return Ok(Self::create_some(None, true));
+        }
+
+        let casts = FunctionSignature::resolve(name, &desc.features.signatures, args)?;
+        let types = args
+            .iter()
+            .enumerate()
+            .map(|(i, arg)| {
+                let arg = cast_type(arg, casts.get(i));
+                match passthrough_null {
+                    true => remove_nullable(&arg),
+                    false => arg,
+                }
+            })
+            .collect::<Vec<_>>();
+        let types = types.iter().collect::<Vec<_>>();
+        let inner = (desc.typed_function_creator)(name, &types)?;
+
+        Ok(Box::new(Self {
+            inner: Some(inner),
+            passthrough_null,
+            casts,
+        }))
+    }
+
+    /// Casts the arguments to the declared types, None if no argument needs a cast.
+    fn cast_arguments(&self, columns: &ColumnsWithField) -> Result<Option<ColumnsWithField>> {
+        let mut casted = false;
+        let mut result = Vec::with_capacity(columns.len());
+        for (i, column) in columns.iter().enumerate() {
+            let data_type = cast_type(column.data_type(), self.casts.get(i));
+            if &data_type == column.data_type() {
+                result.push(column.clone());
+                continue;
             }
-            let types = args.iter().map(|v| remove_nullable(v)).collect::<Vec<_>>();
-            let types = types.iter().collect::<Vec<_>>();
-            (desc.typed_function_creator)(name, &types)?
-        } else {
-            (desc.typed_function_creator)(name, args)?
+
+            casted = true;
+            let col = cast_column_field(column, &data_type)?;
+            let field = DataField::new(column.field().name(), data_type);
+            result.push(ColumnWithField::new(col, field));
+        }
+        Ok(if casted { Some(result) } else { None })
+    }
+}
+
+/// The type of an argument after its declared cast, the nullability of the argument is kept.
+fn cast_type(arg: &DataTypePtr, cast: Option<&Option<DataTypePtr>>) -> DataTypePtr {
+    match cast {
+        Some(Some(target)) if !arg.is_null() => match arg.is_nullable() {
+            true => wrap_nullable(target),
+            false => target.clone(),
+        },
+        _ => arg.clone(),
+    }
+}
+
+impl FunctionAdapter {
+    /// If the only non constant argument is dictionary encoded, evaluates the function once per
+    /// distinct value and picks the results by the codes, otherwise decodes the arguments.
+    fn eval_dictionary(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let inner = self.inner.as_ref().unwrap();
+        let non_const = columns
+            .iter()
+            .filter(|v| !v.column().is_const())
+            .collect::<Vec<_>>();
+
+        let dictionary = match non_const.len() {
+            1 => non_const[0]
+                .column()
+                .as_any()
+                .downcast_ref::<DictionaryColumn>(),
+            _ => None,
         };
 
-        Ok(Self::create(inner, passthrough_null))
+        if let (true, Some(dictionary)) = (self.passthrough_constant(), dictionary) {
+            let ndv = dictionary.values().len();
+            let distinct_columns = columns
+                .iter()
+                .map(|v| {
+                    let col = match v.column().is_const() {
+                        true => v.column().slice(0, ndv),
+                        false => dictionary.values().arc(),
+                    };
+                    ColumnWithField::new(col, v.field().clone())
+                })
+                .collect::<Vec<_>>();
+
+            let col = inner.eval(&distinct_columns, ndv)?;
+            if let Ok(col) = Series::take(&col, dictionary.keys()) {
+                return Ok(col);
+            }
+        }
+
+        let columns = columns
+            .iter()
+            .map(|v| ColumnWithField::new(Series::remove_dictionary(v.column()), v.field().clone()))
+            .collect::<Vec<_>>();
+        inner.eval(&columns, input_rows)
     }
 }
 
@@ -96,6 +207,12 @@ impl Function for FunctionAdapter {
         }
 
         let inner = self.inner.as_ref().unwrap();
+        let args = args
+            .iter()
+            .enumerate()
+            .map(|(i, arg)| cast_type(arg, self.casts.get(i)))
+            .collect::<Vec<_>>();
+        let args = args.iter().collect::<Vec<_>>();
 
         if self.passthrough_null {
             let has_null = args.iter().any(|v| v.is_null());
@@ -114,7 +231,7 @@ impl Function for FunctionAdapter {
                 Ok(typ)
             }
         } else {
-            inner.return_type(args)
+            inner.return_type(&args)
         }
     }
 
@@ -128,6 +245,35 @@ impl Function for FunctionAdapter {
             return inner.eval(columns, input_rows);
         }
 
+        if let Some(columns) = self.cast_arguments(columns)? {
+            return self.eval(&columns, input_rows);
+        }
+
+        // unwrap constant, the nullable constants are const(nullable), so they are evaluated
+        // on a single row before the validity is combined
+        if self.passthrough_constant() && columns.iter().all(|v| v.column().is_const()) {
+            let columns = columns
+                .iter()
+                .map(|v| {
+                    let c = v.column();
+                    let c: &ConstColumn = unsafe { Series::static_cast(c) };
+
+                    ColumnWithField::new(c.inner().clone(), v.field().clone())
+                })
+                .collect::<Vec<_>>();
+
+            let col = self.eval(&columns, 1)?;
+            let col = if col.is_const() && col.len() == 1 {
+                col.replicate(&[input_rows])
+            } else if col.is_null() {
+                NullColumn::new(input_rows).arc()
+            } else {
+                ConstColumn::new(col, input_rows).arc()
+            };
+
+            return Ok(col);
+        }
+
         // nullable or null
         if self.passthrough_null {
             if columns
@@ -189,29 +335,8 @@ impl Function for FunctionAdapter {
             }
         }
 
-        // is there nullable constant? Did not consider this case
-        // unwrap constant
-        if self.passthrough_constant() && columns.iter().all(|v| v.column().is_const()) {
-            let columns = columns
-                .iter()
-                .map(|v| {
-                    let c = v.column();
-                    let c: &ConstColumn = unsafe { Series::static_cast(c) };
-
-                    ColumnWithField::new(c.inner().clone(), v.field().clone())
-                })
-                .collect::<Vec<_>>();
-
-            let col = self.eval(&columns, 1)?;
-            let col = if col.is_const() && col.len() == 1 {
-                col.replicate(&[input_rows])
-            } else if col.is_null() {
-                NullColumn::new(input_rows).arc()
-            } else {
-                ConstColumn::new(col, input_rows).arc()
-            };
-
-            return Ok(col);
+        if columns.iter().any(|v| Series::has_dictionary(v.column())) {
+            return self.eval_dictionary(columns, input_rows);
         }
 
         inner.eval(columns, input_rows)
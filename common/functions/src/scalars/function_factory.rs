@@ -22,6 +22,8 @@ use once_cell::sync::Lazy;
 
 use super::function::Function;
 use super::ArithmeticFunction;
+use super::ArrayFunction;
+use super::BitmapFunction;
 use super::ComparisonFunction;
 use super::ConditionalFunction;
 use super::ContextFunction;
@@ -29,6 +31,7 @@ use super::FunctionAdapter;
 use super::FunctionFeatures;
 use super::HashesFunction;
 use super::LogicFunction;
+use super::MapFunction;
 use super::MathsFunction;
 use super::OtherFunction;
 use super::SemiStructuredFunction;
@@ -94,6 +97,8 @@ static FUNCTION_FACTORY: Lazy<Arc<FunctionFactory>> = Lazy::new(|| {
     ArithmeticFunction::register(&mut function_factory);
     ToCastFunction::register(&mut function_factory);
     TupleClassFunction::register(&mut function_factory);
+    ArrayFunction::register(&mut function_factory);
+    MapFunction::register(&mut function_factory);
     ComparisonFunction::register(&mut function_factory);
     ContextFunction::register(&mut function_factory);
     SemiStructuredFunction::register(&mut function_factory);
@@ -104,6 +109,7 @@ static FUNCTION_FACTORY: Lazy<Arc<FunctionFactory>> = Lazy::new(|| {
     DateFunction::register(&mut function_factory);
     OtherFunction::register(&mut function_factory);
     UUIDFunction::register(&mut function_factory);
+    BitmapFunction::register(&mut function_factory);
     MathsFunction::register(&mut function_factory);
 
     Arc::new(function_factory)
@@ -146,10 +152,7 @@ impl FunctionFactory {
             },
             Some(desc) => {
                 let inner = (desc.function_creator)(origin_name)?;
-                Ok(FunctionAdapter::create(
-                    inner,
-                    desc.features.passthrough_null,
-                ))
+                FunctionAdapter::try_create(inner, origin_name, &desc.features, args)
             }
         }
     }
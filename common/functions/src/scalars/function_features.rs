@@ -12,11 +12,40 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt;
+
 use serde::Serialize;
 
+use super::ArgumentType;
+use super::FunctionSignature;
+
+/// How the result of a function may change between calls with the same arguments.
+/// The variants are ordered from the least to the most volatile.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub enum Volatility {
+    /// Always the same result for the same arguments, e.g. `plus`.
+    /// It can be folded into a constant at planning time and its results can be cached.
+    Immutable,
+    /// The same result within a query, e.g. `now()` or `database()`.
+    Stable,
+    /// Maybe a different result on every call, e.g. `rand()` or `sleep()`.
+    /// It is never folded, cached or used to prune data.
+    Volatile,
+}
+
+impl fmt::Display for Volatility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Volatility::Immutable => write!(f, "immutable"),
+            Volatility::Stable => write!(f, "stable"),
+            Volatility::Volatile => write!(f, "volatile"),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct FunctionFeatures {
-    pub is_deterministic: bool,
+    pub volatility: Volatility,
     pub negative_function_name: Option<String>,
     pub is_bool_func: bool,
     pub is_context_func: bool,
@@ -37,12 +66,16 @@ pub struct FunctionFeatures {
     // (1, 2) means we only accept [1, 2] arguments
     // None means it's not variadic function.
     pub variadic_arguments: Option<(usize, usize)>,
+
+    /// The signatures of the function, the arguments are casted to the types of the first matching
+    /// one by the function adapter. Empty means the function checks its arguments itself.
+    pub signatures: Vec<FunctionSignature>,
 }
 
 impl FunctionFeatures {
     pub fn default() -> FunctionFeatures {
         FunctionFeatures {
-            is_deterministic: false,
+            volatility: Volatility::Volatile,
             negative_function_name: None,
             is_bool_func: false,
             is_context_func: false,
@@ -50,14 +83,29 @@ impl FunctionFeatures {
             passthrough_null: true,
             num_arguments: 0,
             variadic_arguments: None,
+            signatures: vec![],
         }
     }
 
     pub fn deterministic(mut self) -> FunctionFeatures {
-        self.is_deterministic = true;
+        self.volatility = Volatility::Immutable;
+        self
+    }
+
+    pub fn stable(mut self) -> FunctionFeatures {
+        self.volatility = Volatility::Stable;
         self
     }
 
+    pub fn volatile(mut self) -> FunctionFeatures {
+        self.volatility = Volatility::Volatile;
+        self
+    }
+
+    pub fn is_deterministic(&self) -> bool {
+        self.volatility == Volatility::Immutable
+    }
+
     pub fn negative_function(mut self, negative_name: &str) -> FunctionFeatures {
         self.negative_function_name = Some(negative_name.to_string());
         self
@@ -92,4 +140,18 @@ impl FunctionFeatures {
         self.num_arguments = num_arguments;
         self
     }
+
+    /// Adds a signature with exactly the given argument types, call it again for an overload.
+    pub fn argument_types(mut self, argument_types: &[ArgumentType]) -> FunctionFeatures {
+        self.signatures
+            .push(FunctionSignature::exact(argument_types));
+        self
+    }
+
+    /// Adds a signature whose argument types repeat for any number of arguments.
+    pub fn variadic_argument_types(mut self, argument_types: &[ArgumentType]) -> FunctionFeatures {
+        self.signatures
+            .push(FunctionSignature::variadic(argument_types));
+        self
+    }
 }
@@ -0,0 +1,254 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_datavalues::type_coercion::compare_coercion;
+use common_datavalues::type_coercion::merge_types;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Serialize;
+
+/// The types accepted by an argument of a function, declared with `FunctionFeatures::argument_types`.
+/// The function adapter checks the arguments and casts them to the declared type before the
+/// function is created, so the function only sees the types it declared.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum ArgumentType {
+    /// Any type, the argument is never casted.
+    Any,
+    /// String, the numbers, booleans and dates are casted to String.
+    String,
+    /// Any numeric type, the argument is never casted.
+    Numeric,
+    /// The unsigned integers, casted to UInt64.
+    UInt64,
+    /// The integers, casted to Int64.
+    Int64,
+    /// The numbers, casted to Float64.
+    Float64,
+    /// Boolean, the argument is never casted.
+    Boolean,
+    /// Any type, all the `Common` arguments are casted to their common super type,
+    /// e.g. the arguments of `coalesce`.
+    Common,
+    /// A comparable type, all the `Comparable` arguments are casted to the type they are
+    /// compared in, e.g. the arguments of `greatest`.
+    Comparable,
+}
+
+impl ArgumentType {
+    /// The type the argument is casted to, None if it is accepted as it is.
+    /// The nullability of the argument is kept by the adapter.
+    pub fn coerce(&self, data_type: &DataTypePtr) -> Result<Option<DataTypePtr>> {
+        let type_id = remove_nullable(data_type).data_type_id();
+        if type_id.is_null() {
+            return Ok(None);
+        }
+
+        let (accepted, target) = match self {
+            ArgumentType::Any | ArgumentType::Common => (true, None),
+            ArgumentType::String => (
+                type_id.is_string()
+                    || type_id.is_numeric()
+                    || type_id.is_date_or_date_time()
+                    || type_id == TypeID::Boolean,
+                Some(TypeID::String),
+            ),
+            ArgumentType::Numeric => (type_id.is_numeric(), None),
+            ArgumentType::UInt64 => (type_id.is_unsigned_integer(), Some(TypeID::UInt64)),
+            ArgumentType::Int64 => (type_id.is_integer(), Some(TypeID::Int64)),
+            ArgumentType::Float64 => (type_id.is_numeric(), Some(TypeID::Float64)),
+            ArgumentType::Boolean => (type_id == TypeID::Boolean, None),
+            ArgumentType::Comparable => (
+                type_id.is_numeric()
+                    || type_id.is_string()
+                    || type_id.is_decimal()
+                    || type_id.is_date_or_date_time()
+                    || type_id.is_interval()
+                    || type_id.is_uuid()
+                    || type_id.is_ip()
+                    || type_id == TypeID::Boolean,
+                None,
+            ),
+        };
+
+        match (accepted, target) {
+            (false, _) => Err(ErrorCode::IllegalDataType(format!(
+                "Expected {} but got {:?}",
+                self, data_type
+            ))),
+            (true, Some(target)) if target != type_id => Ok(Some(match target {
+                TypeID::String => StringType::arc(),
+                TypeID::UInt64 => UInt64Type::arc(),
+                TypeID::Int64 => Int64Type::arc(),
+                _ => Float64Type::arc(),
+            })),
+            _ => Ok(None),
+        }
+    }
+}
+
+impl fmt::Display for ArgumentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArgumentType::Any => write!(f, "any type"),
+            ArgumentType::String => write!(f, "a string"),
+            ArgumentType::Numeric => write!(f, "a numeric type"),
+            ArgumentType::UInt64 => write!(f, "an unsigned integer"),
+            ArgumentType::Int64 => write!(f, "an integer"),
+            ArgumentType::Float64 => write!(f, "a number"),
+            ArgumentType::Boolean => write!(f, "a boolean"),
+            ArgumentType::Common => write!(f, "any type"),
+            ArgumentType::Comparable => write!(f, "a comparable type"),
+        }
+    }
+}
+
+/// A signature of a function, declared with `FunctionFeatures::argument_types` or
+/// `FunctionFeatures::variadic_argument_types`. A function may declare several signatures,
+/// the first one matching the arguments is used.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct FunctionSignature {
+    argument_types: Vec<ArgumentType>,
+    /// The argument types repeat for the remaining arguments, e.g. `[String, Any]` are the
+    /// key and value pairs of `json_object`.
+    variadic: bool,
+}
+
+impl FunctionSignature {
+    pub fn exact(argument_types: &[ArgumentType]) -> FunctionSignature {
+        FunctionSignature {
+            argument_types: argument_types.to_vec(),
+            variadic: false,
+        }
+    }
+
+    pub fn variadic(argument_types: &[ArgumentType]) -> FunctionSignature {
+        FunctionSignature {
+            argument_types: argument_types.to_vec(),
+            variadic: true,
+        }
+    }
+
+    fn check_arity(&self, num_arguments: usize) -> Result<()> {
+        let expected = self.argument_types.len();
+        match self.variadic {
+            true if expected > 0 && num_arguments % expected == 0 => Ok(()),
+            false if num_arguments == expected => Ok(()),
+            true => Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "Expected a multiple of {} arguments but got {}",
+                expected, num_arguments
+            ))),
+            false => Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "Expected {} arguments but got {}",
+                expected, num_arguments
+            ))),
+        }
+    }
+
+    /// The casts of the arguments, None if the argument is accepted as it is.
+    pub fn coerce(&self, args: &[&DataTypePtr]) -> Result<Vec<Option<DataTypePtr>>> {
+        self.check_arity(args.len())?;
+
+        let argument_types = args
+            .iter()
+            .enumerate()
+            .map(|(i, _)| self.argument_types[i % self.argument_types.len()])
+            .collect::<Vec<_>>();
+
+        let mut casts = args
+            .iter()
+            .zip(argument_types.iter())
+            .enumerate()
+            .map(|(i, (arg, argument_type))| {
+                argument_type
+                    .coerce(arg)
+                    .map_err(|e| e.add_message_back(format!(" (argument {})", i + 1)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        for generic in [ArgumentType::Common, ArgumentType::Comparable] {
+            let indices = (0..args.len())
+                .filter(|i| argument_types[*i] == generic && !args[*i].is_null())
+                .collect::<Vec<_>>();
+
+            let mut common: Option<DataTypePtr> = None;
+            for i in indices.iter() {
+                let arg = remove_nullable(args[*i]);
+                common = Some(match (&common, generic) {
+                    (None, _) => arg,
+                    (Some(common), ArgumentType::Common) => merge_types(common, &arg)?,
+                    (Some(common), _) => compare_coercion(common, &arg)?,
+                });
+            }
+
+            if let Some(common) = common {
+                for i in indices {
+                    if remove_nullable(args[i]) != common {
+                        casts[i] = Some(common.clone());
+                    }
+                }
+            }
+        }
+        Ok(casts)
+    }
+
+    /// Resolves the casts of the arguments from the signatures declared by the function `name`.
+    /// Empty if no signature is declared, the function checks its arguments itself.
+    pub fn resolve(
+        name: &str,
+        signatures: &[FunctionSignature],
+        args: &[&DataTypePtr],
+    ) -> Result<Vec<Option<DataTypePtr>>> {
+        match signatures {
+            [] => Ok(vec![]),
+            [signature] => signature.coerce(args),
+            _ => match signatures.iter().find_map(|v| v.coerce(args).ok()) {
+                Some(casts) => Ok(casts),
+                None => {
+                    let args = args
+                        .iter()
+                        .map(|v| format!("{:?}", v))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    let candidates = signatures
+                        .iter()
+                        .map(|v| format!("{}{}", name, v))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    Err(ErrorCode::IllegalDataType(format!(
+                        "No matching signature for {}({}), the candidates are: {}",
+                        name, args, candidates
+                    )))
+                }
+            },
+        }
+    }
+}
+
+impl fmt::Display for FunctionSignature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let types = self
+            .argument_types
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        match self.variadic {
+            true => write!(f, "({}, ...)", types),
+            false => write!(f, "({})", types),
+        }
+    }
+}
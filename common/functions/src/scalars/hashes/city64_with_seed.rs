@@ -99,6 +99,7 @@ impl Function for City64WithSeedFunction {
                 | TypeID::DateTime64
                 | TypeID::Interval
                 | TypeID::String
+                | TypeID::Binary
         ) {
             return Err(ErrorCode::IllegalDataType(format!(
                 "Unsupported data type: {:?}",
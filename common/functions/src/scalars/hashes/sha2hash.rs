@@ -54,9 +54,9 @@ impl Function for Sha2HashFunction {
         &self,
         args: &[&common_datavalues::DataTypePtr],
     ) -> Result<common_datavalues::DataTypePtr> {
-        if args[0].data_type_id() != TypeID::String {
+        if !args[0].data_type_id().is_string() && !args[0].data_type_id().is_binary() {
             return Err(ErrorCode::IllegalDataType(format!(
-                "Expected first arg as string type, but got {:?}",
+                "Expected first arg as string or binary type, but got {:?}",
                 args[0]
             )));
         }
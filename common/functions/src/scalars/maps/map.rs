@@ -0,0 +1,53 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::map_creator::MapCreatorFunction;
+use super::map_entries::MapKeysFunction;
+use super::map_entries::MapSizeFunction;
+use super::map_entries::MapValuesFunction;
+use super::map_get::MapContainsKeyFunction;
+use crate::scalars::FunctionFactory;
+
+pub struct MapFunction;
+
+impl MapFunction {
+    pub fn register(factory: &mut FunctionFactory) {
+        factory.register("map", MapCreatorFunction::desc());
+        factory.register("map_keys", MapKeysFunction::desc());
+        factory.register("map_values", MapValuesFunction::desc());
+        factory.register("map_size", MapSizeFunction::desc());
+        factory.register("map_contains_key", MapContainsKeyFunction::desc());
+    }
+}
+
+pub(crate) fn check_map_type<'a>(name: &str, data_type: &'a DataTypePtr) -> Result<&'a MapType> {
+    data_type.as_any().downcast_ref::<MapType>().ok_or_else(|| {
+        ErrorCode::BadArguments(format!(
+            "Illegal type {:?} of the first argument of function {}, must be a map",
+            data_type, name
+        ))
+    })
+}
+
+/// The map column and the column of its (key, value) entries.
+pub(crate) fn map_entries(column: &ColumnRef) -> Result<(ArrayColumn, StructColumn)> {
+    let column = column.convert_full_column();
+    let map: &ArrayColumn = Series::check_get(&column)?;
+    let entries: &StructColumn = Series::check_get(map.values())?;
+    Ok((map.clone(), entries.clone()))
+}
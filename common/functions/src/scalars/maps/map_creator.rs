@@ -0,0 +1,141 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::cast_column_field;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// `map(key1, value1, key2, value2, ...)` creates a map, the value of a repeated key is
+/// the last one.
+#[derive(Clone)]
+pub struct MapCreatorFunction {
+    display_name: String,
+}
+
+impl MapCreatorFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(MapCreatorFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .disable_passthrough_null()
+                .variadic_arguments(2, usize::MAX),
+        )
+    }
+
+    /// The types the keys and the values are casted to.
+    fn entry_types(&self, args: &[&DataTypePtr]) -> Result<(DataTypePtr, DataTypePtr)> {
+        if args.len() % 2 != 0 {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "Function {} expects pairs of keys and values, but got {} arguments",
+                self.display_name,
+                args.len()
+            )));
+        }
+
+        let keys = args
+            .iter()
+            .step_by(2)
+            .map(|t| (*t).clone())
+            .collect::<Vec<_>>();
+        let values = args
+            .iter()
+            .skip(1)
+            .step_by(2)
+            .map(|t| (*t).clone())
+            .collect::<Vec<_>>();
+        let key_type = aggregate_types(&keys)?;
+        if key_type.is_null() {
+            return Err(ErrorCode::BadArguments(format!(
+                "The keys of function {} can not be NULL",
+                self.display_name
+            )));
+        }
+        Ok((key_type, aggregate_types(&values)?))
+    }
+}
+
+impl Function for MapCreatorFunction {
+    fn name(&self) -> &str {
+        &self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        let (key_type, value_type) = self.entry_types(args)?;
+        Ok(MapType::arc(remove_nullable(&key_type), value_type))
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let types = columns.iter().map(|c| c.data_type()).collect::<Vec<_>>();
+        let (key_type, value_type) = self.entry_types(&types)?;
+        let keys = columns
+            .iter()
+            .step_by(2)
+            .map(|c| cast_column_field(c, &key_type))
+            .collect::<Result<Vec<_>>>()?;
+        let values = columns
+            .iter()
+            .skip(1)
+            .step_by(2)
+            .map(|c| cast_column_field(c, &value_type))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut maps = Vec::with_capacity(input_rows);
+        for row in 0..input_rows {
+            let mut entries: Vec<(DataValue, DataValue)> = Vec::with_capacity(keys.len());
+            for (key, value) in keys.iter().zip(values.iter()) {
+                let key = key.get(row);
+                if key.is_null() {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "The keys of function {} can not be NULL",
+                        self.display_name
+                    )));
+                }
+
+                let value = value.get(row);
+                match entries.iter_mut().find(|(k, _)| *k == key) {
+                    Some(entry) => entry.1 = value,
+                    None => entries.push((key, value)),
+                }
+            }
+
+            let entries = entries
+                .into_iter()
+                .map(|(key, value)| DataValue::Struct(vec![key, value]))
+                .collect();
+            maps.push(DataValue::Array(entries));
+        }
+
+        let map_type = MapType::create(remove_nullable(&key_type), value_type);
+        map_type.create_column(&maps)
+    }
+}
+
+impl fmt::Display for MapCreatorFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
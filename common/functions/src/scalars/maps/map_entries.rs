@@ -0,0 +1,121 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use super::map::check_map_type;
+use super::map::map_entries;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// The part of the map entries a function returns.
+#[derive(Clone, Copy)]
+enum EntriesPart {
+    Keys,
+    Values,
+    Size,
+}
+
+/// `map_keys(map)`, `map_values(map)` and `map_size(map)` return the keys as an array, the
+/// values as an array, and the number of entries of the map.
+#[derive(Clone)]
+pub struct MapEntriesFunction {
+    display_name: String,
+    part: EntriesPart,
+}
+
+impl MapEntriesFunction {
+    fn desc(part: EntriesPart) -> FunctionDescription {
+        FunctionDescription::creator(Box::new(move |display_name: &str| {
+            let function: Box<dyn Function> = Box::new(MapEntriesFunction {
+                display_name: display_name.to_string(),
+                part,
+            });
+            Ok(function)
+        }))
+        .features(FunctionFeatures::default().deterministic().num_arguments(1))
+    }
+}
+
+pub struct MapKeysFunction;
+
+impl MapKeysFunction {
+    pub fn desc() -> FunctionDescription {
+        MapEntriesFunction::desc(EntriesPart::Keys)
+    }
+}
+
+pub struct MapValuesFunction;
+
+impl MapValuesFunction {
+    pub fn desc() -> FunctionDescription {
+        MapEntriesFunction::desc(EntriesPart::Values)
+    }
+}
+
+pub struct MapSizeFunction;
+
+impl MapSizeFunction {
+    pub fn desc() -> FunctionDescription {
+        MapEntriesFunction::desc(EntriesPart::Size)
+    }
+}
+
+impl Function for MapEntriesFunction {
+    fn name(&self) -> &str {
+        &self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        let map_type = check_map_type(&self.display_name, args[0])?;
+        Ok(match self.part {
+            EntriesPart::Keys => Arc::new(ArrayType::create(map_type.key_type().clone())),
+            EntriesPart::Values => Arc::new(ArrayType::create(map_type.value_type().clone())),
+            EntriesPart::Size => u64::to_data_type(),
+        })
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
+        let (map, entries) = map_entries(columns[0].column())?;
+        let result_type = self.return_type(&[columns[0].data_type()])?;
+        let offsets = map.offsets();
+        Ok(match self.part {
+            EntriesPart::Keys => Arc::new(ArrayColumn::from_data(
+                result_type,
+                offsets.to_vec().into(),
+                entries.values()[0].clone(),
+            )),
+            EntriesPart::Values => Arc::new(ArrayColumn::from_data(
+                result_type,
+                offsets.to_vec().into(),
+                entries.values()[1].clone(),
+            )),
+            EntriesPart::Size => {
+                let sizes = offsets.windows(2).map(|w| (w[1] - w[0]) as u64);
+                Series::from_data(sizes.collect::<Vec<_>>())
+            }
+        })
+    }
+}
+
+impl fmt::Display for MapEntriesFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -0,0 +1,144 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use super::map::check_map_type;
+use super::map::map_entries;
+use crate::scalars::cast_column_field;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// Finds the position of the key in the entries of every row, None if the row has no such key.
+fn find_keys(columns: &ColumnsWithField, name: &str) -> Result<Vec<Option<usize>>> {
+    let map_type = check_map_type(name, columns[0].data_type())?;
+    let (map, entries) = map_entries(columns[0].column())?;
+    let keys = &entries.values()[0];
+    let search_keys = cast_column_field(&columns[1], &wrap_nullable(map_type.key_type()))?;
+
+    let offsets = map.offsets();
+    let positions = (0..map.len())
+        .map(|row| {
+            let key = search_keys.get(row);
+            if key.is_null() {
+                return None;
+            }
+            (offsets[row] as usize..offsets[row + 1] as usize).find(|i| keys.get(*i) == key)
+        })
+        .collect();
+    Ok(positions)
+}
+
+/// `get(map, key)`, also written as `map[key]`, returns the value of the key, or NULL if the
+/// map has no such key. It's registered through `GetFunction` which also serves variants.
+#[derive(Clone)]
+pub struct MapGetFunction {
+    display_name: String,
+}
+
+impl MapGetFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(MapGetFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+}
+
+impl Function for MapGetFunction {
+    fn name(&self) -> &str {
+        &self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        let map_type = check_map_type(&self.display_name, args[0])?;
+        Ok(wrap_nullable(map_type.value_type()))
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
+        let positions = find_keys(columns, &self.display_name)?;
+        let (_, entries) = map_entries(columns[0].column())?;
+        let values = &entries.values()[1];
+
+        let result_type = self.return_type(&[columns[0].data_type()])?;
+        let missing = match result_type.is_nullable() {
+            true => DataValue::Null,
+            false => result_type.default_value(),
+        };
+        let result = positions
+            .iter()
+            .map(|position| match position {
+                Some(i) => values.get(*i),
+                None => missing.clone(),
+            })
+            .collect::<Vec<_>>();
+        result_type.create_column(&result)
+    }
+}
+
+impl fmt::Display for MapGetFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
+
+/// `map_contains_key(map, key)` returns whether the map has the key.
+#[derive(Clone)]
+pub struct MapContainsKeyFunction {
+    display_name: String,
+}
+
+impl MapContainsKeyFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(MapContainsKeyFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .disable_passthrough_null()
+                .num_arguments(2),
+        )
+    }
+}
+
+impl Function for MapContainsKeyFunction {
+    fn name(&self) -> &str {
+        &self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        check_map_type(&self.display_name, args[0])?;
+        Ok(BooleanType::arc())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
+        let positions = find_keys(columns, &self.display_name)?;
+        let column = BooleanColumn::from_iterator(positions.iter().map(|p| p.is_some()));
+        Ok(Arc::new(column))
+    }
+}
+
+impl fmt::Display for MapContainsKeyFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
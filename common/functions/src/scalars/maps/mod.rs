@@ -0,0 +1,26 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod map;
+mod map_creator;
+mod map_entries;
+mod map_get;
+
+pub use map::MapFunction;
+pub use map_creator::MapCreatorFunction;
+pub use map_entries::MapKeysFunction;
+pub use map_entries::MapSizeFunction;
+pub use map_entries::MapValuesFunction;
+pub use map_get::MapContainsKeyFunction;
+pub use map_get::MapGetFunction;
@@ -41,8 +41,11 @@ impl RandomFunction {
     }
 
     pub fn desc() -> FunctionDescription {
-        FunctionDescription::creator(Box::new(Self::try_create))
-            .features(FunctionFeatures::default().variadic_arguments(0, 1))
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .volatile()
+                .variadic_arguments(0, 1),
+        )
     }
 }
 
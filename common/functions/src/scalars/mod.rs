@@ -13,6 +13,8 @@
 // limitations under the License.
 
 mod arithmetics;
+mod arrays;
+mod bitmaps;
 mod comparisons;
 mod conditionals;
 mod contexts;
@@ -24,8 +26,10 @@ mod function_common;
 mod function_factory;
 mod function_features;
 mod function_monotonic;
+mod function_signature;
 mod hashes;
 mod logics;
+mod maps;
 mod maths;
 mod others;
 mod semi_structureds;
@@ -34,6 +38,8 @@ mod tuples;
 mod uuids;
 
 pub use arithmetics::*;
+pub use arrays::*;
+pub use bitmaps::*;
 pub use comparisons::*;
 pub use conditionals::*;
 pub use contexts::*;
@@ -44,9 +50,13 @@ pub use function_adapter::FunctionAdapter;
 pub use function_common::*;
 pub use function_factory::*;
 pub use function_features::FunctionFeatures;
+pub use function_features::Volatility;
 pub use function_monotonic::Monotonicity;
+pub use function_signature::ArgumentType;
+pub use function_signature::FunctionSignature;
 pub use hashes::*;
 pub use logics::*;
+pub use maps::*;
 pub use maths::*;
 pub use others::*;
 pub use semi_structureds::*;
@@ -31,8 +31,12 @@ impl ExistsFunction {
     }
 
     pub fn desc() -> FunctionDescription {
-        FunctionDescription::creator(Box::new(Self::try_create))
-            .features(FunctionFeatures::default().bool_function().num_arguments(1))
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .stable()
+                .bool_function()
+                .num_arguments(1),
+        )
     }
 }
 
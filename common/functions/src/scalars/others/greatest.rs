@@ -0,0 +1,100 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_datavalues::with_match_scalar_type;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::ArgumentType;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+pub type GreatestFunction = LeastGreatestFunction<true>;
+pub type LeastFunction = LeastGreatestFunction<false>;
+
+/// `greatest(a, b, ...)` and `least(a, b, ...)` over any comparable type, the arguments are
+/// casted to the type they are compared in by the function adapter.
+#[derive(Clone)]
+pub struct LeastGreatestFunction<const IS_GREATEST: bool> {
+    display_name: String,
+}
+
+impl<const IS_GREATEST: bool> LeastGreatestFunction<IS_GREATEST> {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(LeastGreatestFunction::<IS_GREATEST> {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .variadic_arguments(1, 1024)
+                .variadic_argument_types(&[ArgumentType::Comparable]),
+        )
+    }
+}
+
+impl<const IS_GREATEST: bool> Function for LeastGreatestFunction<IS_GREATEST> {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        Ok(args[0].clone())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let data_type = columns[0].data_type();
+        with_match_scalar_type!(data_type.data_type_id().to_physical_type(), |$T| {
+            let viewers = columns
+                .iter()
+                .map(|v| $T::try_create_viewer(v.column()))
+                .collect::<Result<Vec<_>>>()?;
+
+            let mut builder = ColumnBuilder::<$T>::with_capacity(input_rows);
+            for row in 0..input_rows {
+                let mut result = viewers[0].value_at(row);
+                for viewer in viewers[1..].iter() {
+                    let value = viewer.value_at(row);
+                    let replace = match IS_GREATEST {
+                        true => value > result,
+                        false => value < result,
+                    };
+                    if replace {
+                        result = value;
+                    }
+                }
+                builder.append(result);
+            }
+            Ok(builder.build(input_rows))
+        }, {
+            Err(ErrorCode::BadDataValueType(format!(
+                "Unsupported type {:?} for function {}",
+                data_type, self.display_name
+            )))
+        })
+    }
+}
+
+impl<const IS_GREATEST: bool> fmt::Display for LeastGreatestFunction<IS_GREATEST> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
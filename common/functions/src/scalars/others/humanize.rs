@@ -0,0 +1,81 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_io::prelude::convert_byte_size;
+use common_io::prelude::convert_number_size;
+
+use crate::scalars::ArgumentType;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+// humanize_size(1024) => '1.02 KB', humanize_number(1024) => '1.02 thousand'
+pub type HumanizeSizeFunction = HumanizeFunctionImpl<true>;
+
+pub type HumanizeNumberFunction = HumanizeFunctionImpl<false>;
+
+#[derive(Clone)]
+pub struct HumanizeFunctionImpl<const BYTE_SIZE: bool> {
+    display_name: String,
+}
+
+impl<const BYTE_SIZE: bool> HumanizeFunctionImpl<BYTE_SIZE> {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(HumanizeFunctionImpl::<BYTE_SIZE> {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .num_arguments(1)
+                .argument_types(&[ArgumentType::Float64]),
+        )
+    }
+}
+
+impl<const BYTE_SIZE: bool> Function for HumanizeFunctionImpl<BYTE_SIZE> {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, _args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        Ok(StringType::arc())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let viewer = f64::try_create_viewer(columns[0].column())?;
+        let mut builder = ColumnBuilder::<Vec<u8>>::with_capacity(input_rows);
+        for val in viewer.iter() {
+            let humanized = match BYTE_SIZE {
+                true => convert_byte_size(val),
+                false => convert_number_size(val),
+            };
+            builder.append(humanized.as_bytes());
+        }
+        Ok(builder.build(input_rows))
+    }
+}
+
+impl<const BYTE_SIZE: bool> fmt::Display for HumanizeFunctionImpl<BYTE_SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -0,0 +1,419 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashSet;
+use std::fmt;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_datavalues::type_coercion::aggregate_types;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use once_cell::sync::OnceCell;
+use ordered_float::OrderedFloat;
+
+use crate::scalars::cast_column_field;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// Lists with at most this many values are probed by binary search instead of hashing.
+const SORTED_IN_LIST_MAX_LEN: usize = 16;
+
+/// `x IN (v1, v2, ...)`, the values of a constant list are collected once by the first block.
+/// As in SQL, a row without match is NULL rather than false if the list contains a NULL.
+#[derive(Clone)]
+pub struct InListFunction<const NEGATED: bool> {
+    /// The [InListValues] of the constant list, typed by the common type of the arguments.
+    values: Arc<OnceCell<Box<dyn Any + Send + Sync>>>,
+}
+
+impl<const NEGATED: bool> InListFunction<NEGATED> {
+    pub fn try_create(_display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(InListFunction::<NEGATED> {
+            values: Arc::new(OnceCell::new()),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .bool_function()
+                .disable_passthrough_null()
+                .variadic_arguments(2, usize::MAX),
+        )
+    }
+}
+
+/// The hashable and ordered key of a value of the list.
+trait InListKey: Scalar {
+    type Key: Hash + Eq + Ord + Send + Sync + 'static;
+
+    fn key(value: Self::RefType<'_>) -> Self::Key;
+}
+
+macro_rules! impl_in_list_key {
+    ($($T: ident),*) => {
+        $(
+            impl InListKey for $T {
+                type Key = $T;
+
+                fn key(value: $T) -> $T {
+                    value
+                }
+            }
+        )*
+    };
+}
+
+impl_in_list_key!(bool, u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl InListKey for f32 {
+    type Key = OrderedFloat<f32>;
+
+    fn key(value: f32) -> OrderedFloat<f32> {
+        OrderedFloat::from(value)
+    }
+}
+
+impl InListKey for f64 {
+    type Key = OrderedFloat<f64>;
+
+    fn key(value: f64) -> OrderedFloat<f64> {
+        OrderedFloat::from(value)
+    }
+}
+
+impl InListKey for Vu8 {
+    type Key = Vec<u8>;
+
+    fn key(value: &[u8]) -> Vec<u8> {
+        value.to_vec()
+    }
+}
+
+enum InListLookup<K> {
+    Sorted(Vec<K>),
+    Hashed(HashSet<K>),
+}
+
+/// The non NULL values of a constant list.
+struct InListValues<K> {
+    lookup: InListLookup<K>,
+    has_null: bool,
+}
+
+impl<K: Hash + Eq + Ord> InListValues<K> {
+    fn create(mut keys: Vec<K>, has_null: bool) -> Self {
+        let lookup = if keys.len() <= SORTED_IN_LIST_MAX_LEN {
+            keys.sort_unstable();
+            keys.dedup();
+            InListLookup::Sorted(keys)
+        } else {
+            InListLookup::Hashed(keys.into_iter().collect())
+        };
+        InListValues { lookup, has_null }
+    }
+
+    #[inline]
+    fn contains(&self, key: &K) -> bool {
+        match &self.lookup {
+            InListLookup::Sorted(keys) => keys.binary_search(key).is_ok(),
+            InListLookup::Hashed(keys) => keys.contains(key),
+        }
+    }
+}
+
+/// The result of a row, NULL if there is no match but the list contains a NULL.
+#[inline]
+fn in_list_result<const NEGATED: bool>(matched: bool, has_null: bool) -> Option<bool> {
+    match (matched, has_null) {
+        (true, _) => Some(!NEGATED),
+        (false, true) => None,
+        (false, false) => Some(NEGATED),
+    }
+}
+
+fn build_result(nullable: bool, result: Vec<Option<bool>>) -> ColumnRef {
+    let rows = result.len();
+    if nullable {
+        let mut builder = NullableColumnBuilder::<bool>::with_capacity(rows);
+        for v in result {
+            builder.append(v.unwrap_or_default(), v.is_some());
+        }
+        builder.build(rows)
+    } else {
+        let mut builder = ColumnBuilder::<bool>::with_capacity(rows);
+        for v in result {
+            builder.append(v.unwrap_or_default());
+        }
+        builder.build(rows)
+    }
+}
+
+fn tuple_fields(column: &ColumnWithField, tuple_type: &StructType) -> Result<Vec<ColumnRef>> {
+    let full_column = column.column().convert_full_column();
+    let tuple: &StructColumn = Series::check_get(&full_column)?;
+    tuple
+        .values()
+        .iter()
+        .zip(tuple_type.types().iter())
+        .map(|(value, data_type)| {
+            let field = ColumnWithField::new(
+                value.clone(),
+                DataField::new(column.field().name(), value.data_type()),
+            );
+            cast_column_field(&field, data_type)
+        })
+        .collect()
+}
+
+/// The hashable key of a tuple, None if any of its fields is NULL.
+fn tuple_key(fields: &[ColumnRef], row: usize) -> Result<Option<Vec<DataGroupValue>>> {
+    let mut key = Vec::with_capacity(fields.len());
+    for field in fields {
+        let value = field.get(row);
+        if value.is_null() {
+            return Ok(None);
+        }
+        key.push(DataGroupValue::try_from(&value)?);
+    }
+    Ok(Some(key))
+}
+
+impl<const NEGATED: bool> InListFunction<NEGATED> {
+    fn tuple_type(args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        let types: Vec<DataTypePtr> = args.iter().map(|arg| (*arg).clone()).collect();
+        let tuple_type = aggregate_types(&types)?;
+        if tuple_type.data_type_id() != TypeID::Struct {
+            return Err(ErrorCode::BadArguments(format!(
+                "Illegal types {:?} of argument of function IN, expect tuples",
+                args
+            )));
+        }
+        Ok(tuple_type)
+    }
+
+    // (a, b) IN ((1, 2), (3, 4))
+    fn eval_tuple(columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let types: Vec<&DataTypePtr> = columns.iter().map(|col| col.data_type()).collect();
+        let tuple_type = Self::tuple_type(&types)?;
+        let tuple_type: &StructType = tuple_type.as_any().downcast_ref().unwrap();
+
+        let mut vals_set = HashSet::with_capacity(columns.len() - 1);
+        for col in &columns[1..] {
+            let fields = tuple_fields(col, tuple_type)?;
+            if let Some(key) = tuple_key(&fields, 0)? {
+                vals_set.insert(key);
+            }
+        }
+
+        let fields = tuple_fields(&columns[0], tuple_type)?;
+        let mut builder: ColumnBuilder<bool> = ColumnBuilder::with_capacity(input_rows);
+        for row in 0..input_rows {
+            let result = match tuple_key(&fields, row)? {
+                Some(key) => vals_set.contains(&key) != NEGATED,
+                None => false,
+            };
+            builder.append(result);
+        }
+        Ok(builder.build(input_rows))
+    }
+
+    /// The arguments other than tuples and NULL are compared by their common type.
+    fn check_type(data_type: &DataTypePtr) -> Result<()> {
+        let type_id = remove_nullable(data_type).data_type_id();
+        if type_id.is_date_or_date_time()
+            || type_id.is_interval()
+            || type_id.is_array()
+            || type_id.is_struct()
+        {
+            return Err(ErrorCode::UnexpectedError(format!(
+                "{} type is not supported for IN now",
+                type_id
+            )));
+        }
+        Ok(())
+    }
+
+    fn eval_scalar<T: InListKey>(
+        &self,
+        input: &ColumnRef,
+        list: &[ColumnRef],
+        nullable: bool,
+    ) -> Result<ColumnRef> {
+        if !list.iter().all(|col| col.is_const()) {
+            return Self::eval_scalar_rows::<T>(input, list, nullable);
+        }
+
+        let values = self.values.get_or_try_init(|| {
+            let mut keys = Vec::with_capacity(list.len());
+            let mut has_null = false;
+            for col in list {
+                let viewer = T::try_create_viewer(col)?;
+                match viewer.valid_at(0) {
+                    true => keys.push(T::key(viewer.value_at(0))),
+                    false => has_null = true,
+                }
+            }
+            let values: Box<dyn Any + Send + Sync> = Box::new(InListValues::create(keys, has_null));
+            Ok::<_, ErrorCode>(values)
+        })?;
+        let values = values
+            .downcast_ref::<InListValues<T::Key>>()
+            .ok_or_else(|| ErrorCode::LogicalError("The IN list is of another type"))?;
+
+        let viewer = T::try_create_viewer(input)?;
+        if !nullable {
+            let mut builder = ColumnBuilder::<bool>::with_capacity(input.len());
+            for value in viewer.iter() {
+                builder.append(values.contains(&T::key(value)) != NEGATED);
+            }
+            return Ok(builder.build(input.len()));
+        }
+
+        let result = viewer
+            .iter_opt()
+            .map(|value| {
+                value.and_then(|value| {
+                    in_list_result::<NEGATED>(values.contains(&T::key(value)), values.has_null)
+                })
+            })
+            .collect();
+        Ok(build_result(nullable, result))
+    }
+
+    /// The list contains columns, e.g. `a IN (b, c)`, the values differ from row to row.
+    fn eval_scalar_rows<T: InListKey>(
+        input: &ColumnRef,
+        list: &[ColumnRef],
+        nullable: bool,
+    ) -> Result<ColumnRef> {
+        let viewer = T::try_create_viewer(input)?;
+        let list_viewers = list
+            .iter()
+            .map(T::try_create_viewer)
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut result = Vec::with_capacity(input.len());
+        for (row, value) in viewer.iter_opt().enumerate() {
+            let value = match value {
+                Some(value) => T::key(value),
+                None => {
+                    result.push(None);
+                    continue;
+                }
+            };
+            let mut has_null = false;
+            let mut matched = false;
+            for list_viewer in &list_viewers {
+                if list_viewer.null_at(row) {
+                    has_null = true;
+                } else if T::key(list_viewer.value_at(row)) == value {
+                    matched = true;
+                    break;
+                }
+            }
+            result.push(in_list_result::<NEGATED>(matched, has_null));
+        }
+        Ok(build_result(nullable, result))
+    }
+}
+
+impl<const NEGATED: bool> Function for InListFunction<NEGATED> {
+    fn name(&self) -> &str {
+        "InListFunction"
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        if args[0].data_type_id() == TypeID::Struct {
+            Self::tuple_type(args)?;
+            return Ok(BooleanType::arc());
+        }
+
+        for dt in args {
+            Self::check_type(dt)?;
+        }
+        let input_dt = remove_nullable(args[0]).data_type_id();
+        if input_dt == TypeID::Null {
+            return Ok(NullType::arc());
+        }
+        match args.iter().any(|dt| dt.is_nullable() || dt.is_null()) {
+            true => Ok(wrap_nullable(&BooleanType::arc())),
+            false => Ok(BooleanType::arc()),
+        }
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        if columns[0].data_type().data_type_id() == TypeID::Struct {
+            return Self::eval_tuple(columns, input_rows);
+        }
+
+        for col in columns {
+            Self::check_type(&col.column().data_type())?;
+        }
+
+        let input_col = &columns[0];
+        let input_dt = remove_nullable(input_col.data_type()).data_type_id();
+        if input_dt == TypeID::Null {
+            let col = NullType::arc().create_constant_column(&DataValue::Null, input_rows)?;
+            return Ok(col);
+        }
+
+        let types: Vec<DataTypePtr> = columns.iter().map(|col| col.column().data_type()).collect();
+        let nullable = types.iter().any(|dt| dt.is_nullable() || dt.is_null());
+        let least_super_dt = aggregate_types(&types)?;
+        let least_super_type_id = remove_nullable(&least_super_dt).data_type_id();
+
+        let input_col = cast_column_field(input_col, &least_super_dt)?;
+        let list = columns[1..]
+            .iter()
+            .map(|col| cast_column_field(col, &least_super_dt))
+            .collect::<Result<Vec<_>>>()?;
+
+        match least_super_type_id {
+            TypeID::Boolean => self.eval_scalar::<bool>(&input_col, &list, nullable),
+            TypeID::UInt8 => self.eval_scalar::<u8>(&input_col, &list, nullable),
+            TypeID::UInt16 => self.eval_scalar::<u16>(&input_col, &list, nullable),
+            TypeID::UInt32 | TypeID::IPv4 => self.eval_scalar::<u32>(&input_col, &list, nullable),
+            TypeID::UInt64 => self.eval_scalar::<u64>(&input_col, &list, nullable),
+            TypeID::Int8 => self.eval_scalar::<i8>(&input_col, &list, nullable),
+            TypeID::Int16 => self.eval_scalar::<i16>(&input_col, &list, nullable),
+            TypeID::Int32 => self.eval_scalar::<i32>(&input_col, &list, nullable),
+            TypeID::Int64 => self.eval_scalar::<i64>(&input_col, &list, nullable),
+            TypeID::String | TypeID::Binary | TypeID::Uuid | TypeID::IPv6 => {
+                self.eval_scalar::<Vu8>(&input_col, &list, nullable)
+            }
+            TypeID::Float32 => self.eval_scalar::<f32>(&input_col, &list, nullable),
+            TypeID::Float64 => self.eval_scalar::<f64>(&input_col, &list, nullable),
+            _ => Err(ErrorCode::UnexpectedError(format!(
+                "{} type is not supported for IN now",
+                least_super_type_id
+            ))),
+        }
+    }
+}
+
+impl<const NEGATED: bool> fmt::Display for InListFunction<NEGATED> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if NEGATED {
+            write!(f, "NOT IN")
+        } else {
+            write!(f, "IN")
+        }
+    }
+}
@@ -64,11 +64,13 @@ impl<const SUPPRESS_CAST_ERROR: bool> Function for InetNtoaFunctionImpl<SUPPRESS
             return Ok(NullType::arc());
         }
 
-        let output_type = if input_type.data_type_id().is_numeric() {
+        let output_type = if input_type.data_type_id().is_numeric()
+            || input_type.data_type_id() == TypeID::IPv4
+        {
             Ok(StringType::arc())
         } else {
             Err(ErrorCode::IllegalDataType(format!(
-                "Expected numeric or null type, but got {}",
+                "Expected numeric, IPv4 or null type, but got {}",
                 args[0].name()
             )))
         }?;
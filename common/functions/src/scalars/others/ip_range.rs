@@ -0,0 +1,148 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::cast_column_field;
+use crate::scalars::scalar_binary_op;
+use crate::scalars::EvalContext;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// Checks whether an IP address is in a CIDR range, e.g. `ip << '10.0.0.0/8'`.
+/// The address may be an IPv4, an IPv6 or the text of either, they are compared as IPv6
+/// with the IPv4 addresses and ranges mapped into `::ffff:0:0/96`.
+#[derive(Clone)]
+pub struct IpInRangeFunction {
+    display_name: String,
+}
+
+impl IpInRangeFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(IpInRangeFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+    }
+
+    /// Parse a CIDR range `address/prefix` into the IPv6 network and the prefix length.
+    /// A range without the prefix contains the address only.
+    fn parse_cidr(cidr: &[u8]) -> Result<([u8; IPV6_SIZE], usize)> {
+        let text = String::from_utf8_lossy(cidr);
+        let (address, prefix) = match text.split_once('/') {
+            Some((address, prefix)) => (address, Some(prefix)),
+            None => (text.as_ref(), None),
+        };
+
+        let is_ipv4 = IPv4Type::parse(address.as_bytes()).is_some();
+        let max_prefix = if is_ipv4 { 32 } else { 128 };
+        let network = IPv6Type::parse(address.as_bytes());
+        let prefix = match prefix {
+            Some(prefix) => prefix.trim().parse::<usize>().ok(),
+            None => Some(max_prefix),
+        };
+
+        match (network, prefix) {
+            (Some(network), Some(prefix)) if prefix <= max_prefix => {
+                let prefix = if is_ipv4 { prefix + 96 } else { prefix };
+                Ok((network, prefix))
+            }
+            _ => Err(ErrorCode::BadArguments(format!(
+                "Invalid CIDR range {:?}",
+                text
+            ))),
+        }
+    }
+
+    fn contains(network: &[u8; IPV6_SIZE], prefix: usize, address: &[u8]) -> bool {
+        let bytes = prefix / 8;
+        if address.len() != IPV6_SIZE || network[..bytes] != address[..bytes] {
+            return false;
+        }
+        let bits = prefix % 8;
+        bits == 0 || {
+            let mask = 0xffu8 << (8 - bits);
+            network[bytes] & mask == address[bytes] & mask
+        }
+    }
+}
+
+impl Function for IpInRangeFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        if !args[0].data_type_id().is_ip() && !args[0].data_type_id().is_string() {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected IPv4, IPv6 or string, but got {:?}",
+                args[0]
+            )));
+        }
+        if !args[1].data_type_id().is_string() {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected string of a CIDR range, but got {:?}",
+                args[1]
+            )));
+        }
+        Ok(BooleanType::arc())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
+        let address = cast_column_field(&columns[0], &IPv6Type::arc())?;
+        let cidr = columns[1].column();
+
+        // the range is usually a constant, parse it once
+        if cidr.is_const() {
+            let (network, prefix) = Self::parse_cidr(&cidr.get(0).as_string()?)?;
+            let viewer = Vu8::try_create_viewer(&address)?;
+            let col = BooleanColumn::from_iterator(
+                viewer
+                    .iter()
+                    .map(|address| Self::contains(&network, prefix, address)),
+            );
+            return Ok(Arc::new(col));
+        }
+
+        let col = scalar_binary_op::<Vu8, Vu8, bool, _>(
+            &address,
+            cidr,
+            |address, cidr, ctx| match Self::parse_cidr(cidr) {
+                Ok((network, prefix)) => Self::contains(&network, prefix, address),
+                Err(e) => {
+                    ctx.set_error(e);
+                    false
+                }
+            },
+            &mut EvalContext::default(),
+        )?;
+        Ok(Arc::new(col))
+    }
+}
+
+impl fmt::Display for IpInRangeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -13,22 +13,31 @@
 // limitations under the License.
 
 mod exists;
+mod greatest;
+mod humanize;
 mod ignore;
-mod in_basic;
+mod in_list;
 mod inet_aton;
 mod inet_ntoa;
+mod ip_range;
 mod other;
 mod running_difference_function;
 mod sleep;
 mod to_type_name;
 
 pub use exists::ExistsFunction;
+pub use greatest::GreatestFunction;
+pub use greatest::LeastFunction;
+pub use greatest::LeastGreatestFunction;
+pub use humanize::HumanizeNumberFunction;
+pub use humanize::HumanizeSizeFunction;
 pub use ignore::IgnoreFunction;
-pub use in_basic::InFunction;
+pub use in_list::InListFunction;
 pub use inet_aton::InetAtonFunction;
 pub use inet_aton::TryInetAtonFunction;
 pub use inet_ntoa::InetNtoaFunction;
 pub use inet_ntoa::TryInetNtoaFunction;
+pub use ip_range::IpInRangeFunction;
 pub use other::OtherFunction;
 pub use running_difference_function::RunningDifferenceFunction;
 pub use sleep::SleepFunction;
@@ -18,8 +18,13 @@ use super::inet_ntoa::InetNtoaFunction;
 use super::inet_ntoa::TryInetNtoaFunction;
 use super::running_difference_function::RunningDifferenceFunction;
 use super::ExistsFunction;
+use super::GreatestFunction;
+use super::HumanizeNumberFunction;
+use super::HumanizeSizeFunction;
 use super::IgnoreFunction;
-use super::InFunction;
+use super::InListFunction;
+use super::IpInRangeFunction;
+use super::LeastFunction;
 use super::SleepFunction;
 use super::ToTypeNameFunction;
 use crate::scalars::FunctionFactory;
@@ -29,29 +34,42 @@ pub struct OtherFunction {}
 
 impl OtherFunction {
     pub fn register(factory: &mut FunctionFactory) {
-        factory.register("in", InFunction::<false>::desc());
-        factory.register("not_in", InFunction::<true>::desc());
+        factory.register("in", InListFunction::<false>::desc());
+        factory.register("not_in", InListFunction::<true>::desc());
         factory.register("exists", ExistsFunction::desc());
         factory.register("totypename", ToTypeNameFunction::desc());
+        factory.register("typeof", ToTypeNameFunction::desc());
         factory.register("sleep", SleepFunction::desc());
 
         factory.register("runningDifference", RunningDifferenceFunction::desc());
         factory.register("ignore", IgnoreFunction::desc());
+        factory.register("humanize_size", HumanizeSizeFunction::desc());
+        factory.register("humanize_number", HumanizeNumberFunction::desc());
+        factory.register("greatest", GreatestFunction::desc());
+        factory.register("least", LeastFunction::desc());
 
         // inet_aton
         factory.register("inet_aton", InetAtonFunction::desc());
         factory.register("IPv4StringToNum", InetAtonFunction::desc());
+        factory.register("ipv4_string_to_num", InetAtonFunction::desc());
 
         // try_inet_aton
         factory.register("try_inet_aton", TryInetAtonFunction::desc());
         factory.register("TryIPv4StringToNum", TryInetAtonFunction::desc());
+        factory.register("try_ipv4_string_to_num", TryInetAtonFunction::desc());
 
         // inet_ntoa
         factory.register("inet_ntoa", InetNtoaFunction::desc());
         factory.register("IPv4NumToString", InetNtoaFunction::desc());
+        factory.register("ipv4_num_to_string", InetNtoaFunction::desc());
 
         // try_inet_ntoa
         factory.register("try_inet_ntoa", TryInetNtoaFunction::desc());
         factory.register("TryIPv4NumToString", TryInetNtoaFunction::desc());
+        factory.register("try_ipv4_num_to_string", TryInetNtoaFunction::desc());
+
+        // ip << cidr
+        factory.register("<<", IpInRangeFunction::desc());
+        factory.register("is_ip_address_in_range", IpInRangeFunction::desc());
     }
 }
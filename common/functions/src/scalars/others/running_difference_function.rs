@@ -44,6 +44,7 @@ impl RunningDifferenceFunction {
     pub fn desc() -> FunctionDescription {
         FunctionDescription::creator(Box::new(Self::try_create)).features(
             FunctionFeatures::default()
+                .volatile()
                 .disable_passthrough_null()
                 .num_arguments(1),
         )
@@ -38,7 +38,7 @@ impl SleepFunction {
 
     pub fn desc() -> FunctionDescription {
         FunctionDescription::creator(Box::new(Self::try_create))
-            .features(FunctionFeatures::default().num_arguments(1))
+            .features(FunctionFeatures::default().volatile().num_arguments(1))
     }
 }
 
@@ -0,0 +1,259 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde_json::Value as JsonValue;
+
+use crate::scalars::ArrayGetFunction;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+use crate::scalars::MapGetFunction;
+use crate::scalars::TypedFunctionDescription;
+
+/// An element of a path into a variant, the key of an object or the index of an array.
+#[derive(Clone, Debug, PartialEq)]
+pub enum JsonPathElement {
+    Key(String),
+    Index(usize),
+}
+
+impl JsonPathElement {
+    /// The key of the element, None if the key is NULL or of an unsupported type.
+    fn try_from_value(value: &DataValue) -> Option<Self> {
+        match value {
+            DataValue::String(v) => Some(Self::Key(String::from_utf8_lossy(v).into_owned())),
+            DataValue::UInt64(v) => Some(Self::Index(*v as usize)),
+            DataValue::Int64(v) if *v >= 0 => Some(Self::Index(*v as usize)),
+            _ => None,
+        }
+    }
+
+    fn get<'a>(&self, value: &'a JsonValue) -> Option<&'a JsonValue> {
+        match (self, value) {
+            (Self::Key(key), JsonValue::Object(object)) => object.get(key),
+            (Self::Index(index), JsonValue::Array(array)) => array.get(*index),
+            _ => None,
+        }
+    }
+}
+
+/// Parses paths like `a.b[0]["c d"]`, keys are separated by dots or quoted in brackets,
+/// array indexes are in brackets.
+pub fn parse_json_path(path: &str) -> Result<Vec<JsonPathElement>> {
+    let bad_path = || ErrorCode::BadArguments(format!("Invalid JSON path: '{}'", path));
+
+    let chars = path.chars().collect::<Vec<_>>();
+    let mut elements = vec![];
+    let mut pos = 0;
+    while pos < chars.len() {
+        match chars[pos] {
+            '[' => {
+                let end = (pos + 1..chars.len())
+                    .find(|i| chars[*i] == ']')
+                    .ok_or_else(bad_path)?;
+                let inner = chars[pos + 1..end].iter().collect::<String>();
+                let inner = inner.trim();
+                let element = match inner.chars().next() {
+                    Some(quote @ ('"' | '\'')) if inner.len() >= 2 && inner.ends_with(quote) => {
+                        JsonPathElement::Key(inner[1..inner.len() - 1].to_string())
+                    }
+                    _ => JsonPathElement::Index(inner.parse().map_err(|_| bad_path())?),
+                };
+                elements.push(element);
+                pos = end + 1;
+            }
+            '.' if !elements.is_empty()
+                && !matches!(chars.get(pos + 1), None | Some('.' | '[')) =>
+            {
+                pos += 1
+            }
+            _ => {
+                let end = (pos..chars.len())
+                    .find(|i| chars[*i] == '.' || chars[*i] == '[')
+                    .unwrap_or(chars.len());
+                if end == pos {
+                    return Err(bad_path());
+                }
+                elements.push(JsonPathElement::Key(chars[pos..end].iter().collect()));
+                pos = end;
+            }
+        }
+    }
+    if elements.is_empty() {
+        return Err(bad_path());
+    }
+    Ok(elements)
+}
+
+fn check_variant_type(name: &str, data_type: &DataTypePtr) -> Result<()> {
+    match remove_nullable(data_type).data_type_id() {
+        TypeID::Null | TypeID::Variant | TypeID::VariantArray | TypeID::VariantObject => Ok(()),
+        _ => Err(ErrorCode::BadArguments(format!(
+            "Invalid argument types for function '{}': {}",
+            name,
+            data_type.name()
+        ))),
+    }
+}
+
+/// Looks up the elements of the path, given row by row by `elements`, in every row of the
+/// variant column, NULL if the variant is NULL or the path doesn't exist.
+fn eval_json_path<F>(column: &ColumnRef, input_rows: usize, elements: F) -> Result<ColumnRef>
+where F: Fn(usize) -> Result<Option<Vec<JsonPathElement>>> {
+    let mut builder = NullableColumnBuilder::<JsonValue>::with_capacity(input_rows);
+    for row in 0..input_rows {
+        let value = match (column.get(row), elements(row)?) {
+            (DataValue::Json(value), Some(elements)) => elements
+                .iter()
+                .try_fold(&value, |value, element| element.get(value))
+                .cloned(),
+            _ => None,
+        };
+        match value {
+            Some(value) => builder.append(&value, true),
+            None => builder.append_null(),
+        }
+    }
+    Ok(builder.build(input_rows))
+}
+
+/// `get(variant, key)`, also written as `variant[key]`, returns the field of an object by a
+/// string key or the element of an array by an integer index, NULL if there is no such one.
+#[derive(Clone)]
+pub struct VariantGetFunction {
+    display_name: String,
+}
+
+impl VariantGetFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(VariantGetFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+}
+
+impl Function for VariantGetFunction {
+    fn name(&self) -> &str {
+        &self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        check_variant_type(&self.display_name, args[0])?;
+        Ok(wrap_nullable(&VariantType::arc()))
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let keys = columns[1].column();
+        eval_json_path(columns[0].column(), input_rows, |row| {
+            Ok(JsonPathElement::try_from_value(&keys.get(row)).map(|element| vec![element]))
+        })
+    }
+}
+
+impl fmt::Display for VariantGetFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
+
+/// `get` looks up a key in a map, an array or a variant, dispatched on the type of the first
+/// argument.
+pub struct GetFunction;
+
+impl GetFunction {
+    pub fn try_create(display_name: &str, args: &[&DataTypePtr]) -> Result<Box<dyn Function>> {
+        match remove_nullable(args[0]).data_type_id() {
+            TypeID::Map => MapGetFunction::try_create(display_name),
+            TypeID::Array => ArrayGetFunction::try_create(display_name),
+            _ => {
+                check_variant_type(display_name, args[0])?;
+                VariantGetFunction::try_create(display_name)
+            }
+        }
+    }
+
+    pub fn desc() -> TypedFunctionDescription {
+        TypedFunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .disable_passthrough_null()
+                .num_arguments(2),
+        )
+    }
+}
+
+/// `get_path(variant, path)` follows a path like `a.b[0]` into the variant, NULL if the path
+/// doesn't exist.
+#[derive(Clone)]
+pub struct GetPathFunction {
+    display_name: String,
+}
+
+impl GetPathFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(GetPathFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .disable_passthrough_null()
+                .num_arguments(2),
+        )
+    }
+}
+
+impl Function for GetPathFunction {
+    fn name(&self) -> &str {
+        &self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        check_variant_type(&self.display_name, args[0])?;
+        match remove_nullable(args[1]).data_type_id() {
+            TypeID::Null | TypeID::String => Ok(wrap_nullable(&VariantType::arc())),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "Invalid argument types for function '{}': the path must be a string, but got {}",
+                self.display_name,
+                args[1].name()
+            ))),
+        }
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let paths = columns[1].column();
+        eval_json_path(columns[0].column(), input_rows, |row| {
+            match paths.get(row) {
+                DataValue::String(path) => {
+                    parse_json_path(&String::from_utf8_lossy(&path)).map(Some)
+                }
+                _ => Ok(None),
+            }
+        })
+    }
+}
+
+impl fmt::Display for GetPathFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde_json::Map;
+use serde_json::Value as JsonValue;
+
+use crate::scalars::ArgumentType;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// `json_object(key1, value1, key2, value2, ...)` builds a variant object from the key and value
+/// pairs, the keys are casted to strings and the null values are kept as JSON nulls.
+#[derive(Clone)]
+pub struct JsonObjectFunction {
+    display_name: String,
+}
+
+impl JsonObjectFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(JsonObjectFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .disable_passthrough_null()
+                .variadic_arguments(0, 1024)
+                .variadic_argument_types(&[ArgumentType::String, ArgumentType::Any]),
+        )
+    }
+}
+
+impl Function for JsonObjectFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, _args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        Ok(VariantType::arc())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let mut objects = vec![Map::new(); input_rows];
+        for pair in columns.chunks(2) {
+            if pair[0].data_type().is_null() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "The keys of {} can't be NULL",
+                    self.display_name
+                )));
+            }
+
+            let keys = Vu8::try_create_viewer(pair[0].column())?;
+            let values = pair[1]
+                .data_type()
+                .create_serializer()
+                .serialize_json(&pair[1].column().convert_full_column())?;
+
+            for (row, (object, value)) in objects.iter_mut().zip(values).enumerate() {
+                if keys.null_at(row) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "The keys of {} can't be NULL",
+                        self.display_name
+                    )));
+                }
+                let key = String::from_utf8_lossy(keys.value_at(row)).to_string();
+                object.insert(key, value);
+            }
+        }
+
+        let mut builder = ColumnBuilder::<JsonValue>::with_capacity(input_rows);
+        for object in objects {
+            builder.append(&JsonValue::Object(object));
+        }
+        Ok(builder.build(input_rows))
+    }
+}
+
+impl fmt::Display for JsonObjectFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -13,10 +13,20 @@
 // limitations under the License.
 
 mod check_json;
+mod get;
+mod json_object;
 mod parse_json;
 mod semi_structured;
+mod to_json;
 
 pub use check_json::CheckJsonFunction;
+pub use get::parse_json_path;
+pub use get::GetFunction;
+pub use get::GetPathFunction;
+pub use get::JsonPathElement;
+pub use get::VariantGetFunction;
+pub use json_object::JsonObjectFunction;
 pub use parse_json::ParseJsonFunction;
 pub use parse_json::TryParseJsonFunction;
 pub use semi_structured::SemiStructuredFunction;
+pub use to_json::ToJsonFunction;
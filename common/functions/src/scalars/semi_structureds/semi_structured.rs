@@ -12,10 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::get::GetFunction;
+use super::get::GetPathFunction;
+use super::json_object::JsonObjectFunction;
 use super::parse_json::ParseJsonFunction;
 use super::parse_json::TryParseJsonFunction;
 use crate::scalars::CheckJsonFunction;
 use crate::scalars::FunctionFactory;
+use crate::scalars::ToJsonFunction;
 
 pub struct SemiStructuredFunction;
 
@@ -24,5 +28,9 @@ impl SemiStructuredFunction {
         factory.register("parse_json", ParseJsonFunction::desc());
         factory.register("try_parse_json", TryParseJsonFunction::desc());
         factory.register("check_json", CheckJsonFunction::desc());
+        factory.register("to_json", ToJsonFunction::desc());
+        factory.register("get_path", GetPathFunction::desc());
+        factory.register("json_object", JsonObjectFunction::desc());
+        factory.register_typed("get", GetFunction::desc());
     }
 }
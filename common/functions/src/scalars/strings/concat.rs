@@ -17,7 +17,7 @@ use std::fmt;
 use common_datavalues::prelude::*;
 use common_exception::Result;
 
-use crate::scalars::assert_string;
+use crate::scalars::ArgumentType;
 use crate::scalars::Function;
 use crate::scalars::FunctionDescription;
 use crate::scalars::FunctionFeatures;
@@ -38,7 +38,8 @@ impl ConcatFunction {
         FunctionDescription::creator(Box::new(Self::try_create)).features(
             FunctionFeatures::default()
                 .deterministic()
-                .variadic_arguments(1, 1024),
+                .variadic_arguments(1, 1024)
+                .variadic_argument_types(&[ArgumentType::String]),
         )
     }
 }
@@ -48,10 +49,7 @@ impl Function for ConcatFunction {
         "concat"
     }
 
-    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
-        for arg in args {
-            assert_string(*arg)?;
-        }
+    fn return_type(&self, _args: &[&DataTypePtr]) -> Result<DataTypePtr> {
         Ok(Vu8::to_data_type())
     }
 
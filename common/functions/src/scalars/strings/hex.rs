@@ -49,7 +49,10 @@ impl Function for HexFunction {
     }
 
     fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
-        if !args[0].data_type_id().is_numeric() && !args[0].data_type_id().is_string() {
+        if !args[0].data_type_id().is_numeric()
+            && !args[0].data_type_id().is_string()
+            && !args[0].data_type_id().is_binary()
+        {
             return Err(ErrorCode::IllegalDataType(format!(
                 "Expected integer or string but got {}",
                 args[0].data_type_id()
@@ -76,8 +79,8 @@ impl Function for HexFunction {
                 });
                 Ok(Arc::new(StringColumn::from_owned_iterator(iter)))
             }
-            TypeID::String => {
-                let col = cast_column_field(&columns[0], &StringType::arc())?;
+            TypeID::String | TypeID::Binary => {
+                let col = cast_column_field(&columns[0], &BinaryType::arc())?;
                 let col = col.as_any().downcast_ref::<StringColumn>().unwrap();
                 let iter = col.iter().map(|val| {
                     let mut buffer = vec![0u8; val.len() * 2];
@@ -17,9 +17,8 @@ use std::fmt;
 use common_datavalues::prelude::*;
 use common_exception::Result;
 
-use crate::scalars::assert_numeric;
-use crate::scalars::assert_string;
 use crate::scalars::default_column_cast;
+use crate::scalars::ArgumentType;
 use crate::scalars::Function;
 use crate::scalars::FunctionDescription;
 use crate::scalars::FunctionFeatures;
@@ -45,9 +44,15 @@ impl<const T: u8> LocatingFunction<T> {
     }
 
     pub fn desc() -> FunctionDescription {
-        let mut feature = FunctionFeatures::default().deterministic();
+        let mut feature = FunctionFeatures::default()
+            .deterministic()
+            .argument_types(&[ArgumentType::String, ArgumentType::String]);
         feature = if T == FUNC_LOCATE {
-            feature.variadic_arguments(2, 3)
+            feature.variadic_arguments(2, 3).argument_types(&[
+                ArgumentType::String,
+                ArgumentType::String,
+                ArgumentType::Numeric,
+            ])
         } else {
             feature.num_arguments(2)
         };
@@ -61,12 +66,7 @@ impl<const T: u8> Function for LocatingFunction<T> {
         &*self.display_name
     }
 
-    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
-        assert_string(args[0])?;
-        assert_string(args[1])?;
-        if args.len() > 2 {
-            assert_numeric(args[2])?;
-        }
+    fn return_type(&self, _args: &[&DataTypePtr]) -> Result<DataTypePtr> {
         Ok(u64::to_data_type())
     }
 
@@ -38,6 +38,8 @@ mod pad;
 mod quote;
 mod regexp_instr;
 mod regexp_like;
+mod regexp_replace;
+mod regexp_substr;
 mod repeat;
 mod replace;
 mod reverse;
@@ -83,6 +85,8 @@ pub use pad::RightPadFunction;
 pub use quote::QuoteFunction;
 pub use regexp_instr::RegexpInStrFunction;
 pub use regexp_like::RegexpLikeFunction;
+pub use regexp_replace::RegexpReplaceFunction;
+pub use regexp_substr::RegexpSubStrFunction;
 pub use repeat::RepeatFunction;
 pub use replace::ReplaceFunction;
 pub use reverse::ReverseFunction;
@@ -0,0 +1,239 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use bstr::ByteSlice;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use itertools::izip;
+use regex::bytes::Regex;
+
+use crate::scalars::assert_string;
+use crate::scalars::cast_column_field;
+use crate::scalars::strings::regexp_like::build_regexp_from_pattern;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+#[derive(Clone)]
+pub struct RegexpReplaceFunction {
+    display_name: String,
+}
+
+impl RegexpReplaceFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(Self {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .variadic_arguments(3, 6),
+        )
+    }
+}
+
+impl Function for RegexpReplaceFunction {
+    fn name(&self) -> &str {
+        &self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        for (i, arg) in args.iter().enumerate() {
+            if i < 3 || i == 5 {
+                assert_string(*arg)?;
+            } else if !arg.data_type_id().is_integer()
+                && !arg.data_type_id().is_string()
+                && !arg.data_type_id().is_null()
+            {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Expected integer or string or null, but got {}",
+                    args[i].data_type_id()
+                )));
+            }
+        }
+
+        Ok(StringType::arc())
+    }
+    // Notes: https://dev.mysql.com/doc/refman/8.0/en/regexp.html#function_regexp-replace
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let mut pos = ConstColumn::new(Series::from_data(vec![1_i64]), input_rows).arc();
+        let mut occurrence = ConstColumn::new(Series::from_data(vec![0_i64]), input_rows).arc();
+        let mut match_type = ConstColumn::new(Series::from_data(vec![""]), input_rows).arc();
+
+        for i in 3..columns.len() {
+            match i {
+                3 => pos = cast_column_field(&columns[3], &Int64Type::arc())?,
+                4 => occurrence = cast_column_field(&columns[4], &Int64Type::arc())?,
+                _ => match_type = cast_column_field(&columns[5], &StringType::arc())?,
+            }
+        }
+
+        let source = columns[0].column();
+        let pat = columns[1].column();
+        let repl = columns[2].column();
+
+        if pat.is_const() && match_type.is_const() {
+            let pat_value = pat.get_string(0)?;
+            let mt_value = match_type.get_string(0)?;
+
+            return self.a_regexp_replace_binary_scalar(
+                source,
+                &pat_value,
+                repl,
+                &pos,
+                &occurrence,
+                &mt_value,
+            );
+        }
+
+        self.a_regexp_replace_binary(source, pat, repl, &pos, &occurrence, &match_type)
+    }
+}
+
+impl RegexpReplaceFunction {
+    fn a_regexp_replace_binary_scalar(
+        &self,
+        source: &ColumnRef,
+        pat: &[u8],
+        repl: &ColumnRef,
+        pos: &ColumnRef,
+        occurrence: &ColumnRef,
+        mt: &[u8],
+    ) -> Result<ColumnRef> {
+        let rows = source.len();
+        let mut builder: ColumnBuilder<Vu8> = ColumnBuilder::with_capacity(rows);
+        let mut buf: Vec<u8> = Vec::new();
+
+        let source = Vu8::try_create_viewer(source)?;
+        let repl = Vu8::try_create_viewer(repl)?;
+        let pos = i64::try_create_viewer(pos)?;
+        let occur = i64::try_create_viewer(occurrence)?;
+
+        let re = build_regexp_from_pattern(self.name(), pat, Some(mt))?;
+
+        let iter = izip!(source, repl, pos, occur);
+        for (s_value, repl_value, pos_value, occur_value) in iter {
+            if s_value.is_empty() || pat.is_empty() {
+                builder.append(s_value);
+                continue;
+            }
+
+            regexp_replace(s_value, &re, repl_value, pos_value, occur_value, &mut buf);
+            builder.append(&buf);
+            buf.clear();
+        }
+
+        Ok(builder.build(rows))
+    }
+
+    fn a_regexp_replace_binary(
+        &self,
+        source: &ColumnRef,
+        pat: &ColumnRef,
+        repl: &ColumnRef,
+        pos: &ColumnRef,
+        occurrence: &ColumnRef,
+        match_type: &ColumnRef,
+    ) -> Result<ColumnRef> {
+        let rows = source.len();
+        let mut builder: ColumnBuilder<Vu8> = ColumnBuilder::with_capacity(rows);
+        let mut buf: Vec<u8> = Vec::new();
+
+        let mut map: HashMap<Vec<u8>, Regex> = HashMap::new();
+        let mut key: Vec<u8> = Vec::new();
+
+        let source = Vu8::try_create_viewer(source)?;
+        let pat = Vu8::try_create_viewer(pat)?;
+        let repl = Vu8::try_create_viewer(repl)?;
+        let pos = i64::try_create_viewer(pos)?;
+        let occur = i64::try_create_viewer(occurrence)?;
+        let mt = Vu8::try_create_viewer(match_type)?;
+
+        let iter = izip!(source, pat, repl, pos, occur, mt);
+        for (s_value, pat_value, repl_value, pos_value, occur_value, mt_value) in iter {
+            if mt_value.starts_with_str("-") {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Incorrect arguments to {} match type: {}",
+                    self.name(),
+                    mt_value.to_str_lossy(),
+                )));
+            }
+            if s_value.is_empty() || pat_value.is_empty() {
+                builder.append(s_value);
+                continue;
+            }
+
+            key.extend_from_slice(pat_value);
+            key.extend_from_slice("-".as_bytes());
+            key.extend_from_slice(mt_value);
+            let re = if let Some(re) = map.get(&key) {
+                re
+            } else {
+                let re = build_regexp_from_pattern(self.name(), pat_value, Some(mt_value))?;
+                map.insert(key.clone(), re);
+                map.get(&key).unwrap()
+            };
+            key.clear();
+
+            regexp_replace(s_value, re, repl_value, pos_value, occur_value, &mut buf);
+            builder.append(&buf);
+            buf.clear();
+        }
+
+        Ok(builder.build(rows))
+    }
+}
+
+/// Replaces the `occur`-th match of `re` in `s` starting at the 1-based `pos`, all the matches
+/// are replaced if `occur` is 0. The replacement may refer to the capture groups with `$n`.
+#[inline]
+fn regexp_replace(s: &[u8], re: &Regex, repl: &[u8], pos: i64, occur: i64, buf: &mut Vec<u8>) {
+    let pos = if pos < 1 { 0 } else { (pos - 1) as usize };
+    if pos >= s.len() {
+        buf.extend_from_slice(s);
+        return;
+    }
+    let occur = if occur < 0 { 0 } else { occur };
+
+    buf.extend_from_slice(&s[..pos]);
+    let mut last = pos;
+    for (i, caps) in re.captures_iter(&s[pos..]).enumerate() {
+        if occur > 0 && (i as i64) + 1 < occur {
+            continue;
+        }
+
+        let m = caps.get(0).unwrap();
+        buf.extend_from_slice(&s[last..pos + m.start()]);
+        caps.expand(repl, buf);
+        last = pos + m.end();
+
+        if occur > 0 {
+            break;
+        }
+    }
+    buf.extend_from_slice(&s[last..]);
+}
+
+impl fmt::Display for RegexpReplaceFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
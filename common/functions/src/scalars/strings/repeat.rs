@@ -18,7 +18,7 @@ use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
-use crate::scalars::cast_column_field;
+use crate::scalars::ArgumentType;
 use crate::scalars::Function;
 use crate::scalars::FunctionDescription;
 use crate::scalars::FunctionFeatures;
@@ -38,8 +38,12 @@ impl RepeatFunction {
     }
 
     pub fn desc() -> FunctionDescription {
-        FunctionDescription::creator(Box::new(Self::try_create))
-            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .num_arguments(2)
+                .argument_types(&[ArgumentType::String, ArgumentType::UInt64]),
+        )
     }
 }
 
@@ -48,30 +52,13 @@ impl Function for RepeatFunction {
         "repeat"
     }
 
-    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
-        if !args[0].data_type_id().is_string() && !args[0].data_type_id().is_null() {
-            return Err(ErrorCode::IllegalDataType(format!(
-                "Expected parameter 1 is string, but got {}",
-                args[0].data_type_id()
-            )));
-        }
-
-        if !args[1].data_type_id().is_unsigned_integer() && !args[1].data_type_id().is_null() {
-            return Err(ErrorCode::IllegalDataType(format!(
-                "Expected parameter 2 is unsigned integer or null, but got {}",
-                args[1].data_type_id()
-            )));
-        }
-
+    fn return_type(&self, _args: &[&DataTypePtr]) -> Result<DataTypePtr> {
         Ok(StringType::arc())
     }
 
     fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
-        let col1 = cast_column_field(&columns[0], &StringType::arc())?;
-        let col1_viewer = Vu8::try_create_viewer(&col1)?;
-
-        let col2 = cast_column_field(&columns[1], &UInt64Type::arc())?;
-        let col2_viewer = u64::try_create_viewer(&col2)?;
+        let col1_viewer = Vu8::try_create_viewer(columns[0].column())?;
+        let col2_viewer = u64::try_create_viewer(columns[1].column())?;
 
         let mut builder = ColumnBuilder::<Vu8>::with_capacity(input_rows);
 
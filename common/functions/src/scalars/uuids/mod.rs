@@ -16,6 +16,7 @@ mod uuid;
 mod uuid_creator;
 mod uuid_verifier;
 
+pub use uuid_creator::RandomUUIDFunction;
 pub use uuid_creator::UUIDZeroFunction;
 pub use uuid_creator::UUIDv4Function;
 pub use uuid_verifier::UUIDIsEmptyFunction;
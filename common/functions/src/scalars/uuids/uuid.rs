@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::uuid_creator::RandomUUIDFunction;
 use super::uuid_creator::UUIDZeroFunction;
 use super::uuid_creator::UUIDv4Function;
 use super::uuid_verifier::UUIDIsEmptyFunction;
@@ -24,6 +25,7 @@ impl UUIDFunction {
     pub fn register(factory: &mut FunctionFactory) {
         factory.register("generateUUIDv4", UUIDv4Function::desc());
         factory.register("zeroUUID", UUIDZeroFunction::desc());
+        factory.register("gen_random_uuid", RandomUUIDFunction::desc());
         factory.register("isemptyUUID", UUIDIsEmptyFunction::desc());
         factory.register("isnotemptyUUID", UUIDIsNotEmptyFunction::desc());
     }
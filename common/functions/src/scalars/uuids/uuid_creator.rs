@@ -17,9 +17,12 @@ use std::marker::PhantomData;
 
 use common_datavalues::Column;
 use common_datavalues::ConstColumn;
+use common_datavalues::MutableColumn;
+use common_datavalues::MutableStringColumn;
 use common_datavalues::NewColumn;
 use common_datavalues::StringColumn;
 use common_datavalues::StringType;
+use common_datavalues::UuidType;
 use common_exception::Result;
 use uuid::Uuid;
 
@@ -48,7 +51,7 @@ where T: UUIDCreator + Clone + Sync + Send + 'static
 
     pub fn desc() -> FunctionDescription {
         FunctionDescription::creator(Box::new(Self::try_create))
-            .features(FunctionFeatures::default())
+            .features(FunctionFeatures::default().volatile())
     }
 }
 
@@ -105,3 +108,53 @@ where T: UUIDCreator + Clone + Sync + Send + 'static
         Ok(ConstColumn::new(col.arc(), input_rows).arc())
     }
 }
+
+/// gen_random_uuid() returns a random version 4 UUID of type UUID for every row.
+#[derive(Clone, Debug)]
+pub struct RandomUUIDFunction {
+    display_name: String,
+}
+
+impl RandomUUIDFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(RandomUUIDFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().volatile())
+    }
+}
+
+impl fmt::Display for RandomUUIDFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}()", self.display_name)
+    }
+}
+
+impl Function for RandomUUIDFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn return_type(
+        &self,
+        _args: &[&common_datavalues::DataTypePtr],
+    ) -> Result<common_datavalues::DataTypePtr> {
+        Ok(UuidType::arc())
+    }
+
+    fn eval(
+        &self,
+        _columns: &common_datavalues::ColumnsWithField,
+        input_rows: usize,
+    ) -> Result<common_datavalues::ColumnRef> {
+        let mut builder = MutableStringColumn::with_capacity(input_rows);
+        for _ in 0..input_rows {
+            builder.append_value(Uuid::new_v4().as_bytes());
+        }
+        Ok(builder.to_column())
+    }
+}
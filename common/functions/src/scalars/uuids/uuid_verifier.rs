@@ -54,6 +54,7 @@ where T: UUIDVerifier + Clone + Sync + Send + 'static
     pub fn desc() -> FunctionDescription {
         FunctionDescription::creator(Box::new(Self::try_create)).features(
             FunctionFeatures::default()
+                .deterministic()
                 .disable_passthrough_null()
                 .num_arguments(1),
         )
@@ -109,9 +110,12 @@ where T: UUIDVerifier + Clone + Sync + Send + 'static
         &self,
         args: &[&common_datavalues::DataTypePtr],
     ) -> Result<common_datavalues::DataTypePtr> {
-        if args[0].data_type_id() != TypeID::String && args[0].data_type_id() != TypeID::Null {
+        if !matches!(
+            args[0].data_type_id(),
+            TypeID::String | TypeID::Uuid | TypeID::Null
+        ) {
             return Err(ErrorCode::IllegalDataType(format!(
-                "Expected string or null, but got {:?}",
+                "Expected string, uuid or null, but got {:?}",
                 args[0]
             )));
         }
@@ -124,7 +128,16 @@ where T: UUIDVerifier + Clone + Sync + Send + 'static
         columns: &common_datavalues::ColumnsWithField,
         _input_rows: usize,
     ) -> Result<common_datavalues::ColumnRef> {
-        let result_column = if columns[0].data_type().data_type_id() == TypeID::String {
+        let data_type_id = columns[0].data_type().data_type_id();
+        let result_column = if data_type_id == TypeID::Uuid {
+            let viewer = Vu8::try_create_viewer(columns[0].column())?;
+            BooleanColumn::from_iterator(viewer.iter().map(|uuid_bytes| {
+                match Uuid::from_slice(uuid_bytes) {
+                    Ok(uuid) => T::verify(uuid),
+                    Err(_) => T::default_verify(),
+                }
+            }))
+        } else if data_type_id == TypeID::String {
             let viewer = Vu8::try_create_viewer(columns[0].column())?;
             BooleanColumn::from_iterator(viewer.iter().map(|uuid_bytes| {
                 if let Ok(uuid_str) = str::from_utf8(uuid_bytes) {
@@ -15,10 +15,13 @@
 use std::borrow::BorrowMut;
 
 use bumpalo::Bump;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
 use common_datavalues::prelude::*;
 use common_datavalues::with_match_primitive_type_id;
 use common_exception::Result;
 use common_functions::aggregates::*;
+use common_io::prelude::BytesMut;
 use float_cmp::approx_eq;
 use pretty_assertions::assert_eq;
 
@@ -1068,3 +1071,232 @@ fn test_aggregate_function_on_boolean() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_aggregate_bool_functions() -> Result<()> {
+    let values = Series::from_data(vec![true, false, true, true]);
+    let all_true = Series::from_data(vec![true, true]);
+    let nullable = Series::from_data(vec![Some(true), None, Some(true), None]);
+    let all_null = Series::from_data(vec![Option::<bool>::None, None]);
+
+    let tests = vec![
+        ("bool_and", values.clone(), DataValue::Boolean(false)),
+        ("bool_and", all_true.clone(), DataValue::Boolean(true)),
+        ("bool_and", nullable.clone(), DataValue::Boolean(true)),
+        ("bool_and", all_null.clone(), DataValue::Null),
+        ("bool_or", values.clone(), DataValue::Boolean(true)),
+        ("bool_or", nullable.clone(), DataValue::Boolean(true)),
+        ("bool_or", all_null.clone(), DataValue::Null),
+        ("count_if", values.clone(), DataValue::UInt64(3)),
+        ("count_if", nullable.clone(), DataValue::UInt64(2)),
+        ("count_if", all_null.clone(), DataValue::UInt64(0)),
+    ];
+
+    let arena = Bump::new();
+    let factory = AggregateFunctionFactory::instance();
+    for (name, column, expect) in tests {
+        let arguments = vec![DataField::new("a", column.data_type())];
+        let func = factory.get(name, vec![], arguments)?;
+        let rows = column.len();
+
+        // accumulate the whole block and row by row, the results must be the same
+        let addr1 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr1.into());
+        func.accumulate(addr1.into(), &[column.clone()], None, rows)?;
+
+        let addr2 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr2.into());
+        for row in 0..rows {
+            func.accumulate_row(addr2.into(), &[column.clone()], row)?;
+        }
+
+        let mut builder = func.return_type()?.create_mutable(2);
+        func.merge_result(addr1.into(), builder.as_mut())?;
+        func.merge_result(addr2.into(), builder.as_mut())?;
+        let result = builder.to_column();
+        assert_eq!(result.get(0), expect, "{}({:?})", name, column);
+        assert_eq!(result.get(1), expect, "{}({:?})", name, column);
+    }
+
+    // rows filtered out by the validity are not aggregated
+    let func = factory.get("count_if", vec![], vec![DataField::new(
+        "a",
+        bool::to_data_type(),
+    )])?;
+    let addr = arena.alloc_layout(func.state_layout());
+    func.init_state(addr.into());
+    let validity: Bitmap = MutableBitmap::from_iter([true, false, false, true]).into();
+    func.accumulate(addr.into(), &[values], Some(&validity), 4)?;
+    let mut builder = func.return_type()?.create_mutable(1);
+    func.merge_result(addr.into(), builder.as_mut())?;
+    assert_eq!(builder.to_column().get(0), DataValue::UInt64(2));
+
+    Ok(())
+}
+
+#[test]
+fn test_aggregate_map_agg() -> Result<()> {
+    let arena = Bump::new();
+    let args = vec![
+        DataField::new("k", Vu8::to_data_type()),
+        DataField::new("v", i64::to_data_type()),
+    ];
+    let columns = vec![
+        Series::from_data(vec!["a", "b", "a"]),
+        Series::from_data(vec![1i64, 2, 3]),
+    ];
+
+    let factory = AggregateFunctionFactory::instance();
+    let func = factory.get("map_agg", vec![], args)?;
+    let map_type = func.return_type()?;
+    assert_eq!(map_type.name(), "Map(String, Int64)");
+
+    let addr1 = arena.alloc_layout(func.state_layout());
+    func.init_state(addr1.into());
+    func.accumulate(addr1.into(), &columns, None, 3)?;
+
+    // the state of another node, transferred to this one
+    let addr2 = arena.alloc_layout(func.state_layout());
+    func.init_state(addr2.into());
+    func.accumulate_row(
+        addr2.into(),
+        &[Series::from_data(vec!["c"]), Series::from_data(vec![4i64])],
+        0,
+    )?;
+    let mut buffer = BytesMut::new();
+    func.serialize(addr2.into(), &mut buffer)?;
+    let addr3 = arena.alloc_layout(func.state_layout());
+    func.init_state(addr3.into());
+    func.deserialize(addr3.into(), &mut buffer.as_ref())?;
+
+    func.merge(addr1.into(), addr3.into())?;
+    let mut array = map_type.create_mutable(1);
+    func.merge_result(addr1.into(), array.as_mut())?;
+
+    let entry = |key: &str, value: i64| {
+        DataValue::Struct(vec![
+            DataValue::String(key.as_bytes().to_vec()),
+            DataValue::Int64(value),
+        ])
+    };
+    let expect = DataValue::Array(vec![entry("a", 3), entry("b", 2), entry("c", 4)]);
+    assert_eq!(array.to_column().get(0), expect);
+    Ok(())
+}
+
+#[test]
+fn test_aggregate_frame_evaluator() -> Result<()> {
+    let column = Series::from_data(vec![Some(1i64), None, Some(3), Some(4), None]);
+    let field = DataField::new("a", wrap_nullable(&i64::to_data_type()));
+    let frames = [(0, 1), (0, 2), (1, 2), (1, 3), (2, 5), (4, 5), (5, 5)];
+
+    let factory = AggregateFunctionFactory::instance();
+    for name in ["sum", "count", "avg", "max", "count_if"] {
+        let (column, field) = match name {
+            "count_if" => (
+                Series::from_data(vec![Some(true), None, Some(false), Some(true), None]),
+                DataField::new("a", wrap_nullable(&bool::to_data_type())),
+            ),
+            _ => (column.clone(), field.clone()),
+        };
+
+        let func = factory.get(name, vec![], vec![field.clone()])?;
+        let mut evaluator = AggregateFrameEvaluator::create(func.clone(), &[column.clone()]);
+        let mut builder = func.return_type()?.create_mutable(frames.len());
+        for (start, end) in frames {
+            evaluator.evaluate(start, end, builder.as_mut())?;
+        }
+        let result = builder.to_column();
+
+        for (row, (start, end)) in frames.iter().enumerate() {
+            let frame = ColumnWithField::new(column.slice(*start, end - start), field.clone());
+            let expected = eval_aggr(name, vec![], &[frame], end - start)?;
+            assert_eq!(
+                expected.get(0),
+                result.get(row),
+                "{} over [{}, {})",
+                name,
+                start,
+                end
+            );
+        }
+    }
+
+    // the frames only move forward
+    let func = factory.get("sum", vec![], vec![field])?;
+    let mut evaluator = AggregateFrameEvaluator::create(func.clone(), &[column]);
+    let mut builder = func.return_type()?.create_mutable(2);
+    evaluator.evaluate(1, 3, builder.as_mut())?;
+    assert!(evaluator.evaluate(0, 3, builder.as_mut()).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_aggregate_accumulate_sorted() -> Result<()> {
+    let column = Series::from_data(vec![None, Some(1i32), Some(2), Some(5), None]);
+    let field = DataField::new("a", wrap_nullable(&i32::to_data_type()));
+
+    let factory = AggregateFunctionFactory::instance();
+    for (name, expected) in [("min", 1i32), ("max", 5)] {
+        let func = factory.get(name, vec![], vec![field.clone()])?;
+        let arena = Bump::new();
+        let place = arena.alloc_layout(func.state_layout());
+        func.init_state(place.into());
+        func.accumulate_sorted(place.into(), &[column.clone()], None, column.len())?;
+
+        let mut builder = func.return_type()?.create_mutable(1);
+        func.merge_result(place.into(), builder.as_mut())?;
+        assert_eq!(
+            builder.to_column().get(0),
+            DataValue::Int64(expected as i64)
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_aggregate_bitmap_agg() -> Result<()> {
+    let arena = Bump::new();
+    let args = vec![DataField::new("a", i64::to_data_type())];
+    let columns = vec![Series::from_data(vec![3i64, 1, 3])];
+
+    let factory = AggregateFunctionFactory::instance();
+    let func = factory.get("bitmap_agg", vec![], args.clone())?;
+    assert_eq!(func.return_type()?.data_type_id(), TypeID::Bitmap);
+
+    let addr1 = arena.alloc_layout(func.state_layout());
+    func.init_state(addr1.into());
+    func.accumulate(addr1.into(), &columns, None, 3)?;
+
+    // the state of another node, transferred to this one
+    let addr2 = arena.alloc_layout(func.state_layout());
+    func.init_state(addr2.into());
+    func.accumulate_row(addr2.into(), &[Series::from_data(vec![4294967296i64])], 0)?;
+    let mut buffer = BytesMut::new();
+    func.serialize(addr2.into(), &mut buffer)?;
+    let addr3 = arena.alloc_layout(func.state_layout());
+    func.init_state(addr3.into());
+    func.deserialize(addr3.into(), &mut buffer.as_ref())?;
+
+    func.merge(addr1.into(), addr3.into())?;
+    let mut array = BitmapType::arc().create_mutable(1);
+    func.merge_result(addr1.into(), array.as_mut())?;
+    let column = array.to_column();
+    let serializer = BitmapType::arc().create_serializer();
+    assert_eq!(serializer.serialize_column(&column)?, vec![
+        "1,3,4294967296".to_string()
+    ]);
+
+    // negative integers can not be added
+    let addr4 = arena.alloc_layout(func.state_layout());
+    func.init_state(addr4.into());
+    let result = func.accumulate(addr4.into(), &[Series::from_data(vec![-1i64])], None, 1);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "bitmap_agg can not add the negative value -1"
+    );
+
+    let args = vec![DataField::new("a", f64::to_data_type())];
+    assert!(factory.get("bitmap_agg", vec![], args).is_err());
+    Ok(())
+}
@@ -12,13 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_datavalues::chrono;
 use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_functions::scalars::*;
 
 use super::scalar_function2_test::test_scalar_functions;
+use super::scalar_function2_test::test_scalar_functions_with_type;
 use super::scalar_function2_test::ScalarFunctionTest;
+use super::scalar_function2_test::ScalarFunctionWithFieldTest;
 
 #[test]
 fn test_arithmetic_function() -> Result<()> {
@@ -372,6 +376,46 @@ fn test_arithmetic_date_interval() -> Result<()> {
                 error: "",
             },
         ),
+        (
+            ArithmeticMinusFunction::try_create_func("", &[
+                &DateTime32Type::arc(None),
+                &DateTime32Type::arc(None),
+            ])?,
+            ScalarFunctionTest {
+                name: "datetime32-sub-datetime32-passed",
+                columns: vec![
+                    Series::from_data(vec![
+                        to_seconds(2020, 3, 31, 10, 30, 00), /* 2020-3-31 10:30:00 */
+                        to_seconds(2000, 1, 31, 10, 30, 00), /* 2000-1-31 10:30:00 */
+                    ]),
+                    Series::from_data(vec![
+                        to_seconds(2020, 3, 30, 10, 30, 00), /* 2020-3-30 10:30:00 */
+                        to_seconds(2000, 1, 31, 10, 31, 40), /* 2000-1-31 10:31:40 */
+                    ]),
+                ],
+                expect: Series::from_data(vec![86400i64, -100]),
+                error: "",
+            },
+        ),
+        (
+            ArithmeticMinusFunction::try_create_func("", &[
+                &DateTime64Type::arc(3, None),
+                &DateTime32Type::arc(None),
+            ])?,
+            ScalarFunctionTest {
+                name: "datetime64-sub-datetime32-passed",
+                columns: vec![
+                    Series::from_data(vec![
+                        to_milliseconds(2020, 3, 31, 10, 30, 10, 500), /* 2020-3-31 10:30:10.500 */
+                    ]),
+                    Series::from_data(vec![
+                        to_seconds(2020, 3, 31, 10, 30, 00), /* 2020-3-31 10:30:00 */
+                    ]),
+                ],
+                expect: Series::from_data(vec![10i64]),
+                error: "",
+            },
+        ),
     ];
 
     for (test_function, test) in tests {
@@ -380,3 +424,82 @@ fn test_arithmetic_date_interval() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_arithmetic_decimal() -> Result<()> {
+    let decimal = |values: Vec<i64>, precision: usize, scale: usize| {
+        ColumnWithField::new(
+            Series::from_data(values),
+            DataField::new("dummy", DecimalType::arc(precision, scale)),
+        )
+    };
+
+    let tests = vec![
+        (
+            ArithmeticPlusFunction::try_create_func("", &[
+                &DecimalType::arc(5, 2),
+                &DecimalType::arc(4, 1),
+            ])?,
+            ScalarFunctionWithFieldTest {
+                name: "add-decimal-passed",
+                columns: vec![decimal(vec![12345, -5], 5, 2), decimal(vec![15, 1], 4, 1)],
+                expect: Series::from_data(vec![12495i64, 5]),
+                error: "",
+            },
+        ),
+        (
+            ArithmeticMulFunction::try_create_func("", &[
+                &DecimalType::arc(5, 2),
+                &Int32Type::arc(),
+            ])?,
+            ScalarFunctionWithFieldTest {
+                name: "mul-decimal-int32-passed",
+                columns: vec![
+                    decimal(vec![12345, -5], 5, 2),
+                    ColumnWithField::new(
+                        Series::from_data(vec![2i32, 3]),
+                        DataField::new("dummy", Int32Type::arc()),
+                    ),
+                ],
+                expect: Series::from_data(vec![24690i64, -15]),
+                error: "",
+            },
+        ),
+        (
+            ArithmeticMinusFunction::try_create_func("", &[
+                &DecimalType::arc(18, 0),
+                &DecimalType::arc(18, 0),
+            ])?,
+            ScalarFunctionWithFieldTest {
+                name: "sub-decimal-overflow",
+                columns: vec![
+                    decimal(vec![999_999_999_999_999_999], 18, 0),
+                    decimal(vec![-1], 18, 0),
+                ],
+                expect: Arc::new(NullColumn::new(1)),
+                error: "Decimal overflow, the result doesn't fit in Decimal(18, 0)",
+            },
+        ),
+        (
+            ArithmeticDivFunction::try_create_func("", &[
+                &DecimalType::arc(5, 2),
+                &DecimalType::arc(5, 2),
+            ])?,
+            ScalarFunctionWithFieldTest {
+                name: "div-decimal-passed",
+                columns: vec![
+                    decimal(vec![12345, -5], 5, 2),
+                    decimal(vec![200, 100], 5, 2),
+                ],
+                expect: Series::from_data(vec![61.725f64, -0.05]),
+                error: "",
+            },
+        ),
+    ];
+
+    for (test_function, test) in tests {
+        test_scalar_functions_with_type(test_function, &[test], true)?
+    }
+
+    Ok(())
+}
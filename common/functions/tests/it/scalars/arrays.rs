@@ -0,0 +1,50 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+use super::scalar_function2_test::test_scalar_functions;
+use super::scalar_function2_test::ScalarFunctionTest;
+
+#[test]
+fn test_array_creator_function() -> Result<()> {
+    let tests = vec![ScalarFunctionTest {
+        name: "array of the common type",
+        columns: vec![
+            Series::from_data(vec![1i32, 2]),
+            Series::from_data(vec![3i64, 4]),
+        ],
+        expect: Series::from_data(vec![vec![1i64, 3], vec![2, 4]]),
+        error: "",
+    }];
+
+    test_scalar_functions(ArrayCreatorFunction::try_create("array")?, &tests, false)
+}
+
+#[test]
+fn test_array_get_function() -> Result<()> {
+    let array = Series::from_data(vec![vec![1i64, 2], vec![], vec![3]]);
+    let tests = vec![ScalarFunctionTest {
+        name: "get",
+        columns: vec![array.clone(), Series::from_data(vec![1u64, 0, 0])],
+        expect: Series::from_data(vec![Some(2i64), None, Some(3)]),
+        error: "",
+    }];
+
+    let function =
+        FunctionFactory::instance().get("get", &[&array.data_type(), &u64::to_data_type()])?;
+    test_scalar_functions(function, &tests, false)
+}
@@ -0,0 +1,96 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions_with_type;
+use crate::scalars::scalar_function2_test::ScalarFunctionWithFieldTest;
+
+fn bitmap(text: &str) -> Vec<u8> {
+    BitmapType::serialize(&BitmapType::parse(text.as_bytes()).unwrap())
+}
+
+fn bitmap_column(name: &str, texts: &[&str]) -> ColumnWithField {
+    let values: Vec<Vec<u8>> = texts.iter().map(|text| bitmap(text)).collect();
+    ColumnWithField::new(
+        Series::from_data(values),
+        DataField::new(name, BitmapType::arc()),
+    )
+}
+
+#[test]
+fn test_bitmap_count_function() -> Result<()> {
+    let tests = vec![ScalarFunctionWithFieldTest {
+        name: "bitmap_count",
+        columns: vec![bitmap_column("a", &["1,2,3", "", "18446744073709551615"])],
+        expect: Series::from_data(vec![3u64, 0, 1]),
+        error: "",
+    }];
+
+    test_scalar_functions_with_type(
+        BitmapCountFunction::try_create("bitmap_count")?,
+        &tests,
+        true,
+    )
+}
+
+#[test]
+fn test_bitmap_logic_functions() -> Result<()> {
+    let tests = vec![
+        ("bitmap_and", ScalarFunctionWithFieldTest {
+            name: "bitmap_and",
+            columns: vec![
+                bitmap_column("a", &["1,2,3", "1"]),
+                bitmap_column("b", &["2,3,4", "2"]),
+            ],
+            expect: Series::from_data(vec![bitmap("2,3"), bitmap("")]),
+            error: "",
+        }),
+        ("bitmap_or", ScalarFunctionWithFieldTest {
+            name: "bitmap_or",
+            columns: vec![
+                bitmap_column("a", &["1,2,3", "1"]),
+                bitmap_column("b", &["2,3,4", "4294967296"]),
+            ],
+            expect: Series::from_data(vec![bitmap("1,2,3,4"), bitmap("1,4294967296")]),
+            error: "",
+        }),
+        ("bitmap_or", ScalarFunctionWithFieldTest {
+            name: "bitmap_or_string",
+            columns: vec![
+                bitmap_column("a", &["1"]),
+                ColumnWithField::new(
+                    Series::from_data(vec!["1"]),
+                    DataField::new("b", StringType::arc()),
+                ),
+            ],
+            expect: Series::from_data(vec![bitmap("1")]),
+            error: "Illegal type String of argument of function bitmap_or, must be a bitmap",
+        }),
+    ];
+
+    for (name, test) in tests {
+        let func = FunctionFactory::instance().get(name, &[
+            test.columns[0].data_type(),
+            test.columns[1].data_type(),
+        ]);
+        match func {
+            Ok(func) => test_scalar_functions_with_type(func, &[test], true)?,
+            Err(cause) => assert_eq!(test.error, cause.message(), "{}", test.name),
+        }
+    }
+    Ok(())
+}
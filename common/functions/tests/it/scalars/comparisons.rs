@@ -12,12 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_functions::scalars::*;
 
 use super::scalar_function2_test::test_scalar_functions;
+use super::scalar_function2_test::test_scalar_functions_by_name;
+use super::scalar_function2_test::test_scalar_functions_with_type;
 use super::scalar_function2_test::ScalarFunctionTest;
+use super::scalar_function2_test::ScalarFunctionWithFieldTest;
 
 #[test]
 fn test_eq_comparison_function() -> Result<()> {
@@ -133,6 +138,120 @@ fn test_not_eq_comparison_function() -> Result<()> {
     )
 }
 
+#[test]
+fn test_null_safe_eq_comparison_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "nullable",
+            columns: vec![
+                Series::from_data(vec![Some(1i64), None, Some(3), None]),
+                Series::from_data(vec![Some(1i64), Some(2), Some(4), None]),
+            ],
+            expect: Series::from_data(vec![true, false, false, true]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "not-nullable",
+            columns: vec![
+                Series::from_data(vec![1u8, 2]),
+                Series::from_data(vec![1i64, 3]),
+            ],
+            expect: Series::from_data(vec![true, false]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "null",
+            columns: vec![
+                Series::from_data(vec![Some("a"), None]),
+                Arc::new(NullColumn::new(2)),
+            ],
+            expect: Series::from_data(vec![false, true]),
+            error: "",
+        },
+    ];
+    test_scalar_functions_by_name("<=>", &tests)?;
+
+    let tests = vec![ScalarFunctionTest {
+        name: "is-distinct-from",
+        columns: vec![
+            Series::from_data(vec![Some(1i64), None, Some(3), None]),
+            Series::from_data(vec![Some(1i64), Some(2), Some(4), None]),
+        ],
+        expect: Series::from_data(vec![false, true, true, false]),
+        error: "",
+    }];
+    test_scalar_functions_by_name("is distinct from", &tests)
+}
+
+#[test]
+fn test_between_comparison_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "const-bounds",
+            columns: vec![
+                Series::from_data(vec![1u8, 2, 3, 4]),
+                ConstColumn::new(Series::from_data(vec![2i64]), 4).arc(),
+                ConstColumn::new(Series::from_data(vec![3.5f64]), 4).arc(),
+            ],
+            expect: Series::from_data(vec![false, true, true, false]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "column-bounds",
+            columns: vec![
+                Series::from_data(vec![1i32, 2, 3, 4]),
+                Series::from_data(vec![0i32, 3, 3, 5]),
+                Series::from_data(vec![1i32, 4, 2, 6]),
+            ],
+            expect: Series::from_data(vec![true, false, false, false]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "nullable",
+            columns: vec![
+                Series::from_data(vec![Some(1i64), None, Some(3)]),
+                Series::from_data(vec![Some(0i64), Some(0), None]),
+                Series::from_data(vec![2i64, 2, 4]),
+            ],
+            expect: Series::from_data(vec![Some(true), None, None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "string",
+            columns: vec![
+                Series::from_data(vec!["abc", "b", "bcd"]),
+                ConstColumn::new(Series::from_data(vec!["abd"]), 3).arc(),
+                ConstColumn::new(Series::from_data(vec!["bc"]), 3).arc(),
+            ],
+            expect: Series::from_data(vec![false, true, false]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "const-value",
+            columns: vec![
+                ConstColumn::new(Series::from_data(vec![3u32]), 3).arc(),
+                Series::from_data(vec![1u32, 4, 3]),
+                Series::from_data(vec![5u32, 5, 3]),
+            ],
+            expect: Series::from_data(vec![true, false, true]),
+            error: "",
+        },
+    ];
+    test_scalar_functions_by_name("between", &tests)?;
+
+    let tests = vec![ScalarFunctionTest {
+        name: "not-between",
+        columns: vec![
+            Series::from_data(vec![1u8, 2, 3, 4]),
+            ConstColumn::new(Series::from_data(vec![2i64]), 4).arc(),
+            ConstColumn::new(Series::from_data(vec![3.5f64]), 4).arc(),
+        ],
+        expect: Series::from_data(vec![true, false, false, true]),
+        error: "",
+    }];
+    test_scalar_functions_by_name("not between", &tests)
+}
+
 #[test]
 fn test_like_comparison_function() -> Result<()> {
     let tests = vec![ScalarFunctionTest {
@@ -171,6 +290,110 @@ fn test_not_like_comparison_function() -> Result<()> {
     )
 }
 
+#[test]
+fn test_like_comparison_function_const_pattern() -> Result<()> {
+    let tests = vec![
+        ("abc%", vec![true, false, false, false]),
+        ("%bc", vec![true, false, false, false]),
+        ("%bc%", vec![true, true, false, false]),
+        ("%b_%", vec![true, true, false, false]),
+        ("%%", vec![true, true, true, true]),
+        ("", vec![false, false, false, true]),
+    ];
+
+    for (pattern, expect) in tests {
+        let pattern = DataValue::String(pattern.as_bytes().to_vec());
+        let test = ScalarFunctionTest {
+            name: "like-const-pattern-passed",
+            columns: vec![
+                Series::from_data(vec!["abc", "xbcx", "ab", ""]),
+                StringType::arc().create_constant_column(&pattern, 4)?,
+            ],
+            expect: Series::from_data(expect),
+            error: "",
+        };
+
+        test_scalar_functions(
+            ComparisonLikeFunction::try_create_func("", &[&StringType::arc(), &StringType::arc()])?,
+            &[test],
+            true,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_ilike_comparison_function() -> Result<()> {
+    let tests = vec![ScalarFunctionTest {
+        name: "ilike-passed",
+        columns: vec![
+            Series::from_data(vec!["ABC", "abd", "aBe", "ÄBF", "abg"]),
+            Series::from_data(vec!["a%", "_B_", "abe", "äbf", "a"]),
+        ],
+        expect: Series::from_data(vec![true, true, true, true, false]),
+        error: "",
+    }];
+
+    test_scalar_functions(
+        ComparisonILikeFunction::try_create_func("", &[&StringType::arc(), &StringType::arc()])?,
+        &tests,
+        true,
+    )?;
+
+    let tests = vec![
+        ("databend", vec![true, false, false, false]),
+        ("data%", vec![true, true, false, false]),
+        ("%BEND", vec![true, false, false, false]),
+        ("%TAB%", vec![true, false, false, false]),
+        ("_ase", vec![false, false, true, false]),
+        ("äbf", vec![false, false, false, true]),
+    ];
+
+    for (pattern, expect) in tests {
+        let pattern = DataValue::String(pattern.as_bytes().to_vec());
+        let test = ScalarFunctionTest {
+            name: "ilike-const-pattern-passed",
+            columns: vec![
+                Series::from_data(vec!["Databend", "DATA", "base", "ÄBF"]),
+                StringType::arc().create_constant_column(&pattern, 4)?,
+            ],
+            expect: Series::from_data(expect),
+            error: "",
+        };
+
+        test_scalar_functions(
+            ComparisonILikeFunction::try_create_func("", &[
+                &StringType::arc(),
+                &StringType::arc(),
+            ])?,
+            &[test],
+            true,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_not_ilike_comparison_function() -> Result<()> {
+    let tests = vec![ScalarFunctionTest {
+        name: "not-ilike-passed",
+        columns: vec![
+            Series::from_data(vec!["ABC", "abd", "aBe", "abf"]),
+            Series::from_data(vec!["a%", "_B_", "abe", "a"]),
+        ],
+        expect: Series::from_data(vec![false, false, false, true]),
+        error: "",
+    }];
+
+    test_scalar_functions(
+        ComparisonNotILikeFunction::try_create_func("", &[&StringType::arc(), &StringType::arc()])?,
+        &tests,
+        true,
+    )
+}
+
 #[test]
 fn test_regexp_comparison_function() -> Result<()> {
     let tests = vec![ScalarFunctionTest {
@@ -211,3 +434,89 @@ fn test_not_regexp_comparison_function() -> Result<()> {
         true,
     )
 }
+
+#[test]
+fn test_tuple_comparison_function() -> Result<()> {
+    let tuple_type = Arc::new(StructType::create(
+        vec!["item_0".to_string(), "item_1".to_string()],
+        vec![Int64Type::arc(), StringType::arc()],
+    ));
+    let tuple = |a: Vec<i64>, b: Vec<&str>| -> ColumnRef {
+        Arc::new(StructColumn::from_data(
+            vec![Series::from_data(a), Series::from_data(b)],
+            tuple_type.clone(),
+        ))
+    };
+    let columns = vec![
+        tuple(vec![1, 1, 1, 2], vec!["a", "b", "c", "a"]),
+        tuple(vec![1, 1, 1, 1], vec!["b", "b", "b", "b"]),
+    ];
+
+    let cases = vec![
+        ("=", vec![false, true, false, false]),
+        ("<>", vec![true, false, true, true]),
+        ("<", vec![true, false, false, false]),
+        ("<=", vec![true, true, false, false]),
+        (">", vec![false, false, true, true]),
+        (">=", vec![false, true, true, true]),
+    ];
+
+    for (op, expect) in cases {
+        let tests = vec![ScalarFunctionTest {
+            name: op,
+            columns: columns.clone(),
+            expect: Series::from_data(expect),
+            error: "",
+        }];
+        let func = FunctionFactory::instance().get(op, &[&tuple_type, &tuple_type])?;
+        test_scalar_functions(func, &tests, true)?;
+    }
+
+    let other_type = Arc::new(StructType::create(vec!["item_0".to_string()], vec![
+        Int64Type::arc(),
+    ]));
+    let result = FunctionFactory::instance().get("=", &[&tuple_type, &other_type]);
+    assert_eq!(
+        result.err().unwrap().message(),
+        "Cannot compare tuples of different sizes: 2 and 1"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_interval_comparison_function() -> Result<()> {
+    let hours = IntervalType::arc(IntervalKind::Hour);
+    let minutes = IntervalType::arc(IntervalKind::Minute);
+    let columns = vec![
+        ColumnWithField::new(
+            Series::from_data(vec![1i64, 2, 1]),
+            DataField::new("hours", hours.clone()),
+        ),
+        ColumnWithField::new(
+            Series::from_data(vec![60i64, 90, 61]),
+            DataField::new("minutes", minutes.clone()),
+        ),
+    ];
+
+    let cases = vec![
+        ("=", vec![true, false, false]),
+        ("<", vec![false, false, true]),
+        (">", vec![false, true, false]),
+    ];
+
+    for (op, expect) in cases {
+        let tests = vec![ScalarFunctionWithFieldTest {
+            name: op,
+            columns: columns.clone(),
+            expect: Series::from_data(expect),
+            error: "",
+        }];
+        let func = FunctionFactory::instance().get(op, &[&hours, &minutes])?;
+        test_scalar_functions_with_type(func, &tests, true)?;
+    }
+
+    let months = IntervalType::arc(IntervalKind::Month);
+    let result = FunctionFactory::instance().get("=", &[&months, &hours]);
+    assert!(result.is_err());
+    Ok(())
+}
@@ -18,6 +18,7 @@ use common_exception::Result;
 use common_functions::scalars::IfFunction;
 
 use crate::scalars::scalar_function2_test::test_scalar_functions;
+use crate::scalars::scalar_function2_test::test_scalar_functions_by_name;
 use crate::scalars::scalar_function2_test::ScalarFunctionTest;
 
 #[test]
@@ -107,3 +108,139 @@ fn test_if_function() -> Result<()> {
 
     test_scalar_functions(IfFunction::try_create("if")?, &tests, false)
 }
+
+#[test]
+fn test_coalesce_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "coalesce-casted-to-common-type",
+            columns: vec![
+                Series::from_data(vec![Some(1u8), None, None]),
+                Series::from_data(vec![None, Some(2u32), None]),
+                Series::from_data(vec![4u16, 5, 6]),
+            ],
+            expect: Series::from_data(vec![1u32, 2, 6]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "coalesce-all-nullable",
+            columns: vec![
+                Series::from_data(vec![Some("a"), None]),
+                Series::from_data(vec![None, None::<&str>]),
+            ],
+            expect: Series::from_data(vec![Some("a"), None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "coalesce-no-common-type",
+            columns: vec![Series::from_data(vec!["a"]), Series::from_data(vec![1i32])],
+            expect: Series::from_data(vec![""]),
+            error: "Can't merge types from String and Int32",
+        },
+    ];
+
+    test_scalar_functions_by_name("coalesce", &tests)
+}
+
+#[test]
+fn test_multi_if_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "multi-if-first-true-branch",
+            columns: vec![
+                Series::from_data([true, false, true, false]),
+                Series::from_data([1u8, 2, 3, 4]),
+                Series::from_data([true, true, false, false]),
+                Series::from_data([10i32, 20, 30, 40]),
+                Series::from_data([100i32, 200, 300, 400]),
+            ],
+            expect: Series::from_data(vec![1i32, 20, 3, 400]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "multi-if-null-else",
+            columns: vec![
+                Series::from_data([Some(true), None, Some(false)]),
+                Series::from_data(["a", "b", "c"]),
+                Arc::new(NullColumn::new(3)),
+            ],
+            expect: Series::from_data(vec![Some("a"), None, None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "multi-if-missing-else",
+            columns: vec![
+                Series::from_data([true, false]),
+                Series::from_data([1u8, 2]),
+                Series::from_data([true, false]),
+                Series::from_data([1u8, 2]),
+            ],
+            expect: Series::from_data(vec![1u8, 2]),
+            error: "Function multi_if expects the pairs of conditions and results and an else result, but got 4 arguments",
+        },
+    ];
+
+    test_scalar_functions_by_name("multi_if", &tests)
+}
+
+#[test]
+fn test_if_null_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "ifnull-casted-to-common-type",
+            columns: vec![
+                Series::from_data(vec![Some(1u8), None, None]),
+                Series::from_data(vec![4u16, 5, 6]),
+            ],
+            expect: Series::from_data(vec![1u16, 5, 6]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "ifnull-three-arguments",
+            columns: vec![
+                Series::from_data(vec![1u8]),
+                Series::from_data(vec![2u8]),
+                Series::from_data(vec![3u8]),
+            ],
+            expect: Series::from_data(vec![1u8]),
+            error: "Expected 2 arguments but got 3",
+        },
+    ];
+
+    test_scalar_functions_by_name("ifNull", &tests)
+}
+
+#[test]
+fn test_null_if_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "nullif-equal-values",
+            columns: vec![
+                Series::from_data(vec![1i32, 2, 3]),
+                Series::from_data(vec![1u8, 0, 3]),
+            ],
+            expect: Series::from_data(vec![None, Some(2i32), None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "nullif-nullable",
+            columns: vec![
+                Series::from_data(vec![Some("a"), None, Some("c")]),
+                Series::from_data(vec![None, Some("b"), Some("x")]),
+            ],
+            expect: Series::from_data(vec![Some("a"), None, Some("c")]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "nullif-null",
+            columns: vec![
+                Series::from_data(vec![1i32, 2]),
+                Arc::new(NullColumn::new(2)),
+            ],
+            expect: Series::from_data(vec![Some(1i32), Some(2)]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions_by_name("nullIf", &tests)
+}
@@ -106,6 +106,15 @@ fn test_to_yyyymmdd_function() -> Result<()> {
             expect: Series::from_data(vec![19700101u32]),
             error: "",
         },
+        ScalarFunctionWithFieldTest {
+            name: "test_toyyyymmdd_date32_before_epoch",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec![-1i32, -365, -366]),
+                DataField::new("dummy_1", Date32Type::arc()),
+            )],
+            expect: Series::from_data(vec![19691231u32, 19690101, 19681231]),
+            error: "",
+        },
         ScalarFunctionWithFieldTest {
             name: "test_toyyyymmdd_datetime",
             columns: vec![ColumnWithField::new(
@@ -169,6 +178,18 @@ fn test_to_yyyymmdd_function() -> Result<()> {
             expect: Series::from_data(vec![20210905u32]),
             error: "",
         },
+        ScalarFunctionWithFieldTest {
+            name: "test_toyyyymmdd_datetime_timezone",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec![1640966400u32]),
+                DataField::new(
+                    "dummy_1",
+                    DateTime32Type::arc(Some("Asia/Shanghai".to_string())),
+                ),
+            )],
+            expect: Series::from_data(vec![20220101u32]),
+            error: "",
+        },
     ];
 
     test_scalar_functions_with_type(ToYYYYMMDDFunction::try_create("c")?, &tests, true)
@@ -541,6 +562,18 @@ fn test_tohour_function() -> Result<()> {
             expect: Series::from_data(vec![10u8]),
             error: "",
         },
+        ScalarFunctionWithFieldTest {
+            name: "test_tohour_datetime_timezone",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec![1634551542u32]),
+                DataField::new(
+                    "dummy_1",
+                    DateTime32Type::arc(Some("Asia/Shanghai".to_string())),
+                ),
+            )],
+            expect: Series::from_data(vec![18u8]),
+            error: "",
+        },
     ];
 
     test_scalar_functions_with_type(ToHourFunction::try_create("a")?, &tests, true)
@@ -704,3 +737,161 @@ fn test_tomonday_function() -> Result<()> {
 
     test_scalar_functions_with_type(ToMondayFunction::try_create("a")?, &tests, true)
 }
+
+#[test]
+fn test_date_trunc_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionWithFieldTest {
+            name: "test_date_trunc_year",
+            columns: vec![
+                ColumnWithField::new(
+                    Arc::new(ConstColumn::new(Series::from_data(vec!["year"]), 1)),
+                    DataField::new("unit", StringType::arc()),
+                ),
+                ColumnWithField::new(
+                    Series::from_data(vec![1630812366u32]),
+                    DataField::new("dummy_1", DateTime32Type::arc(None)),
+                ),
+            ],
+            expect: Series::from_data(vec![1609459200u32]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "test_date_trunc_quarter",
+            columns: vec![
+                ColumnWithField::new(
+                    Arc::new(ConstColumn::new(Series::from_data(vec!["QUARTER"]), 1)),
+                    DataField::new("unit", StringType::arc()),
+                ),
+                ColumnWithField::new(
+                    Series::from_data(vec![1630812366u32]),
+                    DataField::new("dummy_1", DateTime32Type::arc(None)),
+                ),
+            ],
+            expect: Series::from_data(vec![1625097600u32]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "test_date_trunc_month",
+            columns: vec![
+                ColumnWithField::new(
+                    Arc::new(ConstColumn::new(Series::from_data(vec!["month"]), 1)),
+                    DataField::new("unit", StringType::arc()),
+                ),
+                ColumnWithField::new(
+                    Series::from_data(vec![1630812366u32]),
+                    DataField::new("dummy_1", DateTime32Type::arc(None)),
+                ),
+            ],
+            expect: Series::from_data(vec![1630454400u32]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "test_date_trunc_week",
+            columns: vec![
+                ColumnWithField::new(
+                    Arc::new(ConstColumn::new(Series::from_data(vec!["week"]), 1)),
+                    DataField::new("unit", StringType::arc()),
+                ),
+                ColumnWithField::new(
+                    Series::from_data(vec![1630812366u32]),
+                    DataField::new("dummy_1", DateTime32Type::arc(None)),
+                ),
+            ],
+            expect: Series::from_data(vec![1630281600u32]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "test_date_trunc_day",
+            columns: vec![
+                ColumnWithField::new(
+                    Arc::new(ConstColumn::new(Series::from_data(vec!["day"]), 1)),
+                    DataField::new("unit", StringType::arc()),
+                ),
+                ColumnWithField::new(
+                    Series::from_data(vec![1630812366u32]),
+                    DataField::new("dummy_1", DateTime32Type::arc(None)),
+                ),
+            ],
+            expect: Series::from_data(vec![1630800000u32]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "test_date_trunc_hour",
+            columns: vec![
+                ColumnWithField::new(
+                    Arc::new(ConstColumn::new(Series::from_data(vec!["hour"]), 1)),
+                    DataField::new("unit", StringType::arc()),
+                ),
+                ColumnWithField::new(
+                    Series::from_data(vec![1630812366u32]),
+                    DataField::new("dummy_1", DateTime32Type::arc(None)),
+                ),
+            ],
+            expect: Series::from_data(vec![1630810800u32]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "test_date_trunc_minute",
+            columns: vec![
+                ColumnWithField::new(
+                    Arc::new(ConstColumn::new(Series::from_data(vec!["minute"]), 1)),
+                    DataField::new("unit", StringType::arc()),
+                ),
+                ColumnWithField::new(
+                    Series::from_data(vec![1630812366u32]),
+                    DataField::new("dummy_1", DateTime32Type::arc(None)),
+                ),
+            ],
+            expect: Series::from_data(vec![1630812360u32]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "test_date_trunc_second",
+            columns: vec![
+                ColumnWithField::new(
+                    Arc::new(ConstColumn::new(Series::from_data(vec!["second"]), 1)),
+                    DataField::new("unit", StringType::arc()),
+                ),
+                ColumnWithField::new(
+                    Series::from_data(vec![1630812366u32]),
+                    DataField::new("dummy_1", DateTime32Type::arc(None)),
+                ),
+            ],
+            expect: Series::from_data(vec![1630812366u32]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "test_date_trunc_date16",
+            columns: vec![
+                ColumnWithField::new(
+                    Arc::new(ConstColumn::new(Series::from_data(vec!["month"]), 1)),
+                    DataField::new("unit", StringType::arc()),
+                ),
+                ColumnWithField::new(
+                    Series::from_data(vec![18869u16]),
+                    DataField::new("dummy_1", Date16Type::arc()),
+                ),
+            ],
+            expect: Series::from_data(vec![1627776000u32]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "test_date_trunc_unknown_unit",
+            columns: vec![
+                ColumnWithField::new(
+                    Arc::new(ConstColumn::new(Series::from_data(vec!["century"]), 1)),
+                    DataField::new("unit", StringType::arc()),
+                ),
+                ColumnWithField::new(
+                    Series::from_data(vec![1630812366u32]),
+                    DataField::new("dummy_1", DateTime32Type::arc(None)),
+                ),
+            ],
+            expect: Series::from_data(vec![0u32]),
+            error: "Unknown unit 'century' of function date_trunc, expects one of year, quarter, month, week, day, hour, minute and second",
+        },
+    ];
+
+    test_scalar_functions_with_type(DateTruncFunction::try_create("date_trunc")?, &tests, true)
+}
@@ -279,6 +279,43 @@ fn test_datetime_cast_function() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_datetime_timezone_cast_function() -> Result<()> {
+    let tz_type = DateTime32Type::arc(Some("Asia/Shanghai".to_string()));
+
+    // 2022-01-01 08:00:00 in Asia/Shanghai is 2022-01-01 00:00:00 in UTC
+    let strings = ColumnWithField::new(
+        Series::from_data(vec!["2022-01-01 08:00:00"]),
+        DataField::new("dummy_1", StringType::arc()),
+    );
+    let timestamps = cast_column_field(&strings, &tz_type)?;
+    assert_eq!(timestamps.get(0), DataValue::UInt64(1640995200));
+
+    let timestamps = ColumnWithField::new(timestamps, DataField::new("dummy_1", tz_type.clone()));
+    let strings = cast_column_field(&timestamps, &StringType::arc())?;
+    assert_eq!(
+        strings.get(0),
+        DataValue::String(b"2022-01-01 08:00:00".to_vec())
+    );
+
+    // 2021-12-31 16:00:00 in UTC is already 2022-01-01 in Asia/Shanghai
+    let timestamps = ColumnWithField::new(
+        Series::from_data(vec![1640966400u32]),
+        DataField::new("dummy_1", tz_type),
+    );
+    let dates = cast_column_field(&timestamps, &Date16Type::arc())?;
+    assert_eq!(dates.get(0), DataValue::UInt64(18993));
+
+    let timestamps = ColumnWithField::new(
+        Series::from_data(vec![1640966400u32]),
+        DataField::new("dummy_1", DateTime32Type::arc(None)),
+    );
+    let dates = cast_column_field(&timestamps, &Date16Type::arc())?;
+    assert_eq!(dates.get(0), DataValue::UInt64(18992));
+
+    Ok(())
+}
+
 #[test]
 fn test_variant_cast_function() -> Result<()> {
     let tests = vec![
@@ -326,6 +363,312 @@ fn test_variant_cast_function() -> Result<()> {
                 error: "Failed to cast variant value [\"a\",\"b\",\"c\"] to OBJECT",
             },
         ),
+        (
+            CastFunction::create("cast", "string")?,
+            ScalarFunctionWithFieldTest {
+                name: "cast-variant-to-string-passed",
+                columns: vec![ColumnWithField::new(
+                    Series::from_data(vec![json!("abc"), json!([1_i32, 2]), json!(3_i32)]),
+                    DataField::new("dummy_1", VariantType::arc()),
+                )],
+                expect: Series::from_data(vec!["abc", "[1,2]", "3"]),
+                error: "",
+            },
+        ),
+        (
+            CastFunction::create("cast", "int64")?,
+            ScalarFunctionWithFieldTest {
+                name: "cast-variant-to-int64-passed",
+                columns: vec![ColumnWithField::new(
+                    Series::from_data(vec![json!(1_i32), json!("2")]),
+                    DataField::new("dummy_1", VariantType::arc()),
+                )],
+                expect: Series::from_data(vec![1i64, 2]),
+                error: "",
+            },
+        ),
+        (
+            CastFunction::create("cast", "int64")?,
+            ScalarFunctionWithFieldTest {
+                name: "cast-variant-null-to-int64-error",
+                columns: vec![ColumnWithField::new(
+                    Series::from_data(vec![json!(1_i32), json!(null)]),
+                    DataField::new("dummy_1", VariantType::arc()),
+                )],
+                expect: Arc::new(NullColumn::new(2)),
+                error: "Cast error happens in casting from Variant to Int64",
+            },
+        ),
+    ];
+
+    for (test_func, test) in tests {
+        test_scalar_functions_with_type(test_func, &[test], false)?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_binary_type_cast_function() -> Result<()> {
+    let tests = vec![
+        (
+            CastFunction::create("cast", "string")?,
+            ScalarFunctionWithFieldTest {
+                name: "cast-binary-to-string-passed",
+                columns: vec![ColumnWithField::new(
+                    Series::from_data(vec!["abc", "databend"]),
+                    DataField::new("dummy_1", BinaryType::arc()),
+                )],
+                expect: Series::from_data(vec!["abc", "databend"]),
+                error: "",
+            },
+        ),
+        (
+            CastFunction::create("cast", "string")?,
+            ScalarFunctionWithFieldTest {
+                name: "cast-binary-to-string-invalid-utf8",
+                columns: vec![ColumnWithField::new(
+                    Series::from_data(vec![&b"abc"[..], &[0xff_u8, 0xfe][..]]),
+                    DataField::new("dummy_1", BinaryType::arc()),
+                )],
+                expect: Arc::new(NullColumn::new(2)),
+                error: "Cast error happens in casting from Binary to String",
+            },
+        ),
+        (
+            CastFunction::create("cast", "binary")?,
+            ScalarFunctionWithFieldTest {
+                name: "cast-string-to-binary-passed",
+                columns: vec![ColumnWithField::new(
+                    Series::from_data(vec!["abc", "databend"]),
+                    DataField::new("dummy_1", StringType::arc()),
+                )],
+                expect: Series::from_data(vec!["abc", "databend"]),
+                error: "",
+            },
+        ),
+        (
+            CastFunction::create("cast", "int32")?,
+            ScalarFunctionWithFieldTest {
+                name: "cast-binary-to-int32-passed",
+                columns: vec![ColumnWithField::new(
+                    Series::from_data(vec!["4", "3"]),
+                    DataField::new("dummy_1", BinaryType::arc()),
+                )],
+                expect: Series::from_data(vec![4i32, 3]),
+                error: "",
+            },
+        ),
+    ];
+
+    for (test_func, test) in tests {
+        test_scalar_functions_with_type(test_func, &[test], false)?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_ip_cast_function() -> Result<()> {
+    let tests = vec![
+        (
+            CastFunction::create("cast", "ipv4")?,
+            ScalarFunctionWithFieldTest {
+                name: "cast-string-to-ipv4-passed",
+                columns: vec![ColumnWithField::new(
+                    Series::from_data(vec!["192.168.1.1", "0.0.0.0"]),
+                    DataField::new("dummy_1", StringType::arc()),
+                )],
+                expect: Series::from_data(vec![3232235777u32, 0]),
+                error: "",
+            },
+        ),
+        (
+            CastFunction::create("cast", "ipv4")?,
+            ScalarFunctionWithFieldTest {
+                name: "cast-string-to-ipv4-invalid",
+                columns: vec![ColumnWithField::new(
+                    Series::from_data(vec!["192.168.1.256"]),
+                    DataField::new("dummy_1", StringType::arc()),
+                )],
+                expect: Arc::new(NullColumn::new(1)),
+                error: "Cast error happens in casting from String to IPv4",
+            },
+        ),
+        (
+            CastFunction::create("cast", "string")?,
+            ScalarFunctionWithFieldTest {
+                name: "cast-ipv4-to-string-passed",
+                columns: vec![ColumnWithField::new(
+                    Series::from_data(vec![3232235777u32]),
+                    DataField::new("dummy_1", IPv4Type::arc()),
+                )],
+                expect: Series::from_data(vec!["192.168.1.1"]),
+                error: "",
+            },
+        ),
+        (
+            CastFunction::create("cast", "ipv6")?,
+            ScalarFunctionWithFieldTest {
+                name: "cast-ipv4-to-ipv6-passed",
+                columns: vec![ColumnWithField::new(
+                    Series::from_data(vec![3232235777u32]),
+                    DataField::new("dummy_1", IPv4Type::arc()),
+                )],
+                expect: Series::from_data(vec![&IPv6Type::from_ipv4(3232235777)[..]]),
+                error: "",
+            },
+        ),
+        (
+            CastFunction::create("cast", "string")?,
+            ScalarFunctionWithFieldTest {
+                name: "cast-ipv6-to-string-passed",
+                columns: vec![ColumnWithField::new(
+                    Series::from_data(vec![
+                        &IPv6Type::parse(b"2001:DB8:0:0::1").unwrap()[..],
+                        &IPv6Type::from_ipv4(3232235777)[..],
+                    ]),
+                    DataField::new("dummy_1", IPv6Type::arc()),
+                )],
+                expect: Series::from_data(vec!["2001:db8::1", "::ffff:192.168.1.1"]),
+                error: "",
+            },
+        ),
+        (
+            CastFunction::create("cast", "ipv4")?,
+            ScalarFunctionWithFieldTest {
+                name: "cast-ipv6-to-ipv4-invalid",
+                columns: vec![ColumnWithField::new(
+                    Series::from_data(vec![&IPv6Type::parse(b"2001:db8::1").unwrap()[..]]),
+                    DataField::new("dummy_1", IPv6Type::arc()),
+                )],
+                expect: Arc::new(NullColumn::new(1)),
+                error: "Cast error happens in casting from IPv6 to IPv4",
+            },
+        ),
+    ];
+
+    for (test_func, test) in tests {
+        test_scalar_functions_with_type(test_func, &[test], false)?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_uuid_cast_function() -> Result<()> {
+    let text = "936da01f-9abd-4d9d-80c7-02af85c822a8";
+    let bytes = UuidType::parse(text.as_bytes()).unwrap();
+
+    let tests = vec![
+        (
+            CastFunction::create("cast", "uuid")?,
+            ScalarFunctionWithFieldTest {
+                name: "cast-string-to-uuid-passed",
+                columns: vec![ColumnWithField::new(
+                    Series::from_data(vec![text, "936DA01F9ABD4D9D80C702AF85C822A8"]),
+                    DataField::new("dummy_1", StringType::arc()),
+                )],
+                expect: Series::from_data(vec![&bytes[..], &bytes[..]]),
+                error: "",
+            },
+        ),
+        (
+            CastFunction::create("cast", "uuid")?,
+            ScalarFunctionWithFieldTest {
+                name: "cast-string-to-uuid-invalid",
+                columns: vec![ColumnWithField::new(
+                    Series::from_data(vec!["databend"]),
+                    DataField::new("dummy_1", StringType::arc()),
+                )],
+                expect: Arc::new(NullColumn::new(1)),
+                error: "Cast error happens in casting from String to UUID",
+            },
+        ),
+        (
+            CastFunction::create("cast", "string")?,
+            ScalarFunctionWithFieldTest {
+                name: "cast-uuid-to-string-passed",
+                columns: vec![ColumnWithField::new(
+                    Series::from_data(vec![&bytes[..]]),
+                    DataField::new("dummy_1", UuidType::arc()),
+                )],
+                expect: Series::from_data(vec![text]),
+                error: "",
+            },
+        ),
+    ];
+
+    for (test_func, test) in tests {
+        test_scalar_functions_with_type(test_func, &[test], false)?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_decimal_cast_function() -> Result<()> {
+    let tests = vec![
+        (
+            CastFunction::create("cast", "Decimal(5, 2)")?,
+            ScalarFunctionWithFieldTest {
+                name: "cast-string-to-decimal-passed",
+                columns: vec![ColumnWithField::new(
+                    Series::from_data(vec!["123.456", "-0.5", "7"]),
+                    DataField::new("dummy_1", StringType::arc()),
+                )],
+                expect: Series::from_data(vec![12346i64, -50, 700]),
+                error: "",
+            },
+        ),
+        (
+            CastFunction::create("cast", "Decimal(5, 2)")?,
+            ScalarFunctionWithFieldTest {
+                name: "cast-int64-to-decimal-overflow",
+                columns: vec![ColumnWithField::new(
+                    Series::from_data(vec![1i64, 1000]),
+                    DataField::new("dummy_1", Int64Type::arc()),
+                )],
+                expect: Arc::new(NullColumn::new(2)),
+                error: "Cast error happens in casting from Int64 to Decimal(5, 2)",
+            },
+        ),
+        (
+            CastFunction::create("cast", "Decimal(6, 1)")?,
+            ScalarFunctionWithFieldTest {
+                name: "cast-decimal-to-decimal-passed",
+                columns: vec![ColumnWithField::new(
+                    Series::from_data(vec![12345i64, -5]),
+                    DataField::new("dummy_1", DecimalType::arc(5, 2)),
+                )],
+                expect: Series::from_data(vec![1235i64, -1]),
+                error: "",
+            },
+        ),
+        (
+            CastFunction::create("cast", "string")?,
+            ScalarFunctionWithFieldTest {
+                name: "cast-decimal-to-string-passed",
+                columns: vec![ColumnWithField::new(
+                    Series::from_data(vec![12345i64, -5]),
+                    DataField::new("dummy_1", DecimalType::arc(5, 2)),
+                )],
+                expect: Series::from_data(vec!["123.45", "-0.05"]),
+                error: "",
+            },
+        ),
+        (
+            CastFunction::create("cast", "int32")?,
+            ScalarFunctionWithFieldTest {
+                name: "cast-decimal-to-int32-passed",
+                columns: vec![ColumnWithField::new(
+                    Series::from_data(vec![12345i64, -599]),
+                    DataField::new("dummy_1", DecimalType::arc(5, 2)),
+                )],
+                expect: Series::from_data(vec![123i32, -5]),
+                error: "",
+            },
+        ),
     ];
 
     for (test_func, test) in tests {
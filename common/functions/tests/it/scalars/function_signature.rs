@@ -0,0 +1,94 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::ArgumentType;
+use common_functions::scalars::FunctionSignature;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions_by_name;
+use crate::scalars::scalar_function2_test::ScalarFunctionTest;
+
+#[test]
+fn test_function_signature_coerce() -> Result<()> {
+    let string = StringType::arc();
+    let int8 = Int8Type::arc();
+    let uint32 = UInt32Type::arc();
+    let nullable_int16 = wrap_nullable(&Int16Type::arc());
+
+    // the variadic types repeat as a group
+    let signature = FunctionSignature::variadic(&[ArgumentType::String, ArgumentType::Any]);
+    assert_eq!(signature.to_string(), "(a string, any type, ...)");
+    let casts = signature.coerce(&[&string, &int8, &uint32, &string])?;
+    assert_eq!(casts, vec![None, None, Some(string.clone()), None]);
+
+    let err = signature.coerce(&[&string, &int8, &string]).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "Expected a multiple of 2 arguments but got 3"
+    );
+
+    // the generic arguments are casted to their common type, the nullability is kept
+    let signature = FunctionSignature::variadic(&[ArgumentType::Common]);
+    let casts = signature.coerce(&[&int8, &nullable_int16, &uint32])?;
+    let common = Int64Type::arc();
+    assert_eq!(casts, vec![
+        Some(common.clone()),
+        Some(common.clone()),
+        Some(common)
+    ]);
+
+    let signature = FunctionSignature::exact(&[ArgumentType::String, ArgumentType::UInt64]);
+    let err = signature.coerce(&[&string]).unwrap_err();
+    assert_eq!(err.message(), "Expected 2 arguments but got 1");
+
+    Ok(())
+}
+
+#[test]
+fn test_function_signature_overloads() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "locate-number-casted-to-string",
+            columns: vec![
+                Series::from_data(vec![1u8]),
+                Series::from_data(vec!["212"]),
+            ],
+            expect: Series::from_data(vec![2u64]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "locate-with-position",
+            columns: vec![
+                Series::from_data(vec!["2"]),
+                Series::from_data(vec!["212"]),
+                Series::from_data(vec![2u8]),
+            ],
+            expect: Series::from_data(vec![3u64]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "locate-no-matching-signature",
+            columns: vec![
+                Series::from_data(vec!["a"]),
+                Series::from_data(vec!["ba"]),
+                Series::from_data(vec![true]),
+            ],
+            expect: Series::from_data(vec![0u64]),
+            error: "No matching signature for locate(String, String, Boolean), the candidates are: locate(a string, a string), locate(a string, a string, a numeric type)",
+        },
+    ];
+
+    test_scalar_functions_by_name("locate", &tests)
+}
@@ -0,0 +1,128 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+use super::scalar_function2_test::test_scalar_functions;
+use super::scalar_function2_test::ScalarFunctionTest;
+
+fn entry(key: &str, value: i64) -> DataValue {
+    DataValue::Struct(vec![
+        DataValue::String(key.as_bytes().to_vec()),
+        DataValue::Int64(value),
+    ])
+}
+
+#[test]
+fn test_map_creator_function() -> Result<()> {
+    let map_type = MapType::create(Vu8::to_data_type(), i64::to_data_type());
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "map with a repeated key",
+            columns: vec![
+                Series::from_data(vec!["a", "a"]),
+                Series::from_data(vec![1i64, 2]),
+                Series::from_data(vec!["b", "a"]),
+                Series::from_data(vec![3i64, 4]),
+            ],
+            expect: map_type.create_column(&[
+                DataValue::Array(vec![entry("a", 1), entry("b", 3)]),
+                DataValue::Array(vec![entry("a", 4)]),
+            ])?,
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "map without a value",
+            columns: vec![
+                Series::from_data(vec!["a"]),
+                Series::from_data(vec![1i64]),
+                Series::from_data(vec!["b"]),
+            ],
+            expect: Series::from_data(vec![0u8]),
+            error: "Function map expects pairs of keys and values, but got 3 arguments",
+        },
+    ];
+
+    test_scalar_functions(MapCreatorFunction::try_create("map")?, &tests, false)
+}
+
+#[test]
+fn test_map_access_functions() -> Result<()> {
+    let map_type = MapType::create(Vu8::to_data_type(), i64::to_data_type());
+    let map = map_type.create_column(&[
+        DataValue::Array(vec![entry("a", 1), entry("b", 2)]),
+        DataValue::Array(vec![]),
+    ])?;
+    let map_type: DataTypePtr = Arc::new(map_type);
+
+    let tests = vec![ScalarFunctionTest {
+        name: "get",
+        columns: vec![map.clone(), Series::from_data(vec!["b", "b"])],
+        expect: Series::from_data(vec![Some(2i64), None]),
+        error: "",
+    }];
+    test_scalar_functions(MapGetFunction::try_create("get")?, &tests, false)?;
+
+    let tests = vec![ScalarFunctionTest {
+        name: "map_contains_key",
+        columns: vec![map.clone(), Series::from_data(vec!["a", "a"])],
+        expect: Series::from_data(vec![true, false]),
+        error: "",
+    }];
+    test_scalar_functions(
+        MapContainsKeyFunction::try_create("map_contains_key")?,
+        &tests,
+        false,
+    )?;
+
+    let factory = FunctionFactory::instance();
+    let keys_type = ArrayType::create(Vu8::to_data_type());
+    let values_type = ArrayType::create(i64::to_data_type());
+    let tests = vec![
+        (
+            "map_keys",
+            keys_type.create_column(&[
+                DataValue::Array(vec![
+                    DataValue::String(b"a".to_vec()),
+                    DataValue::String(b"b".to_vec()),
+                ]),
+                DataValue::Array(vec![]),
+            ])?,
+        ),
+        (
+            "map_values",
+            values_type.create_column(&[
+                DataValue::Array(vec![DataValue::Int64(1), DataValue::Int64(2)]),
+                DataValue::Array(vec![]),
+            ])?,
+        ),
+        ("map_size", Series::from_data(vec![2u64, 0])),
+    ];
+    for (name, expect) in tests {
+        let function = factory.get(name, &[&map_type])?;
+        let tests = vec![ScalarFunctionTest {
+            name,
+            columns: vec![map.clone()],
+            expect,
+            error: "",
+        }];
+        test_scalar_functions(function, &tests, true)?;
+    }
+
+    Ok(())
+}
@@ -13,12 +13,16 @@
 // limitations under the License.
 
 mod arithmetics;
+mod arrays;
+mod bitmaps;
 mod comparisons;
 mod conditionals;
 mod dates;
 mod expressions;
+mod function_signature;
 mod hashes;
 mod logics;
+mod maps;
 mod maths;
 mod nullables;
 mod others;
@@ -17,11 +17,13 @@ use std::sync::Arc;
 use common_exception::Result;
 use common_functions::scalars::InetAtonFunction;
 use common_functions::scalars::InetNtoaFunction;
+use common_functions::scalars::IpInRangeFunction;
 use common_functions::scalars::RunningDifferenceFunction;
 use common_functions::scalars::TryInetAtonFunction;
 use common_functions::scalars::TryInetNtoaFunction;
 
 use super::scalar_function2_test::test_scalar_functions;
+use super::scalar_function2_test::test_scalar_functions_by_name;
 use super::scalar_function2_test::ScalarFunctionTest;
 use crate::scalars::scalar_function2_test::test_scalar_functions_with_type;
 use crate::scalars::scalar_function2_test::ScalarFunctionWithFieldTest;
@@ -369,7 +371,7 @@ fn test_try_inet_ntoa_function() -> Result<()> {
             name: "string_input_u32",
             columns: vec![Series::from_data(vec!["3232235777"])],
             expect: Series::from_data(vec![Some("192.168.1.1")]),
-            error: "Expected numeric or null type, but got String",
+            error: "Expected numeric, IPv4 or null type, but got String",
         },
     ];
 
@@ -419,10 +421,300 @@ fn test_inet_ntoa_function() -> Result<()> {
             name: "string_input_empty",
             columns: vec![Series::from_data([""])],
             expect: Series::from_data([""]),
-            error: "Expected numeric or null type, but got String",
+            error: "Expected numeric, IPv4 or null type, but got String",
         },
     ];
 
     let test_func = InetNtoaFunction::try_create("inet_ntoa")?;
     test_scalar_functions(test_func, &tests, true)
 }
+
+#[test]
+fn test_ip_in_range_function() -> Result<()> {
+    use common_datavalues::prelude::*;
+
+    let ipv4 = |text: &str| IPv4Type::parse(text.as_bytes()).unwrap();
+    let ipv6 = |text: &str| IPv6Type::parse(text.as_bytes()).unwrap();
+
+    let tests = vec![
+        ScalarFunctionWithFieldTest {
+            name: "ipv4_in_range",
+            columns: vec![
+                ColumnWithField::new(
+                    Series::from_data([ipv4("10.1.2.3"), ipv4("11.0.0.1"), ipv4("10.255.0.0")]),
+                    DataField::new("ip", IPv4Type::arc()),
+                ),
+                ColumnWithField::new(
+                    ConstColumn::new(Series::from_data(["10.0.0.0/8"]), 3).arc(),
+                    DataField::new("cidr", StringType::arc()),
+                ),
+            ],
+            expect: Series::from_data([true, false, true]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "ipv6_in_range",
+            columns: vec![
+                ColumnWithField::new(
+                    Series::from_data([&ipv6("2001:db8::1")[..], &ipv6("::ffff:192.168.1.7")[..]]),
+                    DataField::new("ip", IPv6Type::arc()),
+                ),
+                ColumnWithField::new(
+                    Series::from_data(["2001:db8::/32", "192.168.1.0/25"]),
+                    DataField::new("cidr", StringType::arc()),
+                ),
+            ],
+            expect: Series::from_data([true, true]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "string_in_range",
+            columns: vec![
+                ColumnWithField::new(
+                    Series::from_data(["192.168.1.200", "192.168.1.1"]),
+                    DataField::new("ip", StringType::arc()),
+                ),
+                ColumnWithField::new(
+                    Series::from_data(["192.168.1.0/25", "192.168.1.1"]),
+                    DataField::new("cidr", StringType::arc()),
+                ),
+            ],
+            expect: Series::from_data([false, true]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "invalid_range",
+            columns: vec![
+                ColumnWithField::new(
+                    Series::from_data([ipv4("10.1.2.3")]),
+                    DataField::new("ip", IPv4Type::arc()),
+                ),
+                ColumnWithField::new(
+                    Series::from_data(["10.0.0.0/33"]),
+                    DataField::new("cidr", StringType::arc()),
+                ),
+            ],
+            expect: Series::from_data([false]),
+            error: "Invalid CIDR range \"10.0.0.0/33\"",
+        },
+    ];
+
+    test_scalar_functions_with_type(IpInRangeFunction::try_create("<<")?, &tests, true)
+}
+
+#[test]
+fn test_humanize_size_function() -> Result<()> {
+    use common_datavalues::prelude::*;
+
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "u64_input",
+            columns: vec![Series::from_data([0_u64, 1024, 1_000_000, 10_220_000_000])],
+            expect: Series::from_data(["0 B", "1.02 KB", "1 MB", "10.22 GB"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "nullable_i32_input",
+            columns: vec![Series::from_data([Some(-1500_i32), None])],
+            expect: Series::from_data([Some("-1.5 KB"), None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "string_input",
+            columns: vec![Series::from_data(["1024"])],
+            expect: Series::from_data([""]),
+            error: "Expected a number but got String (argument 1)",
+        },
+    ];
+
+    test_scalar_functions_by_name("humanize_size", &tests)
+}
+
+#[test]
+fn test_humanize_number_function() -> Result<()> {
+    use common_datavalues::prelude::*;
+
+    let tests = vec![ScalarFunctionTest {
+        name: "f64_input",
+        columns: vec![Series::from_data([
+            0.5_f64,
+            1022.0,
+            1_000_000.0,
+            -2_500_000_000.0,
+        ])],
+        expect: Series::from_data(["0.5", "1.02 thousand", "1 million", "-2.5 billion"]),
+        error: "",
+    }];
+
+    test_scalar_functions_by_name("humanize_number", &tests)
+}
+
+#[test]
+fn test_greatest_least_functions() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "greatest-integers",
+            columns: vec![
+                Series::from_data(vec![1i32, 5]),
+                Series::from_data(vec![3i32, 2]),
+                Series::from_data(vec![2i32, 4]),
+            ],
+            expect: Series::from_data(vec![3i32, 5]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "greatest-nullable",
+            columns: vec![
+                Series::from_data(vec![Some(1i64), None]),
+                Series::from_data(vec![2i64, 3]),
+            ],
+            expect: Series::from_data(vec![Some(2i64), None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "greatest-not-comparable",
+            columns: vec![Series::from_data(vec!["a"]), Series::from_data(vec![true])],
+            expect: Series::from_data(vec![""]),
+            error: "Can not compare String with Boolean",
+        },
+    ];
+    test_scalar_functions_by_name("greatest", &tests)?;
+
+    let tests = vec![ScalarFunctionTest {
+        name: "least-strings",
+        columns: vec![
+            Series::from_data(vec!["b", "a"]),
+            Series::from_data(vec!["a", "c"]),
+        ],
+        expect: Series::from_data(vec!["a", "a"]),
+        error: "",
+    }];
+    test_scalar_functions_by_name("least", &tests)
+}
+
+#[test]
+fn test_in_list_function() -> Result<()> {
+    use common_datavalues::prelude::*;
+
+    let constant = |column: ColumnRef, rows: usize| ConstColumn::new(column, rows).arc();
+
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "short_list",
+            columns: vec![
+                Series::from_data([1u8, 2, 3, 4]),
+                constant(Series::from_data([4u8]), 4),
+                constant(Series::from_data([2u8]), 4),
+            ],
+            expect: Series::from_data([false, true, false, true]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "long_list",
+            columns: std::iter::once(Series::from_data([5i64, 25]))
+                .chain((0..20i64).map(|v| constant(Series::from_data([v]), 2)))
+                .collect(),
+            expect: Series::from_data([true, false]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "list_with_null",
+            columns: vec![
+                Series::from_data([1u8, 2]),
+                constant(Series::from_data([2u8]), 2),
+                constant(Series::from_data([None::<u8>]), 2),
+            ],
+            expect: Series::from_data([None, Some(true)]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "nullable_input",
+            columns: vec![
+                Series::from_data([Some(1u8), None]),
+                constant(Series::from_data([1u8]), 2),
+            ],
+            expect: Series::from_data([Some(true), None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "non_constant_list",
+            columns: vec![
+                Series::from_data([1u8, 2, 3]),
+                Series::from_data([1u8, 5, 7]),
+                Series::from_data([Some(0u8), None, Some(3)]),
+            ],
+            expect: Series::from_data([Some(true), None, Some(true)]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "strings",
+            columns: vec![
+                Series::from_data(["a", "b"]),
+                constant(Series::from_data(["b"]), 2),
+                constant(Series::from_data(["c"]), 2),
+            ],
+            expect: Series::from_data([false, true]),
+            error: "",
+        },
+    ];
+    test_scalar_functions_by_name("in", &tests)?;
+
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "not_in",
+            columns: vec![
+                Series::from_data([1.5f64, 2.5]),
+                constant(Series::from_data([2.5f64]), 2),
+            ],
+            expect: Series::from_data([true, false]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "not_in_list_with_null",
+            columns: vec![
+                Series::from_data([1u8, 2]),
+                constant(Series::from_data([2u8]), 2),
+                constant(Series::from_data([None::<u8>]), 2),
+            ],
+            expect: Series::from_data([None, Some(false)]),
+            error: "",
+        },
+    ];
+    test_scalar_functions_by_name("not_in", &tests)
+}
+
+#[test]
+fn test_in_list_function_blocks() -> Result<()> {
+    use common_datavalues::prelude::*;
+    use common_functions::scalars::FunctionFactory;
+
+    // the values of the list are collected by the first block, then reused
+    let types = [
+        u32::to_data_type(),
+        u32::to_data_type(),
+        u32::to_data_type(),
+    ];
+    let types = types.iter().collect::<Vec<_>>();
+    let func = FunctionFactory::instance().get("in", &types)?;
+    for (input, expect) in [
+        ([1u32, 7, 3], [true, false, true]),
+        ([3, 4, 1], [true, false, true]),
+    ] {
+        let columns = vec![
+            Series::from_data(input),
+            ConstColumn::new(Series::from_data([1u32]), 3).arc(),
+            ConstColumn::new(Series::from_data([3u32]), 3).arc(),
+        ];
+        let columns = columns
+            .into_iter()
+            .enumerate()
+            .map(|(i, c)| {
+                let field = DataField::new(&format!("dummy_{}", i), c.data_type());
+                ColumnWithField::new(c, field)
+            })
+            .collect::<Vec<_>>();
+        let result = func.eval(&columns, 3)?;
+        assert_eq!(result.convert_full_column(), Series::from_data(expect));
+    }
+    Ok(())
+}
@@ -16,6 +16,7 @@ use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_functions::scalars::Function;
 use common_functions::scalars::FunctionAdapter;
+use common_functions::scalars::FunctionFactory;
 use pretty_assertions::assert_eq;
 
 pub struct ScalarFunctionTest {
@@ -96,6 +97,43 @@ pub fn test_scalar_functions_with_type(
     Ok(())
 }
 
+/// Tests the function created by the factory, so the argument types declared in its features
+/// are checked and casted.
+pub fn test_scalar_functions_by_name(name: &str, tests: &[ScalarFunctionTest]) -> Result<()> {
+    for test in tests {
+        let types = test
+            .columns
+            .iter()
+            .map(|c| c.data_type())
+            .collect::<Vec<_>>();
+        let types = types.iter().collect::<Vec<_>>();
+        let arguments = test
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(index, c)| {
+                let field = DataField::new(&format!("dummy_{}", index), c.data_type());
+                ColumnWithField::new(c.clone(), field)
+            })
+            .collect::<Vec<_>>();
+        let rows_size = test.columns.first().map_or(1, |c| c.len());
+
+        let result = FunctionFactory::instance()
+            .get(name, &types)
+            .and_then(|func| {
+                func.return_type(&types)?;
+                func.eval(&arguments, rows_size)
+            });
+
+        match result {
+            Ok(v) => assert_eq!(test.expect, v.convert_full_column(), "{}", test.name),
+            Err(cause) => assert_eq!(test.error, cause.message(), "{}", test.name),
+        }
+    }
+
+    Ok(())
+}
+
 #[allow(clippy::borrowed_box)]
 pub fn test_eval(
     test_function: &Box<dyn Function>,
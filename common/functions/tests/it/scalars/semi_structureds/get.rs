@@ -0,0 +1,86 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+use serde_json::json;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions;
+use crate::scalars::scalar_function2_test::ScalarFunctionTest;
+
+#[test]
+fn test_get_function() -> Result<()> {
+    let variants = Series::from_data(vec![
+        json!({"a": 1, "b": {"c": [true, "x"]}}),
+        json!([10, 20]),
+        json!("a"),
+    ]);
+
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "get_by_key",
+            columns: vec![variants.clone(), Series::from_data(vec!["a", "a", "a"])],
+            expect: Series::from_data(vec![Some(json!(1)), None, None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "get_by_index",
+            columns: vec![variants.clone(), Series::from_data(vec![0u64, 1, 0])],
+            expect: Series::from_data(vec![None, Some(json!(20)), None]),
+            error: "",
+        },
+    ];
+    let function =
+        FunctionFactory::instance().get("get", &[&VariantType::arc(), &StringType::arc()])?;
+    test_scalar_functions(function, &tests, false)?;
+
+    let tests = vec![ScalarFunctionTest {
+        name: "get_path",
+        columns: vec![variants, Series::from_data(vec!["b.c[1]", "[0]", "a"])],
+        expect: Series::from_data(vec![Some(json!("x")), Some(json!(10)), None]),
+        error: "",
+    }];
+    test_scalar_functions(GetPathFunction::try_create("get_path")?, &tests, false)
+}
+
+#[test]
+fn test_parse_json_path() -> Result<()> {
+    assert_eq!(parse_json_path("a.b[0]['c d']")?, vec![
+        JsonPathElement::Key("a".to_string()),
+        JsonPathElement::Key("b".to_string()),
+        JsonPathElement::Index(0),
+        JsonPathElement::Key("c d".to_string()),
+    ]);
+    assert_eq!(parse_json_path("[1][\"k\"]")?, vec![
+        JsonPathElement::Index(1),
+        JsonPathElement::Key("k".to_string()),
+    ]);
+
+    for path in ["", ".a", "a.", "a..b", "a[x]", "a[1"] {
+        assert!(parse_json_path(path).is_err(), "path: {}", path);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_to_json_function() -> Result<()> {
+    let tests = vec![ScalarFunctionTest {
+        name: "to_json",
+        columns: vec![Series::from_data(vec![json!({"a": [1, "b"]}), json!("c")])],
+        expect: Series::from_data(vec!["{\"a\":[1,\"b\"]}", "\"c\""]),
+        error: "",
+    }];
+    test_scalar_functions(ToJsonFunction::try_create("to_json")?, &tests, true)
+}
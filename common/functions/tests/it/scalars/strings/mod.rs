@@ -16,6 +16,9 @@
 mod lower;
 mod regexp_instr;
 mod regexp_like;
+mod regexp_replace;
+mod regexp_substr;
+mod repeat;
 mod substring;
 mod trim;
 
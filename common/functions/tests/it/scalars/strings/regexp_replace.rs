@@ -0,0 +1,113 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::RegexpReplaceFunction;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions;
+use crate::scalars::scalar_function2_test::ScalarFunctionTest;
+
+#[test]
+fn test_regexp_replace_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "regexp-replace-three-column-passed",
+            columns: vec![
+                Series::from_data(vec!["a b c", "abc abc", ""]),
+                Series::from_data(vec!["b", "b", "x"]),
+                Series::from_data(vec!["X", "X", "X"]),
+            ],
+            expect: Series::from_data(vec!["a X c", "aXc aXc", ""]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "regexp-replace-five-column-passed",
+            columns: vec![
+                Series::from_data(vec!["abc abc abc", "abc abc abc", "abc abc abc"]),
+                Series::from_data(vec!["b", "b", "b"]),
+                Series::from_data(vec!["X", "X", "X"]),
+                Series::from_data(vec![1_i64, 3, 1]),
+                Series::from_data(vec![0_i64, 0, 2]),
+            ],
+            expect: Series::from_data(vec!["aXc aXc aXc", "abc aXc aXc", "abc aXc abc"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "regexp-replace-capture-group-passed",
+            columns: vec![
+                Series::from_data(vec!["john smith", "jane doe"]),
+                Series::from_data(vec!["(\\w+) (\\w+)", "(\\w+) (\\w+)"]),
+                Series::from_data(vec!["$2 $1", "$2, $1"]),
+            ],
+            expect: Series::from_data(vec!["smith john", "doe, jane"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "regexp-replace-six-column-passed",
+            columns: vec![
+                Series::from_data(vec!["ABC", "ABC"]),
+                Series::from_data(vec!["b", "b"]),
+                Series::from_data(vec!["x", "x"]),
+                Series::from_data(vec![1_i64, 1]),
+                Series::from_data(vec![0_i64, 0]),
+                Series::from_data(vec!["c", "i"]),
+            ],
+            expect: Series::from_data(vec!["ABC", "AxC"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "regexp-replace-match-type-error",
+            columns: vec![
+                Series::from_data(vec!["ABC", "ABC"]),
+                Series::from_data(vec!["b", "b"]),
+                Series::from_data(vec!["x", "x"]),
+                Series::from_data(vec![1_i64, 1]),
+                Series::from_data(vec![0_i64, 0]),
+                Series::from_data(vec!["c", "-i"]),
+            ],
+            expect: Series::from_data(Vec::<&str>::new()),
+            error: "Incorrect arguments to regexp_replace match type: -i",
+        },
+    ];
+
+    test_scalar_functions(
+        RegexpReplaceFunction::try_create("regexp_replace")?,
+        &tests,
+        true,
+    )
+}
+
+#[test]
+fn test_regexp_replace_constant_column() -> Result<()> {
+    let data_type = DataValue::String("dog".as_bytes().into());
+    let data_value = StringType::arc().create_constant_column(&data_type, 3)?;
+
+    let tests = vec![ScalarFunctionTest {
+        name: "regexp-replace-const-column-passed",
+        columns: vec![
+            Series::from_data(vec!["dog cat dog", "cat dog cat", "cat"]),
+            data_value,
+            Series::from_data(vec!["fox", "fox", "fox"]),
+        ],
+        expect: Series::from_data(vec!["fox cat fox", "cat fox cat", "cat"]),
+        error: "",
+    }];
+
+    test_scalar_functions(
+        RegexpReplaceFunction::try_create("regexp_replace")?,
+        &tests,
+        true,
+    )
+}
@@ -0,0 +1,98 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::RegexpSubStrFunction;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions;
+use crate::scalars::scalar_function2_test::ScalarFunctionTest;
+
+#[test]
+fn test_regexp_substr_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "regexp-substr-two-column-passed",
+            columns: vec![
+                Series::from_data(vec!["abc def ghi", "abc def ghi", "abc"]),
+                Series::from_data(vec!["[a-z]+", "d.f", "x"]),
+            ],
+            expect: Series::from_data(vec![Some("abc"), Some("def"), None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "regexp-substr-four-column-passed",
+            columns: vec![
+                Series::from_data(vec!["abc def ghi", "abc def ghi", "abc def ghi", "abc"]),
+                Series::from_data(vec!["[a-z]+", "[a-z]+", "[a-z]+", "[a-z]+"]),
+                Series::from_data(vec![1_i64, 2, 1, 5]),
+                Series::from_data(vec![2_i64, 1, 3, 1]),
+            ],
+            expect: Series::from_data(vec![Some("def"), Some("bc"), Some("ghi"), None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "regexp-substr-five-column-passed",
+            columns: vec![
+                Series::from_data(vec!["ABC def", "ABC def"]),
+                Series::from_data(vec!["abc", "abc"]),
+                Series::from_data(vec![1_i64, 1]),
+                Series::from_data(vec![1_i64, 1]),
+                Series::from_data(vec!["c", "i"]),
+            ],
+            expect: Series::from_data(vec![None, Some("ABC")]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "regexp-substr-match-type-error",
+            columns: vec![
+                Series::from_data(vec!["ABC def", "ABC def"]),
+                Series::from_data(vec!["abc", "abc"]),
+                Series::from_data(vec![1_i64, 1]),
+                Series::from_data(vec![1_i64, 1]),
+                Series::from_data(vec!["c", "-i"]),
+            ],
+            expect: Series::from_data(Vec::<Option<&str>>::new()),
+            error: "Incorrect arguments to regexp_substr match type: -i",
+        },
+    ];
+
+    test_scalar_functions(
+        RegexpSubStrFunction::try_create("regexp_substr")?,
+        &tests,
+        true,
+    )
+}
+
+#[test]
+fn test_regexp_substr_constant_column() -> Result<()> {
+    let data_type = DataValue::String("d.g".as_bytes().into());
+    let data_value = StringType::arc().create_constant_column(&data_type, 3)?;
+
+    let tests = vec![ScalarFunctionTest {
+        name: "regexp-substr-const-column-passed",
+        columns: vec![
+            Series::from_data(vec!["dog cat", "cat dig", "cat"]),
+            data_value,
+        ],
+        expect: Series::from_data(vec![Some("dog"), Some("dig"), None]),
+        error: "",
+    }];
+
+    test_scalar_functions(
+        RegexpSubStrFunction::try_create("regexp_substr")?,
+        &tests,
+        true,
+    )
+}
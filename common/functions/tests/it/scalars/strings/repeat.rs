@@ -0,0 +1,63 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions_by_name;
+use crate::scalars::scalar_function2_test::ScalarFunctionTest;
+
+#[test]
+fn test_repeat_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "repeat-u8-times",
+            columns: vec![
+                Series::from_data(vec!["abc", "d"]),
+                Series::from_data(vec![2u8, 3]),
+            ],
+            expect: Series::from_data(vec!["abcabc", "ddd"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "repeat-number-casted-to-string",
+            columns: vec![
+                Series::from_data(vec![12i32]),
+                Series::from_data(vec![2u64]),
+            ],
+            expect: Series::from_data(vec!["1212"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "repeat-nullable-times",
+            columns: vec![
+                Series::from_data(vec!["ab", "cd"]),
+                Series::from_data(vec![Some(2u16), None]),
+            ],
+            expect: Series::from_data(vec![Some("abab"), None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "repeat-signed-times",
+            columns: vec![
+                Series::from_data(vec!["abc"]),
+                Series::from_data(vec![2i32]),
+            ],
+            expect: Series::from_data(vec![""]),
+            error: "Expected an unsigned integer but got Int32 (argument 2)",
+        },
+    ];
+
+    test_scalar_functions_by_name("repeat", &tests)
+}
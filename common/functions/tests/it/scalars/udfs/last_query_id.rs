@@ -0,0 +1,36 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions;
+use crate::scalars::scalar_function2_test::ScalarFunctionTest;
+
+#[test]
+fn test_last_query_id_function() -> Result<()> {
+    let tests = vec![ScalarFunctionTest {
+        name: "last-query-id-function-passed",
+        columns: vec![Series::from_data(["6fd5f4c1-7f8c-4f8e-a6bb-0c6a5d2c1d2e"])],
+        expect: Series::from_data(["6fd5f4c1-7f8c-4f8e-a6bb-0c6a5d2c1d2e"]),
+        error: "",
+    }];
+
+    test_scalar_functions(
+        LastQueryIdFunction::try_create("last_query_id")?,
+        &tests,
+        true,
+    )
+}
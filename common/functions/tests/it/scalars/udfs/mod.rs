@@ -13,5 +13,6 @@
 // limitations under the License.
 
 mod database;
+mod last_query_id;
 mod to_type_name;
 mod version;
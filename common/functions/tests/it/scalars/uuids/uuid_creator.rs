@@ -42,3 +42,16 @@ fn test_uuid_creator_functions() -> Result<()> {
 
     test_scalar_functions(UUIDZeroFunction::try_create("")?, &tests, true)
 }
+
+#[test]
+fn test_gen_random_uuid_function() -> Result<()> {
+    let func = RandomUUIDFunction::try_create("gen_random_uuid")?;
+    assert_eq!(func.return_type(&[])?.data_type_id(), TypeID::Uuid);
+
+    let column = func.eval(&[], 3)?;
+    let column: &StringColumn = Series::check_get(&column)?;
+    assert_eq!(column.len(), 3);
+    assert!(column.iter().all(|v| v.len() == UUID_SIZE));
+    assert_ne!(column.get_data(0), column.get_data(1));
+    Ok(())
+}
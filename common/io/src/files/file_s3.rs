@@ -18,6 +18,7 @@ use futures::StreamExt;
 use opendal::credential::Credential;
 use opendal::ObjectMode;
 use opendal::Operator;
+use regex::Regex;
 
 pub struct S3File {}
 
@@ -78,6 +79,90 @@ impl S3File {
             }
         }
 
+        list.sort();
         Ok(list)
     }
+
+    // Get the files in the path and its sub directories whose paths match the pattern, sorted by path.
+    // The objects are listed page by page, only the matched paths are kept.
+    // If the pattern is anchored with `^`, the sub directories which can't contain its literal prefix are skipped.
+    pub async fn list_matched(
+        operator: &Operator,
+        path: &str,
+        pattern: &Regex,
+    ) -> Result<Vec<String>> {
+        let mut list: Vec<String> = vec![];
+        let mode = operator.object(path).metadata().await?.mode();
+        match mode {
+            ObjectMode::FILE => {
+                if pattern.is_match(path) {
+                    list.push(path.to_string());
+                }
+            }
+            ObjectMode::DIR => {
+                let prefix = Self::pattern_prefix(pattern.as_str());
+                let mut dirs = vec![path.to_string()];
+                while let Some(dir) = dirs.pop() {
+                    let mut objects = operator.objects(&dir);
+                    while let Some(object) = objects.next().await {
+                        let mut object = object?;
+                        let meta = object.metadata_cached().await?;
+                        let object_path = meta.path();
+                        match meta.mode() {
+                            ObjectMode::FILE if pattern.is_match(object_path) => {
+                                list.push(object_path.to_string());
+                            }
+                            ObjectMode::DIR if object_path != dir => {
+                                let may_match = prefix.as_ref().map_or(true, |prefix| {
+                                    object_path.starts_with(prefix.as_str())
+                                        || prefix.starts_with(object_path)
+                                });
+                                if may_match {
+                                    dirs.push(object_path.to_string());
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            }
+            other => {
+                return Err(ErrorCode::DalError(format!(
+                    "S3 list_matched() can not handle the object mode: {:?}",
+                    other
+                )))
+            }
+        }
+
+        // The same order for every listing, a COPY retried loads the files in the same order.
+        list.sort();
+        Ok(list)
+    }
+
+    // The literal prefix every path matched by the pattern starts with.
+    // Only the pattern anchored by `^` and without alternation has the prefix.
+    pub fn pattern_prefix(pattern: &str) -> Option<String> {
+        let pattern = pattern.strip_prefix('^')?;
+        if pattern.contains('|') {
+            return None;
+        }
+
+        let mut prefix = String::new();
+        for c in pattern.chars() {
+            match c {
+                // The quantifier applies to the last literal char, it's not in the prefix.
+                '*' | '+' | '?' | '{' => {
+                    prefix.pop();
+                    break;
+                }
+                '.' | '$' | '(' | ')' | '[' | ']' | '\\' | '}' | '^' => break,
+                c => prefix.push(c),
+            }
+        }
+
+        match prefix.is_empty() {
+            true => None,
+            false => Some(prefix),
+        }
+    }
 }
@@ -0,0 +1,38 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_io::prelude::*;
+
+#[test]
+fn pattern_prefix_test() {
+    assert_eq!(
+        S3File::pattern_prefix("^data/2022/.*[.]csv"),
+        Some("data/2022/".to_string())
+    );
+    assert_eq!(
+        S3File::pattern_prefix("^data/2022-0[1-3]/"),
+        Some("data/2022-0".to_string())
+    );
+    assert_eq!(
+        S3File::pattern_prefix("^data/a?b"),
+        Some("data/".to_string())
+    );
+    assert_eq!(
+        S3File::pattern_prefix("^data\\.csv"),
+        Some("data".to_string())
+    );
+    assert_eq!(S3File::pattern_prefix(".*[.]parquet"), None);
+    assert_eq!(S3File::pattern_prefix("^data/a|^b"), None);
+    assert_eq!(S3File::pattern_prefix("^.*"), None);
+}
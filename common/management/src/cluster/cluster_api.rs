@@ -26,6 +26,9 @@ pub trait ClusterApi: Sync + Send {
     // Drop the tenant's cluster one node by node.id.
     async fn drop_node(&self, node_id: String, seq: Option<u64>) -> Result<()>;
 
+    // Mark the tenant's cluster one node as draining, keeping its lift time.
+    async fn drain_node(&self, node_id: String) -> Result<()>;
+
     // Keep the tenant's cluster node alive.
     async fn heartbeat(&self, node: &NodeInfo, seq: Option<u64>) -> Result<u64>;
 }
@@ -139,6 +139,38 @@ impl ClusterApi for ClusterMgr {
         }
     }
 
+    async fn drain_node(&self, node_id: String) -> Result<()> {
+        let node_key = format!("{}/{}", self.cluster_prefix, escape_for_key(&node_id)?);
+
+        // Retry when the record is changed between the read and the write.
+        loop {
+            let (seq, mut node_info) = match self.kv_api.get_kv(&node_key).await? {
+                Some(SeqV { seq, data, .. }) => (seq, serde_json::from_slice::<NodeInfo>(&data)?),
+                None => {
+                    return Err(ErrorCode::ClusterUnknownNode(format!(
+                        "unknown node {:?}",
+                        node_id
+                    )))
+                }
+            };
+
+            node_info.id = node_id.clone();
+            node_info.draining = true;
+            let value = Operation::Update(serde_json::to_vec(&node_info)?);
+            let meta = Some(self.new_lift_time());
+            let upsert_node = self.kv_api.upsert_kv(UpsertKVAction::new(
+                &node_key,
+                MatchSeq::Exact(seq),
+                value,
+                meta,
+            ));
+
+            if upsert_node.await?.changed() {
+                return Ok(());
+            }
+        }
+    }
+
     async fn heartbeat(&self, node: &NodeInfo, seq: Option<u64>) -> Result<u64> {
         let meta = Some(self.new_lift_time());
         let node_key = format!("{}/{}", self.cluster_prefix, escape_for_key(&node.id)?);
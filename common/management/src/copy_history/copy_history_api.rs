@@ -0,0 +1,36 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_meta_types::CopiedFileInfo;
+
+#[async_trait::async_trait]
+pub trait CopyHistoryApi: Sync + Send {
+    // Record the load result of a file to /tenant/table-id/file-name.
+    // The first load time of a file loaded before is kept.
+    async fn upsert_copied_file(&self, info: CopiedFileInfo) -> Result<u64>;
+
+    // Get the load result of a file of the table, None if the file is never copied.
+    async fn get_copied_file(
+        &self,
+        table_id: u64,
+        file_name: &str,
+    ) -> Result<Option<CopiedFileInfo>>;
+
+    // Get the load results of all the files copied into the table.
+    async fn get_table_copied_files(&self, table_id: u64) -> Result<Vec<CopiedFileInfo>>;
+
+    // Get the load results of all the tables for a tenant.
+    async fn get_copied_files(&self) -> Result<Vec<CopiedFileInfo>>;
+}
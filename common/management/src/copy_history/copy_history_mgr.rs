@@ -0,0 +1,124 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_api::KVApi;
+use common_meta_types::CopiedFileInfo;
+use common_meta_types::MatchSeq;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+use common_meta_types::UpsertKVAction;
+
+use crate::copy_history::CopyHistoryApi;
+
+static COPY_HISTORY_API_KEY_PREFIX: &str = "__fd_copy_history";
+
+pub struct CopyHistoryMgr {
+    kv_api: Arc<dyn KVApi>,
+    copy_history_prefix: String,
+}
+
+impl CopyHistoryMgr {
+    pub fn create(kv_api: Arc<dyn KVApi>, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while copy history mgr create)",
+            ));
+        }
+
+        Ok(CopyHistoryMgr {
+            kv_api,
+            copy_history_prefix: format!(
+                "{}/{}",
+                COPY_HISTORY_API_KEY_PREFIX,
+                escape_for_key(tenant)?
+            ),
+        })
+    }
+
+    // The trailing slash keeps table 1 from listing the files of table 12.
+    fn table_prefix(&self, table_id: u64) -> String {
+        format!("{}/{}/", self.copy_history_prefix, table_id)
+    }
+
+    fn file_key(&self, table_id: u64, file_name: &str) -> Result<String> {
+        Ok(format!(
+            "{}{}",
+            self.table_prefix(table_id),
+            escape_for_key(file_name)?
+        ))
+    }
+
+    async fn list_copied_files(&self, prefix: &str) -> Result<Vec<CopiedFileInfo>> {
+        let values = self.kv_api.prefix_list_kv(prefix).await?;
+
+        let mut files = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let file = serde_json::from_slice::<CopiedFileInfo>(&value.data)?;
+            files.push(file);
+        }
+        Ok(files)
+    }
+}
+
+#[async_trait::async_trait]
+impl CopyHistoryApi for CopyHistoryMgr {
+    async fn upsert_copied_file(&self, mut info: CopiedFileInfo) -> Result<u64> {
+        if let Some(prev) = self.get_copied_file(info.table_id, &info.file_name).await? {
+            info.first_loaded_on = prev.first_loaded_on;
+        }
+
+        let val = Operation::Update(serde_json::to_vec(&info)?);
+        let key = self.file_key(info.table_id, &info.file_name)?;
+        let upsert_info =
+            self.kv_api
+                .upsert_kv(UpsertKVAction::new(&key, MatchSeq::Any, val, None));
+
+        let res = upsert_info.await?;
+        match res.result {
+            Some(SeqV { seq: s, .. }) => Ok(s),
+            None => Err(ErrorCode::UnknownException(format!(
+                "upsert copied file {} returns no value",
+                info.file_name
+            ))),
+        }
+    }
+
+    async fn get_copied_file(
+        &self,
+        table_id: u64,
+        file_name: &str,
+    ) -> Result<Option<CopiedFileInfo>> {
+        let key = self.file_key(table_id, file_name)?;
+        let res = self.kv_api.get_kv(&key).await?;
+        match res {
+            None => Ok(None),
+            Some(seq_value) => Ok(Some(serde_json::from_slice::<CopiedFileInfo>(
+                &seq_value.data,
+            )?)),
+        }
+    }
+
+    async fn get_table_copied_files(&self, table_id: u64) -> Result<Vec<CopiedFileInfo>> {
+        self.list_copied_files(&self.table_prefix(table_id)).await
+    }
+
+    async fn get_copied_files(&self) -> Result<Vec<CopiedFileInfo>> {
+        self.list_copied_files(&self.copy_history_prefix).await
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod cluster;
+mod copy_history;
 mod role;
 mod setting;
 mod stage;
@@ -22,6 +23,8 @@ mod warehouse;
 
 pub use cluster::ClusterApi;
 pub use cluster::ClusterMgr;
+pub use copy_history::CopyHistoryApi;
+pub use copy_history::CopyHistoryMgr;
 pub use role::RoleApi;
 pub use role::RoleMgr;
 pub use setting::SettingApi;
@@ -21,6 +21,9 @@ pub trait StageApi: Sync + Send {
     // Add a stage info to /tenant/stage-name.
     async fn add_stage(&self, stage: UserStageInfo) -> Result<u64>;
 
+    // Add a stage info, or overwrite the existing one with the same name.
+    async fn replace_stage(&self, stage: UserStageInfo) -> Result<u64>;
+
     async fn get_stage(&self, stage_name: &str, seq: Option<u64>) -> Result<SeqV<UserStageInfo>>;
 
     // Get all the stages for a tenant.
@@ -24,6 +24,9 @@ pub trait UdfApi: Sync + Send {
     // Update a UDF to /tenant/udf-name.
     async fn update_udf(&self, udf: UserDefinedFunction, seq: Option<u64>) -> Result<u64>;
 
+    // Add a UDF, or overwrite the existing one with the same name.
+    async fn replace_udf(&self, udf: UserDefinedFunction) -> Result<u64>;
+
     // Get UDF by name.
     async fn get_udf(&self, udf_name: &str, seq: Option<u64>) -> Result<SeqV<UserDefinedFunction>>;
 
@@ -113,6 +113,35 @@ impl UdfApi for UdfMgr {
         }
     }
 
+    async fn replace_udf(&self, info: UserDefinedFunction) -> Result<u64> {
+        if is_builtin_function(info.name.as_str()) {
+            return Err(ErrorCode::UdfAlreadyExists(format!(
+                "Builtin function can not be replaced: {}",
+                info.name.as_str()
+            )));
+        }
+
+        let mut udf_parser = UDFParser::default();
+        udf_parser
+            .parse(&info.name, &info.parameters, &info.definition)
+            .await?;
+
+        let val = Operation::Update(serde_json::to_vec(&info)?);
+        let key = format!("{}/{}", self.udf_prefix, escape_for_key(&info.name)?);
+        let upsert_info =
+            self.kv_api
+                .upsert_kv(UpsertKVAction::new(&key, MatchSeq::Any, val, None));
+
+        let res = upsert_info.await?;
+        match res.result {
+            Some(SeqV { seq: s, .. }) => Ok(s),
+            None => Err(ErrorCode::UnknownException(format!(
+                "replace UDF {} returns no value",
+                info.name
+            ))),
+        }
+    }
+
     async fn get_udf(&self, udf_name: &str, seq: Option<u64>) -> Result<SeqV<UserDefinedFunction>> {
         let key = format!("{}/{}", self.udf_prefix, escape_for_key(udf_name)?);
         let kv_api = self.kv_api.clone();
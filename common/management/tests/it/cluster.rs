@@ -137,6 +137,37 @@ async fn test_successfully_heartbeat_node() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_successfully_drain_node() -> Result<()> {
+    let current_time = current_seconds_time();
+    let (kv_api, cluster_api) = new_cluster_api().await?;
+
+    let node_info = create_test_node_info();
+    cluster_api.add_node(node_info.clone()).await?;
+    cluster_api.drain_node(node_info.id.clone()).await?;
+
+    let nodes = cluster_api.get_nodes().await?;
+    assert_eq!(nodes.len(), 1);
+    assert!(nodes[0].draining);
+
+    let value = kv_api
+        .get_kv("__fd_clusters/admin//databend_query/test_node")
+        .await?;
+    assert!(value.unwrap().meta.unwrap().expire_at.unwrap() - current_time >= 60);
+
+    // The heartbeat keeps the node draining.
+    cluster_api.heartbeat(&node_info, None).await?;
+    let nodes = cluster_api.get_nodes().await?;
+    assert!(nodes[0].draining);
+
+    match cluster_api.drain_node(String::from("UNKNOWN_ID")).await {
+        Ok(_) => panic!("Unknown node drain node must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2401),
+    }
+
+    Ok(())
+}
+
 fn current_seconds_time() -> u64 {
     let now = std::time::SystemTime::now();
     now.duration_since(UNIX_EPOCH)
@@ -150,6 +181,8 @@ fn create_test_node_info() -> NodeInfo {
         cpu_nums: 0,
         version: 0,
         flight_address: String::from("ip:port"),
+        draining: false,
+        tags: vec![],
     }
 }
 
@@ -0,0 +1,118 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_datavalues::chrono::TimeZone;
+use common_datavalues::chrono::Utc;
+use common_exception::Result;
+use common_management::*;
+use common_meta_api::KVApi;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_types::CopiedFileInfo;
+use common_meta_types::SeqV;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_upsert_copied_file() -> Result<()> {
+    let (kv_api, copy_history_api) = new_copy_history_api().await?;
+
+    let file = create_test_copied_file(1, "data/a.csv");
+    copy_history_api.upsert_copied_file(file.clone()).await?;
+    let value = kv_api
+        .get_kv("__fd_copy_history/admin/1/data%2fa%2ecsv")
+        .await?;
+
+    match value {
+        Some(SeqV {
+            seq: 1,
+            meta: _,
+            data: value,
+        }) => {
+            assert_eq!(value, serde_json::to_vec(&file)?);
+        }
+        catch => panic!("GetKVActionReply{:?}", catch),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_upsert_copied_file_keeps_first_load_time() -> Result<()> {
+    let (_, copy_history_api) = new_copy_history_api().await?;
+
+    let mut file = create_test_copied_file(1, "data/a.csv");
+    file.error = Some("parse error".to_string());
+    copy_history_api.upsert_copied_file(file.clone()).await?;
+
+    let mut reloaded = create_test_copied_file(1, "data/a.csv");
+    reloaded.first_loaded_on = Utc.timestamp(1700000000, 0);
+    reloaded.last_loaded_on = Utc.timestamp(1700000000, 0);
+    copy_history_api.upsert_copied_file(reloaded).await?;
+
+    let got = copy_history_api
+        .get_copied_file(1, "data/a.csv")
+        .await?
+        .unwrap();
+    assert_eq!(got.first_loaded_on, file.first_loaded_on);
+    assert_eq!(got.last_loaded_on, Utc.timestamp(1700000000, 0));
+    assert_eq!(got.error, None);
+    assert!(got.is_loaded(file.size));
+    assert!(!got.is_loaded(file.size + 1));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_get_copied_files() -> Result<()> {
+    let (_, copy_history_api) = new_copy_history_api().await?;
+
+    let file1 = create_test_copied_file(1, "a.csv");
+    let file2 = create_test_copied_file(12, "b.csv");
+    copy_history_api.upsert_copied_file(file1.clone()).await?;
+    copy_history_api.upsert_copied_file(file2.clone()).await?;
+
+    assert_eq!(copy_history_api.get_table_copied_files(1).await?, vec![
+        file1.clone()
+    ]);
+    assert_eq!(copy_history_api.get_table_copied_files(12).await?, vec![
+        file2.clone()
+    ]);
+    assert_eq!(copy_history_api.get_copied_files().await?, vec![
+        file1, file2
+    ]);
+    assert_eq!(copy_history_api.get_copied_file(2, "a.csv").await?, None);
+
+    Ok(())
+}
+
+fn create_test_copied_file(table_id: u64, file_name: &str) -> CopiedFileInfo {
+    CopiedFileInfo {
+        database: "default".to_string(),
+        table: "t".to_string(),
+        table_id,
+        file_name: file_name.to_string(),
+        size: 1024,
+        rows_loaded: 10,
+        error: None,
+        first_loaded_on: Utc.timestamp(1600000000, 0),
+        last_loaded_on: Utc.timestamp(1600000000, 0),
+    }
+}
+
+async fn new_copy_history_api() -> Result<(Arc<MetaEmbedded>, CopyHistoryMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = CopyHistoryMgr::create(test_api.clone(), "admin")?;
+    Ok((test_api, mgr))
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod cluster;
+mod copy_history;
 mod setting;
 mod stage;
 mod udf;
@@ -59,6 +59,25 @@ async fn test_already_exists_add_stage() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_replace_stage() -> Result<()> {
+    let (_, stage_api) = new_stage_api().await?;
+
+    let stage_info = create_test_stage_info();
+    stage_api.replace_stage(stage_info.clone()).await?;
+    stage_api.add_stage(stage_info.clone()).await.unwrap_err();
+
+    let replaced = UserStageInfo {
+        comment: "replaced".to_string(),
+        ..stage_info
+    };
+    stage_api.replace_stage(replaced.clone()).await?;
+
+    let stages = stage_api.get_stages().await?;
+    assert_eq!(stages, vec![replaced]);
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_successfully_get_stages() -> Result<()> {
     let (_, stage_api) = new_stage_api().await?;
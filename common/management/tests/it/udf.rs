@@ -59,6 +59,34 @@ async fn test_already_exists_add_udf() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_replace_udf() -> Result<()> {
+    let (_, udf_api) = new_udf_api().await?;
+
+    let udf = create_test_udf();
+    udf_api.replace_udf(udf.clone()).await?;
+
+    let replaced = UserDefinedFunction::new(
+        "isnotempty",
+        vec!["p".to_string()],
+        "not(isnull(p))",
+        "This is a replaced description",
+    );
+    udf_api.replace_udf(replaced.clone()).await?;
+
+    let udfs = udf_api.get_udfs().await?;
+    assert_eq!(udfs, vec![replaced]);
+
+    match udf_api
+        .replace_udf(UserDefinedFunction::new("abs", vec![], "1", ""))
+        .await
+    {
+        Ok(_) => panic!("Replace builtin function must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2603),
+    }
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_successfully_get_udfs() -> Result<()> {
     let (_, udf_api) = new_udf_api().await?;
@@ -32,6 +32,8 @@ use common_meta_types::MetaError;
 use common_meta_types::MetaId;
 use common_meta_types::RenameTableReply;
 use common_meta_types::RenameTableReq;
+use common_meta_types::SwapTableReply;
+use common_meta_types::SwapTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
@@ -64,6 +66,8 @@ pub trait MetaApi: Send + Sync {
 
     async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply, MetaError>;
 
+    async fn swap_table(&self, req: SwapTableReq) -> Result<SwapTableReply, MetaError>;
+
     async fn get_table(&self, req: GetTableReq) -> Result<Arc<TableInfo>, MetaError>;
 
     async fn list_tables(&self, req: ListTableReq) -> Result<Vec<Arc<TableInfo>>, MetaError>;
@@ -28,6 +28,7 @@ use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::RenameTableReq;
+use common_meta_types::SwapTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
@@ -893,6 +894,104 @@ impl MetaApiTestSuite {
         Ok(())
     }
 
+    pub async fn table_swap<MT: MetaApi>(self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_name = "tb1";
+        let other_tbl_name = "tb2";
+
+        let table_meta = |opt: &str| TableMeta {
+            schema: Arc::new(DataSchema::new(vec![DataField::new(
+                "number",
+                u64::to_data_type(),
+            )])),
+            engine: "JSON".to_string(),
+            options: maplit::hashmap! {"opt-1".into() => opt.into()},
+            ..TableMeta::default()
+        };
+
+        let swap_req = |if_exists| SwapTableReq {
+            if_exists,
+            tenant: tenant.to_string(),
+            db: db_name.to_string(),
+            table_name: tbl_name.to_string(),
+            other_db: db_name.to_string(),
+            other_table_name: other_tbl_name.to_string(),
+        };
+
+        tracing::info!("--- prepare db and one table");
+        {
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                tenant: tenant.to_string(),
+                db: db_name.to_string(),
+                meta: DatabaseMeta::default(),
+            };
+            mt.create_database(plan).await?;
+
+            let req = CreateTableReq {
+                if_not_exists: false,
+                tenant: tenant.to_string(),
+                db: db_name.to_string(),
+                table: tbl_name.to_string(),
+                table_meta: table_meta("val-1"),
+            };
+            mt.create_table(req).await?;
+        }
+
+        tracing::info!("--- swap with unknown table, error");
+        {
+            let res = mt.swap_table(swap_req(false)).await;
+            tracing::debug!("--- swap with unknown table got: {:?}", res);
+
+            assert_eq!(
+                ErrorCode::UnknownTable("").code(),
+                ErrorCode::from(res.unwrap_err()).code()
+            );
+
+            let got = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+            assert_eq!(got.meta.options["opt-1"], "val-1", "table is not changed");
+        }
+
+        tracing::info!("--- swap with unknown table if exists, ok");
+        {
+            mt.swap_table(swap_req(true)).await?;
+        }
+
+        tracing::info!("--- swap, ok");
+        {
+            let req = CreateTableReq {
+                if_not_exists: false,
+                tenant: tenant.to_string(),
+                db: db_name.to_string(),
+                table: other_tbl_name.to_string(),
+                table_meta: table_meta("val-2"),
+            };
+            mt.create_table(req).await?;
+
+            let before = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+            let other_before = mt
+                .get_table((tenant, db_name, other_tbl_name).into())
+                .await?;
+
+            mt.swap_table(swap_req(false)).await?;
+
+            let got = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+            assert_eq!(other_before.ident, got.ident, "tables keep their ids");
+            assert_eq!(tbl_name, got.name);
+            assert_eq!(got.meta.options["opt-1"], "val-2");
+
+            let got = mt
+                .get_table((tenant, db_name, other_tbl_name).into())
+                .await?;
+            assert_eq!(before.ident, got.ident, "tables keep their ids");
+            assert_eq!(other_tbl_name, got.name);
+            assert_eq!(got.meta.options["opt-1"], "val-1");
+        }
+
+        Ok(())
+    }
+
     pub async fn table_list<MT: MetaApi>(&self, mt: &MT) -> anyhow::Result<()> {
         let tenant = "tenant1";
         let db_name = "db1";
@@ -33,6 +33,8 @@ use common_meta_types::MetaError;
 use common_meta_types::MetaId;
 use common_meta_types::RenameTableReply;
 use common_meta_types::RenameTableReq;
+use common_meta_types::SwapTableReply;
+use common_meta_types::SwapTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
@@ -90,6 +92,11 @@ impl MetaApi for MetaEmbedded {
         sm.rename_table(req).await
     }
 
+    async fn swap_table(&self, req: SwapTableReq) -> Result<SwapTableReply, MetaError> {
+        let sm = self.inner.lock().await;
+        sm.swap_table(req).await
+    }
+
     async fn get_table(&self, req: GetTableReq) -> Result<Arc<TableInfo>, MetaError> {
         let sm = self.inner.lock().await;
         let reply = sm.get_table(req).await?;
@@ -36,6 +36,8 @@ use common_meta_types::MetaId;
 use common_meta_types::PrefixListReply;
 use common_meta_types::RenameTableReply;
 use common_meta_types::RenameTableReq;
+use common_meta_types::SwapTableReply;
+use common_meta_types::SwapTableReq;
 use common_meta_types::TableInfo;
 use common_meta_types::UpsertKVAction;
 use common_meta_types::UpsertKVActionReply;
@@ -56,6 +58,7 @@ pub enum MetaGrpcWriteReq {
     CreateTable(CreateTableReq),
     DropTable(DropTableReq),
     RenameTable(RenameTableReq),
+    SwapTable(SwapTableReq),
     CommitTable(UpsertTableOptionReq),
 
     UpsertKV(UpsertKVAction),
@@ -200,6 +203,10 @@ impl RequestFor for RenameTableReq {
     type Reply = RenameTableReply;
 }
 
+impl RequestFor for SwapTableReq {
+    type Reply = SwapTableReply;
+}
+
 impl RequestFor for GetTableReq {
     type Reply = Arc<TableInfo>;
 }
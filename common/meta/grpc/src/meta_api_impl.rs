@@ -32,6 +32,8 @@ use common_meta_types::MetaError;
 use common_meta_types::MetaId;
 use common_meta_types::RenameTableReply;
 use common_meta_types::RenameTableReq;
+use common_meta_types::SwapTableReply;
+use common_meta_types::SwapTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
@@ -77,6 +79,10 @@ impl MetaApi for MetaGrpcClient {
         self.do_write(req).await
     }
 
+    async fn swap_table(&self, req: SwapTableReq) -> Result<SwapTableReply, MetaError> {
+        self.do_write(req).await
+    }
+
     async fn get_table(&self, req: GetTableReq) -> Result<Arc<TableInfo>, MetaError> {
         self.do_read(req).await
     }
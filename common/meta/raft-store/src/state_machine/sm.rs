@@ -552,6 +552,64 @@ impl StateMachine {
         )))
     }
 
+    /// Point each table name to the table id of the other one, the table metas are untouched.
+    fn apply_swap_table_cmd(
+        &self,
+        tenant: &str,
+        db_name: &str,
+        table_name: &str,
+        other_db_name: &str,
+        other_table_name: &str,
+        txn_tree: &TransactionSledTree,
+    ) -> MetaStorageResult<AppliedState> {
+        let lookup_key = TableLookupKey {
+            database_id: self.txn_get_database_id(tenant, db_name, txn_tree)?,
+            table_name: table_name.to_string(),
+        };
+        let other_lookup_key = TableLookupKey {
+            database_id: self.txn_get_database_id(tenant, other_db_name, txn_tree)?,
+            table_name: other_table_name.to_string(),
+        };
+
+        let table_lookup_tree = txn_tree.key_space::<TableLookup>();
+        let table_id = |key: &TableLookupKey| -> MetaStorageResult<u64> {
+            match table_lookup_tree.get(key)? {
+                Some(seq_table_id) => Ok(seq_table_id.data.0),
+                None => Err(MetaStorageError::AppError(AppError::UnknownTable(
+                    UnknownTable::new(&key.table_name, "apply_swap_table_cmd"),
+                ))),
+            }
+        };
+        let table_id_1 = table_id(&lookup_key)?;
+        let table_id_2 = table_id(&other_lookup_key)?;
+
+        self.txn_sub_tree_upsert(
+            &table_lookup_tree,
+            &lookup_key,
+            &MatchSeq::Any,
+            Operation::Update(TableLookupValue(table_id_2)),
+            None,
+        )?;
+        self.txn_sub_tree_upsert(
+            &table_lookup_tree,
+            &other_lookup_key,
+            &MatchSeq::Any,
+            Operation::Update(TableLookupValue(table_id_1)),
+            None,
+        )?;
+
+        self.txn_incr_seq(SEQ_DATABASE_META_ID, txn_tree)?;
+        tracing::debug!(
+            "applied swap Table: {}:{} <-> {}:{}",
+            db_name,
+            table_name,
+            other_db_name,
+            other_table_name
+        );
+
+        Ok(AppliedState::None)
+    }
+
     #[tracing::instrument(level = "debug", skip(self, txn_tree))]
     fn apply_update_kv_cmd(
         &self,
@@ -693,6 +751,21 @@ impl StateMachine {
                 txn_tree,
             ),
 
+            Cmd::SwapTable {
+                tenant,
+                ref db_name,
+                ref table_name,
+                ref other_db_name,
+                ref other_table_name,
+            } => self.apply_swap_table_cmd(
+                tenant,
+                db_name,
+                table_name,
+                other_db_name,
+                other_table_name,
+                txn_tree,
+            ),
+
             Cmd::UpsertKV {
                 key,
                 seq,
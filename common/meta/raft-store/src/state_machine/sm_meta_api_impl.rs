@@ -40,6 +40,8 @@ use common_meta_types::MetaId;
 use common_meta_types::MetaStorageError;
 use common_meta_types::RenameTableReply;
 use common_meta_types::RenameTableReq;
+use common_meta_types::SwapTableReply;
+use common_meta_types::SwapTableReq;
 use common_meta_types::TableAlreadyExists;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
@@ -238,6 +240,25 @@ impl MetaApi for StateMachine {
         }
     }
 
+    async fn swap_table(&self, req: SwapTableReq) -> Result<SwapTableReply, MetaError> {
+        let cmd = Cmd::SwapTable {
+            tenant: req.tenant,
+            db_name: req.db,
+            table_name: req.table_name,
+            other_db_name: req.other_db,
+            other_table_name: req.other_table_name,
+        };
+
+        let res = self.sm_tree.txn(true, |t| self.apply_cmd(&cmd, &t));
+        match res {
+            Err(MetaStorageError::AppError(AppError::UnknownTable(_))) if req.if_exists => {
+                Ok(SwapTableReply {})
+            }
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(SwapTableReply {}),
+        }
+    }
+
     async fn get_table(&self, req: GetTableReq) -> Result<Arc<TableInfo>, MetaError> {
         let tenant = &req.tenant;
         let db = &req.db_name;
@@ -54,6 +54,10 @@ pub struct NodeInfo {
     pub cpu_nums: u64,
     pub version: u32,
     pub flight_address: String,
+    /// A draining node gets no new tasks and leaves the cluster once its running queries finish.
+    pub draining: bool,
+    /// The node groups the node is in, e.g. `ingest` or `adhoc`, see the query node routing rules.
+    pub tags: Vec<String>,
 }
 
 impl TryFrom<Vec<u8>> for NodeInfo {
@@ -77,6 +81,8 @@ impl NodeInfo {
             cpu_nums,
             version: 0,
             flight_address,
+            draining: false,
+            tags: vec![],
         }
     }
 
@@ -85,4 +91,8 @@ impl NodeInfo {
 
         Ok((addr.ip().to_string(), addr.port()))
     }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
 }
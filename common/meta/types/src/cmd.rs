@@ -71,6 +71,15 @@ pub enum Cmd {
         new_table_name: String,
     },
 
+    /// Swap the names of two tables
+    SwapTable {
+        tenant: String,
+        db_name: String,
+        table_name: String,
+        other_db_name: String,
+        other_table_name: String,
+    },
+
     /// Update, remove or insert table options.
     ///
     /// This Cmd requires a present table to operate on.
@@ -145,6 +154,19 @@ impl fmt::Display for Cmd {
                     tenant, db_name, table_name, new_db_name, new_table_name
                 )
             }
+            Cmd::SwapTable {
+                tenant,
+                db_name,
+                table_name,
+                other_db_name,
+                other_table_name,
+            } => {
+                write!(
+                    f,
+                    "swap_table:{}/{}-{}<=>{}-{}",
+                    tenant, db_name, table_name, other_db_name, other_table_name
+                )
+            }
             Cmd::UpsertKV {
                 key,
                 seq,
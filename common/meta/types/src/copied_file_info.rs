@@ -0,0 +1,41 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::chrono::DateTime;
+use common_datavalues::chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The load result of a staged file copied into a table.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct CopiedFileInfo {
+    pub database: String,
+    pub table: String,
+    pub table_id: u64,
+    pub file_name: String,
+    // The object size, a file is loaded again only if its size changed.
+    pub size: u64,
+    pub rows_loaded: u64,
+    // The error of the last load, None if the file was loaded.
+    pub error: Option<String>,
+    pub first_loaded_on: DateTime<Utc>,
+    pub last_loaded_on: DateTime<Utc>,
+}
+
+impl CopiedFileInfo {
+    /// Whether the same version of the file was loaded into the table without error.
+    pub fn is_loaded(&self, size: u64) -> bool {
+        self.error.is_none() && self.size == size
+    }
+}
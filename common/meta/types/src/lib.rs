@@ -19,6 +19,7 @@ mod change;
 mod cluster;
 mod cmd;
 pub mod config;
+mod copied_file_info;
 mod database;
 mod endpoint;
 mod errors;
@@ -73,6 +74,7 @@ pub use cluster::Node;
 pub use cluster::NodeInfo;
 pub use cluster::Slot;
 pub use cmd::Cmd;
+pub use copied_file_info::CopiedFileInfo;
 pub use database::CreateDatabaseReply;
 pub use database::CreateDatabaseReq;
 pub use database::DatabaseInfo;
@@ -141,6 +143,8 @@ pub use table::GetTableReq;
 pub use table::ListTableReq;
 pub use table::RenameTableReply;
 pub use table::RenameTableReq;
+pub use table::SwapTableReply;
+pub use table::SwapTableReq;
 pub use table::TableIdent;
 pub use table::TableInfo;
 pub use table::TableMeta;
@@ -228,6 +228,20 @@ pub struct RenameTableReply {
     pub table_id: u64,
 }
 
+/// Exchange the names of two tables in one step, the tables keep their ids.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct SwapTableReq {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub db: String,
+    pub table_name: String,
+    pub other_db: String,
+    pub other_table_name: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct SwapTableReply {}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct UpsertTableOptionReq {
     pub table_id: u64,
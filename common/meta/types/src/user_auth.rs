@@ -23,6 +23,7 @@ const PLAINTEXT_PASSWORD_STR: &str = "plaintext_password";
 const SHA256_PASSWORD_STR: &str = "sha256_password";
 const DOUBLE_SHA1_PASSWORD_STR: &str = "double_sha1_password";
 const JWT_AUTH_STR: &str = "jwt";
+const LDAP_AUTH_STR: &str = "ldap";
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
 pub enum AuthType {
@@ -31,6 +32,7 @@ pub enum AuthType {
     Sha256Password,
     DoubleSha1Password,
     JWT,
+    Ldap,
 }
 
 impl std::str::FromStr for AuthType {
@@ -42,6 +44,7 @@ impl std::str::FromStr for AuthType {
             DOUBLE_SHA1_PASSWORD_STR => Ok(AuthType::DoubleSha1Password),
             NO_PASSWORD_STR => Ok(AuthType::NoPassword),
             JWT_AUTH_STR => Ok(AuthType::JWT),
+            LDAP_AUTH_STR => Ok(AuthType::Ldap),
             _ => Err(ErrorCode::InvalidAuthInfo(AuthType::bad_auth_types(s))),
         }
     }
@@ -55,6 +58,7 @@ impl AuthType {
             AuthType::Sha256Password => SHA256_PASSWORD_STR,
             AuthType::DoubleSha1Password => DOUBLE_SHA1_PASSWORD_STR,
             AuthType::JWT => JWT_AUTH_STR,
+            AuthType::Ldap => LDAP_AUTH_STR,
         }
     }
 
@@ -65,6 +69,7 @@ impl AuthType {
             SHA256_PASSWORD_STR,
             DOUBLE_SHA1_PASSWORD_STR,
             JWT_AUTH_STR,
+            LDAP_AUTH_STR,
         ];
         let all = all
             .iter()
@@ -92,6 +97,11 @@ pub enum AuthInfo {
         hash_method: PasswordHashMethod,
     },
     JWT,
+    /// The password is checked by binding to the LDAP server as the user.
+    /// `bind_dn` overrides the DN built from the configured template.
+    Ldap {
+        bind_dn: Option<String>,
+    },
 }
 
 fn calc_sha1(v: &[u8]) -> [u8; 20] {
@@ -109,6 +119,9 @@ impl AuthInfo {
         match auth_type {
             AuthType::NoPassword => Ok(AuthInfo::None),
             AuthType::JWT => Ok(AuthInfo::JWT),
+            AuthType::Ldap => Ok(AuthInfo::Ldap {
+                bind_dn: auth_string.clone(),
+            }),
             AuthType::PlaintextPassword
             | AuthType::Sha256Password
             | AuthType::DoubleSha1Password => match auth_string {
@@ -152,6 +165,7 @@ impl AuthInfo {
         match self {
             AuthInfo::None => AuthType::NoPassword,
             AuthInfo::JWT => AuthType::JWT,
+            AuthInfo::Ldap { .. } => AuthType::Ldap,
             AuthInfo::Password {
                 hash_value: _,
                 hash_method: t,
@@ -169,7 +183,10 @@ impl AuthInfo {
                 hash_value: p,
                 hash_method: t,
             } => t.to_string(p),
-            AuthInfo::None | AuthInfo::JWT => "".to_string(),
+            AuthInfo::Ldap {
+                bind_dn: Some(bind_dn),
+            } => bind_dn.clone(),
+            AuthInfo::None | AuthInfo::JWT | AuthInfo::Ldap { .. } => "".to_string(),
         }
     }
 
@@ -69,6 +69,7 @@ pub enum StageFileCompression {
     RawDeflate,
     Lzo,
     Snappy,
+    Xz,
     None,
 }
 
@@ -78,6 +79,27 @@ impl Default for StageFileCompression {
     }
 }
 
+impl FromStr for StageFileCompression {
+    type Err = String;
+    fn from_str(s: &str) -> std::result::Result<Self, String> {
+        match s.to_uppercase().as_str() {
+            "AUTO" => Ok(StageFileCompression::Auto),
+            "GZIP" => Ok(StageFileCompression::Gzip),
+            "BZ2" => Ok(StageFileCompression::Bz2),
+            "BROTLI" => Ok(StageFileCompression::Brotli),
+            "ZSTD" => Ok(StageFileCompression::Zstd),
+            "DEFLATE" => Ok(StageFileCompression::Deflate),
+            "RAW_DEFLATE" => Ok(StageFileCompression::RawDeflate),
+            "LZO" => Ok(StageFileCompression::Lzo),
+            "SNAPPY" => Ok(StageFileCompression::Snappy),
+            "XZ" => Ok(StageFileCompression::Xz),
+            "NONE" => Ok(StageFileCompression::None),
+            _ => Err("Unknown file compression type, must one of { AUTO | GZIP | BZ2 | BROTLI | ZSTD | DEFLATE | RAW_DEFLATE | LZO | SNAPPY | XZ | NONE }"
+                    .to_string()),
+        }
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
 pub enum StageFileFormatType {
     Csv,
@@ -22,6 +22,8 @@ fn test_node_info_ip_port() -> Result<()> {
         cpu_nums: 1,
         version: 1,
         flight_address: "1.2.3.4:123".to_string(),
+        draining: false,
+        tags: vec![],
     };
 
     let (ip, port) = n.ip_port()?;
@@ -30,3 +32,19 @@ fn test_node_info_ip_port() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_node_info_tags() -> Result<()> {
+    // The nodes registered before the tags have none.
+    let old = r#"{"id":"n1","cpu_nums":1,"version":1,"flight_address":"1.2.3.4:123"}"#;
+    let n: NodeInfo = serde_json::from_str(old)?;
+    assert!(n.tags.is_empty());
+    assert!(!n.has_tag("ingest"));
+
+    let mut n = NodeInfo::create("n1".to_string(), 1, "1.2.3.4:123".to_string());
+    n.tags = vec!["ingest".to_string(), "etl".to_string()];
+    assert!(n.has_tag("etl"));
+    assert!(!n.has_tag("adhoc"));
+
+    Ok(())
+}
@@ -20,6 +20,9 @@ mod plan_copy;
 mod plan_database_create;
 mod plan_database_drop;
 mod plan_database_show_create;
+mod plan_deallocate;
+mod plan_distinct;
+mod plan_drain_node;
 mod plan_empty;
 mod plan_explain;
 mod plan_expression;
@@ -44,7 +47,9 @@ mod plan_list;
 mod plan_node;
 mod plan_node_builder;
 mod plan_node_display;
+mod plan_node_display_graphviz;
 mod plan_node_display_indent;
+mod plan_node_display_json;
 mod plan_node_extras;
 mod plan_node_rewriter;
 mod plan_node_s3_stage_table;
@@ -52,6 +57,7 @@ mod plan_node_stage;
 mod plan_node_statistics;
 mod plan_node_visitor;
 mod plan_partition;
+mod plan_prepare;
 mod plan_privilege_grant;
 mod plan_privilege_revoke;
 mod plan_projection;
@@ -62,6 +68,7 @@ mod plan_role_drop;
 mod plan_role_grant;
 mod plan_role_revoke;
 mod plan_select;
+mod plan_select_into_outfile;
 mod plan_setting;
 mod plan_show;
 mod plan_show_databases;
@@ -77,13 +84,16 @@ mod plan_show_users;
 mod plan_sink;
 mod plan_sort;
 mod plan_subqueries_set;
+mod plan_table_comment;
 mod plan_table_create;
 mod plan_table_describe;
 mod plan_table_drop;
 mod plan_table_optimize;
 mod plan_table_rename;
 mod plan_table_show_create;
+mod plan_table_swap;
 mod plan_table_truncate;
+mod plan_top_n;
 mod plan_use_database;
 mod plan_user_alter;
 mod plan_user_create;
@@ -94,6 +104,7 @@ mod plan_user_stage_drop;
 mod plan_user_udf_alter;
 mod plan_user_udf_create;
 mod plan_user_udf_drop;
+mod plan_values;
 mod plan_view_alter;
 mod plan_view_create;
 mod plan_view_drop;
@@ -108,6 +119,9 @@ pub use plan_database_create::CreateDatabasePlan;
 pub use plan_database_create::DatabaseOptions;
 pub use plan_database_drop::DropDatabasePlan;
 pub use plan_database_show_create::ShowCreateDatabasePlan;
+pub use plan_deallocate::DeallocatePlan;
+pub use plan_distinct::DistinctPlan;
+pub use plan_drain_node::DrainNodePlan;
 pub use plan_empty::EmptyPlan;
 pub use plan_explain::ExplainPlan;
 pub use plan_explain::ExplainType;
@@ -124,11 +138,13 @@ pub use plan_expression_common::extract_aliases;
 pub use plan_expression_common::find_aggregate_exprs;
 pub use plan_expression_common::find_aggregate_exprs_in_expr;
 pub use plan_expression_common::find_columns_not_satisfy_exprs;
+pub use plan_expression_common::find_subquery_exprs;
 pub use plan_expression_common::rebase_expr;
 pub use plan_expression_common::rebase_expr_from_input;
 pub use plan_expression_common::resolve_aliases_to_exprs;
 pub use plan_expression_common::sort_to_inner_expr;
 pub use plan_expression_common::unwrap_alias_exprs;
+pub use plan_expression_common::ExpressionVolatilityVisitor;
 pub use plan_expression_common::RequireColumnsVisitor;
 pub use plan_expression_function::add;
 pub use plan_expression_function::avg;
@@ -156,6 +172,8 @@ pub use plan_limit_by::LimitByPlan;
 pub use plan_list::ListPlan;
 pub use plan_node::PlanNode;
 pub use plan_node_builder::PlanBuilder;
+pub use plan_node_display_graphviz::PlanNodeGraphvizVisitor;
+pub use plan_node_display_json::PlanNodeJsonVisitor;
 pub use plan_node_extras::Extras;
 pub use plan_node_rewriter::PlanRewriter;
 pub use plan_node_rewriter::RewriteHelper;
@@ -167,6 +185,7 @@ pub use plan_node_visitor::PlanVisitor;
 pub use plan_partition::PartInfo;
 pub use plan_partition::PartInfoPtr;
 pub use plan_partition::Partitions;
+pub use plan_prepare::PreparePlan;
 pub use plan_privilege_grant::GrantPrivilegePlan;
 pub use plan_privilege_revoke::RevokePrivilegePlan;
 pub use plan_projection::ProjectionPlan;
@@ -178,6 +197,9 @@ pub use plan_role_drop::DropRolePlan;
 pub use plan_role_grant::GrantRolePlan;
 pub use plan_role_revoke::RevokeRolePlan;
 pub use plan_select::SelectPlan;
+pub use plan_select_into_outfile::OutfileCompression;
+pub use plan_select_into_outfile::OutfileFormat;
+pub use plan_select_into_outfile::SelectIntoOutfilePlan;
 pub use plan_setting::SettingPlan;
 pub use plan_setting::VarValue;
 pub use plan_show::PlanShowKind;
@@ -196,6 +218,7 @@ pub use plan_sink::SinkPlan;
 pub use plan_sink::SINK_SCHEMA;
 pub use plan_sort::SortPlan;
 pub use plan_subqueries_set::SubQueriesSetPlan;
+pub use plan_table_comment::CommentTablePlan;
 pub use plan_table_create::CreateTablePlan;
 pub use plan_table_create::TableOptions;
 pub use plan_table_describe::DescribeTablePlan;
@@ -205,7 +228,9 @@ pub use plan_table_optimize::OptimizeTablePlan;
 pub use plan_table_rename::RenameTableEntity;
 pub use plan_table_rename::RenameTablePlan;
 pub use plan_table_show_create::ShowCreateTablePlan;
+pub use plan_table_swap::SwapTablePlan;
 pub use plan_table_truncate::TruncateTablePlan;
+pub use plan_top_n::TopNPlan;
 pub use plan_use_database::UseDatabasePlan;
 pub use plan_user_alter::AlterUserPlan;
 pub use plan_user_create::CreateUserPlan;
@@ -216,6 +241,7 @@ pub use plan_user_stage_drop::DropUserStagePlan;
 pub use plan_user_udf_alter::AlterUserUDFPlan;
 pub use plan_user_udf_create::CreateUserUDFPlan;
 pub use plan_user_udf_drop::DropUserUDFPlan;
+pub use plan_values::ValuesPlan;
 pub use plan_view_alter::AlterViewPlan;
 pub use plan_view_create::CreateViewPlan;
 pub use plan_view_drop::DropViewPlan;
@@ -62,6 +62,9 @@ pub struct CopyPlan {
     pub validation_mode: ValidationMode,
     pub files: Vec<String>,
     pub pattern: String,
+    pub force: bool,
+    /// Sort each loaded block by the cluster key of the table before writing it.
+    pub sort_by_cluster_key: bool,
 }
 
 impl CopyPlan {
@@ -81,6 +84,12 @@ impl Debug for CopyPlan {
         if !self.pattern.is_empty() {
             write!(f, " ,pattern:{:?}", self.pattern)?;
         }
+        if self.force {
+            write!(f, " ,force:{:?}", self.force)?;
+        }
+        if !self.sort_by_cluster_key {
+            write!(f, " ,sort_by_cluster_key:{:?}", self.sort_by_cluster_key)?;
+        }
         write!(f, " ,validation_mode:{:?}", self.validation_mode)
     }
 }
@@ -22,7 +22,9 @@ use crate::PlanNode;
 pub enum ExplainType {
     Syntax,
     Graph,
+    Graphviz,
     Pipeline,
+    Json,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
@@ -17,6 +17,8 @@ use std::fmt;
 use common_datavalues::prelude::*;
 use common_functions::scalars::Function;
 
+use crate::ExpressionChain;
+
 #[derive(Debug, Clone)]
 pub enum ExpressionAction {
     /// Column which must be in input.
@@ -25,6 +27,7 @@ pub enum ExpressionAction {
     Constant(ActionConstant),
     Alias(ActionAlias),
     Function(ActionFunction),
+    Conditional(ActionConditional),
 }
 
 #[derive(Debug, Clone)]
@@ -59,6 +62,19 @@ pub struct ActionFunction {
     pub arg_types: Vec<DataTypePtr>,
 }
 
+/// A conditional function, `if` or `multi_if`, whose results are evaluated only on the rows
+/// selected by their conditions. Each argument is computed by its own chain.
+#[derive(Debug, Clone)]
+pub struct ActionConditional {
+    pub name: String,
+    pub func_name: String,
+    pub return_type: DataTypePtr,
+    /// The conditions of the branches, with the names of their columns.
+    pub conditions: Vec<(String, ExpressionChain)>,
+    /// The results of the branches and the else result last, with the names of their columns.
+    pub results: Vec<(String, ExpressionChain)>,
+}
+
 impl ExpressionAction {
     pub fn column_name(&self) -> &str {
         match self {
@@ -66,6 +82,7 @@ impl ExpressionAction {
             ExpressionAction::Constant(c) => &c.name,
             ExpressionAction::Alias(a) => &a.name,
             ExpressionAction::Function(f) => &f.name,
+            ExpressionAction::Conditional(c) => &c.name,
         }
     }
 }
@@ -20,6 +20,7 @@ use common_functions::scalars::CastFunction;
 use common_functions::scalars::FunctionFactory;
 
 use crate::ActionAlias;
+use crate::ActionConditional;
 use crate::ActionConstant;
 use crate::ActionFunction;
 use crate::ActionInput;
@@ -53,8 +54,14 @@ impl ExpressionChain {
         struct ExpressionActionVisitor(*mut ExpressionChain);
 
         impl ExpressionVisitor for ExpressionActionVisitor {
-            fn pre_visit(self, _expr: &Expression) -> Result<Recursion<Self>> {
-                Ok(Recursion::Continue(self))
+            fn pre_visit(self, expr: &Expression) -> Result<Recursion<Self>> {
+                // the arguments of a conditional are chained on their own
+                match expr {
+                    Expression::ScalarFunction { op, .. } if is_conditional(op) => {
+                        Ok(Recursion::Stop(self))
+                    }
+                    _ => Ok(Recursion::Continue(self)),
+                }
             }
 
             fn post_visit(self, expr: &Expression) -> Result<Self> {
@@ -163,6 +170,10 @@ impl ExpressionChain {
                 self.actions.push(ExpressionAction::Function(function));
             }
 
+            Expression::ScalarFunction { op, args } if is_conditional(op) => {
+                self.add_conditional(expr, op, args)?;
+            }
+
             Expression::ScalarFunction { op, args } => {
                 let arg_types = args
                     .iter()
@@ -221,4 +232,41 @@ impl ExpressionChain {
         }
         Ok(())
     }
+
+    fn add_conditional(&mut self, expr: &Expression, op: &str, args: &[Expression]) -> Result<()> {
+        let arg_types = args
+            .iter()
+            .map(|arg| arg.to_data_type(&self.schema))
+            .collect::<Result<Vec<_>>>()?;
+        let arg_types: Vec<&DataTypePtr> = arg_types.iter().collect();
+
+        let func = FunctionFactory::instance().get(op, &arg_types)?;
+        let return_type = func.return_type(&arg_types)?;
+
+        let mut conditions = Vec::with_capacity(args.len() / 2);
+        let mut results = Vec::with_capacity(args.len() / 2 + 1);
+        for (i, arg) in args.iter().enumerate() {
+            let chain = ExpressionChain::try_create(self.schema.clone(), &[arg.clone()])?;
+            // the arguments are the pairs of conditions and results, and the else result
+            match i % 2 == 0 && i != args.len() - 1 {
+                true => conditions.push((arg.column_name(), chain)),
+                false => results.push((arg.column_name(), chain)),
+            }
+        }
+
+        self.actions
+            .push(ExpressionAction::Conditional(ActionConditional {
+                name: expr.column_name(),
+                func_name: op.to_string(),
+                return_type,
+                conditions,
+                results,
+            }));
+        Ok(())
+    }
+}
+
+/// The conditional functions whose results are evaluated lazily, see [`ActionConditional`].
+fn is_conditional(op: &str) -> bool {
+    op.eq_ignore_ascii_case("if") || op.eq_ignore_ascii_case("multi_if")
 }
@@ -19,6 +19,7 @@ use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::scalars::FunctionFactory;
+use common_functions::scalars::Volatility;
 
 use crate::Expression;
 use crate::ExpressionVisitor;
@@ -83,6 +84,17 @@ pub fn find_column_exprs(exprs: &[Expression]) -> Vec<Expression> {
     })
 }
 
+/// Collect all deeply nested `Expression::Subquery` and `Expression::ScalarSubquery`. They are
+/// returned in order of appearance (depth first), with duplicates omitted.
+pub fn find_subquery_exprs(exprs: &[Expression]) -> Vec<Expression> {
+    find_exprs_in_exprs(exprs, &|nest_exprs| {
+        matches!(
+            nest_exprs,
+            Expression::Subquery { .. } | Expression::ScalarSubquery { .. }
+        )
+    })
+}
+
 /// Search the provided `Expression`'s, and all of their nested `Expression`, for any that
 /// pass the provided test. The returned `Expression`'s are deduplicated and returned
 /// in order of appearance (depth first).
@@ -307,11 +319,13 @@ where F: Fn(&Expression) -> Result<Option<Expression>> {
                 expr: nested_expr,
                 asc,
                 nulls_first,
+                case_insensitive,
                 origin_expr,
             } => Ok(Expression::Sort {
                 expr: Box::new(clone_with_replacement(&**nested_expr, replacement_fn)?),
                 asc: *asc,
                 nulls_first: *nulls_first,
+                case_insensitive: *case_insensitive,
                 origin_expr: origin_expr.clone(),
             }),
             Expression::Cast {
@@ -517,3 +531,53 @@ impl ExpressionVisitor for RequireColumnsVisitor {
         }
     }
 }
+
+// This visitor is for recursively visiting expression tree and computes the volatility of it,
+// which is the most volatile one of all functions in the tree.
+pub struct ExpressionVolatilityVisitor {
+    pub volatility: Volatility,
+}
+
+impl ExpressionVolatilityVisitor {
+    pub fn default() -> Self {
+        Self {
+            volatility: Volatility::Immutable,
+        }
+    }
+
+    pub fn volatility_of_expr(expr: &Expression) -> Result<Volatility> {
+        let mut visitor = Self::default();
+        visitor = expr.accept(visitor)?;
+        Ok(visitor.volatility)
+    }
+
+    fn merge(mut self, volatility: Volatility) -> Recursion<Self> {
+        self.volatility = self.volatility.max(volatility);
+        match self.volatility {
+            Volatility::Volatile => Recursion::Stop(self),
+            _ => Recursion::Continue(self),
+        }
+    }
+}
+
+impl ExpressionVisitor for ExpressionVolatilityVisitor {
+    fn pre_visit(self, expr: &Expression) -> Result<Recursion<Self>> {
+        match expr {
+            Expression::UnaryExpression { op, .. }
+            | Expression::BinaryExpression { op, .. }
+            | Expression::ScalarFunction { op, .. } => {
+                // Unknown functions are treated as volatile, so nothing is folded or pruned by them.
+                let volatility = FunctionFactory::instance()
+                    .get_features(op)
+                    .map(|features| features.volatility)
+                    .unwrap_or(Volatility::Volatile);
+                Ok(self.merge(volatility))
+            }
+            // The result of a subquery depends on the data read within the query.
+            Expression::Subquery { .. } | Expression::ScalarSubquery { .. } => {
+                Ok(self.merge(Volatility::Stable))
+            }
+            _ => Ok(Recursion::Continue(self)),
+        }
+    }
+}
@@ -19,6 +19,7 @@ use common_datavalues::DataField;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::scalars::CastFunction;
 use common_functions::scalars::Function;
 use common_functions::scalars::FunctionFactory;
 use common_functions::scalars::Monotonicity;
@@ -46,6 +47,14 @@ use crate::Recursion;
 // Simple depth first search visit the expression tree and gete monotonicity from
 // every function. Each function is responsible to implement its own monotonicity
 // function.
+// The boundaries, types and monotonicity of the arguments of a function.
+struct FunctionArgs {
+    left_vec: Vec<Option<ColumnWithField>>,
+    right_vec: Vec<Option<ColumnWithField>>,
+    arg_types: Vec<DataTypePtr>,
+    monotonicity_vec: Vec<Monotonicity>,
+}
+
 #[derive(Clone)]
 pub struct ExpressionMonotonicityVisitor {
     input_schema: DataSchemaRef,
@@ -107,6 +116,27 @@ impl ExpressionMonotonicityVisitor {
     }
 
     fn visit_function(mut self, op: &str, args_size: usize) -> Result<Self> {
+        let args = self.pop_args(args_size)?;
+
+        let instance = FunctionFactory::instance();
+        let arg_types: Vec<&DataTypePtr> = args.arg_types.iter().collect();
+        let func = instance.get(op, &arg_types)?;
+        let is_deterministic = instance.get_features(op)?.is_deterministic();
+        self.push_function(op, func.as_ref(), is_deterministic, args)
+    }
+
+    fn visit_cast(mut self, data_type: &DataTypePtr, is_nullable: bool) -> Result<Self> {
+        let args = self.pop_args(1)?;
+
+        let type_name = format!("{:?}", data_type);
+        let func = match is_nullable {
+            true => CastFunction::create_try("cast", &type_name)?,
+            false => CastFunction::create("cast", &type_name)?,
+        };
+        self.push_function("cast", func.as_ref(), true, args)
+    }
+
+    fn pop_args(&mut self, args_size: usize) -> Result<FunctionArgs> {
         let mut left_vec = Vec::with_capacity(args_size);
         let mut right_vec = Vec::with_capacity(args_size);
         let mut arg_types = Vec::with_capacity(args_size);
@@ -129,22 +159,29 @@ impl ExpressionMonotonicityVisitor {
             }
         }
 
-        let instance = FunctionFactory::instance();
-
-        let arg_types: Vec<&DataTypePtr> = arg_types.iter().collect();
-        let func = instance.get(op, &arg_types)?;
+        Ok(FunctionArgs {
+            left_vec,
+            right_vec,
+            arg_types,
+            monotonicity_vec,
+        })
+    }
 
+    fn push_function(
+        mut self,
+        op: &str,
+        func: &dyn Function,
+        is_deterministic: bool,
+        args: FunctionArgs,
+    ) -> Result<Self> {
+        let arg_types: Vec<&DataTypePtr> = args.arg_types.iter().collect();
         let return_type = func.return_type(&arg_types)?;
         let mut monotonic = match self.single_point {
-            false => func.get_monotonicity(monotonicity_vec.as_ref())?,
-            true => {
-                let features = instance.get_features(op)?;
-                if features.is_deterministic {
-                    Monotonicity::create_constant()
-                } else {
-                    Monotonicity::default()
-                }
-            }
+            false => func.get_monotonicity(args.monotonicity_vec.as_ref())?,
+            true => match is_deterministic {
+                true => Monotonicity::create_constant(),
+                false => Monotonicity::default(),
+            },
         };
 
         // Neither a monotonic expression nor constant, interrupt the traversal and return an error directly.
@@ -155,8 +192,8 @@ impl ExpressionMonotonicityVisitor {
             )));
         }
 
-        monotonic.left = Self::try_calculate_boundary(func.as_ref(), &return_type, left_vec)?;
-        monotonic.right = Self::try_calculate_boundary(func.as_ref(), &return_type, right_vec)?;
+        monotonic.left = Self::try_calculate_boundary(func, &return_type, args.left_vec)?;
+        monotonic.right = Self::try_calculate_boundary(func, &return_type, args.right_vec)?;
 
         self.stack.push((return_type, monotonic));
         Ok(self)
@@ -189,6 +226,7 @@ impl ExpressionMonotonicityVisitor {
         if let Expression::Sort {
             asc,
             nulls_first,
+            case_insensitive,
             origin_expr,
             ..
         } = sort_expr
@@ -206,6 +244,7 @@ impl ExpressionMonotonicityVisitor {
                 expr: Box::new(col(column_name)),
                 asc: new_asc,
                 nulls_first: *nulls_first,
+                case_insensitive: *case_insensitive,
                 origin_expr: origin_expr.clone(),
             });
         }
@@ -265,7 +304,11 @@ impl ExpressionVisitor for ExpressionMonotonicityVisitor {
             Expression::BinaryExpression { op, .. } => self.visit_function(op, 2),
             Expression::UnaryExpression { op, .. } => self.visit_function(op, 1),
             Expression::ScalarFunction { op, args } => self.visit_function(op, args.len()),
-            // Todo: Expression::Cast
+            Expression::Cast {
+                data_type,
+                is_nullable,
+                ..
+            } => self.visit_cast(data_type, *is_nullable),
             _ => Err(ErrorCode::UnknownException("Unable to get monotonicity")),
         }
     }
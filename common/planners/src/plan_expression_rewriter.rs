@@ -174,12 +174,14 @@ pub trait ExpressionRewriter: Sized {
         expr: Expression,
         asc: bool,
         nulls_first: bool,
+        case_insensitive: bool,
         origin_expr: &Expression,
     ) -> Result<Expression> {
         Ok(Expression::Sort {
             expr: Box::new(expr),
             asc,
             nulls_first,
+            case_insensitive,
             origin_expr: Box::new(origin_expr.clone()),
         })
     }
@@ -328,6 +330,7 @@ impl<T: ExpressionRewriter> ExpressionVisitor for ExpressionRewriteVisitor<T> {
             Expression::Sort {
                 asc,
                 nulls_first,
+                case_insensitive,
                 origin_expr,
                 ..
             } => match self.stack.pop() {
@@ -335,9 +338,13 @@ impl<T: ExpressionRewriter> ExpressionVisitor for ExpressionRewriteVisitor<T> {
                     "Sort expr expected 1 parameters, actual 0.",
                 )),
                 Some(expr) => {
-                    let new_expr = self
-                        .inner
-                        .mutate_sort(expr, *asc, *nulls_first, origin_expr)?;
+                    let new_expr = self.inner.mutate_sort(
+                        expr,
+                        *asc,
+                        *nulls_first,
+                        *case_insensitive,
+                        origin_expr,
+                    )?;
                     self.stack.push(new_expr);
                     Ok(self)
                 }
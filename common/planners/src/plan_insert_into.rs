@@ -21,7 +21,9 @@ use crate::PlanNode;
 #[derive(serde::Serialize, serde::Deserialize, Clone)]
 pub enum InsertInputSource {
     SelectPlan(Box<PlanNode>),
-    Expressions(String, Vec<Vec<Expression>>),
+    Expressions(Vec<Vec<Expression>>),
+    /// The literal tuples of VALUES, e.g. `(1, 'a'), (2, 'b')`
+    Values(String),
     StreamingWithFormat(String),
 }
 
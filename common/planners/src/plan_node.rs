@@ -15,6 +15,8 @@
 use std::sync::Arc;
 
 use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
 
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
@@ -23,6 +25,7 @@ use crate::AlterUserUDFPlan;
 use crate::AlterViewPlan;
 use crate::BroadcastPlan;
 use crate::CallPlan;
+use crate::CommentTablePlan;
 use crate::CopyPlan;
 use crate::CreateDatabasePlan;
 use crate::CreateRolePlan;
@@ -31,8 +34,11 @@ use crate::CreateUserPlan;
 use crate::CreateUserStagePlan;
 use crate::CreateUserUDFPlan;
 use crate::CreateViewPlan;
+use crate::DeallocatePlan;
 use crate::DescribeTablePlan;
 use crate::DescribeUserStagePlan;
+use crate::DistinctPlan;
+use crate::DrainNodePlan;
 use crate::DropDatabasePlan;
 use crate::DropRolePlan;
 use crate::DropTablePlan;
@@ -53,12 +59,14 @@ use crate::LimitByPlan;
 use crate::LimitPlan;
 use crate::ListPlan;
 use crate::OptimizeTablePlan;
+use crate::PreparePlan;
 use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
 use crate::RemotePlan;
 use crate::RenameTablePlan;
 use crate::RevokePrivilegePlan;
 use crate::RevokeRolePlan;
+use crate::SelectIntoOutfilePlan;
 use crate::SelectPlan;
 use crate::SettingPlan;
 use crate::ShowCreateDatabasePlan;
@@ -68,8 +76,11 @@ use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::SubQueriesSetPlan;
+use crate::SwapTablePlan;
+use crate::TopNPlan;
 use crate::TruncateTablePlan;
 use crate::UseDatabasePlan;
+use crate::ValuesPlan;
 
 #[allow(clippy::large_enum_variant)]
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
@@ -86,9 +97,12 @@ pub enum PlanNode {
     Filter(FilterPlan),
     Having(HavingPlan),
     Sort(SortPlan),
+    TopN(TopNPlan),
+    Distinct(DistinctPlan),
     Limit(LimitPlan),
     LimitBy(LimitByPlan),
     ReadSource(ReadDataSourcePlan),
+    Values(ValuesPlan),
     SubQueryExpression(SubQueriesSetPlan),
     Sink(SinkPlan),
 
@@ -97,6 +111,7 @@ pub enum PlanNode {
 
     // Query.
     Select(SelectPlan),
+    SelectIntoOutfile(SelectIntoOutfilePlan),
 
     // Insert.
     Insert(InsertPlan),
@@ -122,6 +137,8 @@ pub enum PlanNode {
     CreateTable(CreateTablePlan),
     DropTable(DropTablePlan),
     RenameTable(RenameTablePlan),
+    SwapTable(SwapTablePlan),
+    CommentTable(CommentTablePlan),
     TruncateTable(TruncateTablePlan),
     OptimizeTable(OptimizeTablePlan),
     DescribeTable(DescribeTablePlan),
@@ -167,6 +184,13 @@ pub enum PlanNode {
 
     // Kill.
     Kill(KillPlan),
+
+    // Cluster.
+    DrainNode(DrainNodePlan),
+
+    // Prepared statement.
+    Prepare(PreparePlan),
+    Deallocate(DeallocatePlan),
 }
 
 impl PlanNode {
@@ -187,7 +211,10 @@ impl PlanNode {
             PlanNode::Limit(v) => v.schema(),
             PlanNode::LimitBy(v) => v.schema(),
             PlanNode::ReadSource(v) => v.schema(),
+            PlanNode::Values(v) => v.schema(),
             PlanNode::Sort(v) => v.schema(),
+            PlanNode::TopN(v) => v.schema(),
+            PlanNode::Distinct(v) => v.schema(),
             PlanNode::SubQueryExpression(v) => v.schema(),
             PlanNode::Sink(v) => v.schema(),
 
@@ -196,6 +223,7 @@ impl PlanNode {
 
             // Query.
             PlanNode::Select(v) => v.schema(),
+            PlanNode::SelectIntoOutfile(v) => v.schema(),
 
             // Insert.
             PlanNode::Insert(v) => v.schema(),
@@ -218,6 +246,8 @@ impl PlanNode {
             PlanNode::CreateTable(v) => v.schema(),
             PlanNode::DropTable(v) => v.schema(),
             PlanNode::RenameTable(v) => v.schema(),
+            PlanNode::SwapTable(v) => v.schema(),
+            PlanNode::CommentTable(v) => v.schema(),
             PlanNode::TruncateTable(v) => v.schema(),
             PlanNode::OptimizeTable(v) => v.schema(),
             PlanNode::DescribeTable(v) => v.schema(),
@@ -266,6 +296,13 @@ impl PlanNode {
 
             // Kill.
             PlanNode::Kill(v) => v.schema(),
+
+            // Cluster.
+            PlanNode::DrainNode(v) => v.schema(),
+
+            // Prepared statement.
+            PlanNode::Prepare(v) => v.schema(),
+            PlanNode::Deallocate(v) => v.schema(),
         }
     }
 
@@ -285,7 +322,10 @@ impl PlanNode {
             PlanNode::Limit(_) => "LimitPlan",
             PlanNode::LimitBy(_) => "LimitByPlan",
             PlanNode::ReadSource(_) => "ReadSourcePlan",
+            PlanNode::Values(_) => "ValuesPlan",
             PlanNode::Sort(_) => "SortPlan",
+            PlanNode::TopN(_) => "TopNPlan",
+            PlanNode::Distinct(_) => "DistinctPlan",
             PlanNode::SubQueryExpression(_) => "CreateSubQueriesSets",
             PlanNode::Sink(_) => "SinkPlan",
 
@@ -294,6 +334,7 @@ impl PlanNode {
 
             // Select.
             PlanNode::Select(_) => "SelectPlan",
+            PlanNode::SelectIntoOutfile(_) => "SelectIntoOutfilePlan",
 
             // Insert.
             PlanNode::Insert(_) => "InsertPlan",
@@ -316,6 +357,8 @@ impl PlanNode {
             PlanNode::CreateTable(_) => "CreateTablePlan",
             PlanNode::DropTable(_) => "DropTablePlan",
             PlanNode::RenameTable(_) => "RenameTablePlan",
+            PlanNode::SwapTable(_) => "SwapTablePlan",
+            PlanNode::CommentTable(_) => "CommentTablePlan",
             PlanNode::TruncateTable(_) => "TruncateTablePlan",
             PlanNode::OptimizeTable(_) => "OptimizeTablePlan",
             PlanNode::ShowCreateTable(_) => "ShowCreateTablePlan",
@@ -364,6 +407,13 @@ impl PlanNode {
 
             // Kill.
             PlanNode::Kill(_) => "KillQuery",
+
+            // Cluster.
+            PlanNode::DrainNode(_) => "DrainNodePlan",
+
+            // Prepared statement.
+            PlanNode::Prepare(_) => "PreparePlan",
+            PlanNode::Deallocate(_) => "DeallocatePlan",
         }
     }
 
@@ -378,17 +428,167 @@ impl PlanNode {
             PlanNode::Filter(v) => vec![v.input.clone()],
             PlanNode::Having(v) => vec![v.input.clone()],
             PlanNode::Limit(v) => vec![v.input.clone()],
+            PlanNode::LimitBy(v) => vec![v.input.clone()],
             PlanNode::Explain(v) => vec![v.input.clone()],
             PlanNode::Select(v) => vec![v.input.clone()],
+            PlanNode::SelectIntoOutfile(v) => vec![v.input.clone()],
             PlanNode::Sort(v) => vec![v.input.clone()],
+            PlanNode::TopN(v) => vec![v.input.clone()],
+            PlanNode::Distinct(v) => vec![v.input.clone()],
             PlanNode::SubQueryExpression(v) => v.get_inputs(),
             PlanNode::Sink(v) => vec![v.input.clone()],
 
-            _ => vec![],
+            // The SELECT of an INSERT or a CREATE TABLE ... AS SELECT is optimized on its own,
+            // it's not an input of the node.
+            PlanNode::Empty(_)
+            | PlanNode::Remote(_)
+            | PlanNode::ReadSource(_)
+            | PlanNode::Values(_)
+            | PlanNode::Insert(_)
+            | PlanNode::Copy(_)
+            | PlanNode::Call(_)
+            | PlanNode::Show(_)
+            | PlanNode::CreateDatabase(_)
+            | PlanNode::DropDatabase(_)
+            | PlanNode::ShowCreateDatabase(_)
+            | PlanNode::CreateTable(_)
+            | PlanNode::DropTable(_)
+            | PlanNode::RenameTable(_)
+            | PlanNode::SwapTable(_)
+            | PlanNode::CommentTable(_)
+            | PlanNode::TruncateTable(_)
+            | PlanNode::OptimizeTable(_)
+            | PlanNode::ShowCreateTable(_)
+            | PlanNode::DescribeTable(_)
+            | PlanNode::CreateView(_)
+            | PlanNode::AlterView(_)
+            | PlanNode::DropView(_)
+            | PlanNode::CreateUser(_)
+            | PlanNode::AlterUser(_)
+            | PlanNode::DropUser(_)
+            | PlanNode::GrantPrivilege(_)
+            | PlanNode::GrantRole(_)
+            | PlanNode::RevokePrivilege(_)
+            | PlanNode::RevokeRole(_)
+            | PlanNode::CreateRole(_)
+            | PlanNode::DropRole(_)
+            | PlanNode::CreateUserStage(_)
+            | PlanNode::DropUserStage(_)
+            | PlanNode::DescribeUserStage(_)
+            | PlanNode::List(_)
+            | PlanNode::CreateUserUDF(_)
+            | PlanNode::DropUserUDF(_)
+            | PlanNode::AlterUserUDF(_)
+            | PlanNode::UseDatabase(_)
+            | PlanNode::SetVariable(_)
+            | PlanNode::Kill(_)
+            | PlanNode::DrainNode(_)
+            | PlanNode::Prepare(_)
+            | PlanNode::Deallocate(_) => vec![],
         }
     }
 
     pub fn input(&self, n: usize) -> Arc<PlanNode> {
         self.inputs()[n].clone()
     }
+
+    /// Return a copy of the node with its inputs replaced, in the same order as [`PlanNode::inputs`].
+    ///
+    /// The rest of the node, including the schema it carries, is kept as is.
+    pub fn with_new_inputs(&self, inputs: &[PlanNode]) -> Result<PlanNode> {
+        let expect = self.inputs().len();
+        if inputs.len() != expect {
+            return Err(ErrorCode::LogicalError(format!(
+                "Logical error: {} expects {} inputs, but got {}",
+                self.name(),
+                expect,
+                inputs.len()
+            )));
+        }
+
+        let mut node = self.clone();
+        match &mut node {
+            PlanNode::Stage(v) => v.set_input(&inputs[0]),
+            PlanNode::Broadcast(v) => v.set_input(&inputs[0]),
+            PlanNode::Projection(v) => v.set_input(&inputs[0]),
+            PlanNode::Expression(v) => v.set_input(&inputs[0]),
+            PlanNode::AggregatorPartial(v) => v.set_input(&inputs[0]),
+            PlanNode::AggregatorFinal(v) => v.set_input(&inputs[0]),
+            PlanNode::Filter(v) => v.set_input(&inputs[0]),
+            PlanNode::Having(v) => v.set_input(&inputs[0]),
+            PlanNode::Limit(v) => v.set_input(&inputs[0]),
+            PlanNode::LimitBy(v) => v.set_input(&inputs[0]),
+            PlanNode::Explain(v) => v.set_input(&inputs[0]),
+            PlanNode::Select(v) => v.set_input(&inputs[0]),
+            PlanNode::SelectIntoOutfile(v) => v.set_input(&inputs[0]),
+            PlanNode::Sort(v) => v.set_input(&inputs[0]),
+            PlanNode::TopN(v) => v.set_input(&inputs[0]),
+            PlanNode::Distinct(v) => v.set_input(&inputs[0]),
+            PlanNode::SubQueryExpression(v) => v.set_inputs(inputs.iter().collect()),
+            PlanNode::Sink(v) => v.input = Arc::new(inputs[0].clone()),
+
+            PlanNode::Empty(_)
+            | PlanNode::Remote(_)
+            | PlanNode::ReadSource(_)
+            | PlanNode::Values(_)
+            | PlanNode::Insert(_)
+            | PlanNode::Copy(_)
+            | PlanNode::Call(_)
+            | PlanNode::Show(_)
+            | PlanNode::CreateDatabase(_)
+            | PlanNode::DropDatabase(_)
+            | PlanNode::ShowCreateDatabase(_)
+            | PlanNode::CreateTable(_)
+            | PlanNode::DropTable(_)
+            | PlanNode::RenameTable(_)
+            | PlanNode::SwapTable(_)
+            | PlanNode::CommentTable(_)
+            | PlanNode::TruncateTable(_)
+            | PlanNode::OptimizeTable(_)
+            | PlanNode::ShowCreateTable(_)
+            | PlanNode::DescribeTable(_)
+            | PlanNode::CreateView(_)
+            | PlanNode::AlterView(_)
+            | PlanNode::DropView(_)
+            | PlanNode::CreateUser(_)
+            | PlanNode::AlterUser(_)
+            | PlanNode::DropUser(_)
+            | PlanNode::GrantPrivilege(_)
+            | PlanNode::GrantRole(_)
+            | PlanNode::RevokePrivilege(_)
+            | PlanNode::RevokeRole(_)
+            | PlanNode::CreateRole(_)
+            | PlanNode::DropRole(_)
+            | PlanNode::CreateUserStage(_)
+            | PlanNode::DropUserStage(_)
+            | PlanNode::DescribeUserStage(_)
+            | PlanNode::List(_)
+            | PlanNode::CreateUserUDF(_)
+            | PlanNode::DropUserUDF(_)
+            | PlanNode::AlterUserUDF(_)
+            | PlanNode::UseDatabase(_)
+            | PlanNode::SetVariable(_)
+            | PlanNode::Kill(_)
+            | PlanNode::DrainNode(_)
+            | PlanNode::Prepare(_)
+            | PlanNode::Deallocate(_) => {}
+        }
+
+        Ok(node)
+    }
+
+    /// Rebuild the plan tree bottom-up.
+    ///
+    /// The inputs of a node are transformed first, then `f` is called with the node rebuilt on
+    /// the new inputs, so a rule only has to match the nodes it cares about and return the others as is.
+    pub fn transform_up<F>(&self, f: &mut F) -> Result<PlanNode>
+    where F: FnMut(PlanNode) -> Result<PlanNode> {
+        let new_inputs = self
+            .inputs()
+            .iter()
+            .map(|input| input.transform_up(f))
+            .collect::<Result<Vec<_>>>()?;
+
+        f(self.with_new_inputs(&new_inputs)?)
+    }
 }
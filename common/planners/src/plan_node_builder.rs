@@ -23,6 +23,7 @@ use crate::plan_subqueries_set::SubQueriesSetPlan;
 use crate::validate_expression;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::DistinctPlan;
 use crate::EmptyPlan;
 use crate::ExplainPlan;
 use crate::ExplainType;
@@ -37,6 +38,7 @@ use crate::ProjectionPlan;
 use crate::RewriteHelper;
 use crate::SelectPlan;
 use crate::SortPlan;
+use crate::TopNPlan;
 
 pub enum AggregateMode {
     Partial,
@@ -222,6 +224,25 @@ impl PlanBuilder {
         })))
     }
 
+    /// Apply a sort that only keeps the first n rows
+    pub fn top_n(&self, exprs: &[Expression], n: usize) -> Result<Self> {
+        Ok(Self::from(&PlanNode::TopN(TopNPlan {
+            order_by: exprs.to_vec(),
+            limit: n,
+            schema: self.plan.schema(),
+            input: self.wrap_subquery_plan(exprs)?,
+        })))
+    }
+
+    /// Apply a distinct, keeping the first row of each key
+    pub fn distinct(&self, exprs: &[Expression]) -> Result<Self> {
+        Ok(Self::from(&PlanNode::Distinct(DistinctPlan {
+            distinct_on: exprs.to_vec(),
+            schema: self.plan.schema(),
+            input: self.wrap_subquery_plan(exprs)?,
+        })))
+    }
+
     /// Apply a limit
     pub fn limit(&self, n: usize) -> Result<Self> {
         Ok(Self::from(&PlanNode::Limit(LimitPlan {
@@ -20,6 +20,7 @@ use common_datavalues::remove_nullable;
 use common_datavalues::DataField;
 use common_datavalues::DataSchema;
 
+use crate::plan_node_display_graphviz::PlanNodeGraphvizVisitor;
 use crate::plan_node_display_indent::PlanNodeIndentFormatDisplay;
 use crate::PlanNode;
 
@@ -28,22 +29,16 @@ impl PlanNode {
         PlanNodeIndentFormatDisplay::create(0, self, false)
     }
 
-    pub fn display_graphviz(&self) -> impl fmt::Display + '_ {
-        struct Wrapper<'a>(&'a PlanNode);
+    pub fn display_graphviz(&self, with_schema: bool) -> impl fmt::Display + '_ {
+        struct Wrapper<'a>(&'a PlanNode, bool);
         impl<'a> fmt::Display for Wrapper<'a> {
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                writeln!(
-                    f,
-                    "// Begin Databend GraphViz Plan (see https://graphviz.org)"
-                )?;
-                writeln!(f, "digraph {{")?;
-                // TODO()
-                writeln!(f, "}}")?;
-                writeln!(f, "// End Databend GraphViz Plan")?;
-                Ok(())
+                let dot =
+                    PlanNodeGraphvizVisitor::to_dot(self.0, self.1).map_err(|_| fmt::Error)?;
+                write!(f, "{}", dot)
             }
         }
-        Wrapper(self)
+        Wrapper(self, with_schema)
     }
 
     pub fn display_schema(schema: &DataSchema) -> impl fmt::Display + '_ {
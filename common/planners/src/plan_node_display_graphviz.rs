@@ -0,0 +1,349 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::DataSchemaRef;
+use common_exception::Result;
+
+use crate::AggregatorFinalPlan;
+use crate::AggregatorPartialPlan;
+use crate::BroadcastPlan;
+use crate::DistinctPlan;
+use crate::EmptyPlan;
+use crate::Expression;
+use crate::ExpressionPlan;
+use crate::FilterPlan;
+use crate::HavingPlan;
+use crate::LimitByPlan;
+use crate::LimitPlan;
+use crate::PlanNode;
+use crate::PlanVisitor;
+use crate::ProjectionPlan;
+use crate::ReadDataSourcePlan;
+use crate::RemotePlan;
+use crate::SinkPlan;
+use crate::SortPlan;
+use crate::StagePlan;
+use crate::SubQueriesSetPlan;
+use crate::TopNPlan;
+use crate::ValuesPlan;
+
+/// Renders a PlanNode tree as a GraphViz DOT digraph.
+///
+/// Every plan node is a box labeled with its kind and attributes, edges point from
+/// the input to the node consuming it. Empty and Select nodes are skipped, the same
+/// as the indent display does.
+pub struct PlanNodeGraphvizVisitor {
+    with_schema: bool,
+    // Ids of the nodes whose parent is not rendered yet.
+    stack: Vec<usize>,
+    nodes: Vec<String>,
+    edges: Vec<String>,
+}
+
+impl PlanNodeGraphvizVisitor {
+    pub fn create(with_schema: bool) -> PlanNodeGraphvizVisitor {
+        PlanNodeGraphvizVisitor {
+            with_schema,
+            stack: vec![],
+            nodes: vec![],
+            edges: vec![],
+        }
+    }
+
+    pub fn to_dot(plan: &PlanNode, with_schema: bool) -> Result<String> {
+        let mut visitor = PlanNodeGraphvizVisitor::create(with_schema);
+        visitor.visit_plan_node(plan)?;
+
+        let mut dot = String::new();
+        dot.push_str("// Begin Databend GraphViz Plan (see https://graphviz.org)\n");
+        dot.push_str("digraph {\n");
+        for line in visitor.nodes.iter().chain(visitor.edges.iter()) {
+            dot.push_str("  ");
+            dot.push_str(line);
+            dot.push('\n');
+        }
+        dot.push_str("}\n");
+        dot.push_str("// End Databend GraphViz Plan\n");
+        Ok(dot)
+    }
+
+    fn escape(text: &str) -> String {
+        text.replace('\\', "\\\\").replace('"', "\\\"")
+    }
+
+    fn exprs_label(exprs: &[Expression]) -> String {
+        exprs
+            .iter()
+            .map(|expr| format!("{:?}", expr))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn schema_label(schema: &DataSchemaRef) -> String {
+        format!("{}", PlanNode::display_schema(schema))
+    }
+
+    fn visit_input(&mut self, input: &PlanNode) -> Result<usize> {
+        let before = self.stack.len();
+        self.visit_plan_node(input)?;
+        Ok(self.stack.len() - before)
+    }
+
+    fn add_node(
+        &mut self,
+        kind: &str,
+        schema: &DataSchemaRef,
+        attrs: Vec<(&str, String)>,
+        children: usize,
+    ) -> Result<()> {
+        let id = self.nodes.len();
+
+        let mut label = Self::escape(kind);
+        for (name, value) in attrs {
+            label.push_str(&format!("\\n{}: {}", name, Self::escape(&value)));
+        }
+        if self.with_schema {
+            let schema = Self::schema_label(schema);
+            label.push_str(&format!("\\nschema: {}", Self::escape(&schema)));
+        }
+
+        self.nodes
+            .push(format!("node{} [shape=box, label=\"{}\"];", id, label));
+        for child in self.stack.split_off(self.stack.len() - children) {
+            self.edges.push(format!("node{} -> node{};", child, id));
+        }
+
+        self.stack.push(id);
+        Ok(())
+    }
+}
+
+impl PlanVisitor for PlanNodeGraphvizVisitor {
+    fn visit_aggregate_partial(&mut self, plan: &AggregatorPartialPlan) -> Result<()> {
+        let children = self.visit_input(plan.input.as_ref())?;
+        self.add_node(
+            "AggregatorPartial",
+            &plan.schema(),
+            vec![
+                ("groupBy", Self::exprs_label(&plan.group_expr)),
+                ("aggrExpr", Self::exprs_label(&plan.aggr_expr)),
+            ],
+            children,
+        )
+    }
+
+    fn visit_aggregate_final(&mut self, plan: &AggregatorFinalPlan) -> Result<()> {
+        let children = self.visit_input(plan.input.as_ref())?;
+        self.add_node(
+            "AggregatorFinal",
+            &plan.schema(),
+            vec![
+                ("groupBy", Self::exprs_label(&plan.group_expr)),
+                ("aggrExpr", Self::exprs_label(&plan.aggr_expr)),
+            ],
+            children,
+        )
+    }
+
+    fn visit_empty(&mut self, _: &EmptyPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_stage(&mut self, plan: &StagePlan) -> Result<()> {
+        let children = self.visit_input(plan.input.as_ref())?;
+        self.add_node(
+            "RedistributeStage",
+            &plan.schema(),
+            vec![
+                ("kind", format!("{:?}", plan.kind)),
+                ("scatters", format!("{:?}", plan.scatters_expr)),
+            ],
+            children,
+        )
+    }
+
+    fn visit_broadcast(&mut self, plan: &BroadcastPlan) -> Result<()> {
+        let children = self.visit_input(plan.input.as_ref())?;
+        self.add_node("Broadcast", &plan.schema(), vec![], children)
+    }
+
+    fn visit_remote(&mut self, plan: &RemotePlan) -> Result<()> {
+        self.add_node(
+            "Remote",
+            &plan.schema(),
+            vec![
+                ("stage", plan.stage_id.clone()),
+                ("stream", plan.stream_id.clone()),
+                ("fetch_nodes", plan.fetch_nodes.join(", ")),
+            ],
+            0,
+        )
+    }
+
+    fn visit_projection(&mut self, plan: &ProjectionPlan) -> Result<()> {
+        let children = self.visit_input(plan.input.as_ref())?;
+        self.add_node(
+            "Projection",
+            &plan.schema(),
+            vec![("expr", Self::exprs_label(&plan.expr))],
+            children,
+        )
+    }
+
+    fn visit_expression(&mut self, plan: &ExpressionPlan) -> Result<()> {
+        let children = self.visit_input(plan.input.as_ref())?;
+        self.add_node(
+            "Expression",
+            &plan.schema(),
+            vec![
+                ("expr", Self::exprs_label(&plan.exprs)),
+                ("desc", plan.desc.clone()),
+            ],
+            children,
+        )
+    }
+
+    fn visit_sub_queries_sets(&mut self, plan: &SubQueriesSetPlan) -> Result<()> {
+        let mut children = 0;
+        for input in plan.get_inputs() {
+            children += self.visit_input(input.as_ref())?;
+        }
+
+        self.add_node("CreateSubQueriesSets", &plan.schema(), vec![], children)
+    }
+
+    fn visit_filter(&mut self, plan: &FilterPlan) -> Result<()> {
+        let children = self.visit_input(plan.input.as_ref())?;
+        self.add_node(
+            "Filter",
+            &plan.schema(),
+            vec![("predicate", format!("{:?}", plan.predicate))],
+            children,
+        )
+    }
+
+    fn visit_having(&mut self, plan: &HavingPlan) -> Result<()> {
+        let children = self.visit_input(plan.input.as_ref())?;
+        self.add_node(
+            "Having",
+            &plan.schema(),
+            vec![("predicate", format!("{:?}", plan.predicate))],
+            children,
+        )
+    }
+
+    fn visit_sort(&mut self, plan: &SortPlan) -> Result<()> {
+        let children = self.visit_input(plan.input.as_ref())?;
+        self.add_node(
+            "Sort",
+            &plan.schema(),
+            vec![("orderBy", Self::exprs_label(&plan.order_by))],
+            children,
+        )
+    }
+
+    fn visit_top_n(&mut self, plan: &TopNPlan) -> Result<()> {
+        let children = self.visit_input(plan.input.as_ref())?;
+        self.add_node(
+            "TopN",
+            &plan.schema(),
+            vec![
+                ("orderBy", Self::exprs_label(&plan.order_by)),
+                ("limit", plan.limit.to_string()),
+            ],
+            children,
+        )
+    }
+
+    fn visit_distinct(&mut self, plan: &DistinctPlan) -> Result<()> {
+        let children = self.visit_input(plan.input.as_ref())?;
+        self.add_node(
+            "Distinct",
+            &plan.schema(),
+            vec![("distinctOn", Self::exprs_label(&plan.distinct_on))],
+            children,
+        )
+    }
+
+    fn visit_limit(&mut self, plan: &LimitPlan) -> Result<()> {
+        let children = self.visit_input(plan.input.as_ref())?;
+        let mut attrs = vec![];
+        if let Some(n) = plan.n {
+            attrs.push(("limit", n.to_string()));
+        }
+        if plan.offset != 0 {
+            attrs.push(("offset", plan.offset.to_string()));
+        }
+        self.add_node("Limit", &plan.schema(), attrs, children)
+    }
+
+    fn visit_limit_by(&mut self, plan: &LimitByPlan) -> Result<()> {
+        let children = self.visit_input(plan.input.as_ref())?;
+        self.add_node(
+            "LimitBy",
+            &plan.schema(),
+            vec![
+                ("limit", plan.limit.to_string()),
+                ("limitBy", Self::exprs_label(&plan.limit_by)),
+            ],
+            children,
+        )
+    }
+
+    fn visit_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<()> {
+        let mut attrs = vec![
+            ("source", plan.source_info.desc()),
+            (
+                "statistics",
+                format!(
+                    "read_rows: {}, read_bytes: {}, partitions_scanned: {}, partitions_total: {}",
+                    plan.statistics.read_rows,
+                    plan.statistics.read_bytes,
+                    plan.statistics.partitions_scanned,
+                    plan.statistics.partitions_total
+                ),
+            ),
+        ];
+
+        if let Some(push_downs) = &plan.push_downs {
+            if !push_downs.filters.is_empty() {
+                attrs.push(("filters", Self::exprs_label(&push_downs.filters)));
+            }
+            if let Some(limit) = push_downs.limit {
+                attrs.push(("limit", limit.to_string()));
+            }
+        }
+
+        self.add_node("ReadDataSource", &plan.schema(), attrs, 0)
+    }
+
+    fn visit_values(&mut self, plan: &ValuesPlan) -> Result<()> {
+        self.add_node(
+            "Values",
+            &plan.schema(),
+            vec![("rows", plan.values.len().to_string())],
+            0,
+        )
+    }
+
+    fn visit_append(&mut self, plan: &SinkPlan) -> Result<()> {
+        let children = self.visit_input(plan.input.as_ref())?;
+        self.add_node(
+            "Sink",
+            &plan.schema(),
+            vec![("table", plan.table_info.name.clone())],
+            children,
+        )
+    }
+}
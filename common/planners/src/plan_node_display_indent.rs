@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::fmt;
 use std::fmt::Formatter;
 
@@ -23,6 +24,7 @@ use crate::CopyPlan;
 use crate::CreateDatabasePlan;
 use crate::CreateRolePlan;
 use crate::CreateTablePlan;
+use crate::DistinctPlan;
 use crate::DropDatabasePlan;
 use crate::DropRolePlan;
 use crate::DropTablePlan;
@@ -36,6 +38,8 @@ use crate::RenameTablePlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::SubQueriesSetPlan;
+use crate::TopNPlan;
+use crate::ValuesPlan;
 
 pub struct PlanNodeIndentFormatDisplay<'a> {
     indent: usize,
@@ -69,9 +73,12 @@ impl<'a> fmt::Display for PlanNodeIndentFormatDisplay<'a> {
             PlanNode::Filter(plan) => write!(f, "Filter: {:?}", plan.predicate),
             PlanNode::Having(plan) => write!(f, "Having: {:?}", plan.predicate),
             PlanNode::Sort(plan) => Self::format_sort(f, plan),
+            PlanNode::TopN(plan) => Self::format_top_n(f, plan),
+            PlanNode::Distinct(plan) => Self::format_distinct(f, plan),
             PlanNode::Limit(plan) => Self::format_limit(f, plan),
             PlanNode::SubQueryExpression(plan) => Self::format_subquery_expr(f, plan),
             PlanNode::ReadSource(plan) => Self::format_read_source(f, plan),
+            PlanNode::Values(plan) => Self::format_values(f, plan),
             PlanNode::CreateDatabase(plan) => Self::format_create_database(f, plan),
             PlanNode::DropDatabase(plan) => Self::format_drop_database(f, plan),
             PlanNode::CreateTable(plan) => Self::format_create_table(f, plan),
@@ -193,6 +200,36 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
         fmt::Result::Ok(())
     }
 
+    fn format_distinct(f: &mut Formatter, plan: &DistinctPlan) -> fmt::Result {
+        write!(f, "Distinct: ")?;
+        for i in 0..plan.distinct_on.len() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{:?}", plan.distinct_on[i])?;
+        }
+
+        fmt::Result::Ok(())
+    }
+
+    fn format_top_n(f: &mut Formatter, plan: &TopNPlan) -> fmt::Result {
+        write!(f, "TopN: {}, ", plan.limit)?;
+        for i in 0..plan.order_by.len() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            let expr = plan.order_by[i].clone();
+            write!(
+                f,
+                "{:?}:{:?}",
+                expr,
+                expr.to_data_type(&plan.schema()).unwrap()
+            )?;
+        }
+
+        fmt::Result::Ok(())
+    }
+
     fn format_limit(f: &mut Formatter, plan: &LimitPlan) -> fmt::Result {
         match (plan.n, plan.offset) {
             (Some(n), 0) => write!(f, "Limit: {}", n),
@@ -256,12 +293,35 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
                     write!(f, "order_by: {:?}", p.order_by)?;
                 }
 
+                if let Some(sample) = p.sample {
+                    if comma {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "sample: {}%", sample)?;
+                }
+
                 write!(f, "]")?;
             }
         }
         Ok(())
     }
 
+    fn format_values(f: &mut Formatter, plan: &ValuesPlan) -> fmt::Result {
+        let fields = plan
+            .schema
+            .fields()
+            .iter()
+            .cloned()
+            .enumerate()
+            .collect::<BTreeMap<_, _>>();
+        write!(
+            f,
+            "Values: schema: {}, rows: {}",
+            PlanNode::display_scan_fields(&fields),
+            plan.values.len()
+        )
+    }
+
     fn format_create_database(f: &mut Formatter, plan: &CreateDatabasePlan) -> fmt::Result {
         write!(f, "Create database {:},", plan.db)?;
         write!(f, " if_not_exists:{:},", plan.if_not_exists)?;
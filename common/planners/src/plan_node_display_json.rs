@@ -0,0 +1,371 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::remove_nullable;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde_json::json;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::AggregatorFinalPlan;
+use crate::AggregatorPartialPlan;
+use crate::BroadcastPlan;
+use crate::DistinctPlan;
+use crate::EmptyPlan;
+use crate::ExplainPlan;
+use crate::Expression;
+use crate::ExpressionPlan;
+use crate::FilterPlan;
+use crate::HavingPlan;
+use crate::LimitByPlan;
+use crate::LimitPlan;
+use crate::PlanNode;
+use crate::PlanVisitor;
+use crate::ProjectionPlan;
+use crate::ReadDataSourcePlan;
+use crate::RemotePlan;
+use crate::SelectPlan;
+use crate::SinkPlan;
+use crate::SortPlan;
+use crate::StagePlan;
+use crate::SubQueriesSetPlan;
+use crate::TopNPlan;
+use crate::ValuesPlan;
+
+/// Serializes a PlanNode tree into a stable JSON document.
+///
+/// Every node is rendered as an object with the keys `kind`, `schema`, `children`
+/// and the node specific attributes (expressions, limits, statistics...).
+/// Keys are emitted in the order they are inserted by the visitor (serde_json is built with
+/// `preserve_order`), which doesn't depend on the run, so the output is stable and can be
+/// consumed by external tools.
+pub struct PlanNodeJsonVisitor {
+    stack: Vec<Value>,
+}
+
+impl PlanNodeJsonVisitor {
+    pub fn create() -> PlanNodeJsonVisitor {
+        PlanNodeJsonVisitor { stack: vec![] }
+    }
+
+    pub fn to_json(plan: &PlanNode) -> Result<Value> {
+        let mut visitor = PlanNodeJsonVisitor::create();
+        visitor.visit_plan_node(plan)?;
+
+        match visitor.stack.len() {
+            1 => Ok(visitor.stack.remove(0)),
+            0 => Ok(json!({
+                "kind": plan.name(),
+                "schema": Self::schema_json(&plan.schema()),
+                "children": [],
+            })),
+            _ => Err(ErrorCode::LogicalError(
+                "Logical error: plan json visitor must produce a single root node.",
+            )),
+        }
+    }
+
+    pub fn to_json_string(plan: &PlanNode) -> Result<String> {
+        Ok(serde_json::to_string_pretty(&Self::to_json(plan)?)?)
+    }
+
+    fn schema_json(schema: &DataSchemaRef) -> Value {
+        Value::Array(
+            schema
+                .fields()
+                .iter()
+                .map(|field| {
+                    json!({
+                        "name": field.name(),
+                        "type": format!("{:?}", remove_nullable(field.data_type())),
+                        "nullable": field.is_nullable(),
+                    })
+                })
+                .collect(),
+        )
+    }
+
+    fn exprs_json(exprs: &[Expression]) -> Value {
+        Value::Array(
+            exprs
+                .iter()
+                .map(|expr| Value::String(format!("{:?}", expr)))
+                .collect(),
+        )
+    }
+
+    fn add_node(
+        &mut self,
+        kind: &str,
+        schema: &DataSchemaRef,
+        attrs: Vec<(&str, Value)>,
+        children: usize,
+    ) -> Result<()> {
+        if self.stack.len() < children {
+            return Err(ErrorCode::LogicalError(format!(
+                "Logical error: {} expects {} children in plan json visitor.",
+                kind, children
+            )));
+        }
+
+        let children = self.stack.split_off(self.stack.len() - children);
+        let mut node = Map::new();
+        node.insert("kind".to_string(), Value::String(kind.to_string()));
+        node.insert("schema".to_string(), Self::schema_json(schema));
+        node.insert("children".to_string(), Value::Array(children));
+        for (name, value) in attrs {
+            node.insert(name.to_string(), value);
+        }
+
+        self.stack.push(Value::Object(node));
+        Ok(())
+    }
+}
+
+impl PlanVisitor for PlanNodeJsonVisitor {
+    fn visit_aggregate_partial(&mut self, plan: &AggregatorPartialPlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())?;
+        self.add_node(
+            "AggregatorPartial",
+            &plan.schema(),
+            vec![
+                ("group_by", Self::exprs_json(&plan.group_expr)),
+                ("aggregates", Self::exprs_json(&plan.aggr_expr)),
+            ],
+            1,
+        )
+    }
+
+    fn visit_aggregate_final(&mut self, plan: &AggregatorFinalPlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())?;
+        self.add_node(
+            "AggregatorFinal",
+            &plan.schema(),
+            vec![
+                ("group_by", Self::exprs_json(&plan.group_expr)),
+                ("aggregates", Self::exprs_json(&plan.aggr_expr)),
+            ],
+            1,
+        )
+    }
+
+    fn visit_empty(&mut self, plan: &EmptyPlan) -> Result<()> {
+        self.add_node("Empty", &plan.schema(), vec![], 0)
+    }
+
+    fn visit_stage(&mut self, plan: &StagePlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())?;
+        self.add_node(
+            "RedistributeStage",
+            &plan.schema(),
+            vec![
+                ("stage_kind", Value::String(format!("{:?}", plan.kind))),
+                ("scatters", Self::exprs_json(&[plan.scatters_expr.clone()])),
+            ],
+            1,
+        )
+    }
+
+    fn visit_broadcast(&mut self, plan: &BroadcastPlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())?;
+        self.add_node("Broadcast", &plan.schema(), vec![], 1)
+    }
+
+    fn visit_remote(&mut self, plan: &RemotePlan) -> Result<()> {
+        self.add_node(
+            "Remote",
+            &plan.schema(),
+            vec![
+                ("stage_id", Value::String(plan.stage_id.clone())),
+                ("stream_id", Value::String(plan.stream_id.clone())),
+                ("fetch_nodes", json!(plan.fetch_nodes)),
+            ],
+            0,
+        )
+    }
+
+    fn visit_projection(&mut self, plan: &ProjectionPlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())?;
+        self.add_node(
+            "Projection",
+            &plan.schema(),
+            vec![("expressions", Self::exprs_json(&plan.expr))],
+            1,
+        )
+    }
+
+    fn visit_expression(&mut self, plan: &ExpressionPlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())?;
+        self.add_node(
+            "Expression",
+            &plan.schema(),
+            vec![
+                ("expressions", Self::exprs_json(&plan.exprs)),
+                ("description", Value::String(plan.desc.clone())),
+            ],
+            1,
+        )
+    }
+
+    fn visit_sub_queries_sets(&mut self, plan: &SubQueriesSetPlan) -> Result<()> {
+        let inputs = plan.get_inputs();
+        for input in &inputs {
+            self.visit_plan_node(input.as_ref())?;
+        }
+
+        self.add_node("CreateSubQueriesSets", &plan.schema(), vec![], inputs.len())
+    }
+
+    fn visit_filter(&mut self, plan: &FilterPlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())?;
+        self.add_node(
+            "Filter",
+            &plan.schema(),
+            vec![("predicate", Value::String(format!("{:?}", plan.predicate)))],
+            1,
+        )
+    }
+
+    fn visit_having(&mut self, plan: &HavingPlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())?;
+        self.add_node(
+            "Having",
+            &plan.schema(),
+            vec![("predicate", Value::String(format!("{:?}", plan.predicate)))],
+            1,
+        )
+    }
+
+    fn visit_sort(&mut self, plan: &SortPlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())?;
+        self.add_node(
+            "Sort",
+            &plan.schema(),
+            vec![("order_by", Self::exprs_json(&plan.order_by))],
+            1,
+        )
+    }
+
+    fn visit_top_n(&mut self, plan: &TopNPlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())?;
+        self.add_node(
+            "TopN",
+            &plan.schema(),
+            vec![
+                ("order_by", Self::exprs_json(&plan.order_by)),
+                ("limit", json!(plan.limit)),
+            ],
+            1,
+        )
+    }
+
+    fn visit_distinct(&mut self, plan: &DistinctPlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())?;
+        self.add_node(
+            "Distinct",
+            &plan.schema(),
+            vec![("distinct_on", Self::exprs_json(&plan.distinct_on))],
+            1,
+        )
+    }
+
+    fn visit_limit(&mut self, plan: &LimitPlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())?;
+        self.add_node(
+            "Limit",
+            &plan.schema(),
+            vec![("limit", json!(plan.n)), ("offset", json!(plan.offset))],
+            1,
+        )
+    }
+
+    fn visit_limit_by(&mut self, plan: &LimitByPlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())?;
+        self.add_node(
+            "LimitBy",
+            &plan.schema(),
+            vec![
+                ("limit", json!(plan.limit)),
+                ("limit_by", Self::exprs_json(&plan.limit_by)),
+            ],
+            1,
+        )
+    }
+
+    fn visit_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<()> {
+        let mut attrs = vec![
+            ("source", Value::String(plan.source_info.desc())),
+            (
+                "statistics",
+                json!({
+                    "read_rows": plan.statistics.read_rows,
+                    "read_bytes": plan.statistics.read_bytes,
+                    "partitions_scanned": plan.statistics.partitions_scanned,
+                    "partitions_total": plan.statistics.partitions_total,
+                    "is_exact": plan.statistics.is_exact,
+                }),
+            ),
+        ];
+
+        if let Some(push_downs) = &plan.push_downs {
+            attrs.push((
+                "push_downs",
+                json!({
+                    "projection": push_downs.projection,
+                    "filters": Self::exprs_json(&push_downs.filters),
+                    "limit": push_downs.limit,
+                    "order_by": Self::exprs_json(&push_downs.order_by),
+                }),
+            ));
+        }
+
+        self.add_node("ReadDataSource", &plan.schema(), attrs, 0)
+    }
+
+    fn visit_values(&mut self, plan: &ValuesPlan) -> Result<()> {
+        self.add_node(
+            "Values",
+            &plan.schema(),
+            vec![("rows", json!(plan.values.len()))],
+            0,
+        )
+    }
+
+    fn visit_select(&mut self, plan: &SelectPlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())?;
+        self.add_node("Select", &plan.schema(), vec![], 1)
+    }
+
+    fn visit_explain(&mut self, plan: &ExplainPlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())?;
+        self.add_node(
+            "Explain",
+            &plan.schema(),
+            vec![("explain_type", Value::String(format!("{:?}", plan.typ)))],
+            1,
+        )
+    }
+
+    fn visit_append(&mut self, plan: &SinkPlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())?;
+        self.add_node(
+            "Sink",
+            &plan.schema(),
+            vec![("table", Value::String(plan.table_info.name.clone()))],
+            1,
+        )
+    }
+}
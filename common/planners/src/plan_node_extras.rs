@@ -25,6 +25,8 @@ pub struct Extras {
     pub limit: Option<usize>,
     /// Optional order_by expression plan
     pub order_by: Vec<Expression>,
+    /// Optional percentage of the table to sample, from `TABLESAMPLE (n PERCENT)`
+    pub sample: Option<f64>,
 }
 
 impl Extras {
@@ -34,6 +36,7 @@ impl Extras {
             filters: vec![],
             limit: None,
             order_by: vec![],
+            sample: None,
         }
     }
 }
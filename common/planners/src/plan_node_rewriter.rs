@@ -29,6 +29,7 @@ use crate::AlterUserPlan;
 use crate::AlterUserUDFPlan;
 use crate::AlterViewPlan;
 use crate::CallPlan;
+use crate::CommentTablePlan;
 use crate::CopyPlan;
 use crate::CreateDatabasePlan;
 use crate::CreateRolePlan;
@@ -37,8 +38,11 @@ use crate::CreateUserPlan;
 use crate::CreateUserStagePlan;
 use crate::CreateUserUDFPlan;
 use crate::CreateViewPlan;
+use crate::DeallocatePlan;
 use crate::DescribeTablePlan;
 use crate::DescribeUserStagePlan;
+use crate::DistinctPlan;
+use crate::DrainNodePlan;
 use crate::DropDatabasePlan;
 use crate::DropRolePlan;
 use crate::DropTablePlan;
@@ -64,12 +68,14 @@ use crate::ListPlan;
 use crate::OptimizeTablePlan;
 use crate::PlanBuilder;
 use crate::PlanNode;
+use crate::PreparePlan;
 use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
 use crate::RemotePlan;
 use crate::RenameTablePlan;
 use crate::RevokePrivilegePlan;
 use crate::RevokeRolePlan;
+use crate::SelectIntoOutfilePlan;
 use crate::SelectPlan;
 use crate::SettingPlan;
 use crate::ShowCreateDatabasePlan;
@@ -78,8 +84,11 @@ use crate::ShowPlan;
 use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
+use crate::SwapTablePlan;
+use crate::TopNPlan;
 use crate::TruncateTablePlan;
 use crate::UseDatabasePlan;
+use crate::ValuesPlan;
 
 /// `PlanRewriter` is a visitor that can help to rewrite `PlanNode`
 /// By default, a `PlanRewriter` will traverse the plan tree in pre-order and return rewritten plan tree.
@@ -100,6 +109,7 @@ use crate::UseDatabasePlan;
 /// let mut rewriter = FilterRewriter {};
 /// let new_plan = rewriter.rewrite_plan_node(&plan)?; // new_plan is the rewritten plan
 /// ```
+/// For rules that only rebuild the tree without touching the schema, see also [`PlanNode::transform_up`].
 pub trait PlanRewriter: Sized {
     fn rewrite_plan_node(&mut self, plan: &PlanNode) -> Result<PlanNode> {
         match plan {
@@ -115,14 +125,18 @@ pub trait PlanRewriter: Sized {
             PlanNode::Having(plan) => self.rewrite_having(plan),
             PlanNode::Expression(plan) => self.rewrite_expression(plan),
             PlanNode::Sort(plan) => self.rewrite_sort(plan),
+            PlanNode::TopN(plan) => self.rewrite_top_n(plan),
+            PlanNode::Distinct(plan) => self.rewrite_distinct(plan),
             PlanNode::Limit(plan) => self.rewrite_limit(plan),
             PlanNode::LimitBy(plan) => self.rewrite_limit_by(plan),
             PlanNode::ReadSource(plan) => self.rewrite_read_data_source(plan),
+            PlanNode::Values(plan) => self.rewrite_values(plan),
             PlanNode::SubQueryExpression(plan) => self.rewrite_sub_queries_sets(plan),
             PlanNode::Sink(plan) => self.rewrite_sink(plan),
 
             // Query.
             PlanNode::Select(plan) => self.rewrite_select(plan),
+            PlanNode::SelectIntoOutfile(plan) => self.rewrite_select_into_outfile(plan),
 
             // Explain.
             PlanNode::Explain(plan) => self.rewrite_explain(plan),
@@ -148,6 +162,8 @@ pub trait PlanRewriter: Sized {
             PlanNode::CreateTable(plan) => self.rewrite_create_table(plan),
             PlanNode::DropTable(plan) => self.rewrite_drop_table(plan),
             PlanNode::RenameTable(plan) => self.rewrite_rename_table(plan),
+            PlanNode::SwapTable(plan) => self.rewrite_swap_table(plan),
+            PlanNode::CommentTable(plan) => self.rewrite_comment_table(plan),
             PlanNode::TruncateTable(plan) => self.rewrite_truncate_table(plan),
             PlanNode::OptimizeTable(plan) => self.rewrite_optimize_table(plan),
             PlanNode::DescribeTable(plan) => self.rewrite_describe_table(plan),
@@ -194,6 +210,13 @@ pub trait PlanRewriter: Sized {
 
             // Kill.
             PlanNode::Kill(plan) => self.rewrite_kill(plan),
+
+            // Cluster.
+            PlanNode::DrainNode(plan) => self.rewrite_drain_node(plan),
+
+            // Prepared statement.
+            PlanNode::Prepare(plan) => self.rewrite_prepare(plan),
+            PlanNode::Deallocate(plan) => self.rewrite_deallocate(plan),
         }
     }
 
@@ -317,6 +340,22 @@ pub trait PlanRewriter: Sized {
         PlanBuilder::from(&new_input).sort(&new_order_by)?.build()
     }
 
+    fn rewrite_top_n(&mut self, plan: &TopNPlan) -> Result<PlanNode> {
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        let new_order_by = self.rewrite_exprs(&new_input.schema(), &plan.order_by)?;
+        PlanBuilder::from(&new_input)
+            .top_n(&new_order_by, plan.limit)?
+            .build()
+    }
+
+    fn rewrite_distinct(&mut self, plan: &DistinctPlan) -> Result<PlanNode> {
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        let new_distinct_on = self.rewrite_exprs(&new_input.schema(), &plan.distinct_on)?;
+        PlanBuilder::from(&new_input)
+            .distinct(&new_distinct_on)?
+            .build()
+    }
+
     fn rewrite_limit(&mut self, plan: &LimitPlan) -> Result<PlanNode> {
         let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
         PlanBuilder::from(&new_input)
@@ -335,12 +374,25 @@ pub trait PlanRewriter: Sized {
         Ok(PlanNode::ReadSource(plan.clone()))
     }
 
+    fn rewrite_values(&mut self, plan: &ValuesPlan) -> Result<PlanNode> {
+        Ok(PlanNode::Values(plan.clone()))
+    }
+
     fn rewrite_select(&mut self, plan: &SelectPlan) -> Result<PlanNode> {
         Ok(PlanNode::Select(SelectPlan {
             input: Arc::new(self.rewrite_plan_node(plan.input.as_ref())?),
         }))
     }
 
+    fn rewrite_select_into_outfile(&mut self, plan: &SelectIntoOutfilePlan) -> Result<PlanNode> {
+        Ok(PlanNode::SelectIntoOutfile(SelectIntoOutfilePlan {
+            path: plan.path.clone(),
+            format: plan.format,
+            compression: plan.compression,
+            input: Arc::new(self.rewrite_plan_node(plan.input.as_ref())?),
+        }))
+    }
+
     fn rewrite_explain(&mut self, plan: &ExplainPlan) -> Result<PlanNode> {
         Ok(PlanNode::Explain(ExplainPlan {
             typ: plan.typ,
@@ -356,6 +408,14 @@ pub trait PlanRewriter: Sized {
         Ok(PlanNode::RenameTable(plan.clone()))
     }
 
+    fn rewrite_swap_table(&mut self, plan: &SwapTablePlan) -> Result<PlanNode> {
+        Ok(PlanNode::SwapTable(plan.clone()))
+    }
+
+    fn rewrite_comment_table(&mut self, plan: &CommentTablePlan) -> Result<PlanNode> {
+        Ok(PlanNode::CommentTable(plan.clone()))
+    }
+
     fn rewrite_optimize_table(&mut self, plan: &OptimizeTablePlan) -> Result<PlanNode> {
         Ok(PlanNode::OptimizeTable(plan.clone()))
     }
@@ -428,6 +488,18 @@ pub trait PlanRewriter: Sized {
         Ok(PlanNode::Kill(plan.clone()))
     }
 
+    fn rewrite_drain_node(&mut self, plan: &DrainNodePlan) -> Result<PlanNode> {
+        Ok(PlanNode::DrainNode(plan.clone()))
+    }
+
+    fn rewrite_prepare(&mut self, plan: &PreparePlan) -> Result<PlanNode> {
+        Ok(PlanNode::Prepare(plan.clone()))
+    }
+
+    fn rewrite_deallocate(&mut self, plan: &DeallocatePlan) -> Result<PlanNode> {
+        Ok(PlanNode::Deallocate(plan.clone()))
+    }
+
     fn create_user(&mut self, plan: &CreateUserPlan) -> Result<PlanNode> {
         Ok(PlanNode::CreateUser(plan.clone()))
     }
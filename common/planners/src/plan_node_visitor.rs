@@ -22,6 +22,7 @@ use crate::AlterUserPlan;
 use crate::AlterUserUDFPlan;
 use crate::AlterViewPlan;
 use crate::CallPlan;
+use crate::CommentTablePlan;
 use crate::CopyPlan;
 use crate::CreateDatabasePlan;
 use crate::CreateRolePlan;
@@ -30,8 +31,11 @@ use crate::CreateUserPlan;
 use crate::CreateUserStagePlan;
 use crate::CreateUserUDFPlan;
 use crate::CreateViewPlan;
+use crate::DeallocatePlan;
 use crate::DescribeTablePlan;
 use crate::DescribeUserStagePlan;
+use crate::DistinctPlan;
+use crate::DrainNodePlan;
 use crate::DropDatabasePlan;
 use crate::DropRolePlan;
 use crate::DropTablePlan;
@@ -54,12 +58,14 @@ use crate::LimitPlan;
 use crate::ListPlan;
 use crate::OptimizeTablePlan;
 use crate::PlanNode;
+use crate::PreparePlan;
 use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
 use crate::RemotePlan;
 use crate::RenameTablePlan;
 use crate::RevokePrivilegePlan;
 use crate::RevokeRolePlan;
+use crate::SelectIntoOutfilePlan;
 use crate::SelectPlan;
 use crate::SettingPlan;
 use crate::ShowCreateDatabasePlan;
@@ -68,8 +74,11 @@ use crate::ShowPlan;
 use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
+use crate::SwapTablePlan;
+use crate::TopNPlan;
 use crate::TruncateTablePlan;
 use crate::UseDatabasePlan;
+use crate::ValuesPlan;
 
 /// `PlanVisitor` implements visitor pattern(reference [syn](https://docs.rs/syn/1.0.72/syn/visit/trait.Visit.html)) for `PlanNode`.
 ///
@@ -122,6 +131,8 @@ pub trait PlanVisitor {
             PlanNode::Projection(plan) => self.visit_projection(plan),
             PlanNode::Filter(plan) => self.visit_filter(plan),
             PlanNode::Sort(plan) => self.visit_sort(plan),
+            PlanNode::TopN(plan) => self.visit_top_n(plan),
+            PlanNode::Distinct(plan) => self.visit_distinct(plan),
             PlanNode::Stage(plan) => self.visit_stage(plan),
             PlanNode::Broadcast(plan) => self.visit_broadcast(plan),
             PlanNode::Remote(plan) => self.visit_remote(plan),
@@ -130,11 +141,13 @@ pub trait PlanVisitor {
             PlanNode::Limit(plan) => self.visit_limit(plan),
             PlanNode::LimitBy(plan) => self.visit_limit_by(plan),
             PlanNode::ReadSource(plan) => self.visit_read_data_source(plan),
+            PlanNode::Values(plan) => self.visit_values(plan),
             PlanNode::SubQueryExpression(plan) => self.visit_sub_queries_sets(plan),
             PlanNode::Sink(plan) => self.visit_append(plan),
 
             // Query.
             PlanNode::Select(plan) => self.visit_select(plan),
+            PlanNode::SelectIntoOutfile(plan) => self.visit_select_into_outfile(plan),
 
             // Explain.
             PlanNode::Explain(plan) => self.visit_explain(plan),
@@ -160,6 +173,8 @@ pub trait PlanVisitor {
             PlanNode::CreateTable(plan) => self.visit_create_table(plan),
             PlanNode::DropTable(plan) => self.visit_drop_table(plan),
             PlanNode::RenameTable(plan) => self.visit_rename_table(plan),
+            PlanNode::SwapTable(plan) => self.visit_swap_table(plan),
+            PlanNode::CommentTable(plan) => self.visit_comment_table(plan),
             PlanNode::TruncateTable(plan) => self.visit_truncate_table(plan),
             PlanNode::OptimizeTable(plan) => self.visit_optimize_table(plan),
             PlanNode::DescribeTable(plan) => self.visit_describe_table(plan),
@@ -206,6 +221,13 @@ pub trait PlanVisitor {
 
             // Kill.
             PlanNode::Kill(plan) => self.visit_kill_query(plan),
+
+            // Cluster.
+            PlanNode::DrainNode(plan) => self.visit_drain_node(plan),
+
+            // Prepared statement.
+            PlanNode::Prepare(plan) => self.visit_prepare(plan),
+            PlanNode::Deallocate(plan) => self.visit_deallocate(plan),
         }
     }
 
@@ -293,6 +315,16 @@ pub trait PlanVisitor {
         self.visit_exprs(&plan.order_by)
     }
 
+    fn visit_top_n(&mut self, plan: &TopNPlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())?;
+        self.visit_exprs(&plan.order_by)
+    }
+
+    fn visit_distinct(&mut self, plan: &DistinctPlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())?;
+        self.visit_exprs(&plan.distinct_on)
+    }
+
     fn visit_limit(&mut self, plan: &LimitPlan) -> Result<()> {
         self.visit_plan_node(plan.input.as_ref())
     }
@@ -305,10 +337,18 @@ pub trait PlanVisitor {
         Ok(())
     }
 
+    fn visit_values(&mut self, _: &ValuesPlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_select(&mut self, plan: &SelectPlan) -> Result<()> {
         self.visit_plan_node(plan.input.as_ref())
     }
 
+    fn visit_select_into_outfile(&mut self, plan: &SelectIntoOutfilePlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())
+    }
+
     fn visit_explain(&mut self, plan: &ExplainPlan) -> Result<()> {
         self.visit_plan_node(plan.input.as_ref())
     }
@@ -369,6 +409,14 @@ pub trait PlanVisitor {
         Ok(())
     }
 
+    fn visit_swap_table(&mut self, _: &SwapTablePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_comment_table(&mut self, _: &CommentTablePlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_optimize_table(&mut self, _: &OptimizeTablePlan) -> Result<()> {
         Ok(())
     }
@@ -428,6 +476,19 @@ pub trait PlanVisitor {
     fn visit_kill_query(&mut self, _: &KillPlan) -> Result<()> {
         Ok(())
     }
+
+    fn visit_drain_node(&mut self, _: &DrainNodePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_prepare(&mut self, _: &PreparePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_deallocate(&mut self, _: &DeallocatePlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_append(&mut self, _: &SinkPlan) -> Result<()> {
         Ok(())
     }
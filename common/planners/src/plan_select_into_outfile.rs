@@ -0,0 +1,89 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+
+use crate::PlanNode;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum OutfileFormat {
+    Csv,
+    Tsv,
+    Parquet,
+}
+
+impl FromStr for OutfileFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, String> {
+        match s.to_uppercase().as_str() {
+            "CSV" => Ok(OutfileFormat::Csv),
+            "TSV" | "TABSEPARATED" => Ok(OutfileFormat::Tsv),
+            "PARQUET" => Ok(OutfileFormat::Parquet),
+            _ => Err(format!(
+                "Unknown outfile format:{:?}, must one of {{ CSV | TSV | PARQUET }}",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum OutfileCompression {
+    None,
+    Gzip,
+    Zstd,
+}
+
+impl FromStr for OutfileCompression {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, String> {
+        match s.to_uppercase().as_str() {
+            "" | "NONE" => Ok(OutfileCompression::None),
+            "GZIP" | "GZ" => Ok(OutfileCompression::Gzip),
+            "ZSTD" | "ZST" => Ok(OutfileCompression::Zstd),
+            _ => Err(format!(
+                "Unknown outfile compression:{:?}, must one of {{ NONE | GZIP | ZSTD }}",
+                s
+            )),
+        }
+    }
+}
+
+/// `SELECT ... INTO OUTFILE '<path>'`, writes the result of the input select to a file on the server.
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
+pub struct SelectIntoOutfilePlan {
+    pub path: String,
+    pub format: OutfileFormat,
+    pub compression: OutfileCompression,
+    pub input: Arc<PlanNode>,
+}
+
+impl SelectIntoOutfilePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("file", Vu8::to_data_type()),
+            DataField::new("rows", u64::to_data_type()),
+            DataField::new("bytes", u64::to_data_type()),
+        ])
+    }
+
+    pub fn set_input(&mut self, node: &PlanNode) {
+        self.input = Arc::new(node.clone());
+    }
+}
@@ -25,6 +25,8 @@ pub type TableOptions = HashMap<String, String>;
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct CreateTablePlan {
     pub if_not_exists: bool,
+    /// Replace the existing object in one step instead of failing
+    pub or_replace: bool,
     pub tenant: String,
     pub db: String,
     /// The table name
@@ -19,6 +19,7 @@ use common_datavalues::DataSchemaRef;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct TruncateTablePlan {
+    pub if_exists: bool,
     pub db: String,
     /// The table name
     pub table: String,
@@ -22,6 +22,7 @@ use common_meta_types::UserOption;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct CreateUserPlan {
+    pub if_not_exists: bool,
     pub user: UserIdentity,
     pub auth_info: AuthInfo,
     pub user_option: UserOption,
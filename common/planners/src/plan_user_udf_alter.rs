@@ -20,6 +20,7 @@ use common_meta_types::UserDefinedFunction;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct AlterUserUDFPlan {
+    pub if_exists: bool,
     pub udf: UserDefinedFunction,
 }
 
@@ -0,0 +1,32 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::DataSchemaRef;
+
+use crate::Expression;
+
+/// A relation built from literal rows, e.g. `(VALUES (1, 'a'), (2, 'b')) AS t(id, name)`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
+pub struct ValuesPlan {
+    /// The rows, each expression already casted and aliased to the field of the schema
+    pub values: Vec<Vec<Expression>>,
+    /// Output data schema
+    pub schema: DataSchemaRef,
+}
+
+impl ValuesPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+}
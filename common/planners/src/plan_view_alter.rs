@@ -19,6 +19,7 @@ use common_datavalues::DataSchemaRef;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct AlterViewPlan {
+    pub if_exists: bool,
     pub tenant: String,
     pub db: String,
     pub viewname: String,
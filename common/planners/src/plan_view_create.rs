@@ -20,6 +20,7 @@ use common_datavalues::DataSchemaRef;
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct CreateViewPlan {
     pub if_not_exists: bool,
+    pub or_replace: bool,
     pub tenant: String,
     pub db: String,
     pub viewname: String,
@@ -27,4 +27,5 @@ mod plan_partition;
 mod plan_projection;
 mod plan_rewriter;
 mod plan_select;
+mod plan_serde;
 mod test;
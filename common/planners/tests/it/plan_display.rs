@@ -31,6 +31,7 @@ fn test_plan_display_indent() -> Result<()> {
 
     let plan_create = PlanNode::CreateTable(CreateTablePlan {
         if_not_exists: true,
+        or_replace: false,
         tenant: "tenant1".into(),
         db: "foo".into(),
         table: "bar".into(),
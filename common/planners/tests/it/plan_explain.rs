@@ -47,3 +47,111 @@ fn test_explain_plan() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_explain_json_plan() -> Result<()> {
+    let source = Test::create().generate_source_plan_for_test(10000)?;
+    let plan = PlanBuilder::from(&source)
+        .filter(add(col("number"), lit(1)).eq(lit(4)))?
+        .project(&[col("number").alias("c1")])?
+        .limit_offset(Some(10), 2)?
+        .build()?;
+
+    let json = PlanNodeJsonVisitor::to_json(&plan)?;
+    assert_eq!(json["kind"], "Limit");
+    assert_eq!(json["limit"], 10);
+    assert_eq!(json["offset"], 2);
+    assert_eq!(json["schema"][0]["name"], "c1");
+    assert_eq!(json["schema"][0]["type"], "UInt64");
+    assert_eq!(json["schema"][0]["nullable"], false);
+
+    let projection = &json["children"][0];
+    assert_eq!(projection["kind"], "Projection");
+    assert_eq!(projection["expressions"][0], "number as c1");
+
+    let filter = &projection["children"][0];
+    assert_eq!(filter["kind"], "Filter");
+    assert_eq!(filter["predicate"], "((number + 1) = 4)");
+
+    let source = &filter["children"][0];
+    assert_eq!(source["kind"], "ReadDataSource");
+    assert_eq!(source["statistics"]["read_rows"], 10000);
+    assert_eq!(source["children"].as_array().map(|v| v.len()), Some(0));
+
+    // The keys keep the order they are inserted in.
+    let keys = json
+        .as_object()
+        .map(|node| node.keys().take(3).cloned().collect::<Vec<_>>());
+    assert_eq!(
+        keys,
+        Some(vec![
+            "kind".to_string(),
+            "schema".to_string(),
+            "children".to_string()
+        ])
+    );
+    let keys = json["schema"][0]
+        .as_object()
+        .map(|field| field.keys().cloned().collect::<Vec<_>>());
+    assert_eq!(
+        keys,
+        Some(vec![
+            "name".to_string(),
+            "type".to_string(),
+            "nullable".to_string()
+        ])
+    );
+
+    // The output must be stable across runs.
+    assert_eq!(
+        PlanNodeJsonVisitor::to_json_string(&plan)?,
+        PlanNodeJsonVisitor::to_json_string(&plan)?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_explain_graphviz_plan() -> Result<()> {
+    let source = Test::create().generate_source_plan_for_test(10000)?;
+    let plan = PlanBuilder::from(&source)
+        .filter(add(col("number"), lit(1)).eq(lit(4)))?
+        .project(&[col("number").alias("c1")])?
+        .limit_offset(Some(10), 2)?
+        .build()?;
+
+    let dot = PlanNodeGraphvizVisitor::to_dot(&plan, false)?;
+    let lines = dot.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 11);
+    assert_eq!(
+        lines[0],
+        "// Begin Databend GraphViz Plan (see https://graphviz.org)"
+    );
+    assert_eq!(lines[1], "digraph {");
+    assert!(lines[2].starts_with("  node0 [shape=box, label=\"ReadDataSource\\nsource: "));
+    assert_eq!(
+        lines[3],
+        "  node1 [shape=box, label=\"Filter\\npredicate: ((number + 1) = 4)\"];"
+    );
+    assert_eq!(
+        lines[4],
+        "  node2 [shape=box, label=\"Projection\\nexpr: number as c1\"];"
+    );
+    assert_eq!(
+        lines[5],
+        "  node3 [shape=box, label=\"Limit\\nlimit: 10\\noffset: 2\"];"
+    );
+    assert_eq!(lines[6], "  node0 -> node1;");
+    assert_eq!(lines[7], "  node1 -> node2;");
+    assert_eq!(lines[8], "  node2 -> node3;");
+    assert_eq!(lines[9], "}");
+    assert_eq!(lines[10], "// End Databend GraphViz Plan");
+    assert_eq!(dot, format!("{}", plan.display_graphviz(false)));
+
+    let dot = PlanNodeGraphvizVisitor::to_dot(&plan, true)?;
+    assert!(dot.contains(
+        "  node3 [shape=box, label=\"Limit\\nlimit: 10\\noffset: 2\\nschema: [c1:UInt64]\"];"
+    ));
+
+    Ok(())
+}
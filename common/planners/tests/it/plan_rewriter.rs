@@ -226,3 +226,47 @@ fn test_plan_rewriter_1() -> Result<()> {
     assert_eq!(before_rewrite, after_rewrite);
     Ok(())
 }
+
+#[test]
+fn test_plan_transform_up() -> Result<()> {
+    use pretty_assertions::assert_eq;
+
+    let source = Test::create().generate_source_plan_for_test(10000)?;
+    let plan = PlanBuilder::from(&source)
+        .filter(col("number").eq(lit(1i64)))?
+        .limit(10)?
+        .project(&[col("number").alias("x")])?
+        .limit(5)?
+        .build()?;
+
+    // Identity rule keeps the plan as is, nodes are visited bottom-up.
+    let mut visited = vec![];
+    let same = plan.transform_up(&mut |node| {
+        visited.push(node.name().to_string());
+        Ok(node)
+    })?;
+    assert_eq!(format!("{:?}", plan), format!("{:?}", same));
+    assert_eq!(visited, vec![
+        "ReadSourcePlan",
+        "FilterPlan",
+        "LimitPlan",
+        "ProjectionPlan",
+        "LimitPlan"
+    ]);
+
+    // Remove all the limits.
+    let without_limit = plan.transform_up(&mut |node| match node {
+        PlanNode::Limit(limit) => Ok(limit.input.as_ref().clone()),
+        other => Ok(other),
+    })?;
+    let expect = "\
+    Projection: number as x:UInt64\
+    \n  Filter: (number = 1)\
+    \n    ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10000, read_bytes: 80000, partitions_scanned: 8, partitions_total: 8]";
+    assert_eq!(expect, format!("{:?}", without_limit));
+
+    // The number of inputs must match.
+    assert!(plan.with_new_inputs(&[]).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::*;
+use pretty_assertions::assert_eq;
+
+use crate::test::Test;
+
+#[test]
+fn test_plan_serde_round_trip() -> Result<()> {
+    let source = Test::create().generate_source_plan_for_test(10000)?;
+    let plan = PlanBuilder::from(&source)
+        .filter(col("number").eq(lit(1u64)))?
+        .aggregate_partial(&[sum(col("number")).alias("sumx")], &[modular(
+            col("number"),
+            lit(3u64),
+        )])?
+        .aggregate_final(source.schema(), &[sum(col("number")).alias("sumx")], &[
+            modular(col("number"), lit(3u64)),
+        ])?
+        .having(col("sumx").gt(lit(10u64)))?
+        .sort(&[sort("sumx", false, true)])?
+        .limit_offset(Some(5), 2)?
+        .project(&[col("sumx")])?
+        .build()?;
+
+    // The plans are shipped to the other nodes as JSON in the flight actions.
+    let bytes = serde_json::to_vec(&plan)?;
+    let actual = serde_json::from_slice::<PlanNode>(&bytes)?;
+
+    assert_eq!(plan, actual);
+    assert_eq!(plan.schema(), actual.schema());
+    assert_eq!(format!("{:?}", plan), format!("{:?}", actual));
+    Ok(())
+}
+
+#[test]
+fn test_expression_serde_round_trip() -> Result<()> {
+    let subquery = Arc::new(Test::create().generate_source_plan_for_test(10)?);
+    let exprs = vec![
+        col("a").alias("b"),
+        Expression::QualifiedColumn(vec!["t".to_string(), "a".to_string()]),
+        lit(1i8),
+        lit("databend".as_bytes()),
+        lit_null(),
+        Expression::create_literal_with_type(DataValue::Float64(1.5), f64::to_data_type()),
+        not(col("a")),
+        add(col("a"), lit(1u32)),
+        Expression::AggregateFunction {
+            op: "count".to_string(),
+            distinct: true,
+            params: vec![DataValue::UInt64(1)],
+            args: vec![col("a")],
+        },
+        sort("a", true, false),
+        Expression::Wildcard,
+        Expression::Cast {
+            expr: Box::new(col("a")),
+            data_type: Arc::new(NullableType::create(Date32Type::arc())),
+            is_nullable: true,
+        },
+        Expression::ScalarSubquery {
+            name: "_subquery_1".to_string(),
+            query_plan: subquery.clone(),
+        },
+        Expression::Subquery {
+            name: "_subquery_2".to_string(),
+            query_plan: subquery,
+        },
+    ];
+
+    for expr in exprs {
+        let actual = serde_json::from_slice::<Expression>(&serde_json::to_vec(&expr)?)?;
+        assert_eq!(expr, actual);
+        assert_eq!(expr.column_name(), actual.column_name());
+    }
+
+    Ok(())
+}
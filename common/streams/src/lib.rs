@@ -18,6 +18,7 @@ mod stream_abort;
 mod stream_cast;
 mod stream_correct_with_schema;
 mod stream_datablock;
+mod stream_distinct;
 mod stream_limit_by;
 mod stream_progress;
 mod stream_skip;
@@ -32,6 +33,7 @@ pub use stream_abort::AbortStream;
 pub use stream_cast::CastStream;
 pub use stream_correct_with_schema::CorrectWithSchemaStream;
 pub use stream_datablock::DataBlockStream;
+pub use stream_distinct::DistinctStream;
 pub use stream_limit_by::LimitByStream;
 pub use stream_progress::ProgressStream;
 pub use stream_skip::SkipStream;
@@ -13,11 +13,15 @@
 // limitations under the License.
 
 mod source;
+mod source_compression;
 mod source_csv;
 mod source_ndjson;
 mod source_parquet;
 
 pub use source::Source;
+pub use source_compression::decompress_reader;
+pub use source_compression::Compression;
+pub use source_compression::DecompressReader;
 pub use source_csv::CsvSourceBuilder;
 pub use source_ndjson::NDJsonSourceBuilder;
 pub use source_parquet::ParquetSourceBuilder;
@@ -0,0 +1,122 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+
+use async_compression::futures::bufread::BzDecoder;
+use async_compression::futures::bufread::GzipDecoder;
+use async_compression::futures::bufread::XzDecoder;
+use async_compression::futures::bufread::ZstdDecoder;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use futures::io::BufReader;
+use futures::AsyncBufRead;
+use futures::AsyncBufReadExt;
+use futures::AsyncRead;
+
+/// Compression codec of a file being loaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    /// Detect the codec from the file extension, then from the leading magic bytes.
+    Auto,
+    None,
+    Gzip,
+    Zstd,
+    Bz2,
+    Xz,
+}
+
+impl Compression {
+    /// Guess the codec from the file extension, e.g. `data.csv.gz`.
+    pub fn from_path(path: &str) -> Option<Compression> {
+        let ext = path.rsplit_once('.').map(|(_, ext)| ext.to_lowercase())?;
+        match ext.as_str() {
+            "gz" | "gzip" => Some(Compression::Gzip),
+            "zst" | "zstd" => Some(Compression::Zstd),
+            "bz2" => Some(Compression::Bz2),
+            "xz" => Some(Compression::Xz),
+            _ => None,
+        }
+    }
+
+    /// Guess the codec from the first bytes of the file.
+    pub fn from_magic(head: &[u8]) -> Option<Compression> {
+        if head.starts_with(&[0x1f, 0x8b]) {
+            Some(Compression::Gzip)
+        } else if head.starts_with(&[0x28, 0xb5, 0x2f, 0xfd]) {
+            Some(Compression::Zstd)
+        } else if head.starts_with(b"BZh") {
+            Some(Compression::Bz2)
+        } else if head.starts_with(&[0xfd, b'7', b'z', b'X', b'Z', 0x00]) {
+            Some(Compression::Xz)
+        } else {
+            None
+        }
+    }
+}
+
+impl FromStr for Compression {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, String> {
+        match s.to_uppercase().as_str() {
+            "AUTO" => Ok(Compression::Auto),
+            "NONE" => Ok(Compression::None),
+            "GZIP" => Ok(Compression::Gzip),
+            "ZSTD" => Ok(Compression::Zstd),
+            "BZ2" => Ok(Compression::Bz2),
+            "XZ" => Ok(Compression::Xz),
+            _ => Err(format!(
+                "Unknown compression:{:?}, must one of {{ AUTO | NONE | GZIP | ZSTD | BZ2 | XZ }}",
+                s
+            )),
+        }
+    }
+}
+
+pub type DecompressReader = Box<dyn AsyncBufRead + Unpin + Send>;
+
+/// Wrap `reader` with the decoder of `compression`.
+/// `path` is only used to detect the codec when `compression` is `Auto`.
+pub async fn decompress_reader<R>(
+    reader: R,
+    compression: Compression,
+    path: &str,
+) -> Result<DecompressReader>
+where
+    R: AsyncRead + Unpin + Send + 'static,
+{
+    let mut reader = BufReader::new(reader);
+
+    let compression = match compression {
+        Compression::Auto => match Compression::from_path(path) {
+            Some(compression) => compression,
+            None => {
+                let head = reader.fill_buf().await.map_err(|e| {
+                    ErrorCode::BadBytes(format!("Cannot read the head of {}: {}", path, e))
+                })?;
+                Compression::from_magic(head).unwrap_or(Compression::None)
+            }
+        },
+        compression => compression,
+    };
+
+    Ok(match compression {
+        Compression::Auto | Compression::None => Box::new(reader),
+        Compression::Gzip => Box::new(BufReader::new(GzipDecoder::new(reader))),
+        Compression::Zstd => Box::new(BufReader::new(ZstdDecoder::new(reader))),
+        Compression::Bz2 => Box::new(BufReader::new(BzDecoder::new(reader))),
+        Compression::Xz => Box::new(BufReader::new(XzDecoder::new(reader))),
+    })
+}
@@ -15,8 +15,14 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::ArrayRef;
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::compute::cast;
+use common_arrow::arrow::compute::cast::CastOptions;
 use common_arrow::arrow::datatypes::Field;
 use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::parquet::read::infer_schema;
 use common_arrow::arrow::io::parquet::read::read_metadata_async;
 use common_arrow::arrow::io::parquet::read::schema::FileMetaData;
 use common_arrow::arrow::io::parquet::read::RowGroupDeserializer;
@@ -72,7 +78,12 @@ pub struct ParquetSource<R> {
     reader: R,
     builder: ParquetSourceBuilder,
     current_row_group: usize,
+    table_schema: DataSchemaRef,
     arrow_table_schema: ArrowSchema,
+    /// The fields of the file matching the projected table fields, resolved with the metadata.
+    file_fields: Option<Vec<Field>>,
+    /// The file schema is the table schema, the arrays are taken as they are without casting.
+    schema_matched: bool,
     rows: usize,
 }
 
@@ -80,17 +91,86 @@ impl<R> ParquetSource<R>
 where R: AsyncRead + AsyncSeek + Unpin + Send
 {
     fn create(builder: ParquetSourceBuilder, reader: R) -> Self {
-        let arrow_table_schema =
-            Arc::new(builder.schema.project(builder.projection.clone())).to_arrow();
+        let table_schema = Arc::new(builder.schema.project(builder.projection.clone()));
+        let arrow_table_schema = table_schema.to_arrow();
 
         ParquetSource {
             reader,
             builder,
+            table_schema,
             arrow_table_schema,
+            file_fields: None,
+            schema_matched: false,
             current_row_group: 0,
             rows: 0,
         }
     }
+
+    /// Casts the arrays read with the file fields into the table fields, the arrays are kept
+    /// as they are if the schemas match, only the null constraints are checked then.
+    fn to_table_chunk(&self, chunk: Chunk<ArrayRef>) -> Result<Chunk<ArrayRef>> {
+        let table_fields = &self.arrow_table_schema.fields;
+        let chunk = match self.schema_matched {
+            true => chunk,
+            false => {
+                let cast_options = CastOptions {
+                    wrapped: false,
+                    partial: false,
+                };
+                let arrays = chunk
+                    .into_arrays()
+                    .into_iter()
+                    .zip(table_fields.iter())
+                    .map(|(array, field)| {
+                        if array.data_type() == field.data_type() {
+                            return Ok(array);
+                        }
+                        let casted = cast::cast(array.as_ref(), field.data_type(), cast_options)
+                            .map_err(|e| {
+                                ErrorCode::BadDataValueType(format!(
+                                    "Can't cast column {} of the parquet file from {:?} to {:?}: {}",
+                                    field.name,
+                                    array.data_type(),
+                                    field.data_type(),
+                                    e
+                                ))
+                            })?;
+                        Ok(ArrayRef::from(casted))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Chunk::try_new(arrays).map_err(|e| ErrorCode::ParquetError(e.to_string()))?
+            }
+        };
+
+        for (array, field) in chunk.arrays().iter().zip(table_fields.iter()) {
+            if !field.is_nullable && array.null_count() > 0 {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "Column {} is not nullable, but the parquet file has {} null values in it",
+                    field.name,
+                    array.null_count()
+                )));
+            }
+        }
+        Ok(chunk)
+    }
+}
+
+/// Finds the fields of the file to read for the table fields by name, the table field is used
+/// for a column missing in the file, which is then reported by the reader.
+fn resolve_file_fields(table_fields: &[Field], metadata: &FileMetaData) -> Result<Vec<Field>> {
+    let file_schema = infer_schema(metadata).map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
+    let file_fields = table_fields
+        .iter()
+        .map(|table_field| {
+            file_schema
+                .fields
+                .iter()
+                .find(|f| f.name == table_field.name)
+                .unwrap_or(table_field)
+                .clone()
+        })
+        .collect();
+    Ok(file_fields)
 }
 
 #[async_trait]
@@ -119,16 +199,21 @@ where R: AsyncRead + AsyncSeek + Unpin + Send
             return Ok(None);
         }
 
-        let fields = &self.arrow_table_schema.fields;
+        if self.file_fields.is_none() {
+            let table_fields = &self.arrow_table_schema.fields;
+            let file_fields = resolve_file_fields(table_fields, metadata)?;
+            self.schema_matched = file_fields
+                .iter()
+                .zip(table_fields.iter())
+                .all(|(file_field, table_field)| file_field.data_type() == table_field.data_type());
+            self.file_fields = Some(file_fields);
+        }
 
         let row_group = &metadata.row_groups[self.current_row_group];
-        let fields_to_read: Vec<&Field> = self
-            .builder
-            .projection
-            .clone()
-            .into_iter()
-            .map(|idx| &fields[idx])
-            .collect();
+        let fields_to_read: Vec<&Field> = match &self.file_fields {
+            Some(fields) => fields.iter().collect(),
+            None => unreachable!(),
+        };
 
         let column_chunks =
             read_columns_many_async(&mut self.reader, row_group, fields_to_read, None)
@@ -144,7 +229,8 @@ where R: AsyncRead + AsyncSeek + Unpin + Send
             Some(chunk) => chunk.map_err(|e| ErrorCode::ParquetError(e.to_string()))?,
         };
 
-        let mut block = DataBlock::from_chunk(&self.builder.schema, &chunk)?;
+        let chunk = self.to_table_chunk(chunk)?;
+        let mut block = DataBlock::from_chunk(&self.table_schema, &chunk)?;
         self.current_row_group += 1;
         self.rows += block.num_rows();
 
@@ -0,0 +1,88 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::task::Context;
+use std::task::Poll;
+
+use common_datablocks::DataBlock;
+use common_datablocks::HashMethod;
+use common_datablocks::HashMethodSerializer;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use futures::Stream;
+use futures::StreamExt;
+
+use crate::SendableDataBlockStream;
+
+/// Keeps the first row of each distinct key over the whole input stream.
+pub struct DistinctStream {
+    input: SendableDataBlockStream,
+    distinct_columns_name: Vec<String>,
+    keys: HashSet<SmallVu8>,
+}
+
+impl DistinctStream {
+    pub fn try_create(
+        input: SendableDataBlockStream,
+        distinct_columns_name: Vec<String>,
+    ) -> Result<Self> {
+        Ok(DistinctStream {
+            input,
+            distinct_columns_name,
+            keys: HashSet::new(),
+        })
+    }
+
+    pub fn distinct(&mut self, block: &DataBlock) -> Result<Option<DataBlock>> {
+        let mut columns = Vec::with_capacity(self.distinct_columns_name.len());
+        for column_name in &self.distinct_columns_name {
+            columns.push(block.try_column_by_name(column_name)?);
+        }
+
+        // Serialize the keys of the whole block at once, a row is kept when its key is new.
+        let method = HashMethodSerializer::default();
+        let keys = method.build_keys(&columns, block.num_rows())?;
+        let filter = keys
+            .into_iter()
+            .map(|key| self.keys.insert(key))
+            .collect::<Vec<_>>();
+
+        let block = DataBlock::filter_block(block, &Series::from_data(filter))?;
+        match block.num_rows() {
+            0 => Ok(None),
+            _ => Ok(Some(block)),
+        }
+    }
+}
+
+impl Stream for DistinctStream {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        ctx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        loop {
+            match futures::ready!(self.input.poll_next_unpin(ctx)) {
+                Some(Ok(ref v)) => match self.distinct(v).transpose() {
+                    Some(res) => return Poll::Ready(Some(res)),
+                    // All the rows of the block were seen before, skip it.
+                    None => continue,
+                },
+                other => return Poll::Ready(other),
+            }
+        }
+    }
+}
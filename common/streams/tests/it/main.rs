@@ -15,6 +15,7 @@
 mod sources;
 mod stream_cast;
 mod stream_datablock;
+mod stream_distinct;
 mod stream_limit_by;
 mod stream_progress;
 mod stream_skip;
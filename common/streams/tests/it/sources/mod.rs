@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod source_compression;
 mod source_csv;
 mod source_ndjson;
 mod source_parquet;
@@ -0,0 +1,103 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use common_base::tokio;
+use common_datablocks::assert_blocks_eq;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_streams::decompress_reader;
+use common_streams::Compression;
+use common_streams::CsvSourceBuilder;
+use common_streams::Source;
+use flate2::write::GzEncoder;
+use futures::io::Cursor;
+
+const CSV: &[u8] = b"1,\"1\",1.11\n2,\"2\",2\n3,\"3-'3'-3\",3\n";
+
+fn gzip(bytes: &[u8]) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(vec![], flate2::Compression::default());
+    encoder.write_all(bytes).unwrap();
+    encoder.finish().unwrap()
+}
+
+#[test]
+fn test_compression_detect() {
+    assert_eq!(
+        Compression::from_path("a/b.csv.gz"),
+        Some(Compression::Gzip)
+    );
+    assert_eq!(Compression::from_path("b.csv.ZST"), Some(Compression::Zstd));
+    assert_eq!(Compression::from_path("b.csv.bz2"), Some(Compression::Bz2));
+    assert_eq!(Compression::from_path("b.ndjson.xz"), Some(Compression::Xz));
+    assert_eq!(Compression::from_path("b.csv"), None);
+    assert_eq!(Compression::from_path("b"), None);
+
+    assert_eq!(Compression::from_magic(&gzip(CSV)), Some(Compression::Gzip));
+    assert_eq!(
+        Compression::from_magic(&zstd::encode_all(CSV, 0).unwrap()),
+        Some(Compression::Zstd)
+    );
+    assert_eq!(
+        Compression::from_magic(b"BZh91AY&SY"),
+        Some(Compression::Bz2)
+    );
+    assert_eq!(Compression::from_magic(CSV), None);
+    assert_eq!(Compression::from_magic(&[]), None);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_source_csv_compressed() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i8::to_data_type()),
+        DataField::new("b", Vu8::to_data_type()),
+        DataField::new("c", f64::to_data_type()),
+    ]);
+
+    let tests = vec![
+        (CSV.to_vec(), Compression::None, "a.csv"),
+        (CSV.to_vec(), Compression::Auto, "a.csv"),
+        (gzip(CSV), Compression::Gzip, "a.csv"),
+        // Detected by extension.
+        (gzip(CSV), Compression::Auto, "a.csv.gz"),
+        // Detected by magic bytes.
+        (gzip(CSV), Compression::Auto, "a.csv"),
+        (zstd::encode_all(CSV, 0).unwrap(), Compression::Auto, "a"),
+    ];
+
+    for (bytes, compression, path) in tests {
+        let reader = decompress_reader(Cursor::new(bytes), compression, path).await?;
+
+        let mut builder = CsvSourceBuilder::create(schema.clone());
+        builder.block_size(10);
+        let mut csv_source = builder.build(reader)?;
+        let block = csv_source.read().await?.unwrap();
+        assert_blocks_eq(
+            vec![
+                "+---+---------+------+",
+                "| a | b       | c    |",
+                "+---+---------+------+",
+                "| 1 | 1       | 1.11 |",
+                "| 2 | 2       | 2    |",
+                "| 3 | 3-'3'-3 | 3    |",
+                "+---+---------+------+",
+            ],
+            &[block],
+        );
+        assert!(csv_source.read().await?.is_none());
+    }
+
+    Ok(())
+}
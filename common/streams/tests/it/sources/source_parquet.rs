@@ -14,9 +14,12 @@
 
 use std::fs::File;
 
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::parquet::encoding::Encoding;
 use common_base::tokio;
 use common_datablocks::assert_blocks_eq;
 use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_streams::ParquetSourceBuilder;
@@ -27,8 +30,6 @@ use opendal::Operator;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_source_parquet() -> Result<()> {
-    use common_datavalues::prelude::*;
-
     let schema = DataSchemaRefExt::create(vec![
         DataField::new("a", i8::to_data_type()),
         DataField::new("b", Vu8::to_data_type()),
@@ -47,9 +48,7 @@ async fn test_source_parquet() -> Result<()> {
     let col_b = Series::from_data(vec!["1", "1", "2", "1", "2", "3"]);
     let sample_block = DataBlock::create(schema.clone(), vec![col_a, col_b]);
 
-    use common_arrow::arrow::chunk::Chunk;
     let batch = Chunk::try_from(sample_block)?;
-    use common_arrow::parquet::encoding::Encoding;
     let encodings = std::iter::repeat(Encoding::Plain)
         .take(arrow_schema.fields.len())
         .collect::<Vec<_>>();
@@ -113,3 +112,100 @@ async fn test_source_parquet() -> Result<()> {
     assert_eq!(page_nums_expects, page_nums);
     Ok(())
 }
+
+async fn write_and_read_parquet(
+    file_block: DataBlock,
+    table_schema: DataSchemaRef,
+) -> Result<Vec<DataBlock>> {
+    use common_arrow::arrow::io::parquet::write::*;
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Lz4,
+        version: Version::V2,
+    };
+
+    let arrow_schema = file_block.schema().to_arrow();
+    let encodings = std::iter::repeat(Encoding::Plain)
+        .take(arrow_schema.fields.len())
+        .collect::<Vec<_>>();
+    let batch = Chunk::try_from(file_block)?;
+
+    let name = "test-parquet";
+    let dir = tempfile::tempdir().unwrap();
+    let (len, _file_meta) = {
+        let rg_iter = std::iter::once(Ok(batch));
+        let row_groups = RowGroupIterator::try_new(rg_iter, &arrow_schema, options, encodings)?;
+        let mut writer = File::create(dir.path().join(name)).unwrap();
+        common_arrow::write_parquet_file(&mut writer, row_groups, arrow_schema, options)
+            .map_err(|e| ErrorCode::ParquetError(e.to_string()))?
+    };
+
+    let local = Operator::new(
+        fs::Backend::build()
+            .root(dir.path().to_str().unwrap())
+            .finish()
+            .await
+            .unwrap(),
+    );
+    let stream = local.object(name).limited_reader(len);
+    let stream = BufReader::with_capacity(4 * 1024 * 1024, stream);
+
+    let mut parquet_source = ParquetSourceBuilder::create(table_schema).build(stream)?;
+    let mut blocks = vec![];
+    while let Some(block) = parquet_source.read().await? {
+        blocks.push(block);
+    }
+    Ok(blocks)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_source_parquet_cast() -> Result<()> {
+    let file_schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i32::to_data_type()),
+        DataField::new("b", Vu8::to_data_type()),
+    ]);
+    let file_block = DataBlock::create(file_schema, vec![
+        Series::from_data(vec![1i32, 2, 3]),
+        Series::from_data(vec!["x", "y", "z"]),
+    ]);
+
+    // a is casted from Int32 into Int64, b is read as it is
+    let table_schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i64::to_data_type()),
+        DataField::new("b", Vu8::to_data_type()),
+    ]);
+    let blocks = write_and_read_parquet(file_block, table_schema.clone()).await?;
+    assert_eq!(blocks[0].schema(), &table_schema);
+    assert_blocks_eq(
+        vec![
+            "+---+---+",
+            "| a | b |",
+            "+---+---+",
+            "| 1 | x |",
+            "| 2 | y |",
+            "| 3 | z |",
+            "+---+---+",
+        ],
+        &blocks,
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_source_parquet_null_constraint() -> Result<()> {
+    let file_schema =
+        DataSchemaRefExt::create(vec![DataField::new_nullable("a", i32::to_data_type())]);
+    let file_block = DataBlock::create(file_schema, vec![Series::from_data(vec![
+        Some(1i32),
+        None,
+        Some(3),
+    ])]);
+
+    let table_schema = DataSchemaRefExt::create(vec![DataField::new("a", i32::to_data_type())]);
+    let result = write_and_read_parquet(file_block, table_schema).await;
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Column a is not nullable, but the parquet file has 1 null values in it"
+    );
+    Ok(())
+}
@@ -0,0 +1,93 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_datablocks::*;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_streams::*;
+use futures::stream::StreamExt;
+
+#[tokio::test]
+async fn test_distinct_stream() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("id", u8::to_data_type()),
+        DataField::new("name", Vu8::to_data_type()),
+    ]);
+
+    let ids = vec![2u8, 2, 2, 3, 3, 3];
+    let names = vec!["2-1", "2-1", "2-2", "3-1", "3-1", "3-2"];
+    let block0 = DataBlock::create(schema.clone(), vec![
+        Series::from_data(ids),
+        Series::from_data(names),
+    ]);
+
+    let ids = vec![2u8, 3];
+    let names = vec!["2-2", "3-1"];
+    let block1 = DataBlock::create(schema.clone(), vec![
+        Series::from_data(ids),
+        Series::from_data(names),
+    ]);
+
+    let ids = vec![2u8, 4, 4];
+    let names = vec!["2-3", "4-1", "4-1"];
+    let block2 = DataBlock::create(schema.clone(), vec![
+        Series::from_data(ids),
+        Series::from_data(names),
+    ]);
+
+    let input = DataBlockStream::create(schema.clone(), None, vec![
+        block0.clone(),
+        block1.clone(),
+        block2.clone(),
+    ]);
+    let stream =
+        DistinctStream::try_create(Box::pin(input), vec!["id".to_string(), "name".to_string()])?;
+
+    // block1 is all duplicates and is skipped.
+    let result = stream.map(|res| res.unwrap()).collect::<Vec<_>>().await;
+    assert_eq!(result.len(), 2);
+
+    let expected = vec![
+        "+----+------+",
+        "| id | name |",
+        "+----+------+",
+        "| 2  | 2-1  |",
+        "| 2  | 2-2  |",
+        "| 2  | 2-3  |",
+        "| 3  | 3-1  |",
+        "| 3  | 3-2  |",
+        "| 4  | 4-1  |",
+        "+----+------+",
+    ];
+    assert_blocks_sorted_eq(expected, &result);
+
+    // Distinct on a subset of the columns keeps the first row of each key.
+    let input = DataBlockStream::create(schema.clone(), None, vec![block0, block1, block2]);
+    let stream = DistinctStream::try_create(Box::pin(input), vec!["id".to_string()])?;
+    let result = stream.map(|res| res.unwrap()).collect::<Vec<_>>().await;
+
+    let expected = vec![
+        "+----+------+",
+        "| id | name |",
+        "+----+------+",
+        "| 2  | 2-1  |",
+        "| 3  | 3-1  |",
+        "| 4  | 4-1  |",
+        "+----+------+",
+    ];
+    assert_blocks_sorted_eq(expected, &result);
+
+    Ok(())
+}
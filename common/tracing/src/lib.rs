@@ -14,6 +14,7 @@
 
 mod logging;
 mod panic_hook;
+mod text_log;
 mod tracing_to_jaeger;
 
 pub use logging::init_default_ut_tracing;
@@ -21,6 +22,11 @@ pub use logging::init_global_tracing;
 pub use logging::init_meta_ut_tracing;
 pub use logging::init_query_logger;
 pub use panic_hook::set_panic_hook;
+pub use text_log::TextLogLayer;
+pub use text_log::TextLogRecord;
+pub use text_log::TextLogStore;
+pub use text_log::DEFAULT_TEXT_LOG_MAX_ROWS;
+pub use text_log::DEFAULT_TEXT_LOG_RETENTION_SECS;
 pub use tracing;
 pub use tracing_appender;
 pub use tracing_futures;
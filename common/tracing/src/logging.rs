@@ -42,6 +42,8 @@ use tracing_subscriber::registry::LookupSpan;
 use tracing_subscriber::EnvFilter;
 use tracing_subscriber::Registry;
 
+use crate::TextLogLayer;
+
 /// Init tracing for unittest.
 /// Write logs to file `unittest`.
 pub fn init_default_ut_tracing() {
@@ -102,6 +104,7 @@ pub fn init_global_tracing(app_name: &str, dir: &str, level: &str) -> Vec<Worker
         .with(JsonStorageLayer)
         .with(stdout_logging_layer)
         .with(file_logging_layer)
+        .with(TextLogLayer)
         .with(jaeger_layer);
 
     #[cfg(feature = "console")]
@@ -0,0 +1,322 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::fs;
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_infallible::Mutex;
+use once_cell::sync::Lazy;
+use tracing::field::Field;
+use tracing::field::Visit;
+use tracing::Event;
+use tracing::Subscriber;
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::Layer;
+
+pub const DEFAULT_TEXT_LOG_MAX_ROWS: u64 = 100000;
+pub const DEFAULT_TEXT_LOG_RETENTION_SECS: u64 = 86400;
+
+// The persisted records are split into a file per hour, named by the hours since the unix epoch.
+const MILLIS_PER_HOUR: i64 = 3_600_000;
+const TEXT_LOG_FILE_EXTENSION: &str = "log";
+
+/// One tracing event kept in the text log.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct TextLogRecord {
+    /// Milliseconds since the unix epoch.
+    pub event_time: i64,
+    pub level: String,
+    pub target: String,
+    pub thread_name: String,
+    pub message: String,
+}
+
+struct TextLogBuffer {
+    max_rows: u64,
+    retention: Duration,
+    // The records not persisted, e.g. the ones logged before the directory is set.
+    records: VecDeque<TextLogRecord>,
+    dir: Option<PathBuf>,
+    // The file of the current hour, and the hour.
+    file: Option<(i64, File)>,
+}
+
+impl TextLogBuffer {
+    fn expire_before(&self, now: i64) -> i64 {
+        now - self.retention.as_millis() as i64
+    }
+
+    fn rotate(&mut self, now: i64) {
+        while self.records.len() as u64 > self.max_rows {
+            self.records.pop_front();
+        }
+
+        let expire_before = self.expire_before(now);
+        while let Some(record) = self.records.front() {
+            if record.event_time >= expire_before {
+                break;
+            }
+            self.records.pop_front();
+        }
+    }
+
+    // Remove the persisted files of the hours before the retention.
+    fn rotate_files(&self, now: i64) {
+        let expire_before = self.expire_before(now);
+        if let Some(dir) = &self.dir {
+            for (hour, path) in text_log_files(dir) {
+                if (hour + 1) * MILLIS_PER_HOUR <= expire_before {
+                    let _ = fs::remove_file(path);
+                }
+            }
+        }
+    }
+
+    fn append(&mut self, record: TextLogRecord) {
+        if self.dir.is_none() || self.persist(&record).is_err() {
+            // A failure can't be logged from the tracing layer itself, the record is kept
+            // in memory instead.
+            self.records.push_back(record);
+        }
+    }
+
+    fn persist(&mut self, record: &TextLogRecord) -> std::io::Result<()> {
+        let hour = record.event_time.div_euclid(MILLIS_PER_HOUR);
+        if !matches!(&self.file, Some((current, _)) if *current == hour) {
+            self.file = None;
+            if let Some(dir) = &self.dir {
+                let file = OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(text_log_file(dir, hour))?;
+                self.file = Some((hour, file));
+            }
+            self.rotate_files(now_millis());
+        }
+
+        let mut line = serde_json::to_vec(record)?;
+        line.push(b'\n');
+        match &mut self.file {
+            Some((_, file)) => file.write_all(&line),
+            None => Ok(()),
+        }
+    }
+
+    fn records(&self, now: i64) -> Vec<TextLogRecord> {
+        let expire_before = self.expire_before(now);
+        let mut records = match &self.dir {
+            None => vec![],
+            Some(dir) => text_log_files(dir)
+                .into_iter()
+                .filter_map(|(_, path)| fs::read_to_string(path).ok())
+                .flat_map(|content| {
+                    // A line cut by a crash is skipped.
+                    content
+                        .lines()
+                        .filter_map(|line| serde_json::from_str::<TextLogRecord>(line).ok())
+                        .collect::<Vec<_>>()
+                })
+                .filter(|record| record.event_time >= expire_before)
+                .collect(),
+        };
+        records.extend(self.records.iter().cloned());
+
+        let skip = records.len().saturating_sub(self.max_rows as usize);
+        records.split_off(skip)
+    }
+}
+
+fn text_log_file(dir: &Path, hour: i64) -> PathBuf {
+    dir.join(format!("{}.{}", hour, TEXT_LOG_FILE_EXTENSION))
+}
+
+/// The persisted files in the directory with their hours, from the oldest to the newest.
+fn text_log_files(dir: &Path) -> Vec<(i64, PathBuf)> {
+    let mut files = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| {
+                    path.extension().and_then(|ext| ext.to_str()) == Some(TEXT_LOG_FILE_EXTENSION)
+                })
+                .filter_map(|path| {
+                    let hour = path.file_stem()?.to_str()?.parse::<i64>().ok()?;
+                    Some((hour, path))
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    files.sort();
+    files
+}
+
+/// A bounded log of the tracing events of the process, persisted in a directory once
+/// `persist_to` is called and kept in memory until then.
+/// The records older than the retention are rotated out, the persisted ones a file at a time,
+/// and only the newest `max_rows` records are kept.
+pub struct TextLogStore {
+    buffer: Mutex<TextLogBuffer>,
+}
+
+static TEXT_LOG_STORE: Lazy<TextLogStore> = Lazy::new(|| {
+    TextLogStore::create(
+        DEFAULT_TEXT_LOG_MAX_ROWS,
+        Duration::from_secs(DEFAULT_TEXT_LOG_RETENTION_SECS),
+    )
+});
+
+impl TextLogStore {
+    pub fn create(max_rows: u64, retention: Duration) -> TextLogStore {
+        TextLogStore {
+            buffer: Mutex::new(TextLogBuffer {
+                max_rows,
+                retention,
+                records: VecDeque::new(),
+                dir: None,
+                file: None,
+            }),
+        }
+    }
+
+    /// The store filled by the `TextLogLayer` of the global tracing subscriber.
+    pub fn instance() -> &'static TextLogStore {
+        &TEXT_LOG_STORE
+    }
+
+    pub fn set_retention(&self, max_rows: u64, retention: Duration) {
+        let mut buffer = self.buffer.lock();
+        buffer.max_rows = max_rows;
+        buffer.retention = retention;
+        buffer.rotate(now_millis());
+        buffer.rotate_files(now_millis());
+    }
+
+    /// Persist the records in `dir`, the ones kept in memory so far included.
+    /// The records persisted in `dir` before, e.g. by the previous run of the process, are kept.
+    pub fn persist_to(&self, dir: impl AsRef<Path>) -> std::io::Result<()> {
+        fs::create_dir_all(dir.as_ref())?;
+
+        let mut buffer = self.buffer.lock();
+        buffer.dir = Some(dir.as_ref().to_path_buf());
+        buffer.file = None;
+        buffer.rotate_files(now_millis());
+        for record in std::mem::take(&mut buffer.records) {
+            buffer.append(record);
+        }
+        Ok(())
+    }
+
+    pub fn append(&self, record: TextLogRecord) {
+        let mut buffer = self.buffer.lock();
+        buffer.append(record);
+        buffer.rotate(now_millis());
+    }
+
+    /// The records within the retention, from the oldest to the newest.
+    pub fn records(&self) -> Vec<TextLogRecord> {
+        let mut buffer = self.buffer.lock();
+        let now = now_millis();
+        buffer.rotate(now);
+        buffer.rotate_files(now);
+        buffer.records(now)
+    }
+
+    pub fn clear(&self) {
+        let mut buffer = self.buffer.lock();
+        buffer.records.clear();
+        buffer.file = None;
+        if let Some(dir) = &buffer.dir {
+            for (_, path) in text_log_files(dir) {
+                let _ = fs::remove_file(path);
+            }
+        }
+    }
+}
+
+fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or_default()
+}
+
+/// Layer appending every event that passes the filters to the global `TextLogStore`.
+pub struct TextLogLayer;
+
+impl<S: Subscriber> Layer<S> for TextLogLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let meta = event.metadata();
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+
+        TextLogStore::instance().append(TextLogRecord {
+            event_time: now_millis(),
+            level: meta.level().to_string(),
+            target: meta.target().to_string(),
+            thread_name: std::thread::current()
+                .name()
+                .unwrap_or_default()
+                .to_string(),
+            message: visitor.finish(),
+        });
+    }
+}
+
+/// Formats the `message` of an event followed by its other fields as `name=value`.
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl MessageVisitor {
+    fn finish(self) -> String {
+        match (self.message.is_empty(), self.fields.is_empty()) {
+            (_, true) => self.message,
+            (true, false) => self.fields,
+            (false, false) => format!("{} {}", self.message, self.fields),
+        }
+    }
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_string();
+        } else {
+            self.record_debug(field, &value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() == "message" {
+            self.message = format!("{:?}", value);
+        } else {
+            if !self.fields.is_empty() {
+                self.fields.push(' ');
+            }
+            self.fields
+                .push_str(&format!("{}={:?}", field.name(), value));
+        }
+    }
+}
@@ -71,6 +71,10 @@ impl ActionHandler {
                 let r = self.handle(a).await;
                 RaftReply::from(r)
             }
+            MetaGrpcWriteReq::SwapTable(a) => {
+                let r = self.handle(a).await;
+                RaftReply::from(r)
+            }
             MetaGrpcWriteReq::CommitTable(a) => {
                 let r = self.handle(a).await;
                 RaftReply::from(r)
@@ -24,6 +24,7 @@ use common_meta_types::Cmd::CreateTable;
 use common_meta_types::Cmd::DropDatabase;
 use common_meta_types::Cmd::DropTable;
 use common_meta_types::Cmd::RenameTable;
+use common_meta_types::Cmd::SwapTable;
 use common_meta_types::Cmd::UpsertTableOptions;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
@@ -45,6 +46,8 @@ use common_meta_types::MetaError;
 use common_meta_types::OkOrExist;
 use common_meta_types::RenameTableReply;
 use common_meta_types::RenameTableReq;
+use common_meta_types::SwapTableReply;
+use common_meta_types::SwapTableReq;
 use common_meta_types::TableAlreadyExists;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
@@ -260,6 +263,32 @@ impl RequestHandler<RenameTableReq> for ActionHandler {
     }
 }
 
+#[async_trait::async_trait]
+impl RequestHandler<SwapTableReq> for ActionHandler {
+    async fn handle(&self, req: SwapTableReq) -> Result<SwapTableReply, MetaError> {
+        let if_exists = req.if_exists;
+
+        let cr = LogEntry {
+            txid: None,
+            cmd: SwapTable {
+                tenant: req.tenant,
+                db_name: req.db,
+                table_name: req.table_name,
+                other_db_name: req.other_db,
+                other_table_name: req.other_table_name,
+            },
+        };
+
+        match self.meta_node.write(cr).await {
+            Err(MetaError::AppError(AppError::UnknownTable(_))) if if_exists => {
+                Ok(SwapTableReply {})
+            }
+            Err(e) => Err(e),
+            Ok(_) => Ok(SwapTableReply {}),
+        }
+    }
+}
+
 #[async_trait::async_trait]
 impl RequestHandler<GetTableReq> for ActionHandler {
     async fn handle(&self, req: GetTableReq) -> Result<Arc<TableInfo>, MetaError> {
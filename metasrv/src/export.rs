@@ -18,6 +18,56 @@ use common_meta_sled_store::SledKeySpace;
 use common_meta_sled_store::SledOrderedSerde;
 use common_meta_sled_store::SledSerde;
 use common_meta_types::MetaStorageError;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Version of the exported data layout.
+///
+/// Bump it when the format of an exported line changes, so that an importer can refuse data it does not understand.
+pub const EXPORT_DATA_VERSION: u64 = 1;
+
+/// Takes the place of the sled tree name in the header line of the exported data.
+pub const EXPORT_HEADER: &str = "header";
+
+/// The first line of the exported data: `["header",{"data_version":1}]`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ExportHeader {
+    pub data_version: u64,
+}
+
+impl ExportHeader {
+    pub fn current() -> Self {
+        ExportHeader {
+            data_version: EXPORT_DATA_VERSION,
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String, MetaStorageError> {
+        let line = serde_json::to_string(&(EXPORT_HEADER, self))?;
+        Ok(line)
+    }
+
+    /// Parse the header from an exported line, returns `None` if the line is not a header.
+    ///
+    /// Data exported by older versions has no header line, it is treated as version 0.
+    pub fn from_json(line: &str) -> Option<Self> {
+        match serde_json::from_str::<(String, ExportHeader)>(line) {
+            Ok((tree_name, header)) if tree_name == EXPORT_HEADER => Some(header),
+            _ => None,
+        }
+    }
+
+    /// Check if the data can be imported by this version.
+    pub fn check_compatible(&self) -> Result<(), MetaStorageError> {
+        if self.data_version > EXPORT_DATA_VERSION {
+            return Err(MetaStorageError::SerdeError(format!(
+                "exported data version {} is newer than the max supported version {}",
+                self.data_version, EXPORT_DATA_VERSION
+            )));
+        }
+        Ok(())
+    }
+}
 
 /// Convert (sub_tree_prefix, key, value, key_space1, key_space2...) into a [`KeySpaceKV`] for export.
 macro_rules! to_kv_variant {
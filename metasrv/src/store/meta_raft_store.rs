@@ -53,6 +53,7 @@ use openraft::SnapshotMeta;
 use openraft::StorageError;
 
 use crate::export::vec_kv_to_json;
+use crate::export::ExportHeader;
 use crate::store::ToStorageError;
 use crate::Opened;
 
@@ -229,10 +230,21 @@ impl MetaRaftStore {
         Ok(())
     }
 
+    /// Export a consistent snapshot of raft state, logs and state machine.
+    ///
+    /// The first line is an [`ExportHeader`] that tags the data with its format version.
+    /// The state machine lock is held during the whole export, so no log is applied meanwhile.
     #[tracing::instrument(level = "debug", skip(self))]
     pub async fn export(&self) -> Result<Vec<String>, std::io::Error> {
+        let sm = self.state_machine.write().await;
+
         let mut res = vec![];
 
+        let header = ExportHeader::current()
+            .to_json()
+            .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e))?;
+        res.push(header);
+
         let state_kvs = self
             .raft_state
             .inner
@@ -256,11 +268,8 @@ impl MetaRaftStore {
             res.push(line);
         }
 
-        let name = self.state_machine.write().await.sm_tree.name.clone();
-        let sm_kvs = self
-            .state_machine
-            .write()
-            .await
+        let name = sm.sm_tree.name.clone();
+        let sm_kvs = sm
             .sm_tree
             .export()
             .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e))?;
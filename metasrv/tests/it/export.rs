@@ -0,0 +1,39 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_meta::export::ExportHeader;
+use databend_meta::export::EXPORT_DATA_VERSION;
+
+#[test]
+fn test_export_header() -> anyhow::Result<()> {
+    let header = ExportHeader::current();
+    assert_eq!(EXPORT_DATA_VERSION, header.data_version);
+
+    let line = header.to_json()?;
+    assert_eq!(r#"["header",{"data_version":1}]"#, line);
+    assert_eq!(Some(header.clone()), ExportHeader::from_json(&line));
+    header.check_compatible()?;
+
+    // A kv line is not a header.
+    let kv_line = r#"["state_machine/0",{"Sequences":{"key":"generic-kv","value":3}}]"#;
+    assert_eq!(None, ExportHeader::from_json(kv_line));
+
+    // Data exported by a newer version can not be imported.
+    let newer = ExportHeader {
+        data_version: EXPORT_DATA_VERSION + 1,
+    };
+    assert!(newer.check_compatible().is_err());
+
+    Ok(())
+}
@@ -67,6 +67,7 @@ async fn test_export() -> anyhow::Result<()> {
         }
 
         let want = vec![
+            r#"["header",{"data_version":1}]"#, //
             r#"["test-29000-raft_state",{"RaftStateKV":{"key":"Id","value":{"NodeId":0}}}]"#, //
             r#"["test-29000-raft_state",{"RaftStateKV":{"key":"HardState","value":{"HardState":{"current_term":1,"voted_for":0}}}}]"#, //
             r#"["test-29000-raft_log",{"Logs":{"key":0,"value":{"log_id":{"term":0,"index":0},"payload":{"Membership":{"configs":[[0]],"all_nodes":[0]}}}}}]"#, //
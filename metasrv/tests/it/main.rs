@@ -19,6 +19,7 @@
 
 mod api;
 mod configs;
+mod export;
 mod grpc;
 mod meta_node;
 mod store;
@@ -14,12 +14,14 @@
 
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::NodeInfo;
 use poem::http::StatusCode;
 use poem::web::Data;
 use poem::web::IntoResponse;
 use poem::web::Json;
+use poem::web::Path;
 
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
@@ -42,6 +44,29 @@ pub async fn cluster_list_handler(
     Ok(Json(nodes))
 }
 
+// POST /v1/cluster/drain/:node_id
+// stop assigning new tasks to the node, it leaves the cluster once its running queries finish
+// request: None
+// return: the status code only
+#[poem::handler]
+pub async fn cluster_drain_handler(
+    sessions: Data<&Arc<SessionManager>>,
+    Path(node_id): Path<String>,
+) -> poem::Result<impl IntoResponse> {
+    let discovery = sessions.0.get_cluster_discovery();
+    discovery.drain_node(&node_id).await.map_err(|cause| {
+        let status = match cause.code() == ErrorCode::ClusterUnknownNodeCode() {
+            true => StatusCode::NOT_FOUND,
+            false => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        poem::Error::from_string(
+            format!("Failed to drain cluster node {node_id}. cause: {cause}"),
+            status,
+        )
+    })?;
+    Ok(StatusCode::OK)
+}
+
 async fn list_nodes(sessions: &Arc<SessionManager>) -> Result<Vec<Arc<NodeInfo>>> {
     let watch_cluster_session = sessions
         .create_session(SessionType::HTTPAPI("WatchCluster".to_string()))
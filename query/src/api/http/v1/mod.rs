@@ -16,4 +16,5 @@ pub mod cluster;
 pub mod config;
 pub mod health;
 pub mod logs;
+pub mod pipeline;
 pub mod status;
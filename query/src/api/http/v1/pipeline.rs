@@ -0,0 +1,64 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use poem::http::StatusCode;
+use poem::web::Data;
+use poem::web::Json;
+use poem::web::Path;
+use poem::web::Query;
+use poem::IntoResponse;
+use poem::Response;
+use serde::Deserialize;
+
+use crate::sessions::SessionManager;
+
+#[derive(Deserialize)]
+pub struct PipelineParams {
+    // json or dot, json by default
+    pub format: Option<String>,
+}
+
+// the processors graph of the query running in the session, while it executes
+#[poem::handler]
+pub async fn pipeline_handler(
+    sessions_extension: Data<&Arc<SessionManager>>,
+    Path(session_id): Path<String>,
+    Query(params): Query<PipelineParams>,
+) -> poem::Result<Response> {
+    let profile = match sessions_extension.0.get_session_by_id(&session_id).await {
+        None => None,
+        Some(session) => session.pipeline_profile(),
+    };
+
+    let profile = profile.ok_or_else(|| {
+        poem::Error::from_string(
+            format!("No running pipeline in session {session_id}"),
+            StatusCode::NOT_FOUND,
+        )
+    })?;
+
+    match params.format.as_deref() {
+        None | Some("json") => Ok(Json(profile).into_response()),
+        Some("dot") => Ok(profile
+            .to_dot()
+            .with_content_type("text/vnd.graphviz")
+            .into_response()),
+        Some(format) => Err(poem::Error::from_string(
+            format!("Unknown pipeline format {format}, expect json or dot"),
+            StatusCode::BAD_REQUEST,
+        )),
+    }
+}
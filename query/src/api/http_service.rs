@@ -20,6 +20,7 @@ use common_exception::Result;
 use common_tracing::tracing;
 use poem::get;
 use poem::listener::RustlsConfig;
+use poem::post;
 use poem::Endpoint;
 use poem::EndpointExt;
 use poem::Route;
@@ -52,6 +53,14 @@ impl HttpService {
                 "/v1/cluster/list",
                 get(super::http::v1::cluster::cluster_list_handler),
             )
+            .at(
+                "/v1/cluster/drain/:node_id",
+                post(super::http::v1::cluster::cluster_drain_handler),
+            )
+            .at(
+                "/v1/processes/:session_id/pipeline",
+                get(super::http::v1::pipeline::pipeline_handler),
+            )
             .at(
                 "/debug/home",
                 get(super::http::debug::home::debug_home_handler),
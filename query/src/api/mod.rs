@@ -15,6 +15,7 @@
 // The api module only used for internal communication, such as GRPC between cluster and the managed HTTP REST API.
 
 pub use http_service::HttpService;
+pub use rpc::is_compatible_protocol_version;
 pub use rpc::BroadcastAction;
 pub use rpc::CancelAction;
 pub use rpc::DatabendQueryFlightDispatcher;
@@ -24,6 +25,10 @@ pub use rpc::FlightClient;
 pub use rpc::FlightTicket;
 pub use rpc::ShuffleAction;
 pub use rpc::StreamTicket;
+pub use rpc::SystemTableTicket;
+pub use rpc::FLIGHT_PROTOCOL_VERSION;
+pub use rpc::MIN_COMPATIBLE_FLIGHT_PROTOCOL_VERSION;
+pub use rpc::MIN_SYSTEM_TABLE_PROTOCOL_VERSION;
 pub use rpc_service::RpcService;
 
 pub mod http;
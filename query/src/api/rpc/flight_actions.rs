@@ -19,6 +19,9 @@ use common_exception::ErrorCode;
 use common_exception::ToErrorCode;
 use common_planners::Expression;
 use common_planners::PlanNode;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
 use tonic::Status;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
@@ -43,17 +46,82 @@ pub struct CancelAction {
     pub query_id: String,
 }
 
+/// Version of the flight action protocol spoken by this node.
+///
+/// Bump it when the encoded actions or plans change in a way older nodes cannot read,
+/// and raise [`MIN_COMPATIBLE_FLIGHT_PROTOCOL_VERSION`] once the old encoding is dropped.
+pub const FLIGHT_PROTOCOL_VERSION: u32 = 2;
+
+/// The oldest flight action protocol version this node can still exchange plans with.
+/// Nodes that predate the negotiation register themselves with version 0, and are still
+/// served until their encoding is dropped, so a cluster can be upgraded one node at a time.
+pub const MIN_COMPATIBLE_FLIGHT_PROTOCOL_VERSION: u32 = 0;
+
+/// The oldest flight protocol version serving `SystemTableTicket`s.
+pub const MIN_SYSTEM_TABLE_PROTOCOL_VERSION: u32 = 2;
+
+pub fn is_compatible_protocol_version(version: u32) -> bool {
+    (MIN_COMPATIBLE_FLIGHT_PROTOCOL_VERSION..=FLIGHT_PROTOCOL_VERSION).contains(&version)
+}
+
+#[derive(serde::Deserialize)]
+struct FlightActionHeader {
+    #[serde(default)]
+    protocol_version: u32,
+}
+
+fn encode_action<T: Serialize>(action: &T, name: &str) -> Result<Vec<u8>, ErrorCode> {
+    let mut value = serde_json::to_value(action)
+        .map_err_to_code(ErrorCode::LogicalError, || {
+            format!("Logical error: cannot serialize {}.", name)
+        })?;
+
+    // Unknown fields are ignored by the decoder, so older nodes can still read the body.
+    if let Value::Object(fields) = &mut value {
+        fields.insert(
+            String::from("protocol_version"),
+            Value::from(FLIGHT_PROTOCOL_VERSION),
+        );
+    }
+
+    serde_json::to_vec(&value).map_err_to_code(ErrorCode::LogicalError, || {
+        format!("Logical error: cannot serialize {}.", name)
+    })
+}
+
+fn decode_action<T: DeserializeOwned>(body: &[u8], name: &str) -> Result<T, Status> {
+    let utf8_body = match std::str::from_utf8(body) {
+        Err(cause) => return Err(Status::invalid_argument(cause.to_string())),
+        Ok(utf8_body) => utf8_body,
+    };
+
+    // Check the version before decoding the plan, a plan from an incompatible node
+    // would otherwise fail with an opaque deserialization error.
+    match serde_json::from_str::<FlightActionHeader>(utf8_body) {
+        Err(cause) => return Err(Status::invalid_argument(cause.to_string())),
+        Ok(header) if !is_compatible_protocol_version(header.protocol_version) => {
+            return Err(Status::failed_precondition(format!(
+                "Incompatible flight protocol version {} for {}, this node supports versions {} to {}",
+                header.protocol_version,
+                name,
+                MIN_COMPATIBLE_FLIGHT_PROTOCOL_VERSION,
+                FLIGHT_PROTOCOL_VERSION
+            )));
+        }
+        Ok(_) => {}
+    }
+
+    match serde_json::from_str::<T>(utf8_body) {
+        Err(cause) => Err(Status::invalid_argument(cause.to_string())),
+        Ok(action) => Ok(action),
+    }
+}
+
 impl TryInto<ShuffleAction> for Vec<u8> {
     type Error = Status;
 
     fn try_into(self) -> Result<ShuffleAction, Self::Error> {
-        match std::str::from_utf8(&self) {
-            Err(cause) => Err(Status::invalid_argument(cause.to_string())),
-            Ok(utf8_body) => match serde_json::from_str::<ShuffleAction>(utf8_body) {
-                Err(cause) => Err(Status::invalid_argument(cause.to_string())),
-                Ok(action) => Ok(action),
-            },
-        }
+        decode_action(&self, "ShuffleAction")
     }
 }
 
@@ -61,9 +129,7 @@ impl TryInto<Vec<u8>> for ShuffleAction {
     type Error = ErrorCode;
 
     fn try_into(self) -> Result<Vec<u8>, Self::Error> {
-        serde_json::to_vec(&self).map_err_to_code(ErrorCode::LogicalError, || {
-            "Logical error: cannot serialize ShuffleAction."
-        })
+        encode_action(&self, "ShuffleAction")
     }
 }
 
@@ -71,13 +137,7 @@ impl TryInto<BroadcastAction> for Vec<u8> {
     type Error = Status;
 
     fn try_into(self) -> Result<BroadcastAction, Self::Error> {
-        match std::str::from_utf8(&self) {
-            Err(cause) => Err(Status::invalid_argument(cause.to_string())),
-            Ok(utf8_body) => match serde_json::from_str::<BroadcastAction>(utf8_body) {
-                Err(cause) => Err(Status::invalid_argument(cause.to_string())),
-                Ok(action) => Ok(action),
-            },
-        }
+        decode_action(&self, "BroadcastAction")
     }
 }
 
@@ -85,9 +145,7 @@ impl TryInto<Vec<u8>> for BroadcastAction {
     type Error = ErrorCode;
 
     fn try_into(self) -> Result<Vec<u8>, Self::Error> {
-        serde_json::to_vec(&self).map_err_to_code(ErrorCode::LogicalError, || {
-            "Logical error: cannot serialize BroadcastAction."
-        })
+        encode_action(&self, "BroadcastAction")
     }
 }
 
@@ -95,13 +153,7 @@ impl TryInto<CancelAction> for Vec<u8> {
     type Error = Status;
 
     fn try_into(self) -> Result<CancelAction, Self::Error> {
-        match std::str::from_utf8(&self) {
-            Err(cause) => Err(Status::invalid_argument(cause.to_string())),
-            Ok(utf8_body) => match serde_json::from_str::<CancelAction>(utf8_body) {
-                Err(cause) => Err(Status::invalid_argument(cause.to_string())),
-                Ok(action) => Ok(action),
-            },
-        }
+        decode_action(&self, "CancelAction")
     }
 }
 
@@ -109,9 +161,7 @@ impl TryInto<Vec<u8>> for CancelAction {
     type Error = ErrorCode;
 
     fn try_into(self) -> Result<Vec<u8>, Self::Error> {
-        serde_json::to_vec(&self).map_err_to_code(ErrorCode::LogicalError, || {
-            "Logical error: cannot serialize BroadcastAction."
-        })
+        encode_action(&self, "CancelAction")
     }
 }
 
@@ -35,10 +35,12 @@ use crate::api::rpc::flight_scatter::FlightScatter;
 use crate::api::rpc::flight_scatter_broadcast::BroadcastFlightScatter;
 use crate::api::rpc::flight_scatter_hash::HashFlightScatter;
 use crate::api::rpc::flight_tickets::StreamTicket;
+use crate::api::rpc::flight_tickets::SystemTableTicket;
 use crate::api::FlightAction;
 use crate::pipelines::processors::PipelineBuilder;
 use crate::sessions::QueryContext;
 use crate::sessions::SessionRef;
+use crate::storages::ToReadDataSourcePlan;
 
 struct StreamInfo {
     #[allow(unused)]
@@ -90,6 +92,34 @@ impl DatabendQueryFlightDispatcher {
         }
     }
 
+    /// Streams the rows of a system table of this node, for the cluster system tables of the requesting node.
+    #[tracing::instrument(level = "debug", skip_all, fields(session.id = session.get_id().as_str()))]
+    pub async fn get_system_table_stream(
+        &self,
+        session: SessionRef,
+        ticket: &SystemTableTicket,
+    ) -> Result<(mpsc::Receiver<Result<DataBlock>>, DataSchemaRef)> {
+        let query_context = session.create_query_context().await?;
+        let table = query_context.get_table("system", &ticket.table).await?;
+        let source_plan = table.read_plan(query_context.clone(), None).await?;
+        let mut stream = table.read(query_context.clone(), &source_plan).await?;
+
+        let (tx, rx) = mpsc::channel(2);
+        query_context.try_spawn(
+            async move {
+                let _session = session;
+                while let Some(item) = stream.next().await {
+                    if tx.send(item).await.is_err() {
+                        break;
+                    }
+                }
+            }
+            .instrument(Span::current()),
+        )?;
+
+        Ok((rx, table.schema()))
+    }
+
     #[tracing::instrument(level = "debug", skip_all, fields(session.id = session.get_id().as_str()))]
     pub async fn broadcast_action(&self, session: SessionRef, action: FlightAction) -> Result<()> {
         let query_id = action.get_query_id();
@@ -106,20 +106,31 @@ impl FlightService for DatabendQueryFlightService {
         common_tracing::extract_remote_span_as_parent(&request);
         let ticket: FlightTicket = request.into_inner().try_into()?;
 
-        match ticket {
+        let (receiver, data_schema) = match ticket {
             FlightTicket::StreamTicket(steam_ticket) => {
-                let (receiver, data_schema) = self.dispatcher.get_stream(&steam_ticket)?;
-                let arrow_schema = data_schema.to_arrow();
-                let ipc_fields = default_ipc_fields(&arrow_schema.fields);
-
-                serialize_schema(&arrow_schema, Some(&ipc_fields));
+                self.dispatcher.get_stream(&steam_ticket)?
+            }
+            FlightTicket::SystemTableTicket(table_ticket) => {
+                let is_aborted = self.dispatcher.is_aborted();
+                let session = self
+                    .sessions
+                    .create_rpc_session(table_ticket.query_id.clone(), is_aborted)
+                    .await?;
 
-                Ok(RawResponse::new(
-                    Box::pin(FlightDataStream::create(receiver, ipc_fields))
-                        as FlightStream<FlightData>,
-                ))
+                self.dispatcher
+                    .get_system_table_stream(session, &table_ticket)
+                    .await?
             }
-        }
+        };
+
+        let arrow_schema = data_schema.to_arrow();
+        let ipc_fields = default_ipc_fields(&arrow_schema.fields);
+
+        serialize_schema(&arrow_schema, Some(&ipc_fields));
+
+        Ok(RawResponse::new(
+            Box::pin(FlightDataStream::create(receiver, ipc_fields)) as FlightStream<FlightData>,
+        ))
     }
 
     type DoPutStream = FlightStream<PutResult>;
@@ -26,9 +26,17 @@ pub struct StreamTicket {
     pub stream: String,
 }
 
+/// Fetches the rows of a local system table of the remote node.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct SystemTableTicket {
+    pub query_id: String,
+    pub table: String,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
 pub enum FlightTicket {
     StreamTicket(StreamTicket),
+    SystemTableTicket(SystemTableTicket),
 }
 
 impl FlightTicket {
@@ -39,6 +47,13 @@ impl FlightTicket {
             stream: stream.to_string(),
         })
     }
+
+    pub fn system_table(query_id: &str, table: &str) -> FlightTicket {
+        FlightTicket::SystemTableTicket(SystemTableTicket {
+            query_id: query_id.to_string(),
+            table: table.to_string(),
+        })
+    }
 }
 
 impl TryInto<FlightTicket> for Ticket {
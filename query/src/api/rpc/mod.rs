@@ -12,15 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub use flight_actions::is_compatible_protocol_version;
 pub use flight_actions::BroadcastAction;
 pub use flight_actions::CancelAction;
 pub use flight_actions::FlightAction;
 pub use flight_actions::ShuffleAction;
+pub use flight_actions::FLIGHT_PROTOCOL_VERSION;
+pub use flight_actions::MIN_COMPATIBLE_FLIGHT_PROTOCOL_VERSION;
+pub use flight_actions::MIN_SYSTEM_TABLE_PROTOCOL_VERSION;
 pub use flight_client::FlightClient;
 pub use flight_dispatcher::DatabendQueryFlightDispatcher;
 pub use flight_service::DatabendQueryFlightService;
 pub use flight_tickets::FlightTicket;
 pub use flight_tickets::StreamTicket;
+pub use flight_tickets::SystemTableTicket;
 
 mod flight_actions;
 mod flight_client;
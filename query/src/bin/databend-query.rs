@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
 
+use common_base::tokio;
 use common_base::RuntimeTracker;
 use common_macros::databend_main;
 use common_meta_embedded::MetaEmbedded;
@@ -21,6 +24,7 @@ use common_metrics::init_default_metrics_recorder;
 use common_tracing::init_global_tracing;
 use common_tracing::set_panic_hook;
 use common_tracing::tracing;
+use common_tracing::TextLogStore;
 use databend_query::api::HttpService;
 use databend_query::api::RpcService;
 use databend_query::configs::Config;
@@ -64,6 +68,11 @@ async fn main(_global_tracker: Arc<RuntimeTracker>) -> common_exception::Result<
         conf.log.log_dir.as_str(),
         conf.log.log_level.as_str(),
     );
+    TextLogStore::instance().set_retention(
+        conf.log.text_log_max_rows,
+        Duration::from_secs(conf.log.text_log_retention_secs),
+    );
+    TextLogStore::instance().persist_to(Path::new(&conf.log.log_dir).join("text_log"))?;
 
     init_default_metrics_recorder();
 
@@ -158,6 +167,8 @@ async fn main(_global_tracker: Arc<RuntimeTracker>) -> common_exception::Result<
         let cluster_discovery = session_manager.get_cluster_discovery();
         let register_to_metastore = cluster_discovery.register_to_metastore(&conf);
         register_to_metastore.await?;
+        tokio::spawn(session_manager.drain_routine());
+        tokio::spawn(session_manager.consistency_check_routine());
         tracing::info!(
             "Databend query has been registered:{:?} to metasrv:[{:?}].",
             conf.query.cluster_id,
@@ -34,6 +34,8 @@ use common_meta_types::MetaError;
 use common_meta_types::MetaId;
 use common_meta_types::RenameTableReply;
 use common_meta_types::RenameTableReq;
+use common_meta_types::SwapTableReply;
+use common_meta_types::SwapTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
@@ -136,6 +138,11 @@ impl MetaApi for MetaBackend {
             .await
     }
 
+    async fn swap_table(&self, req: SwapTableReq) -> Result<SwapTableReply, MetaError> {
+        self.query_backend(move |cli| async move { cli.swap_table(req).await })
+            .await
+    }
+
     async fn get_table(&self, req: GetTableReq) -> std::result::Result<Arc<TableInfo>, MetaError> {
         self.query_backend(move |cli| async move { cli.get_table(req).await })
             .await
@@ -25,6 +25,8 @@ use common_meta_types::DropTableReq;
 use common_meta_types::MetaId;
 use common_meta_types::RenameTableReply;
 use common_meta_types::RenameTableReq;
+use common_meta_types::SwapTableReply;
+use common_meta_types::SwapTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
@@ -94,6 +96,9 @@ pub trait Catalog: DynClone + Send + Sync {
 
     async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply>;
 
+    // Exchange the names of two tables atomically.
+    async fn swap_table(&self, req: SwapTableReq) -> Result<SwapTableReply>;
+
     // Check a db.table is exists or not.
     async fn exists_table(&self, tenant: &str, db_name: &str, table_name: &str) -> Result<bool> {
         match self.get_table(tenant, db_name, table_name).await {
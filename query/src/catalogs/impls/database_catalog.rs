@@ -26,6 +26,8 @@ use common_meta_types::DropTableReq;
 use common_meta_types::MetaId;
 use common_meta_types::RenameTableReply;
 use common_meta_types::RenameTableReq;
+use common_meta_types::SwapTableReply;
+use common_meta_types::SwapTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
@@ -295,6 +297,31 @@ impl Catalog for DatabaseCatalog {
         self.mutable_catalog.rename_table(req).await
     }
 
+    async fn swap_table(&self, req: SwapTableReq) -> Result<SwapTableReply> {
+        if req.tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while swap table)",
+            ));
+        }
+        tracing::info!("Swap table from req:{:?}", req);
+
+        if self
+            .immutable_catalog
+            .exists_database(&req.tenant, &req.db)
+            .await?
+            || self
+                .immutable_catalog
+                .exists_database(&req.tenant, &req.other_db)
+                .await?
+        {
+            return Err(ErrorCode::UnImplement(
+                "Cannot swap table with tables in system databases",
+            ));
+        }
+
+        self.mutable_catalog.swap_table(req).await
+    }
+
     async fn upsert_table_option(
         &self,
         req: UpsertTableOptionReq,
@@ -25,6 +25,8 @@ use common_meta_types::DropTableReq;
 use common_meta_types::MetaId;
 use common_meta_types::RenameTableReply;
 use common_meta_types::RenameTableReq;
+use common_meta_types::SwapTableReply;
+use common_meta_types::SwapTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
@@ -146,6 +148,12 @@ impl Catalog for ImmutableCatalog {
         ))
     }
 
+    async fn swap_table(&self, _req: SwapTableReq) -> Result<SwapTableReply> {
+        Err(ErrorCode::UnImplement(
+            "Cannot swap table in system database",
+        ))
+    }
+
     async fn upsert_table_option(
         &self,
         req: UpsertTableOptionReq,
@@ -33,6 +33,8 @@ use common_meta_types::ListTableReq;
 use common_meta_types::MetaId;
 use common_meta_types::RenameTableReply;
 use common_meta_types::RenameTableReq;
+use common_meta_types::SwapTableReply;
+use common_meta_types::SwapTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
@@ -243,6 +245,11 @@ impl Catalog for MutableCatalog {
         Ok(res)
     }
 
+    async fn swap_table(&self, req: SwapTableReq) -> Result<SwapTableReply> {
+        let res = self.ctx.meta.swap_table(req).await?;
+        Ok(res)
+    }
+
     async fn upsert_table_option(
         &self,
         req: UpsertTableOptionReq,
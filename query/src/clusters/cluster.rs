@@ -43,7 +43,9 @@ use futures::StreamExt;
 use rand::thread_rng;
 use rand::Rng;
 
+use crate::api::is_compatible_protocol_version;
 use crate::api::FlightClient;
+use crate::api::FLIGHT_PROTOCOL_VERSION;
 use crate::common::MetaClientProvider;
 use crate::configs::Config;
 
@@ -51,6 +53,7 @@ pub struct ClusterDiscovery {
     local_id: String,
     heartbeat: Mutex<ClusterHeartbeat>,
     api_provider: Arc<dyn ClusterApi>,
+    drain_notify: Arc<Notify>,
 }
 
 impl ClusterDiscovery {
@@ -66,11 +69,17 @@ impl ClusterDiscovery {
         let local_id = GlobalUniqName::unique();
         let meta_client = ClusterDiscovery::create_meta_client(&cfg).await?;
         let (lift_time, provider) = Self::create_provider(&cfg, meta_client)?;
+        let drain_notify = Arc::new(Notify::new());
 
         Ok(Arc::new(ClusterDiscovery {
             local_id: local_id.clone(),
             api_provider: provider.clone(),
-            heartbeat: Mutex::new(ClusterHeartbeat::create(lift_time, provider)),
+            heartbeat: Mutex::new(ClusterHeartbeat::create(
+                lift_time,
+                provider,
+                drain_notify.clone(),
+            )),
+            drain_notify,
         }))
     }
 
@@ -94,6 +103,23 @@ impl ClusterDiscovery {
                 let mut res = Vec::with_capacity(cluster_nodes.len());
 
                 for node in &cluster_nodes {
+                    // During a rolling upgrade, only schedule on the nodes that can read our plans.
+                    if node.id != self.local_id && !is_compatible_protocol_version(node.version) {
+                        tracing::warn!(
+                            "Skip cluster node {} with incompatible flight protocol version {}, local version {}",
+                            node.id,
+                            node.version,
+                            FLIGHT_PROTOCOL_VERSION
+                        );
+                        continue;
+                    }
+
+                    // A draining node only finishes the queries it is running.
+                    if node.id != self.local_id && node.draining {
+                        tracing::debug!("Skip draining cluster node {}", node.id);
+                        continue;
+                    }
+
                     res.push(Arc::new(node.clone()))
                 }
 
@@ -159,7 +185,15 @@ impl ClusterDiscovery {
         let cpus = cfg.query.num_cpus;
         // TODO: 127.0.0.1 || ::0
         let address = cfg.query.flight_api_address.clone();
-        let node_info = NodeInfo::create(self.local_id.clone(), cpus, address);
+        let mut node_info = NodeInfo::create(self.local_id.clone(), cpus, address);
+        node_info.version = FLIGHT_PROTOCOL_VERSION;
+        node_info.tags = cfg
+            .query
+            .node_tags
+            .split(',')
+            .map(|tag| tag.trim().to_string())
+            .filter(|tag| !tag.is_empty())
+            .collect();
 
         self.drop_invalid_nodes(&node_info).await?;
         match self.api_provider.add_node(node_info.clone()).await {
@@ -168,6 +202,32 @@ impl ClusterDiscovery {
         }
     }
 
+    /// Marks the node as draining: the other nodes stop scheduling tasks on it,
+    /// and the node leaves the cluster once its running queries are finished.
+    pub async fn drain_node(self: &Arc<Self>, node_id: &str) -> Result<()> {
+        self.api_provider.drain_node(node_id.to_string()).await?;
+
+        // The other nodes find out they are draining on their next heartbeat.
+        if node_id == self.local_id {
+            self.drain_notify.notify_one();
+        }
+        Ok(())
+    }
+
+    /// Resolves once this node is marked as draining.
+    pub async fn wait_drain_request(self: &Arc<Self>) {
+        self.drain_notify.notified().await
+    }
+
+    /// Stops the heartbeat and removes this node from the cluster.
+    pub async fn deregister(self: &Arc<Self>) -> Result<()> {
+        let mut heartbeat = self.heartbeat.lock().await;
+        heartbeat.shutdown().await?;
+        self.api_provider
+            .drop_node(self.local_id.clone(), None)
+            .await
+    }
+
     async fn start_heartbeat(self: &Arc<Self>, node_info: NodeInfo) -> Result<()> {
         let mut heartbeat = self.heartbeat.lock().await;
         heartbeat.start(node_info);
@@ -235,6 +295,17 @@ impl Cluster {
     pub fn get_nodes(&self) -> Vec<Arc<NodeInfo>> {
         self.nodes.to_vec()
     }
+
+    /// The nodes with the tag, and the local node which coordinates the query.
+    pub fn with_tag(&self, tag: &str) -> Arc<Cluster> {
+        let nodes = self
+            .nodes
+            .iter()
+            .filter(|node| self.is_local(node) || node.has_tag(tag))
+            .cloned()
+            .collect();
+        Cluster::create(nodes, self.local_id.clone())
+    }
 }
 
 struct ClusterHeartbeat {
@@ -243,13 +314,19 @@ struct ClusterHeartbeat {
     shutdown_notify: Arc<Notify>,
     cluster_api: Arc<dyn ClusterApi>,
     shutdown_handler: Option<JoinHandle<()>>,
+    drain_notify: Arc<Notify>,
 }
 
 impl ClusterHeartbeat {
-    pub fn create(timeout: Duration, cluster_api: Arc<dyn ClusterApi>) -> ClusterHeartbeat {
+    pub fn create(
+        timeout: Duration,
+        cluster_api: Arc<dyn ClusterApi>,
+        drain_notify: Arc<Notify>,
+    ) -> ClusterHeartbeat {
         ClusterHeartbeat {
             timeout,
             cluster_api,
+            drain_notify,
             shutdown: Arc::new(AtomicBool::new(false)),
             shutdown_notify: Arc::new(Notify::new()),
             shutdown_handler: None,
@@ -260,10 +337,12 @@ impl ClusterHeartbeat {
         let shutdown = self.shutdown.clone();
         let shutdown_notify = self.shutdown_notify.clone();
         let cluster_api = self.cluster_api.clone();
+        let drain_notify = self.drain_notify.clone();
         let sleep_range = self.heartbeat_interval(self.timeout);
 
         async move {
             let mut shutdown_notified = Box::pin(shutdown_notify.notified());
+            let mut draining = false;
 
             while !shutdown.load(Ordering::Relaxed) {
                 let mills = {
@@ -283,12 +362,30 @@ impl ClusterHeartbeat {
                         if let Err(failure) = heartbeat.await {
                             tracing::error!("Cluster cluster api heartbeat failure: {:?}", failure);
                         }
+
+                        if !draining {
+                            draining = Self::is_draining(&cluster_api, &node.id).await;
+                            if draining {
+                                tracing::info!("Cluster node {} is draining", node.id);
+                                drain_notify.notify_one();
+                            }
+                        }
                     }
                 }
             }
         }
     }
 
+    async fn is_draining(cluster_api: &Arc<dyn ClusterApi>, node_id: &str) -> bool {
+        match cluster_api.get_nodes().await {
+            Ok(nodes) => nodes.iter().any(|node| node.id == node_id && node.draining),
+            Err(failure) => {
+                tracing::error!("Cluster cluster api get_nodes failure: {:?}", failure);
+                false
+            }
+        }
+    }
+
     fn heartbeat_interval(&self, duration: Duration) -> RangeInclusive<u128> {
         (duration / 3).as_millis()..=((duration / 3) * 2).as_millis()
     }
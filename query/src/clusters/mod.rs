@@ -13,6 +13,8 @@
 // limitations under the License.
 
 mod cluster;
+mod node_routing;
 
 pub use cluster::Cluster;
 pub use cluster::ClusterDiscovery;
+pub use node_routing::NodeRoutingRules;
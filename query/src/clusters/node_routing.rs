@@ -0,0 +1,110 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::PlanNode;
+
+/// Routes the distributed tasks of a query to the nodes with a tag, e.g. the INSERT statements
+/// to the `ingest` nodes. The rules are `<selector>=<tag>` separated by commas, a selector is
+/// `user:<name>`, `role:<name>` or `statement:<kind>`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NodeRoutingRules {
+    users: HashMap<String, String>,
+    roles: HashMap<String, String>,
+    statements: HashMap<String, String>,
+}
+
+impl NodeRoutingRules {
+    pub fn try_create(rules: &str) -> Result<NodeRoutingRules> {
+        let mut routing_rules = NodeRoutingRules::default();
+
+        for rule in rules.split(',').map(str::trim).filter(|r| !r.is_empty()) {
+            let (selector, tag) = rule
+                .split_once('=')
+                .map(|(selector, tag)| (selector.trim(), tag.trim()))
+                .filter(|(_, tag)| !tag.is_empty())
+                .ok_or_else(|| Self::invalid_rule(rule))?;
+
+            let (kind, name) = selector
+                .split_once(':')
+                .map(|(kind, name)| (kind.trim().to_lowercase(), name.trim()))
+                .filter(|(_, name)| !name.is_empty())
+                .ok_or_else(|| Self::invalid_rule(rule))?;
+
+            let rules = match kind.as_str() {
+                "user" => &mut routing_rules.users,
+                "role" => &mut routing_rules.roles,
+                "statement" => &mut routing_rules.statements,
+                _ => return Err(Self::invalid_rule(rule)),
+            };
+
+            let name = match kind.as_str() {
+                "statement" => Self::check_statement_kind(rule, name.to_lowercase())?,
+                _ => name.to_string(),
+            };
+            rules.insert(name, tag.to_string());
+        }
+
+        Ok(routing_rules)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.users.is_empty() && self.roles.is_empty() && self.statements.is_empty()
+    }
+
+    /// The tag of the nodes the query runs on, a user rule wins over the role rules,
+    /// which win over the statement rule.
+    pub fn route(&self, user: &str, roles: &[String], statement_kind: &str) -> Option<&str> {
+        self.users
+            .get(user)
+            .or_else(|| roles.iter().find_map(|role| self.roles.get(role)))
+            .or_else(|| self.statements.get(statement_kind))
+            .map(|tag| tag.as_str())
+    }
+
+    /// The statement kind the `statement:<kind>` rules match.
+    pub fn statement_kind(plan: &PlanNode) -> &'static str {
+        match plan {
+            PlanNode::Select(_) | PlanNode::SelectIntoOutfile(_) | PlanNode::Explain(_) => "select",
+            PlanNode::Insert(_) => "insert",
+            _ => "other",
+        }
+    }
+
+    // COPY has no distributed tasks, it runs on the node receiving it only, so a rule
+    // on it would never take effect.
+    fn check_statement_kind(rule: &str, kind: String) -> Result<String> {
+        match kind.as_str() {
+            "select" | "insert" | "other" => Ok(kind),
+            "copy" => Err(ErrorCode::InvalidConfig(format!(
+                "Invalid node routing rule {:?}, COPY runs on the node receiving it only and can't be routed",
+                rule
+            ))),
+            _ => Err(ErrorCode::InvalidConfig(format!(
+                "Invalid node routing rule {:?}, the statement kinds are select, insert and other",
+                rule
+            ))),
+        }
+    }
+
+    fn invalid_rule(rule: &str) -> ErrorCode {
+        ErrorCode::InvalidConfig(format!(
+            "Invalid node routing rule {:?}, expect user:<name>=<tag>, role:<name>=<tag> or statement:<kind>=<tag>",
+            rule
+        ))
+    }
+}
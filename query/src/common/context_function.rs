@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::UNIX_EPOCH;
 
 use common_datavalues::DataValue;
 use common_exception::ErrorCode;
@@ -46,6 +47,20 @@ impl ContextFunction {
             "current_user" => vec![Expression::create_literal(DataValue::String(
                 ctx.get_current_user()?.identity().to_string().into_bytes(),
             ))],
+            "last_query_id" => vec![Expression::create_literal(DataValue::String(
+                ctx.get_last_query_id().into_bytes(),
+            ))],
+            // Bound to the start time of the query, so every row and every stage
+            // of a distributed query sees the same time.
+            "now" | "current_timestamp" | "today" | "yesterday" | "tomorrow" => {
+                let created_time = ctx
+                    .get_created_time()
+                    .duration_since(UNIX_EPOCH)
+                    .map_err(|e| ErrorCode::LogicalError(e.to_string()))?;
+                vec![Expression::create_literal(DataValue::UInt64(
+                    created_time.as_secs(),
+                ))]
+            }
             _ => vec![],
         })
     }
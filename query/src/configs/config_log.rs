@@ -22,6 +22,8 @@ use crate::configs::Config;
 pub const LOG_LEVEL: &str = "LOG_LEVEL";
 pub const LOG_DIR: &str = "LOG_DIR";
 pub const LOG_QUERY_ENABLED: &str = "LOG_QUERY_ENABLED";
+pub const TEXT_LOG_MAX_ROWS: &str = "TEXT_LOG_MAX_ROWS";
+pub const TEXT_LOG_RETENTION_SECS: &str = "TEXT_LOG_RETENTION_SECS";
 
 /// Log config group.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Args)]
@@ -39,6 +41,14 @@ pub struct LogConfig {
     /// Log file dir
     #[clap(long, env = LOG_QUERY_ENABLED)]
     pub log_query_enabled: bool,
+
+    /// Max rows kept in system.text_log
+    #[clap(long, env = TEXT_LOG_MAX_ROWS, default_value = "100000")]
+    pub text_log_max_rows: u64,
+
+    /// Seconds the rows of system.text_log are kept
+    #[clap(long, env = TEXT_LOG_RETENTION_SECS, default_value = "86400")]
+    pub text_log_retention_secs: u64,
 }
 
 impl Default for LogConfig {
@@ -47,6 +57,8 @@ impl Default for LogConfig {
             log_level: "INFO".to_string(),
             log_dir: "./_logs".to_string(),
             log_query_enabled: false,
+            text_log_max_rows: 100000,
+            text_log_retention_secs: 86400,
         }
     }
 }
@@ -56,5 +68,13 @@ impl LogConfig {
         env_helper!(mut_config, log, log_level, String, LOG_LEVEL);
         env_helper!(mut_config, log, log_dir, String, LOG_DIR);
         env_helper!(mut_config, log, log_query_enabled, bool, LOG_QUERY_ENABLED);
+        env_helper!(mut_config, log, text_log_max_rows, u64, TEXT_LOG_MAX_ROWS);
+        env_helper!(
+            mut_config,
+            log,
+            text_log_retention_secs,
+            u64,
+            TEXT_LOG_RETENTION_SECS
+        );
     }
 }
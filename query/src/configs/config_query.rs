@@ -65,6 +65,15 @@ const QUERY_DATABASE_ENGINE_GITHUB_ENABLED: &str = "QUERY_DATABASE_ENGINE_GITHUB
 
 const QUERY_MANAGEMENT_MODE: &str = "QUERY_MANAGEMENT_MODE";
 const QUERY_JWT_KEY_FILE: &str = "QUERY_JWT_KEY_FILE";
+const QUERY_LDAP_URL: &str = "QUERY_LDAP_URL";
+const QUERY_LDAP_BIND_DN_TEMPLATE: &str = "QUERY_LDAP_BIND_DN_TEMPLATE";
+const QUERY_OUTFILE_ALLOWED_DIRS: &str = "QUERY_OUTFILE_ALLOWED_DIRS";
+const QUERY_PLAN_CACHE_COUNT: &str = "QUERY_PLAN_CACHE_COUNT";
+const QUERY_RESULT_CACHE_BYTES: &str = "QUERY_RESULT_CACHE_BYTES";
+const QUERY_NODE_TAGS: &str = "QUERY_NODE_TAGS";
+const QUERY_NODE_ROUTING_RULES: &str = "QUERY_NODE_ROUTING_RULES";
+const QUERY_CONSISTENCY_CHECK_INTERVAL_SECS: &str = "QUERY_CONSISTENCY_CHECK_INTERVAL_SECS";
+const QUERY_CONSISTENCY_CHECK_RETENTION_SECS: &str = "QUERY_CONSISTENCY_CHECK_RETENTION_SECS";
 
 /// Query config group.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Args)]
@@ -217,6 +226,47 @@ pub struct QueryConfig {
 
     #[clap(long, env = QUERY_JWT_KEY_FILE, default_value = "")]
     pub jwt_key_file: String,
+
+    /// LDAP server for the users identified with ldap, e.g. ldaps://ldap.example.com:636
+    #[clap(long, env = QUERY_LDAP_URL, default_value = "")]
+    pub ldap_url: String,
+
+    /// DN to bind as, `{user}` is replaced by the user name,
+    /// e.g. uid={user},ou=people,dc=example,dc=com or {user}@corp.example.com for AD
+    #[clap(long, env = QUERY_LDAP_BIND_DN_TEMPLATE, default_value = "")]
+    pub ldap_bind_dn_template: String,
+
+    /// Comma separated directories `SELECT ... INTO OUTFILE` can write to, empty disables it.
+    #[clap(long, env = QUERY_OUTFILE_ALLOWED_DIRS, default_value = "")]
+    pub outfile_allowed_dirs: String,
+
+    /// Max number of the SELECT plans cached, 0 disables the plan cache.
+    #[clap(long, env = QUERY_PLAN_CACHE_COUNT, default_value = "0")]
+    pub plan_cache_count: u64,
+
+    /// Max bytes of the query results cached, 0 disables the result cache.
+    /// The sessions enable it by the `enable_query_result_cache` setting.
+    #[clap(long, env = QUERY_RESULT_CACHE_BYTES, default_value = "67108864")]
+    pub result_cache_bytes: u64,
+
+    /// Comma separated tags of this node, e.g. ingest,etl, the routing rules pick the nodes by them.
+    #[clap(long, env = QUERY_NODE_TAGS, default_value = "")]
+    pub node_tags: String,
+
+    /// Comma separated rules `user:<name>=<tag>`, `role:<name>=<tag>` or `statement:<kind>=<tag>`,
+    /// the distributed tasks of a matched query only run on the nodes with the tag.
+    /// The statement kinds are select, insert and other, COPY runs on the node receiving it only.
+    #[clap(long, env = QUERY_NODE_ROUTING_RULES, default_value = "")]
+    pub node_routing_rules: String,
+
+    /// Seconds between the checks that the objects referenced by the table snapshots exist
+    /// in the storage, 0 disables the checks. The findings are in `system.consistency_findings`.
+    #[clap(long, env = QUERY_CONSISTENCY_CHECK_INTERVAL_SECS, default_value = "0")]
+    pub consistency_check_interval_secs: u64,
+
+    /// Seconds an object of a table stays unreferenced by the snapshots before it is reported.
+    #[clap(long, env = QUERY_CONSISTENCY_CHECK_RETENTION_SECS, default_value = "86400")]
+    pub consistency_check_retention_secs: u64,
 }
 
 impl Default for QueryConfig {
@@ -261,6 +311,15 @@ impl Default for QueryConfig {
             table_disk_cache_mb_size: 1024,
             management_mode: false,
             jwt_key_file: "".to_string(),
+            ldap_url: "".to_string(),
+            ldap_bind_dn_template: "".to_string(),
+            outfile_allowed_dirs: "".to_string(),
+            plan_cache_count: 0,
+            result_cache_bytes: 64 * 1024 * 1024,
+            node_tags: "".to_string(),
+            node_routing_rules: "".to_string(),
+            consistency_check_interval_secs: 0,
+            consistency_check_retention_secs: 86400,
         }
     }
 }
@@ -508,5 +567,56 @@ impl QueryConfig {
             QUERY_MANAGEMENT_MODE
         );
         env_helper!(mut_config, query, management_mode, bool, QUERY_JWT_KEY_FILE);
+        env_helper!(mut_config, query, ldap_url, String, QUERY_LDAP_URL);
+        env_helper!(
+            mut_config,
+            query,
+            ldap_bind_dn_template,
+            String,
+            QUERY_LDAP_BIND_DN_TEMPLATE
+        );
+        env_helper!(
+            mut_config,
+            query,
+            outfile_allowed_dirs,
+            String,
+            QUERY_OUTFILE_ALLOWED_DIRS
+        );
+        env_helper!(
+            mut_config,
+            query,
+            plan_cache_count,
+            u64,
+            QUERY_PLAN_CACHE_COUNT
+        );
+        env_helper!(
+            mut_config,
+            query,
+            result_cache_bytes,
+            u64,
+            QUERY_RESULT_CACHE_BYTES
+        );
+        env_helper!(mut_config, query, node_tags, String, QUERY_NODE_TAGS);
+        env_helper!(
+            mut_config,
+            query,
+            node_routing_rules,
+            String,
+            QUERY_NODE_ROUTING_RULES
+        );
+        env_helper!(
+            mut_config,
+            query,
+            consistency_check_interval_secs,
+            u64,
+            QUERY_CONSISTENCY_CHECK_INTERVAL_SECS
+        );
+        env_helper!(
+            mut_config,
+            query,
+            consistency_check_retention_secs,
+            u64,
+            QUERY_CONSISTENCY_CHECK_RETENTION_SECS
+        );
     }
 }
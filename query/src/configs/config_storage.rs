@@ -39,6 +39,9 @@ const S3_STORAGE_ENABLE_POD_IAM_POLICY: &str = "S3_STORAGE_ENABLE_POD_IAM_POLICY
 const S3_STORAGE_BUCKET: &str = "S3_STORAGE_BUCKET";
 const S3_STORAGE_ROOT: &str = "S3_STORAGE_ROOT";
 
+// Storage encryption env.
+const STORAGE_ENCRYPTION_KEY: &str = "STORAGE_ENCRYPTION_KEY";
+
 // Azure Storage Blob env.
 const AZURE_STORAGE_ACCOUNT: &str = "AZURE_STORAGE_ACCOUNT";
 const AZURE_BLOB_MASTER_KEY: &str = "AZURE_BLOB_MASTER_KEY";
@@ -186,6 +189,35 @@ impl fmt::Debug for AzureStorageBlobConfig {
     }
 }
 
+#[derive(Clone, PartialEq, Serialize, Deserialize, Args)]
+#[serde(default)]
+pub struct StorageEncryptionConfig {
+    /// Base64 encoded 256-bit key for the client-side AES-GCM encryption of table meta and
+    /// blocks, empty means no encryption
+    #[clap(long, env = STORAGE_ENCRYPTION_KEY, default_value = "")]
+    pub encryption_key: String,
+}
+
+impl Default for StorageEncryptionConfig {
+    fn default() -> Self {
+        Self {
+            encryption_key: "".to_string(),
+        }
+    }
+}
+
+impl fmt::Debug for StorageEncryptionConfig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{{")?;
+        write!(
+            f,
+            "storage.encryption.encryption_key: \"{}\", ",
+            mask_string(&self.encryption_key[..], 3)
+        )?;
+        write!(f, "}}")
+    }
+}
+
 /// Storage config group.
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Args)]
@@ -209,6 +241,10 @@ pub struct StorageConfig {
     // azure storage blob config.
     #[clap(flatten)]
     pub azure_storage_blob: AzureStorageBlobConfig,
+
+    // Storage encryption config.
+    #[clap(flatten)]
+    pub encryption: StorageEncryptionConfig,
 }
 
 impl Default for StorageConfig {
@@ -218,6 +254,7 @@ impl Default for StorageConfig {
             disk: DiskStorageConfig::default(),
             s3: S3StorageConfig::default(),
             azure_storage_blob: AzureStorageBlobConfig::default(),
+            encryption: StorageEncryptionConfig::default(),
             storage_num_cpus: 0,
         }
     }
@@ -286,5 +323,14 @@ impl StorageConfig {
             String,
             AZURE_BLOB_MASTER_KEY
         );
+
+        // Encryption.
+        env_helper!(
+            mut_config.storage,
+            encryption,
+            encryption_key,
+            String,
+            STORAGE_ENCRYPTION_KEY
+        );
     }
 }
@@ -30,3 +30,4 @@ pub use config_storage::AzureStorageBlobConfig;
 pub use config_storage::DiskStorageConfig;
 pub use config_storage::S3StorageConfig;
 pub use config_storage::StorageConfig;
+pub use config_storage::StorageEncryptionConfig;
@@ -49,6 +49,13 @@ impl SystemDatabase {
             Arc::new(system::QueryLogTable::create(sys_db_meta.next_id())),
             system::EnginesTable::create(sys_db_meta.next_id()),
             system::RolesTable::create(sys_db_meta.next_id()),
+            system::AsyncInsertsTable::create(sys_db_meta.next_id()),
+            system::CopyHistoryTable::create(sys_db_meta.next_id()),
+            system::TextLogTable::create(sys_db_meta.next_id()),
+            system::ClusterProcessesTable::create(sys_db_meta.next_id()),
+            system::ClusterMetricsTable::create(sys_db_meta.next_id()),
+            system::TableSizesTable::create(sys_db_meta.next_id()),
+            system::ConsistencyFindingsTable::create(sys_db_meta.next_id()),
         ];
 
         for tbl in table_list.into_iter() {
@@ -15,7 +15,11 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_meta_types::DropTableReq;
 use common_meta_types::GrantObject;
+use common_meta_types::SwapTableReq;
+use common_tracing::tracing;
+use uuid::Uuid;
 
 use crate::catalogs::Catalog;
 use crate::sessions::QueryContext;
@@ -55,3 +59,70 @@ pub async fn validate_grant_object_exists(
 
     Ok(())
 }
+
+/// The name a `CREATE OR REPLACE` builds the new table or view under, before it is swapped in.
+pub fn replace_staging_table_name(table: &str) -> String {
+    format!("__replace_{}_{}", table, Uuid::new_v4().to_simple())
+}
+
+/// Atomically put the fully built staging table in place of `table`, then drop the replaced one,
+/// which lives under the staging name after the swap.
+///
+/// Queries on `table` observe either the old or the new object, never a missing one.
+/// The staging table is dropped if the swap fails.
+pub async fn swap_in_staging_table(
+    ctx: &Arc<QueryContext>,
+    db: &str,
+    table: &str,
+    staging_table: &str,
+) -> Result<()> {
+    let tenant = ctx.get_tenant();
+    let catalog = ctx.get_catalog();
+
+    let swapped = catalog
+        .swap_table(SwapTableReq {
+            if_exists: false,
+            tenant: tenant.clone(),
+            db: db.to_string(),
+            table_name: table.to_string(),
+            other_db: db.to_string(),
+            other_table_name: staging_table.to_string(),
+        })
+        .await;
+    if let Err(cause) = swapped {
+        drop_staging_table(ctx, db, staging_table).await;
+        return Err(cause);
+    }
+
+    let replaced = catalog.get_table(&tenant, db, staging_table).await?;
+    catalog
+        .drop_table(DropTableReq {
+            if_exists: true,
+            tenant,
+            db: db.to_string(),
+            table: staging_table.to_string(),
+        })
+        .await?;
+
+    // Purge the data of the replaced table, the same as `DROP TABLE` does.
+    let keep_last_snapshot = false;
+    replaced.optimize(ctx.clone(), keep_last_snapshot).await
+}
+
+/// Best effort removal of a staging table left by a failed `CREATE OR REPLACE`.
+pub async fn drop_staging_table(ctx: &Arc<QueryContext>, db: &str, staging_table: &str) {
+    let req = DropTableReq {
+        if_exists: true,
+        tenant: ctx.get_tenant(),
+        db: db.to_string(),
+        table: staging_table.to_string(),
+    };
+    if let Err(cause) = ctx.get_catalog().drop_table(req).await {
+        tracing::warn!(
+            "failed to drop staging table {}.{}: {}",
+            db,
+            staging_table,
+            cause
+        );
+    }
+}
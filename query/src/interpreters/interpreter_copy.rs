@@ -12,13 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::path::Path;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::Duration;
 
+use chrono::Utc;
 use common_datablocks::DataBlock;
+use common_datablocks::SortColumnDescription;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_io::prelude::S3File;
+use common_meta_types::CopiedFileInfo;
 use common_planners::CopyPlan;
 use common_planners::PlanNode;
 use common_planners::ReadDataSourcePlan;
@@ -35,7 +42,13 @@ use crate::interpreters::InterpreterPtr;
 use crate::pipelines::new::executor::PipelinePullingExecutor;
 use crate::pipelines::new::QueryPipelineBuilder;
 use crate::sessions::QueryContext;
+use crate::storages::fuse::io::BlockStreamWriter;
+use crate::storages::fuse::DEFAULT_BLOCKS_PER_CLUSTER_KEY_SORT;
+use crate::storages::fuse::DEFAULT_ROW_PER_BLOCK;
+use crate::storages::fuse::FUSE_OPT_KEY_CLUSTER_KEY;
+use crate::storages::fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use crate::storages::StageSource;
+use crate::storages::Table;
 
 pub struct CopyInterpreter {
     ctx: Arc<QueryContext>,
@@ -53,7 +66,8 @@ impl CopyInterpreter {
     // 2. If the plan.files is empty, there are also two case:
     //     2.1 If the path is a file like /path/to/path/file, S3File::list() will return the same file path.
     //     2.2 If the path is a folder, S3File::list() will return all the files in it.
-    async fn list_files(&self) -> Result<Vec<String>> {
+    //     2.3 If the PATTERN is set, S3File::list_matched() will return the matched files in the folder and its sub folders.
+    async fn list_files(&self, pattern: Option<&Regex>) -> Result<Vec<String>> {
         let files = match &self.plan.from.source_info {
             SourceInfo::S3StageSource(table_info) => {
                 let path = &table_info.path;
@@ -64,10 +78,16 @@ impl CopyInterpreter {
                         let new_path = Path::new(path).join(file);
                         files_with_path.push(new_path.to_string_lossy().to_string());
                     }
+                    if let Some(pattern) = pattern {
+                        files_with_path.retain(|file| pattern.is_match(file));
+                    }
                     files_with_path
                 } else {
                     let op = StageSource::get_op(&self.ctx, &table_info.stage_info).await?;
-                    S3File::list(&op, path).await?
+                    match pattern {
+                        Some(pattern) => S3File::list_matched(&op, path, pattern).await?,
+                        None => S3File::list(&op, path).await?,
+                    }
                 };
 
                 Ok(files_with_path)
@@ -81,6 +101,81 @@ impl CopyInterpreter {
         files
     }
 
+    // Get the size of the files, a file copied before is copied again only if its size changed.
+    async fn stat_files(&self, files: &[String]) -> Result<Vec<u64>> {
+        match &self.plan.from.source_info {
+            SourceInfo::S3StageSource(table_info) => {
+                let op = StageSource::get_op(&self.ctx, &table_info.stage_info).await?;
+                let mut sizes = Vec::with_capacity(files.len());
+                for file in files {
+                    let meta = op.object(file).metadata().await?;
+                    sizes.push(meta.content_length());
+                }
+                Ok(sizes)
+            }
+            other => Err(ErrorCode::LogicalError(format!(
+                "Cannot stat files for the source info: {:?}",
+                other
+            ))),
+        }
+    }
+
+    fn new_copied_file(
+        &self,
+        file_name: String,
+        size: u64,
+        rows_loaded: u64,
+        error: Option<String>,
+    ) -> CopiedFileInfo {
+        let now = Utc::now();
+        CopiedFileInfo {
+            database: self.plan.db_name.clone(),
+            table: self.plan.tbl_name.clone(),
+            table_id: self.plan.tbl_id,
+            file_name,
+            size,
+            rows_loaded,
+            error,
+            first_loaded_on: now,
+            last_loaded_on: now,
+        }
+    }
+
+    // The sort of the loaded blocks by the cluster key of the table, so each written block covers
+    // a narrow range of the key and is well pruned by its min/max statistics.
+    // The key is cut at its first column not loaded by the COPY, None if nothing is left.
+    fn cluster_key_sort(&self, table: &dyn Table) -> Result<Option<Vec<SortColumnDescription>>> {
+        let cluster_key = match table.options().get(FUSE_OPT_KEY_CLUSTER_KEY) {
+            Some(cluster_key) if self.plan.sort_by_cluster_key => cluster_key,
+            _ => return Ok(None),
+        };
+
+        let table_schema = table.schema();
+        let mut sort_columns = vec![];
+        for column_name in cluster_key.split(',').map(|v| v.trim()) {
+            if !table_schema.has_field(column_name) {
+                return Err(ErrorCode::BadOption(format!(
+                    "Unknown column {} in the {} of the table {}",
+                    column_name, FUSE_OPT_KEY_CLUSTER_KEY, self.plan.tbl_name
+                )));
+            }
+            if !self.plan.schema.has_field(column_name) {
+                break;
+            }
+            sort_columns.push(SortColumnDescription {
+                column_name: column_name.to_string(),
+                asc: true,
+                nulls_first: false,
+                case_insensitive: false,
+            });
+        }
+
+        Ok(match sort_columns.is_empty() {
+            true => None,
+            false => Some(sort_columns),
+        })
+    }
+
     // Rewrite the ReadDataSourcePlan.S3StageSource.file_name to new file name.
     fn rewrite_read_plan_file_name(
         mut plan: ReadDataSourcePlan,
@@ -100,9 +195,13 @@ impl CopyInterpreter {
     // Note:
     //  We parse the `s3://` to ReadSourcePlan instead of to a SELECT plan is that:
     //  COPY should deal with the file one by one and do some error handler on the OnError strategy.
+    // Returns the append operations and the number of rows read from the file.
 
     #[tracing::instrument(level = "debug", name = "copy_one_file_to_table", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
-    async fn copy_one_file_to_table(&self, file_name: Option<String>) -> Result<Vec<DataBlock>> {
+    async fn copy_one_file_to_table(
+        &self,
+        file_name: Option<String>,
+    ) -> Result<(Vec<DataBlock>, u64)> {
         let ctx = self.ctx.clone();
         let settings = self.ctx.get_settings();
 
@@ -118,21 +217,52 @@ impl CopyInterpreter {
         let pipeline_builder = QueryPipelineBuilder::create(ctx.clone());
         let mut pipeline = pipeline_builder.finalize(&from_plan)?;
         pipeline.set_max_threads(settings.get_max_threads()? as usize);
+        pipeline.set_stall_timeout(
+            Duration::from_secs(settings.get_executor_stall_timeout_secs()?),
+            settings.get_executor_abort_on_stall()? != 0,
+        );
 
         let async_runtime = ctx.get_storage_runtime();
         let executor = PipelinePullingExecutor::try_create(async_runtime, pipeline)?;
-        let source_stream = Box::pin(ProcessorExecutorStream::create(executor)?);
-
+        ctx.attach_executor(&executor.get_inner());
         let table = ctx
             .get_table(&self.plan.db_name, &self.plan.tbl_name)
             .await?;
+        let sort_columns = self.cluster_key_sort(table.as_ref())?;
+
+        let rows = Arc::new(AtomicU64::new(0));
+        let counter = rows.clone();
+        let source_stream: SendableDataBlockStream = Box::pin(
+            ProcessorExecutorStream::create(executor)?.map_ok(move |block| {
+                counter.fetch_add(block.num_rows() as u64, Ordering::Relaxed);
+                block
+            }),
+        );
+        // The rows of several written blocks are sorted together, then split into the blocks.
+        let source_stream = match sort_columns {
+            Some(sort_columns) => {
+                let row_per_block = table
+                    .options()
+                    .get(FUSE_OPT_KEY_ROW_PER_BLOCK)
+                    .and_then(|v| v.parse::<usize>().ok())
+                    .unwrap_or(DEFAULT_ROW_PER_BLOCK);
+                BlockStreamWriter::sort_block_stream(
+                    source_stream,
+                    row_per_block,
+                    DEFAULT_BLOCKS_PER_CLUSTER_KEY_SORT,
+                    sort_columns,
+                )
+            }
+            None => source_stream,
+        };
+
         let operations = table
             .append_data(ctx.clone(), source_stream)
             .await?
             .try_collect()
             .await?;
 
-        Ok(operations)
+        Ok((operations, rows.load(Ordering::Relaxed)))
     }
 }
 
@@ -147,32 +277,55 @@ impl Interpreter for CopyInterpreter {
         &self,
         mut _input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
-        let mut files = self.list_files().await?;
-
         // Pattern match check.
         let pattern = &self.plan.pattern;
-        if !pattern.is_empty() {
-            let regex = Regex::new(pattern).map_err(|e| {
+        let regex = match pattern.is_empty() {
+            true => None,
+            false => Some(Regex::new(pattern).map_err(|e| {
                 ErrorCode::SyntaxException(format!(
                     "Pattern format invalid, got:{}, error:{:?}",
                     pattern, e
                 ))
-            })?;
-
-            let matched_files = files
-                .iter()
-                .filter(|file| regex.is_match(file))
-                .cloned()
-                .collect();
-            files = matched_files;
-        }
+            })?),
+        };
+        let files = self.list_files(regex.as_ref()).await?;
 
         tracing::info!("copy file list:{:?}, pattern:{}", &files, pattern,);
 
+        // Files loaded before are skipped unless FORCE = TRUE, this makes a retried COPY idempotent.
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = self.ctx.get_user_manager();
+        let sizes = self.stat_files(&files).await?;
+        let copied_files: HashMap<String, CopiedFileInfo> = user_mgr
+            .get_table_copied_files(&tenant, self.plan.tbl_id)
+            .await?
+            .into_iter()
+            .map(|info| (info.file_name.clone(), info))
+            .collect();
+
         let mut write_results = vec![];
-        for file in files {
-            let result = self.copy_one_file_to_table(Some(file)).await?;
-            write_results.extend_from_slice(result.as_slice());
+        let mut loaded_files = vec![];
+        for (file, size) in files.into_iter().zip(sizes) {
+            if !self.plan.force {
+                if let Some(info) = copied_files.get(&file) {
+                    if info.is_loaded(size) {
+                        tracing::info!("skip the copied file:{}", file);
+                        continue;
+                    }
+                }
+            }
+
+            match self.copy_one_file_to_table(Some(file.clone())).await {
+                Ok((result, rows)) => {
+                    write_results.extend_from_slice(result.as_slice());
+                    loaded_files.push(self.new_copied_file(file, size, rows, None));
+                }
+                Err(cause) => {
+                    let failed = self.new_copied_file(file, size, 0, Some(cause.message()));
+                    user_mgr.upsert_copied_file(&tenant, failed).await?;
+                    return Err(cause);
+                }
+            }
         }
 
         let table = self
@@ -185,6 +338,11 @@ impl Interpreter for CopyInterpreter {
             .commit_insertion(self.ctx.clone(), write_results, false)
             .await?;
 
+        // Record the files only after the commit, the files of a failed commit are copied again.
+        for info in loaded_files {
+            user_mgr.upsert_copied_file(&tenant, info).await?;
+        }
+
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
             None,
@@ -25,6 +25,7 @@ use crate::catalogs::Catalog;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::QueryContext;
+use crate::sql::SQLCommon;
 
 pub struct ShowCreateDatabaseInterpreter {
     ctx: Arc<QueryContext>,
@@ -54,7 +55,7 @@ impl Interpreter for ShowCreateDatabaseInterpreter {
         let calalog = self.ctx.get_catalog();
         let db = calalog.get_database(tenant.as_str(), &self.plan.db).await?;
         let name = db.name();
-        let mut info = format!("CREATE DATABASE `{}`", name);
+        let mut info = format!("CREATE DATABASE {}", SQLCommon::quote_identifier(name));
         if !db.engine().is_empty() {
             let engine = format!(" ENGINE={}", db.engine().to_uppercase());
             let engine_options = db
@@ -19,6 +19,8 @@ use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_planners::ExplainPlan;
 use common_planners::ExplainType;
+use common_planners::PlanNodeGraphvizVisitor;
+use common_planners::PlanNodeJsonVisitor;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 
@@ -48,8 +50,10 @@ impl Interpreter for ExplainInterpreter {
 
         let block = match self.explain.typ {
             ExplainType::Graph => self.explain_graph(),
+            ExplainType::Graphviz => self.explain_graphviz(),
             ExplainType::Syntax => self.explain_syntax(),
             ExplainType::Pipeline => self.explain_pipeline(),
+            ExplainType::Json => self.explain_json(),
         }?;
 
         Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
@@ -71,8 +75,9 @@ impl ExplainInterpreter {
             Optimizers::create(self.ctx.clone()),
             &self.explain.input,
         )?;
+        let with_schema = self.ctx.get_settings().get_explain_graphviz_with_schema()? != 0;
         let formatted_plan = Series::from_data(
-            format!("{}", plan.display_graphviz())
+            format!("{}", plan.display_graphviz(with_schema))
                 .lines()
                 .map(|s| s.as_bytes())
                 .collect::<Vec<_>>(),
@@ -80,6 +85,18 @@ impl ExplainInterpreter {
         Ok(DataBlock::create(schema, vec![formatted_plan]))
     }
 
+    fn explain_graphviz(&self) -> Result<DataBlock> {
+        let schema = self.schema();
+        let plan = plan_schedulers::apply_plan_rewrite(
+            Optimizers::create(self.ctx.clone()),
+            &self.explain.input,
+        )?;
+        let with_schema = self.ctx.get_settings().get_explain_graphviz_with_schema()? != 0;
+        let formatted_plan =
+            Series::from_data(vec![PlanNodeGraphvizVisitor::to_dot(&plan, with_schema)?]);
+        Ok(DataBlock::create(schema, vec![formatted_plan]))
+    }
+
     fn explain_syntax(&self) -> Result<DataBlock> {
         let schema = self.schema();
         let plan = plan_schedulers::apply_plan_rewrite(
@@ -95,6 +112,16 @@ impl ExplainInterpreter {
         Ok(DataBlock::create(schema, vec![formatted_plan]))
     }
 
+    fn explain_json(&self) -> Result<DataBlock> {
+        let schema = self.schema();
+        let plan = plan_schedulers::apply_plan_rewrite(
+            Optimizers::create(self.ctx.clone()),
+            &self.explain.input,
+        )?;
+        let formatted_plan = Series::from_data(vec![PlanNodeJsonVisitor::to_json_string(&plan)?]);
+        Ok(DataBlock::create(schema, vec![formatted_plan]))
+    }
+
     fn explain_pipeline(&self) -> Result<DataBlock> {
         let schema = self.schema();
         let optimizer = Optimizers::without_scatters(self.ctx.clone());
@@ -29,6 +29,7 @@ use crate::interpreters::interpreter_table_rename::RenameTableInterpreter;
 use crate::interpreters::AlterUserInterpreter;
 use crate::interpreters::AlterUserUDFInterpreter;
 use crate::interpreters::CallInterpreter;
+use crate::interpreters::CommentTableInterpreter;
 use crate::interpreters::CopyInterpreter;
 use crate::interpreters::CreateDatabaseInterpreter;
 use crate::interpreters::CreateRoleInterpreter;
@@ -36,7 +37,9 @@ use crate::interpreters::CreateTableInterpreter;
 use crate::interpreters::CreateUserInterpreter;
 use crate::interpreters::CreateUserUDFInterpreter;
 use crate::interpreters::CreateViewInterpreter;
+use crate::interpreters::DeallocateInterpreter;
 use crate::interpreters::DescribeTableInterpreter;
+use crate::interpreters::DrainNodeInterpreter;
 use crate::interpreters::DropDatabaseInterpreter;
 use crate::interpreters::DropRoleInterpreter;
 use crate::interpreters::DropTableInterpreter;
@@ -51,9 +54,11 @@ use crate::interpreters::InterceptorInterpreter;
 use crate::interpreters::Interpreter;
 use crate::interpreters::KillInterpreter;
 use crate::interpreters::OptimizeTableInterpreter;
+use crate::interpreters::PrepareInterpreter;
 use crate::interpreters::RevokePrivilegeInterpreter;
 use crate::interpreters::RevokeRoleInterpreter;
 use crate::interpreters::SelectInterpreter;
+use crate::interpreters::SelectIntoOutfileInterpreter;
 use crate::interpreters::SettingInterpreter;
 use crate::interpreters::ShowCreateDatabaseInterpreter;
 use crate::interpreters::ShowCreateTableInterpreter;
@@ -66,6 +71,7 @@ use crate::interpreters::ShowRolesInterpreter;
 use crate::interpreters::ShowSettingsInterpreter;
 use crate::interpreters::ShowTablesInterpreter;
 use crate::interpreters::ShowUsersInterpreter;
+use crate::interpreters::SwapTableInterpreter;
 use crate::interpreters::TruncateTableInterpreter;
 use crate::interpreters::UseDatabaseInterpreter;
 use crate::sessions::QueryContext;
@@ -74,10 +80,15 @@ pub struct InterpreterFactory;
 
 impl InterpreterFactory {
     pub fn get(ctx: Arc<QueryContext>, plan: PlanNode) -> Result<Arc<dyn Interpreter>> {
+        ctx.apply_node_routing(&plan);
+
         let ctx_clone = ctx.clone();
         let inner = match plan.clone() {
             // Query.
             PlanNode::Select(v) => SelectInterpreter::try_create(ctx_clone, v),
+            PlanNode::SelectIntoOutfile(v) => {
+                SelectIntoOutfileInterpreter::try_create(ctx_clone, v)
+            }
 
             // Select.
             PlanNode::Explain(v) => ExplainInterpreter::try_create(ctx_clone, v),
@@ -134,6 +145,8 @@ impl InterpreterFactory {
             PlanNode::CreateTable(v) => CreateTableInterpreter::try_create(ctx_clone, v),
             PlanNode::DropTable(v) => DropTableInterpreter::try_create(ctx_clone, v),
             PlanNode::RenameTable(v) => RenameTableInterpreter::try_create(ctx_clone, v),
+            PlanNode::SwapTable(v) => SwapTableInterpreter::try_create(ctx_clone, v),
+            PlanNode::CommentTable(v) => CommentTableInterpreter::try_create(ctx_clone, v),
             PlanNode::TruncateTable(v) => TruncateTableInterpreter::try_create(ctx_clone, v),
             PlanNode::OptimizeTable(v) => OptimizeTableInterpreter::try_create(ctx_clone, v),
             PlanNode::DescribeTable(v) => DescribeTableInterpreter::try_create(ctx_clone, v),
@@ -181,9 +194,16 @@ impl InterpreterFactory {
             // Kill.
             PlanNode::Kill(v) => KillInterpreter::try_create(ctx_clone, v),
 
+            // Cluster.
+            PlanNode::DrainNode(v) => DrainNodeInterpreter::try_create(ctx_clone, v),
+
             // Set.
             PlanNode::SetVariable(v) => SettingInterpreter::try_create(ctx_clone, v),
 
+            // Prepared statement.
+            PlanNode::Prepare(v) => PrepareInterpreter::try_create(ctx_clone, v),
+            PlanNode::Deallocate(v) => DeallocateInterpreter::try_create(ctx_clone, v),
+
             _ => Result::Err(ErrorCode::UnknownTypeOfQuery(format!(
                 "Can't get the interpreter by plan:{}",
                 plan.name()
@@ -14,6 +14,8 @@
 
 use std::sync::Arc;
 
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::GrantObject;
@@ -22,6 +24,7 @@ use common_planners::InsertInputSource;
 use common_planners::InsertPlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
 use futures::TryStreamExt;
 
 use super::interpreter_insert_with_stream::SendableWithSchema;
@@ -31,6 +34,9 @@ use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::pipelines::transforms::AddOnStream;
 use crate::sessions::QueryContext;
+use crate::sessions::SessionType;
+use crate::sessions::ASYNC_INSERT_FLUSH_DELAY;
+use crate::storages::Table;
 
 pub struct InsertInterpreter {
     ctx: Arc<QueryContext>,
@@ -69,17 +75,17 @@ impl Interpreter for InsertInterpreter {
             .await?;
 
         let need_fill_missing_columns = table.schema() != self.plan.schema();
+        // INSERT ... SELECT has no data to accept up front, and an overwrite can't be coalesced
+        // with the other inserts into the table, so they always run in place.
+        let async_insert = self.ctx.get_settings().get_async_insert()? != 0 && !self.plan.overwrite;
 
         let append_logs = match &self.plan.source {
             InsertInputSource::SelectPlan(plan_node) => {
                 let with_plan = InsertWithPlan::new(&self.ctx, &self.plan.schema, plan_node);
                 with_plan.execute(table.as_ref()).await
             }
-            InsertInputSource::Expressions(values, values_exprs) => {
-                let block_size = self.ctx.get_settings().get_max_block_size()? as usize;
-                let stream = values
-                    .to_stream(self.plan.schema.clone(), block_size)
-                    .or_else(|_| values_exprs.to_stream(self.plan.schema.clone(), block_size))?;
+            InsertInputSource::Expressions(_) | InsertInputSource::Values(_) => {
+                let stream = self.values_stream()?;
 
                 let stream = if need_fill_missing_columns {
                     Box::pin(AddOnStream::try_create(
@@ -91,6 +97,10 @@ impl Interpreter for InsertInterpreter {
                     stream
                 };
 
+                if async_insert {
+                    return self.async_append(table, stream).await;
+                }
+
                 let with_stream = InsertWithStream::new(&self.ctx, &table);
                 with_stream.append_stream(stream).await
             }
@@ -109,6 +119,10 @@ impl Interpreter for InsertInterpreter {
                     stream
                 };
 
+                if async_insert {
+                    return self.async_append(table, stream).await;
+                }
+
                 let with_stream = InsertWithStream::new(&self.ctx, &table);
                 with_stream.append_stream(stream).await
             }
@@ -129,3 +143,85 @@ impl Interpreter for InsertInterpreter {
         )))
     }
 }
+
+impl InsertInterpreter {
+    /// The blocks of the values in the statement, the literal values are converted into blocks
+    /// one by one while being consumed.
+    fn values_stream(&self) -> Result<SendableDataBlockStream> {
+        let block_size = self.ctx.get_settings().get_max_block_size()? as usize;
+        let schema = self.plan.schema();
+        match &self.plan.source {
+            InsertInputSource::Expressions(values_exprs) => {
+                values_exprs.to_stream(schema, block_size)
+            }
+            InsertInputSource::Values(values) => values.clone().to_stream(schema, block_size),
+            _ => Err(ErrorCode::LogicalError(
+                "Insert source is not values, it's a bug",
+            )),
+        }
+    }
+
+    /// Accept the data of the statement into the batch of the table, the batch is written and
+    /// committed into the table in the background. The returned handle reports the flush status
+    /// in `system.async_inserts`.
+    async fn async_append(
+        &self,
+        table: Arc<dyn Table>,
+        stream: SendableDataBlockStream,
+    ) -> Result<SendableDataBlockStream> {
+        let blocks: Vec<DataBlock> = stream.try_collect().await?;
+
+        let queue = self.ctx.get_async_insert_queue();
+        let database_name = self.plan.database_name.clone();
+        let table_name = self.plan.table_name.clone();
+        let (id, new_batch) = queue.enqueue(&database_name, &table_name, blocks)?;
+
+        // The inserts joining a batch are flushed by the one that started it.
+        if new_batch {
+            // The flush outlives this query, so it runs in a session of its own.
+            let session = self.ctx.get_current_session();
+            let user = session.get_current_user()?;
+            let database = session.get_current_database();
+            let session_mgr = session.get_session_manager();
+            common_base::tokio::spawn(async move {
+                common_base::tokio::time::sleep(ASYNC_INSERT_FLUSH_DELAY).await;
+                let (ids, blocks) = match queue.take_batch(&database_name, &table_name) {
+                    Some(batch) => (batch.ids, batch.blocks),
+                    // Failed by the shutdown already.
+                    None => return,
+                };
+
+                let res: Result<()> = async {
+                    let session = session_mgr.create_session(SessionType::AsyncInsert).await?;
+                    session.set_current_user(user);
+                    session.set_current_database(database);
+                    let ctx = session.create_query_context().await?;
+
+                    let schema = table.schema();
+                    let stream = Box::pin(DataBlockStream::create(schema, None, blocks));
+                    let with_stream = InsertWithStream::new(&ctx, &table);
+                    let append_logs = with_stream.append_stream(stream).await?;
+                    table
+                        .commit_insertion(ctx.clone(), append_logs.try_collect().await?, false)
+                        .await
+                }
+                .await;
+
+                if let Err(cause) = &res {
+                    tracing::warn!(
+                        "Async inserts {:?} into {}.{} failed: {}",
+                        ids,
+                        database_name,
+                        table_name,
+                        cause
+                    );
+                }
+                queue.finish(&ids, res);
+            });
+        }
+
+        let schema = DataSchemaRefExt::create(vec![DataField::new("id", Vu8::to_data_type())]);
+        let block = DataBlock::create(schema.clone(), vec![Series::from_data(vec![id.as_str()])]);
+        Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
+    }
+}
@@ -14,20 +14,28 @@
 //
 
 use std::io;
+use std::io::BufRead;
 use std::io::BufReader;
 
 use common_datablocks::DataBlock;
-use common_datavalues::DataSchemaRef;
+use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::ToErrorCode;
+use common_functions::scalars::cast_with_type;
+use common_functions::scalars::DEFAULT_CAST_OPTIONS;
 use common_io::prelude::BufReadExt;
 
+/// Converts the literal tuples of VALUES, e.g. `(1, 'a'), (2, NULL)`, into blocks of at most
+/// `block_size` rows. The tuples are tokenized from the reader one value at a time, so only
+/// the rows of the block being built are held in memory.
 pub struct ValueSource<R> {
     reader: BufReader<R>,
     schema: DataSchemaRef,
     block_size: usize,
     rows: usize,
+    /// The current value, unquoted and unescaped.
+    value: Vec<u8>,
 }
 
 impl<R> ValueSource<R>
@@ -40,14 +48,11 @@ where R: io::Read + Send + Sync
             block_size,
             schema,
             rows: 0,
+            value: Vec::new(),
         }
     }
 
     fn read(&mut self) -> Result<Option<DataBlock>> {
-        let reader = &mut self.reader;
-        let mut buf = Vec::new();
-        let mut temp = Vec::new();
-
         let mut desers = self
             .schema
             .fields()
@@ -55,64 +60,24 @@ where R: io::Read + Send + Sync
             .map(|f| f.data_type().create_deserializer(self.block_size))
             .collect::<Vec<_>>();
 
-        let col_size = desers.len();
+        // The values not in the text format of the column type, by the column and the row.
+        let mut casted = vec![vec![]; desers.len()];
         let mut rows = 0;
-        for _row in 0..self.block_size {
-            let _ = reader.ignore_spaces()?;
-            if reader.buffer().is_empty() {
-                break;
+        while rows < self.block_size {
+            self.reader.ignore_spaces()?;
+            if self.reader.ignore_byte(b';')? {
+                self.reader.ignore_spaces()?;
             }
-            // not the first row
-            if rows + self.rows != 0 {
-                reader.until(b',', &mut buf)?;
+            if self.reader.working_buffer()?.is_empty() {
+                break;
             }
-            let _ = reader.ignore_spaces()?;
-            let _ = reader.ignore_byte(b'(')?;
-
-            for (col, deser) in desers.iter_mut().enumerate().take(col_size) {
-                buf.clear();
-                let _ = reader.ignore_spaces()?;
-
-                let bs: Result<&[u8]> = {
-                    if reader.ignore_byte(b'\'')? {
-                        reader.until(b'\'', &mut buf)?;
-
-                        let res = &buf.as_slice()[0..buf.len() - 1];
-                        if col != col_size - 1 {
-                            reader.until(b',', &mut temp)?;
-                        } else {
-                            reader.until(b')', &mut temp)?;
-                        }
-                        Ok(res)
-                    } else if reader.ignore_byte(b'"')? {
-                        reader.until(b'"', &mut buf)?;
-
-                        let res = &buf.as_slice()[0..buf.len() - 1];
-                        if col != col_size - 1 {
-                            reader.until(b',', &mut temp)?;
-                        } else {
-                            reader.until(b')', &mut temp)?;
-                        }
-                        Ok(res)
-                    } else if col != col_size - 1 {
-                        reader.until(b',', &mut buf)?;
-                        Ok(&buf.as_slice()[0..buf.len() - 1])
-                    } else {
-                        reader.until(b')', &mut buf)?;
-                        Ok(&buf.as_slice()[0..buf.len() - 1])
-                    }
-                };
-                let bs = bs?;
-                let col_value = std::str::from_utf8(bs)
-                    .map_err_to_code(ErrorCode::BadBytes, || "Cannot convert value to utf8")?;
-                if col_value.to_lowercase() == "null" {
-                    return Err(ErrorCode::BadDataValueType(
-                        "Can not handle null values".to_string(),
-                    ));
-                }
 
-                deser.de_text(bs)?;
+            let row = self.rows + rows + 1;
+            // not the first row
+            if row != 1 {
+                self.expect_byte(b',', row)?;
             }
+            self.read_row(&mut desers, &mut casted, rows, row)?;
             rows += 1;
         }
 
@@ -120,13 +85,166 @@ where R: io::Read + Send + Sync
             return Ok(None);
         }
         self.rows += rows;
-        let series = desers
+        let mut series = desers
             .iter_mut()
             .map(|deser| deser.finish_to_column())
             .collect::<Vec<_>>();
 
+        for ((column, field), casted) in series.iter_mut().zip(self.schema.fields()).zip(casted) {
+            if !casted.is_empty() {
+                let mut values = column.to_values();
+                for (block_row, value) in casted {
+                    values[block_row] = value;
+                }
+                *column = field.data_type().create_column(&values)?;
+            }
+        }
+
         Ok(Some(DataBlock::create(self.schema.clone(), series)))
     }
+
+    fn read_row(
+        &mut self,
+        desers: &mut [Box<dyn TypeDeserializer>],
+        casted: &mut [Vec<(usize, DataValue)>],
+        block_row: usize,
+        row: usize,
+    ) -> Result<()> {
+        self.expect_byte(b'(', row)?;
+        for (col, deser) in desers.iter_mut().enumerate() {
+            if col != 0 {
+                self.expect_byte(b',', row)?;
+            }
+
+            let quoted = self.read_value(row)?;
+            let field = self.schema.field(col);
+            if !quoted && self.value.is_empty() {
+                return Err(ErrorCode::BadBytes(format!(
+                    "Missing the value of column {} at row {}",
+                    field.name(),
+                    row
+                )));
+            }
+
+            let deserialized = match !quoted && self.value.eq_ignore_ascii_case(b"null") {
+                true => deser.de_null(),
+                false => deser.de_text(&self.value).is_ok(),
+            };
+            if !deserialized {
+                // Casted like the literal in SQL, e.g. a timestamp from the seconds.
+                let value = self.cast_value(field, quoted).map_err(|cause| {
+                    cause.add_message_back(format!(" (column {} at row {})", field.name(), row))
+                })?;
+                deser.de_default();
+                casted[col].push((block_row, value));
+            }
+        }
+        self.expect_byte(b')', row)
+    }
+
+    fn cast_value(&self, field: &DataField, quoted: bool) -> Result<DataValue> {
+        let value = if quoted {
+            DataValue::String(self.value.clone())
+        } else if self.value.eq_ignore_ascii_case(b"null") {
+            DataValue::Null
+        } else if self.value.eq_ignore_ascii_case(b"true") {
+            DataValue::Boolean(true)
+        } else if self.value.eq_ignore_ascii_case(b"false") {
+            DataValue::Boolean(false)
+        } else {
+            let literal = std::str::from_utf8(&self.value)
+                .map_err_to_code(ErrorCode::BadBytes, || "Cannot convert value to utf8")?;
+            DataValue::try_from_literal(literal, None)?
+        };
+
+        let from_type = value.data_type();
+        let column = from_type.create_constant_column(&value, 1)?;
+        let column = cast_with_type(
+            &column,
+            &from_type,
+            field.data_type(),
+            &DEFAULT_CAST_OPTIONS,
+        )?;
+        Ok(column.get(0))
+    }
+
+    fn expect_byte(&mut self, b: u8, row: usize) -> Result<()> {
+        self.reader.ignore_spaces()?;
+        match self.reader.ignore_byte(b)? {
+            true => Ok(()),
+            false => Err(ErrorCode::BadBytes(format!(
+                "Expected '{}' at row {} of the values",
+                b as char, row
+            ))),
+        }
+    }
+
+    // Reads the next value into `self.value`, returns whether it was quoted.
+    fn read_value(&mut self, row: usize) -> Result<bool> {
+        self.value.clear();
+        self.reader.ignore_spaces()?;
+
+        let quote = match self.reader.working_buffer()?.first().copied() {
+            Some(quote @ (b'\'' | b'"')) => quote,
+            _ => {
+                // A number, boolean or NULL ends at the next delimiter.
+                loop {
+                    let buf = self.reader.working_buffer()?;
+                    let len = buf
+                        .iter()
+                        .position(|c| matches!(c, b',' | b')') || c.is_ascii_whitespace())
+                        .unwrap_or(buf.len());
+                    let end = len < buf.len() || buf.is_empty();
+                    self.value.extend_from_slice(&buf[..len]);
+                    self.reader.consume(len);
+                    if end {
+                        return Ok(false);
+                    }
+                }
+            }
+        };
+
+        self.reader.consume(1);
+        loop {
+            let buf = self.reader.working_buffer()?;
+            let len = match buf.iter().position(|c| *c == quote || *c == b'\\') {
+                Some(len) => len,
+                None if buf.is_empty() => {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "Unterminated string at row {} of the values",
+                        row
+                    )));
+                }
+                None => {
+                    self.value.extend_from_slice(buf);
+                    let len = buf.len();
+                    self.reader.consume(len);
+                    continue;
+                }
+            };
+
+            let escape = buf[len] == b'\\';
+            self.value.extend_from_slice(&buf[..len]);
+            self.reader.consume(len + 1);
+            if escape {
+                let c = match self.reader.working_buffer()?.first().copied() {
+                    Some(b'n') => b'\n',
+                    Some(b't') => b'\t',
+                    Some(b'r') => b'\r',
+                    Some(b'0') => b'\0',
+                    Some(c) => c,
+                    None => continue,
+                };
+                self.value.push(c);
+                self.reader.consume(1);
+            } else if self.reader.ignore_byte(quote)? {
+                // A doubled quote stands for the quote itself.
+                self.value.push(quote);
+            } else {
+                return Ok(true);
+            }
+        }
+    }
 }
 
 impl<R> Iterator for ValueSource<R>
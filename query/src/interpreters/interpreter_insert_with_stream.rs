@@ -20,7 +20,6 @@ use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_planners::Expression;
-use common_streams::DataBlockStream;
 use common_streams::ProgressStream;
 use common_streams::SendableDataBlockStream;
 
@@ -87,18 +86,13 @@ impl SendableWithSchema for &[Vec<Expression>] {
     }
 }
 
-impl SendableWithSchema for &str {
+impl SendableWithSchema for String {
     fn to_stream(
         self,
         schema: Arc<DataSchema>,
         max_block_size: usize,
     ) -> Result<SendableDataBlockStream> {
-        let value_source = ValueSource::new(Cursor::new(self), schema.clone(), max_block_size);
-        let data_blocks = value_source
-            .into_iter()
-            .collect::<Result<Vec<DataBlock>>>()?;
-        let stream = Box::pin(DataBlockStream::create(schema, None, data_blocks));
-
-        Ok(stream)
+        let value_source = ValueSource::new(Cursor::new(self.into_bytes()), schema, max_block_size);
+        Ok(Box::pin(futures::stream::iter(value_source)))
     }
 }
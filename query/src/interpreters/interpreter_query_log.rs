@@ -195,7 +195,7 @@ impl InterpreterQueryLog {
         // Query.
         let query_id = self.ctx.get_id();
         let query_kind = self.plan.name().to_string();
-        let query_text = self.ctx.get_query_str();
+        let query_text = self.ctx.get_query_str_for_log()?;
         // Schema.
         let current_database = self.ctx.get_current_database();
 
@@ -298,7 +298,7 @@ impl InterpreterQueryLog {
         // Query.
         let query_id = self.ctx.get_id();
         let query_kind = self.plan.name().to_string();
-        let query_text = self.ctx.get_query_str();
+        let query_text = self.ctx.get_query_str_for_log()?;
 
         // Stats.
         let event_time = now
@@ -320,7 +320,11 @@ impl InterpreterQueryLog {
 
         let scan_partitions = dal_metrics.get_partitions_scanned();
         let total_partitions = dal_metrics.get_partitions_total();
-        let cpu_usage = self.ctx.get_settings().get_max_threads()? as u32;
+        // The effective parallelism, max_threads if the query didn't run a pipeline.
+        let cpu_usage = match self.ctx.get_parallelism() {
+            0 => self.ctx.get_settings().get_max_threads()? as u32,
+            parallelism => parallelism as u32,
+        };
         let memory_usage = self.ctx.get_current_session().get_memory_usage() as u64;
 
         // Result.
@@ -53,7 +53,9 @@ impl Interpreter for CreateRoleInterpreter {
         let tenant = self.ctx.get_tenant();
         let user_mgr = self.ctx.get_user_manager();
         let role_info = RoleInfo::new(plan.role_name);
-        user_mgr.add_role(&tenant, role_info, false).await?;
+        user_mgr
+            .add_role(&tenant, role_info, plan.if_not_exists)
+            .await?;
 
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
@@ -13,11 +13,13 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_datavalues::DataSchemaRef;
 use common_exception::Result;
 use common_planners::PlanNode;
 use common_planners::SelectPlan;
+use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
 
@@ -25,6 +27,7 @@ use crate::interpreters::plan_schedulers;
 use crate::interpreters::stream::ProcessorExecutorStream;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
+use crate::interpreters::ResultCache;
 use crate::optimizers::Optimizers;
 use crate::pipelines::new::executor::PipelinePullingExecutor;
 use crate::pipelines::new::NewPipeline;
@@ -42,29 +45,29 @@ impl SelectInterpreter {
     }
 
     fn rewrite_plan(&self) -> Result<PlanNode> {
-        plan_schedulers::apply_plan_rewrite(
+        let optimized_plan = plan_schedulers::apply_plan_rewrite(
             Optimizers::create(self.ctx.clone()),
             &self.select.input,
-        )
-    }
-}
+        )?;
 
-#[async_trait::async_trait]
-impl Interpreter for SelectInterpreter {
-    fn name(&self) -> &str {
-        "SelectInterpreter"
+        plan_schedulers::check_scan_limits(&self.ctx, &optimized_plan)?;
+        Ok(optimized_plan)
     }
 
-    fn schema(&self) -> DataSchemaRef {
-        self.select.schema()
+    // The result cache key of the query, None if the result cache is not used.
+    fn result_cache_key(&self) -> Result<Option<String>> {
+        let settings = self.ctx.get_settings();
+        if settings.get_enable_query_result_cache()? == 0 {
+            return Ok(None);
+        }
+
+        match self.ctx.get_result_cache().is_enabled() {
+            true => ResultCache::key(&self.ctx, &self.rewrite_plan()?),
+            false => Ok(None),
+        }
     }
 
-    #[tracing::instrument(level = "debug", name = "select_interpreter_execute", skip(self, _input_stream), fields(ctx.id = self.ctx.get_id().as_str()))]
-    async fn execute(
-        &self,
-        _input_stream: Option<SendableDataBlockStream>,
-    ) -> Result<SendableDataBlockStream> {
-        // TODO: maybe panic?
+    async fn execute_query(&self) -> Result<SendableDataBlockStream> {
         let settings = self.ctx.get_settings();
 
         if settings.get_enable_new_processor_framework()? != 0 {
@@ -72,6 +75,7 @@ impl Interpreter for SelectInterpreter {
                 let async_runtime = self.ctx.get_storage_runtime();
                 let new_pipeline = self.execute2()?;
                 let executor = PipelinePullingExecutor::try_create(async_runtime, new_pipeline)?;
+                self.ctx.attach_executor(&executor.get_inner());
                 let executor_stream = Box::pin(ProcessorExecutorStream::create(executor)?);
                 return Ok(Box::pin(self.ctx.try_create_abortable(executor_stream)?));
             }
@@ -83,6 +87,41 @@ impl Interpreter for SelectInterpreter {
             plan_schedulers::schedule_query(&self.ctx, &optimized_plan).await
         }
     }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for SelectInterpreter {
+    fn name(&self) -> &str {
+        "SelectInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.select.schema()
+    }
+
+    #[tracing::instrument(level = "debug", name = "select_interpreter_execute", skip(self, _input_stream), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let key = match self.result_cache_key()? {
+            None => return self.execute_query().await,
+            Some(key) => key,
+        };
+
+        let result_cache = self.ctx.get_result_cache();
+        if let Some(blocks) = result_cache.get(&key) {
+            return Ok(Box::pin(DataBlockStream::create(
+                self.select.schema(),
+                None,
+                blocks,
+            )));
+        }
+
+        let max_bytes = self.ctx.get_settings().get_query_result_cache_max_bytes()? as usize;
+        let stream = self.execute_query().await?;
+        Ok(result_cache.cache_stream(key, max_bytes, stream))
+    }
 
     fn execute2(&self) -> Result<NewPipeline> {
         let settings = self.ctx.get_settings();
@@ -94,6 +133,11 @@ impl Interpreter for SelectInterpreter {
         };
         let mut new_pipeline = builder.finalize(&select_plan)?;
         new_pipeline.set_max_threads(settings.get_max_threads()? as usize);
+        new_pipeline.set_stall_timeout(
+            Duration::from_secs(settings.get_executor_stall_timeout_secs()?),
+            settings.get_executor_abort_on_stall()? != 0,
+        );
+        self.ctx.set_parallelism(new_pipeline.get_max_threads());
         Ok(new_pipeline)
     }
 }
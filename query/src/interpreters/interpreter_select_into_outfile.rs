@@ -0,0 +1,317 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io::BufWriter;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::parquet::write::to_parquet_schema;
+use common_arrow::arrow::io::parquet::write::Compression as ParquetCompression;
+use common_arrow::arrow::io::parquet::write::RowGroupIterator;
+use common_arrow::arrow::io::parquet::write::Version;
+use common_arrow::arrow::io::parquet::write::WriteOptions;
+use common_arrow::parquet::encoding::Encoding;
+use common_arrow::parquet::error::ParquetError;
+use common_arrow::parquet::write::FileWriter;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::OutfileCompression;
+use common_planners::OutfileFormat;
+use common_planners::PlanNode;
+use common_planners::SelectIntoOutfilePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use futures::StreamExt;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::interpreters::SelectInterpreter;
+use crate::servers::http::formats::csv_output::block_to_csv;
+use crate::servers::http::formats::tsv_output::block_to_tsv;
+use crate::sessions::QueryContext;
+
+pub struct SelectIntoOutfileInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: SelectIntoOutfilePlan,
+}
+
+impl SelectIntoOutfileInterpreter {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        plan: SelectIntoOutfilePlan,
+    ) -> Result<InterpreterPtr> {
+        Ok(Arc::new(SelectIntoOutfileInterpreter { ctx, plan }))
+    }
+
+    // The file must be in one of the directories of `query.outfile_allowed_dirs`,
+    // symlinks and `..` are resolved before the check.
+    fn check_path(&self) -> Result<PathBuf> {
+        let conf = self.ctx.get_config();
+        let allowed_dirs = conf
+            .query
+            .outfile_allowed_dirs
+            .split(',')
+            .map(|dir| dir.trim())
+            .filter(|dir| !dir.is_empty())
+            .collect::<Vec<_>>();
+        if allowed_dirs.is_empty() {
+            return Err(ErrorCode::PermissionDenied(
+                "INTO OUTFILE is disabled, set query.outfile_allowed_dirs to enable it",
+            ));
+        }
+
+        let path = Path::new(&self.plan.path);
+        let file_name = path.file_name().ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "INTO OUTFILE path {} is not a file",
+                self.plan.path
+            ))
+        })?;
+        let parent = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
+        let path = parent
+            .canonicalize()
+            .map_err(|e| {
+                ErrorCode::BadArguments(format!(
+                    "INTO OUTFILE directory {} is invalid: {}",
+                    parent.display(),
+                    e
+                ))
+            })?
+            .join(file_name);
+
+        for dir in allowed_dirs {
+            if let Ok(dir) = Path::new(dir).canonicalize() {
+                if path.starts_with(&dir) {
+                    return Ok(path);
+                }
+            }
+        }
+
+        Err(ErrorCode::PermissionDenied(format!(
+            "INTO OUTFILE path {} is not in query.outfile_allowed_dirs",
+            self.plan.path
+        )))
+    }
+
+    async fn write_file(&self, file: File) -> Result<u64> {
+        if self.plan.format == OutfileFormat::Parquet {
+            let mut writer = BufWriter::new(file);
+            let rows = self.write_parquet(&mut writer).await?;
+            writer.flush()?;
+            return Ok(rows);
+        }
+
+        match self.plan.compression {
+            OutfileCompression::None => {
+                let mut writer = BufWriter::new(file);
+                let rows = self.write_blocks(&mut writer).await?;
+                writer.flush()?;
+                Ok(rows)
+            }
+            OutfileCompression::Gzip => {
+                let mut writer = GzEncoder::new(BufWriter::new(file), Compression::default());
+                let rows = self.write_blocks(&mut writer).await?;
+                writer.finish()?.flush()?;
+                Ok(rows)
+            }
+            OutfileCompression::Zstd => {
+                let mut writer = zstd::Encoder::new(BufWriter::new(file), 0)?;
+                let rows = self.write_blocks(&mut writer).await?;
+                writer.finish()?.flush()?;
+                Ok(rows)
+            }
+        }
+    }
+
+    async fn execute_select(&self) -> Result<SendableDataBlockStream> {
+        let select = match self.plan.input.as_ref() {
+            PlanNode::Select(select) => select.clone(),
+            other => {
+                return Err(ErrorCode::LogicalError(format!(
+                    "INTO OUTFILE expects a select plan, but got {}",
+                    other.name()
+                )))
+            }
+        };
+
+        let interpreter = SelectInterpreter::try_create(self.ctx.clone(), select)?;
+        interpreter.execute(None).await
+    }
+
+    async fn write_blocks<W: Write + Send>(&self, writer: &mut W) -> Result<u64> {
+        let mut stream = self.execute_select().await?;
+
+        let mut rows = 0;
+        while let Some(block) = stream.next().await {
+            let block = block?;
+            if block.num_rows() == 0 {
+                continue;
+            }
+
+            let bytes = match self.plan.format {
+                OutfileFormat::Csv => block_to_csv(&block)?,
+                OutfileFormat::Tsv => block_to_tsv(&block)?,
+                OutfileFormat::Parquet => unreachable!("parquet is written by write_parquet"),
+            };
+            writer.write_all(&bytes)?;
+            rows += block.num_rows() as u64;
+        }
+        Ok(rows)
+    }
+
+    // Row groups are cut at `parquet_row_group_rows` rows and written as soon as they are full,
+    // every column chunk carries its min/max/null count statistics for the readers to prune.
+    async fn write_parquet<W: Write + Send>(&self, writer: &mut W) -> Result<u64> {
+        let rows_per_group = self.ctx.get_settings().get_parquet_row_group_rows()?.max(1) as usize;
+        let arrow_schema = self.plan.input.schema().to_arrow();
+        let options = WriteOptions {
+            write_statistics: true,
+            compression: ParquetCompression::Snappy,
+            version: Version::V2,
+        };
+        let parquet_schema = to_parquet_schema(&arrow_schema)?;
+        let created_by = Some("Arrow2 - Native Rust implementation of Arrow".to_string());
+        let mut file_writer = FileWriter::new(writer, parquet_schema, options, created_by);
+        file_writer.start().map_err(parquet_error)?;
+
+        let mut stream = self.execute_select().await?;
+        let mut pending: Vec<DataBlock> = vec![];
+        let mut pending_rows = 0;
+        let mut rows = 0;
+        while let Some(block) = stream.next().await {
+            let block = block?;
+            if block.num_rows() == 0 {
+                continue;
+            }
+
+            rows += block.num_rows() as u64;
+            pending_rows += block.num_rows();
+            pending.push(block);
+            if pending_rows < rows_per_group {
+                continue;
+            }
+
+            let block = DataBlock::concat_blocks(&pending)?;
+            pending.clear();
+            pending_rows = 0;
+            for group in DataBlock::split_block_by_size(&block, rows_per_group)? {
+                if group.num_rows() < rows_per_group {
+                    pending_rows = group.num_rows();
+                    pending.push(group);
+                } else {
+                    Self::write_row_group(&mut file_writer, &arrow_schema, options, group)?;
+                }
+            }
+        }
+        if !pending.is_empty() {
+            let block = DataBlock::concat_blocks(&pending)?;
+            Self::write_row_group(&mut file_writer, &arrow_schema, options, block)?;
+        }
+
+        file_writer.end(None).map_err(parquet_error)?;
+        Ok(rows)
+    }
+
+    fn write_row_group<W: Write>(
+        file_writer: &mut FileWriter<W>,
+        arrow_schema: &ArrowSchema,
+        options: WriteOptions,
+        block: DataBlock,
+    ) -> Result<()> {
+        let encodings = vec![Encoding::Plain; arrow_schema.fields.len()];
+        let chunk = Chunk::try_from(block)?;
+        let row_groups = RowGroupIterator::try_new(
+            vec![Ok(chunk)].into_iter(),
+            arrow_schema,
+            options,
+            encodings,
+        )?;
+        for group in row_groups {
+            let (group, len) = group?;
+            file_writer.write(group, len).map_err(parquet_error)?;
+        }
+        Ok(())
+    }
+}
+
+fn parquet_error(e: ParquetError) -> ErrorCode {
+    ErrorCode::ParquetError(e.to_string())
+}
+
+#[async_trait::async_trait]
+impl Interpreter for SelectIntoOutfileInterpreter {
+    fn name(&self) -> &str {
+        "SelectIntoOutfileInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    #[tracing::instrument(level = "debug", name = "select_into_outfile_interpreter_execute", skip(self, _input_stream), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let path = self.check_path()?;
+
+        // Never overwrite an existing file.
+        let file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .map_err(|e| {
+                ErrorCode::BadArguments(format!(
+                    "Cannot create INTO OUTFILE file {}: {}",
+                    path.display(),
+                    e
+                ))
+            })?;
+
+        let rows = match self.write_file(file).await {
+            Ok(rows) => rows,
+            Err(cause) => {
+                let _ = std::fs::remove_file(&path);
+                return Err(cause);
+            }
+        };
+        let bytes = std::fs::metadata(&path)?.len();
+
+        let path = path.to_string_lossy().to_string();
+        let block = DataBlock::create(self.plan.schema(), vec![
+            Series::from_data(vec![path.as_bytes()]),
+            Series::from_data(vec![rows]),
+            Series::from_data(vec![bytes]),
+        ]);
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![block],
+        )))
+    }
+}
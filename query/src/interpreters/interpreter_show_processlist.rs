@@ -42,7 +42,7 @@ impl ShowProcessListInterpreter {
     }
 
     fn build_query(&self) -> Result<String> {
-        Ok("SELECT * FROM system.processes".to_string())
+        Ok("SELECT * FROM system.cluster_processes".to_string())
     }
 }
 
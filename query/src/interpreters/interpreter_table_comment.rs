@@ -0,0 +1,84 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::MatchSeq;
+use common_meta_types::UpsertTableOptionReq;
+use common_planners::CommentTablePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::Catalog;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+use crate::sql::OPT_KEY_COMMENT;
+
+pub struct CommentTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CommentTablePlan,
+}
+
+impl CommentTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CommentTablePlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(CommentTableInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CommentTableInterpreter {
+    fn name(&self) -> &str {
+        "CommentTableInterpreter"
+    }
+
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog();
+        let table = match catalog.get_table(&plan.tenant, &plan.db, &plan.table).await {
+            Ok(table) => Some(table),
+            Err(e) if plan.if_exists && e.code() == ErrorCode::UnknownTableCode() => None,
+            Err(e) => return Err(e),
+        };
+
+        if let Some(table) = table {
+            // The comment is kept as a table option, which is persisted with the table meta.
+            let comment = match plan.comment.is_empty() {
+                true => None,
+                false => Some(plan.comment.clone()),
+            };
+            let table_ident = &table.get_table_info().ident;
+            catalog
+                .upsert_table_option(UpsertTableOptionReq {
+                    table_id: table_ident.table_id,
+                    seq: MatchSeq::Exact(table_ident.version),
+                    options: [(OPT_KEY_COMMENT.to_owned(), comment)]
+                        .into_iter()
+                        .collect(),
+                })
+                .await?;
+        }
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
@@ -29,9 +29,13 @@ use common_streams::SendableDataBlockStream;
 
 use super::InsertInterpreter;
 use crate::catalogs::Catalog;
+use crate::interpreters::interpreter_common::drop_staging_table;
+use crate::interpreters::interpreter_common::replace_staging_table_name;
+use crate::interpreters::interpreter_common::swap_in_staging_table;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::QueryContext;
+use crate::storages::view::view_table::VIEW_ENGINE;
 
 pub struct CreateTableInterpreter {
     ctx: Arc<QueryContext>,
@@ -64,13 +68,15 @@ impl Interpreter for CreateTableInterpreter {
 
         let engine = self.plan.engine();
 
-        if self
-            .ctx
-            .get_catalog()
-            .list_tables(&*self.plan.tenant, &*self.plan.db)
-            .await?
-            .iter()
-            .all(|table| table.name() != self.plan.table.as_str())
+        // A replaced table is created anew, so its engine is checked even if the name exists.
+        if (self.plan.or_replace
+            || self
+                .ctx
+                .get_catalog()
+                .list_tables(&*self.plan.tenant, &*self.plan.db)
+                .await?
+                .iter()
+                .all(|table| table.name() != self.plan.table.as_str()))
             && self
                 .ctx
                 .get_catalog()
@@ -86,29 +92,92 @@ impl Interpreter for CreateTableInterpreter {
             )));
         }
 
-        match &self.plan.as_select {
-            Some(select_plan_node) => {
-                self.create_table_as_select(input_stream, select_plan_node.clone())
-                    .await
+        if self.plan.or_replace {
+            let existing = self
+                .ctx
+                .get_catalog()
+                .get_table(&self.plan.tenant, &self.plan.db, &self.plan.table)
+                .await
+                .ok();
+            if let Some(existing) = existing {
+                if existing.get_table_info().engine() == VIEW_ENGINE {
+                    return Err(ErrorCode::UnexpectedError(format!(
+                        "{}.{} is VIEW, please use `CREATE OR REPLACE VIEW`",
+                        &self.plan.db, &self.plan.table
+                    )));
+                }
+                // The replaced table is dropped, which needs the privilege to drop it.
+                self.ctx
+                    .get_current_session()
+                    .validate_privilege(
+                        &GrantObject::Database(self.plan.db.clone()),
+                        UserPrivilegeType::Drop,
+                    )
+                    .await?;
+                return self.replace_table(input_stream).await;
             }
-            None => self.create_table().await,
         }
+
+        self.create(&self.plan, input_stream).await?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
     }
 }
 
 impl CreateTableInterpreter {
+    async fn create(
+        &self,
+        plan: &CreateTablePlan,
+        input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<()> {
+        match &plan.as_select {
+            Some(select_plan_node) => {
+                self.create_table_as_select(plan, input_stream, select_plan_node.clone())
+                    .await
+            }
+            None => self.create_table(plan).await,
+        }
+    }
+
+    /// Build the new table under a staging name, then swap it with the existing one.
+    async fn replace_table(
+        &self,
+        input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let mut plan = self.plan.clone();
+        plan.or_replace = false;
+        plan.table = replace_staging_table_name(&self.plan.table);
+
+        if let Err(cause) = self.create(&plan, input_stream).await {
+            drop_staging_table(&self.ctx, &plan.db, &plan.table).await;
+            return Err(cause);
+        }
+        swap_in_staging_table(&self.ctx, &plan.db, &self.plan.table, &plan.table).await?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+
     async fn create_table_as_select(
         &self,
+        plan: &CreateTablePlan,
         input_stream: Option<SendableDataBlockStream>,
         select_plan_node: Box<PlanNode>,
-    ) -> Result<SendableDataBlockStream> {
+    ) -> Result<()> {
         let tenant = self.ctx.get_tenant();
         let catalog = self.ctx.get_catalog();
 
         // TODO: maybe the table creation and insertion should be a transaction, but it may require create_table support 2pc.
-        catalog.create_table(self.plan.clone().into()).await?;
+        catalog.create_table(plan.clone().into()).await?;
         let table = catalog
-            .get_table(tenant.as_str(), &self.plan.db, &self.plan.table)
+            .get_table(tenant.as_str(), &plan.db, &plan.table)
             .await?;
 
         // If the table creation query contains column definitions, like 'CREATE TABLE t1(a int) AS SELECT * from t2',
@@ -130,8 +199,8 @@ impl CreateTableInterpreter {
             .collect();
         let schema = DataSchemaRefExt::create(select_fields);
         let insert_plan = InsertPlan {
-            database_name: self.plan.db.clone(),
-            table_name: self.plan.table.clone(),
+            database_name: plan.db.clone(),
+            table_name: plan.table.clone(),
             table_id: table.get_id(),
             schema,
             overwrite: false,
@@ -139,22 +208,11 @@ impl CreateTableInterpreter {
         };
         let insert_interpreter = InsertInterpreter::try_create(self.ctx.clone(), insert_plan)?;
         insert_interpreter.execute(input_stream).await?;
-
-        Ok(Box::pin(DataBlockStream::create(
-            self.plan.schema(),
-            None,
-            vec![],
-        )))
+        Ok(())
     }
 
-    async fn create_table(&self) -> Result<SendableDataBlockStream> {
+    async fn create_table(&self, plan: &CreateTablePlan) -> Result<()> {
         let catalog = self.ctx.get_catalog();
-        catalog.create_table(self.plan.clone().into()).await?;
-
-        Ok(Box::pin(DataBlockStream::create(
-            self.plan.schema(),
-            None,
-            vec![],
-        )))
+        catalog.create_table(plan.clone().into()).await
     }
 }
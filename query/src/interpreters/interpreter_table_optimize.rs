@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::Optimization;
 use common_planners::OptimizeTablePlan;
@@ -50,7 +51,17 @@ impl Interpreter for OptimizeTableInterpreter {
         _input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
         let plan = &self.plan;
-        let mut table = self.ctx.get_table(&plan.database, &plan.table).await?;
+        let mut table = match self.ctx.get_table(&plan.database, &plan.table).await {
+            Ok(table) => table,
+            Err(e) if plan.if_exists && e.code() == ErrorCode::UnknownTableCode() => {
+                return Ok(Box::pin(DataBlockStream::create(
+                    self.plan.schema(),
+                    None,
+                    vec![],
+                )));
+            }
+            Err(e) => return Err(e),
+        };
         let operation = &plan.operation;
 
         let do_purge = operation.contains(Optimization::PURGE);
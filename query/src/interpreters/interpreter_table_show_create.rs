@@ -27,6 +27,7 @@ use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::QueryContext;
 use crate::sql::is_reserved_opt_key;
+use crate::sql::SQLCommon;
 
 pub struct ShowCreateTableInterpreter {
     ctx: Arc<QueryContext>,
@@ -60,11 +61,11 @@ impl Interpreter for ShowCreateTableInterpreter {
         let engine = table.engine();
         let schema = table.schema();
 
-        let mut table_info = format!("CREATE TABLE `{}` (\n", name);
+        let mut table_info = format!("CREATE TABLE {} (\n", SQLCommon::quote_identifier(name));
         for field in schema.fields().iter() {
             let column = format!(
-                "  `{}` {},\n",
-                field.name(),
+                "  {} {},\n",
+                SQLCommon::quote_identifier(field.name()),
                 format_data_type_sql(field.data_type())
             );
             table_info.push_str(column.as_str());
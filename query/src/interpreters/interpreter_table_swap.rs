@@ -0,0 +1,68 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_meta_types::SwapTableReq;
+use common_planners::SwapTablePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::Catalog;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+
+pub struct SwapTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: SwapTablePlan,
+}
+
+impl SwapTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: SwapTablePlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(SwapTableInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for SwapTableInterpreter {
+    fn name(&self) -> &str {
+        "SwapTableInterpreter"
+    }
+
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let plan = self.plan.clone();
+        let catalog = self.ctx.get_catalog();
+        catalog
+            .swap_table(SwapTableReq {
+                if_exists: plan.if_exists,
+                tenant: plan.tenant,
+                db: plan.db,
+                table_name: plan.table_name,
+                other_db: plan.other_db,
+                other_table_name: plan.other_table_name,
+            })
+            .await?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
@@ -68,9 +68,13 @@ impl Interpreter for CreateUserStageInterpreter {
             }
         }
 
-        let _create_stage = user_mgr
-            .add_stage(&plan.tenant, user_stage, plan.if_not_exists)
-            .await?;
+        if plan.or_replace {
+            let _replace_stage = user_mgr.replace_stage(&plan.tenant, user_stage).await?;
+        } else {
+            let _create_stage = user_mgr
+                .add_stage(&plan.tenant, user_stage, plan.if_not_exists)
+                .await?;
+        }
 
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
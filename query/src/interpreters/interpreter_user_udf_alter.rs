@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::AlterUserUDFPlan;
 use common_streams::DataBlockStream;
@@ -51,7 +52,12 @@ impl Interpreter for AlterUserUDFInterpreter {
 
         let tenant = self.ctx.get_tenant();
         let user_mgr = self.ctx.get_user_manager();
-        user_mgr.update_udf(&tenant, plan.udf).await?;
+        match user_mgr.update_udf(&tenant, plan.udf).await {
+            Err(e) if plan.if_exists && e.code() == ErrorCode::UnknownUDFCode() => {}
+            res => {
+                res?;
+            }
+        }
 
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
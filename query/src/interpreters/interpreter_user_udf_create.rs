@@ -51,7 +51,11 @@ impl Interpreter for CreateUserUDFInterpreter {
         let tenant = self.ctx.get_tenant();
         let user_mgr = self.ctx.get_user_manager();
         let udf = plan.udf;
-        let _ = user_mgr.add_udf(&tenant, udf, plan.if_not_exists).await?;
+        if plan.or_replace {
+            let _ = user_mgr.replace_udf(&tenant, udf).await?;
+        } else {
+            let _ = user_mgr.add_udf(&tenant, udf, plan.if_not_exists).await?;
+        }
 
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
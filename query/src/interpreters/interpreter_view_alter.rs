@@ -71,6 +71,13 @@ impl Interpreter for AlterViewInterpreter {
                     && table.get_table_info().engine() == VIEW_ENGINE
             })
         {
+            if self.plan.if_exists {
+                return Ok(Box::pin(DataBlockStream::create(
+                    self.plan.schema(),
+                    None,
+                    vec![],
+                )));
+            }
             return Err(ErrorCode::ViewAlreadyExists(format!(
                 "{}.{} view is not existed",
                 self.plan.db, self.plan.viewname
@@ -26,6 +26,9 @@ use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 
 use crate::catalogs::Catalog;
+use crate::interpreters::interpreter_common::drop_staging_table;
+use crate::interpreters::interpreter_common::replace_staging_table_name;
+use crate::interpreters::interpreter_common::swap_in_staging_table;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::QueryContext;
@@ -59,26 +62,67 @@ impl Interpreter for CreateViewInterpreter {
             .await?;
 
         // check whether view has exists
-        if self
+        let existing = self
             .ctx
             .get_catalog()
             .list_tables(&*self.plan.tenant, &*self.plan.db)
             .await?
-            .iter()
-            .any(|table| table.name() == self.plan.viewname.as_str())
-        {
-            return Err(ErrorCode::ViewAlreadyExists(format!(
-                "{}.{} as view Already Exists",
-                self.plan.db, self.plan.viewname
-            )));
+            .into_iter()
+            .find(|table| table.name() == self.plan.viewname.as_str());
+        if let Some(existing) = existing {
+            if self.plan.if_not_exists {
+                return Ok(Box::pin(DataBlockStream::create(
+                    self.plan.schema(),
+                    None,
+                    vec![],
+                )));
+            }
+            if !self.plan.or_replace {
+                return Err(ErrorCode::ViewAlreadyExists(format!(
+                    "{}.{} as view Already Exists",
+                    self.plan.db, self.plan.viewname
+                )));
+            }
+            if existing.get_table_info().engine() != VIEW_ENGINE {
+                return Err(ErrorCode::UnexpectedError(format!(
+                    "{}.{} is not VIEW, please use `CREATE OR REPLACE TABLE`",
+                    self.plan.db, self.plan.viewname
+                )));
+            }
+            // The replaced view is dropped, which needs the privilege to drop it.
+            self.ctx
+                .get_current_session()
+                .validate_privilege(
+                    &GrantObject::Database(self.plan.db.clone()),
+                    UserPrivilegeType::Drop,
+                )
+                .await?;
+            return self.replace_view().await;
         }
 
-        self.create_view().await
+        self.create_view(&self.plan.viewname).await
     }
 }
 
 impl CreateViewInterpreter {
-    async fn create_view(&self) -> Result<SendableDataBlockStream> {
+    /// Build the new view under a staging name, then swap it with the existing one.
+    async fn replace_view(&self) -> Result<SendableDataBlockStream> {
+        let db = &self.plan.db;
+        let staging = replace_staging_table_name(&self.plan.viewname);
+        if let Err(cause) = self.create_view(&staging).await {
+            drop_staging_table(&self.ctx, db, &staging).await;
+            return Err(cause);
+        }
+        swap_in_staging_table(&self.ctx, db, &self.plan.viewname, &staging).await?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+
+    async fn create_view(&self, viewname: &str) -> Result<SendableDataBlockStream> {
         let catalog = self.ctx.get_catalog();
         let mut options = HashMap::new();
         options.insert("query".to_string(), self.plan.subquery.clone());
@@ -86,7 +130,7 @@ impl CreateViewInterpreter {
             if_not_exists: self.plan.if_not_exists,
             tenant: self.plan.tenant.clone(),
             db: self.plan.db.clone(),
-            table: self.plan.viewname.clone(),
+            table: viewname.to_string(),
             table_meta: TableMeta {
                 engine: VIEW_ENGINE.to_string(),
                 options,
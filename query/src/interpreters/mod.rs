@@ -19,6 +19,8 @@ mod interpreter_copy;
 mod interpreter_database_create;
 mod interpreter_database_drop;
 mod interpreter_database_show_create;
+mod interpreter_deallocate;
+mod interpreter_drain_node;
 mod interpreter_explain;
 mod interpreter_factory;
 mod interpreter_factory_interceptor;
@@ -27,6 +29,7 @@ mod interpreter_insert_values;
 mod interpreter_insert_with_stream;
 mod interpreter_kill;
 mod interpreter_list;
+mod interpreter_prepare;
 mod interpreter_privilege_grant;
 mod interpreter_privilege_revoke;
 mod interpreter_query_log;
@@ -35,6 +38,7 @@ mod interpreter_role_drop;
 mod interpreter_role_grant;
 mod interpreter_role_revoke;
 mod interpreter_select;
+mod interpreter_select_into_outfile;
 mod interpreter_setting;
 mod interpreter_show_databases;
 mod interpreter_show_engines;
@@ -46,12 +50,14 @@ mod interpreter_show_roles;
 mod interpreter_show_settings;
 mod interpreter_show_tables;
 mod interpreter_show_users;
+mod interpreter_table_comment;
 mod interpreter_table_create;
 mod interpreter_table_describe;
 mod interpreter_table_drop;
 mod interpreter_table_optimize;
 mod interpreter_table_rename;
 mod interpreter_table_show_create;
+mod interpreter_table_swap;
 mod interpreter_table_truncate;
 mod interpreter_use_database;
 mod interpreter_user_alter;
@@ -67,6 +73,7 @@ mod interpreter_view_alter;
 mod interpreter_view_create;
 mod interpreter_view_drop;
 mod plan_schedulers;
+mod result_cache;
 mod stream;
 
 pub use interpreter::Interpreter;
@@ -76,12 +83,16 @@ pub use interpreter_copy::CopyInterpreter;
 pub use interpreter_database_create::CreateDatabaseInterpreter;
 pub use interpreter_database_drop::DropDatabaseInterpreter;
 pub use interpreter_database_show_create::ShowCreateDatabaseInterpreter;
+pub use interpreter_deallocate::DeallocateInterpreter;
+pub use interpreter_drain_node::DrainNodeInterpreter;
 pub use interpreter_explain::ExplainInterpreter;
 pub use interpreter_factory::InterpreterFactory;
 pub use interpreter_factory_interceptor::InterceptorInterpreter;
 pub use interpreter_insert::InsertInterpreter;
+pub use interpreter_insert_values::ValueSource;
 pub use interpreter_kill::KillInterpreter;
 pub use interpreter_list::ListInterpreter;
+pub use interpreter_prepare::PrepareInterpreter;
 pub use interpreter_privilege_grant::GrantPrivilegeInterpreter;
 pub use interpreter_privilege_revoke::RevokePrivilegeInterpreter;
 pub use interpreter_query_log::InterpreterQueryLog;
@@ -92,6 +103,7 @@ pub use interpreter_role_drop::DropRoleInterpreter;
 pub use interpreter_role_grant::GrantRoleInterpreter;
 pub use interpreter_role_revoke::RevokeRoleInterpreter;
 pub use interpreter_select::SelectInterpreter;
+pub use interpreter_select_into_outfile::SelectIntoOutfileInterpreter;
 pub use interpreter_setting::SettingInterpreter;
 pub use interpreter_show_databases::ShowDatabasesInterpreter;
 pub use interpreter_show_functions::ShowFunctionsInterpreter;
@@ -102,12 +114,14 @@ pub use interpreter_show_roles::ShowRolesInterpreter;
 pub use interpreter_show_settings::ShowSettingsInterpreter;
 pub use interpreter_show_tables::ShowTablesInterpreter;
 pub use interpreter_show_users::ShowUsersInterpreter;
+pub use interpreter_table_comment::CommentTableInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_describe::DescribeTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
 pub use interpreter_table_optimize::OptimizeTableInterpreter;
 pub use interpreter_table_rename::RenameTableInterpreter;
 pub use interpreter_table_show_create::ShowCreateTableInterpreter;
+pub use interpreter_table_swap::SwapTableInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
 pub use interpreter_use_database::UseDatabaseInterpreter;
 pub use interpreter_user_alter::AlterUserInterpreter;
@@ -121,3 +135,4 @@ pub use interpreter_view_alter::AlterViewInterpreter;
 pub use interpreter_view_create::CreateViewInterpreter;
 pub use interpreter_view_drop::DropViewInterpreter;
 pub use plan_schedulers::PlanScheduler;
+pub use result_cache::ResultCache;
@@ -15,6 +15,7 @@
 #[allow(clippy::needless_range_loop)]
 mod plan_scheduler;
 mod plan_scheduler_error;
+mod plan_scheduler_governor;
 mod plan_scheduler_insert;
 mod plan_scheduler_query;
 mod plan_scheduler_rewriter;
@@ -22,6 +23,7 @@ mod plan_scheduler_stream;
 
 pub use plan_scheduler::PlanScheduler;
 pub use plan_scheduler_error::handle_error;
+pub use plan_scheduler_governor::check_scan_limits;
 pub use plan_scheduler_insert::InsertWithPlan;
 pub use plan_scheduler_query::schedule_query;
 pub use plan_scheduler_rewriter::apply_plan_rewrite;
@@ -23,6 +23,7 @@ use common_meta_types::NodeInfo;
 use common_planners::AggregatorFinalPlan;
 use common_planners::AggregatorPartialPlan;
 use common_planners::BroadcastPlan;
+use common_planners::DistinctPlan;
 use common_planners::EmptyPlan;
 use common_planners::Expression;
 use common_planners::ExpressionPlan;
@@ -42,6 +43,8 @@ use common_planners::SortPlan;
 use common_planners::StageKind;
 use common_planners::StagePlan;
 use common_planners::SubQueriesSetPlan;
+use common_planners::TopNPlan;
+use common_planners::ValuesPlan;
 use common_tracing::tracing;
 
 use crate::api::BroadcastAction;
@@ -300,9 +303,12 @@ impl PlanScheduler {
             PlanNode::Projection(plan) => self.visit_projection(plan, tasks),
             PlanNode::Filter(plan) => self.visit_filter(plan, tasks),
             PlanNode::Sort(plan) => self.visit_sort(plan, tasks),
+            PlanNode::TopN(plan) => self.visit_top_n(plan, tasks),
+            PlanNode::Distinct(plan) => self.visit_distinct(plan, tasks),
             PlanNode::Limit(plan) => self.visit_limit(plan, tasks),
             PlanNode::LimitBy(plan) => self.visit_limit_by(plan, tasks),
             PlanNode::ReadSource(plan) => self.visit_data_source(plan, tasks),
+            PlanNode::Values(plan) => self.visit_values(plan, tasks),
             PlanNode::Sink(plan) => self.visit_sink(plan, tasks),
             PlanNode::Select(plan) => self.visit_select(plan, tasks),
             PlanNode::Stage(plan) => self.visit_stage(plan, tasks),
@@ -726,6 +732,62 @@ impl PlanScheduler {
         }
     }
 
+    fn visit_top_n(&mut self, plan: &TopNPlan, tasks: &mut Tasks) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref(), tasks)?;
+        match self.running_mode {
+            RunningMode::Cluster => self.visit_cluster_top_n(plan),
+            RunningMode::Standalone => self.visit_local_top_n(plan),
+        };
+        Ok(())
+    }
+
+    fn visit_local_top_n(&mut self, plan: &TopNPlan) {
+        self.nodes_plan[self.local_pos] = PlanNode::TopN(TopNPlan {
+            schema: plan.schema.clone(),
+            order_by: plan.order_by.clone(),
+            limit: plan.limit,
+            input: Arc::new(self.nodes_plan[self.local_pos].clone()),
+        });
+    }
+
+    fn visit_cluster_top_n(&mut self, plan: &TopNPlan) {
+        for index in 0..self.nodes_plan.len() {
+            self.nodes_plan[index] = PlanNode::TopN(TopNPlan {
+                schema: plan.schema.clone(),
+                order_by: plan.order_by.clone(),
+                limit: plan.limit,
+                input: Arc::new(self.nodes_plan[index].clone()),
+            });
+        }
+    }
+
+    fn visit_distinct(&mut self, plan: &DistinctPlan, tasks: &mut Tasks) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref(), tasks)?;
+        match self.running_mode {
+            RunningMode::Cluster => self.visit_cluster_distinct(plan),
+            RunningMode::Standalone => self.visit_local_distinct(plan),
+        };
+        Ok(())
+    }
+
+    fn visit_local_distinct(&mut self, plan: &DistinctPlan) {
+        self.nodes_plan[self.local_pos] = PlanNode::Distinct(DistinctPlan {
+            schema: plan.schema.clone(),
+            distinct_on: plan.distinct_on.clone(),
+            input: Arc::new(self.nodes_plan[self.local_pos].clone()),
+        });
+    }
+
+    fn visit_cluster_distinct(&mut self, plan: &DistinctPlan) {
+        for index in 0..self.nodes_plan.len() {
+            self.nodes_plan[index] = PlanNode::Distinct(DistinctPlan {
+                schema: plan.schema.clone(),
+                distinct_on: plan.distinct_on.clone(),
+                input: Arc::new(self.nodes_plan[index].clone()),
+            });
+        }
+    }
+
     fn visit_limit(&mut self, plan: &LimitPlan, tasks: &mut Tasks) -> Result<()> {
         self.visit_plan_node(plan.input.as_ref(), tasks)?;
         match self.running_mode {
@@ -808,6 +870,13 @@ impl PlanScheduler {
         Ok(())
     }
 
+    fn visit_values(&mut self, plan: &ValuesPlan, _: &mut Tasks) -> Result<()> {
+        // The literal rows are produced on the local node only.
+        self.running_mode = RunningMode::Standalone;
+        self.nodes_plan[self.local_pos] = PlanNode::Values(plan.clone());
+        Ok(())
+    }
+
     fn visit_sink(&mut self, plan: &SinkPlan, tasks: &mut Tasks) -> Result<()> {
         self.visit_plan_node(plan.input.as_ref(), tasks)?;
         let local = self.running_mode == RunningMode::Standalone;
@@ -0,0 +1,76 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::convert_byte_size;
+use common_planners::PlanNode;
+use common_planners::PlanVisitor;
+use common_planners::ReadDataSourcePlan;
+
+use crate::sessions::QueryContext;
+
+/// Reject the query before it runs if the optimized plan is estimated to scan
+/// more than `max_scan_rows` or `max_scan_bytes`, unless `ignore_scan_limits` is set.
+pub fn check_scan_limits(ctx: &Arc<QueryContext>, plan: &PlanNode) -> Result<()> {
+    let settings = ctx.get_settings();
+    if settings.get_ignore_scan_limits()? != 0 {
+        return Ok(());
+    }
+
+    let max_rows = settings.get_max_scan_rows()? as usize;
+    let max_bytes = settings.get_max_scan_bytes()? as usize;
+    if max_rows == 0 && max_bytes == 0 {
+        return Ok(());
+    }
+
+    let mut estimator = ScanEstimator::default();
+    estimator.visit_plan_node(plan)?;
+
+    if max_rows != 0 && estimator.read_rows > max_rows {
+        return Err(ErrorCode::QueryScanLimitExceeded(format!(
+            "Query is estimated to scan {} rows, which exceeds max_scan_rows = {}. \
+             Add filters or a LIMIT to read less data, or SET ignore_scan_limits = 1 to run it anyway",
+            estimator.read_rows, max_rows
+        )));
+    }
+
+    if max_bytes != 0 && estimator.read_bytes > max_bytes {
+        return Err(ErrorCode::QueryScanLimitExceeded(format!(
+            "Query is estimated to scan {}, which exceeds max_scan_bytes = {}. \
+             Add filters or a LIMIT to read less data, or SET ignore_scan_limits = 1 to run it anyway",
+            convert_byte_size(estimator.read_bytes as f64),
+            convert_byte_size(max_bytes as f64)
+        )));
+    }
+
+    Ok(())
+}
+
+// Sums the statistics of every table scan in the plan, including the ones in subqueries.
+#[derive(Default)]
+struct ScanEstimator {
+    read_rows: usize,
+    read_bytes: usize,
+}
+
+impl PlanVisitor for ScanEstimator {
+    fn visit_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<()> {
+        self.read_rows = self.read_rows.saturating_add(plan.statistics.read_rows);
+        self.read_bytes = self.read_bytes.saturating_add(plan.statistics.read_bytes);
+        Ok(())
+    }
+}
@@ -79,6 +79,7 @@ impl<'a> InsertWithPlan<'a> {
             Optimizers::create(self.ctx.clone()),
             &select_plan.input,
         )?;
+        plan_schedulers::check_scan_limits(&self.ctx, &optimized_plan)?;
 
         // rewrite the optimized the plan
         let rewritten_plan = match optimized_plan {
@@ -0,0 +1,234 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Borrow;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+
+use common_cache::Cache;
+use common_cache::DefaultHashBuilder;
+use common_cache::LruCache;
+use common_cache::Meter;
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use common_functions::scalars::FunctionFactory;
+use common_infallible::Mutex;
+use common_planners::Expression;
+use common_planners::PlanNode;
+use common_planners::PlanVisitor;
+use common_planners::ReadDataSourcePlan;
+use common_planners::RewriteHelper;
+use common_planners::SourceInfo;
+use common_streams::SendableDataBlockStream;
+use futures::Stream;
+use futures::StreamExt;
+
+use crate::sessions::QueryContext;
+
+/// The result blocks of a query.
+struct CachedResult {
+    blocks: Vec<DataBlock>,
+    // The bytes of the blocks and the key.
+    bytes: usize,
+}
+
+struct ResultMeter;
+
+impl Meter<String, Arc<CachedResult>> for ResultMeter {
+    type Measure = usize;
+    fn measure<Q: ?Sized>(&self, _: &Q, v: &Arc<CachedResult>) -> usize
+    where String: Borrow<Q> {
+        v.bytes
+    }
+}
+
+/// A bounded LRU cache of the small query results, keyed on the optimized plan.
+///
+/// The optimized plan holds the id and version of every table it reads, and only the plans
+/// reading FUSE tables with deterministic expressions are cached, so a cached result is returned
+/// only if nothing underneath has changed: a new snapshot of a table is a new key.
+pub struct ResultCache {
+    cache: Option<Mutex<LruCache<String, Arc<CachedResult>, DefaultHashBuilder, ResultMeter>>>,
+}
+
+impl ResultCache {
+    pub fn create(capacity_bytes: u64) -> ResultCache {
+        let cache = match capacity_bytes {
+            0 => None,
+            capacity => Some(Mutex::new(LruCache::with_meter(capacity, ResultMeter))),
+        };
+        ResultCache { cache }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.cache.is_some()
+    }
+
+    /// The cache key of the optimized plan, None if the result can't be cached.
+    pub fn key(ctx: &QueryContext, plan: &PlanNode) -> Result<Option<String>> {
+        let mut visitor = CacheableResult { cacheable: true };
+        visitor.visit_plan_node(plan)?;
+        if !visitor.cacheable {
+            return Ok(None);
+        }
+
+        Ok(serde_json::to_value(plan)
+            .ok()
+            .map(|plan| format!("{:?}.{}", ctx.get_tenant(), Self::sort_keys(plan))))
+    }
+
+    // The plan holds hash maps (e.g. the options of the tables), the keys of the objects are
+    // sorted so that the same plan is always serialized the same way.
+    fn sort_keys(value: serde_json::Value) -> serde_json::Value {
+        match value {
+            serde_json::Value::Object(map) => {
+                let mut entries = map.into_iter().collect::<Vec<_>>();
+                entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+                serde_json::Value::Object(
+                    entries
+                        .into_iter()
+                        .map(|(key, value)| (key, Self::sort_keys(value)))
+                        .collect(),
+                )
+            }
+            serde_json::Value::Array(values) => {
+                serde_json::Value::Array(values.into_iter().map(Self::sort_keys).collect())
+            }
+            other => other,
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<Vec<DataBlock>> {
+        let cache = self.cache.as_ref()?;
+        let mut cache = cache.lock();
+        cache.get(key).map(|cached| cached.blocks.clone())
+    }
+
+    pub fn put(&self, key: String, blocks: Vec<DataBlock>) {
+        if let Some(cache) = &self.cache {
+            let bytes = key.len()
+                + blocks
+                    .iter()
+                    .map(|b| b.retained_memory_size())
+                    .sum::<usize>();
+            cache
+                .lock()
+                .put(key, Arc::new(CachedResult { blocks, bytes }));
+        }
+    }
+
+    pub fn clear(&self) {
+        if let Some(cache) = &self.cache {
+            cache.lock().clear();
+        }
+    }
+
+    /// Cache the blocks of the stream once it ends without an error,
+    /// unless they are larger than `max_bytes`.
+    pub fn cache_stream(
+        self: &Arc<Self>,
+        key: String,
+        max_bytes: usize,
+        input: SendableDataBlockStream,
+    ) -> SendableDataBlockStream {
+        Box::pin(ResultCacheStream {
+            cache: self.clone(),
+            key: Some(key),
+            max_bytes,
+            bytes: 0,
+            blocks: vec![],
+            input,
+        })
+    }
+}
+
+// Whether the plan reads FUSE tables only, and every function in it is deterministic.
+struct CacheableResult {
+    cacheable: bool,
+}
+
+impl PlanVisitor for CacheableResult {
+    fn visit_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<()> {
+        let fuse = match &plan.source_info {
+            SourceInfo::TableSource(info) => info.engine().eq_ignore_ascii_case("FUSE"),
+            SourceInfo::S3StageSource(_) => false,
+        };
+        let sampled = matches!(&plan.push_downs, Some(extras) if extras.sample.is_some());
+        self.cacheable &= fuse && !sampled;
+        Ok(())
+    }
+
+    fn visit_expr(&mut self, expr: &Expression) -> Result<()> {
+        match expr {
+            Expression::Subquery { query_plan, .. }
+            | Expression::ScalarSubquery { query_plan, .. } => {
+                return self.visit_subquery_plan(query_plan.as_ref());
+            }
+            Expression::UnaryExpression { op, .. }
+            | Expression::BinaryExpression { op, .. }
+            | Expression::ScalarFunction { op, .. } => {
+                self.cacheable &= FunctionFactory::instance()
+                    .get_features(op)
+                    .map(|features| features.is_deterministic())
+                    .unwrap_or(false);
+            }
+            _ => {}
+        }
+        self.visit_exprs(&RewriteHelper::expression_plan_children(expr)?)
+    }
+}
+
+struct ResultCacheStream {
+    cache: Arc<ResultCache>,
+    // None once the result is known not to be cached.
+    key: Option<String>,
+    max_bytes: usize,
+    bytes: usize,
+    blocks: Vec<DataBlock>,
+    input: SendableDataBlockStream,
+}
+
+impl Stream for ResultCacheStream {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let next = self.input.poll_next_unpin(cx);
+        match &next {
+            Poll::Ready(Some(Ok(block))) if self.key.is_some() => {
+                self.bytes += block.retained_memory_size();
+                if self.bytes > self.max_bytes {
+                    self.key = None;
+                    self.blocks.clear();
+                } else {
+                    let block = block.clone();
+                    self.blocks.push(block);
+                }
+            }
+            Poll::Ready(Some(Err(_))) => {
+                self.key = None;
+                self.blocks.clear();
+            }
+            Poll::Ready(None) => {
+                if let Some(key) = self.key.take() {
+                    let blocks = std::mem::take(&mut self.blocks);
+                    self.cache.put(key, blocks);
+                }
+            }
+            _ => {}
+        }
+        next
+    }
+}
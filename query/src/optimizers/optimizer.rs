@@ -20,9 +20,13 @@ use common_tracing::tracing;
 use metrics::histogram;
 
 use crate::optimizers::optimizer_scatters::ScattersOptimizer;
+use crate::optimizers::CommonSubexpressionOptimizer;
 use crate::optimizers::ConstantFoldingOptimizer;
 use crate::optimizers::ExprTransformOptimizer;
+use crate::optimizers::LimitPushDownOptimizer;
+use crate::optimizers::ProjectionPruningOptimizer;
 use crate::optimizers::StatisticsExactOptimizer;
+use crate::optimizers::TopNOptimizer;
 use crate::optimizers::TopNPushDownOptimizer;
 use crate::sessions::QueryContext;
 
@@ -49,8 +53,12 @@ impl Optimizers {
             inner: vec![
                 Box::new(ConstantFoldingOptimizer::create(ctx.clone())),
                 Box::new(ExprTransformOptimizer::create(ctx.clone())),
+                Box::new(CommonSubexpressionOptimizer::create(ctx.clone())),
+                Box::new(ProjectionPruningOptimizer::create(ctx.clone())),
                 Box::new(TopNPushDownOptimizer::create(ctx.clone())),
-                Box::new(StatisticsExactOptimizer::create(ctx)),
+                Box::new(LimitPushDownOptimizer::create(ctx.clone())),
+                Box::new(StatisticsExactOptimizer::create(ctx.clone())),
+                Box::new(TopNOptimizer::create(ctx)),
             ],
         }
     }
@@ -0,0 +1,169 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_functions::scalars::FunctionFactory;
+use common_planners::rebase_expr;
+use common_planners::Expression;
+use common_planners::ExpressionPlan;
+use common_planners::FilterPlan;
+use common_planners::PlanBuilder;
+use common_planners::PlanNode;
+use common_planners::RewriteHelper;
+
+use crate::optimizers::Optimizer;
+use crate::sessions::QueryContext;
+
+/// Compute the subexpressions shared by a Filter and the Expression on top of it only once.
+///
+/// For `SELECT expensive(x) FROM t WHERE expensive(x) > 1`, the plan
+///
+/// ```text
+/// Expression: expensive(x):UInt64 (Before Projection)
+///   Filter: (expensive(x) > 1)
+///     ReadDataSource
+/// ```
+///
+/// is rewritten into
+///
+/// ```text
+/// Expression: expensive(x):UInt64 (Before Projection)
+///   Filter: (expensive(x) > 1)
+///     Expression: expensive(x):UInt64 (Common Subexpressions)
+///       ReadDataSource
+/// ```
+///
+/// where both the Filter and the upper Expression read the `expensive(x)` column instead of
+/// evaluating it again. Repeats within a single node are already evaluated once by the
+/// expression executor, which keys the intermediate results by column name.
+pub struct CommonSubexpressionOptimizer {}
+
+impl CommonSubexpressionOptimizer {
+    pub fn create(_ctx: Arc<QueryContext>) -> CommonSubexpressionOptimizer {
+        CommonSubexpressionOptimizer {}
+    }
+
+    /// Whether the expression is worth computing once: a deterministic function of the input
+    /// columns, without subqueries or aggregates.
+    fn is_computable(expr: &Expression) -> Result<bool> {
+        let computable = match expr {
+            Expression::UnaryExpression { op, .. }
+            | Expression::BinaryExpression { op, .. }
+            | Expression::ScalarFunction { op, .. } => FunctionFactory::instance()
+                .get_features(op)?
+                .is_deterministic(),
+            Expression::Cast { .. } => true,
+            Expression::Column(_) | Expression::Literal { .. } => return Ok(true),
+            _ => false,
+        };
+
+        if !computable {
+            return Ok(false);
+        }
+        for child in RewriteHelper::expression_plan_children(expr)? {
+            if !Self::is_computable(&child)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    fn is_leaf(expr: &Expression) -> bool {
+        matches!(expr, Expression::Column(_) | Expression::Literal { .. })
+    }
+
+    fn collect_names(expr: &Expression, names: &mut HashSet<String>) -> Result<()> {
+        if !Self::is_leaf(expr) {
+            names.insert(expr.column_name());
+        }
+        for child in RewriteHelper::expression_plan_children(expr)? {
+            Self::collect_names(&child, names)?;
+        }
+        Ok(())
+    }
+
+    /// Find the outermost subexpressions of `expr` named in `names`.
+    fn collect_common(
+        expr: &Expression,
+        names: &HashSet<String>,
+        common: &mut Vec<Expression>,
+    ) -> Result<()> {
+        if Self::is_leaf(expr) {
+            return Ok(());
+        }
+        if names.contains(&expr.column_name()) && Self::is_computable(expr)? {
+            if !common.contains(expr) {
+                common.push(expr.clone());
+            }
+            return Ok(());
+        }
+        for child in RewriteHelper::expression_plan_children(expr)? {
+            Self::collect_common(&child, names, common)?;
+        }
+        Ok(())
+    }
+
+    fn eliminate(expression: &ExpressionPlan, filter: &FilterPlan) -> Result<Option<PlanNode>> {
+        let mut names = HashSet::new();
+        for expr in &expression.exprs {
+            Self::collect_names(expr, &mut names)?;
+        }
+
+        let mut common = vec![];
+        Self::collect_common(&filter.predicate, &names, &mut common)?;
+
+        // Computed further down already.
+        let input_schema = filter.input.schema();
+        common.retain(|expr| input_schema.field_with_name(&expr.column_name()).is_err());
+        if common.is_empty() {
+            return Ok(None);
+        }
+
+        let predicate = rebase_expr(&filter.predicate, &common)?;
+        let exprs = expression
+            .exprs
+            .iter()
+            .map(|expr| rebase_expr(expr, &common))
+            .collect::<Result<Vec<_>>>()?;
+
+        let plan = PlanBuilder::from(filter.input.as_ref())
+            .expression(&common, "Common Subexpressions")?
+            .filter(predicate)?
+            .expression(&exprs, &expression.desc)?
+            .build()?;
+        Ok(Some(plan))
+    }
+}
+
+impl Optimizer for CommonSubexpressionOptimizer {
+    fn name(&self) -> &str {
+        "CommonSubexpression"
+    }
+
+    fn optimize(&mut self, plan: &PlanNode) -> Result<PlanNode> {
+        plan.transform_up(&mut |node| {
+            if let PlanNode::Expression(expression) = &node {
+                if let PlanNode::Filter(filter) = expression.input.as_ref() {
+                    if let Some(plan) = Self::eliminate(expression, filter)? {
+                        return Ok(plan);
+                    }
+                }
+            }
+            Ok(node)
+        })
+    }
+}
@@ -50,7 +50,7 @@ impl ConstantFoldingImpl {
         let factory = FunctionFactory::instance();
         let function_features = factory.get_features(op)?;
 
-        if function_features.is_deterministic && Self::constants_arguments(&args) {
+        if function_features.is_deterministic() && Self::constants_arguments(&args) {
             let op = op.to_string();
             return ConstantFoldingImpl::execute_expression(
                 Expression::ScalarFunction { op, args },
@@ -61,6 +61,29 @@ impl ConstantFoldingImpl {
         Ok(f(op, args))
     }
 
+    // `x AND false` is false and `x OR true` is true whatever x is, even NULL.
+    fn short_circuit(op: &str, args: &[Expression], name: &str) -> Option<Expression> {
+        let dominant = match op.to_lowercase().as_str() {
+            "and" => false,
+            "or" => true,
+            _ => return None,
+        };
+
+        let is_dominant = |expr: &Expression| match expr {
+            Expression::Literal { value, .. } => *value == DataValue::Boolean(dominant),
+            _ => false,
+        };
+
+        match args.iter().any(is_dominant) {
+            true => Some(Expression::Literal {
+                value: DataValue::Boolean(dominant),
+                column_name: Some(name.to_string()),
+                data_type: bool::to_data_type(),
+            }),
+            false => None,
+        }
+    }
+
     fn expr_executor(schema: &DataSchemaRef, expr: Expression) -> Result<ExpressionExecutor> {
         let output_fields = vec![expr.to_data_field(schema)?];
         let output_schema = DataSchemaRefExt::create(output_fields);
@@ -153,9 +176,14 @@ impl PlanRewriter for ConstantFoldingImpl {
                 origin_expr: &Expression,
             ) -> Result<Expression> {
                 let origin_name = origin_expr.column_name();
+                let args = vec![left, right];
+                if let Some(expr) = ConstantFoldingImpl::short_circuit(op, &args, &origin_name) {
+                    return Ok(expr);
+                }
+
                 ConstantFoldingImpl::rewrite_function(
                     op,
-                    vec![left, right],
+                    args,
                     origin_name,
                     Expression::create_binary_expression,
                 )
@@ -217,6 +217,10 @@ impl ExprTransformImpl {
         }
     }
 
+    fn is_literal_true(expr: &Expression) -> bool {
+        matches!(expr, Expression::Literal { value, .. } if *value == DataValue::Boolean(true))
+    }
+
     fn eval_const_cond(
         column_name: String,
         expr: &Expression,
@@ -306,6 +310,10 @@ impl PlanRewriter for ExprTransformImpl {
         let new_predicate = Self::constant_transformer(&plan.predicate)?;
         let new_predicate = Self::boolean_transformer(&new_predicate)?;
         let new_predicate = Self::truth_transformer(&new_predicate, false)?;
+        if Self::is_literal_true(&new_predicate) {
+            // An always-true filter filters nothing.
+            return Ok(new_input);
+        }
         PlanBuilder::from(&new_input).filter(new_predicate)?.build()
     }
 
@@ -323,6 +331,10 @@ impl PlanRewriter for ExprTransformImpl {
         let new_predicate = Self::constant_transformer(&plan.predicate)?;
         let new_predicate = Self::boolean_transformer(&new_predicate)?;
         let new_predicate = Self::truth_transformer(&new_predicate, false)?;
+        if Self::is_literal_true(&new_predicate) {
+            // An always-true having filters nothing.
+            return Ok(new_input);
+        }
         PlanBuilder::from(&new_input).having(new_predicate)?.build()
     }
 
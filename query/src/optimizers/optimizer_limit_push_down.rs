@@ -0,0 +1,67 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_planners::LimitPlan;
+use common_planners::PlanNode;
+
+use crate::optimizers::Optimizer;
+use crate::sessions::QueryContext;
+
+/// Push a Limit below the projections and expressions under it, so they are only
+/// evaluated on the rows which are kept.
+///
+/// The Limit then sits on top of the source or the stateful operator (Filter, Sort, ...)
+/// feeding it, where the scatters optimizer splits it into a partial limit on each node.
+pub struct LimitPushDownOptimizer {}
+
+impl LimitPushDownOptimizer {
+    pub fn create(_ctx: Arc<QueryContext>) -> LimitPushDownOptimizer {
+        LimitPushDownOptimizer {}
+    }
+
+    fn push_down(limit: &LimitPlan, node: &PlanNode) -> Result<PlanNode> {
+        match node {
+            PlanNode::Projection(_) | PlanNode::Expression(_) => {
+                let input = Self::push_down(limit, node.input(0).as_ref())?;
+                node.with_new_inputs(&[input])
+            }
+            _ => Ok(PlanNode::Limit(LimitPlan {
+                n: limit.n,
+                offset: limit.offset,
+                input: Arc::new(node.clone()),
+            })),
+        }
+    }
+}
+
+impl Optimizer for LimitPushDownOptimizer {
+    fn name(&self) -> &str {
+        "LimitPushDown"
+    }
+
+    fn optimize(&mut self, plan: &PlanNode) -> Result<PlanNode> {
+        plan.transform_up(&mut |node| match &node {
+            PlanNode::Limit(limit) => match limit.input.as_ref() {
+                PlanNode::Projection(_) | PlanNode::Expression(_) => {
+                    Self::push_down(limit, limit.input.as_ref())
+                }
+                _ => Ok(node),
+            },
+            _ => Ok(node),
+        })
+    }
+}
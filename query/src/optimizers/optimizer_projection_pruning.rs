@@ -0,0 +1,279 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::*;
+
+use crate::optimizers::Optimizer;
+use crate::sessions::QueryContext;
+
+/// Computes the columns required by each plan node from the top down, drops the projection
+/// items nobody above asks for and narrows the projection of every read source to the
+/// required columns, so that a table is never read for columns the query doesn't use.
+pub struct ProjectionPruningOptimizer {}
+
+struct ProjectionPruningImpl {
+    before_group_by_schema: Option<DataSchemaRef>,
+    // The names of the columns required by the nodes above, None if all of them are required.
+    required_columns: Option<HashSet<String>>,
+}
+
+// Collects the name of every sub-expression, since a node above may reuse a value computed
+// by a node below by its name, not only the columns.
+#[derive(Default)]
+struct RequiredNamesVisitor {
+    names: HashSet<String>,
+}
+
+impl ExpressionVisitor for RequiredNamesVisitor {
+    fn pre_visit(self, expr: &Expression) -> Result<Recursion<Self>> {
+        let mut visitor = self;
+        visitor.names.insert(expr.column_name());
+        Ok(Recursion::Continue(visitor))
+    }
+}
+
+impl PlanRewriter for ProjectionPruningImpl {
+    fn rewrite_aggregate_partial(&mut self, plan: &AggregatorPartialPlan) -> Result<PlanNode> {
+        self.require_exprs(&plan.aggr_expr)?;
+        self.require_exprs(&plan.group_expr)?;
+
+        let new_input = self.rewrite_plan_node(&plan.input)?;
+        match self.before_group_by_schema {
+            Some(_) => Err(ErrorCode::LogicalError(
+                "Logical error: before group by schema must be None",
+            )),
+            None => {
+                self.before_group_by_schema = Some(new_input.schema());
+                let new_aggr_expr = self.rewrite_exprs(&new_input.schema(), &plan.aggr_expr)?;
+                let new_group_expr = self.rewrite_exprs(&new_input.schema(), &plan.group_expr)?;
+                PlanBuilder::from(&new_input)
+                    .aggregate_partial(&new_aggr_expr, &new_group_expr)?
+                    .build()
+            }
+        }
+    }
+
+    fn rewrite_aggregate_final(&mut self, plan: &AggregatorFinalPlan) -> Result<PlanNode> {
+        // The aggregation only outputs the aggregate functions and the group by keys.
+        self.required_columns = Some(HashSet::new());
+        self.require_exprs(&plan.aggr_expr)?;
+        self.require_exprs(&plan.group_expr)?;
+
+        let new_input = self.rewrite_plan_node(&plan.input)?;
+        match self.before_group_by_schema.take() {
+            None => Err(ErrorCode::LogicalError(
+                "Logical error: before group by schema must be Some",
+            )),
+            Some(schema_before_group_by) => {
+                let new_aggr_expr = self.rewrite_exprs(&new_input.schema(), &plan.aggr_expr)?;
+                let new_group_expr = self.rewrite_exprs(&new_input.schema(), &plan.group_expr)?;
+                PlanBuilder::from(&new_input)
+                    .aggregate_final(schema_before_group_by, &new_aggr_expr, &new_group_expr)?
+                    .build()
+            }
+        }
+    }
+
+    fn rewrite_projection(&mut self, plan: &ProjectionPlan) -> Result<PlanNode> {
+        let exprs = self.prune_exprs(&plan.expr);
+
+        // The projection only outputs its own expressions.
+        self.required_columns = Some(HashSet::new());
+        self.require_exprs(&exprs)?;
+
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        let new_exprs = self.rewrite_exprs(&new_input.schema(), &exprs)?;
+        PlanBuilder::from(&new_input).project(&new_exprs)?.build()
+    }
+
+    fn rewrite_expression(&mut self, plan: &ExpressionPlan) -> Result<PlanNode> {
+        let exprs = self.prune_exprs(&plan.exprs);
+        self.require_exprs(&exprs)?;
+
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        let new_exprs = self.rewrite_exprs(&new_input.schema(), &exprs)?;
+        PlanBuilder::from(&new_input)
+            .expression(&new_exprs, &plan.desc)?
+            .build()
+    }
+
+    fn rewrite_filter(&mut self, plan: &FilterPlan) -> Result<PlanNode> {
+        self.require_exprs(&[plan.predicate.clone()])?;
+
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        let new_predicate = self.rewrite_expr(&new_input.schema(), &plan.predicate)?;
+        PlanBuilder::from(&new_input).filter(new_predicate)?.build()
+    }
+
+    fn rewrite_having(&mut self, plan: &HavingPlan) -> Result<PlanNode> {
+        self.require_exprs(&[plan.predicate.clone()])?;
+
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        let new_predicate = self.rewrite_expr(&new_input.schema(), &plan.predicate)?;
+        PlanBuilder::from(&new_input).having(new_predicate)?.build()
+    }
+
+    fn rewrite_sort(&mut self, plan: &SortPlan) -> Result<PlanNode> {
+        self.require_exprs(&plan.order_by)?;
+
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        let new_order_by = self.rewrite_exprs(&new_input.schema(), &plan.order_by)?;
+        PlanBuilder::from(&new_input).sort(&new_order_by)?.build()
+    }
+
+    fn rewrite_top_n(&mut self, plan: &TopNPlan) -> Result<PlanNode> {
+        self.require_exprs(&plan.order_by)?;
+
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        let new_order_by = self.rewrite_exprs(&new_input.schema(), &plan.order_by)?;
+        PlanBuilder::from(&new_input)
+            .top_n(&new_order_by, plan.limit)?
+            .build()
+    }
+
+    fn rewrite_distinct(&mut self, plan: &DistinctPlan) -> Result<PlanNode> {
+        self.require_exprs(&plan.distinct_on)?;
+
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        let new_distinct_on = self.rewrite_exprs(&new_input.schema(), &plan.distinct_on)?;
+        PlanBuilder::from(&new_input)
+            .distinct(&new_distinct_on)?
+            .build()
+    }
+
+    fn rewrite_limit_by(&mut self, plan: &LimitByPlan) -> Result<PlanNode> {
+        self.require_exprs(&plan.limit_by)?;
+
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        PlanBuilder::from(&new_input)
+            .limit_by(plan.limit, &plan.limit_by)?
+            .build()
+    }
+
+    fn rewrite_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<PlanNode> {
+        if let Some(extras) = &plan.push_downs {
+            self.require_exprs(&extras.filters)?;
+            self.require_exprs(&extras.order_by)?;
+        }
+
+        let required = match &self.required_columns {
+            Some(required) => required,
+            None => return Ok(PlanNode::ReadSource(plan.clone())),
+        };
+
+        // Only a source which has already been told which columns to read is narrowed,
+        // the others may not honor the projection.
+        let projection = match &plan.push_downs {
+            Some(Extras {
+                projection: Some(projection),
+                ..
+            }) => projection,
+            _ => return Ok(PlanNode::ReadSource(plan.clone())),
+        };
+
+        let schema = plan.source_info.schema();
+        let new_projection = projection
+            .iter()
+            .filter(|index| required.contains(schema.field(**index).name()))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        // Keep at least one column, to know the number of rows.
+        if new_projection.is_empty() || new_projection.len() == projection.len() {
+            return Ok(PlanNode::ReadSource(plan.clone()));
+        }
+
+        let mut new_plan = plan.clone();
+        new_plan.scan_fields = Some(
+            new_projection
+                .iter()
+                .map(|index| (*index, schema.field(*index).clone()))
+                .collect::<BTreeMap<_, _>>(),
+        );
+        if let Some(extras) = &mut new_plan.push_downs {
+            extras.projection = Some(new_projection);
+        }
+        Ok(PlanNode::ReadSource(new_plan))
+    }
+
+    fn rewrite_subquery_plan(&mut self, subquery_plan: &PlanNode) -> Result<PlanNode> {
+        let mut optimizer = ProjectionPruningOptimizer {};
+        optimizer.optimize(subquery_plan)
+    }
+}
+
+impl ProjectionPruningImpl {
+    pub fn new() -> ProjectionPruningImpl {
+        ProjectionPruningImpl {
+            before_group_by_schema: None,
+            required_columns: None,
+        }
+    }
+
+    // Drop the expressions not required above, unless none of them is.
+    fn prune_exprs(&self, exprs: &[Expression]) -> Vec<Expression> {
+        if let Some(required) = &self.required_columns {
+            let pruned = exprs
+                .iter()
+                .filter(|expr| required.contains(&expr.column_name()))
+                .cloned()
+                .collect::<Vec<_>>();
+            if !pruned.is_empty() {
+                return pruned;
+            }
+        }
+        exprs.to_vec()
+    }
+
+    fn require_exprs(&mut self, exprs: &[Expression]) -> Result<()> {
+        if exprs
+            .iter()
+            .any(|expr| matches!(expr, Expression::Wildcard))
+        {
+            self.required_columns = None;
+        }
+
+        if let Some(required) = &mut self.required_columns {
+            for expr in exprs {
+                let visitor = expr.accept(RequiredNamesVisitor::default())?;
+                required.extend(visitor.names);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Optimizer for ProjectionPruningOptimizer {
+    fn name(&self) -> &str {
+        "ProjectionPruning"
+    }
+
+    fn optimize(&mut self, plan: &PlanNode) -> Result<PlanNode> {
+        let mut visitor = ProjectionPruningImpl::new();
+        visitor.rewrite_plan_node(plan)
+    }
+}
+
+impl ProjectionPruningOptimizer {
+    pub fn create(_ctx: Arc<QueryContext>) -> ProjectionPruningOptimizer {
+        ProjectionPruningOptimizer {}
+    }
+}
@@ -21,6 +21,7 @@ use common_exception::Result;
 use common_planners::AggregatorFinalPlan;
 use common_planners::AggregatorPartialPlan;
 use common_planners::BroadcastPlan;
+use common_planners::DistinctPlan;
 use common_planners::Expression;
 use common_planners::LimitByPlan;
 use common_planners::LimitPlan;
@@ -31,6 +32,8 @@ use common_planners::ReadDataSourcePlan;
 use common_planners::SortPlan;
 use common_planners::StageKind;
 use common_planners::StagePlan;
+use common_planners::TopNPlan;
+use common_planners::ValuesPlan;
 
 use crate::optimizers::Optimizer;
 use crate::sessions::QueryContext;
@@ -130,15 +133,84 @@ impl ScattersOptimizerImpl {
         }
     }
 
+    fn cluster_top_n(&mut self, plan: &TopNPlan) -> Result<PlanNode> {
+        // Top n we convergent it in local node
+        self.running_mode = RunningMode::Standalone;
+
+        match self.input.take() {
+            None => Err(ErrorCode::LogicalError("Cluster top n input is None")),
+            Some(input) => {
+                // Each node only needs to send its own top n rows to be merged.
+                let input = PlanBuilder::from(input.as_ref())
+                    .top_n(&plan.order_by, plan.limit)?
+                    .build()?;
+
+                Self::convergent_shuffle_stage_builder(Arc::new(input))
+                    .top_n(&plan.order_by, plan.limit)?
+                    .build()
+            }
+        }
+    }
+
+    fn standalone_top_n(&mut self, plan: &TopNPlan) -> Result<PlanNode> {
+        match self.input.take() {
+            None => Err(ErrorCode::LogicalError("Standalone top n input is None")),
+            Some(input) => PlanBuilder::from(input.as_ref())
+                .top_n(&plan.order_by, plan.limit)?
+                .build(),
+        }
+    }
+
+    fn cluster_distinct(&mut self, plan: &DistinctPlan) -> Result<PlanNode> {
+        // Distinct we convergent it in local node
+        self.running_mode = RunningMode::Standalone;
+
+        match self.input.take() {
+            None => Err(ErrorCode::LogicalError("Cluster distinct input is None")),
+            Some(input) => {
+                // Each node only needs to send its own distinct rows to be merged.
+                let input = PlanBuilder::from(input.as_ref())
+                    .distinct(&plan.distinct_on)?
+                    .build()?;
+
+                Self::convergent_shuffle_stage_builder(Arc::new(input))
+                    .distinct(&plan.distinct_on)?
+                    .build()
+            }
+        }
+    }
+
+    fn standalone_distinct(&mut self, plan: &DistinctPlan) -> Result<PlanNode> {
+        match self.input.take() {
+            None => Err(ErrorCode::LogicalError("Standalone distinct input is None")),
+            Some(input) => PlanBuilder::from(input.as_ref())
+                .distinct(&plan.distinct_on)?
+                .build(),
+        }
+    }
+
     fn cluster_limit(&mut self, plan: &LimitPlan) -> Result<PlanNode> {
         // Limit we convergent it in local node
         self.running_mode = RunningMode::Standalone;
 
         match self.input.take() {
             None => Err(ErrorCode::LogicalError("Cluster limit input is None")),
-            Some(input) => Self::convergent_shuffle_stage_builder(input)
-                .limit_offset(plan.n, plan.offset)?
-                .build(),
+            Some(input) => {
+                // Each node keeps at most limit + offset rows, the offset can only be
+                // skipped once all the rows are merged in local node.
+                let input = match plan.n {
+                    None => input,
+                    Some(n) => Arc::new(
+                        PlanBuilder::from(input.as_ref())
+                            .limit(n.saturating_add(plan.offset))?
+                            .build()?,
+                    ),
+                };
+
+                Self::convergent_shuffle_stage_builder(input)
+                    .limit_offset(plan.n, plan.offset)?
+                    .build()
+            }
         }
     }
 
@@ -157,9 +229,16 @@ impl ScattersOptimizerImpl {
 
         match self.input.take() {
             None => Err(ErrorCode::LogicalError("Cluster limit by input is None.")),
-            Some(input) => Self::convergent_shuffle_stage_builder(input)
-                .limit_by(plan.limit, &plan.limit_by)?
-                .build(),
+            Some(input) => {
+                // Rows dropped by the limit by in each node would be dropped after merged too.
+                let input = PlanBuilder::from(input.as_ref())
+                    .limit_by(plan.limit, &plan.limit_by)?
+                    .build()?;
+
+                Self::convergent_shuffle_stage_builder(Arc::new(input))
+                    .limit_by(plan.limit, &plan.limit_by)?
+                    .build()
+            }
         }
     }
 
@@ -260,6 +339,24 @@ impl PlanRewriter for ScattersOptimizerImpl {
         }
     }
 
+    fn rewrite_top_n(&mut self, plan: &TopNPlan) -> Result<PlanNode> {
+        self.input = Some(Arc::new(self.rewrite_plan_node(plan.input.as_ref())?));
+
+        match self.running_mode {
+            RunningMode::Cluster => self.cluster_top_n(plan),
+            RunningMode::Standalone => self.standalone_top_n(plan),
+        }
+    }
+
+    fn rewrite_distinct(&mut self, plan: &DistinctPlan) -> Result<PlanNode> {
+        self.input = Some(Arc::new(self.rewrite_plan_node(plan.input.as_ref())?));
+
+        match self.running_mode {
+            RunningMode::Cluster => self.cluster_distinct(plan),
+            RunningMode::Standalone => self.standalone_distinct(plan),
+        }
+    }
+
     fn rewrite_limit(&mut self, plan: &LimitPlan) -> Result<PlanNode> {
         self.input = Some(Arc::new(self.rewrite_plan_node(plan.input.as_ref())?));
 
@@ -288,6 +385,11 @@ impl PlanRewriter for ScattersOptimizerImpl {
 
         Ok(PlanNode::ReadSource(plan.clone()))
     }
+
+    fn rewrite_values(&mut self, plan: &ValuesPlan) -> Result<PlanNode> {
+        self.running_mode = RunningMode::Standalone;
+        Ok(PlanNode::Values(plan.clone()))
+    }
 }
 
 impl ScattersOptimizer {
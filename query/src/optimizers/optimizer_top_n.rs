@@ -0,0 +1,78 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_planners::LimitPlan;
+use common_planners::PlanNode;
+use common_planners::TopNPlan;
+
+use crate::optimizers::Optimizer;
+use crate::sessions::QueryContext;
+
+/// Fuse a Sort followed by a Limit into a TopN, which only keeps the first rows of
+/// every stream instead of sorting all of them.
+///
+/// Projections and expressions between the two are kept, they neither drop nor reorder rows.
+/// The Limit itself stays on top to skip the offset.
+pub struct TopNOptimizer {}
+
+impl TopNOptimizer {
+    pub fn create(_ctx: Arc<QueryContext>) -> TopNOptimizer {
+        TopNOptimizer {}
+    }
+
+    fn fuse_sort(node: &PlanNode, limit: usize) -> Result<Option<PlanNode>> {
+        match node {
+            PlanNode::Sort(plan) => Ok(Some(PlanNode::TopN(TopNPlan {
+                order_by: plan.order_by.clone(),
+                limit,
+                input: plan.input.clone(),
+                schema: plan.schema(),
+            }))),
+            PlanNode::Projection(_) | PlanNode::Expression(_) => {
+                match Self::fuse_sort(node.input(0).as_ref(), limit)? {
+                    None => Ok(None),
+                    Some(input) => Ok(Some(node.with_new_inputs(&[input])?)),
+                }
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+impl Optimizer for TopNOptimizer {
+    fn name(&self) -> &str {
+        "TopN"
+    }
+
+    fn optimize(&mut self, plan: &PlanNode) -> Result<PlanNode> {
+        plan.transform_up(&mut |node| match &node {
+            PlanNode::Limit(LimitPlan {
+                n: Some(n),
+                offset,
+                input,
+            }) => match Self::fuse_sort(input.as_ref(), n + offset)? {
+                None => Ok(node),
+                Some(input) => Ok(PlanNode::Limit(LimitPlan {
+                    n: Some(*n),
+                    offset: *offset,
+                    input: Arc::new(input),
+                })),
+            },
+            _ => Ok(node),
+        })
+    }
+}
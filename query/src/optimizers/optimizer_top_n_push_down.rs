@@ -75,6 +75,17 @@ impl PlanRewriter for TopNPushDownImpl {
         }
     }
 
+    fn rewrite_distinct(&mut self, plan: &DistinctPlan) -> Result<PlanNode> {
+        // The rows removed by the distinct are unknown, so we clear the top n option.
+        self.limit = None;
+
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        let new_distinct_on = self.rewrite_exprs(&new_input.schema(), &plan.distinct_on)?;
+        PlanBuilder::from(&new_input)
+            .distinct(&new_distinct_on)?
+            .build()
+    }
+
     fn rewrite_limit(&mut self, plan: &LimitPlan) -> Result<PlanNode> {
         let current_limit = self.limit;
         let current_order_by = self.order_by.clone();
@@ -112,6 +123,7 @@ impl PlanRewriter for TopNPushDownImpl {
                         filters: extras.filters.clone(),
                         limit: Some(new_limit),
                         order_by: self.get_sort_columns(plan.schema())?,
+                        sample: extras.sample,
                     })
                 }
                 None => {
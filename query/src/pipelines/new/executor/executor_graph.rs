@@ -28,6 +28,9 @@ use petgraph::prelude::NodeIndex;
 use petgraph::prelude::StableGraph;
 use petgraph::Direction;
 
+use crate::pipelines::new::executor::executor_profile::EdgeProfile;
+use crate::pipelines::new::executor::executor_profile::PipelineProfile;
+use crate::pipelines::new::executor::executor_profile::ProcessorProfile;
 use crate::pipelines::new::executor::executor_tasks::ExecutorTasksQueue;
 use crate::pipelines::new::executor::executor_worker_context::ExecutorTask;
 use crate::pipelines::new::executor::executor_worker_context::ExecutorWorkerContext;
@@ -42,6 +45,7 @@ use crate::pipelines::new::processors::DirectedEdge;
 use crate::pipelines::new::processors::UpdateList;
 use crate::pipelines::new::processors::UpdateTrigger;
 
+#[derive(Debug)]
 enum State {
     Idle,
     // Preparing,
@@ -54,9 +58,7 @@ struct Node {
     processor: ProcessorPtr,
 
     updated_list: Arc<UpdateList>,
-    #[allow(dead_code)]
     inputs_port: Vec<Arc<InputPort>>,
-    #[allow(dead_code)]
     outputs_port: Vec<Arc<OutputPort>>,
 }
 
@@ -82,6 +84,19 @@ impl Node {
     pub unsafe fn create_trigger(&self, index: EdgeIndex) -> *mut UpdateTrigger {
         self.updated_list.create_trigger(index)
     }
+
+    pub fn profile(&self, index: NodeIndex) -> ProcessorProfile {
+        ProcessorProfile {
+            id: index.index(),
+            name: unsafe { self.processor.name().to_string() },
+            state: format!("{:?}", *self.state.lock().unwrap()),
+            rows_in: self.inputs_port.iter().map(|p| p.pulled_rows()).sum(),
+            rows_out: self.outputs_port.iter().map(|p| p.pushed_rows()).sum(),
+            bytes_in: self.inputs_port.iter().map(|p| p.pulled_bytes()).sum(),
+            bytes_out: self.outputs_port.iter().map(|p| p.pushed_bytes()).sum(),
+            busy_time_ms: self.processor.busy_time().as_millis() as u64,
+        }
+    }
 }
 
 struct ExecutingGraph {
@@ -184,6 +199,26 @@ impl ExecutingGraph {
         Ok(ExecutingGraph { graph })
     }
 
+    pub fn profile(&self) -> PipelineProfile {
+        let processors = self
+            .graph
+            .node_indices()
+            .map(|index| self.graph[index].profile(index))
+            .collect();
+
+        let edges = self
+            .graph
+            .edge_indices()
+            .filter_map(|index| self.graph.edge_endpoints(index))
+            .map(|(from, to)| EdgeProfile {
+                from: from.index(),
+                to: to.index(),
+            })
+            .collect();
+
+        PipelineProfile { processors, edges }
+    }
+
     /// # Safety
     ///
     /// Method is thread unsafe and require thread safe call
@@ -348,6 +383,10 @@ impl RunningGraph {
         ExecutingGraph::schedule_queue(&self.0.upgradable_read(), node_index, &mut schedule_queue)?;
         Ok(schedule_queue)
     }
+
+    pub fn profile(&self) -> PipelineProfile {
+        self.0.read().profile()
+    }
 }
 
 impl Debug for Node {
@@ -0,0 +1,71 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The runtime state of a processor in the executing graph.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ProcessorProfile {
+    pub id: usize,
+    pub name: String,
+    /// Idle, Processing or Finished.
+    pub state: String,
+    pub rows_in: usize,
+    pub rows_out: usize,
+    pub bytes_in: usize,
+    pub bytes_out: usize,
+    pub busy_time_ms: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct EdgeProfile {
+    pub from: usize,
+    pub to: usize,
+}
+
+/// A snapshot of the executing graph, taken while the query runs.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PipelineProfile {
+    pub processors: Vec<ProcessorProfile>,
+    pub edges: Vec<EdgeProfile>,
+}
+
+impl PipelineProfile {
+    /// Renders the graph in the graphviz DOT language.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph {\n");
+        for p in &self.processors {
+            let _ = writeln!(
+                dot,
+                "    {} [ label = \"{}\\n{}\\nrows in: {}, rows out: {}\\nbytes in: {}, bytes out: {}\\nbusy: {}ms\" ]",
+                p.id,
+                p.name,
+                p.state,
+                p.rows_in,
+                p.rows_out,
+                p.bytes_in,
+                p.bytes_out,
+                p.busy_time_ms
+            );
+        }
+        for edge in &self.edges {
+            let _ = writeln!(dot, "    {} -> {}", edge.from, edge.to);
+        }
+        dot.push('}');
+        dot
+    }
+}
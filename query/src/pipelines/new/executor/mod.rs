@@ -16,6 +16,7 @@ mod pipeline_executor;
 
 mod executor_graph;
 mod executor_notify;
+mod executor_profile;
 mod executor_tasks;
 mod executor_worker_context;
 mod pipeline_complete_executor;
@@ -23,5 +24,8 @@ mod pipeline_pulling_executor;
 mod pipeline_pushing_executor;
 
 pub use executor_graph::RunningGraph;
+pub use executor_profile::EdgeProfile;
+pub use executor_profile::PipelineProfile;
+pub use executor_profile::ProcessorProfile;
 pub use pipeline_executor::PipelineExecutor;
 pub use pipeline_pulling_executor::PipelinePullingExecutor;
@@ -13,33 +13,49 @@
 // limitations under the License.
 
 use std::collections::VecDeque;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::thread::JoinHandle;
+use std::time::Duration;
+use std::time::Instant;
 
 use common_base::Runtime;
 use common_base::Thread;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_infallible::Mutex;
 use common_tracing::tracing;
 
 use crate::pipelines::new::executor::executor_graph::RunningGraph;
 use crate::pipelines::new::executor::executor_notify::WorkersNotify;
+use crate::pipelines::new::executor::executor_profile::PipelineProfile;
 use crate::pipelines::new::executor::executor_tasks::ExecutorTasksQueue;
 use crate::pipelines::new::executor::executor_worker_context::ExecutorWorkerContext;
 use crate::pipelines::new::pipeline::NewPipeline;
 
+const WATCHDOG_INTERVAL: Duration = Duration::from_secs(1);
+
 pub struct PipelineExecutor {
     threads_num: usize,
     graph: RunningGraph,
     workers_notify: Arc<WorkersNotify>,
     pub async_runtime: Arc<Runtime>,
     pub global_tasks_queue: Arc<ExecutorTasksQueue>,
+
+    stall_timeout: Duration,
+    abort_on_stall: bool,
+    // The number of the processed tasks, the watchdog checks it changes.
+    processed_tasks: AtomicUsize,
+    stall_error: Mutex<Option<ErrorCode>>,
 }
 
 impl PipelineExecutor {
     pub fn create(async_rt: Arc<Runtime>, pipeline: NewPipeline) -> Result<Arc<PipelineExecutor>> {
         unsafe {
             let threads_num = pipeline.get_max_threads();
+            let stall_timeout = pipeline.get_stall_timeout();
+            let abort_on_stall = pipeline.get_abort_on_stall();
             let workers_notify = WorkersNotify::create(threads_num);
             let global_tasks_queue = ExecutorTasksQueue::create(threads_num);
 
@@ -58,6 +74,10 @@ impl PipelineExecutor {
                 workers_notify,
                 global_tasks_queue,
                 async_runtime: async_rt,
+                stall_timeout,
+                abort_on_stall,
+                processed_tasks: AtomicUsize::new(0),
+                stall_error: Mutex::new(None),
             }))
         }
     }
@@ -68,7 +88,16 @@ impl PipelineExecutor {
         Ok(())
     }
 
+    /// The processors of the executing graph with their current state and statistics.
+    pub fn get_profile(&self) -> PipelineProfile {
+        self.graph.profile()
+    }
+
     pub fn execute(self: &Arc<Self>) -> Result<()> {
+        if !self.stall_timeout.is_zero() {
+            self.start_watchdog();
+        }
+
         let mut threads = self.execute_threads(self.threads_num);
 
         while let Some(join_handle) = threads.pop() {
@@ -80,7 +109,68 @@ impl PipelineExecutor {
             }?;
         }
 
-        Ok(())
+        match self.stall_error.lock().take() {
+            Some(cause) => Err(cause),
+            None => Ok(()),
+        }
+    }
+
+    // Checks the processors make progress until the executor finishes.
+    fn start_watchdog(self: &Arc<Self>) {
+        let this = self.clone();
+        let name = Some("PipelineWatchdog".to_string());
+        Thread::named_spawn(name, move || {
+            let mut processed_tasks = this.processed_tasks.load(Ordering::Relaxed);
+            let mut last_progress = Instant::now();
+
+            while !this.global_tasks_queue.is_finished() {
+                std::thread::sleep(WATCHDOG_INTERVAL);
+
+                let now_processed_tasks = this.processed_tasks.load(Ordering::Relaxed);
+                if now_processed_tasks != processed_tasks {
+                    processed_tasks = now_processed_tasks;
+                    last_progress = Instant::now();
+                    continue;
+                }
+
+                if last_progress.elapsed() >= this.stall_timeout
+                    && !this.global_tasks_queue.is_finished()
+                {
+                    this.on_stall();
+                    last_progress = Instant::now();
+                }
+            }
+        });
+    }
+
+    fn on_stall(&self) {
+        let profile = self.get_profile();
+        tracing::warn!(
+            "Pipeline executor made no progress for {:?}, processors graph:\n{}",
+            self.stall_timeout,
+            profile.to_dot()
+        );
+
+        if self.abort_on_stall {
+            let running = profile
+                .processors
+                .iter()
+                .filter(|p| p.state != "Finished")
+                .map(|p| format!("{}({}): {}", p.name, p.id, p.state))
+                .collect::<Vec<_>>();
+
+            let mut stall_error = self.stall_error.lock();
+            *stall_error = Some(ErrorCode::AbortedQuery(format!(
+                "Aborted query, the pipeline made no progress for {:?}, unfinished processors: [{}]",
+                self.stall_timeout,
+                running.join(", ")
+            )));
+            drop(stall_error);
+
+            if let Err(cause) = self.finish() {
+                tracing::warn!("Catch error when abort stalled pipeline {:?}", cause);
+            }
+        }
     }
 
     fn execute_threads(self: &Arc<Self>, threads_size: usize) -> Vec<JoinHandle<Result<()>>> {
@@ -122,6 +212,8 @@ impl PipelineExecutor {
 
             while context.has_task() {
                 if let Some(executed_pid) = context.execute_task(self)? {
+                    self.processed_tasks.fetch_add(1, Ordering::Relaxed);
+
                     // We immediately schedule the processor again.
                     let schedule_queue = self.graph.schedule_queue(executed_pid)?;
                     schedule_queue.schedule(&self.global_tasks_queue, &mut context);
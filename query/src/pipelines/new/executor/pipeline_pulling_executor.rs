@@ -111,6 +111,10 @@ impl PipelinePullingExecutor {
         self.executor.finish()
     }
 
+    pub fn get_inner(&self) -> Arc<PipelineExecutor> {
+        self.executor.clone()
+    }
+
     pub fn pull_data(&mut self) -> Result<Option<DataBlock>> {
         match self.receiver.recv() {
             Ok(data_block) => data_block,
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -26,6 +27,8 @@ use crate::pipelines::new::processors::ResizeProcessor;
 
 pub struct NewPipeline {
     max_threads: usize,
+    stall_timeout: Duration,
+    abort_on_stall: bool,
     pub pipes: Vec<NewPipe>,
 }
 
@@ -33,6 +36,8 @@ impl NewPipeline {
     pub fn create() -> NewPipeline {
         NewPipeline {
             max_threads: 0,
+            stall_timeout: Duration::ZERO,
+            abort_on_stall: false,
             pipes: Vec::new(),
         }
     }
@@ -99,6 +104,21 @@ impl NewPipeline {
         self.max_threads
     }
 
+    /// The executor logs the processors graph, and aborts the query if `abort` is true,
+    /// when the pipeline makes no progress for `timeout`. A zero timeout disables the check.
+    pub fn set_stall_timeout(&mut self, timeout: Duration, abort: bool) {
+        self.stall_timeout = timeout;
+        self.abort_on_stall = abort;
+    }
+
+    pub fn get_stall_timeout(&self) -> Duration {
+        self.stall_timeout
+    }
+
+    pub fn get_abort_on_stall(&self) -> bool {
+        self.abort_on_stall
+    }
+
     pub fn add_transform<F>(&mut self, f: F) -> Result<()>
     where F: Fn(Arc<InputPort>, Arc<OutputPort>) -> Result<ProcessorPtr> {
         let mut transform_builder = TransformPipeBuilder::create();
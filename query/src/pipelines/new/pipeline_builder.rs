@@ -18,6 +18,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::AggregatorFinalPlan;
 use common_planners::AggregatorPartialPlan;
+use common_planners::DistinctPlan;
 use common_planners::ExpressionPlan;
 use common_planners::FilterPlan;
 use common_planners::HavingPlan;
@@ -30,8 +31,11 @@ use common_planners::ReadDataSourcePlan;
 use common_planners::SelectPlan;
 use common_planners::SortPlan;
 use common_planners::SubQueriesSetPlan;
+use common_planners::TopNPlan;
+use common_planners::ValuesPlan;
 
 use crate::pipelines::new::pipeline::NewPipeline;
+use crate::pipelines::new::processors::port::OutputPort;
 use crate::pipelines::new::processors::AggregatorParams;
 use crate::pipelines::new::processors::AggregatorTransformParams;
 use crate::pipelines::new::processors::ExpressionTransform;
@@ -39,12 +43,15 @@ use crate::pipelines::new::processors::ProjectionTransform;
 use crate::pipelines::new::processors::SubQueriesPuller;
 use crate::pipelines::new::processors::TransformAggregator;
 use crate::pipelines::new::processors::TransformCreateSets;
+use crate::pipelines::new::processors::TransformDistinct;
 use crate::pipelines::new::processors::TransformFilter;
 use crate::pipelines::new::processors::TransformHaving;
 use crate::pipelines::new::processors::TransformLimit;
 use crate::pipelines::new::processors::TransformLimitBy;
 use crate::pipelines::new::processors::TransformSortMerge;
 use crate::pipelines::new::processors::TransformSortPartial;
+use crate::pipelines::new::processors::ValuesSource;
+use crate::pipelines::new::SourcePipeBuilder;
 use crate::pipelines::transforms::get_sort_descriptions;
 use crate::sessions::QueryContext;
 
@@ -81,9 +88,12 @@ impl PlanVisitor for QueryPipelineBuilder {
             PlanNode::Filter(n) => self.visit_filter(n),
             PlanNode::Having(n) => self.visit_having(n),
             PlanNode::Sort(n) => self.visit_sort(n),
+            PlanNode::TopN(n) => self.visit_top_n(n),
+            PlanNode::Distinct(n) => self.visit_distinct(n),
             PlanNode::Limit(n) => self.visit_limit(n),
             PlanNode::LimitBy(n) => self.visit_limit_by(n),
             PlanNode::ReadSource(n) => self.visit_read_data_source(n),
+            PlanNode::Values(n) => self.visit_values(n),
             PlanNode::Select(n) => self.visit_select(n),
             PlanNode::SubQueryExpression(n) => self.visit_sub_queries_sets(n),
             _ => Err(ErrorCode::UnImplement("")),
@@ -271,6 +281,70 @@ impl PlanVisitor for QueryPipelineBuilder {
             })
     }
 
+    fn visit_top_n(&mut self, plan: &TopNPlan) -> Result<()> {
+        self.visit_plan_node(&plan.input)?;
+
+        // Every block and every merged stream only keeps the top n rows.
+        let rows_limit = Some(plan.limit);
+        self.pipeline
+            .add_transform(|transform_input_port, transform_output_port| {
+                TransformSortPartial::try_create(
+                    transform_input_port,
+                    transform_output_port,
+                    rows_limit,
+                    get_sort_descriptions(&plan.schema, &plan.order_by)?,
+                )
+            })?;
+
+        self.pipeline
+            .add_transform(|transform_input_port, transform_output_port| {
+                TransformSortMerge::try_create(
+                    transform_input_port,
+                    transform_output_port,
+                    rows_limit,
+                    get_sort_descriptions(&plan.schema, &plan.order_by)?,
+                )
+            })?;
+
+        self.pipeline.resize(1)?;
+        self.pipeline
+            .add_transform(|transform_input_port, transform_output_port| {
+                TransformSortMerge::try_create(
+                    transform_input_port,
+                    transform_output_port,
+                    rows_limit,
+                    get_sort_descriptions(&plan.schema, &plan.order_by)?,
+                )
+            })
+    }
+
+    fn visit_distinct(&mut self, plan: &DistinctPlan) -> Result<()> {
+        // The limit above can't be applied to the rows before the distinct.
+        self.limit = None;
+        self.offset = 0;
+        self.visit_plan_node(&plan.input)?;
+
+        // Deduplicate each stream first, then the same key found in different streams.
+        self.pipeline
+            .add_transform(|transform_input_port, transform_output_port| {
+                TransformDistinct::try_create(
+                    transform_input_port,
+                    transform_output_port,
+                    &plan.distinct_on,
+                )
+            })?;
+
+        self.pipeline.resize(1)?;
+        self.pipeline
+            .add_transform(|transform_input_port, transform_output_port| {
+                TransformDistinct::try_create(
+                    transform_input_port,
+                    transform_output_port,
+                    &plan.distinct_on,
+                )
+            })
+    }
+
     fn visit_limit_by(&mut self, plan: &LimitByPlan) -> Result<()> {
         self.visit_plan_node(&plan.input)?;
 
@@ -292,4 +366,16 @@ impl PlanVisitor for QueryPipelineBuilder {
         let table = self.ctx.build_table_from_source_plan(plan)?;
         table.read2(self.ctx.clone(), plan, &mut self.pipeline)
     }
+
+    fn visit_values(&mut self, plan: &ValuesPlan) -> Result<()> {
+        let output = OutputPort::create();
+        let mut builder = SourcePipeBuilder::create();
+        builder.add_source(
+            output.clone(),
+            ValuesSource::create(self.ctx.clone(), output, plan.clone())?,
+        );
+
+        self.pipeline.add_pipe(builder.finalize());
+        Ok(())
+    }
 }
@@ -39,6 +39,7 @@ pub use sources::EmptySource;
 pub use sources::SyncReceiverSource;
 pub use sources::SyncSource;
 pub use sources::SyncSourcer;
+pub use sources::ValuesSource;
 pub use transforms::AggregatorParams;
 pub use transforms::AggregatorTransformParams;
 pub use transforms::ExpressionTransform;
@@ -46,6 +47,7 @@ pub use transforms::ProjectionTransform;
 pub use transforms::SubQueriesPuller;
 pub use transforms::TransformAggregator;
 pub use transforms::TransformCreateSets;
+pub use transforms::TransformDistinct;
 pub use transforms::TransformDummy;
 pub use transforms::TransformFilter;
 pub use transforms::TransformHaving;
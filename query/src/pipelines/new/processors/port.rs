@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::sync::atomic::AtomicPtr;
+use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
@@ -111,6 +112,8 @@ impl SharedStatus {
 pub struct InputPort {
     shared: UnSafeCellWrap<Arc<SharedStatus>>,
     update_trigger: UnSafeCellWrap<*mut UpdateTrigger>,
+    pulled_rows: AtomicUsize,
+    pulled_bytes: AtomicUsize,
 }
 
 impl InputPort {
@@ -118,6 +121,8 @@ impl InputPort {
         Arc::new(InputPort {
             shared: UnSafeCellWrap::create(SharedStatus::create()),
             update_trigger: UnSafeCellWrap::create(std::ptr::null_mut()),
+            pulled_rows: AtomicUsize::new(0),
+            pulled_bytes: AtomicUsize::new(0),
         })
     }
 
@@ -168,11 +173,30 @@ impl InputPort {
             let unset_flags = HAS_DATA | NEED_DATA;
             match self.shared.swap(std::ptr::null_mut(), 0, unset_flags) {
                 address if address.is_null() => None,
-                address => Some((*Box::from_raw(address)).0),
+                address => {
+                    let data = (*Box::from_raw(address)).0;
+                    if let Ok(block) = &data {
+                        self.pulled_rows
+                            .fetch_add(block.num_rows(), Ordering::Relaxed);
+                        self.pulled_bytes
+                            .fetch_add(block.memory_size(), Ordering::Relaxed);
+                    }
+                    Some(data)
+                }
             }
         }
     }
 
+    /// The number of rows pulled from the port so far.
+    pub fn pulled_rows(&self) -> usize {
+        self.pulled_rows.load(Ordering::Relaxed)
+    }
+
+    /// The memory size of the blocks pulled from the port so far.
+    pub fn pulled_bytes(&self) -> usize {
+        self.pulled_bytes.load(Ordering::Relaxed)
+    }
+
     /// # Safety
     ///
     /// Method is thread unsafe and require thread safe call
@@ -191,6 +215,8 @@ impl InputPort {
 pub struct OutputPort {
     shared: UnSafeCellWrap<Arc<SharedStatus>>,
     update_trigger: UnSafeCellWrap<*mut UpdateTrigger>,
+    pushed_rows: AtomicUsize,
+    pushed_bytes: AtomicUsize,
 }
 
 impl OutputPort {
@@ -198,11 +224,20 @@ impl OutputPort {
         Arc::new(OutputPort {
             shared: UnSafeCellWrap::create(SharedStatus::create()),
             update_trigger: UnSafeCellWrap::create(std::ptr::null_mut()),
+            pushed_rows: AtomicUsize::new(0),
+            pushed_bytes: AtomicUsize::new(0),
         })
     }
 
     #[inline(always)]
     pub fn push_data(&self, data: Result<DataBlock>) {
+        if let Ok(block) = &data {
+            self.pushed_rows
+                .fetch_add(block.num_rows(), Ordering::Relaxed);
+            self.pushed_bytes
+                .fetch_add(block.memory_size(), Ordering::Relaxed);
+        }
+
         unsafe {
             UpdateTrigger::update_output(&self.update_trigger);
 
@@ -233,6 +268,16 @@ impl OutputPort {
         ((flags & NEED_DATA) == NEED_DATA) && ((flags & HAS_DATA) == 0)
     }
 
+    /// The number of rows pushed into the port so far.
+    pub fn pushed_rows(&self) -> usize {
+        self.pushed_rows.load(Ordering::Relaxed)
+    }
+
+    /// The memory size of the blocks pushed into the port so far.
+    pub fn pushed_bytes(&self) -> usize {
+        self.pushed_bytes.load(Ordering::Relaxed)
+    }
+
     /// # Safety
     ///
     /// Method is thread unsafe and require thread safe call
@@ -13,7 +13,11 @@
 // limitations under the License.
 
 use std::cell::UnsafeCell;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -52,6 +56,8 @@ pub trait Processor: Send {
 pub struct ProcessorPtr {
     id: Arc<UnsafeCell<NodeIndex>>,
     inner: Arc<UnsafeCell<Box<dyn Processor>>>,
+    // The time spent in process and async_process, in nanoseconds.
+    busy_time: Arc<AtomicU64>,
 }
 
 unsafe impl Send for ProcessorPtr {}
@@ -63,6 +69,7 @@ impl ProcessorPtr {
         ProcessorPtr {
             id: Arc::new(UnsafeCell::new(node_index(0))),
             inner: Arc::new(UnsafeCell::new(inner)),
+            busy_time: Arc::new(AtomicU64::new(0)),
         }
     }
 
@@ -88,13 +95,34 @@ impl ProcessorPtr {
 
     /// # Safety
     pub unsafe fn process(&self) -> Result<()> {
-        (*self.inner.get()).process()
+        let instant = Instant::now();
+        let res = (*self.inner.get()).process();
+        add_elapsed(&self.busy_time, instant);
+        res
     }
 
     /// # Safety
     pub unsafe fn async_process(&self) -> BoxFuture<'static, Result<()>> {
-        (*self.inner.get()).async_process().boxed()
+        let busy_time = self.busy_time.clone();
+        let future = (*self.inner.get()).async_process();
+        async move {
+            let instant = Instant::now();
+            let res = future.await;
+            add_elapsed(&busy_time, instant);
+            res
+        }
+        .boxed()
+    }
+
+    /// The total time spent in processing, for the async processors it includes the waiting.
+    pub fn busy_time(&self) -> Duration {
+        Duration::from_nanos(self.busy_time.load(Ordering::Relaxed))
     }
 }
 
+#[inline]
+fn add_elapsed(busy_time: &AtomicU64, instant: Instant) {
+    busy_time.fetch_add(instant.elapsed().as_nanos() as u64, Ordering::Relaxed);
+}
+
 pub type Processors = Vec<ProcessorPtr>;
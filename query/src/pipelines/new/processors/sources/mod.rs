@@ -16,6 +16,7 @@ mod async_source;
 mod empty_source;
 mod sync_source;
 mod sync_source_receiver;
+mod values_source;
 
 pub use async_source::AsyncSource;
 pub use async_source::AsyncSourcer;
@@ -23,6 +24,7 @@ pub use empty_source::EmptySource;
 pub use sync_source::SyncSource;
 pub use sync_source::SyncSourcer;
 pub use sync_source_receiver::SyncReceiverSource;
+pub use values_source::ValuesSource;
 
 #[allow(dead_code)]
 mod source_example {
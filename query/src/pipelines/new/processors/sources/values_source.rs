@@ -0,0 +1,51 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use common_planners::ValuesPlan;
+
+use crate::pipelines::new::processors::port::OutputPort;
+use crate::pipelines::new::processors::processor::ProcessorPtr;
+use crate::pipelines::new::processors::SyncSource;
+use crate::pipelines::new::processors::SyncSourcer;
+use crate::pipelines::transforms::ValuesTransform;
+use crate::sessions::QueryContext;
+
+pub struct ValuesSource {
+    plan: Option<ValuesPlan>,
+}
+
+impl ValuesSource {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        output: Arc<OutputPort>,
+        plan: ValuesPlan,
+    ) -> Result<ProcessorPtr> {
+        SyncSourcer::create(ctx, output, ValuesSource { plan: Some(plan) })
+    }
+}
+
+impl SyncSource for ValuesSource {
+    const NAME: &'static str = "ValuesSource";
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        match self.plan.take() {
+            None => Ok(None),
+            Some(plan) => Ok(Some(ValuesTransform::materialize(&plan)?)),
+        }
+    }
+}
@@ -27,6 +27,7 @@ use common_datavalues::ColumnRef;
 use common_datavalues::MutableColumn;
 use common_datavalues::MutableStringColumn;
 use common_datavalues::ScalarColumnBuilder;
+use common_datavalues::Series;
 use common_exception::Result;
 use common_functions::aggregates::StateAddr;
 use common_functions::aggregates::StateAddrs;
@@ -124,7 +125,7 @@ impl<const HAS_AGG: bool, Method: HashMethod + PolymorphicKeysHelper<Method> + S
 
             for argument_name in function_arguments {
                 let argument_column = block.try_column_by_name(argument_name)?;
-                function_arguments_column.push(argument_column.clone());
+                function_arguments_column.push(Series::remove_dictionary(argument_column));
             }
 
             aggregate_arguments_columns.push(function_arguments_column);
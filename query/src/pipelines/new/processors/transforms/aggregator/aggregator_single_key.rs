@@ -139,7 +139,8 @@ impl Aggregator for SingleKeyAggregator<false> {
         for (idx, func) in self.funcs.iter().enumerate() {
             let mut arg_columns = vec![];
             for name in self.arg_names[idx].iter() {
-                arg_columns.push(block.try_column_by_name(name)?.clone());
+                let column = block.try_column_by_name(name)?;
+                arg_columns.push(Series::remove_dictionary(column));
             }
             let place = self.places[idx].into();
             func.accumulate(place, &arg_columns, None, rows)?;
@@ -16,6 +16,7 @@ mod aggregator;
 mod transform;
 mod transform_aggregator;
 mod transform_create_sets;
+mod transform_distinct;
 mod transform_dummy;
 mod transform_expression;
 mod transform_filter;
@@ -29,6 +30,7 @@ pub use aggregator::AggregatorTransformParams;
 pub use transform_aggregator::TransformAggregator;
 pub use transform_create_sets::SubQueriesPuller;
 pub use transform_create_sets::TransformCreateSets;
+pub use transform_distinct::TransformDistinct;
 pub use transform_dummy::TransformDummy;
 pub use transform_expression::ExpressionTransform;
 pub use transform_expression::ProjectionTransform;
@@ -0,0 +1,71 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datablocks::HashMethod;
+use common_datablocks::HashMethodSerializer;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::Expression;
+
+use crate::pipelines::new::processors::port::InputPort;
+use crate::pipelines::new::processors::port::OutputPort;
+use crate::pipelines::new::processors::processor::ProcessorPtr;
+use crate::pipelines::new::processors::transforms::transform::Transform;
+use crate::pipelines::new::processors::transforms::transform::Transformer;
+
+pub struct TransformDistinct {
+    distinct_columns_name: Vec<String>,
+    keys: HashSet<SmallVu8>,
+}
+
+impl TransformDistinct {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        distinct_exprs: &[Expression],
+    ) -> Result<ProcessorPtr> {
+        let distinct_columns_name = distinct_exprs.iter().map(|col| col.column_name()).collect();
+
+        Ok(Transformer::create(input, output, TransformDistinct {
+            distinct_columns_name,
+            keys: HashSet::new(),
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Transform for TransformDistinct {
+    const NAME: &'static str = "DistinctTransform";
+
+    fn transform(&mut self, block: DataBlock) -> Result<DataBlock> {
+        let mut columns = Vec::with_capacity(self.distinct_columns_name.len());
+        for column_name in &self.distinct_columns_name {
+            columns.push(block.try_column_by_name(column_name)?);
+        }
+
+        // A row is kept when its key has not been seen in this or any previous block.
+        let method = HashMethodSerializer::default();
+        let keys = method.build_keys(&columns, block.num_rows())?;
+        let filter = keys
+            .into_iter()
+            .map(|key| self.keys.insert(key))
+            .collect::<Vec<_>>();
+
+        DataBlock::filter_block(&block, &Series::from_data(filter))
+    }
+}
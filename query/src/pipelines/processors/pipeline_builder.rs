@@ -18,6 +18,7 @@ use common_exception::Result;
 use common_planners::AggregatorFinalPlan;
 use common_planners::AggregatorPartialPlan;
 use common_planners::BroadcastPlan;
+use common_planners::DistinctPlan;
 use common_planners::ExpressionPlan;
 use common_planners::FilterPlan;
 use common_planners::HavingPlan;
@@ -32,6 +33,8 @@ use common_planners::SinkPlan;
 use common_planners::SortPlan;
 use common_planners::StagePlan;
 use common_planners::SubQueriesSetPlan;
+use common_planners::TopNPlan;
+use common_planners::ValuesPlan;
 use common_tracing::tracing;
 
 use crate::api::FlightTicket;
@@ -39,6 +42,7 @@ use crate::pipelines::processors::Pipeline;
 use crate::pipelines::transforms::AggregatorFinalTransform;
 use crate::pipelines::transforms::AggregatorPartialTransform;
 use crate::pipelines::transforms::CreateSetsTransform;
+use crate::pipelines::transforms::DistinctTransform;
 use crate::pipelines::transforms::ExpressionTransform;
 use crate::pipelines::transforms::GroupByFinalTransform;
 use crate::pipelines::transforms::GroupByPartialTransform;
@@ -52,6 +56,8 @@ use crate::pipelines::transforms::SortMergeTransform;
 use crate::pipelines::transforms::SortPartialTransform;
 use crate::pipelines::transforms::SourceTransform;
 use crate::pipelines::transforms::SubQueriesPuller;
+use crate::pipelines::transforms::TopNTransform;
+use crate::pipelines::transforms::ValuesTransform;
 use crate::pipelines::transforms::WhereTransform;
 use crate::sessions::QueryContext;
 
@@ -92,9 +98,12 @@ impl PipelineBuilder {
             PlanNode::Filter(node) => self.visit_filter(node),
             PlanNode::Having(node) => self.visit_having(node),
             PlanNode::Sort(node) => self.visit_sort(node),
+            PlanNode::TopN(node) => self.visit_top_n(node),
+            PlanNode::Distinct(node) => self.visit_distinct(node),
             PlanNode::Limit(node) => self.visit_limit(node),
             PlanNode::LimitBy(node) => self.visit_limit_by(node),
             PlanNode::ReadSource(node) => self.visit_read_data_source(node),
+            PlanNode::Values(node) => self.visit_values(node),
             PlanNode::SubQueryExpression(node) => self.visit_create_sets(node),
             PlanNode::Sink(node) => self.visit_sink(node),
             other => Result::Err(ErrorCode::UnknownPlan(format!(
@@ -284,6 +293,65 @@ impl PipelineBuilder {
         Ok(pipeline)
     }
 
+    fn visit_top_n(&mut self, plan: &TopNPlan) -> Result<Pipeline> {
+        let mut pipeline = self.visit(&*plan.input)?;
+
+        // processor 1: block ---> top n rows
+        // processor 2: block ---> top n rows
+        // processor 3: block ---> top n rows
+        pipeline.add_simple_transform(|| {
+            Ok(Box::new(TopNTransform::try_create(
+                plan.schema(),
+                plan.order_by.clone(),
+                plan.limit,
+            )?))
+        })?;
+
+        // processor1 top n rows --
+        //                            \
+        // processor2 top n rows ----> processor  --> merge to the final top n rows
+        //                            /
+        // processor3 top n rows --
+        if pipeline.last_pipe()?.nums() > 1 {
+            pipeline.merge_processor()?;
+            pipeline.add_simple_transform(|| {
+                Ok(Box::new(TopNTransform::try_create(
+                    plan.schema(),
+                    plan.order_by.clone(),
+                    plan.limit,
+                )?))
+            })?;
+        }
+        Ok(pipeline)
+    }
+
+    fn visit_distinct(&mut self, plan: &DistinctPlan) -> Result<Pipeline> {
+        // The limit above can't be applied to the rows before the distinct.
+        self.limit = None;
+        self.offset = 0;
+
+        let mut pipeline = self.visit(&*plan.input)?;
+
+        // processor 1: block ---> distinct rows of stream 1
+        // processor 2: block ---> distinct rows of stream 2
+        pipeline.add_simple_transform(|| {
+            Ok(Box::new(DistinctTransform::create(
+                plan.distinct_on.clone(),
+            )))
+        })?;
+
+        // The same key may still be found in different streams.
+        if pipeline.last_pipe()?.nums() > 1 {
+            pipeline.merge_processor()?;
+            pipeline.add_simple_transform(|| {
+                Ok(Box::new(DistinctTransform::create(
+                    plan.distinct_on.clone(),
+                )))
+            })?;
+        }
+        Ok(pipeline)
+    }
+
     fn visit_limit(&mut self, node: &LimitPlan) -> Result<Pipeline> {
         self.limit = node.n;
         self.offset = node.offset;
@@ -313,9 +381,9 @@ impl PipelineBuilder {
         self.ctx.try_set_partitions(plan.parts.clone())?;
 
         let mut pipeline = Pipeline::create(self.ctx.clone());
-        let max_threads = self.ctx.get_settings().get_max_threads()? as usize;
-        let max_threads = std::cmp::min(max_threads, plan.parts.len());
-        let workers = std::cmp::max(max_threads, 1);
+        let table = self.ctx.build_table_from_source_plan(plan)?;
+        let workers = table.read_parallelism(&self.ctx, plan)?;
+        self.ctx.set_parallelism(workers);
 
         for _i in 0..workers {
             let source = SourceTransform::try_create(self.ctx.clone(), plan.clone())?;
@@ -324,6 +392,12 @@ impl PipelineBuilder {
         Ok(pipeline)
     }
 
+    fn visit_values(&mut self, plan: &ValuesPlan) -> Result<Pipeline> {
+        let mut pipeline = Pipeline::create(self.ctx.clone());
+        pipeline.add_source(Arc::new(ValuesTransform::try_create(plan.clone())?))?;
+        Ok(pipeline)
+    }
+
     fn visit_sink(&mut self, plan: &SinkPlan) -> Result<Pipeline> {
         let mut pipeline = self.visit(&plan.input)?;
         pipeline.add_simple_transform(|| {
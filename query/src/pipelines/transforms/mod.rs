@@ -15,6 +15,7 @@
 mod transform_aggregator_final;
 mod transform_aggregator_partial;
 mod transform_create_sets;
+mod transform_distinct;
 mod transform_expression;
 mod transform_expression_executor;
 mod transform_filter;
@@ -27,6 +28,8 @@ mod transform_remote;
 mod transform_sort_merge;
 mod transform_sort_partial;
 mod transform_source;
+mod transform_top_n;
+mod transform_values;
 
 pub mod group_by;
 mod streams;
@@ -37,6 +40,7 @@ pub use transform_aggregator_final::AggregatorFinalTransform;
 pub use transform_aggregator_partial::AggregatorPartialTransform;
 pub use transform_create_sets::CreateSetsTransform;
 pub use transform_create_sets::SubQueriesPuller;
+pub use transform_distinct::DistinctTransform;
 pub use transform_expression::ExpressionTransform;
 pub use transform_expression_executor::ExpressionExecutor;
 pub use transform_filter::HavingTransform;
@@ -52,3 +56,5 @@ pub use transform_sort_merge::SortMergeTransform;
 pub use transform_sort_partial::get_sort_descriptions;
 pub use transform_sort_partial::SortPartialTransform;
 pub use transform_source::SourceTransform;
+pub use transform_top_n::TopNTransform;
+pub use transform_values::ValuesTransform;
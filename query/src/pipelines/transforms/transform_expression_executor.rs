@@ -19,6 +19,8 @@ use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::scalars::cast_column_field;
+use common_planners::ActionConditional;
 use common_planners::ActionFunction;
 use common_planners::Expression;
 use common_planners::ExpressionAction;
@@ -82,13 +84,6 @@ impl ExpressionExecutor {
         // !currently not supported a+1 as c, b+1 as c
         let mut alias_action_map: HashMap<&str, Vec<&str>> = HashMap::new();
 
-        for f in block.schema().fields().iter() {
-            let column =
-                ColumnWithField::new(block.try_column_by_name(f.name())?.clone(), f.clone());
-            column_map.insert(f.name(), column);
-        }
-
-        let rows = block.num_rows();
         for action in self.chain.actions.iter() {
             if let ExpressionAction::Alias(alias) = action {
                 if let Some(v) = alias_action_map.get_mut(alias.arg_name.as_str()) {
@@ -97,40 +92,10 @@ impl ExpressionExecutor {
                     alias_action_map.insert(alias.arg_name.as_str(), vec![alias.name.as_str()]);
                 }
             }
-
-            if column_map.contains_key(action.column_name()) {
-                continue;
-            }
-
-            match action {
-                ExpressionAction::Input(input) => {
-                    let column = block.try_column_by_name(&input.name)?.clone();
-                    let column = ColumnWithField::new(
-                        column,
-                        block.schema().field_with_name(&input.name)?.clone(),
-                    );
-                    column_map.insert(input.name.as_str(), column);
-                }
-                ExpressionAction::Function(f) => {
-                    let column_with_field = self.execute_function(&mut column_map, f, rows)?;
-                    column_map.insert(f.name.as_str(), column_with_field);
-                }
-                ExpressionAction::Constant(constant) => {
-                    let column = constant
-                        .data_type
-                        .create_constant_column(&constant.value, rows)?;
-
-                    let column = ColumnWithField::new(
-                        column,
-                        DataField::new(constant.name.as_str(), constant.data_type.clone()),
-                    );
-
-                    column_map.insert(constant.name.as_str(), column);
-                }
-                _ => {}
-            }
         }
 
+        self.execute_actions(&self.chain.actions, block, &mut column_map)?;
+
         if self.alias_project {
             for (k, v) in alias_action_map.iter() {
                 let column = column_map.get(k).ok_or_else(|| {
@@ -170,10 +135,160 @@ impl ExpressionExecutor {
         ))
     }
 
+    fn execute_actions<'a>(
+        &self,
+        actions: &'a [ExpressionAction],
+        block: &'a DataBlock,
+        column_map: &mut HashMap<&'a str, ColumnWithField>,
+    ) -> Result<()> {
+        for f in block.schema().fields().iter() {
+            let column =
+                ColumnWithField::new(block.try_column_by_name(f.name())?.clone(), f.clone());
+            column_map.insert(f.name(), column);
+        }
+
+        let rows = block.num_rows();
+        for action in actions.iter() {
+            if column_map.contains_key(action.column_name()) {
+                continue;
+            }
+
+            match action {
+                ExpressionAction::Input(input) => {
+                    let column = block.try_column_by_name(&input.name)?.clone();
+                    let column = ColumnWithField::new(
+                        column,
+                        block.schema().field_with_name(&input.name)?.clone(),
+                    );
+                    column_map.insert(input.name.as_str(), column);
+                }
+                ExpressionAction::Function(f) => {
+                    let column_with_field = self.execute_function(column_map, f, rows)?;
+                    column_map.insert(f.name.as_str(), column_with_field);
+                }
+                ExpressionAction::Conditional(c) => {
+                    let column_with_field = self.execute_conditional(c, block)?;
+                    column_map.insert(c.name.as_str(), column_with_field);
+                }
+                ExpressionAction::Constant(constant) => {
+                    let column = constant
+                        .data_type
+                        .create_constant_column(&constant.value, rows)?;
+
+                    let column = ColumnWithField::new(
+                        column,
+                        DataField::new(constant.name.as_str(), constant.data_type.clone()),
+                    );
+
+                    column_map.insert(constant.name.as_str(), column);
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Evaluates the column `name` computed by the chain on the rows of the block.
+    fn execute_chain(
+        &self,
+        chain: &ExpressionChain,
+        name: &str,
+        block: &DataBlock,
+    ) -> Result<ColumnRef> {
+        let mut column_map = HashMap::new();
+        self.execute_actions(&chain.actions, block, &mut column_map)?;
+        match column_map.remove(name) {
+            Some(column) => Ok(column.column().clone()),
+            None => Err(ErrorCode::LogicalError(format!(
+                "Conditional argument: {} not exists, there are bugs!",
+                name
+            ))),
+        }
+    }
+
+    /// Evaluates each condition on the rows not selected by the previous ones, and each result
+    /// only on the rows selected by its condition, the else result takes the remaining rows.
+    fn execute_conditional(
+        &self,
+        c: &ActionConditional,
+        block: &DataBlock,
+    ) -> Result<ColumnWithField> {
+        let rows = block.num_rows();
+        let mut remaining: Vec<u32> = (0..rows as u32).collect();
+        // the rows of the results, in the order they are concatenated
+        let mut selections = Vec::with_capacity(c.results.len());
+        let mut columns = Vec::with_capacity(c.results.len());
+
+        for (i, (name, chain)) in c.results.iter().enumerate() {
+            if remaining.is_empty() {
+                break;
+            }
+
+            let remaining_block = DataBlock::block_take_by_indices(block, &remaining)?;
+            // the rows of the remaining block with their indices in the block
+            let remaining_rows = remaining
+                .iter()
+                .enumerate()
+                .map(|(row, index)| (row as u32, *index));
+            let selected: Vec<(u32, u32)> = match c.conditions.get(i) {
+                Some((condition_name, condition_chain)) => {
+                    let condition =
+                        self.execute_chain(condition_chain, condition_name, &remaining_block)?;
+                    let condition = DataBlock::cast_to_nonull_boolean(&condition)?;
+                    let condition = bool::try_create_viewer(&condition)?;
+
+                    let (selected, rest): (Vec<_>, Vec<_>) =
+                        remaining_rows.partition(|(row, _)| condition.value_at(*row as usize));
+                    remaining = rest.into_iter().map(|(_, index)| index).collect();
+                    selected
+                }
+                // the else result takes all the remaining rows
+                None => {
+                    let selected = remaining_rows.collect();
+                    remaining = vec![];
+                    selected
+                }
+            };
+
+            if selected.is_empty() {
+                continue;
+            }
+
+            let (rows_in_remaining, indices): (Vec<_>, Vec<_>) = selected.into_iter().unzip();
+            let selected_block =
+                DataBlock::block_take_by_indices(&remaining_block, &rows_in_remaining)?;
+            let column = self.execute_chain(chain, name, &selected_block)?;
+            let field = DataField::new(name, column.data_type());
+            let column = cast_column_field(&ColumnWithField::new(column, field), &c.return_type)?;
+            columns.push(Series::remove_dictionary(&column));
+            selections.push(indices);
+        }
+
+        let field = DataField::new(&c.name, c.return_type.clone());
+        if columns.is_empty() {
+            let column = c.return_type.create_column(&[])?;
+            return Ok(ColumnWithField::new(column, field));
+        }
+
+        // the position of each row in the concatenated results
+        let mut positions = vec![0u32; rows];
+        let mut offset = 0;
+        for indices in selections.iter() {
+            for (i, index) in indices.iter().enumerate() {
+                positions[*index as usize] = (offset + i) as u32;
+            }
+            offset += indices.len();
+        }
+
+        let column = Series::concat(&columns)?;
+        let column = Series::take(&column, &positions)?;
+        Ok(ColumnWithField::new(column, field))
+    }
+
     #[inline]
     fn execute_function(
         &self,
-        column_map: &mut HashMap<&str, ColumnWithField>,
+        column_map: &HashMap<&str, ColumnWithField>,
         f: &ActionFunction,
         rows: usize,
     ) -> Result<ColumnWithField> {
@@ -0,0 +1,99 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::Result;
+use common_planners::Expression;
+use common_streams::CorrectWithSchemaStream;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+use futures::StreamExt;
+
+use crate::pipelines::processors::EmptyProcessor;
+use crate::pipelines::processors::Processor;
+use crate::pipelines::transforms::transform_sort_partial::get_sort_descriptions;
+
+/// Keeps the first `limit` rows of the input stream in sort order.
+///
+/// Only the current top rows are held: every incoming block is sorted with the limit
+/// and merged into them, so the memory is bounded by `limit` rows plus one block.
+pub struct TopNTransform {
+    schema: DataSchemaRef,
+    exprs: Vec<Expression>,
+    limit: usize,
+    input: Arc<dyn Processor>,
+}
+
+impl TopNTransform {
+    pub fn try_create(schema: DataSchemaRef, exprs: Vec<Expression>, limit: usize) -> Result<Self> {
+        Ok(TopNTransform {
+            schema,
+            exprs,
+            limit,
+            input: Arc::new(EmptyProcessor::create()),
+        })
+    }
+}
+
+#[async_trait]
+impl Processor for TopNTransform {
+    fn name(&self) -> &str {
+        "TopNTransform"
+    }
+
+    fn connect_to(&mut self, input: Arc<dyn Processor>) -> Result<()> {
+        self.input = input;
+        Ok(())
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        vec![self.input.clone()]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    #[tracing::instrument(level = "debug", name = "top_n_execute", skip(self))]
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        tracing::debug!("execute...");
+
+        let sort_columns_descriptions = get_sort_descriptions(&self.schema, &self.exprs)?;
+        let limit = Some(self.limit);
+        let mut top_n: Option<DataBlock> = None;
+        let mut stream = self.input.execute().await?;
+
+        while let Some(block) = stream.next().await {
+            let block = DataBlock::sort_block(&block?, &sort_columns_descriptions, limit)?;
+            top_n = Some(match top_n.take() {
+                None => block,
+                Some(top_n) => {
+                    DataBlock::merge_sort_block(&top_n, &block, &sort_columns_descriptions, limit)?
+                }
+            });
+        }
+
+        let results = top_n.into_iter().collect::<Vec<_>>();
+        Ok(Box::pin(CorrectWithSchemaStream::new(
+            Box::pin(DataBlockStream::create(self.schema.clone(), None, results)),
+            self.schema.clone(),
+        )))
+    }
+}
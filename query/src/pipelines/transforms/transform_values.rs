@@ -0,0 +1,97 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::ValuesPlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+
+use crate::pipelines::processors::EmptyProcessor;
+use crate::pipelines::processors::Processor;
+use crate::pipelines::transforms::ExpressionExecutor;
+
+/// Produces the literal rows of a `VALUES` constructor as a single block.
+pub struct ValuesTransform {
+    plan: ValuesPlan,
+}
+
+impl ValuesTransform {
+    pub fn try_create(plan: ValuesPlan) -> Result<Self> {
+        Ok(ValuesTransform { plan })
+    }
+
+    /// Evaluate every row against a dummy one-row block and concat the results.
+    pub fn materialize(plan: &ValuesPlan) -> Result<DataBlock> {
+        let dummy = DataSchemaRefExt::create(vec![DataField::new("dummy", u8::to_data_type())]);
+        let one_row_block = DataBlock::create(dummy.clone(), vec![Series::from_data(vec![1u8])]);
+
+        let blocks = plan
+            .values
+            .iter()
+            .map(|exprs| {
+                let executor = ExpressionExecutor::try_create(
+                    "VALUES constructor",
+                    dummy.clone(),
+                    plan.schema(),
+                    exprs.clone(),
+                    true,
+                )?;
+                executor.execute(&one_row_block)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        DataBlock::concat_blocks(&blocks)
+    }
+}
+
+#[async_trait]
+impl Processor for ValuesTransform {
+    fn name(&self) -> &str {
+        "ValuesTransform"
+    }
+
+    fn connect_to(&mut self, _: Arc<dyn Processor>) -> Result<()> {
+        Result::Err(ErrorCode::LogicalError(
+            "Cannot call ValuesTransform connect_to",
+        ))
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        vec![Arc::new(EmptyProcessor::create())]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    #[tracing::instrument(level = "debug", name = "values_execute", skip(self))]
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        tracing::debug!("execute...");
+
+        let block = Self::materialize(&self.plan)?;
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![block],
+        )))
+    }
+}
@@ -25,6 +25,7 @@ use crate::servers::clickhouse::interactive_worker_base::InteractiveWorkerBase;
 use crate::servers::clickhouse::writers::to_clickhouse_err;
 use crate::servers::clickhouse::writers::QueryWriter;
 use crate::sessions::SessionRef;
+use crate::sql::SQLRedactor;
 use crate::users::auth::auth_mgr::Credential;
 
 pub struct InteractiveWorker {
@@ -51,7 +52,17 @@ impl ClickHouseSession for InteractiveWorker {
         let session = self.session.clone();
         let get_query_result = InteractiveWorkerBase::do_query(ctx, session);
         if let Err(cause) = query_writer.write(get_query_result.await).await {
-            let new_error = cause.add_message(&ctx.state.query);
+            let log_redaction = self
+                .session
+                .get_settings()
+                .get_enable_log_redaction()
+                .unwrap_or_default()
+                != 0;
+            let new_error = match log_redaction {
+                true => SQLRedactor::redact_error(cause)
+                    .add_message(SQLRedactor::redact_query(&ctx.state.query)),
+                false => cause.add_message(&ctx.state.query),
+            };
             return Err(to_clickhouse_err(new_error));
         }
 
@@ -45,6 +45,7 @@ use crate::interpreters::InterpreterFactory;
 use crate::sessions::QueryContext;
 use crate::sessions::SessionRef;
 use crate::sql::PlanParser;
+use crate::sql::SQLRedactor;
 
 pub struct InteractiveWorkerBase;
 
@@ -61,7 +62,10 @@ impl InteractiveWorkerBase {
         session: SessionRef,
     ) -> Result<Receiver<BlockItem>> {
         let query = &ch_ctx.state.query;
-        tracing::debug!("{}", query);
+        match session.get_settings().get_enable_log_redaction()? {
+            0 => tracing::debug!("{}", query),
+            _ => tracing::debug!("{}", SQLRedactor::redact_query(query)),
+        }
 
         let ctx = session.create_query_context().await?;
         ctx.attach_query_str(query);
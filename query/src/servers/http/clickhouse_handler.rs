@@ -55,6 +55,8 @@ const FORMAT_JSON_EACH_ROW: &str = "JSONEachRow";
 #[derive(Deserialize)]
 pub struct StatementHandlerParams {
     query: String,
+    /// The current database of the query, like the `database` setting of ClickHouse.
+    database: Option<String>,
 }
 
 fn supported_formats() -> String {
@@ -111,6 +113,7 @@ pub async fn clickhouse_handler_get(
         .create_query_context()
         .await
         .map_err(InternalServerError)?;
+    use_database(&context, params.database).await?;
 
     let mut sql = params.query;
     let plan = PlanParser::parse(context.clone(), &sql)
@@ -128,6 +131,13 @@ pub async fn clickhouse_handler_get(
         .map_err(InternalServerError)
 }
 
+async fn use_database(ctx: &Arc<QueryContext>, database: Option<String>) -> PoemResult<()> {
+    match database.filter(|db| !db.is_empty()) {
+        None => Ok(()),
+        Some(db) => ctx.set_current_database(db).await.map_err(BadRequest),
+    }
+}
+
 fn try_parse_insert_formatted(sql: &str) -> Result<Option<(Format, Vec<DfStatement>)>> {
     if let Ok((statements, _)) = DfParser::parse_sql(sql) {
         if statements.is_empty() {
@@ -193,6 +203,7 @@ pub async fn clickhouse_handler_post(
         .create_query_context()
         .await
         .map_err(InternalServerError)?;
+    use_database(&ctx, params.database).await?;
 
     let plan = PlanParser::build_plan(statements, ctx.clone())
         .await
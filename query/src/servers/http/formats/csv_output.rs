@@ -0,0 +1,74 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+const FIELD_DELIMITER: u8 = b',';
+const ROW_DELIMITER: u8 = b'\n';
+const QUOTE: u8 = b'"';
+
+pub fn block_to_csv(block: &DataBlock) -> Result<Vec<u8>> {
+    let rows_size = block.num_rows();
+    let columns_size = block.num_columns();
+
+    let mut col_table = Vec::new();
+    for col_index in 0..columns_size {
+        let column = block.column(col_index);
+        let column = column.convert_full_column();
+        let field = block.schema().field(col_index);
+        let data_type = field.data_type();
+        let serializer = data_type.create_serializer();
+        col_table.push(serializer.serialize_column(&column).map_err(|e| {
+            ErrorCode::UnexpectedError(format!(
+                "fail to serialize filed {}, error = {}",
+                field.name(),
+                e
+            ))
+        })?);
+    }
+
+    let mut buf = vec![];
+    for row_index in 0..rows_size {
+        for (col_index, col) in col_table.iter().enumerate() {
+            if col_index > 0 {
+                buf.push(FIELD_DELIMITER);
+            }
+            write_field(&mut buf, col[row_index].as_bytes());
+        }
+        buf.push(ROW_DELIMITER);
+    }
+    Ok(buf)
+}
+
+// Quote the field if it has a delimiter, quote or line break, doubling the quotes in it.
+fn write_field(buf: &mut Vec<u8>, field: &[u8]) {
+    let need_quote = field
+        .iter()
+        .any(|c| matches!(*c, FIELD_DELIMITER | QUOTE | b'\n' | b'\r'));
+    if !need_quote {
+        buf.extend_from_slice(field);
+        return;
+    }
+
+    buf.push(QUOTE);
+    for c in field {
+        if *c == QUOTE {
+            buf.push(QUOTE);
+        }
+        buf.push(*c);
+    }
+    buf.push(QUOTE);
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod csv_output;
 pub mod tsv_output;
 
 pub enum Format {
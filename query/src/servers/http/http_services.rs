@@ -29,6 +29,7 @@ use super::v1::upload_to_stage;
 use crate::common::service::HttpShutdownHandler;
 use crate::configs::Config;
 use crate::servers::http::middleware::HTTPSessionMiddleware;
+use crate::servers::http::v1::batch_router;
 use crate::servers::http::v1::clickhouse_router;
 use crate::servers::http::v1::query_route;
 use crate::servers::http::v1::statement_router;
@@ -67,6 +68,7 @@ echo '{}' | curl '{:?}/clickhouse/?query=INSERT%20INTO%20test%20FORMAT%20JSONEac
             )
             .nest("/clickhouse", clickhouse_router())
             .nest("/v1/statement", statement_router())
+            .nest("/v1/batch", batch_router())
             .nest("/v1/query", query_route())
             .at("/v1/streaming_load", put(streaming_load))
             .at("/v1/upload_to_stage", put(upload_to_stage))
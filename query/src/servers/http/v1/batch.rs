@@ -0,0 +1,258 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::UserInfo;
+use common_planners::DropDatabasePlan;
+use common_planners::DropTablePlan;
+use common_planners::PlanNode;
+use common_planners::RenameTableEntity;
+use common_planners::RenameTablePlan;
+use common_tracing::tracing;
+use futures::TryStreamExt;
+use poem::error::Result as PoemResult;
+use poem::post;
+use poem::web::Data;
+use poem::web::Json;
+use poem::Endpoint;
+use poem::Route;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::http_query_handlers::QueryError;
+use super::query::ExecuteStateName;
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionRef;
+use crate::sessions::SessionType;
+use crate::sql::PlanParser;
+
+/// An ordered list of statements, executed one after another in a new session.
+/// The execution stops at the first failed statement.
+#[derive(Deserialize, Debug)]
+pub struct BatchRequest {
+    pub statements: Vec<String>,
+    /// All or nothing: only metadata statements that can be undone are allowed,
+    /// those applied before a failed one are undone in reverse order.
+    #[serde(default)]
+    pub atomic: bool,
+    #[serde(default)]
+    pub database: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BatchStatementResult {
+    pub sql: String,
+    pub state: ExecuteStateName,
+    /// Number of rows in the result of the statement.
+    pub rows: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BatchResponse {
+    /// The executed statements, the last one is the failed statement if `error` is set.
+    pub results: Vec<BatchStatementResult>,
+    pub error: Option<QueryError>,
+    /// Whether the statements applied before the failed one were undone.
+    pub rolled_back: bool,
+}
+
+#[poem::handler]
+pub async fn batch_handler(
+    sessions_extension: Data<&Arc<SessionManager>>,
+    user_info: Data<&UserInfo>,
+    Json(req): Json<BatchRequest>,
+) -> PoemResult<Json<BatchResponse>> {
+    let session_manager = sessions_extension.0;
+    let mut response = BatchResponse {
+        results: vec![],
+        error: None,
+        rolled_back: false,
+    };
+
+    let session = match create_session(session_manager, &user_info, &req).await {
+        Ok(session) => session,
+        Err(e) => {
+            response.error = Some(QueryError::from_error_code(&e));
+            return Ok(Json(response));
+        }
+    };
+
+    let mut undo_plans = vec![];
+    for sql in req.statements {
+        match execute_statement(&session, &sql, req.atomic).await {
+            Ok((rows, undo)) => {
+                undo_plans.extend(undo);
+                response.results.push(BatchStatementResult {
+                    sql,
+                    state: ExecuteStateName::Succeeded,
+                    rows,
+                });
+            }
+            Err(e) => {
+                response.results.push(BatchStatementResult {
+                    sql,
+                    state: ExecuteStateName::Failed,
+                    rows: 0,
+                });
+                response.error = Some(QueryError::from_error_code(&e));
+                if req.atomic {
+                    response.rolled_back = rollback(&session, undo_plans).await;
+                }
+                break;
+            }
+        }
+    }
+
+    Ok(Json(response))
+}
+
+pub fn batch_router() -> impl Endpoint {
+    Route::new().at("/", post(batch_handler))
+}
+
+async fn create_session(
+    session_manager: &Arc<SessionManager>,
+    user_info: &UserInfo,
+    req: &BatchRequest,
+) -> Result<SessionRef> {
+    let session = session_manager
+        .create_session(SessionType::HTTPQuery)
+        .await?;
+    session.set_current_user(user_info.clone());
+    if let Some(db) = req.database.as_ref().filter(|db| !db.is_empty()) {
+        // Check the database exists, like `USE db` does.
+        let ctx = session.create_query_context().await?;
+        ctx.set_current_database(db.clone()).await?;
+    }
+    Ok(session)
+}
+
+// Returns the number of result rows and the plans undoing the statement in an atomic batch.
+async fn execute_statement(
+    session: &SessionRef,
+    sql: &str,
+    atomic: bool,
+) -> Result<(usize, Vec<PlanNode>)> {
+    let ctx = session.create_query_context().await?;
+    ctx.attach_query_str(sql);
+    let plan = PlanParser::parse(ctx.clone(), sql).await?;
+    let undo = match atomic {
+        true => undo_plans(&ctx, &plan).await?,
+        false => vec![],
+    };
+
+    let rows = execute_plan(ctx, plan).await?;
+    Ok((rows, undo))
+}
+
+async fn execute_plan(ctx: Arc<QueryContext>, plan: PlanNode) -> Result<usize> {
+    let interpreter = InterpreterFactory::get(ctx, plan)?;
+    // Write Start to query log table.
+    let _ = interpreter
+        .start()
+        .await
+        .map_err(|e| tracing::error!("interpreter.start.error: {:?}", e));
+
+    let stream = interpreter.execute(None).await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+
+    // Write Finish to query log table.
+    let _ = interpreter
+        .finish()
+        .await
+        .map_err(|e| tracing::error!("interpreter.finish.error: {:?}", e));
+    Ok(blocks.iter().map(|block| block.num_rows()).sum())
+}
+
+// The meta service has no transaction across requests, so every statement of an
+// atomic batch is paired with the metadata change which reverts it.
+async fn undo_plans(ctx: &QueryContext, plan: &PlanNode) -> Result<Vec<PlanNode>> {
+    let catalog = ctx.get_catalog();
+    match plan {
+        PlanNode::CreateDatabase(plan) => {
+            // CREATE DATABASE IF NOT EXISTS on an existing database changes nothing.
+            if catalog.exists_database(&plan.tenant, &plan.db).await? {
+                return Ok(vec![]);
+            }
+            Ok(vec![PlanNode::DropDatabase(DropDatabasePlan {
+                if_exists: true,
+                tenant: plan.tenant.clone(),
+                db: plan.db.clone(),
+            })])
+        }
+        PlanNode::CreateTable(plan) => {
+            // The replaced table is dropped and purged, it could not be brought back.
+            if plan.or_replace {
+                return Err(ErrorCode::BadArguments(
+                    "CREATE OR REPLACE TABLE can not be undone, it is not allowed in an atomic batch",
+                ));
+            }
+            if catalog
+                .exists_table(&plan.tenant, &plan.db, &plan.table)
+                .await?
+            {
+                return Ok(vec![]);
+            }
+            Ok(vec![PlanNode::DropTable(DropTablePlan {
+                if_exists: true,
+                tenant: plan.tenant.clone(),
+                db: plan.db.clone(),
+                table: plan.table.clone(),
+            })])
+        }
+        PlanNode::RenameTable(plan) => {
+            let entities = plan
+                .entities
+                .iter()
+                .rev()
+                .map(|entity| RenameTableEntity {
+                    if_exists: true,
+                    db: entity.new_db.clone(),
+                    table_name: entity.new_table_name.clone(),
+                    new_db: entity.db.clone(),
+                    new_table_name: entity.table_name.clone(),
+                })
+                .collect();
+            Ok(vec![PlanNode::RenameTable(RenameTablePlan {
+                tenant: plan.tenant.clone(),
+                entities,
+            })])
+        }
+        other => Err(ErrorCode::BadArguments(format!(
+            "{} can not be undone, only CREATE DATABASE, CREATE TABLE and RENAME TABLE are allowed in an atomic batch",
+            other.name()
+        ))),
+    }
+}
+
+// Undo the applied statements in reverse order, returns whether all of them were undone.
+async fn rollback(session: &SessionRef, undo_plans: Vec<PlanNode>) -> bool {
+    let mut rolled_back = true;
+    for plan in undo_plans.into_iter().rev() {
+        let res = match session.create_query_context().await {
+            Ok(ctx) => execute_plan(ctx, plan).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = res {
+            tracing::error!("batch rollback error: {:?}", e);
+            rolled_back = false;
+        }
+    }
+    rolled_back
+}
@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use serde_json::Value as JsonValue;
@@ -36,7 +37,12 @@ fn transpose(col_table: Vec<Vec<JsonValue>>) -> Vec<Vec<JsonValue>> {
     row_table
 }
 
-pub fn block_to_json(block: &DataBlock) -> Result<Vec<Vec<JsonValue>>> {
+/// NaN and Infinity are not valid JSON numbers, they are output as null unless
+/// `quote_non_finite_floats` is set, then as the strings the text formats use.
+pub fn block_to_json(
+    block: &DataBlock,
+    quote_non_finite_floats: bool,
+) -> Result<Vec<Vec<JsonValue>>> {
     let mut col_table = Vec::new();
     let columns_size = block.columns().len();
     for col_index in 0..columns_size {
@@ -45,14 +51,40 @@ pub fn block_to_json(block: &DataBlock) -> Result<Vec<Vec<JsonValue>>> {
         let field = block.schema().field(col_index);
         let data_type = field.data_type();
         let serializer = data_type.create_serializer();
-        col_table.push(serializer.serialize_json(&column).map_err(|e| {
+        let mut values = serializer.serialize_json(&column).map_err(|e| {
             ErrorCode::UnexpectedError(format!(
                 "fail to serialize filed {}, error = {}",
                 field.name(),
                 e
             ))
-        })?);
+        })?;
+        if quote_non_finite_floats {
+            quote_non_finite(&column, &mut values);
+        }
+        col_table.push(values);
     }
 
     Ok(transpose(col_table))
 }
+
+fn quote_non_finite(column: &ColumnRef, values: &mut [JsonValue]) {
+    let (all_null, validity) = column.validity();
+    if all_null {
+        return;
+    }
+    let inner = Series::remove_nullable(column);
+    let floats: Vec<f64> = if let Ok(c) = Series::check_get::<Float64Column>(&inner) {
+        c.values().to_vec()
+    } else if let Ok(c) = Series::check_get::<Float32Column>(&inner) {
+        c.values().iter().map(|v| *v as f64).collect()
+    } else {
+        return;
+    };
+
+    for (row, v) in floats.iter().enumerate() {
+        let valid = validity.map(|b| b.get_bit(row)).unwrap_or(true);
+        if valid && !v.is_finite() {
+            values[row] = JsonValue::String(v.to_string());
+        }
+    }
+}
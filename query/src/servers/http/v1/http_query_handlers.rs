@@ -38,6 +38,7 @@ use super::query::HttpQueryRequest;
 use super::query::HttpQueryResponseInternal;
 use super::JsonBlockRef;
 use crate::sessions::SessionManager;
+use crate::sql::SQLRedactor;
 
 pub fn make_page_uri(query_id: &str, page_no: usize) -> String {
     format!("/v1/query/{}/page/{}", query_id, page_no)
@@ -60,7 +61,7 @@ pub struct QueryError {
 }
 
 impl QueryError {
-    fn from_error_code(e: &ErrorCode) -> Self {
+    pub(crate) fn from_error_code(e: &ErrorCode) -> Self {
         QueryError {
             code: e.code(),
             message: e.message(),
@@ -204,7 +205,10 @@ pub(crate) async fn query_handler(
     user_info: Data<&UserInfo>,
     Json(req): Json<HttpQueryRequest>,
 ) -> PoemResult<Json<QueryResponse>> {
-    tracing::info!("receive http query: {:?}", req);
+    tracing::info!(
+        "receive http query: {}",
+        SQLRedactor::redact_query(&req.sql)
+    );
     let session_manager = sessions_extension.0;
     let http_query_manager = session_manager.get_http_query_manager();
     let query_id = http_query_manager.next_query_id();
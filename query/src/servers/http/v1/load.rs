@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::str::FromStr;
 use std::sync::Arc;
 
 use async_compat::CompatExt;
@@ -22,6 +23,8 @@ use common_exception::ToErrorCode;
 use common_meta_types::UserInfo;
 use common_planners::InsertInputSource;
 use common_planners::PlanNode;
+use common_streams::decompress_reader;
+use common_streams::Compression;
 use common_streams::CsvSourceBuilder;
 use common_streams::NDJsonSourceBuilder;
 use common_streams::ParquetSourceBuilder;
@@ -90,18 +93,27 @@ pub async fn streaming_load(
         .get_max_block_size()
         .map_err(InternalServerError)? as usize;
 
+    // Compression of the uploaded files, detected by the file name or the magic bytes by default.
+    let compression = req
+        .headers()
+        .get("compression")
+        .and_then(|v| v.to_str().ok())
+        .map(Compression::from_str)
+        .unwrap_or(Ok(Compression::Auto))
+        .map_err(|e| poem::Error::from_string(e, StatusCode::BAD_REQUEST))?;
+
     // validate plan
     let source_stream = match &plan {
         PlanNode::Insert(insert) => match &insert.source {
             InsertInputSource::StreamingWithFormat(format) => {
                 if format.to_lowercase().as_str() == "csv" {
-                    build_csv_stream(&plan, req, multipart, max_block_size)
+                    build_csv_stream(&plan, req, multipart, max_block_size, compression)
                 } else if format.to_lowercase().as_str() == "parquet" {
                     build_parquet_stream(&plan, multipart)
                 } else if format.to_lowercase().as_str() == "ndjson"
                     || format.to_lowercase().as_str() == "jsoneachrow"
                 {
-                    build_ndjson_stream(&plan, multipart)
+                    build_ndjson_stream(&plan, multipart, compression)
                 } else {
                     Err(poem::Error::from_string(
                         format!(
@@ -188,13 +200,15 @@ fn build_parquet_stream(
 fn build_ndjson_stream(
     plan: &PlanNode,
     mut multipart: Multipart,
+    compression: Compression,
 ) -> PoemResult<SendableDataBlockStream> {
     let builder = NDJsonSourceBuilder::create(plan.schema());
     let stream = stream! {
         while let Ok(Some(field)) = multipart.next_field().await {
+            let file_name = field.file_name().unwrap_or_default().to_string();
             let bytes = field.bytes().await.map_err_to_code(ErrorCode::BadBytes,  || "Read part to field bytes error")?;
-            let cursor = std::io::Cursor::new(bytes);
-            let mut source = builder.build(cursor)?;
+            let reader = decompress_reader(Cursor::new(bytes), compression, &file_name).await?;
+            let mut source = builder.build(reader)?;
 
             loop {
                 let block = source.read().await;
@@ -215,6 +229,7 @@ fn build_csv_stream(
     req: &Request,
     mut multipart: Multipart,
     block_size: usize,
+    compression: Compression,
 ) -> PoemResult<SendableDataBlockStream> {
     let mut builder = CsvSourceBuilder::create(plan.schema());
     builder.block_size(block_size);
@@ -284,8 +299,10 @@ fn build_csv_stream(
 
     let stream = stream! {
         while let Ok(Some(field)) = multipart.next_field().await {
+            let file_name = field.file_name().unwrap_or_default().to_string();
             let reader = field.into_async_read();
-            let mut source = builder.build(reader.compat())?;
+            let reader = decompress_reader(reader.compat(), compression, &file_name).await?;
+            let mut source = builder.build(reader)?;
 
             loop {
                 let block = source.read().await;
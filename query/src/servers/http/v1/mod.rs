@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod batch;
 pub mod block_to_json;
 mod http_query_handlers;
 mod load;
@@ -19,6 +20,11 @@ mod query;
 mod stage;
 mod statement;
 
+pub use batch::batch_handler;
+pub use batch::batch_router;
+pub use batch::BatchRequest;
+pub use batch::BatchResponse;
+pub use batch::BatchStatementResult;
 pub(crate) use block_to_json::block_to_json;
 pub(crate) use block_to_json::JsonBlock;
 pub(crate) use block_to_json::JsonBlockRef;
@@ -26,6 +32,7 @@ pub use http_query_handlers::make_final_uri;
 pub use http_query_handlers::make_page_uri;
 pub use http_query_handlers::make_state_uri;
 pub use http_query_handlers::query_route;
+pub use http_query_handlers::QueryError;
 pub use http_query_handlers::QueryResponse;
 pub use http_query_handlers::QueryStats;
 pub use load::streaming_load;
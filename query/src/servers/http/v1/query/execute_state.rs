@@ -37,6 +37,7 @@ use crate::interpreters::InterpreterFactory;
 use crate::sessions::QueryContext;
 use crate::sessions::SessionRef;
 use crate::sql::PlanParser;
+use crate::sql::SQLRedactor;
 
 #[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq)]
 pub enum ExecuteStateName {
@@ -136,6 +137,22 @@ impl ExecuteState {
         request: &HttpQueryRequest,
         session: SessionRef,
         block_tx: mpsc::Sender<DataBlock>,
+    ) -> Result<(Arc<RwLock<Executor>>, DataSchemaRef)> {
+        // The errors are returned to the client and may end up in its logs.
+        let log_redaction = session.get_settings().get_enable_log_redaction()? != 0;
+        Self::try_start(request, session, block_tx, log_redaction)
+            .await
+            .map_err(|err| match log_redaction {
+                true => SQLRedactor::redact_error(err),
+                false => err,
+            })
+    }
+
+    async fn try_start(
+        request: &HttpQueryRequest,
+        session: SessionRef,
+        block_tx: mpsc::Sender<DataBlock>,
+        log_redaction: bool,
     ) -> Result<(Arc<RwLock<Executor>>, DataSchemaRef)> {
         let sql = &request.sql;
         let start_time = Instant::now();
@@ -177,6 +194,10 @@ impl ExecuteState {
                 Ok(_) => Executor::stop(&executor_clone, Ok(()), false).await,
                 Err(err) => {
                     let kill = err.message().starts_with("aborted");
+                    let err = match log_redaction {
+                        true => SQLRedactor::redact_error(err),
+                        false => err,
+                    };
                     Executor::stop(&executor_clone, Err(err), kill).await
                 }
             };
@@ -136,7 +136,9 @@ impl HttpQuery {
                     .create_session(SessionType::HTTPQuery)
                     .await?;
                 if let Some(db) = &session_conf.database {
-                    session.set_current_database(db.clone());
+                    // Check the database exists, like `USE db` does.
+                    let ctx = session.create_query_context().await?;
+                    ctx.set_current_database(db.clone()).await?;
                 }
                 if let Some(secs) = session_conf.max_idle_time {
                     if secs > 0 {
@@ -162,12 +164,18 @@ impl HttpQuery {
         };
         session.set_current_user(user_info.clone());
         let session_id = session.get_id().clone();
+        let quote_non_finite_floats =
+            session.get_settings().get_json_quote_non_finite_floats()? != 0;
 
         //TODO(youngsofun): support config/set channel size
         let (block_tx, block_rx) = mpsc::channel(10);
 
         let (state, schema) = ExecuteState::try_create(&request, session, block_tx).await?;
-        let data = Arc::new(TokioMutex::new(ResultDataManager::new(schema, block_rx)));
+        let data = Arc::new(TokioMutex::new(ResultDataManager::new(
+            schema,
+            block_rx,
+            quote_non_finite_floats,
+        )));
         let query = HttpQuery {
             id: id.to_string(),
             session_id,
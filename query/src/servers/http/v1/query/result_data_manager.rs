@@ -55,13 +55,19 @@ pub struct ResultDataManager {
     last_page: Option<Page>,
     pub(crate) block_rx: mpsc::Receiver<DataBlock>,
     end: bool,
+    quote_non_finite_floats: bool,
 }
 
 impl ResultDataManager {
-    pub fn new(schema: DataSchemaRef, block_rx: mpsc::Receiver<DataBlock>) -> ResultDataManager {
+    pub fn new(
+        schema: DataSchemaRef,
+        block_rx: mpsc::Receiver<DataBlock>,
+        quote_non_finite_floats: bool,
+    ) -> ResultDataManager {
         ResultDataManager {
             schema,
             block_rx,
+            quote_non_finite_floats,
             total_rows: 0,
             last_page: None,
             total_pages: 0,
@@ -136,7 +142,7 @@ impl ResultDataManager {
             match ResultDataManager::receive(block_rx, tp).await {
                 Ok(block) => {
                     rows += block.num_rows();
-                    results.push(block_to_json(&block)?);
+                    results.push(block_to_json(&block, self.quote_non_finite_floats)?);
                     // TODO(youngsofun):  set it in post if needed
                     if rows >= TARGET_ROWS_PER_PAGE {
                         break;
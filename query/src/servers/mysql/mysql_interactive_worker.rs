@@ -12,12 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::sync::Arc;
 use std::time::Instant;
 
 use common_base::TrySpawn;
 use common_datablocks::DataBlock;
+use common_datavalues::DataValue;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::ToErrorCode;
@@ -27,11 +29,15 @@ use common_tracing::tracing;
 use common_tracing::tracing::Instrument;
 use metrics::histogram;
 use opensrv_mysql::AsyncMysqlShim;
+use opensrv_mysql::Column;
+use opensrv_mysql::ColumnFlags;
+use opensrv_mysql::ColumnType;
 use opensrv_mysql::ErrorKind;
 use opensrv_mysql::InitWriter;
 use opensrv_mysql::ParamParser;
 use opensrv_mysql::QueryResultWriter;
 use opensrv_mysql::StatementMetaWriter;
+use opensrv_mysql::ValueInner;
 use rand::RngCore;
 use regex::RegexSet;
 use tokio_stream::StreamExt;
@@ -42,10 +48,15 @@ use crate::servers::mysql::writers::DFQueryResultWriter;
 use crate::sessions::QueryContext;
 use crate::sessions::SessionRef;
 use crate::sql::PlanParser;
+use crate::sql::PreparedStatement;
+use crate::sql::SQLRedactor;
 use crate::users::CertifiedInfo;
 
 struct InteractiveWorkerBase<W: std::io::Write> {
     session: SessionRef,
+    // Statements prepared with COM_STMT_PREPARE, keyed by statement id.
+    prepared: HashMap<u32, PreparedStatement>,
+    next_statement_id: u32,
     generic_hold: PhantomData<W>,
 }
 
@@ -171,14 +182,21 @@ impl<W: std::io::Write + Send + Sync> AsyncMysqlShim<W> for InteractiveWorker<W>
         }
 
         let mut writer = DFQueryResultWriter::create(writer);
+        let log_redaction = self.session.get_settings().get_enable_log_redaction()? != 0;
 
         let instant = Instant::now();
-        let blocks = self.base.do_query(query).await;
+        let mut blocks = self.base.do_query(query).await;
+        if log_redaction {
+            blocks = blocks.map_err(SQLRedactor::redact_error);
+        }
 
         let mut write_result = writer.write(blocks);
 
         if let Err(cause) = write_result {
-            let suffix = format!("(while in query {})", query);
+            let suffix = match log_redaction {
+                true => format!("(while in query {})", SQLRedactor::redact_query(query)),
+                false => format!("(while in query {})", query),
+            };
             write_result = Err(cause.add_message_back(suffix));
         }
 
@@ -228,28 +246,107 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
         Ok(authed)
     }
 
-    async fn do_prepare(&mut self, _: &str, writer: StatementMetaWriter<'_, W>) -> Result<()> {
-        writer.error(
-            ErrorKind::ER_UNKNOWN_ERROR,
-            "Prepare is not support in Databend.".as_bytes(),
-        )?;
+    async fn do_prepare(&mut self, query: &str, writer: StatementMetaWriter<'_, W>) -> Result<()> {
+        let statement = match PreparedStatement::try_create(query) {
+            Ok(statement) => statement,
+            Err(cause) => {
+                writer.error(ErrorKind::ER_PARSE_ERROR, cause.message().as_bytes())?;
+                return Ok(());
+            }
+        };
+
+        // We don't know the parameter types until they are bound, so every parameter
+        // is described as a string and the result columns are sent with the first execute.
+        let params = (0..statement.params_count())
+            .map(|_| Column {
+                table: "".to_string(),
+                column: "?".to_string(),
+                coltype: ColumnType::MYSQL_TYPE_VAR_STRING,
+                colflags: ColumnFlags::empty(),
+            })
+            .collect::<Vec<_>>();
+
+        let id = self.next_statement_id;
+        self.next_statement_id = self.next_statement_id.wrapping_add(1).max(1);
+        self.prepared.insert(id, statement);
+
+        writer.reply(id, &params, &[])?;
         Ok(())
     }
 
     async fn do_execute(
         &mut self,
-        _: u32,
-        _: ParamParser<'_>,
+        id: u32,
+        params: ParamParser<'_>,
         writer: QueryResultWriter<'_, W>,
     ) -> Result<()> {
-        writer.error(
-            ErrorKind::ER_UNKNOWN_ERROR,
-            "Execute is not support in Databend.".as_bytes(),
-        )?;
-        Ok(())
+        let query = match self.bind_prepared(id, params) {
+            Ok(query) => query,
+            Err(cause) => {
+                writer.error(ErrorKind::ER_UNKNOWN_ERROR, cause.message().as_bytes())?;
+                return Ok(());
+            }
+        };
+
+        let mut writer = DFQueryResultWriter::create(writer);
+        let blocks = self.do_query(&query).await;
+        writer.write(blocks)
+    }
+
+    async fn do_close(&mut self, id: u32) {
+        self.prepared.remove(&id);
     }
 
-    async fn do_close(&mut self, _: u32) {}
+    fn bind_prepared(&self, id: u32, params: ParamParser<'_>) -> Result<String> {
+        let statement = self.prepared.get(&id).ok_or_else(|| {
+            ErrorCode::UnknownPreparedStatement(format!("Unknown prepared statement id {}", id))
+        })?;
+
+        let values = params
+            .into_iter()
+            .map(|param| Self::param_value(param.value.into_inner()))
+            .collect::<Result<Vec<_>>>()?;
+        statement.bind(&values)
+    }
+
+    fn param_value(value: ValueInner<'_>) -> Result<DataValue> {
+        match value {
+            ValueInner::NULL => Ok(DataValue::Null),
+            ValueInner::Bytes(bytes) => Ok(DataValue::String(bytes.to_vec())),
+            ValueInner::Int(v) => Ok(DataValue::Int64(v)),
+            ValueInner::UInt(v) => Ok(DataValue::UInt64(v)),
+            ValueInner::Double(v) => Ok(DataValue::Float64(v)),
+            ValueInner::Date(bytes) | ValueInner::Datetime(bytes) => {
+                Ok(DataValue::String(Self::datetime_param(bytes)?.into_bytes()))
+            }
+            ValueInner::Time(_) => Err(ErrorCode::UnImplement(
+                "TIME parameters are not supported in prepared statements",
+            )),
+        }
+    }
+
+    // Binary protocol DATE/DATETIME: year(2), month, day, [hour, minute, second, [micros(4)]].
+    fn datetime_param(bytes: &[u8]) -> Result<String> {
+        match bytes.len() {
+            0 => Ok("0000-00-00".to_string()),
+            4 | 7 | 11 => {
+                let year = u16::from_le_bytes([bytes[0], bytes[1]]);
+                let mut res = format!("{:04}-{:02}-{:02}", year, bytes[2], bytes[3]);
+                if bytes.len() >= 7 {
+                    res.push_str(&format!(" {:02}:{:02}:{:02}", bytes[4], bytes[5], bytes[6]));
+                }
+                if bytes.len() == 11 {
+                    let micros = u32::from_le_bytes([bytes[7], bytes[8], bytes[9], bytes[10]]);
+                    res.push_str(&format!(".{:06}", micros));
+                }
+                Ok(res)
+            }
+            len => Err(ErrorCode::BadBytes(format!(
+                "Invalid length {} of binary DATETIME parameter",
+                len
+            ))),
+        }
+    }
 
     fn federated_server_setup_set_or_jdbc_command(&mut self, query: &str) -> bool {
         let expr = RegexSet::new(&[
@@ -267,9 +364,12 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
         expr.is_match(query)
     }
 
-    #[tracing::instrument(level = "debug", skip(self))]
+    #[tracing::instrument(level = "debug", skip(self, query))]
     async fn do_query(&mut self, query: &str) -> Result<(Vec<DataBlock>, String)> {
-        tracing::debug!("{}", query);
+        match self.session.get_settings().get_enable_log_redaction()? {
+            0 => tracing::debug!("{}", query),
+            _ => tracing::debug!("{}", SQLRedactor::redact_query(query)),
+        }
 
         if self.federated_server_setup_set_or_jdbc_command(query) {
             Ok((vec![DataBlock::empty()], String::from("")))
@@ -392,6 +492,8 @@ impl<W: std::io::Write> InteractiveWorker<W> {
             session: session.clone(),
             base: InteractiveWorkerBase::<W> {
                 session,
+                prepared: HashMap::new(),
+                next_statement_id: 1,
                 generic_hold: PhantomData::default(),
             },
             salt: scramble,
@@ -77,13 +77,20 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
                 TypeID::Float32 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
                 TypeID::Float64 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
                 TypeID::String => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+                TypeID::Binary => Ok(ColumnType::MYSQL_TYPE_BLOB),
+                TypeID::Uuid | TypeID::IPv4 | TypeID::IPv6 | TypeID::Bitmap => {
+                    Ok(ColumnType::MYSQL_TYPE_VARCHAR)
+                }
                 TypeID::Boolean => Ok(ColumnType::MYSQL_TYPE_SHORT),
                 TypeID::Date16 | TypeID::Date32 => Ok(ColumnType::MYSQL_TYPE_DATE),
                 TypeID::DateTime32 => Ok(ColumnType::MYSQL_TYPE_DATETIME),
                 TypeID::DateTime64 => Ok(ColumnType::MYSQL_TYPE_DATETIME),
                 TypeID::Null => Ok(ColumnType::MYSQL_TYPE_NULL),
                 TypeID::Interval => Ok(ColumnType::MYSQL_TYPE_LONG),
+                TypeID::Decimal => Ok(ColumnType::MYSQL_TYPE_NEWDECIMAL),
                 TypeID::Struct => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+                TypeID::Array => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+                TypeID::Map => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 TypeID::Variant => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 TypeID::VariantArray => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 TypeID::VariantObject => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
@@ -159,13 +166,25 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
                                             .to_string(),
                                     )?
                                 }
-                                (TypeID::String, DataValue::String(v)) => {
+                                (TypeID::Decimal, DataValue::Int64(_))
+                                | (TypeID::Uuid, DataValue::String(_))
+                                | (TypeID::IPv4, DataValue::UInt64(_))
+                                | (TypeID::IPv6, DataValue::String(_))
+                                | (TypeID::Bitmap, DataValue::String(_)) => {
+                                    let serializer = data_type.create_serializer();
+                                    row_writer.write_col(serializer.serialize_value(&val)?)?
+                                }
+                                (TypeID::String | TypeID::Binary, DataValue::String(v)) => {
                                     row_writer.write_col(v)?
                                 }
                                 (TypeID::Struct, DataValue::Struct(_)) => {
                                     let serializer = data_type.create_serializer();
                                     row_writer.write_col(serializer.serialize_value(&val)?)?
                                 }
+                                (TypeID::Array | TypeID::Map, DataValue::Array(_)) => {
+                                    let serializer = data_type.create_serializer();
+                                    row_writer.write_col(serializer.serialize_value(&val)?)?
+                                }
                                 (TypeID::Variant, DataValue::Json(_)) => {
                                     let serializer = data_type.create_serializer();
                                     row_writer.write_col(serializer.serialize_value(&val)?)?
@@ -182,6 +201,11 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
 
                                 (_, DataValue::UInt64(v)) => row_writer.write_col(v)?,
 
+                                // Float32 values are widened in DataValue, write them back with
+                                // the precision of the column, as the other formats do.
+                                (TypeID::Float32, DataValue::Float64(v)) => {
+                                    row_writer.write_col(v as f32)?
+                                }
                                 (_, DataValue::Float64(v)) => row_writer.write_col(v)?,
                                 (_, v) => {
                                     return Err(ErrorCode::BadDataValueType(format!(
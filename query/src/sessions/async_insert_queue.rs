@@ -0,0 +1,187 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::Mutex;
+use common_infallible::RwLock;
+use uuid::Uuid;
+
+/// Keep the status of this many most recent async inserts.
+const MAX_ASYNC_INSERT_ENTRIES: usize = 1024;
+
+/// How long the first insert of a batch waits for more inserts into the same table before
+/// the batch is flushed.
+pub const ASYNC_INSERT_FLUSH_DELAY: Duration = Duration::from_millis(200);
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum AsyncInsertStatus {
+    Pending,
+    Flushed,
+    Failed(String),
+}
+
+impl AsyncInsertStatus {
+    pub fn name(&self) -> &'static str {
+        match self {
+            AsyncInsertStatus::Pending => "Pending",
+            AsyncInsertStatus::Flushed => "Flushed",
+            AsyncInsertStatus::Failed(_) => "Failed",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct AsyncInsertEntry {
+    pub id: String,
+    pub database: String,
+    pub table: String,
+    pub rows: u64,
+    pub status: AsyncInsertStatus,
+    pub created_on: DateTime<Utc>,
+    pub flushed_on: Option<DateTime<Utc>>,
+}
+
+/// The data of the async inserts into a table that are flushed together.
+#[derive(Default)]
+pub struct AsyncInsertBatch {
+    pub ids: Vec<String>,
+    pub blocks: Vec<DataBlock>,
+}
+
+/// Tracks the INSERTs accepted with `async_insert` until their data is flushed into the table.
+///
+/// The inserts into a table are coalesced into a batch until the batch is taken to be flushed,
+/// so a burst of small inserts is written and committed once.
+#[derive(Default)]
+pub struct AsyncInsertQueue {
+    entries: RwLock<VecDeque<AsyncInsertEntry>>,
+    batches: Mutex<HashMap<(String, String), AsyncInsertBatch>>,
+    flushing: AtomicUsize,
+    closed: AtomicBool,
+}
+
+impl AsyncInsertQueue {
+    pub fn create() -> Arc<AsyncInsertQueue> {
+        Arc::new(AsyncInsertQueue::default())
+    }
+
+    /// Add accepted data to the batch of its table and return the handle to look its status up
+    /// with, and whether the data started a new batch, the caller then schedules its flush.
+    pub fn enqueue(
+        &self,
+        database: &str,
+        table: &str,
+        blocks: Vec<DataBlock>,
+    ) -> Result<(String, bool)> {
+        let mut batches = self.batches.lock();
+        if self.closed.load(Ordering::Acquire) {
+            return Err(ErrorCode::AbortedSession(
+                "The server is shutting down, async inserts are not accepted",
+            ));
+        }
+
+        let id = Uuid::new_v4().to_string();
+        let rows = blocks.iter().map(|block| block.num_rows() as u64).sum();
+        {
+            let mut entries = self.entries.write();
+            if entries.len() >= MAX_ASYNC_INSERT_ENTRIES {
+                entries.pop_front();
+            }
+
+            entries.push_back(AsyncInsertEntry {
+                id: id.clone(),
+                database: database.to_string(),
+                table: table.to_string(),
+                rows,
+                status: AsyncInsertStatus::Pending,
+                created_on: Utc::now(),
+                flushed_on: None,
+            });
+        }
+
+        let key = (database.to_string(), table.to_string());
+        let new_batch = !batches.contains_key(&key);
+        let batch = batches.entry(key).or_default();
+        batch.ids.push(id.clone());
+        batch.blocks.extend(blocks);
+        Ok((id, new_batch))
+    }
+
+    /// Take the batch of the table to flush it, the next insert into the table starts a new
+    /// batch. Every taken batch must be passed to [AsyncInsertQueue::finish].
+    pub fn take_batch(&self, database: &str, table: &str) -> Option<AsyncInsertBatch> {
+        let mut batches = self.batches.lock();
+        let key = (database.to_string(), table.to_string());
+        let batch = batches.remove(&key);
+        if batch.is_some() {
+            self.flushing.fetch_add(1, Ordering::AcqRel);
+        }
+        batch
+    }
+
+    pub fn finish(&self, ids: &[String], res: Result<()>) {
+        {
+            let mut entries = self.entries.write();
+            for entry in entries.iter_mut().filter(|entry| ids.contains(&entry.id)) {
+                entry.status = match &res {
+                    Ok(_) => AsyncInsertStatus::Flushed,
+                    Err(cause) => AsyncInsertStatus::Failed(cause.message()),
+                };
+                entry.flushed_on = Some(Utc::now());
+            }
+        }
+        self.flushing.fetch_sub(1, Ordering::AcqRel);
+    }
+
+    /// Stop accepting inserts, the accepted ones are still flushed.
+    pub fn close(&self) {
+        let _batches = self.batches.lock();
+        self.closed.store(true, Ordering::Release);
+    }
+
+    /// Whether every accepted insert has been flushed or has failed.
+    pub fn is_drained(&self) -> bool {
+        let batches = self.batches.lock();
+        batches.is_empty() && self.flushing.load(Ordering::Acquire) == 0
+    }
+
+    /// Mark the inserts that are not flushed yet as failed, e.g. when the server cannot wait
+    /// for them any longer.
+    pub fn fail_pending(&self, cause: &str) {
+        self.batches.lock().clear();
+        let mut entries = self.entries.write();
+        for entry in entries.iter_mut() {
+            if entry.status == AsyncInsertStatus::Pending {
+                entry.status = AsyncInsertStatus::Failed(cause.to_string());
+                entry.flushed_on = Some(Utc::now());
+            }
+        }
+    }
+
+    pub fn get_entries(&self) -> Vec<AsyncInsertEntry> {
+        self.entries.read().iter().cloned().collect()
+    }
+}
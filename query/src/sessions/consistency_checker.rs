@@ -0,0 +1,124 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Utc;
+use common_infallible::RwLock;
+
+use crate::storages::fuse::operations::ConsistencyReport;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConsistencyFindingKind {
+    MissingSnapshot,
+    MissingSegment,
+    MissingBlock,
+    Unreferenced,
+}
+
+impl ConsistencyFindingKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ConsistencyFindingKind::MissingSnapshot => "MissingSnapshot",
+            ConsistencyFindingKind::MissingSegment => "MissingSegment",
+            ConsistencyFindingKind::MissingBlock => "MissingBlock",
+            ConsistencyFindingKind::Unreferenced => "Unreferenced",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ConsistencyFinding {
+    pub database: String,
+    pub table: String,
+    pub kind: ConsistencyFindingKind,
+    pub location: String,
+    pub detected_on: DateTime<Utc>,
+}
+
+/// Keeps the findings of the latest check of the table snapshots against the storage.
+#[derive(Default)]
+pub struct ConsistencyChecker {
+    findings: RwLock<Vec<ConsistencyFinding>>,
+    /// The storage has no creation time of the objects, an unreferenced object is aged
+    /// from the first check which found it.
+    unreferenced_since: RwLock<HashMap<String, DateTime<Utc>>>,
+}
+
+impl ConsistencyChecker {
+    pub fn create() -> Arc<ConsistencyChecker> {
+        Arc::new(ConsistencyChecker::default())
+    }
+
+    /// Replace the findings with the reports of a check, `(database, table, report)`.
+    /// The unreferenced objects are only reported once they are older than the retention.
+    pub fn update(&self, reports: Vec<(String, String, ConsistencyReport)>, retention: Duration) {
+        let now = Utc::now();
+        let previous_unreferenced = self.unreferenced_since.read().clone();
+
+        let mut findings = vec![];
+        let mut unreferenced_since = HashMap::new();
+        for (database, table, report) in reports {
+            let missing = report
+                .missing_snapshot
+                .into_iter()
+                .map(|loc| (ConsistencyFindingKind::MissingSnapshot, loc))
+                .chain(
+                    report
+                        .missing_segments
+                        .into_iter()
+                        .map(|loc| (ConsistencyFindingKind::MissingSegment, loc)),
+                )
+                .chain(
+                    report
+                        .missing_blocks
+                        .into_iter()
+                        .map(|loc| (ConsistencyFindingKind::MissingBlock, loc)),
+                );
+            for (kind, location) in missing {
+                findings.push(ConsistencyFinding {
+                    database: database.clone(),
+                    table: table.clone(),
+                    kind,
+                    location,
+                    detected_on: now,
+                });
+            }
+
+            for location in report.unreferenced {
+                let since = previous_unreferenced.get(&location).cloned().unwrap_or(now);
+                if now - since >= retention {
+                    findings.push(ConsistencyFinding {
+                        database: database.clone(),
+                        table: table.clone(),
+                        kind: ConsistencyFindingKind::Unreferenced,
+                        location: location.clone(),
+                        detected_on: since,
+                    });
+                }
+                unreferenced_since.insert(location, since);
+            }
+        }
+
+        *self.findings.write() = findings;
+        *self.unreferenced_since.write() = unreferenced_since;
+    }
+
+    pub fn get_findings(&self) -> Vec<ConsistencyFinding> {
+        self.findings.read().clone()
+    }
+}
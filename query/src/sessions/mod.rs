@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod async_insert_queue;
+mod consistency_checker;
 mod metrics;
 mod query_ctx;
 mod query_ctx_shared;
@@ -26,6 +28,14 @@ mod session_settings;
 mod session_status;
 mod session_type;
 
+pub use async_insert_queue::AsyncInsertBatch;
+pub use async_insert_queue::AsyncInsertEntry;
+pub use async_insert_queue::AsyncInsertQueue;
+pub use async_insert_queue::AsyncInsertStatus;
+pub use async_insert_queue::ASYNC_INSERT_FLUSH_DELAY;
+pub use consistency_checker::ConsistencyChecker;
+pub use consistency_checker::ConsistencyFinding;
+pub use consistency_checker::ConsistencyFindingKind;
 pub use query_ctx::QueryContext;
 pub use query_ctx_shared::QueryContextShared;
 pub use session::Session;
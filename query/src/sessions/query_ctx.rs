@@ -18,6 +18,7 @@ use std::net::SocketAddr;
 use std::sync::atomic::Ordering;
 use std::sync::atomic::Ordering::Acquire;
 use std::sync::Arc;
+use std::time::SystemTime;
 
 use common_base::tokio::task::JoinHandle;
 use common_base::Progress;
@@ -47,14 +48,24 @@ use opendal::Operator;
 use crate::catalogs::Catalog;
 use crate::catalogs::DatabaseCatalog;
 use crate::clusters::Cluster;
+use crate::clusters::ClusterDiscovery;
+use crate::clusters::NodeRoutingRules;
 use crate::configs::Config;
+use crate::interpreters::ResultCache;
+use crate::pipelines::new::executor::PipelineExecutor;
 use crate::servers::http::v1::HttpQueryHandle;
+use crate::sessions::AsyncInsertQueue;
+use crate::sessions::ConsistencyChecker;
 use crate::sessions::ProcessInfo;
 use crate::sessions::QueryContextShared;
 use crate::sessions::Session;
 use crate::sessions::SessionRef;
 use crate::sessions::Settings;
+use crate::sql::PlanCache;
+use crate::sql::PreparedStatement;
+use crate::sql::SQLRedactor;
 use crate::storages::cache::CacheManager;
+use crate::storages::fuse::io::MetaCipher;
 use crate::storages::S3StageTable;
 use crate::storages::Table;
 use crate::users::auth::auth_mgr::AuthMgr;
@@ -204,6 +215,11 @@ impl QueryContext {
         self.shared.attach_query_plan(query_plan);
     }
 
+    // The executor of the query, whose processors graph is shown by the admin api.
+    pub fn attach_executor(&self, executor: &Arc<PipelineExecutor>) {
+        self.shared.attach_executor(executor);
+    }
+
     pub fn get_cluster(&self) -> Arc<Cluster> {
         self.shared.get_cluster()
     }
@@ -223,6 +239,11 @@ impl QueryContext {
         self.shared.get_table(database, table).await
     }
 
+    /// The tables fetched by `get_table` in this query, keyed by (database, table).
+    pub fn get_tables_refs(&self) -> Vec<((String, String), Arc<dyn Table>)> {
+        self.shared.get_tables_refs()
+    }
+
     pub fn get_id(&self) -> String {
         self.shared.init_query_id.as_ref().read().clone()
     }
@@ -256,10 +277,38 @@ impl QueryContext {
         Ok(())
     }
 
+    pub fn get_created_time(&self) -> SystemTime {
+        self.shared.get_created_time()
+    }
+
+    pub fn get_last_query_id(&self) -> String {
+        self.shared.get_last_query_id()
+    }
+
     pub fn get_current_user(&self) -> Result<UserInfo> {
         self.shared.get_current_user()
     }
 
+    pub fn get_prepared_statement(&self, name: &str) -> Result<Arc<PreparedStatement>> {
+        self.shared.get_prepared_statement(name).ok_or_else(|| {
+            ErrorCode::UnknownPreparedStatement(format!("Unknown prepared statement {}", name))
+        })
+    }
+
+    pub fn set_prepared_statement(&self, name: String, statement: PreparedStatement) {
+        self.shared.set_prepared_statement(name, statement)
+    }
+
+    pub fn remove_prepared_statement(&self, name: &str) -> Result<()> {
+        match self.shared.remove_prepared_statement(name) {
+            Some(_) => Ok(()),
+            None => Err(ErrorCode::UnknownPreparedStatement(format!(
+                "Unknown prepared statement {}",
+                name
+            ))),
+        }
+    }
+
     pub fn get_fuse_version(&self) -> String {
         self.version.clone()
     }
@@ -281,6 +330,18 @@ impl QueryContext {
         format!("_subquery_{}", index)
     }
 
+    /// Record the number of streams a scan or pipeline runs with, the widest one is kept.
+    pub fn set_parallelism(&self, parallelism: usize) {
+        self.shared
+            .parallelism
+            .fetch_max(parallelism, Ordering::Relaxed);
+    }
+
+    /// The effective parallelism of the query, 0 if nothing was recorded yet.
+    pub fn get_parallelism(&self) -> usize {
+        self.shared.parallelism.load(Ordering::Relaxed)
+    }
+
     // Get user manager api.
     pub fn get_user_manager(&self) -> Arc<UserApiProvider> {
         self.shared.get_user_manager()
@@ -327,16 +388,70 @@ impl QueryContext {
         self.shared.get_query_str()
     }
 
+    /// Get the session running query for logging, literals are redacted if `enable_log_redaction` is set.
+    pub fn get_query_str_for_log(&self) -> Result<String> {
+        let query = self.shared.get_query_str();
+        match self.get_settings().get_enable_log_redaction()? {
+            0 => Ok(query),
+            _ => Ok(SQLRedactor::redact_query(&query)),
+        }
+    }
+
     /// Get the client socket address.
     pub fn get_client_address(&self) -> Option<SocketAddr> {
         self.shared.session.session_ctx.get_client_host()
     }
 
+    /// Get the status of the INSERTs accepted with `async_insert`.
+    pub fn get_async_insert_queue(&self) -> Arc<AsyncInsertQueue> {
+        self.shared.session.session_mgr.get_async_insert_queue()
+    }
+
+    /// Get the findings of the latest check of the table snapshots against the storage.
+    pub fn get_consistency_checker(&self) -> Arc<ConsistencyChecker> {
+        self.shared.session.session_mgr.get_consistency_checker()
+    }
+
     /// Get the storage cache manager
     pub fn get_storage_cache_manager(&self) -> Arc<CacheManager> {
         self.shared.session.session_mgr.get_storage_cache_manager()
     }
 
+    /// Runs the query on the node group of the matched node routing rule, if any.
+    pub fn apply_node_routing(&self, plan: &PlanNode) {
+        let rules = self.shared.session.session_mgr.get_node_routing_rules();
+        if rules.is_empty() {
+            return;
+        }
+
+        let (user, roles) = match self.get_current_user() {
+            Ok(user) => (user.name.clone(), user.grants.roles()),
+            Err(_) => (String::new(), vec![]),
+        };
+
+        let statement_kind = NodeRoutingRules::statement_kind(plan);
+        if let Some(tag) = rules.route(&user, &roles, statement_kind) {
+            tracing::debug!(
+                "Route the {} query to the nodes tagged {}",
+                statement_kind,
+                tag
+            );
+            self.shared.route_to_tag(tag);
+        }
+    }
+
+    pub fn get_cluster_discovery(&self) -> Arc<ClusterDiscovery> {
+        self.shared.session.session_mgr.get_cluster_discovery()
+    }
+
+    pub fn get_plan_cache(&self) -> Arc<PlanCache> {
+        self.shared.session.session_mgr.get_plan_cache()
+    }
+
+    pub fn get_result_cache(&self) -> Arc<ResultCache> {
+        self.shared.session.session_mgr.get_result_cache()
+    }
+
     // Get the storage data accessor operator from the session manager.
     pub fn get_storage_operator(&self) -> Result<Operator> {
         let operator = self.shared.session.get_storage_operator();
@@ -344,6 +459,11 @@ impl QueryContext {
         Ok(operator.layer(self.shared.dal_ctx.as_ref().clone()))
     }
 
+    /// The cipher of the table data in the storage, None if the encryption is not configured.
+    pub fn get_meta_cipher(&self) -> Option<Arc<MetaCipher>> {
+        self.shared.session.session_mgr.get_meta_cipher()
+    }
+
     pub fn get_dal_context(&self) -> &DalContext {
         self.shared.dal_ctx.as_ref()
     }
@@ -16,6 +16,8 @@ use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
+use std::sync::Weak;
+use std::time::SystemTime;
 
 use common_base::Progress;
 use common_base::Runtime;
@@ -33,9 +35,11 @@ use crate::catalogs::Catalog;
 use crate::catalogs::DatabaseCatalog;
 use crate::clusters::Cluster;
 use crate::configs::Config;
+use crate::pipelines::new::executor::PipelineExecutor;
 use crate::servers::http::v1::HttpQueryHandle;
 use crate::sessions::Session;
 use crate::sessions::Settings;
+use crate::sql::PreparedStatement;
 use crate::storages::Table;
 use crate::users::auth::auth_mgr::AuthMgr;
 use crate::users::RoleCacheMgr;
@@ -62,18 +66,23 @@ pub struct QueryContextShared {
     pub(in crate::sessions) session: Arc<Session>,
     pub(in crate::sessions) runtime: Arc<RwLock<Option<Arc<Runtime>>>>,
     pub(in crate::sessions) init_query_id: Arc<RwLock<String>>,
-    pub(in crate::sessions) cluster_cache: Arc<Cluster>,
+    pub(in crate::sessions) cluster_cache: Arc<RwLock<Arc<Cluster>>>,
     pub(in crate::sessions) sources_abort_handle: Arc<RwLock<Vec<AbortHandle>>>,
     pub(in crate::sessions) ref_count: Arc<AtomicUsize>,
     pub(in crate::sessions) subquery_index: Arc<AtomicUsize>,
+    /// the number of streams of the widest scan or pipeline of the query
+    pub(in crate::sessions) parallelism: Arc<AtomicUsize>,
     pub(in crate::sessions) running_query: Arc<RwLock<Option<String>>>,
     pub(in crate::sessions) http_query: Arc<RwLock<Option<HttpQueryHandle>>>,
     pub(in crate::sessions) running_plan: Arc<RwLock<Option<PlanNode>>>,
+    pub(in crate::sessions) executor: Arc<RwLock<Weak<PipelineExecutor>>>,
     pub(in crate::sessions) tables_refs: Arc<Mutex<HashMap<DatabaseAndTable, Arc<dyn Table>>>>,
     pub(in crate::sessions) dal_ctx: Arc<DalContext>,
     pub(in crate::sessions) user_manager: Arc<UserApiProvider>,
     pub(in crate::sessions) auth_manager: Arc<AuthMgr>,
     pub(in crate::sessions) role_cache_manager: Arc<RoleCacheMgr>,
+    /// the start time of the query, which `now()` is bound to
+    pub(in crate::sessions) created_time: SystemTime,
 }
 
 impl QueryContextShared {
@@ -85,7 +94,7 @@ impl QueryContextShared {
         let user_manager = UserApiProvider::create_global(conf.clone()).await?;
         Ok(Arc::new(QueryContextShared {
             session,
-            cluster_cache,
+            cluster_cache: Arc::new(RwLock::new(cluster_cache)),
             init_query_id: Arc::new(RwLock::new(Uuid::new_v4().to_string())),
             scan_progress: Arc::new(Progress::create()),
             result_progress: Arc::new(Progress::create()),
@@ -94,14 +103,17 @@ impl QueryContextShared {
             sources_abort_handle: Arc::new(RwLock::new(Vec::new())),
             ref_count: Arc::new(AtomicUsize::new(0)),
             subquery_index: Arc::new(AtomicUsize::new(1)),
+            parallelism: Arc::new(AtomicUsize::new(0)),
             running_query: Arc::new(RwLock::new(None)),
             http_query: Arc::new(RwLock::new(None)),
             running_plan: Arc::new(RwLock::new(None)),
+            executor: Arc::new(RwLock::new(Weak::new())),
             tables_refs: Arc::new(Mutex::new(HashMap::new())),
             dal_ctx: Arc::new(Default::default()),
             user_manager: user_manager.clone(),
             auth_manager: Arc::new(AuthMgr::create(conf, user_manager.clone()).await?),
             role_cache_manager: Arc::new(RoleCacheMgr::new(user_manager)),
+            created_time: SystemTime::now(),
         }))
     }
 
@@ -121,7 +133,13 @@ impl QueryContextShared {
     }
 
     pub fn get_cluster(&self) -> Arc<Cluster> {
-        self.cluster_cache.clone()
+        self.cluster_cache.read().clone()
+    }
+
+    /// Keeps only the nodes with the tag for the distributed tasks of the query.
+    pub fn route_to_tag(&self, tag: &str) {
+        let mut cluster = self.cluster_cache.write();
+        *cluster = cluster.with_tag(tag);
     }
 
     pub fn get_current_database(&self) -> String {
@@ -132,10 +150,30 @@ impl QueryContextShared {
         self.session.set_current_database(new_database_name);
     }
 
+    pub fn get_created_time(&self) -> SystemTime {
+        self.created_time
+    }
+
+    pub fn get_last_query_id(&self) -> String {
+        self.session.get_last_query_id()
+    }
+
     pub fn get_current_user(&self) -> Result<UserInfo> {
         self.session.get_current_user()
     }
 
+    pub fn get_prepared_statement(&self, name: &str) -> Option<Arc<PreparedStatement>> {
+        self.session.get_prepared_statement(name)
+    }
+
+    pub fn set_prepared_statement(&self, name: String, statement: PreparedStatement) {
+        self.session.set_prepared_statement(name, statement)
+    }
+
+    pub fn remove_prepared_statement(&self, name: &str) -> Option<Arc<PreparedStatement>> {
+        self.session.remove_prepared_statement(name)
+    }
+
     pub fn get_tenant(&self) -> String {
         self.session.get_tenant()
     }
@@ -176,6 +214,14 @@ impl QueryContextShared {
         }
     }
 
+    pub fn get_tables_refs(&self) -> Vec<(DatabaseAndTable, Arc<dyn Table>)> {
+        let tables_refs = self.tables_refs.lock();
+        tables_refs
+            .iter()
+            .map(|(key, table)| (key.clone(), table.clone()))
+            .collect()
+    }
+
     async fn get_table_to_cache(&self, database: &str, table: &str) -> Result<Arc<dyn Table>> {
         let tenant = self.get_tenant();
         let catalog = self.get_catalog();
@@ -229,6 +275,15 @@ impl QueryContextShared {
         *running_plan = Some(plan.clone());
     }
 
+    pub fn attach_executor(&self, executor: &Arc<PipelineExecutor>) {
+        let mut running_executor = self.executor.write();
+        *running_executor = Arc::downgrade(executor);
+    }
+
+    pub fn get_executor(&self) -> Option<Arc<PipelineExecutor>> {
+        self.executor.read().upgrade()
+    }
+
     pub fn add_source_abort_handle(&self, handle: AbortHandle) {
         let mut sources_abort_handle = self.sources_abort_handle.write();
         sources_abort_handle.push(handle);
@@ -245,6 +300,10 @@ impl QueryContextShared {
 
 impl Session {
     pub(in crate::sessions) fn destroy_context_shared(&self) {
-        self.session_ctx.take_query_context_shared();
+        if let Some(shared) = self.session_ctx.take_query_context_shared() {
+            // The query is finished, it's the one last_query_id() returns from now on.
+            let query_id = shared.init_query_id.read().clone();
+            self.session_ctx.set_last_query_id(query_id);
+        }
     }
 }
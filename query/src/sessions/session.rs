@@ -36,6 +36,7 @@ use crate::sessions::SessionManager;
 use crate::sessions::SessionStatus;
 use crate::sessions::SessionType;
 use crate::sessions::Settings;
+use crate::sql::PreparedStatement;
 
 #[derive(Clone, MallocSizeOf)]
 pub struct Session {
@@ -175,6 +176,10 @@ impl Session {
         self.session_ctx.get_current_database()
     }
 
+    pub fn get_last_query_id(self: &Arc<Self>) -> String {
+        self.session_ctx.get_last_query_id()
+    }
+
     pub fn get_tenant(self: &Arc<Self>) -> String {
         self.session_ctx.get_tenant()
     }
@@ -189,6 +194,22 @@ impl Session {
         self.session_ctx.set_current_user(user)
     }
 
+    pub fn get_prepared_statement(self: &Arc<Self>, name: &str) -> Option<Arc<PreparedStatement>> {
+        self.session_ctx.get_prepared_statement(name)
+    }
+
+    pub fn set_prepared_statement(self: &Arc<Self>, name: String, statement: PreparedStatement) {
+        self.session_ctx
+            .set_prepared_statement(name, Arc::new(statement))
+    }
+
+    pub fn remove_prepared_statement(
+        self: &Arc<Self>,
+        name: &str,
+    ) -> Option<Arc<PreparedStatement>> {
+        self.session_ctx.remove_prepared_statement(name)
+    }
+
     pub async fn validate_privilege(
         self: &Arc<Self>,
         object: &GrantObject,
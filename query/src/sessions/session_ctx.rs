@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
@@ -25,6 +26,7 @@ use futures::channel::oneshot::Sender;
 
 use crate::configs::Config;
 use crate::sessions::QueryContextShared;
+use crate::sql::PreparedStatement;
 
 #[derive(MallocSizeOf)]
 pub struct SessionContext {
@@ -32,6 +34,7 @@ pub struct SessionContext {
     conf: Config,
     abort: AtomicBool,
     current_database: RwLock<String>,
+    last_query_id: RwLock<String>,
     #[ignore_malloc_size_of = "insignificant"]
     current_user: RwLock<Option<UserInfo>>,
     #[ignore_malloc_size_of = "insignificant"]
@@ -40,6 +43,8 @@ pub struct SessionContext {
     io_shutdown_tx: RwLock<Option<Sender<Sender<()>>>>,
     #[ignore_malloc_size_of = "insignificant"]
     query_context_shared: RwLock<Option<Arc<QueryContextShared>>>,
+    #[ignore_malloc_size_of = "insignificant"]
+    prepared_statements: RwLock<HashMap<String, Arc<PreparedStatement>>>,
 }
 
 impl SessionContext {
@@ -50,8 +55,10 @@ impl SessionContext {
             current_user: Default::default(),
             client_host: Default::default(),
             current_database: RwLock::new("default".to_string()),
+            last_query_id: Default::default(),
             io_shutdown_tx: Default::default(),
             query_context_shared: Default::default(),
+            prepared_statements: Default::default(),
         })
     }
 
@@ -77,6 +84,17 @@ impl SessionContext {
         *lock = db
     }
 
+    // Get the id of the last finished query, empty if there is none yet.
+    pub fn get_last_query_id(&self) -> String {
+        let lock = self.last_query_id.read();
+        lock.clone()
+    }
+
+    pub fn set_last_query_id(&self, query_id: String) {
+        let mut lock = self.last_query_id.write();
+        *lock = query_id
+    }
+
     pub fn get_tenant(&self) -> String {
         self.conf.query.tenant_id.clone()
     }
@@ -134,4 +152,20 @@ impl SessionContext {
         let mut lock = self.query_context_shared.write();
         lock.take()
     }
+
+    pub fn get_prepared_statement(&self, name: &str) -> Option<Arc<PreparedStatement>> {
+        let lock = self.prepared_statements.read();
+        lock.get(name).cloned()
+    }
+
+    // Add or replace the prepared statement.
+    pub fn set_prepared_statement(&self, name: String, statement: Arc<PreparedStatement>) {
+        let mut lock = self.prepared_statements.write();
+        lock.insert(name, statement);
+    }
+
+    pub fn remove_prepared_statement(&self, name: &str) -> Option<Arc<PreparedStatement>> {
+        let mut lock = self.prepared_statements.write();
+        lock.remove(name)
+    }
 }
@@ -19,6 +19,7 @@ use common_base::ProgressValues;
 use common_contexts::DalMetrics;
 use common_meta_types::UserInfo;
 
+use crate::pipelines::new::executor::PipelineProfile;
 use crate::sessions::Session;
 use crate::sessions::SessionContext;
 use crate::sessions::SessionType;
@@ -70,6 +71,14 @@ impl Session {
         }
     }
 
+    /// The processors graph of the running query, None if it's not executed by the new processors.
+    pub fn pipeline_profile(self: &Arc<Self>) -> Option<PipelineProfile> {
+        self.session_ctx
+            .get_query_context_shared()
+            .and_then(|context_shared| context_shared.get_executor())
+            .map(|executor| executor.get_profile())
+    }
+
     fn process_state(self: &Arc<Self>, status: &SessionContext) -> String {
         match status.get_query_context_shared() {
             _ if status.get_abort() => String::from("Aborting"),
@@ -40,16 +40,25 @@ use opendal::Accessor;
 use opendal::Operator;
 use opendal::Scheme as DalSchema;
 
+use crate::catalogs::Catalog;
 use crate::catalogs::DatabaseCatalog;
 use crate::clusters::ClusterDiscovery;
+use crate::clusters::NodeRoutingRules;
 use crate::configs::Config;
+use crate::interpreters::ResultCache;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::session::Session;
 use crate::sessions::session_ref::SessionRef;
+use crate::sessions::AsyncInsertQueue;
+use crate::sessions::ConsistencyChecker;
 use crate::sessions::ProcessInfo;
 use crate::sessions::SessionManagerStatus;
 use crate::sessions::SessionType;
+use crate::sql::PlanCache;
 use crate::storages::cache::CacheManager;
+use crate::storages::fuse::io::MetaCipher;
+use crate::storages::fuse::operations::ConsistencyReport;
+use crate::storages::fuse::FuseTable;
 use crate::users::auth::auth_mgr::AuthMgr;
 use crate::users::UserApiProvider;
 
@@ -60,14 +69,20 @@ pub struct SessionManager {
     pub(in crate::sessions) user_manager: RwLock<Arc<UserApiProvider>>,
     pub(in crate::sessions) auth_manager: RwLock<Arc<AuthMgr>>,
     pub(in crate::sessions) http_query_manager: Arc<HttpQueryManager>,
+    pub(in crate::sessions) async_insert_queue: Arc<AsyncInsertQueue>,
+    pub(in crate::sessions) consistency_checker: Arc<ConsistencyChecker>,
 
     pub(in crate::sessions) max_sessions: usize,
     pub(in crate::sessions) active_sessions: Arc<RwLock<HashMap<String, Arc<Session>>>>,
     pub(in crate::sessions) storage_cache_manager: RwLock<Arc<CacheManager>>,
+    pub(in crate::sessions) plan_cache: RwLock<Arc<PlanCache>>,
+    pub(in crate::sessions) result_cache: RwLock<Arc<ResultCache>>,
+    pub(in crate::sessions) node_routing_rules: RwLock<Arc<NodeRoutingRules>>,
     pub(in crate::sessions) query_logger:
         RwLock<Option<Arc<dyn tracing::Subscriber + Send + Sync>>>,
     pub status: Arc<RwLock<SessionManagerStatus>>,
     storage_operator: RwLock<Operator>,
+    meta_cipher: RwLock<Option<Arc<MetaCipher>>>,
     storage_runtime: Arc<Runtime>,
     _guards: Vec<WorkerGuard>,
 }
@@ -76,6 +91,11 @@ impl SessionManager {
     pub async fn from_conf(conf: Config) -> Result<Arc<SessionManager>> {
         let catalog = Arc::new(DatabaseCatalog::try_create_with_config(conf.clone()).await?);
         let storage_cache_manager = Arc::new(CacheManager::init(&conf.query));
+        let plan_cache = Arc::new(PlanCache::create(conf.query.plan_cache_count));
+        let result_cache = Arc::new(ResultCache::create(conf.query.result_cache_bytes));
+        let node_routing_rules = Arc::new(NodeRoutingRules::try_create(
+            &conf.query.node_routing_rules,
+        )?);
 
         // Cluster discovery.
         let discovery = ClusterDiscovery::create_global(conf.clone()).await?;
@@ -94,6 +114,8 @@ impl SessionManager {
             .await?
             .layer(DalRuntime::new(storage_runtime.inner()));
 
+        let meta_cipher = MetaCipher::try_create(&conf.storage.encryption.encryption_key)?;
+
         // User manager and init the default users.
         let user = UserApiProvider::create_global(conf.clone()).await?;
         let auth_manager = Arc::new(AuthMgr::create(conf.clone(), user.clone()).await?);
@@ -116,13 +138,19 @@ impl SessionManager {
             discovery: RwLock::new(discovery),
             user_manager: RwLock::new(user),
             http_query_manager,
+            async_insert_queue: AsyncInsertQueue::create(),
+            consistency_checker: ConsistencyChecker::create(),
             max_sessions,
             active_sessions,
             auth_manager: RwLock::new(auth_manager),
             storage_cache_manager: RwLock::new(storage_cache_manager),
+            plan_cache: RwLock::new(plan_cache),
+            result_cache: RwLock::new(result_cache),
+            node_routing_rules: RwLock::new(node_routing_rules),
             query_logger: RwLock::new(query_logger),
             status,
             storage_operator: RwLock::new(storage_operator),
+            meta_cipher: RwLock::new(meta_cipher.map(Arc::new)),
             storage_runtime: Arc::new(storage_runtime),
             _guards,
         }))
@@ -136,10 +164,22 @@ impl SessionManager {
         self.discovery.read().clone()
     }
 
+    pub fn get_node_routing_rules(self: &Arc<Self>) -> Arc<NodeRoutingRules> {
+        self.node_routing_rules.read().clone()
+    }
+
     pub fn get_http_query_manager(self: &Arc<Self>) -> Arc<HttpQueryManager> {
         self.http_query_manager.clone()
     }
 
+    pub fn get_async_insert_queue(self: &Arc<Self>) -> Arc<AsyncInsertQueue> {
+        self.async_insert_queue.clone()
+    }
+
+    pub fn get_consistency_checker(self: &Arc<Self>) -> Arc<ConsistencyChecker> {
+        self.consistency_checker.clone()
+    }
+
     pub fn get_auth_manager(self: &Arc<Self>) -> Arc<AuthMgr> {
         self.auth_manager.read().clone()
     }
@@ -157,10 +197,23 @@ impl SessionManager {
         self.storage_operator.read().clone()
     }
 
+    /// The cipher of the table data in the storage, None if the encryption is not configured.
+    pub fn get_meta_cipher(&self) -> Option<Arc<MetaCipher>> {
+        self.meta_cipher.read().clone()
+    }
+
     pub fn get_storage_cache_manager(&self) -> Arc<CacheManager> {
         self.storage_cache_manager.read().clone()
     }
 
+    pub fn get_plan_cache(&self) -> Arc<PlanCache> {
+        self.plan_cache.read().clone()
+    }
+
+    pub fn get_result_cache(&self) -> Arc<ResultCache> {
+        self.result_cache.read().clone()
+    }
+
     pub fn get_storage_runtime(&self) -> Arc<Runtime> {
         self.storage_runtime.clone()
     }
@@ -272,14 +325,19 @@ impl SessionManager {
         timeout_secs: i32,
     ) -> impl Future<Output = ()> {
         let active_sessions = self.active_sessions.clone();
+        let async_insert_queue = self.async_insert_queue.clone();
         async move {
             tracing::info!(
                 "Waiting {} secs for connections to close. You can press Ctrl + C again to force shutdown.",
                 timeout_secs);
             let mut signal = Box::pin(signal.next());
 
+            // The accepted async inserts are flushed before their sessions are killed.
+            async_insert_queue.close();
             for _index in 0..timeout_secs {
-                if SessionManager::destroy_idle_sessions(&active_sessions).await {
+                if async_insert_queue.is_drained()
+                    && SessionManager::destroy_idle_sessions(&active_sessions).await
+                {
                     return;
                 }
 
@@ -292,6 +350,7 @@ impl SessionManager {
             }
 
             tracing::info!("Will shutdown forcefully.");
+            async_insert_queue.fail_pending("The server shut down before the data was flushed");
             active_sessions
                 .read()
                 .values()
@@ -307,6 +366,102 @@ impl SessionManager {
             .collect::<Vec<_>>()
     }
 
+    /// Waits for this node to be drained, then leaves the cluster as soon as
+    /// the running queries are finished.
+    pub fn drain_routine(self: &Arc<Self>) -> impl Future<Output = ()> {
+        let session_manager = self.clone();
+        async move {
+            let discovery = session_manager.get_cluster_discovery();
+            discovery.wait_drain_request().await;
+
+            loop {
+                let running_queries = session_manager
+                    .processes_info()
+                    .await
+                    .iter()
+                    .filter(|process| process.state == "Query")
+                    .count();
+
+                if running_queries == 0 {
+                    break;
+                }
+
+                tracing::info!(
+                    "Draining, waiting for {} running queries to finish.",
+                    running_queries
+                );
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+
+            match discovery.deregister().await {
+                Ok(_) => tracing::info!("Drained, the node has left the cluster."),
+                Err(cause) => tracing::warn!("Cannot leave the cluster, cause {:?}", cause),
+            }
+        }
+    }
+
+    /// Checks the snapshots of the fuse tables against the storage every
+    /// `consistency_check_interval_secs`, the findings are kept by the [ConsistencyChecker].
+    pub fn consistency_check_routine(self: &Arc<Self>) -> impl Future<Output = ()> {
+        let session_manager = self.clone();
+        async move {
+            let conf = session_manager.get_conf();
+            let interval = conf.query.consistency_check_interval_secs;
+            if interval == 0 {
+                return;
+            }
+            let retention =
+                chrono::Duration::seconds(conf.query.consistency_check_retention_secs as i64);
+
+            loop {
+                tokio::time::sleep(Duration::from_secs(interval)).await;
+                match session_manager.check_tables_consistency().await {
+                    Ok(reports) => session_manager
+                        .consistency_checker
+                        .update(reports, retention),
+                    Err(cause) => tracing::warn!("Consistency check failed, cause {:?}", cause),
+                }
+            }
+        }
+    }
+
+    async fn check_tables_consistency(
+        self: &Arc<Self>,
+    ) -> Result<Vec<(String, String, ConsistencyReport)>> {
+        let session = self.create_session(SessionType::ConsistencyCheck).await?;
+        let ctx = session.create_query_context().await?;
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog();
+
+        let mut reports = vec![];
+        for database in catalog.list_databases(tenant.as_str()).await? {
+            let tables = catalog
+                .list_tables(tenant.as_str(), database.name())
+                .await?;
+            for table in tables {
+                // only the fuse tables keep their data in the storage
+                let fuse_table = match FuseTable::try_from_table(table.as_ref()) {
+                    Ok(fuse_table) => fuse_table,
+                    Err(_) => continue,
+                };
+                match fuse_table.check_consistency(ctx.clone()).await {
+                    Ok(report) => reports.push((
+                        database.name().to_string(),
+                        table.name().to_string(),
+                        report,
+                    )),
+                    Err(cause) => tracing::warn!(
+                        "Cannot check the consistency of {}.{}, cause {:?}",
+                        database.name(),
+                        table.name(),
+                        cause
+                    ),
+                }
+            }
+        }
+        Ok(reports)
+    }
+
     async fn destroy_idle_sessions(sessions: &Arc<RwLock<HashMap<String, Arc<Session>>>>) -> bool {
         // Read lock does not support reentrant
         // https://github.com/Amanieu/parking_lot/blob/lock_api-0.4.4/lock_api/src/rwlock.rs#L422
@@ -423,6 +578,11 @@ impl SessionManager {
         }
 
         *self.storage_cache_manager.write() = Arc::new(CacheManager::init(&config.query));
+        *self.plan_cache.write() = Arc::new(PlanCache::create(config.query.plan_cache_count));
+        *self.result_cache.write() = Arc::new(ResultCache::create(config.query.result_cache_bytes));
+        *self.node_routing_rules.write() = Arc::new(NodeRoutingRules::try_create(
+            &config.query.node_routing_rules,
+        )?);
 
         {
             // NOTE: Magic happens here. We will add a layer upon original storage operator
@@ -433,6 +593,11 @@ impl SessionManager {
             *self.storage_operator.write() = operator;
         }
 
+        {
+            let cipher = MetaCipher::try_create(&config.storage.encryption.encryption_key)?;
+            *self.meta_cipher.write() = cipher.map(Arc::new);
+        }
+
         {
             let discovery = ClusterDiscovery::create_global(config.clone()).await?;
             *self.discovery.write() = discovery;
@@ -19,6 +19,7 @@ use std::fmt::Formatter;
 use std::sync::Arc;
 
 use common_datavalues::prelude::*;
+use common_datavalues::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_infallible::RwLock;
@@ -137,6 +138,148 @@ impl Settings {
                 level: ScopeLevel::Session,
                 desc: "Enable new processor framework if value != 0, default value: 1",
             },
+
+            // enable_log_redaction
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("enable_log_redaction", DataValue::UInt64(0)),
+                level: ScopeLevel::Session,
+                desc: "Redact literals in logged queries and values in cast/parse errors if value != 0, default value: 0",
+            },
+
+            // explain_graphviz_with_schema
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("explain_graphviz_with_schema", DataValue::UInt64(0)),
+                level: ScopeLevel::Session,
+                desc: "Show the output schema of every plan node in EXPLAIN GRAPHVIZ if value != 0, default value: 0",
+            },
+
+            // max_scan_rows
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("max_scan_rows", DataValue::UInt64(0)),
+                level: ScopeLevel::Session,
+                desc: "Reject queries estimated to scan more rows than this if value != 0, default value: 0",
+            },
+
+            // max_scan_bytes
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("max_scan_bytes", DataValue::UInt64(0)),
+                level: ScopeLevel::Session,
+                desc: "Reject queries estimated to scan more bytes than this if value != 0, default value: 0",
+            },
+
+            // ignore_scan_limits
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("ignore_scan_limits", DataValue::UInt64(0)),
+                level: ScopeLevel::Session,
+                desc: "Run queries exceeding max_scan_rows or max_scan_bytes if value != 0, default value: 0",
+            },
+
+            // max_expression_depth
+            SettingValue {
+                default_value: DataValue::UInt64(128),
+                user_setting: UserSetting::create("max_expression_depth", DataValue::UInt64(128)),
+                level: ScopeLevel::Session,
+                desc: "Reject queries with expressions nested deeper than this, default value: 128",
+            },
+
+            // json_quote_non_finite_floats
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "json_quote_non_finite_floats",
+                    DataValue::UInt64(0),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Output NaN and Infinity floats as strings in JSON if value != 0, otherwise as null, default value: 0",
+            },
+
+            // unquoted_ident_case_sensitive
+            SettingValue {
+                default_value: DataValue::UInt64(1),
+                user_setting: UserSetting::create(
+                    "unquoted_ident_case_sensitive",
+                    DataValue::UInt64(1),
+                ),
+                level: ScopeLevel::Session,
+                desc: "Keep the case of unquoted identifiers if value != 0, otherwise fold them to lowercase, default value: 1",
+            },
+
+            // async_insert
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("async_insert", DataValue::UInt64(0)),
+                level: ScopeLevel::Session,
+                desc: "Return a handle once INSERT data is accepted and flush it in the background if value != 0, default value: 0",
+            },
+
+            // enable_query_result_cache
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("enable_query_result_cache", DataValue::UInt64(0)),
+                level: ScopeLevel::Session,
+                desc: "Reuse the cached results of identical deterministic queries on unchanged FUSE tables if value != 0, default value: 0",
+            },
+
+            // query_result_cache_max_bytes
+            SettingValue {
+                default_value: DataValue::UInt64(1024 * 1024),
+                user_setting: UserSetting::create("query_result_cache_max_bytes", DataValue::UInt64(1024 * 1024)),
+                level: ScopeLevel::Session,
+                desc: "Max bytes of a query result to be cached, default value: 1048576",
+            },
+
+            // parquet_row_group_rows
+            SettingValue {
+                default_value: DataValue::UInt64(1024 * 1024),
+                user_setting: UserSetting::create("parquet_row_group_rows", DataValue::UInt64(1024 * 1024)),
+                level: ScopeLevel::Session,
+                desc: "Max rows of a row group in the parquet files written by INTO OUTFILE, default value: 1048576",
+            },
+
+            // enforce_deterministic_order
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("enforce_deterministic_order", DataValue::UInt64(0)),
+                level: ScopeLevel::Session,
+                desc: "Sort the results of queries without ORDER BY by all the output columns if value != 0, default value: 0",
+            },
+
+            // timezone
+            SettingValue {
+                default_value: DataValue::String("UTC".as_bytes().to_vec()),
+                user_setting: UserSetting::create("timezone", DataValue::String("UTC".as_bytes().to_vec())),
+                level: ScopeLevel::Session,
+                desc: "The timezone of the timestamps cast from strings without a timezone, default value: UTC",
+            },
+
+            // executor_stall_timeout_secs
+            SettingValue {
+                default_value: DataValue::UInt64(300),
+                user_setting: UserSetting::create("executor_stall_timeout_secs", DataValue::UInt64(300)),
+                level: ScopeLevel::Session,
+                desc: "Log the processors graph if the query makes no progress for this many seconds, 0 disables the check, default value: 300",
+            },
+
+            // executor_abort_on_stall
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("executor_abort_on_stall", DataValue::UInt64(0)),
+                level: ScopeLevel::Session,
+                desc: "Abort the query if it makes no progress for executor_stall_timeout_secs if value != 0, default value: 0",
+            },
+
+            // dictionary_encoding_max_ndv
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("dictionary_encoding_max_ndv", DataValue::UInt64(0)),
+                level: ScopeLevel::Session,
+                desc: "Dictionary encode the scanned string columns with at most this many distinct values, 0 disables the encoding, default value: 0",
+            },
         ];
 
         let settings = Arc::new(RwLock::new(HashMap::default()));
@@ -223,6 +366,91 @@ impl Settings {
         self.try_get_u64(key)
     }
 
+    pub fn get_async_insert(&self) -> Result<u64> {
+        let key = "async_insert";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_enable_log_redaction(&self) -> Result<u64> {
+        let key = "enable_log_redaction";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_explain_graphviz_with_schema(&self) -> Result<u64> {
+        let key = "explain_graphviz_with_schema";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_max_scan_rows(&self) -> Result<u64> {
+        let key = "max_scan_rows";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_max_scan_bytes(&self) -> Result<u64> {
+        let key = "max_scan_bytes";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_ignore_scan_limits(&self) -> Result<u64> {
+        let key = "ignore_scan_limits";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_max_expression_depth(&self) -> Result<u64> {
+        let key = "max_expression_depth";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_json_quote_non_finite_floats(&self) -> Result<u64> {
+        let key = "json_quote_non_finite_floats";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_unquoted_ident_case_sensitive(&self) -> Result<u64> {
+        let key = "unquoted_ident_case_sensitive";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_enable_query_result_cache(&self) -> Result<u64> {
+        let key = "enable_query_result_cache";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_query_result_cache_max_bytes(&self) -> Result<u64> {
+        let key = "query_result_cache_max_bytes";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_parquet_row_group_rows(&self) -> Result<u64> {
+        let key = "parquet_row_group_rows";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_enforce_deterministic_order(&self) -> Result<u64> {
+        let key = "enforce_deterministic_order";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_timezone(&self) -> Result<String> {
+        let key = "timezone";
+        self.try_get_string(key)
+    }
+
+    pub fn get_executor_stall_timeout_secs(&self) -> Result<u64> {
+        let key = "executor_stall_timeout_secs";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_executor_abort_on_stall(&self) -> Result<u64> {
+        let key = "executor_abort_on_stall";
+        self.try_get_u64(key)
+    }
+
+    pub fn get_dictionary_encoding_max_ndv(&self) -> Result<u64> {
+        let key = "dictionary_encoding_max_ndv";
+        self.try_get_u64(key)
+    }
+
     fn check_and_get_setting_value(&self, key: &str) -> Result<SettingValue> {
         let settings = self.settings.read();
         let setting = settings
@@ -237,13 +465,28 @@ impl Settings {
         setting.user_setting.value.as_u64()
     }
 
+    fn try_get_string(&self, key: &str) -> Result<String> {
+        let setting = self.check_and_get_setting_value(key)?;
+        let value = setting.user_setting.value.as_string()?;
+        Ok(String::from_utf8(value)?)
+    }
+
     // Set u64 value to settings map, if is_global will write to metasrv.
     fn try_set_u64(&self, key: &str, val: u64, is_global: bool) -> Result<()> {
+        self.try_set_value(key, DataValue::UInt64(val), is_global)
+    }
+
+    // Set string value to settings map, if is_global will write to metasrv.
+    fn try_set_string(&self, key: &str, val: String, is_global: bool) -> Result<()> {
+        self.try_set_value(key, DataValue::String(val.into_bytes()), is_global)
+    }
+
+    fn try_set_value(&self, key: &str, val: DataValue, is_global: bool) -> Result<()> {
         let mut settings = self.settings.write();
         let mut setting = settings
             .get_mut(key)
             .ok_or_else(|| ErrorCode::UnknownVariable(format!("Unknown variable: {:?}", key)))?;
-        setting.user_setting.value = DataValue::UInt64(val);
+        setting.user_setting.value = val;
 
         if is_global {
             let tenant = self.session_ctx.get_tenant();
@@ -298,6 +541,15 @@ impl Settings {
                 let u64_val = val.parse::<u64>()?;
                 self.try_set_u64(&key, u64_val, is_global)?;
             }
+            TypeID::String => {
+                if key == "timezone" && val.parse::<Tz>().is_err() {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Unknown timezone: {:?}",
+                        val
+                    )));
+                }
+                self.try_set_string(&key, val, is_global)?;
+            }
             v => {
                 return Err(ErrorCode::UnknownVariable(format!(
                     "Unsupported variable:{:?} type:{:?} when set_settings().",
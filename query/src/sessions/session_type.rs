@@ -23,12 +23,20 @@ pub enum SessionType {
     ClickHouseHttpHandler,
     FlightRPC,
     HTTPAPI(String),
+    AsyncInsert,
+    ConsistencyCheck,
     Test,
 }
 
 impl SessionType {
     pub fn is_user_session(&self) -> bool {
-        !matches!(self, SessionType::HTTPAPI(_) | SessionType::Test)
+        !matches!(
+            self,
+            SessionType::HTTPAPI(_)
+                | SessionType::AsyncInsert
+                | SessionType::ConsistencyCheck
+                | SessionType::Test
+        )
     }
 }
 
@@ -43,6 +51,8 @@ impl fmt::Display for SessionType {
             SessionType::Test => "Test".to_string(),
             SessionType::FlightRPC => "FlightRPC".to_string(),
             SessionType::HTTPAPI(usage) => format!("HTTPAPI({})", usage),
+            SessionType::AsyncInsert => "AsyncInsert".to_string(),
+            SessionType::ConsistencyCheck => "ConsistencyCheck".to_string(),
         };
         write!(f, "{}", name)
     }
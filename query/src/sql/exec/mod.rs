@@ -121,9 +121,8 @@ impl Executor {
         self.ctx.try_set_partitions(plan.parts.clone())?;
 
         let mut pipeline = Pipeline::create(self.ctx.clone());
-        let max_threads = self.ctx.get_settings().get_max_threads()? as usize;
-        let max_threads = std::cmp::min(max_threads, plan.parts.len());
-        let workers = std::cmp::max(max_threads, 1);
+        let workers = table.read_parallelism(&self.ctx, &plan)?;
+        self.ctx.set_parallelism(workers);
 
         for _ in 0..workers {
             let source = SourceTransform::try_create(self.ctx.clone(), plan.clone())?;
@@ -17,18 +17,24 @@ mod exec;
 mod metrics;
 pub mod optimizer;
 mod parsers;
+mod plan_cache;
 mod plan_parser;
 mod planner;
 mod sql_common;
 mod sql_parser;
+mod sql_prepared_statement;
+mod sql_redactor;
 mod sql_statement;
 pub mod statements;
 mod table_option_keys;
 
 pub use common::*;
+pub use plan_cache::PlanCache;
 pub use plan_parser::PlanParser;
 pub use planner::*;
 pub use sql_common::SQLCommon;
 pub use sql_parser::DfParser;
+pub use sql_prepared_statement::PreparedStatement;
+pub use sql_redactor::SQLRedactor;
 pub use sql_statement::*;
 pub use table_option_keys::*;
@@ -13,12 +13,14 @@
 // limitations under the License.
 
 mod parser_call;
+mod parser_cluster;
 mod parser_copy;
 mod parser_database;
 mod parser_explain;
 mod parser_insert;
 mod parser_kill;
 mod parser_optimize;
+mod parser_prepare;
 mod parser_query;
 mod parser_set;
 mod parser_show;
@@ -0,0 +1,31 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use sqlparser::parser::ParserError;
+
+use crate::sql::statements::DfDrainNode;
+use crate::sql::DfParser;
+use crate::sql::DfStatement;
+
+impl<'a> DfParser<'a> {
+    // ALTER CLUSTER DRAIN NODE 'node_id'
+    pub(crate) fn parse_alter_cluster(&mut self) -> Result<DfStatement, ParserError> {
+        if !(self.consume_token("DRAIN") && self.consume_token("NODE")) {
+            return self.expected("DRAIN NODE", self.parser.peek_token());
+        }
+
+        let node_id = self.parser.parse_literal_string()?;
+        Ok(DfStatement::DrainNode(DfDrainNode { node_id }))
+    }
+}
@@ -66,10 +66,13 @@ impl<'a> DfParser<'a> {
             self.expect_token(")")?;
         }
 
-        // PATTERN = '<regex_pattern>'
+        // PATTERN = '<regex_pattern>' or PATTERN => '<regex_pattern>'
         let mut pattern = "".to_string();
         if self.consume_token("PATTERN") {
-            self.expect_token("=")?;
+            if !self.consume_token("=>") {
+                self.expect_token("=")?;
+                self.consume_token(">");
+            }
             pattern = self.parse_value_or_ident()?;
         }
 
@@ -86,6 +89,8 @@ impl<'a> DfParser<'a> {
          copyOptions ::=
          ON_ERROR = { CONTINUE | SKIP_FILE | SKIP_FILE_<num> | SKIP_FILE_<num>% | ABORT_STATEMENT }
          SIZE_LIMIT = <num>
+         FORCE = TRUE | FALSE
+         SORT_BY_CLUSTER_KEY = TRUE | FALSE
         */
         let mut on_error = "".to_string();
         if self.consume_token("ON_ERROR") {
@@ -99,6 +104,18 @@ impl<'a> DfParser<'a> {
             size_limit = self.parse_value_or_ident()?;
         }
 
+        let mut force = "".to_string();
+        if self.consume_token("FORCE") {
+            self.expect_token("=")?;
+            force = self.parse_value_or_ident()?;
+        }
+
+        let mut sort_by_cluster_key = "".to_string();
+        if self.consume_token("SORT_BY_CLUSTER_KEY") {
+            self.expect_token("=")?;
+            sort_by_cluster_key = self.parse_value_or_ident()?;
+        }
+
         // VALIDATION_MODE = RETURN_<n>_ROWS | RETURN_ERRORS | RETURN_ALL_ERRORS
         let mut validation_mode = "".to_string();
         if self.consume_token("VALIDATION_MODE") {
@@ -117,6 +134,8 @@ impl<'a> DfParser<'a> {
             pattern,
             on_error,
             size_limit,
+            force,
+            sort_by_cluster_key,
             validation_mode,
         }))
     }
@@ -38,6 +38,17 @@ impl<'a> DfParser<'a> {
                     self.parser.next_token();
                     ExplainType::Graph
                 }
+                "GRAPHVIZ" => {
+                    self.parser.next_token();
+                    ExplainType::Graphviz
+                }
+                "FORMAT" => {
+                    self.parser.next_token();
+                    match self.parser.next_token() {
+                        Token::Word(w) if w.value.to_uppercase() == "JSON" => ExplainType::Json,
+                        unexpected => return self.expected("JSON", unexpected),
+                    }
+                }
                 _ => ExplainType::Syntax,
             },
             _ => ExplainType::Syntax,
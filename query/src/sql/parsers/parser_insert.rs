@@ -16,7 +16,10 @@
 // See notice.md
 
 use sqlparser::ast::Statement;
+use sqlparser::dialect::keywords::Keyword;
+use sqlparser::parser::IsOptional;
 use sqlparser::parser::ParserError;
+use sqlparser::tokenizer::Token;
 
 use crate::parser_err;
 use crate::sql::statements::DfInsertStatement;
@@ -49,8 +52,208 @@ impl<'a> DfParser<'a> {
                 after_columns,
                 table,
                 on,
+                values: None,
             })),
             _ => parser_err!("Expect set insert statement"),
         }
     }
+
+    /// Parse the head of `INSERT INTO ... VALUES`, the values are kept as the raw text, which
+    /// is split off by `split_insert_values`.
+    pub(crate) fn parse_insert_values(&mut self, values: &str) -> Result<DfStatement, ParserError> {
+        self.parser.expect_keyword(Keyword::INSERT)?;
+        let overwrite = self.parser.parse_keyword(Keyword::OVERWRITE);
+        if !overwrite {
+            self.parser.expect_keyword(Keyword::INTO)?;
+        }
+        let table = self.parser.parse_keyword(Keyword::TABLE);
+        let table_name = self.parser.parse_object_name()?;
+        let columns = self
+            .parser
+            .parse_parenthesized_column_list(IsOptional::Optional)?;
+        self.parser.expect_keyword(Keyword::VALUES)?;
+        self.parser.expect_token(&Token::EOF)?;
+
+        Ok(DfStatement::InsertQuery(DfInsertStatement {
+            or: None,
+            table_name,
+            columns,
+            overwrite,
+            source: None,
+            partitioned: None,
+            format: None,
+            after_columns: vec![],
+            table,
+            on: None,
+            values: Some(values.to_string()),
+        }))
+    }
+
+    /// Split `INSERT ... VALUES (...), (...)` into the head ending with VALUES and the tuples,
+    /// if the tuples hold literals only. Such tuples are not tokenized and parsed into the AST,
+    /// which takes a lot of memory for a large insertion, they're converted into blocks
+    /// in streaming while inserting instead.
+    pub(crate) fn split_insert_values(sql: &str) -> Option<(&str, &str)> {
+        let bytes = sql.as_bytes();
+        let start = bytes.iter().position(|c| !c.is_ascii_whitespace())?;
+        if !starts_with_word(&bytes[start..], b"insert") {
+            return None;
+        }
+
+        let head_end = find_values_keyword(bytes, start)?;
+        let tuples_end = head_end + scan_literal_tuples(&bytes[head_end..])?;
+        Some((&sql[..head_end], sql[head_end..tuples_end].trim_start()))
+    }
+}
+
+fn is_word_byte(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || c == b'_'
+}
+
+fn starts_with_word(bytes: &[u8], word: &[u8]) -> bool {
+    bytes.len() >= word.len()
+        && bytes[..word.len()].eq_ignore_ascii_case(word)
+        && !bytes.get(word.len()).map_or(false, |c| is_word_byte(*c))
+}
+
+// Returns the position right after the first VALUES keyword out of parentheses, quotes and
+// comments, None if the statement ends before it.
+fn find_values_keyword(bytes: &[u8], start: usize) -> Option<usize> {
+    let mut depth = 0_usize;
+    let mut pos = start;
+    while pos < bytes.len() {
+        match bytes[pos] {
+            quote @ (b'\'' | b'"' | b'`') => {
+                pos += 1 + bytes[pos + 1..].iter().position(|c| *c == quote)?;
+            }
+            b'-' if bytes.get(pos + 1) == Some(&b'-') => {
+                pos += bytes[pos..].iter().position(|c| *c == b'\n')?;
+            }
+            b'/' if bytes.get(pos + 1) == Some(&b'*') => {
+                pos += 2 + bytes[pos + 2..].windows(2).position(|w| w == b"*/")? + 1;
+            }
+            b'(' => depth += 1,
+            b')' => depth = depth.saturating_sub(1),
+            b';' if depth == 0 => return None,
+            c if is_word_byte(c) => {
+                let len = bytes[pos..]
+                    .iter()
+                    .position(|c| !is_word_byte(*c))
+                    .unwrap_or(bytes.len() - pos);
+                if depth == 0 && bytes[pos..pos + len].eq_ignore_ascii_case(b"values") {
+                    return Some(pos + len);
+                }
+                pos += len;
+                continue;
+            }
+            _ => {}
+        }
+        pos += 1;
+    }
+    None
+}
+
+// Returns the length of the literal tuples at the beginning of the bytes, they may only be
+// followed by a semicolon and whitespaces. None if there are no tuples or anything other than
+// a literal is met, e.g. an expression or a comment.
+fn scan_literal_tuples(bytes: &[u8]) -> Option<usize> {
+    let skip_spaces = |pos: usize| {
+        pos + bytes[pos..]
+            .iter()
+            .take_while(|c| c.is_ascii_whitespace())
+            .count()
+    };
+
+    let mut pos = 0;
+    let mut tuples_end = None;
+    loop {
+        pos = skip_spaces(pos);
+        if tuples_end.is_some() {
+            match bytes.get(pos) {
+                Some(b',') => pos = skip_spaces(pos + 1),
+                _ => break,
+            }
+        }
+        if bytes.get(pos) != Some(&b'(') {
+            return None;
+        }
+
+        pos += 1;
+        loop {
+            pos = skip_spaces(pos);
+            pos += scan_literal(&bytes[pos..])?;
+            pos = skip_spaces(pos);
+            match bytes.get(pos) {
+                Some(b',') => pos += 1,
+                Some(b')') => break,
+                _ => return None,
+            }
+        }
+        pos += 1;
+        tuples_end = Some(pos);
+    }
+
+    if bytes.get(pos) == Some(&b';') {
+        pos = skip_spaces(pos + 1);
+    }
+    match pos == bytes.len() {
+        true => tuples_end,
+        false => None,
+    }
+}
+
+// Returns the length of the quoted string, number, boolean or NULL at the beginning of the bytes.
+fn scan_literal(bytes: &[u8]) -> Option<usize> {
+    match bytes.first()? {
+        quote @ (b'\'' | b'"') => {
+            let mut pos = 1;
+            loop {
+                match bytes.get(pos)? {
+                    b'\\' => pos += 2,
+                    c if c == quote && bytes.get(pos + 1) == Some(quote) => pos += 2,
+                    c if c == quote => return Some(pos + 1),
+                    _ => pos += 1,
+                }
+            }
+        }
+        _ => {
+            let len = bytes
+                .iter()
+                .position(|c| !(is_word_byte(*c) || matches!(c, b'.' | b'+' | b'-')))
+                .unwrap_or(bytes.len());
+            let word = &bytes[..len];
+            let is_literal = is_number(word)
+                || word.eq_ignore_ascii_case(b"true")
+                || word.eq_ignore_ascii_case(b"false")
+                || word.eq_ignore_ascii_case(b"null");
+            is_literal.then(|| len)
+        }
+    }
+}
+
+// [+-]digits[.digits][e[+-]digits], with at least one digit in the mantissa.
+fn is_number(bytes: &[u8]) -> bool {
+    let digits = |bytes: &[u8]| bytes.iter().take_while(|c| c.is_ascii_digit()).count();
+
+    let mut pos = usize::from(matches!(bytes.first(), Some(b'+' | b'-')));
+    let mut mantissa = digits(&bytes[pos..]);
+    pos += mantissa;
+    if bytes.get(pos) == Some(&b'.') {
+        let fraction = digits(&bytes[pos + 1..]);
+        mantissa += fraction;
+        pos += 1 + fraction;
+    }
+    if mantissa == 0 {
+        return false;
+    }
+    if matches!(bytes.get(pos), Some(b'e' | b'E')) {
+        pos += 1;
+        pos += usize::from(matches!(bytes.get(pos), Some(b'+' | b'-')));
+        let exponent = digits(&bytes[pos..]);
+        if exponent == 0 {
+            return false;
+        }
+        pos += exponent;
+    }
+    pos == bytes.len()
 }
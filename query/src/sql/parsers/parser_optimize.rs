@@ -26,9 +26,10 @@ use crate::sql::DfStatement;
 
 impl<'a> DfParser<'a> {
     pub(crate) fn parse_optimize(&mut self) -> Result<DfStatement, ParserError> {
-        // syntax: "optimize TABLE t [purge | compact | all]",  default action is "purge"
+        // syntax: "optimize TABLE [IF EXISTS] t [purge | compact | all]",  default action is "purge"
         self.expect_token("OPTIMIZE")?;
         self.parser.expect_keyword(Keyword::TABLE)?;
+        let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
         let object_name = self.parser.parse_object_name()?;
         let operation = match self.parser.next_token() {
             Token::EOF => Ok(Optimization::PURGE),
@@ -44,6 +45,7 @@ impl<'a> DfParser<'a> {
         }?;
 
         Ok(DfStatement::OptimizeTable(DfOptimizeTable {
+            if_exists,
             name: object_name,
             operation,
         }))
@@ -0,0 +1,68 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use sqlparser::dialect::keywords::Keyword;
+use sqlparser::parser::ParserError;
+use sqlparser::tokenizer::Token;
+
+use crate::sql::statements::DfDeallocate;
+use crate::sql::statements::DfExecute;
+use crate::sql::statements::DfPrepare;
+use crate::sql::DfParser;
+use crate::sql::DfStatement;
+
+impl<'a> DfParser<'a> {
+    // PREPARE <name> FROM '<query>'
+    pub(crate) fn parse_prepare(&mut self) -> Result<DfStatement, ParserError> {
+        self.parser.next_token();
+        let name = self.parser.parse_identifier()?;
+        self.parser.expect_keyword(Keyword::FROM)?;
+        let query = self.parser.parse_literal_string()?;
+
+        Ok(DfStatement::Prepare(DfPrepare {
+            name: name.value,
+            query,
+        }))
+    }
+
+    // EXECUTE <name> [USING <param>, ...]
+    pub(crate) fn parse_execute(&mut self) -> Result<DfStatement, ParserError> {
+        self.parser.next_token();
+        let name = self.parser.parse_identifier()?;
+
+        let mut params = vec![];
+        if self.parser.parse_keyword(Keyword::USING) {
+            loop {
+                params.push(self.parser.parse_expr()?);
+                if !self.parser.consume_token(&Token::Comma) {
+                    break;
+                }
+            }
+        }
+
+        Ok(DfStatement::Execute(DfExecute {
+            name: name.value,
+            params,
+        }))
+    }
+
+    // DEALLOCATE [PREPARE] <name>
+    pub(crate) fn parse_deallocate(&mut self) -> Result<DfStatement, ParserError> {
+        self.parser.next_token();
+        self.parser.parse_keyword(Keyword::PREPARE);
+        let name = self.parser.parse_identifier()?;
+
+        Ok(DfStatement::Deallocate(DfDeallocate { name: name.value }))
+    }
+}
@@ -15,9 +15,17 @@
 // Borrow from apache/arrow/rust/datafusion/src/sql/sql_parser
 // See notice.md
 
+use sqlparser::ast::Expr;
+use sqlparser::ast::Ident;
+use sqlparser::dialect::keywords::Keyword;
+use sqlparser::parser::Parser;
 use sqlparser::parser::ParserError;
+use sqlparser::tokenizer::Token;
+use sqlparser::tokenizer::Whitespace;
 
+use crate::sql::statements::DfPivot;
 use crate::sql::statements::DfQueryStatement;
+use crate::sql::statements::DfSelectIntoOutfile;
 use crate::sql::DfParser;
 use crate::sql::DfStatement;
 
@@ -25,9 +33,424 @@ impl<'a> DfParser<'a> {
     // SELECT.
     pub(crate) fn parse_query(&mut self) -> Result<DfStatement, ParserError> {
         // self.parser.prev_token();
+        let into_outfile = self.parse_into_outfile()?;
+        let pivot = self.parse_pivot()?;
+        let table_sample = self.parse_table_sample()?;
+        let distinct_on = self.parse_distinct_on()?;
+        let (wildcard_exclude, wildcard_replace) = self.parse_wildcard_options()?;
         let native_query = self.parser.parse_query()?;
-        Ok(DfStatement::Query(Box::new(DfQueryStatement::try_from(
-            native_query,
-        )?)))
+        let mut statement = DfQueryStatement::try_from(native_query)?;
+        statement.distinct_on = distinct_on;
+        statement.pivot = pivot;
+        statement.table_sample = table_sample;
+        statement.wildcard_exclude = wildcard_exclude;
+        statement.wildcard_replace = wildcard_replace;
+
+        match into_outfile {
+            None => Ok(DfStatement::Query(Box::new(statement))),
+            Some((path, format, compression)) => {
+                Ok(DfStatement::SelectIntoOutfile(DfSelectIntoOutfile {
+                    query: Box::new(statement),
+                    path,
+                    format,
+                    compression,
+                }))
+            }
+        }
+    }
+
+    // `SELECT ... INTO OUTFILE '<path>' [FORMAT <format>] [COMPRESSION '<compression>']` is not
+    // supported by sqlparser, so we cut the trailing clause out of the tokens.
+    fn parse_into_outfile(&mut self) -> Result<Option<IntoOutfileOptions>, ParserError> {
+        let mut tokens = vec![];
+        while let Some(token) = self.parser.next_token_no_skip() {
+            tokens.push(token.clone());
+        }
+
+        let mut into_outfile = None;
+        if let Some((start, end)) = Self::find_into_outfile_clause(&tokens) {
+            let clause_tokens = tokens.drain(start..end).collect::<Vec<_>>();
+            let mut parser = Parser::new(clause_tokens, self.dialect);
+            parser.next_token();
+            parser.next_token();
+            let path = parser.parse_literal_string()?;
+
+            let mut format = "".to_string();
+            let mut compression = "".to_string();
+            loop {
+                match parser.next_token() {
+                    Token::EOF => break,
+                    Token::Word(word) if word.value.eq_ignore_ascii_case("FORMAT") => {
+                        format = Self::parse_into_outfile_option(&mut parser)?;
+                    }
+                    Token::Word(word) if word.value.eq_ignore_ascii_case("COMPRESSION") => {
+                        parser.consume_token(&Token::Eq);
+                        compression = Self::parse_into_outfile_option(&mut parser)?;
+                    }
+                    unexpected => {
+                        return Err(ParserError::ParserError(format!(
+                            "Expected FORMAT or COMPRESSION after INTO OUTFILE, found: {}",
+                            unexpected
+                        )))
+                    }
+                }
+            }
+
+            into_outfile = Some((path, format, compression));
+        }
+
+        self.parser = Parser::new(tokens, self.dialect);
+        Ok(into_outfile)
+    }
+
+    fn parse_into_outfile_option(parser: &mut Parser) -> Result<String, ParserError> {
+        match parser.next_token() {
+            Token::Word(word) => Ok(word.value),
+            Token::SingleQuotedString(value) => Ok(value),
+            unexpected => Err(ParserError::ParserError(format!(
+                "Expected a value after INTO OUTFILE, found: {}",
+                unexpected
+            ))),
+        }
+    }
+
+    // Returns the token range of the `INTO OUTFILE ...` clause, it runs to the end of the query.
+    fn find_into_outfile_clause(tokens: &[Token]) -> Option<(usize, usize)> {
+        let mut depth = 0;
+        let mut start = None;
+        for (index, token) in tokens.iter().enumerate() {
+            match token {
+                Token::LParen => depth += 1,
+                Token::RParen => depth -= 1,
+                Token::SemiColon if depth == 0 => return start.map(|start| (start, index)),
+                Token::Word(word)
+                    if depth == 0 && start.is_none() && word.keyword == Keyword::INTO =>
+                {
+                    let next = next_non_whitespace(tokens, index + 1)?;
+                    if let Token::Word(next) = &tokens[next] {
+                        if next.quote_style.is_none() && next.value.eq_ignore_ascii_case("OUTFILE")
+                        {
+                            start = Some(index);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        start.map(|start| (start, tokens.len()))
+    }
+
+    // SELECT DISTINCT ON (expr, ...) is not supported by sqlparser, so we take the ON list
+    // here and leave a plain SELECT DISTINCT with the rest of the tokens to it.
+    fn parse_distinct_on(&mut self) -> Result<Vec<Expr>, ParserError> {
+        if !self
+            .parser
+            .parse_keywords(&[Keyword::SELECT, Keyword::DISTINCT, Keyword::ON])
+        {
+            return Ok(vec![]);
+        }
+
+        self.parser.expect_token(&Token::LParen)?;
+        let distinct_on = self.parser.parse_comma_separated(Parser::parse_expr)?;
+        self.parser.expect_token(&Token::RParen)?;
+
+        let mut tokens = vec![
+            Token::make_keyword("SELECT"),
+            Token::Whitespace(Whitespace::Space),
+            Token::make_keyword("DISTINCT"),
+        ];
+        while let Some(token) = self.parser.next_token_no_skip() {
+            tokens.push(token.clone());
+        }
+
+        self.parser = Parser::new(tokens, self.dialect);
+        Ok(distinct_on)
+    }
+
+    // `FROM ... PIVOT (agg(expr) FOR column IN (value, ...)) [AS alias]` is not supported by
+    // sqlparser either, so we cut the PIVOT clause out of the tokens and parse it on its own.
+    fn parse_pivot(&mut self) -> Result<Option<DfPivot>, ParserError> {
+        let mut tokens = vec![];
+        while let Some(token) = self.parser.next_token_no_skip() {
+            tokens.push(token.clone());
+        }
+
+        let clause = Self::find_pivot_clause(&tokens);
+        let pivot = match clause {
+            None => None,
+            Some((start, end, unpivot)) => {
+                if unpivot {
+                    return Err(ParserError::ParserError(
+                        "UNPIVOT is unsupported yet, it requires UNION ALL".to_string(),
+                    ));
+                }
+
+                let clause_tokens = tokens.drain(start..end).collect::<Vec<_>>();
+                Some(self.parse_pivot_clause(clause_tokens)?)
+            }
+        };
+
+        self.parser = Parser::new(tokens, self.dialect);
+        Ok(pivot)
+    }
+
+    // Returns the token range of the PIVOT/UNPIVOT clause and whether it is an UNPIVOT.
+    fn find_pivot_clause(tokens: &[Token]) -> Option<(usize, usize, bool)> {
+        let mut depth = 0;
+        for (index, token) in tokens.iter().enumerate() {
+            match token {
+                Token::LParen => depth += 1,
+                Token::RParen => depth -= 1,
+                Token::SemiColon if depth == 0 => return None,
+                Token::Word(word) if depth == 0 && word.quote_style.is_none() => {
+                    let unpivot = match word.value.to_uppercase().as_str() {
+                        "PIVOT" => false,
+                        "UNPIVOT" => true,
+                        _ => continue,
+                    };
+
+                    let lparen = match next_non_whitespace(tokens, index + 1) {
+                        Some(lparen) if tokens[lparen] == Token::LParen => lparen,
+                        _ => continue,
+                    };
+
+                    // Unbalanced parentheses, leave the error to the query parser.
+                    let mut end = matching_rparen(tokens, lparen)? + 1;
+                    if let Some(next) = next_non_whitespace(tokens, end) {
+                        match &tokens[next] {
+                            Token::Word(word) if word.keyword == Keyword::AS => {
+                                end = next_non_whitespace(tokens, next + 1)
+                                    .map_or(next + 1, |v| v + 1);
+                            }
+                            Token::Word(word) if word.keyword == Keyword::NoKeyword => {
+                                end = next + 1;
+                            }
+                            _ => {}
+                        }
+                    }
+
+                    return Some((index, end, unpivot));
+                }
+                _ => {}
+            }
+        }
+
+        None
+    }
+
+    fn parse_pivot_clause(&self, tokens: Vec<Token>) -> Result<DfPivot, ParserError> {
+        let mut parser = Parser::new(tokens, self.dialect);
+        parser.next_token();
+        parser.expect_token(&Token::LParen)?;
+        let aggregate = parser.parse_expr()?;
+        parser.expect_keyword(Keyword::FOR)?;
+        let column = parser.parse_identifier()?;
+        parser.expect_keyword(Keyword::IN)?;
+        parser.expect_token(&Token::LParen)?;
+        let values = parser.parse_comma_separated(Parser::parse_expr)?;
+        parser.expect_token(&Token::RParen)?;
+        parser.expect_token(&Token::RParen)?;
+
+        let alias = match parser.parse_keyword(Keyword::AS) {
+            true => Some(parser.parse_identifier()?),
+            false => parser.parse_identifier().ok(),
+        };
+
+        Ok(DfPivot {
+            aggregate,
+            column,
+            values,
+            alias,
+        })
+    }
+
+    // `FROM table TABLESAMPLE (n [PERCENT])` is not supported by sqlparser, so we cut the
+    // clause out of the tokens and return the percentage of the table to scan.
+    fn parse_table_sample(&mut self) -> Result<Option<f64>, ParserError> {
+        let mut tokens = vec![];
+        while let Some(token) = self.parser.next_token_no_skip() {
+            tokens.push(token.clone());
+        }
+
+        let mut table_sample = None;
+        if let Some((start, end)) = Self::find_table_sample_clause(&tokens) {
+            let clause_tokens = tokens.drain(start..end).collect::<Vec<_>>();
+            let mut parser = Parser::new(clause_tokens, self.dialect);
+            parser.next_token();
+            parser.expect_token(&Token::LParen)?;
+            let percent = match parser.next_token() {
+                Token::Number(value, _) => value.parse::<f64>().ok(),
+                _ => None,
+            };
+            // The PERCENT is optional, the number is always a percentage.
+            parser.parse_keyword(Keyword::PERCENT);
+            parser.expect_token(&Token::RParen)?;
+
+            match percent {
+                Some(percent) if percent > 0.0 && percent <= 100.0 => {
+                    table_sample = Some(percent);
+                }
+                _ => {
+                    return Err(ParserError::ParserError(
+                        "TABLESAMPLE expects a percentage in (0, 100]".to_string(),
+                    ));
+                }
+            }
+        }
+
+        self.parser = Parser::new(tokens, self.dialect);
+        Ok(table_sample)
+    }
+
+    // Returns the token range of the `TABLESAMPLE (...)` clause.
+    fn find_table_sample_clause(tokens: &[Token]) -> Option<(usize, usize)> {
+        let mut depth = 0;
+        for (index, token) in tokens.iter().enumerate() {
+            match token {
+                Token::LParen => depth += 1,
+                Token::RParen => depth -= 1,
+                Token::SemiColon if depth == 0 => return None,
+                Token::Word(word)
+                    if depth == 0
+                        && word.quote_style.is_none()
+                        && word.value.eq_ignore_ascii_case("TABLESAMPLE") =>
+                {
+                    // Unbalanced parentheses, leave the error to the query parser.
+                    let lparen = next_non_whitespace(tokens, index + 1)?;
+                    let end = match tokens[lparen] {
+                        Token::LParen => matching_rparen(tokens, lparen)? + 1,
+                        _ => lparen,
+                    };
+                    return Some((index, end));
+                }
+                _ => {}
+            }
+        }
+
+        None
+    }
+
+    // `SELECT * EXCLUDE (column, ...) REPLACE (expr AS column, ...)` is not supported by
+    // sqlparser, so we take the modifiers following the `*` out of the tokens here.
+    fn parse_wildcard_options(&mut self) -> Result<WildcardOptions, ParserError> {
+        let mut tokens = vec![];
+        while let Some(token) = self.parser.next_token_no_skip() {
+            tokens.push(token.clone());
+        }
+
+        let mut exclude = vec![];
+        let mut replace = vec![];
+        if let Some((start, end)) = Self::find_wildcard_options(&tokens) {
+            let option_tokens = tokens.drain(start..end).collect::<Vec<_>>();
+            let mut parser = Parser::new(option_tokens, self.dialect);
+            while let Token::Word(word) = parser.next_token() {
+                if word.value.eq_ignore_ascii_case("EXCLUDE") {
+                    match parser.consume_token(&Token::LParen) {
+                        true => {
+                            exclude.extend(parser.parse_comma_separated(Parser::parse_identifier)?);
+                            parser.expect_token(&Token::RParen)?;
+                        }
+                        false => exclude.push(parser.parse_identifier()?),
+                    }
+                } else {
+                    parser.expect_token(&Token::LParen)?;
+                    replace.extend(parser.parse_comma_separated(|parser| {
+                        let expr = parser.parse_expr()?;
+                        parser.expect_keyword(Keyword::AS)?;
+                        Ok((expr, parser.parse_identifier()?))
+                    })?);
+                    parser.expect_token(&Token::RParen)?;
+                }
+            }
+        }
+
+        self.parser = Parser::new(tokens, self.dialect);
+        Ok((exclude, replace))
+    }
+
+    // Returns the token range of the modifiers following the `*` in the projection.
+    fn find_wildcard_options(tokens: &[Token]) -> Option<(usize, usize)> {
+        let mut depth = 0;
+        let mut prev_token = None;
+        for (index, token) in tokens.iter().enumerate() {
+            match token {
+                Token::LParen => depth += 1,
+                Token::RParen => depth -= 1,
+                Token::SemiColon if depth == 0 => return None,
+                Token::Word(word) if depth == 0 && word.keyword == Keyword::FROM => return None,
+                Token::Mul if depth == 0 && is_wildcard_position(prev_token) => {
+                    let mut end = index + 1;
+                    while let Some(next) = next_non_whitespace(tokens, end) {
+                        if !is_wildcard_option(&tokens[next]) {
+                            break;
+                        }
+
+                        end = match next_non_whitespace(tokens, next + 1) {
+                            Some(lparen) if tokens[lparen] == Token::LParen => {
+                                matching_rparen(tokens, lparen)? + 1
+                            }
+                            Some(identifier) => identifier + 1,
+                            None => next + 1,
+                        };
+                    }
+
+                    if end > index + 1 {
+                        return Some((index + 1, end));
+                    }
+                }
+                _ => {}
+            }
+
+            if !matches!(token, Token::Whitespace(_)) {
+                prev_token = Some(token);
+            }
+        }
+
+        None
+    }
+}
+
+type WildcardOptions = (Vec<Ident>, Vec<(Expr, Ident)>);
+
+// The path, format and compression of `INTO OUTFILE`.
+type IntoOutfileOptions = (String, String, String);
+
+fn next_non_whitespace(tokens: &[Token], from: usize) -> Option<usize> {
+    (from..tokens.len()).find(|index| !matches!(tokens[*index], Token::Whitespace(_)))
+}
+
+fn matching_rparen(tokens: &[Token], lparen: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (index, token) in tokens.iter().enumerate().skip(lparen) {
+        match token {
+            Token::LParen => depth += 1,
+            Token::RParen => depth -= 1,
+            _ => {}
+        }
+
+        if depth == 0 {
+            return Some(index);
+        }
+    }
+
+    None
+}
+
+// A `*` following SELECT, DISTINCT or a comma is a wildcard, not a multiplication.
+fn is_wildcard_position(prev_token: Option<&Token>) -> bool {
+    match prev_token {
+        Some(Token::Comma) => true,
+        Some(Token::Word(word)) => matches!(word.keyword, Keyword::SELECT | Keyword::DISTINCT),
+        _ => false,
+    }
+}
+
+fn is_wildcard_option(token: &Token) -> bool {
+    match token {
+        Token::Word(word) if word.quote_style.is_none() => {
+            word.value.eq_ignore_ascii_case("EXCLUDE") || word.value.eq_ignore_ascii_case("REPLACE")
+        }
+        _ => false,
     }
 }
@@ -29,10 +29,11 @@ use crate::sql::DfParser;
 use crate::sql::DfStatement;
 
 impl<'a> DfParser<'a> {
-    pub(crate) fn parse_create_stage(&mut self) -> Result<DfStatement, ParserError> {
-        let if_not_exists =
-            self.parser
-                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+    pub(crate) fn parse_create_stage(
+        &mut self,
+        or_replace: bool,
+    ) -> Result<DfStatement, ParserError> {
+        let if_not_exists = self.parse_if_not_exists(or_replace)?;
         let name = self.parser.parse_literal_string()?;
 
         let mut credential_options = HashMap::default();
@@ -103,6 +104,7 @@ impl<'a> DfParser<'a> {
 
         let create = DfCreateUserStage {
             if_not_exists,
+            or_replace,
             stage_name: name,
             location,
             credential_options,
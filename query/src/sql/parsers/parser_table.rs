@@ -40,10 +40,11 @@ use crate::sql::DfStatement;
 
 impl<'a> DfParser<'a> {
     // Create table.
-    pub(crate) fn parse_create_table(&mut self) -> Result<DfStatement, ParserError> {
-        let if_not_exists =
-            self.parser
-                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+    pub(crate) fn parse_create_table(
+        &mut self,
+        or_replace: bool,
+    ) -> Result<DfStatement, ParserError> {
+        let if_not_exists = self.parse_if_not_exists(or_replace)?;
         let table_name = self.parser.parse_object_name()?;
 
         // Parse the table which we copy schema from. This is for create table like statement.
@@ -78,6 +79,7 @@ impl<'a> DfParser<'a> {
 
         let create = DfCreateTable {
             if_not_exists,
+            or_replace,
             name: table_name,
             columns,
             engine,
@@ -117,9 +119,32 @@ impl<'a> DfParser<'a> {
             };
 
             Ok(DfStatement::AlterTable(rename))
+        } else if self.consume_token("SWAP") {
+            self.parser.expect_keyword(Keyword::WITH)?;
+            let other_table_name = self.parser.parse_object_name()?;
+
+            let swap = DfAlterTable {
+                if_exists,
+                table_name,
+                action: AlterTableAction::SwapTable(other_table_name),
+            };
+
+            Ok(DfStatement::AlterTable(swap))
+        } else if self.consume_token("COMMENT") {
+            // The '=' is optional, like the COMMENT option of CREATE TABLE in MySQL.
+            self.parser.consume_token(&Token::Eq);
+            let comment = self.parser.parse_literal_string()?;
+
+            let alter = DfAlterTable {
+                if_exists,
+                table_name,
+                action: AlterTableAction::Comment(comment),
+            };
+
+            Ok(DfStatement::AlterTable(alter))
         } else {
             Err(ParserError::ParserError(String::from(
-                "Alter table only support rename for now!",
+                "Alter table only support rename, swap with and comment for now!",
             )))
         }
     }
@@ -128,6 +153,7 @@ impl<'a> DfParser<'a> {
     pub(crate) fn parse_rename_table(&mut self) -> Result<DfStatement, ParserError> {
         let mut name_map = HashMap::new();
         self.parser.expect_keyword(Keyword::TABLE)?;
+        let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
         let name = self.parser.parse_object_name()?;
         self.parser.expect_keyword(Keyword::TO)?;
         let new_name = self.parser.parse_object_name()?;
@@ -140,16 +166,21 @@ impl<'a> DfParser<'a> {
             name_map.insert(name, new_name);
         }
 
-        let rename = DfRenameTable { name_map };
+        let rename = DfRenameTable {
+            if_exists,
+            name_map,
+        };
 
         Ok(DfStatement::RenameTable(rename))
     }
 
     // Truncate table.
     pub(crate) fn parse_truncate_table(&mut self) -> Result<DfStatement, ParserError> {
+        let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
         let table_name = self.parser.parse_object_name()?;
         let purge = self.parser.parse_keyword(Keyword::PURGE);
         let statement = DfTruncateTable {
+            if_exists,
             name: table_name,
             purge,
         };
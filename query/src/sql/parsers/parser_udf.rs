@@ -107,10 +107,11 @@ impl<'a> DfParser<'a> {
         Ok(definition)
     }
 
-    pub(crate) fn parse_create_udf(&mut self) -> Result<DfStatement, ParserError> {
-        let if_not_exists =
-            self.parser
-                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+    pub(crate) fn parse_create_udf(
+        &mut self,
+        or_replace: bool,
+    ) -> Result<DfStatement, ParserError> {
+        let if_not_exists = self.parse_if_not_exists(or_replace)?;
 
         let udf_name = self.parser.parse_literal_string()?;
         self.parser.expect_keyword(Keyword::AS)?;
@@ -122,6 +123,7 @@ impl<'a> DfParser<'a> {
         let description = self.parse_udf_desc(desc_token)?;
         let create_udf = DfCreateUDF {
             if_not_exists,
+            or_replace,
             udf_name,
             parameters,
             definition,
@@ -132,6 +134,7 @@ impl<'a> DfParser<'a> {
     }
 
     pub(crate) fn parse_alter_udf(&mut self) -> Result<DfStatement, ParserError> {
+        let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
         let udf_name = self.parser.parse_literal_string()?;
         let as_token = Token::make_keyword("AS");
         self.parser.expect_token(&as_token)?;
@@ -142,6 +145,7 @@ impl<'a> DfParser<'a> {
 
         let description = self.parse_udf_desc(desc_token)?;
         let update_udf = DfAlterUDF {
+            if_exists,
             udf_name,
             parameters,
             definition,
@@ -64,6 +64,8 @@ impl<'a> DfParser<'a> {
             && self.parser.expect_token(&Token::LParen).is_ok()
             && self.parser.expect_token(&Token::RParen).is_ok();
 
+        let if_exists =
+            !if_current_user && self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
         let (username, hostname) = if !if_current_user {
             self.parse_principal_name_and_host()?
         } else {
@@ -83,6 +85,7 @@ impl<'a> DfParser<'a> {
         };
 
         let alter = DfAlterUser {
+            if_exists,
             if_current_user,
             user: UserIdentity { username, hostname },
             auth_option,
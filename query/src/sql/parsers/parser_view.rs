@@ -29,10 +29,11 @@ use crate::sql::DfStatement;
 impl<'a> DfParser<'a> {
     // Create view.
     // syntax reference to https://clickhouse.com/docs/zh/sql-reference/statements/create/view/
-    pub(crate) fn parse_create_view(&mut self) -> Result<DfStatement, ParserError> {
-        let if_not_exists =
-            self.parser
-                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+    pub(crate) fn parse_create_view(
+        &mut self,
+        or_replace: bool,
+    ) -> Result<DfStatement, ParserError> {
+        let if_not_exists = self.parse_if_not_exists(or_replace)?;
         let name = self.parser.parse_object_name()?;
 
         if self.consume_token("AS") {
@@ -41,6 +42,7 @@ impl<'a> DfParser<'a> {
             let subquery = format!("{}", native_query);
             let create = DfCreateView {
                 if_not_exists,
+                or_replace,
                 name,
                 subquery,
                 query,
@@ -64,12 +66,14 @@ impl<'a> DfParser<'a> {
     }
 
     pub(crate) fn parse_alter_view(&mut self) -> Result<DfStatement, ParserError> {
+        let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
         let name = self.parser.parse_object_name()?;
         if self.consume_token("AS") {
             let native_query = self.parser.parse_query()?;
             let query = DfQueryStatement::try_from(native_query.clone())?;
             let subquery = format!("{}", native_query);
             let alter = DfAlterView {
+                if_exists,
                 name,
                 subquery,
                 query,
@@ -0,0 +1,255 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_cache::Cache;
+use common_cache::Count;
+use common_cache::DefaultHashBuilder;
+use common_cache::LruCache;
+use common_exception::Result;
+use common_functions::scalars::FunctionFactory;
+use common_infallible::Mutex;
+use common_meta_types::TableIdent;
+use common_planners::PlanNode;
+use common_planners::PlanVisitor;
+use common_planners::ReadDataSourcePlan;
+use common_planners::SourceInfo;
+use sqlparser::dialect::GenericDialect;
+use sqlparser::tokenizer::Token;
+use sqlparser::tokenizer::Tokenizer;
+
+use crate::sessions::QueryContext;
+use crate::sql::DfStatement;
+
+/// The plan of a query, with the tables resolved while analyzing it.
+struct CachedPlan {
+    plan: PlanNode,
+    tables: Vec<(String, String, TableIdent)>,
+}
+
+/// A bounded LRU cache of the SELECT plans, keyed on the normalized query text.
+///
+/// The context functions (e.g. `now()`, `current_user()`) are bound to constants while analyzing,
+/// so the queries calling them are not cached. The key has the user and the settings the
+/// analysis depends on.
+/// Only the plans reading FUSE tables (through views or not) are cached, as the version of a FUSE
+/// table changes with its data, and a cached plan is reused only if every table resolved while
+/// analyzing it has the same id and version. The DDL statements clear the whole cache.
+pub struct PlanCache {
+    cache: Option<Mutex<LruCache<String, Arc<CachedPlan>, DefaultHashBuilder, Count>>>,
+}
+
+impl PlanCache {
+    pub fn create(capacity: u64) -> PlanCache {
+        let cache = match capacity {
+            0 => None,
+            capacity => Some(Mutex::new(LruCache::new(capacity))),
+        };
+        PlanCache { cache }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.cache.is_some()
+    }
+
+    /// The cache key of the query, None if the query can't be cached.
+    pub fn key(ctx: &QueryContext, query: &str) -> Result<Option<String>> {
+        let tokens = match Self::tokenize(query) {
+            None => return Ok(None),
+            Some(tokens) => tokens,
+        };
+        if tokens.iter().any(Self::is_context_function) {
+            return Ok(None);
+        }
+
+        let settings = ctx.get_settings();
+        let case_sensitive = settings.get_unquoted_ident_case_sensitive()? != 0;
+        let deterministic_order = settings.get_enforce_deterministic_order()? != 0;
+        Ok(Some(format!(
+            "{:?}.{:?}.{:?}.{:?}.{}.{}.{}.{}",
+            ctx.get_tenant(),
+            ctx.get_current_user()?.identity().to_string(),
+            ctx.get_current_database(),
+            settings.get_timezone()?,
+            settings.get_max_expression_depth()?,
+            case_sensitive,
+            deterministic_order,
+            Self::normalize_tokens(tokens, case_sensitive)
+        )))
+    }
+
+    /// Collapse the whitespaces and comments into one space, drop the trailing semicolons,
+    /// and lowercase the unquoted identifiers if they are case insensitive.
+    /// e.g. `SELECT  a FROM T -- all\n;` => `select a from t`
+    pub fn normalize(query: &str, case_sensitive: bool) -> Option<String> {
+        Self::tokenize(query).map(|tokens| Self::normalize_tokens(tokens, case_sensitive))
+    }
+
+    fn tokenize(query: &str) -> Option<Vec<Token>> {
+        let dialect = GenericDialect {};
+        let mut tokens = Tokenizer::new(&dialect, query).tokenize().ok()?;
+        while matches!(
+            tokens.last(),
+            Some(Token::Whitespace(_)) | Some(Token::SemiColon)
+        ) {
+            tokens.pop();
+        }
+        Some(tokens)
+    }
+
+    fn normalize_tokens(tokens: Vec<Token>, case_sensitive: bool) -> String {
+        let mut normalized = String::with_capacity(query.len());
+        for token in tokens {
+            match token {
+                Token::Whitespace(_) => {
+                    if !normalized.is_empty() && !normalized.ends_with(' ') {
+                        normalized.push(' ');
+                    }
+                }
+                Token::Word(word) if word.quote_style.is_none() && !case_sensitive => {
+                    normalized.push_str(&word.value.to_lowercase());
+                }
+                other => normalized.push_str(&other.to_string()),
+            }
+        }
+        normalized
+    }
+
+    // An unquoted word naming a context function, e.g. `now` or `current_user`.
+    fn is_context_function(token: &Token) -> bool {
+        match token {
+            Token::Word(word) if word.quote_style.is_none() => FunctionFactory::instance()
+                .get_features(&word.value)
+                .map(|features| features.is_context_func)
+                .unwrap_or(false),
+            _ => false,
+        }
+    }
+
+    /// Only the SELECT queries are cached.
+    pub fn is_cacheable(statement: &DfStatement) -> bool {
+        matches!(statement, DfStatement::Query(_))
+    }
+
+    /// The statements changing what a query is analyzed to.
+    pub fn is_invalidating(statement: &DfStatement) -> bool {
+        matches!(
+            statement,
+            DfStatement::CreateDatabase(_)
+                | DfStatement::DropDatabase(_)
+                | DfStatement::CreateTable(_)
+                | DfStatement::DropTable(_)
+                | DfStatement::AlterTable(_)
+                | DfStatement::TruncateTable(_)
+                | DfStatement::RenameTable(_)
+                | DfStatement::CreateView(_)
+                | DfStatement::AlterView(_)
+                | DfStatement::DropView(_)
+                | DfStatement::CreateUDF(_)
+                | DfStatement::DropUDF(_)
+                | DfStatement::AlterUDF(_)
+        )
+    }
+
+    pub async fn get(&self, ctx: &QueryContext, key: &str) -> Result<Option<PlanNode>> {
+        let cached = match &self.cache {
+            None => None,
+            Some(cache) => cache.lock().get(key).cloned(),
+        };
+
+        let cached = match cached {
+            None => return Ok(None),
+            Some(cached) => cached,
+        };
+
+        for (database, table, ident) in &cached.tables {
+            let unchanged = match ctx.get_table(database, table).await {
+                Ok(table) => table.get_table_info().ident == *ident,
+                Err(_) => false,
+            };
+
+            if !unchanged {
+                if let Some(cache) = &self.cache {
+                    cache.lock().pop(key);
+                }
+                return Ok(None);
+            }
+        }
+
+        Ok(Some(cached.plan.clone()))
+    }
+
+    pub fn put(&self, ctx: &QueryContext, key: String, plan: &PlanNode) {
+        let cache = match &self.cache {
+            None => return,
+            Some(cache) => cache,
+        };
+
+        let mut tables = vec![];
+        for ((database, name), table) in ctx.get_tables_refs() {
+            let engine = table.engine();
+            if !engine.eq_ignore_ascii_case("FUSE") && !engine.eq_ignore_ascii_case("VIEW") {
+                return;
+            }
+            tables.push((database, name, table.get_table_info().ident.clone()));
+        }
+
+        // The table functions and the sampled scans are planned for one query.
+        let mut scans = CacheableScans {
+            tables: &tables,
+            cacheable: true,
+        };
+        if scans.visit_plan_node(plan).is_err() {
+            return;
+        }
+
+        if scans.cacheable {
+            cache.lock().put(
+                key,
+                Arc::new(CachedPlan {
+                    plan: plan.clone(),
+                    tables,
+                }),
+            );
+        }
+    }
+
+    pub fn clear(&self) {
+        if let Some(cache) = &self.cache {
+            cache.lock().clear();
+        }
+    }
+}
+
+// Whether every scan in the plan reads one of the tables.
+struct CacheableScans<'a> {
+    tables: &'a [(String, String, TableIdent)],
+    cacheable: bool,
+}
+
+impl<'a> PlanVisitor for CacheableScans<'a> {
+    fn visit_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<()> {
+        let from_tables = match &plan.source_info {
+            SourceInfo::TableSource(info) => self
+                .tables
+                .iter()
+                .any(|(_, _, ident)| ident.table_id == info.ident.table_id),
+            SourceInfo::S3StageSource(_) => false,
+        };
+        let sampled = matches!(&plan.push_downs, Some(extras) if extras.sample.is_some());
+        self.cacheable &= from_tables && !sampled;
+        Ok(())
+    }
+}
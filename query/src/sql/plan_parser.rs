@@ -30,25 +30,67 @@ use crate::sql::statements::QueryRelation;
 use crate::sql::DfHint;
 use crate::sql::DfParser;
 use crate::sql::DfStatement;
+use crate::sql::PlanCache;
 
 pub struct PlanParser;
 
 impl PlanParser {
     pub async fn parse(ctx: Arc<QueryContext>, query: &str) -> Result<PlanNode> {
-        let (statements, _) = DfParser::parse_sql(query)?;
-        PlanParser::build_plan(statements, ctx).await
+        let max_depth = ctx.get_settings().get_max_expression_depth()?;
+        let (statements, _) = DfParser::parse_sql_with_max_depth(query, max_depth)?;
+        PlanParser::build_plan_with_cache(query, statements, ctx).await
     }
 
     pub async fn parse_with_hint(
         query: &str,
         ctx: Arc<QueryContext>,
     ) -> (Result<PlanNode>, Vec<DfHint>) {
-        match DfParser::parse_sql(query) {
+        let max_depth = match ctx.get_settings().get_max_expression_depth() {
+            Ok(max_depth) => max_depth,
+            Err(cause) => return (Err(cause), vec![]),
+        };
+
+        match DfParser::parse_sql_with_max_depth(query, max_depth) {
             Err(cause) => (Err(cause), vec![]),
-            Ok((statements, hints)) => (PlanParser::build_plan(statements, ctx).await, hints),
+            Ok((statements, hints)) => (
+                PlanParser::build_plan_with_cache(query, statements, ctx).await,
+                hints,
+            ),
         }
     }
 
+    // Reuse the plan cached for the same SELECT query, see PlanCache.
+    async fn build_plan_with_cache(
+        query: &str,
+        statements: Vec<DfStatement>,
+        ctx: Arc<QueryContext>,
+    ) -> Result<PlanNode> {
+        let plan_cache = ctx.get_plan_cache();
+        let key = match statements.as_slice() {
+            [statement] if PlanCache::is_invalidating(statement) => {
+                plan_cache.clear();
+                None
+            }
+            [statement] if plan_cache.is_enabled() && PlanCache::is_cacheable(statement) => {
+                PlanCache::key(&ctx, query)?
+            }
+            _ => None,
+        };
+
+        let key = match key {
+            None => return PlanParser::build_plan(statements, ctx).await,
+            Some(key) => key,
+        };
+
+        if let Some(plan) = plan_cache.get(&ctx, &key).await? {
+            return Ok(plan);
+        }
+
+        let plan = PlanParser::build_plan(statements, ctx.clone()).await?;
+        plan_cache.put(&ctx, key, &plan);
+        Ok(plan)
+    }
+
     pub async fn build_plan(
         statements: Vec<DfStatement>,
         ctx: Arc<QueryContext>,
@@ -79,7 +121,8 @@ impl PlanParser {
         let before_order = Self::build_before_order(group_by, data)?;
         let having = Self::build_having_plan(before_order, data)?;
         let order_by = Self::build_order_by_plan(having, data)?;
-        let projection = Self::build_projection_plan(order_by, data)?;
+        let distinct = Self::build_distinct_plan(order_by, data)?;
+        let projection = Self::build_projection_plan(distinct, data)?;
         let limit = Self::build_limit_plan(projection, data)?;
 
         Ok(PlanNode::Select(SelectPlan {
@@ -92,6 +135,7 @@ impl PlanParser {
             QueryRelation::None => Err(ErrorCode::LogicalError("Not from in select query")),
             QueryRelation::Nested(data) => Self::build_query_plan(data),
             QueryRelation::FromTable(plan) => Ok(PlanNode::ReadSource(plan.as_ref().clone())),
+            QueryRelation::FromValues(plan) => Ok(PlanNode::Values(plan.as_ref().clone())),
         }
     }
 
@@ -182,6 +226,15 @@ impl PlanParser {
         }
     }
 
+    fn build_distinct_plan(plan: PlanNode, data: &QueryAnalyzeState) -> Result<PlanNode> {
+        match data.distinct_expressions.is_empty() {
+            true => Ok(plan),
+            false => PlanBuilder::from(&plan)
+                .distinct(&data.distinct_expressions)?
+                .build(),
+        }
+    }
+
     fn build_projection_plan(plan: PlanNode, data: &QueryAnalyzeState) -> Result<PlanNode> {
         PlanBuilder::from(&plan)
             .project(&data.projection_expressions)?
@@ -12,10 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use sqlparser::ast::DataType as SQLDataType;
+use sqlparser::ast::Ident;
+
+use crate::sessions::QueryContext;
 
 pub struct SQLCommon;
 
@@ -32,12 +37,22 @@ impl SQLCommon {
             | SQLDataType::String
             | SQLDataType::Text => Ok(Vu8::to_data_type()),
 
-            SQLDataType::Decimal(_, _) => Ok(f64::to_data_type()),
+            SQLDataType::Decimal(precision, scale) => {
+                let precision = precision.unwrap_or(10) as usize;
+                let scale = scale.unwrap_or(0) as usize;
+                match precision > MAX_DECIMAL_PRECISION {
+                    // The unscaled value of a decimal is an Int64, the wider ones are kept
+                    // as Float64 like before the decimal type, e.g. DECIMAL(38, 10).
+                    true => Ok(f64::to_data_type()),
+                    false => Ok(Arc::new(DecimalType::try_create(precision, scale)?)),
+                }
+            }
             SQLDataType::Float(_) => Ok(f32::to_data_type()),
             SQLDataType::Real | SQLDataType::Double => Ok(f64::to_data_type()),
             SQLDataType::Boolean => Ok(bool::to_data_type()),
             SQLDataType::Date => Ok(Date16Type::arc()),
             SQLDataType::Timestamp => Ok(DateTime32Type::arc(None)),
+            SQLDataType::Uuid => Ok(UuidType::arc()),
 
             //custom types for databend
             // Custom(ObjectName([Ident { value: "uint8", quote_style: None }])
@@ -58,4 +73,25 @@ impl SQLCommon {
             ))),
         }
     }
+
+    /// Quoted identifiers keep their exact spelling, unquoted ones are folded to lowercase
+    /// unless `case_sensitive` is set.
+    pub fn normalize_identifier(ident: &Ident, case_sensitive: bool) -> String {
+        match ident.quote_style.is_some() || case_sensitive {
+            true => ident.value.clone(),
+            false => ident.value.to_lowercase(),
+        }
+    }
+
+    /// Resolve an identifier into a name according to the `unquoted_ident_case_sensitive` setting.
+    pub fn resolve_identifier(ctx: &QueryContext, ident: &Ident) -> Result<String> {
+        let case_sensitive = ctx.get_settings().get_unquoted_ident_case_sensitive()? != 0;
+        Ok(Self::normalize_identifier(ident, case_sensitive))
+    }
+
+    /// Quote a name with backticks, doubling the backticks inside it, so that it can be
+    /// parsed back whatever characters it contains.
+    pub fn quote_identifier(name: &str) -> String {
+        format!("`{}`", name.replace('`', "``"))
+    }
 }
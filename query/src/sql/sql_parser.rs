@@ -48,10 +48,14 @@ macro_rules! parser_err {
     };
 }
 
+/// The default nesting depth of expressions a query is allowed, see `max_expression_depth`.
+pub const DEFAULT_MAX_EXPRESSION_DEPTH: u64 = 128;
+
 /// SQL Parser
 pub struct DfParser<'a> {
     pub(crate) parser: Parser<'a>,
     pub(crate) sql: &'a str,
+    pub(crate) dialect: &'a dyn Dialect,
 }
 
 impl<'a> DfParser<'a> {
@@ -63,20 +67,39 @@ impl<'a> DfParser<'a> {
 
     /// Parse the specified tokens with dialect
     pub fn new_with_dialect(sql: &'a str, dialect: &'a dyn Dialect) -> Result<Self, ParserError> {
+        DfParser::new_with_max_depth(sql, dialect, DEFAULT_MAX_EXPRESSION_DEPTH)
+    }
+
+    /// Parse the specified tokens with dialect, rejecting expressions nested deeper than max_depth
+    pub fn new_with_max_depth(
+        sql: &'a str,
+        dialect: &'a dyn Dialect,
+        max_depth: u64,
+    ) -> Result<Self, ParserError> {
         let mut tokenizer = Tokenizer::new(dialect, sql);
-        let tokens = tokenizer.tokenize()?;
+        let tokens = unescape_quoted_identifiers(tokenizer.tokenize()?);
+        check_expression_depth(&tokens, max_depth)?;
 
         Ok(DfParser {
             sql,
+            dialect,
             parser: Parser::new(tokens, dialect),
         })
     }
 
     /// Parse a SQL statement and produce a set of statements with dialect
     pub fn parse_sql(sql: &str) -> Result<(Vec<DfStatement>, Vec<DfHint>), ErrorCode> {
+        DfParser::parse_sql_with_max_depth(sql, DEFAULT_MAX_EXPRESSION_DEPTH)
+    }
+
+    /// Parse a SQL statement, rejecting expressions nested deeper than max_depth
+    pub fn parse_sql_with_max_depth(
+        sql: &str,
+        max_depth: u64,
+    ) -> Result<(Vec<DfStatement>, Vec<DfHint>), ErrorCode> {
         let dialect = &GenericDialect {};
         let start = Instant::now();
-        let result = DfParser::parse_sql_with_dialect(sql, dialect)?;
+        let result = DfParser::parse_sql_with_dialect_and_max_depth(sql, dialect, max_depth)?;
         histogram!(super::metrics::METRIC_PARSER_USEDTIME, start.elapsed());
         Ok(result)
     }
@@ -86,7 +109,25 @@ impl<'a> DfParser<'a> {
         sql: &str,
         dialect: &dyn Dialect,
     ) -> Result<(Vec<DfStatement>, Vec<DfHint>), ParserError> {
-        let mut parser = DfParser::new_with_dialect(sql, dialect)?;
+        DfParser::parse_sql_with_dialect_and_max_depth(sql, dialect, DEFAULT_MAX_EXPRESSION_DEPTH)
+    }
+
+    fn parse_sql_with_dialect_and_max_depth(
+        sql: &str,
+        dialect: &dyn Dialect,
+        max_depth: u64,
+    ) -> Result<(Vec<DfStatement>, Vec<DfHint>), ParserError> {
+        // The literal values of an insertion are kept as text, only the head is parsed. Otherwise,
+        // e.g. the head is not `INSERT INTO table [(columns)] VALUES`, the whole SQL is parsed.
+        if let Some((head, values)) = DfParser::split_insert_values(sql) {
+            let mut parser = DfParser::new_with_max_depth(head, dialect, max_depth)?;
+            if let Ok(statement) = parser.parse_insert_values(values) {
+                let hints = DfParser::parse_hints(head, dialect, max_depth)?;
+                return Ok((vec![statement], hints));
+            }
+        }
+
+        let mut parser = DfParser::new_with_max_depth(sql, dialect, max_depth)?;
         let mut stmts = Vec::new();
 
         let mut expecting_statement_delimiter = false;
@@ -108,9 +149,18 @@ impl<'a> DfParser<'a> {
             expecting_statement_delimiter = true;
         }
 
+        let hints = DfParser::parse_hints(sql, dialect, max_depth)?;
+        Ok((stmts, hints))
+    }
+
+    fn parse_hints(
+        sql: &str,
+        dialect: &dyn Dialect,
+        max_depth: u64,
+    ) -> Result<Vec<DfHint>, ParserError> {
         let mut hints = Vec::new();
 
-        let mut parser = DfParser::new_with_dialect(sql, dialect)?;
+        let mut parser = DfParser::new_with_max_depth(sql, dialect, max_depth)?;
         loop {
             let token = parser.parser.next_token_no_skip();
             match token {
@@ -121,7 +171,7 @@ impl<'a> DfParser<'a> {
                 _ => continue,
             }
         }
-        Ok((stmts, hints))
+        Ok(hints)
     }
 
     /// Report unexpected token
@@ -183,6 +233,9 @@ impl<'a> DfParser<'a> {
                         self.parser.next_token();
                         self.parse_call()
                     }
+                    Keyword::PREPARE => self.parse_prepare(),
+                    Keyword::EXECUTE => self.parse_execute(),
+                    Keyword::DEALLOCATE => self.parse_deallocate(),
 
                     // Change to snowflake dialect for list cmd
                     Keyword::LIST => {
@@ -207,17 +260,22 @@ impl<'a> DfParser<'a> {
     }
 
     fn parse_create(&mut self) -> Result<DfStatement, ParserError> {
+        let or_replace = self.parser.parse_keywords(&[Keyword::OR, Keyword::REPLACE]);
         match self.parser.next_token() {
             Token::Word(w) => {
                 //TODO:make stage to sql parser keyword
                 match w.keyword {
-                    Keyword::TABLE => self.parse_create_table(),
+                    Keyword::TABLE => self.parse_create_table(or_replace),
+                    Keyword::FUNCTION => self.parse_create_udf(or_replace),
+                    Keyword::STAGE => self.parse_create_stage(or_replace),
+                    Keyword::VIEW => self.parse_create_view(or_replace),
+                    _ if or_replace => self.expected(
+                        "TABLE, VIEW, STAGE or FUNCTION after CREATE OR REPLACE",
+                        Token::Word(w),
+                    ),
                     Keyword::DATABASE => self.parse_create_database(),
                     Keyword::USER => self.parse_create_user(),
                     Keyword::ROLE => self.parse_create_role(),
-                    Keyword::FUNCTION => self.parse_create_udf(),
-                    Keyword::STAGE => self.parse_create_stage(),
-                    Keyword::VIEW => self.parse_create_view(),
                     _ => self.expected("create statement", Token::Word(w)),
                 }
             }
@@ -225,6 +283,17 @@ impl<'a> DfParser<'a> {
         }
     }
 
+    /// Parse the optional `IF NOT EXISTS` of a create statement, which conflicts with `OR REPLACE`.
+    pub(crate) fn parse_if_not_exists(&mut self, or_replace: bool) -> Result<bool, ParserError> {
+        let if_not_exists =
+            self.parser
+                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        if if_not_exists && or_replace {
+            return parser_err!("OR REPLACE and IF NOT EXISTS cannot be used together");
+        }
+        Ok(if_not_exists)
+    }
+
     /// This is a copy from sqlparser
     /// Parse a literal value (numbers, strings, date/time, booleans)
     #[allow(dead_code)]
@@ -275,6 +344,10 @@ impl<'a> DfParser<'a> {
     }
 
     fn parse_alter(&mut self) -> Result<DfStatement, ParserError> {
+        if self.consume_token("CLUSTER") {
+            return self.parse_alter_cluster();
+        }
+
         match self.parser.next_token() {
             Token::Word(w) => match w.keyword {
                 Keyword::USER => self.parse_alter_user(),
@@ -444,3 +517,61 @@ impl<'a> DfParser<'a> {
         }
     }
 }
+
+// The tokenizer ends a quoted identifier at the first closing quote, so an identifier with an
+// escaped (doubled) quote such as `a``b` comes out as two adjacent quoted words. Join them back
+// into one word holding the quote itself.
+fn unescape_quoted_identifiers(tokens: Vec<Token>) -> Vec<Token> {
+    let mut unescaped: Vec<Token> = Vec::with_capacity(tokens.len());
+    for token in tokens {
+        if let (Some(Token::Word(prev)), Token::Word(word)) = (unescaped.last_mut(), &token) {
+            if let (Some(prev_quote), Some(quote)) = (prev.quote_style, word.quote_style) {
+                if prev_quote == quote {
+                    prev.value.push(quote);
+                    prev.value.push_str(&word.value);
+                    continue;
+                }
+            }
+        }
+        unescaped.push(token);
+    }
+    unescaped
+}
+
+// Nested expressions are parsed recursively, and so are they analyzed and planned, thus
+// a query nested too deep would overflow the stack. Such a query is rejected by the depth
+// of its parentheses, CASE expressions and chained NOTs before being parsed.
+fn check_expression_depth(tokens: &[Token], max_depth: u64) -> Result<(), ParserError> {
+    let mut depth = 0_u64;
+    let mut chained_nots = 0_u64;
+    for token in tokens {
+        match token {
+            Token::Whitespace(_) => continue,
+            Token::LParen => depth += 1,
+            Token::RParen => depth = depth.saturating_sub(1),
+            Token::Word(word) if word.keyword == Keyword::CASE => depth += 1,
+            Token::Word(word) if word.keyword == Keyword::END => depth = depth.saturating_sub(1),
+            Token::Word(word) if word.keyword == Keyword::NOT => {
+                chained_nots += 1;
+                if depth + chained_nots > max_depth {
+                    break;
+                }
+                continue;
+            }
+            _ => {}
+        }
+
+        chained_nots = 0;
+        if depth > max_depth {
+            break;
+        }
+    }
+
+    match depth + chained_nots > max_depth {
+        true => parser_err!(format!(
+            "Expression is too complex, it is nested deeper than max_expression_depth({})",
+            max_depth
+        )),
+        false => Ok(()),
+    }
+}
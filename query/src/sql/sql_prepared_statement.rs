@@ -0,0 +1,255 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Range;
+
+use common_datavalues::DataValue;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::sql::DfParser;
+use crate::sql::DfStatement;
+
+/// The most parameters of a prepared statement, the limit of the MySQL protocol.
+const MAX_PLACEHOLDERS: usize = 65535;
+
+#[derive(Clone, Debug)]
+struct Placeholder {
+    range: Range<usize>,
+    // Index of the parameter bound to this placeholder.
+    index: usize,
+}
+
+/// A statement with `?` or `$n` placeholders, bound to the parameters each time it is executed.
+///
+/// The statement is re-planned on every execution: a plan pins the partitions it reads, so a
+/// cached one would miss the data written after the statement was prepared.
+#[derive(Clone, Debug)]
+pub struct PreparedStatement {
+    query: String,
+    placeholders: Vec<Placeholder>,
+    params: usize,
+}
+
+impl PreparedStatement {
+    pub fn try_create(query: &str) -> Result<PreparedStatement> {
+        let placeholders = Self::scan_placeholders(query)?;
+        let params = placeholders.iter().map(|v| v.index + 1).max().unwrap_or(0);
+        let statement = PreparedStatement {
+            query: query.to_string(),
+            placeholders,
+            params,
+        };
+
+        // The parameters are not known yet, check the syntax with NULLs in their place.
+        let (statements, _) =
+            DfParser::parse_sql(&statement.substitute(|_| Ok(String::from("NULL")))?)?;
+        match statements.as_slice() {
+            [DfStatement::Prepare(_)]
+            | [DfStatement::Execute(_)]
+            | [DfStatement::Deallocate(_)] => Err(ErrorCode::SyntaxException(
+                "Cannot prepare a prepared statement command",
+            )),
+            [_] => Ok(statement),
+            _ => Err(ErrorCode::SyntaxException(
+                "Prepared statement must be a single statement",
+            )),
+        }
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    pub fn params_count(&self) -> usize {
+        self.params
+    }
+
+    /// Substitute the placeholders with the parameters as SQL literals.
+    pub fn bind(&self, params: &[DataValue]) -> Result<String> {
+        if params.len() != self.params {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "Prepared statement expects {} parameters, but got {}",
+                self.params,
+                params.len()
+            )));
+        }
+
+        self.substitute(|index| Self::literal(&params[index]))
+    }
+
+    /// Substitute every placeholder with the literal of its parameter index.
+    fn substitute(&self, literal: impl Fn(usize) -> Result<String>) -> Result<String> {
+        let mut query = String::with_capacity(self.query.len());
+        let mut last = 0;
+        for placeholder in &self.placeholders {
+            query.push_str(&self.query[last..placeholder.range.start]);
+            query.push_str(&literal(placeholder.index)?);
+            last = placeholder.range.end;
+        }
+
+        query.push_str(&self.query[last..]);
+        Ok(query)
+    }
+
+    fn literal(value: &DataValue) -> Result<String> {
+        match value {
+            DataValue::Null => Ok(String::from("NULL")),
+            DataValue::Boolean(v) => Ok(v.to_string()),
+            // Parenthesized, `a-?` must not become the comment `a--1`.
+            DataValue::Int64(v) if *v < 0 => Ok(format!("({})", v)),
+            DataValue::Int64(v) => Ok(v.to_string()),
+            DataValue::UInt64(v) => Ok(v.to_string()),
+            DataValue::Float64(v) if !v.is_finite() => Err(ErrorCode::BadArguments(format!(
+                "Cannot bind {} to a prepared statement",
+                v
+            ))),
+            DataValue::Float64(v) if *v < 0.0 => Ok(format!("({})", v)),
+            DataValue::Float64(v) => Ok(v.to_string()),
+            DataValue::String(v) => match std::str::from_utf8(v) {
+                Ok(v) => Ok(Self::quote(v)),
+                Err(_) => Ok(format!(
+                    "unhex('{}')",
+                    v.iter().map(|b| format!("{:02x}", b)).collect::<String>()
+                )),
+            },
+            other => Err(ErrorCode::BadArguments(format!(
+                "Cannot bind {:?} to a prepared statement",
+                other
+            ))),
+        }
+    }
+
+    fn quote(value: &str) -> String {
+        let mut quoted = String::with_capacity(value.len() + 2);
+        quoted.push('\'');
+        for c in value.chars() {
+            match c {
+                '\'' => quoted.push_str("\\'"),
+                '\\' => quoted.push_str("\\\\"),
+                '\n' => quoted.push_str("\\n"),
+                '\r' => quoted.push_str("\\r"),
+                '\t' => quoted.push_str("\\t"),
+                '\0' => quoted.push_str("\\0"),
+                c => quoted.push(c),
+            }
+        }
+        quoted.push('\'');
+        quoted
+    }
+
+    // Find the `?` and `$n` outside of quoted strings, quoted identifiers and comments.
+    fn scan_placeholders(query: &str) -> Result<Vec<Placeholder>> {
+        let bytes = query.as_bytes();
+        let mut placeholders = vec![];
+        let mut positional = 0;
+        let mut numbered = false;
+
+        let mut i = 0;
+        while i < bytes.len() {
+            match bytes[i] {
+                quote @ (b'\'' | b'"' | b'`') => {
+                    i = Self::skip_quoted(bytes, i, quote);
+                    continue;
+                }
+                b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                    while i < bytes.len() && bytes[i] != b'\n' {
+                        i += 1;
+                    }
+                    continue;
+                }
+                b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                    i = match query[i + 2..].find("*/") {
+                        Some(end) => i + 2 + end + 2,
+                        None => bytes.len(),
+                    };
+                    continue;
+                }
+                b'?' => {
+                    if positional >= MAX_PLACEHOLDERS {
+                        return Err(ErrorCode::SyntaxException(format!(
+                            "Too many placeholders, a prepared statement has at most {} parameters",
+                            MAX_PLACEHOLDERS
+                        )));
+                    }
+
+                    placeholders.push(Placeholder {
+                        range: i..i + 1,
+                        index: positional,
+                    });
+                    positional += 1;
+                }
+                b'$' if bytes.get(i + 1).map_or(false, u8::is_ascii_digit)
+                    && (i == 0 || !Self::is_identifier_byte(bytes[i - 1])) =>
+                {
+                    let mut end = i + 1;
+                    while end < bytes.len() && bytes[end].is_ascii_digit() {
+                        end += 1;
+                    }
+
+                    let number = match query[i + 1..end].parse::<usize>() {
+                        Ok(number) if (1..=MAX_PLACEHOLDERS).contains(&number) => number,
+                        _ => {
+                            return Err(ErrorCode::SyntaxException(format!(
+                                "Invalid placeholder {}, placeholders are numbered from $1 to ${}",
+                                &query[i..end],
+                                MAX_PLACEHOLDERS
+                            )));
+                        }
+                    };
+
+                    placeholders.push(Placeholder {
+                        range: i..end,
+                        index: number - 1,
+                    });
+                    numbered = true;
+                    i = end;
+                    continue;
+                }
+                _ => {}
+            }
+
+            i += 1;
+        }
+
+        if numbered && positional != 0 {
+            return Err(ErrorCode::SyntaxException(
+                "Cannot mix ? and $n placeholders in a prepared statement",
+            ));
+        }
+
+        Ok(placeholders)
+    }
+
+    fn skip_quoted(bytes: &[u8], start: usize, quote: u8) -> usize {
+        let mut i = start + 1;
+        while i < bytes.len() {
+            if bytes[i] == b'\\' && quote != b'`' {
+                i += 2;
+            } else if bytes[i] == quote && bytes.get(i + 1) == Some(&quote) {
+                i += 2;
+            } else if bytes[i] == quote {
+                return i + 1;
+            } else {
+                i += 1;
+            }
+        }
+
+        bytes.len()
+    }
+
+    fn is_identifier_byte(byte: u8) -> bool {
+        byte.is_ascii_alphanumeric() || byte == b'_'
+    }
+}
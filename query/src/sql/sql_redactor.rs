@@ -0,0 +1,78 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use sqlparser::dialect::GenericDialect;
+use sqlparser::tokenizer::Token;
+use sqlparser::tokenizer::Tokenizer;
+
+const PLACEHOLDER: &str = "?";
+
+// Quoted strings and standalone numbers, the values a cast/parse error may carry.
+static MESSAGE_VALUE_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"'(?:[^'\\]|\\.)*'|"(?:[^"\\]|\\.)*"|\b\d+(?:\.\d+)?(?:[eE][+-]?\d+)?\b"#).unwrap()
+});
+
+/// Scrub user data from query text and error messages before they are logged or persisted.
+pub struct SQLRedactor;
+
+impl SQLRedactor {
+    /// Replace every literal in the query with a placeholder, the rest of the query is kept as is.
+    /// e.g. `select * from t where name = 'alice' limit 10` => `select * from t where name = ? limit ?`
+    pub fn redact_query(query: &str) -> String {
+        let dialect = GenericDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, query);
+        match tokenizer.tokenize() {
+            Ok(tokens) => tokens
+                .iter()
+                .map(|token| match token {
+                    Token::Number(_, _)
+                    | Token::SingleQuotedString(_)
+                    | Token::NationalStringLiteral(_)
+                    | Token::HexStringLiteral(_) => PLACEHOLDER.to_string(),
+                    other => other.to_string(),
+                })
+                .collect(),
+            // The query cannot be tokenized, we know nothing about where the literals are.
+            Err(_) => Self::redact_message(query),
+        }
+    }
+
+    /// Replace the quoted strings and numbers in the message with placeholders.
+    pub fn redact_message(message: &str) -> String {
+        MESSAGE_VALUE_PATTERN
+            .replace_all(message, PLACEHOLDER)
+            .into_owned()
+    }
+
+    /// Scrub the values from the message of cast/parse failures, other errors are returned as is.
+    pub fn redact_error(error: ErrorCode) -> ErrorCode {
+        let code = error.code();
+        if code != ErrorCode::BadDataValueTypeCode()
+            && code != ErrorCode::BadBytesCode()
+            && code != ErrorCode::SyntaxExceptionCode()
+        {
+            return error;
+        }
+
+        ErrorCode::create(
+            code,
+            Self::redact_message(&error.message()),
+            None,
+            error.backtrace(),
+        )
+    }
+}
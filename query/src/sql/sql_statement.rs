@@ -38,20 +38,25 @@ use crate::sql::statements::DfCreateTable;
 use crate::sql::statements::DfCreateUDF;
 use crate::sql::statements::DfCreateUser;
 use crate::sql::statements::DfCreateView;
+use crate::sql::statements::DfDeallocate;
 use crate::sql::statements::DfDescribeTable;
+use crate::sql::statements::DfDrainNode;
 use crate::sql::statements::DfDropDatabase;
 use crate::sql::statements::DfDropRole;
 use crate::sql::statements::DfDropTable;
 use crate::sql::statements::DfDropUDF;
 use crate::sql::statements::DfDropUser;
+use crate::sql::statements::DfExecute;
 use crate::sql::statements::DfExplain;
 use crate::sql::statements::DfGrantPrivilegeStatement;
 use crate::sql::statements::DfInsertStatement;
 use crate::sql::statements::DfKillStatement;
 use crate::sql::statements::DfOptimizeTable;
+use crate::sql::statements::DfPrepare;
 use crate::sql::statements::DfQueryStatement;
 use crate::sql::statements::DfRenameTable;
 use crate::sql::statements::DfRevokePrivilegeStatement;
+use crate::sql::statements::DfSelectIntoOutfile;
 use crate::sql::statements::DfSetVariable;
 use crate::sql::statements::DfShowCreateDatabase;
 use crate::sql::statements::DfShowCreateTable;
@@ -73,6 +78,7 @@ use crate::sql::statements::DfUseDatabase;
 pub enum DfStatement {
     // ANSI SQL AST node
     Query(Box<DfQueryStatement>),
+    SelectIntoOutfile(DfSelectIntoOutfile),
     Explain(DfExplain),
 
     // Databases.
@@ -114,6 +120,9 @@ pub enum DfStatement {
     // Kill
     KillStatement(DfKillStatement),
 
+    // Cluster
+    DrainNode(DfDrainNode),
+
     // Set
     SetVariable(DfSetVariable),
 
@@ -159,6 +168,11 @@ pub enum DfStatement {
 
     // Engine
     ShowEngines(DfShowEngines),
+
+    // Prepared statement
+    Prepare(DfPrepare),
+    Execute(DfExecute),
+    Deallocate(DfDeallocate),
 }
 
 /// Comment hints from SQL.
@@ -241,7 +241,7 @@ impl ExpressionAnalyzer {
     }
 
     fn analyze_identifier(&self, ident: &Ident, arguments: &mut Vec<Expression>) -> Result<()> {
-        let column_name = ident.clone().value;
+        let column_name = SQLCommon::resolve_identifier(&self.context, ident)?;
         arguments.push(Expression::Column(column_name));
         Ok(())
     }
@@ -250,7 +250,7 @@ impl ExpressionAnalyzer {
         let mut names = Vec::with_capacity(idents.len());
 
         for ident in idents {
-            names.push(ident.clone().value);
+            names.push(SQLCommon::resolve_identifier(&self.context, ident)?);
         }
 
         arguments.push(Expression::QualifiedColumn(names));
@@ -273,7 +273,10 @@ impl ExpressionAnalyzer {
         let subquery_context = QueryContext::create_from(query_context.clone());
 
         let analyze_subquery = statement.analyze(subquery_context);
-        if let AnalyzedResult::SelectQuery(analyze_data) = analyze_subquery.await? {
+        let analyzed = analyze_subquery
+            .await
+            .map_err(Self::correlated_subquery_error)?;
+        if let AnalyzedResult::SelectQuery(analyze_data) = analyzed {
             let subquery_plan = PlanParser::build_query_plan(&analyze_data)?;
             return Ok(Expression::Subquery {
                 name: query_context.get_subquery_name(&subquery_plan),
@@ -298,7 +301,10 @@ impl ExpressionAnalyzer {
         let subquery_context = QueryContext::create_from(query_context.clone());
 
         let analyze_subquery = statement.analyze(subquery_context);
-        if let AnalyzedResult::SelectQuery(analyze_data) = analyze_subquery.await? {
+        let analyzed = analyze_subquery
+            .await
+            .map_err(Self::correlated_subquery_error)?;
+        if let AnalyzedResult::SelectQuery(analyze_data) = analyzed {
             let subquery_plan = PlanParser::build_query_plan(&analyze_data)?;
             args.push(Expression::ScalarSubquery {
                 name: query_context.get_subquery_name(&subquery_plan),
@@ -314,6 +320,20 @@ impl ExpressionAnalyzer {
         )))
     }
 
+    // A subquery is analyzed on its own, so a reference to a column of the outer query is
+    // unknown there. Rewriting correlated IN/EXISTS/scalar subqueries into semi, anti or
+    // left joins needs SELECT JOIN, which is unimplemented yet.
+    fn correlated_subquery_error(cause: ErrorCode) -> ErrorCode {
+        const HINT: &str =
+            " (correlated subqueries are unimplemented, they need SELECT JOIN to be decorrelated)";
+
+        // Nested subqueries pass the error through every level, hint only once.
+        match cause.code() == ErrorCode::UnknownColumnCode() && !cause.message().ends_with(HINT) {
+            true => cause.add_message_back(HINT),
+            false => cause,
+        }
+    }
+
     fn analyze_wildcard(&self, arguments: &mut Vec<Expression>) -> Result<()> {
         arguments.push(Expression::Wildcard);
         Ok(())
@@ -327,7 +347,7 @@ impl ExpressionAnalyzer {
             Some(inner_expr) => {
                 args.push(Expression::Cast {
                     expr: Box::new(inner_expr),
-                    data_type: data_type.clone(),
+                    data_type: self.with_session_timezone(data_type)?,
                     is_nullable: false,
                 });
                 Ok(())
@@ -335,6 +355,35 @@ impl ExpressionAnalyzer {
         }
     }
 
+    /// Timestamps without an explicit timezone are casted in the session timezone.
+    fn with_session_timezone(&self, data_type: &DataTypePtr) -> Result<DataTypePtr> {
+        let tz = match data_type.data_type_id() {
+            TypeID::DateTime32 | TypeID::DateTime64 => {
+                self.context.get_settings().get_timezone()?
+            }
+            _ => return Ok(data_type.clone()),
+        };
+
+        if tz == "UTC" {
+            return Ok(data_type.clone());
+        }
+
+        if let Some(t) = data_type.as_any().downcast_ref::<DateTime32Type>() {
+            if t.tz().is_none() {
+                return Ok(Arc::new(DateTime32Type::try_create(Some(tz))?));
+            }
+        }
+        if let Some(t) = data_type.as_any().downcast_ref::<DateTime64Type>() {
+            if t.tz().is_none() {
+                return Ok(Arc::new(DateTime64Type::try_create(
+                    t.precision(),
+                    Some(tz),
+                )?));
+            }
+        }
+        Ok(data_type.clone())
+    }
+
     fn analyze_between(&self, negated: bool, args: &mut Vec<Expression>) -> Result<()> {
         if args.len() < 3 {
             return Err(ErrorCode::SyntaxException(
@@ -342,23 +391,16 @@ impl ExpressionAnalyzer {
             ));
         }
 
-        let s_args = args.split_off(args.len() - 3);
-        let expression = s_args[0].clone();
-        let low_expression = s_args[1].clone();
-        let high_expression = s_args[2].clone();
-
-        match negated {
-            false => args.push(
-                expression
-                    .gt_eq(low_expression)
-                    .and(expression.lt_eq(high_expression)),
-            ),
-            true => args.push(
-                expression
-                    .lt(low_expression)
-                    .or(expression.gt(high_expression)),
-            ),
+        // both bounds are compared in one pass by the function
+        let op = match negated {
+            false => "between",
+            true => "not between",
         };
+        let s_args = args.split_off(args.len() - 3);
+        args.push(Expression::ScalarFunction {
+            op: op.to_string(),
+            args: s_args,
+        });
 
         Ok(())
     }
@@ -476,6 +518,16 @@ impl ExprRPNBuilder {
             Expr::BinaryOp { op, .. } => {
                 self.rpn.push(ExprRPNItem::binary_operator(op.to_string()));
             }
+            Expr::IsDistinctFrom(..) => {
+                self.rpn.push(ExprRPNItem::binary_operator(String::from(
+                    "is distinct from",
+                )));
+            }
+            Expr::IsNotDistinctFrom(..) => {
+                self.rpn.push(ExprRPNItem::binary_operator(String::from(
+                    "is not distinct from",
+                )));
+            }
             Expr::Exists(subquery) => {
                 self.rpn.push(ExprRPNItem::Exists(subquery.clone()));
             }
@@ -23,6 +23,7 @@ use common_planners::ExplainType;
 use common_planners::Expression;
 use common_planners::PlanNode;
 use common_planners::ReadDataSourcePlan;
+use common_planners::ValuesPlan;
 
 use crate::sessions::QueryContext;
 use crate::sql::DfStatement;
@@ -39,6 +40,7 @@ pub enum QueryRelation {
     None,
     FromTable(Box<ReadDataSourcePlan>),
     Nested(Box<QueryAnalyzeState>),
+    FromValues(Box<ValuesPlan>),
 }
 
 #[derive(Clone)]
@@ -46,6 +48,8 @@ pub struct QueryAnalyzeState {
     pub filter: Option<Expression>,
     pub having: Option<Expression>,
     pub order_by_expressions: Vec<Expression>,
+    // the keys of SELECT DISTINCT, empty if the query is not distinct
+    pub distinct_expressions: Vec<Expression>,
     // before order or before projection expression plan
     pub expressions: Vec<Expression>,
     pub projection_expressions: Vec<Expression>,
@@ -81,6 +85,7 @@ impl Default for QueryAnalyzeState {
             filter: None,
             having: None,
             order_by_expressions: vec![],
+            distinct_expressions: vec![],
             expressions: vec![],
             projection_expressions: vec![],
             group_by_expressions: vec![],
@@ -129,6 +134,10 @@ impl Debug for QueryAnalyzeState {
             debug_struct.field("order_by", &self.order_by_expressions);
         }
 
+        if !self.distinct_expressions.is_empty() {
+            debug_struct.field("distinct", &self.distinct_expressions);
+        }
+
         if !self.projection_expressions.is_empty() {
             debug_struct.field("projection", &self.projection_expressions);
         }
@@ -147,6 +156,7 @@ impl AnalyzableStatement for DfStatement {
     async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
         match self {
             DfStatement::Query(v) => v.analyze(ctx).await,
+            DfStatement::SelectIntoOutfile(v) => v.analyze(ctx).await,
             DfStatement::Explain(v) => v.analyze(ctx).await,
             DfStatement::ShowDatabases(v) => v.analyze(ctx).await,
             DfStatement::ShowCreateDatabase(v) => v.analyze(ctx).await,
@@ -168,6 +178,7 @@ impl AnalyzableStatement for DfStatement {
             DfStatement::ShowMetrics(v) => v.analyze(ctx).await,
             DfStatement::ShowGrants(v) => v.analyze(ctx).await,
             DfStatement::KillStatement(v) => v.analyze(ctx).await,
+            DfStatement::DrainNode(v) => v.analyze(ctx).await,
             DfStatement::InsertQuery(v) => v.analyze(ctx).await,
             DfStatement::SetVariable(v) => v.analyze(ctx).await,
             DfStatement::CreateUser(v) => v.analyze(ctx).await,
@@ -194,6 +205,9 @@ impl AnalyzableStatement for DfStatement {
             DfStatement::CreateView(v) => v.analyze(ctx).await,
             DfStatement::AlterView(v) => v.analyze(ctx).await,
             DfStatement::DropView(v) => v.analyze(ctx).await,
+            DfStatement::Prepare(v) => v.analyze(ctx).await,
+            DfStatement::Execute(v) => v.analyze(ctx).await,
+            DfStatement::Deallocate(v) => v.analyze(ctx).await,
         }
     }
 }
@@ -27,7 +27,7 @@ impl ValueExprAnalyzer {
             Value::Null => Self::analyze_null_value(),
             Value::Boolean(value) => Self::analyze_bool_value(value),
             Value::Number(value, _) => Self::analyze_number_value(value, None),
-            Value::HexStringLiteral(value) => Self::analyze_number_value(value, Some(16)),
+            Value::HexStringLiteral(value) => Self::analyze_hex_value(value),
             Value::SingleQuotedString(value) => Self::analyze_string_value(value),
             Value::Interval {
                 leading_precision: Some(_),
@@ -71,6 +71,17 @@ impl ValueExprAnalyzer {
         Ok(Expression::create_literal(data_value))
     }
 
+    // x'ABCD' is a binary literal, its value is not required to be valid UTF-8.
+    fn analyze_hex_value(value: &str) -> Result<Expression> {
+        let bytes = hex::decode(value).map_err(|e| {
+            ErrorCode::SyntaxException(format!("Invalid hex literal x'{}': {}", value, e))
+        })?;
+        Ok(Expression::create_literal_with_type(
+            DataValue::String(bytes),
+            BinaryType::arc(),
+        ))
+    }
+
     fn unsupported_interval(interval: &Value) -> Result<Expression> {
         //TODO: support parsing literal interval like '1 hour'
         Err(ErrorCode::SyntaxException(format!(
@@ -31,8 +31,10 @@ mod statement_create_udf;
 mod statement_create_user;
 mod statement_create_user_stage;
 mod statement_create_view;
+mod statement_deallocate;
 mod statement_describe_table;
 mod statement_describe_user_stage;
+mod statement_drain_node;
 mod statement_drop_database;
 mod statement_drop_role;
 mod statement_drop_table;
@@ -40,16 +42,21 @@ mod statement_drop_udf;
 mod statement_drop_user;
 mod statement_drop_user_stage;
 mod statement_drop_view;
+mod statement_execute;
 mod statement_explain;
 mod statement_grant;
 mod statement_insert;
 mod statement_kill;
 mod statement_list;
 mod statement_optimize_table;
+mod statement_prepare;
 mod statement_rename_table;
 mod statement_revoke;
 mod statement_select;
 mod statement_select_convert;
+mod statement_select_into_outfile;
+mod statement_select_pivot;
+mod statement_select_wildcard;
 mod statement_set_variable;
 mod statement_show_create_database;
 mod statement_show_create_table;
@@ -89,8 +96,10 @@ pub use statement_create_user::DfCreateUser;
 pub use statement_create_user::DfUserWithOption;
 pub use statement_create_user_stage::DfCreateUserStage;
 pub use statement_create_view::DfCreateView;
+pub use statement_deallocate::DfDeallocate;
 pub use statement_describe_table::DfDescribeTable;
 pub use statement_describe_user_stage::DfDescribeUserStage;
+pub use statement_drain_node::DfDrainNode;
 pub use statement_drop_database::DfDropDatabase;
 pub use statement_drop_role::DfDropRole;
 pub use statement_drop_table::DfDropTable;
@@ -98,6 +107,7 @@ pub use statement_drop_udf::DfDropUDF;
 pub use statement_drop_user::DfDropUser;
 pub use statement_drop_user_stage::DfDropUserStage;
 pub use statement_drop_view::DfDropView;
+pub use statement_execute::DfExecute;
 pub use statement_explain::DfExplain;
 pub use statement_grant::DfGrantObject;
 pub use statement_grant::DfGrantPrivilegeStatement;
@@ -106,10 +116,13 @@ pub use statement_insert::DfInsertStatement;
 pub use statement_kill::DfKillStatement;
 pub use statement_list::DfList;
 pub use statement_optimize_table::DfOptimizeTable;
+pub use statement_prepare::DfPrepare;
 pub use statement_rename_table::DfRenameTable;
 pub use statement_revoke::DfRevokePrivilegeStatement;
 pub use statement_revoke::DfRevokeRoleStatement;
+pub use statement_select::DfPivot;
 pub use statement_select::DfQueryStatement;
+pub use statement_select_into_outfile::DfSelectIntoOutfile;
 pub use statement_set_variable::DfSetVariable;
 pub use statement_show_create_database::DfShowCreateDatabase;
 pub use statement_show_create_table::DfShowCreateTable;
@@ -25,9 +25,12 @@ pub struct QueryASTIR {
     pub having_predicate: Option<Expression>,
     pub aggregate_expressions: Vec<Expression>,
     pub order_by_expressions: Vec<Expression>,
+    pub distinct: bool,
+    pub distinct_on_expressions: Vec<Expression>,
     pub projection_expressions: Vec<Expression>,
     pub limit: Option<usize>,
     pub offset: Option<usize>,
+    pub sample: Option<f64>,
 }
 
 pub trait QueryASTIRVisitor<Data> {
@@ -42,6 +45,7 @@ pub trait QueryASTIRVisitor<Data> {
 
         Self::visit_group_by(&mut ir.group_by_expressions, data)?;
         Self::visit_order_by(&mut ir.order_by_expressions, data)?;
+        Self::visit_distinct_on(&mut ir.distinct_on_expressions, data)?;
         Self::visit_aggregates(&mut ir.aggregate_expressions, data)?;
         Self::visit_projection(&mut ir.projection_expressions, data)?;
         Ok(())
@@ -116,6 +120,14 @@ pub trait QueryASTIRVisitor<Data> {
         Ok(())
     }
 
+    fn visit_distinct_on(exprs: &mut Vec<Expression>, data: &mut Data) -> Result<()> {
+        for expr in exprs {
+            Self::visit_recursive_expr(expr, data)?;
+        }
+
+        Ok(())
+    }
+
     fn visit_projection(exprs: &mut Vec<Expression>, data: &mut Data) -> Result<()> {
         for expr in exprs {
             Self::visit_recursive_expr(expr, data)?;
@@ -149,10 +161,20 @@ impl Debug for QueryASTIR {
             debug_struct.field("order by", &self.order_by_expressions);
         }
 
+        if !self.distinct_on_expressions.is_empty() {
+            debug_struct.field("distinct on", &self.distinct_on_expressions);
+        } else if self.distinct {
+            debug_struct.field("distinct", &self.distinct);
+        }
+
         if !self.projection_expressions.is_empty() {
             debug_struct.field("projection", &self.projection_expressions);
         }
 
+        if let Some(sample) = &self.sample {
+            debug_struct.field("sample", sample);
+        }
+
         debug_struct.finish()
     }
 }
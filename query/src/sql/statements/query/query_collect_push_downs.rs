@@ -12,9 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::collections::HashSet;
 
 use common_exception::Result;
+use common_planners::lit;
 use common_planners::Expression;
 use common_planners::Extras;
 
@@ -27,6 +30,7 @@ pub struct QueryCollectPushDowns {
     require_columns: HashSet<String>,
     require_filters: Vec<Expression>,
     aggregating: bool,
+    sampling: bool,
 }
 
 /// Collect the query need to push downs parts .
@@ -45,6 +49,8 @@ impl QueryASTIRVisitor<QueryCollectPushDowns> for QueryCollectPushDowns {
 
     fn visit_filter(predicate: &mut Expression, data: &mut QueryCollectPushDowns) -> Result<()> {
         data.require_filters = vec![predicate.clone()];
+        data.require_filters
+            .extend(Self::infer_equal_filters(predicate));
         Self::visit_recursive_expr(predicate, data)
     }
 }
@@ -59,21 +65,32 @@ impl QueryCollectPushDowns {
             require_columns: HashSet::new(),
             require_filters: vec![],
             aggregating,
+            sampling: ir.sample.is_some(),
         };
         QueryCollectPushDowns::visit(ir, &mut push_downs_data)?;
         push_downs_data.collect_push_downs(ir, schema)
     }
 
-    fn collect_push_downs(mut self, ir: &QueryASTIR, schema: &mut JoinedSchema) -> Result<()> {
+    fn collect_push_downs(mut self, ir: &mut QueryASTIR, schema: &mut JoinedSchema) -> Result<()> {
+        let mut sampled_by_tables = true;
         for index in 0..schema.get_tables_desc().len() {
             let table_desc = &schema.get_tables_desc()[index];
             let projection = self.collect_table_require_columns(table_desc);
 
+            let sample = match table_desc {
+                JoinedTableDesc::Table { table, .. } if table.support_sampling() => ir.sample,
+                _ => {
+                    sampled_by_tables = false;
+                    None
+                }
+            };
+
             let mut limit = None;
             let mut order_by = vec![];
             if schema.get_tables_desc().len() == 1
                 && ir.group_by_expressions.is_empty()
                 && ir.aggregate_expressions.is_empty()
+                && !ir.distinct
             {
                 limit = ir.limit.map(|c| c + ir.offset.unwrap_or(0));
                 order_by = ir.order_by_expressions.clone();
@@ -84,12 +101,89 @@ impl QueryCollectPushDowns {
                 filters: self.require_filters.clone(),
                 limit,
                 order_by,
+                sample,
             });
         }
 
+        if let Some(percent) = ir.sample {
+            if !sampled_by_tables {
+                Self::sample_rows(ir, percent);
+            }
+        }
+
         Ok(())
     }
 
+    // Infer `a = 1` from `a = b AND b = 1`, so that the scan can be pruned by `a` too.
+    // The inferred filters are implied by the predicate, they are only pushed down.
+    pub fn infer_equal_filters(predicate: &Expression) -> Vec<Expression> {
+        let mut conjunctions = vec![];
+        Self::split_conjunctions(predicate, &mut conjunctions);
+
+        let mut classes: Vec<BTreeSet<String>> = vec![];
+        let mut constants: BTreeMap<String, Expression> = BTreeMap::new();
+        for conjunction in conjunctions {
+            if let Expression::BinaryExpression { op, left, right } = conjunction {
+                if op != "=" {
+                    continue;
+                }
+
+                match (left.as_ref(), right.as_ref()) {
+                    (Expression::Column(left), Expression::Column(right)) => {
+                        let mut class = BTreeSet::from([left.clone(), right.clone()]);
+                        classes.retain(|other| {
+                            match other.contains(left) || other.contains(right) {
+                                true => {
+                                    class.extend(other.iter().cloned());
+                                    false
+                                }
+                                false => true,
+                            }
+                        });
+                        classes.push(class);
+                    }
+                    (Expression::Column(column), literal @ Expression::Literal { .. })
+                    | (literal @ Expression::Literal { .. }, Expression::Column(column)) => {
+                        constants
+                            .entry(column.clone())
+                            .or_insert_with(|| literal.clone());
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let mut filters = vec![];
+        for class in classes {
+            let constant = class.iter().find_map(|column| constants.get(column));
+            if let Some(constant) = constant {
+                for column in class.iter().filter(|c| !constants.contains_key(*c)) {
+                    filters.push(Expression::Column(column.clone()).eq(constant.clone()));
+                }
+            }
+        }
+        filters
+    }
+
+    fn split_conjunctions<'a>(expr: &'a Expression, conjunctions: &mut Vec<&'a Expression>) {
+        match expr {
+            Expression::BinaryExpression { op, left, right } if op.eq_ignore_ascii_case("and") => {
+                Self::split_conjunctions(left, conjunctions);
+                Self::split_conjunctions(right, conjunctions);
+            }
+            _ => conjunctions.push(expr),
+        }
+    }
+
+    // The tables which can not sample their partitions are sampled by a filter on the rows read.
+    fn sample_rows(ir: &mut QueryASTIR, percent: f64) {
+        let predicate = Expression::create_scalar_function("rand", vec![]).lt(lit(percent / 100.0));
+        ir.filter_predicate = Some(match ir.filter_predicate.take() {
+            None => predicate,
+            Some(filter) => filter.and(predicate),
+        });
+    }
+
     fn collect_table_require_columns(&mut self, table_desc: &JoinedTableDesc) -> Vec<usize> {
         let has_exact_total_row_count = if let JoinedTableDesc::Table { table, .. } = table_desc {
             table.has_exact_total_row_count()
@@ -99,11 +193,12 @@ impl QueryCollectPushDowns {
         };
 
         match self.require_columns.is_empty() {
-            true if self.aggregating && has_exact_total_row_count => {
+            true if self.aggregating && has_exact_total_row_count && !self.sampling => {
                 // This query
                 // - has aggregation expression in "projection"
                 // - requires no columns
                 // - DO have the exact number of row count
+                // - is not sampled
                 // thus, no need to collect the smallest column
                 vec![]
             }
@@ -22,15 +22,16 @@ use common_planners::find_aggregate_exprs_in_expr;
 use common_planners::resolve_aliases_to_exprs;
 use common_planners::Expression;
 use sqlparser::ast::Expr;
-use sqlparser::ast::OffsetRows;
 use sqlparser::ast::SelectItem;
 
 use crate::sessions::QueryContext;
 use crate::sql::statements::analyzer_expr::ExpressionAnalyzer;
 use crate::sql::statements::query::QueryASTIR;
 use crate::sql::statements::DfQueryStatement;
+use crate::sql::SQLCommon;
 
 pub struct QueryNormalizer {
+    ctx: Arc<QueryContext>,
     query_ast_ir: QueryASTIR,
     expression_analyzer: ExpressionAnalyzer,
     aliases_map: HashMap<String, Expression>,
@@ -40,7 +41,8 @@ pub struct QueryNormalizer {
 impl QueryNormalizer {
     fn create(ctx: Arc<QueryContext>) -> QueryNormalizer {
         QueryNormalizer {
-            expression_analyzer: ExpressionAnalyzer::create(ctx),
+            expression_analyzer: ExpressionAnalyzer::create(ctx.clone()),
+            ctx,
             aliases_map: HashMap::new(),
             query_ast_ir: QueryASTIR {
                 filter_predicate: None,
@@ -48,9 +50,12 @@ impl QueryNormalizer {
                 having_predicate: None,
                 aggregate_expressions: vec![],
                 order_by_expressions: vec![],
+                distinct: false,
+                distinct_on_expressions: vec![],
                 projection_expressions: vec![],
                 limit: None,
                 offset: None,
+                sample: None,
             },
         }
     }
@@ -81,10 +86,15 @@ impl QueryNormalizer {
             return Err(cause.add_message_back(" (while in analyze select order by)"));
         }
 
+        if let Err(cause) = self.analyze_distinct(query).await {
+            return Err(cause.add_message_back(" (while in analyze select distinct)"));
+        }
+
         if let Err(cause) = self.analyze_limit(query).await {
             return Err(cause.add_message_back(" (while in analyze select limit)"));
         }
 
+        self.query_ast_ir.sample = query.table_sample;
         Ok(self.query_ast_ir)
     }
 
@@ -130,15 +140,18 @@ impl QueryNormalizer {
 
     async fn analyze_order_by(&mut self, query: &DfQueryStatement) -> Result<()> {
         for order_by_expr in &query.order_by {
-            let expression = self.resolve_aliases(&order_by_expr.expr).await?;
+            let (expr, case_insensitive) = Self::unwrap_collation(&order_by_expr.expr)?;
+            let expression = self.resolve_aliases(expr).await?;
 
             self.add_aggregate_function(&expression)?;
+            let asc = order_by_expr.asc.unwrap_or(true);
             self.query_ast_ir
                 .order_by_expressions
                 .push(Expression::Sort {
                     expr: Box::new(expression.clone()),
-                    asc: order_by_expr.asc.unwrap_or(true),
-                    nulls_first: order_by_expr.asc.unwrap_or(true),
+                    asc,
+                    nulls_first: order_by_expr.nulls_first.unwrap_or(asc),
+                    case_insensitive,
                     origin_expr: Box::new(expression),
                 });
         }
@@ -146,6 +159,40 @@ impl QueryNormalizer {
         Ok(())
     }
 
+    // `expr COLLATE name` in ORDER BY: `*_ci` collations compare case insensitively,
+    // `binary` and `*_bin` are the default byte order.
+    fn unwrap_collation(expr: &Expr) -> Result<(&Expr, bool)> {
+        match expr {
+            Expr::Collate { expr, collation } => {
+                let name = collation.to_string().to_lowercase();
+                if name.ends_with("_ci") {
+                    Ok((expr.as_ref(), true))
+                } else if name == "binary" || name.ends_with("_bin") {
+                    Ok((expr.as_ref(), false))
+                } else {
+                    Err(ErrorCode::SyntaxException(format!(
+                        "Unsupported collation: {}, expected a *_ci or *_bin collation",
+                        collation
+                    )))
+                }
+            }
+            _ => Ok((expr, false)),
+        }
+    }
+
+    async fn analyze_distinct(&mut self, query: &DfQueryStatement) -> Result<()> {
+        self.query_ast_ir.distinct = query.distinct;
+
+        for distinct_on_expr in &query.distinct_on {
+            let expression = self.resolve_aliases(distinct_on_expr).await?;
+
+            self.add_aggregate_function(&expression)?;
+            self.query_ast_ir.distinct_on_expressions.push(expression);
+        }
+
+        Ok(())
+    }
+
     async fn analyze_limit(&mut self, query: &DfQueryStatement) -> Result<()> {
         if let Some(limit) = &query.limit {
             let expression_analyzer = &self.expression_analyzer;
@@ -159,16 +206,13 @@ impl QueryNormalizer {
             self.query_ast_ir.limit = Some(limit_literal);
         }
 
+        // OFFSET n [ROW | ROWS], the ROW and ROWS are noise words.
         if let Some(offset) = &query.offset {
-            if !matches!(offset.rows, OffsetRows::None) {
-                return Err(ErrorCode::SyntaxException("Unimplemented limit n ROW|ROWS"));
-            }
-
             let expression_analyzer = &self.expression_analyzer;
             let offset_literal = match expression_analyzer.analyze(&offset.value).await? {
                 Expression::Literal { value, .. } => Ok(value.as_u64()? as usize),
                 _ => Err(ErrorCode::SyntaxException(format!(
-                    "Unexpected expression for LIMIT clause: {:?}",
+                    "Unexpected expression for OFFSET clause: {:?}",
                     offset
                 ))),
             }?;
@@ -191,7 +235,7 @@ impl QueryNormalizer {
                     output_columns.push(expr_analyzer.analyze(expr).await?);
                 }
                 SelectItem::ExprWithAlias { expr, alias } => {
-                    let expr_alias = alias.value.clone();
+                    let expr_alias = SQLCommon::resolve_identifier(&self.ctx, alias)?;
                     let expr = Box::new(expr_analyzer.analyze(expr).await?);
                     output_columns.push(Expression::Alias(expr_alias, expr));
                 }
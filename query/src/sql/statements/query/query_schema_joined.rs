@@ -22,6 +22,7 @@ use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::Extras;
+use common_planners::ValuesPlan;
 
 use crate::sql::statements::QueryAnalyzeState;
 use crate::storages::Table;
@@ -55,6 +56,11 @@ impl JoinedSchema {
         Self::from_table_desc(table_desc)
     }
 
+    pub fn from_values(plan: ValuesPlan, prefix: Vec<String>) -> Result<JoinedSchema> {
+        let table_desc = JoinedTableDesc::from_values(plan, prefix);
+        Self::from_table_desc(table_desc)
+    }
+
     fn from_table_desc(table_desc: JoinedTableDesc) -> Result<JoinedSchema> {
         let mut short_name_columns = HashMap::new();
 
@@ -171,6 +177,11 @@ pub enum JoinedTableDesc {
         name_parts: Vec<String>,
         columns_desc: Vec<JoinedColumnDesc>,
     },
+    Values {
+        plan: ValuesPlan,
+        name_parts: Vec<String>,
+        columns_desc: Vec<JoinedColumnDesc>,
+    },
 }
 
 impl JoinedTableDesc {
@@ -205,10 +216,26 @@ impl JoinedTableDesc {
         }
     }
 
+    pub fn from_values(plan: ValuesPlan, prefix: Vec<String>) -> JoinedTableDesc {
+        let schema = plan.schema();
+        let mut columns_desc = Vec::with_capacity(schema.fields().len());
+
+        for data_field in schema.fields() {
+            columns_desc.push(JoinedColumnDesc::from_field(data_field, false));
+        }
+
+        JoinedTableDesc::Values {
+            plan,
+            columns_desc,
+            name_parts: prefix,
+        }
+    }
+
     pub fn get_name_parts(&self) -> &[String] {
         match self {
             JoinedTableDesc::Table { name_parts, .. } => name_parts,
             JoinedTableDesc::Subquery { name_parts, .. } => name_parts,
+            JoinedTableDesc::Values { name_parts, .. } => name_parts,
         }
     }
 
@@ -216,6 +243,7 @@ impl JoinedTableDesc {
         match self {
             JoinedTableDesc::Table { columns_desc, .. } => columns_desc,
             JoinedTableDesc::Subquery { columns_desc, .. } => columns_desc,
+            JoinedTableDesc::Values { columns_desc, .. } => columns_desc,
         }
     }
 }
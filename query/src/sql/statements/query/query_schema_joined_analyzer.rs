@@ -14,16 +14,23 @@
 
 use std::sync::Arc;
 
+use common_datavalues::type_coercion::aggregate_types;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRefExt;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::Expression;
+use common_planners::ValuesPlan;
 use sqlparser::ast::FunctionArg;
 use sqlparser::ast::Ident;
 use sqlparser::ast::JoinOperator;
 use sqlparser::ast::ObjectName;
 use sqlparser::ast::Query;
+use sqlparser::ast::SetExpr;
 use sqlparser::ast::TableAlias;
 use sqlparser::ast::TableFactor;
 use sqlparser::ast::TableWithJoins;
+use sqlparser::ast::Values;
 
 use crate::catalogs::Catalog;
 use crate::sessions::QueryContext;
@@ -34,6 +41,7 @@ use crate::sql::statements::AnalyzedResult;
 use crate::sql::statements::DfQueryStatement;
 use crate::sql::DfParser;
 use crate::sql::DfStatement;
+use crate::sql::SQLCommon;
 use crate::storages::view::view_table::QUERY;
 use crate::storages::view::view_table::VIEW_ENGINE;
 
@@ -54,7 +62,7 @@ impl JoinedSchemaAnalyzer {
         for rpn_item in &rpn {
             match rpn_item {
                 RelationRPNItem::Join(_) => {
-                    return Err(ErrorCode::UnImplement("Unimplemented SELECT JOIN yet."));
+                    return Err(ErrorCode::UnImplement("Unimplemented SELECT JOIN yet"));
                 }
                 RelationRPNItem::Table(v) => {
                     let schema = self.table(v);
@@ -68,6 +76,10 @@ impl JoinedSchemaAnalyzer {
                     let schema = self.subquery(v);
                     analyzed_tables.push(schema.await?);
                 }
+                RelationRPNItem::Values(v) => {
+                    let schema = self.values(v);
+                    analyzed_tables.push(schema.await?);
+                }
             }
         }
 
@@ -87,7 +99,7 @@ impl JoinedSchemaAnalyzer {
             AnalyzedResult::SelectQuery(state) => match &v.alias {
                 None => JoinedSchema::from_subquery(state, Vec::new()),
                 Some(alias) => {
-                    let name_prefix = vec![alias.name.value.clone()];
+                    let name_prefix = vec![SQLCommon::resolve_identifier(&self.ctx, &alias.name)?];
                     JoinedSchema::from_subquery(state, name_prefix)
                 }
             },
@@ -97,6 +109,80 @@ impl JoinedSchemaAnalyzer {
         }
     }
 
+    async fn values(&self, item: &ValuesRPNItem) -> Result<JoinedSchema> {
+        let rows = &item.values.0;
+        let columns = rows.first().map(|row| row.len()).unwrap_or(0);
+        let names = match &item.alias {
+            Some(alias) if !alias.columns.is_empty() => {
+                if alias.columns.len() != columns {
+                    return Err(ErrorCode::SyntaxException(format!(
+                        "VALUES has {} columns, but {} column aliases are specified",
+                        columns,
+                        alias.columns.len()
+                    )));
+                }
+                alias
+                    .columns
+                    .iter()
+                    .map(|c| SQLCommon::resolve_identifier(&self.ctx, c))
+                    .collect::<Result<Vec<_>>>()?
+            }
+            _ => (1..=columns)
+                .map(|i| format!("column{}", i))
+                .collect::<Vec<_>>(),
+        };
+
+        // The rows can only reference literals, so the types are inferred with an empty schema.
+        let empty_schema = DataSchemaRefExt::create(vec![]);
+        let analyzer = ExpressionAnalyzer::create(self.ctx.clone());
+        let mut values = Vec::with_capacity(rows.len());
+        let mut rows_types = vec![Vec::with_capacity(rows.len()); columns];
+        for row in rows {
+            if row.len() != columns {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "VALUES rows must have the same number of columns, expected {} but got {}",
+                    columns,
+                    row.len()
+                )));
+            }
+
+            let mut exprs = Vec::with_capacity(columns);
+            for (index, value) in row.iter().enumerate() {
+                let expr = analyzer.analyze(value).await?;
+                rows_types[index].push(expr.to_data_type(&empty_schema)?);
+                exprs.push(expr);
+            }
+            values.push(exprs);
+        }
+
+        let mut fields = Vec::with_capacity(columns);
+        for (name, types) in names.iter().zip(rows_types.iter()) {
+            fields.push(DataField::new(name, aggregate_types(types)?));
+        }
+
+        let schema = DataSchemaRefExt::create(fields);
+        for exprs in values.iter_mut() {
+            for (index, expr) in exprs.iter_mut().enumerate() {
+                let field = schema.field(index);
+                let mut value = expr.clone();
+                if &value.to_data_type(&empty_schema)? != field.data_type() {
+                    value = Expression::Cast {
+                        expr: Box::new(value),
+                        data_type: field.data_type().clone(),
+                        is_nullable: field.is_nullable(),
+                    };
+                }
+                *expr = Expression::Alias(field.name().clone(), Box::new(value));
+            }
+        }
+
+        let name_prefix = match &item.alias {
+            None => Vec::new(),
+            Some(alias) => vec![SQLCommon::resolve_identifier(&self.ctx, &alias.name)?],
+        };
+        JoinedSchema::from_values(ValuesPlan { values, schema }, name_prefix)
+    }
+
     async fn table(&self, item: &TableRPNItem) -> Result<JoinedSchema> {
         // TODO(Winter): await query_context.get_table
         let (database, table) = self.resolve_table(&item.name)?;
@@ -127,7 +213,8 @@ impl JoinedSchemaAnalyzer {
                     JoinedSchema::from_table(read_table, name_prefix)
                 }
                 Some(table_alias) => {
-                    let name_prefix = vec![table_alias.name.value.clone()];
+                    let name_prefix =
+                        vec![SQLCommon::resolve_identifier(&self.ctx, &table_alias.name)?];
                     JoinedSchema::from_table(read_table, name_prefix)
                 }
             }
@@ -157,7 +244,8 @@ impl JoinedSchemaAnalyzer {
         match &item.alias {
             None => JoinedSchema::from_table(table_function.as_table(), Vec::new()),
             Some(table_alias) => {
-                let name_prefix = vec![table_alias.name.value.clone()];
+                let name_prefix =
+                    vec![SQLCommon::resolve_identifier(&self.ctx, &table_alias.name)?];
                 JoinedSchema::from_table(table_function.as_table(), name_prefix)
             }
         }
@@ -166,8 +254,14 @@ impl JoinedSchemaAnalyzer {
     fn resolve_table(&self, name: &ObjectName) -> Result<(String, String)> {
         match name.0.len() {
             0 => Err(ErrorCode::SyntaxException("Table name is empty")),
-            1 => Ok((self.ctx.get_current_database(), name.0[0].value.clone())),
-            2 => Ok((name.0[0].value.clone(), name.0[1].value.clone())),
+            1 => Ok((
+                self.ctx.get_current_database(),
+                SQLCommon::resolve_identifier(&self.ctx, &name.0[0])?,
+            )),
+            2 => Ok((
+                SQLCommon::resolve_identifier(&self.ctx, &name.0[0])?,
+                SQLCommon::resolve_identifier(&self.ctx, &name.0[1])?,
+            )),
             _ => Err(ErrorCode::SyntaxException(
                 "Table name must be [`db`].`table`",
             )),
@@ -185,6 +279,11 @@ struct DerivedRPNItem {
     alias: Option<TableAlias>,
 }
 
+struct ValuesRPNItem {
+    values: Values,
+    alias: Option<TableAlias>,
+}
+
 struct TableFunctionRPNItem {
     name: ObjectName,
     args: Vec<FunctionArg>,
@@ -195,6 +294,7 @@ enum RelationRPNItem {
     Table(TableRPNItem),
     TableFunction(TableFunctionRPNItem),
     Derived(DerivedRPNItem),
+    Values(ValuesRPNItem),
     Join(JoinOperator),
 }
 
@@ -273,8 +373,27 @@ impl RelationRPNBuilder {
                 subquery,
                 alias,
             } => {
+                // A LATERAL subquery is correlated with the relations on its left, it needs
+                // a join to be evaluated per row of them.
                 if *lateral {
-                    return Err(ErrorCode::UnImplement("Cannot SELECT LATERAL subquery."));
+                    return Err(ErrorCode::UnImplement(
+                        "Cannot SELECT LATERAL subquery, SELECT JOIN is unimplemented yet",
+                    ));
+                }
+
+                // A bare VALUES list, anything else goes through the subquery analyzer.
+                if let SetExpr::Values(values) = &subquery.body {
+                    if subquery.order_by.is_empty()
+                        && subquery.limit.is_none()
+                        && subquery.offset.is_none()
+                        && subquery.fetch.is_none()
+                    {
+                        self.rpn.push(RelationRPNItem::Values(ValuesRPNItem {
+                            values: values.clone(),
+                            alias: alias.clone(),
+                        }));
+                        return Ok(());
+                    }
                 }
 
                 self.rpn.push(RelationRPNItem::Derived(DerivedRPNItem {
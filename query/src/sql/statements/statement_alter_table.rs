@@ -16,15 +16,18 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::CommentTablePlan;
 use common_planners::PlanNode;
 use common_planners::RenameTableEntity;
 use common_planners::RenameTablePlan;
+use common_planners::SwapTablePlan;
 use common_tracing::tracing;
 use sqlparser::ast::ObjectName;
 
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
+use crate::sql::SQLCommon;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfAlterTable {
@@ -36,6 +39,8 @@ pub struct DfAlterTable {
 #[derive(Clone, Debug, PartialEq)]
 pub enum AlterTableAction {
     RenameTable(ObjectName),
+    SwapTable(ObjectName),
+    Comment(String),
     // TODO AddColumn etc.
 }
 
@@ -62,6 +67,28 @@ impl AnalyzableStatement for DfAlterTable {
                     PlanNode::RenameTable(RenameTablePlan { tenant, entities }),
                 )))
             }
+            AlterTableAction::SwapTable(o) => {
+                let (other_db, other_table_name) = self.resolve_table(ctx, o)?;
+                Ok(AnalyzedResult::SimpleQuery(Box::new(PlanNode::SwapTable(
+                    SwapTablePlan {
+                        if_exists: self.if_exists,
+                        tenant,
+                        db,
+                        table_name,
+                        other_db,
+                        other_table_name,
+                    },
+                ))))
+            }
+            AlterTableAction::Comment(comment) => Ok(AnalyzedResult::SimpleQuery(Box::new(
+                PlanNode::CommentTable(CommentTablePlan {
+                    if_exists: self.if_exists,
+                    tenant,
+                    db,
+                    table: table_name,
+                    comment: comment.clone(),
+                }),
+            ))),
         }
     }
 }
@@ -75,8 +102,14 @@ impl DfAlterTable {
         let idents = &table_name.0;
         match idents.len() {
             0 => Err(ErrorCode::SyntaxException("Alter table name is empty")),
-            1 => Ok((ctx.get_current_database(), idents[0].value.clone())),
-            2 => Ok((idents[0].value.clone(), idents[1].value.clone())),
+            1 => Ok((
+                ctx.get_current_database(),
+                SQLCommon::resolve_identifier(&ctx, &idents[0])?,
+            )),
+            2 => Ok((
+                SQLCommon::resolve_identifier(&ctx, &idents[0])?,
+                SQLCommon::resolve_identifier(&ctx, &idents[1])?,
+            )),
             _ => Err(ErrorCode::SyntaxException(
                 "Alter table name must be [`db`].`table`",
             )),
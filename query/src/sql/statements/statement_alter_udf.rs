@@ -26,6 +26,7 @@ use crate::sql::statements::AnalyzedResult;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfAlterUDF {
+    pub if_exists: bool,
     pub udf_name: String,
     pub parameters: Vec<String>,
     pub definition: String,
@@ -38,6 +39,7 @@ impl AnalyzableStatement for DfAlterUDF {
     async fn analyze(&self, _ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
         Ok(AnalyzedResult::SimpleQuery(Box::new(
             PlanNode::AlterUserUDF(AlterUserUDFPlan {
+                if_exists: self.if_exists,
                 udf: UserDefinedFunction::new(
                     self.udf_name.as_str(),
                     self.parameters.clone(),
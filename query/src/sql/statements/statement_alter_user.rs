@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::UserIdentity;
 use common_planners::AlterUserPlan;
@@ -28,6 +29,7 @@ use crate::sql::statements::DfUserWithOption;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfAlterUser {
+    pub if_exists: bool,
     pub if_current_user: bool,
     pub user: UserIdentity,
     // None means no change to make
@@ -42,9 +44,24 @@ impl AnalyzableStatement for DfAlterUser {
         let user_info = if self.if_current_user {
             ctx.get_current_user()?
         } else {
-            ctx.get_user_manager()
+            match ctx
+                .get_user_manager()
                 .get_user(&ctx.get_tenant(), self.user.clone())
-                .await?
+                .await
+            {
+                Ok(user_info) => user_info,
+                // Nothing to alter, the interpreter skips a plan without changes.
+                Err(e) if self.if_exists && e.code() == ErrorCode::UnknownUserCode() => {
+                    return Ok(AnalyzedResult::SimpleQuery(Box::new(PlanNode::AlterUser(
+                        AlterUserPlan {
+                            user: self.user.clone(),
+                            auth_info: None,
+                            user_option: None,
+                        },
+                    ))));
+                }
+                Err(e) => return Err(e),
+            }
         };
 
         let new_auth_info = if let Some(auth_option) = &self.auth_option {
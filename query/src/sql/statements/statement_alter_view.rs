@@ -28,6 +28,7 @@ use crate::sql::statements::DfQueryStatement;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfAlterView {
+    pub if_exists: bool,
     /// View Name
     pub name: ObjectName,
     /// Original SQL String, store in meta service
@@ -47,6 +48,7 @@ impl AnalyzableStatement for DfAlterView {
         let (db, viewname) = DfCreateTable::resolve_table(ctx.clone(), &self.name, "View")?;
         Ok(AnalyzedResult::SimpleQuery(Box::new(PlanNode::AlterView(
             AlterViewPlan {
+                if_exists: self.if_exists,
                 tenant,
                 db,
                 viewname,
@@ -20,6 +20,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_io::prelude::get_abs_path;
 use common_meta_types::FileFormatOptions;
+use common_meta_types::StageFileCompression;
 use common_meta_types::StageFileFormatType;
 use common_meta_types::StageS3Storage;
 use common_meta_types::StageStorage;
@@ -150,11 +151,19 @@ pub fn parse_copy_file_format_options(
         .unwrap_or(&"".to_string())
         .clone();
 
+    // Compression, default NONE.
+    let compression = match file_format_options.get("compression") {
+        Some(compression) => StageFileCompression::from_str(compression).map_err(|e| {
+            ErrorCode::SyntaxException(format!("File compression type error:{:?}", e))
+        })?,
+        None => StageFileCompression::None,
+    };
+
     Ok(FileFormatOptions {
         format: file_format,
         skip_header,
         field_delimiter,
         record_delimiter,
-        compression: Default::default(),
+        compression,
     })
 }
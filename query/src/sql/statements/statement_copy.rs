@@ -38,6 +38,7 @@ use super::parse_stage_storage;
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
+use crate::sql::statements::DfCreateTable;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfCopy {
@@ -51,20 +52,15 @@ pub struct DfCopy {
     pub pattern: String,
     pub on_error: String,
     pub size_limit: String,
+    pub force: String,
+    pub sort_by_cluster_key: String,
     pub validation_mode: String,
 }
 
 #[async_trait::async_trait]
 impl AnalyzableStatement for DfCopy {
     async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
-        let mut db_name = ctx.get_current_database();
-        let mut tbl_name = self.name.0[0].value.clone();
-
-        if self.name.0.len() > 1 {
-            db_name = tbl_name;
-            tbl_name = self.name.0[1].value.clone();
-        }
-
+        let (db_name, tbl_name) = DfCreateTable::resolve_table(ctx.clone(), &self.name, "Table")?;
         let table = ctx.get_table(&db_name, &tbl_name).await?;
         let mut schema = table.schema();
         let tbl_id = table.get_id();
@@ -111,6 +107,30 @@ impl AnalyzableStatement for DfCopy {
             }
         }
 
+        // Force, load the files even if they were loaded before.
+        let force = match self.force.to_lowercase().as_str() {
+            "" | "false" => false,
+            "true" => true,
+            _ => {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "force must be TRUE or FALSE, got: {}",
+                    self.force
+                )))
+            }
+        };
+
+        // Sort the loaded blocks by the cluster key of the table, on by default.
+        let sort_by_cluster_key = match self.sort_by_cluster_key.to_lowercase().as_str() {
+            "" | "true" => true,
+            "false" => false,
+            _ => {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "sort_by_cluster_key must be TRUE or FALSE, got: {}",
+                    self.sort_by_cluster_key
+                )))
+            }
+        };
+
         // Validation mode.
         let validation_mode = ValidationMode::from_str(self.validation_mode.as_str())
             .map_err(ErrorCode::SyntaxException)?;
@@ -144,6 +164,8 @@ impl AnalyzableStatement for DfCopy {
             validation_mode,
             files: self.files.clone(),
             pattern,
+            force,
+            sort_by_cluster_key,
         };
 
         Ok(AnalyzedResult::SimpleQuery(Box::new(PlanNode::Copy(
@@ -26,6 +26,7 @@ use sqlparser::ast::ObjectName;
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
+use crate::sql::SQLCommon;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfCreateDatabase {
@@ -41,7 +42,7 @@ impl AnalyzableStatement for DfCreateDatabase {
     #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
         let tenant = ctx.get_tenant();
-        let db = self.database_name()?;
+        let db = self.database_name(&ctx)?;
         let if_not_exists = self.if_not_exists;
         let meta = self.database_meta()?;
 
@@ -57,12 +58,12 @@ impl AnalyzableStatement for DfCreateDatabase {
 }
 
 impl DfCreateDatabase {
-    fn database_name(&self) -> Result<String> {
+    fn database_name(&self, ctx: &QueryContext) -> Result<String> {
         if self.name.0.is_empty() {
             return Result::Err(ErrorCode::SyntaxException("Create database name is empty"));
         }
 
-        Ok(self.name.0[0].value.clone())
+        SQLCommon::resolve_identifier(ctx, &self.name.0[0])
     }
 
     fn database_meta(&self) -> Result<DatabaseMeta> {
@@ -39,10 +39,12 @@ use crate::sql::DfStatement;
 use crate::sql::PlanParser;
 use crate::sql::SQLCommon;
 use crate::sql::OPT_KEY_DATABASE_ID;
+use crate::storages::fuse::FUSE_OPT_KEY_CLUSTER_KEY;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfCreateTable {
     pub if_not_exists: bool,
+    pub or_replace: bool,
     /// Table name
     pub name: ObjectName,
     pub columns: Vec<ColumnDef>,
@@ -63,6 +65,7 @@ impl AnalyzableStatement for DfCreateTable {
         let (db, table) = Self::resolve_table(ctx.clone(), &self.name, "Table")?;
         let mut table_meta = self.table_meta(ctx.clone(), db.as_str()).await?;
         let if_not_exists = self.if_not_exists;
+        let or_replace = self.or_replace;
         let tenant = ctx.get_tenant();
         let as_select_plan_node = match &self.query {
             // CTAS
@@ -87,10 +90,12 @@ impl AnalyzableStatement for DfCreateTable {
             // Query doesn't contain 'As Select' statement
             None => None,
         };
+        self.validate_cluster_key(&table_meta.schema)?;
 
         Ok(AnalyzedResult::SimpleQuery(Box::new(
             PlanNode::CreateTable(CreateTablePlan {
                 if_not_exists,
+                or_replace,
                 tenant,
                 db,
                 table,
@@ -113,8 +118,14 @@ impl DfCreateTable {
                 "{} name is empty",
                 table_type
             ))),
-            1 => Ok((ctx.get_current_database(), idents[0].value.clone())),
-            2 => Ok((idents[0].value.clone(), idents[1].value.clone())),
+            1 => Ok((
+                ctx.get_current_database(),
+                SQLCommon::resolve_identifier(&ctx, &idents[0])?,
+            )),
+            2 => Ok((
+                SQLCommon::resolve_identifier(&ctx, &idents[0])?,
+                SQLCommon::resolve_identifier(&ctx, &idents[1])?,
+            )),
             _ => Err(ErrorCode::SyntaxException(format!(
                 "{} name must be [`db`].`{}`",
                 table_type, table_type
@@ -171,13 +182,13 @@ impl DfCreateTable {
                             _ => {}
                         }
                     }
+                    let column_name = SQLCommon::resolve_identifier(&ctx, &column.name)?;
                     let field = SQLCommon::make_data_type(&column.data_type).map(|data_type| {
                         if nullable {
-                            DataField::new_nullable(&column.name.value, data_type)
+                            DataField::new_nullable(&column_name, data_type)
                                 .with_default_expr(default_expr)
                         } else {
-                            DataField::new(&column.name.value, data_type)
-                                .with_default_expr(default_expr)
+                            DataField::new(&column_name, data_type).with_default_expr(default_expr)
                         }
                     })?;
                     fields.push(field);
@@ -213,6 +224,21 @@ impl DfCreateTable {
         Ok(meta)
     }
 
+    // The columns of the cluster key must be the columns of the table.
+    fn validate_cluster_key(&self, schema: &DataSchemaRef) -> Result<()> {
+        if let Some(cluster_key) = self.options.get(FUSE_OPT_KEY_CLUSTER_KEY) {
+            for column_name in cluster_key.split(',').map(|v| v.trim()) {
+                if !schema.has_field(column_name) {
+                    return Err(ErrorCode::BadOption(format!(
+                        "Unknown column {} in the {} option",
+                        column_name, FUSE_OPT_KEY_CLUSTER_KEY
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn validate_table_options(&self) -> Result<()> {
         let reserved = self
             .options
@@ -27,6 +27,7 @@ use crate::sql::statements::AnalyzedResult;
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfCreateUDF {
     pub if_not_exists: bool,
+    pub or_replace: bool,
     pub udf_name: String,
     pub parameters: Vec<String>,
     pub definition: String,
@@ -40,6 +41,7 @@ impl AnalyzableStatement for DfCreateUDF {
         Ok(AnalyzedResult::SimpleQuery(Box::new(
             PlanNode::CreateUserUDF(CreateUserUDFPlan {
                 if_not_exists: self.if_not_exists,
+                or_replace: self.or_replace,
                 udf: UserDefinedFunction::new(
                     self.udf_name.as_str(),
                     self.parameters.clone(),
@@ -102,6 +102,7 @@ impl AnalyzableStatement for DfCreateUser {
         }
         Ok(AnalyzedResult::SimpleQuery(Box::new(PlanNode::CreateUser(
             CreateUserPlan {
+                if_not_exists: self.if_not_exists,
                 user: self.user.clone(),
                 auth_info: AuthInfo::create(
                     &self.auth_option.auth_type,
@@ -35,6 +35,7 @@ use crate::sql::statements::AnalyzedResult;
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct DfCreateUserStage {
     pub if_not_exists: bool,
+    pub or_replace: bool,
     pub stage_name: String,
 
     pub location: String,
@@ -85,6 +86,7 @@ impl AnalyzableStatement for DfCreateUserStage {
         Ok(AnalyzedResult::SimpleQuery(Box::new(
             PlanNode::CreateUserStage(CreateUserStagePlan {
                 if_not_exists: self.if_not_exists,
+                or_replace: self.or_replace,
                 tenant: ctx.get_tenant(),
                 user_stage_info: stage_info,
             }),
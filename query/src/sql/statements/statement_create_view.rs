@@ -30,6 +30,7 @@ use crate::sql::statements::DfQueryStatement;
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfCreateView {
     pub if_not_exists: bool,
+    pub or_replace: bool,
     /// View Name
     pub name: ObjectName,
     /// Original SQL String, store in meta service
@@ -45,12 +46,14 @@ impl AnalyzableStatement for DfCreateView {
         // check whether query is valid
         let _ = self.query.analyze(ctx.clone()).await?;
         let if_not_exists = self.if_not_exists;
+        let or_replace = self.or_replace;
         let subquery = self.subquery.clone();
         let tenant = ctx.get_tenant();
         let (db, viewname) = DfCreateTable::resolve_table(ctx.clone(), &self.name, "View")?;
         Ok(AnalyzedResult::SimpleQuery(Box::new(PlanNode::CreateView(
             CreateViewPlan {
                 if_not_exists,
+                or_replace,
                 tenant,
                 db,
                 viewname,
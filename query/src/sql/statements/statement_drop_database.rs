@@ -24,6 +24,7 @@ use sqlparser::ast::ObjectName;
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
+use crate::sql::SQLCommon;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfDropDatabase {
@@ -35,7 +36,7 @@ pub struct DfDropDatabase {
 impl AnalyzableStatement for DfDropDatabase {
     #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
-        let db = self.database_name()?;
+        let db = self.database_name(&ctx)?;
         let if_exists = self.if_exists;
         let tenant = ctx.get_tenant();
 
@@ -50,11 +51,11 @@ impl AnalyzableStatement for DfDropDatabase {
 }
 
 impl DfDropDatabase {
-    fn database_name(&self) -> Result<String> {
+    fn database_name(&self, ctx: &QueryContext) -> Result<String> {
         if self.name.0.is_empty() {
             return Result::Err(ErrorCode::SyntaxException("Create database name is empty"));
         }
 
-        Ok(self.name.0[0].value.clone())
+        SQLCommon::resolve_identifier(ctx, &self.name.0[0])
     }
 }
@@ -24,6 +24,7 @@ use sqlparser::ast::ObjectName;
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
+use crate::sql::SQLCommon;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfDropTable {
@@ -58,8 +59,14 @@ impl DfDropTable {
         } = self;
         match idents.len() {
             0 => Err(ErrorCode::SyntaxException("Drop table name is empty")),
-            1 => Ok((ctx.get_current_database(), idents[0].value.clone())),
-            2 => Ok((idents[0].value.clone(), idents[1].value.clone())),
+            1 => Ok((
+                ctx.get_current_database(),
+                SQLCommon::resolve_identifier(&ctx, &idents[0])?,
+            )),
+            2 => Ok((
+                SQLCommon::resolve_identifier(&ctx, &idents[0])?,
+                SQLCommon::resolve_identifier(&ctx, &idents[1])?,
+            )),
             _ => Err(ErrorCode::SyntaxException(
                 "Drop table name must be [`db`].`table`",
             )),
@@ -0,0 +1,82 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::DataValue;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_tracing::tracing;
+use sqlparser::ast::Expr;
+use sqlparser::ast::UnaryOperator;
+use sqlparser::ast::Value;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+use crate::sql::DfParser;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfExecute {
+    pub name: String,
+    pub params: Vec<Expr>,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfExecute {
+    #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        let prepared = ctx.get_prepared_statement(&self.name)?;
+        let params = self
+            .params
+            .iter()
+            .map(Self::param_value)
+            .collect::<Result<Vec<_>>>()?;
+
+        // The statement was checked when it was prepared, it parses to a single statement.
+        let query = prepared.bind(&params)?;
+        let (statements, _) = DfParser::parse_sql(&query)?;
+        statements[0].analyze(ctx).await
+    }
+}
+
+impl DfExecute {
+    fn param_value(expr: &Expr) -> Result<DataValue> {
+        match expr {
+            Expr::Value(Value::Null) => Ok(DataValue::Null),
+            Expr::Value(Value::Boolean(v)) => Ok(DataValue::Boolean(*v)),
+            Expr::Value(Value::Number(v, _)) => DataValue::try_from_literal(v, None),
+            Expr::Value(Value::SingleQuotedString(v)) => {
+                Ok(DataValue::String(v.as_bytes().to_vec()))
+            }
+            Expr::UnaryOp {
+                op: UnaryOperator::Minus,
+                expr,
+            } => match expr.as_ref() {
+                Expr::Value(Value::Number(v, _)) => {
+                    DataValue::try_from_literal(&format!("-{}", v), None)
+                }
+                _ => Self::unsupported_param(expr),
+            },
+            _ => Self::unsupported_param(expr),
+        }
+    }
+
+    fn unsupported_param(expr: &Expr) -> Result<DataValue> {
+        Err(ErrorCode::SyntaxException(format!(
+            "EXECUTE parameters must be literals, found: {}",
+            expr
+        )))
+    }
+}
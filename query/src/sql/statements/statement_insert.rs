@@ -39,6 +39,7 @@ use crate::sql::statements::AnalyzedResult;
 use crate::sql::statements::DfQueryStatement;
 use crate::sql::DfStatement;
 use crate::sql::PlanParser;
+use crate::sql::SQLCommon;
 use crate::storages::Table;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -62,6 +63,8 @@ pub struct DfInsertStatement {
     pub table: bool,
     /// on duplicate key update
     pub on: Option<OnInsert>,
+    /// Literal tuples of VALUES kept as text, they're converted into blocks while inserting
+    pub values: Option<String>,
 }
 
 #[async_trait::async_trait]
@@ -73,10 +76,13 @@ impl AnalyzableStatement for DfInsertStatement {
         let (database_name, table_name) = self.resolve_table(&ctx)?;
         let write_table = ctx.get_table(&database_name, &table_name).await?;
         let table_id = write_table.get_id();
-        let schema = self.insert_schema(write_table)?;
+        let schema = self.insert_schema(&ctx, write_table)?;
 
         let input_source = match &self.source {
-            None => self.analyze_insert_without_source().await,
+            None => match &self.values {
+                Some(values) => Ok(InsertInputSource::Values(values.clone())),
+                None => self.analyze_insert_without_source().await,
+            },
             Some(source) => match &source.body {
                 SetExpr::Values(v) => self.analyze_insert_values(ctx.clone(), v, &schema).await,
                 SetExpr::Select(_) => self.analyze_insert_select(ctx.clone(), source).await,
@@ -105,11 +111,11 @@ impl DfInsertStatement {
             0 => Err(ErrorCode::SyntaxException("Insert table name is empty")),
             1 => Ok((
                 ctx.get_current_database(),
-                self.table_name.0[0].value.clone(),
+                SQLCommon::resolve_identifier(ctx, &self.table_name.0[0])?,
             )),
             2 => Ok((
-                self.table_name.0[0].value.clone(),
-                self.table_name.0[1].value.clone(),
+                SQLCommon::resolve_identifier(ctx, &self.table_name.0[0])?,
+                SQLCommon::resolve_identifier(ctx, &self.table_name.0[1])?,
             )),
             _ => Err(ErrorCode::SyntaxException(
                 "Insert table name must be [`db`].`table`",
@@ -164,11 +170,7 @@ impl DfInsertStatement {
             value_exprs.push(exprs);
         }
 
-        let values = format!("{}", values);
-        Ok(InsertInputSource::Expressions(
-            (values["VALUES ".len()..]).to_string(),
-            value_exprs,
-        ))
+        Ok(InsertInputSource::Expressions(value_exprs))
     }
 
     async fn analyze_insert_without_source(&self) -> Result<InsertInputSource> {
@@ -189,7 +191,11 @@ impl DfInsertStatement {
         Ok(InsertInputSource::SelectPlan(Box::new(select_plan)))
     }
 
-    fn insert_schema(&self, read_table: Arc<dyn Table>) -> Result<DataSchemaRef> {
+    fn insert_schema(
+        &self,
+        ctx: &QueryContext,
+        read_table: Arc<dyn Table>,
+    ) -> Result<DataSchemaRef> {
         match self.columns.is_empty() {
             true => Ok(read_table.schema()),
             false => {
@@ -197,7 +203,10 @@ impl DfInsertStatement {
                 let fields = self
                     .columns
                     .iter()
-                    .map(|ident| schema.field_with_name(&ident.value).map(|v| v.clone()))
+                    .map(|ident| {
+                        let name = SQLCommon::resolve_identifier(ctx, ident)?;
+                        schema.field_with_name(&name).map(|v| v.clone())
+                    })
                     .collect::<Result<Vec<_>>>()?;
 
                 Ok(DataSchemaRefExt::create(fields))
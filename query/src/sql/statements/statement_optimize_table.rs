@@ -25,9 +25,11 @@ use sqlparser::ast::ObjectName;
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
+use crate::sql::SQLCommon;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfOptimizeTable {
+    pub if_exists: bool,
     pub name: ObjectName,
     pub operation: Optimization,
 }
@@ -38,6 +40,7 @@ impl AnalyzableStatement for DfOptimizeTable {
     async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
         let (database, table) = self.resolve_table(ctx)?;
         let plan_node = OptimizeTablePlan {
+            if_exists: self.if_exists,
             database,
             table,
             operation: self.operation,
@@ -56,8 +59,14 @@ impl DfOptimizeTable {
         } = self;
         match idents.len() {
             0 => Err(ErrorCode::SyntaxException("Compact table name is empty")),
-            1 => Ok((ctx.get_current_database(), idents[0].value.clone())),
-            2 => Ok((idents[0].value.clone(), idents[1].value.clone())),
+            1 => Ok((
+                ctx.get_current_database(),
+                SQLCommon::resolve_identifier(&ctx, &idents[0])?,
+            )),
+            2 => Ok((
+                SQLCommon::resolve_identifier(&ctx, &idents[0])?,
+                SQLCommon::resolve_identifier(&ctx, &idents[1])?,
+            )),
             _ => Err(ErrorCode::SyntaxException(
                 "Compact table name must be [`db`].`table`",
             )),
@@ -26,9 +26,11 @@ use sqlparser::ast::ObjectName;
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
+use crate::sql::SQLCommon;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfRenameTable {
+    pub if_exists: bool,
     pub name_map: HashMap<ObjectName, ObjectName>,
 }
 
@@ -42,7 +44,7 @@ impl AnalyzableStatement for DfRenameTable {
             let (db, table_name) = self.resolve_table(ctx.clone(), k)?;
             let (new_db, new_table_name) = self.resolve_table(ctx.clone(), v)?;
             entities.push(RenameTableEntity {
-                if_exists: false,
+                if_exists: self.if_exists,
                 db,
                 table_name,
                 new_db,
@@ -65,8 +67,14 @@ impl DfRenameTable {
         let idents = &table_name.0;
         match idents.len() {
             0 => Err(ErrorCode::SyntaxException("Rename table name is empty")),
-            1 => Ok((ctx.get_current_database(), idents[0].value.clone())),
-            2 => Ok((idents[0].value.clone(), idents[1].value.clone())),
+            1 => Ok((
+                ctx.get_current_database(),
+                SQLCommon::resolve_identifier(&ctx, &idents[0])?,
+            )),
+            2 => Ok((
+                SQLCommon::resolve_identifier(&ctx, &idents[0])?,
+                SQLCommon::resolve_identifier(&ctx, &idents[1])?,
+            )),
             _ => Err(ErrorCode::SyntaxException(
                 "Rename table name must be [`db`].`table`",
             )),
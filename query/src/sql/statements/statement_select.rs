@@ -25,6 +25,7 @@ use common_planners::rebase_expr;
 use common_planners::Expression;
 use common_tracing::tracing;
 use sqlparser::ast::Expr;
+use sqlparser::ast::Ident;
 use sqlparser::ast::Offset;
 use sqlparser::ast::OrderByExpr;
 use sqlparser::ast::SelectItem;
@@ -47,19 +48,49 @@ use crate::storages::ToReadDataSourcePlan;
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfQueryStatement {
     pub from: Vec<TableWithJoins>,
+    pub distinct: bool,
+    /// The keys of a `SELECT DISTINCT ON (...)`, empty for a plain DISTINCT
+    pub distinct_on: Vec<Expr>,
     pub projection: Vec<SelectItem>,
+    /// The columns left out of the `*` by `SELECT * EXCLUDE (...)`
+    pub wildcard_exclude: Vec<Ident>,
+    /// The columns substituted in the `*` by `SELECT * REPLACE (expr AS column, ...)`
+    pub wildcard_replace: Vec<(Expr, Ident)>,
     pub selection: Option<Expr>,
     pub group_by: Vec<Expr>,
     pub having: Option<Expr>,
     pub order_by: Vec<OrderByExpr>,
     pub limit: Option<Expr>,
     pub offset: Option<Offset>,
+    /// The `PIVOT (...)` applied to the FROM clause, lowered to a conditional aggregation
+    pub pivot: Option<DfPivot>,
+    /// The percentage of the FROM table to scan by `TABLESAMPLE (n PERCENT)`
+    pub table_sample: Option<f64>,
+}
+
+/// `PIVOT (aggregate FOR column IN (value, ...)) [AS alias]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfPivot {
+    pub aggregate: Expr,
+    pub column: Ident,
+    pub values: Vec<Expr>,
+    pub alias: Option<Ident>,
 }
 
 #[async_trait::async_trait]
 impl AnalyzableStatement for DfQueryStatement {
     #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        if let Some(pivot) = &self.pivot {
+            let lowered = self.lower_pivot(pivot, ctx.clone()).await?;
+            return lowered.analyze(ctx).await;
+        }
+
+        if !self.wildcard_exclude.is_empty() || !self.wildcard_replace.is_empty() {
+            let expanded = self.expand_wildcard_options(ctx.clone()).await?;
+            return expanded.analyze(ctx).await;
+        }
+
         let analyzer = JoinedSchemaAnalyzer::create(ctx.clone());
         let mut joined_schema = analyzer.analyze(self).await?;
 
@@ -68,6 +99,9 @@ impl AnalyzableStatement for DfQueryStatement {
         let has_aggregation = !find_aggregate_exprs(&ir.projection_expressions).is_empty();
 
         QualifiedRewriter::rewrite(&joined_schema, ctx.clone(), &mut ir)?;
+        if ctx.get_settings().get_enforce_deterministic_order()? != 0 {
+            Self::enforce_deterministic_order(&mut ir);
+        }
         QueryCollectPushDowns::collect_extras(&mut ir, &mut joined_schema, has_aggregation)?;
         let analyze_state = self.analyze_query(ir).await?;
         self.check_and_finalize(joined_schema, analyze_state, ctx)
@@ -103,6 +137,7 @@ impl DfQueryStatement {
                     expr,
                     asc,
                     nulls_first,
+                    case_insensitive,
                     origin_expr,
                 } => {
                     analyze_state.add_expression(expr);
@@ -110,6 +145,7 @@ impl DfQueryStatement {
                         expr: Box::new(rebase_expr(expr, &analyze_state.expressions)?),
                         asc: *asc,
                         nulls_first: *nulls_first,
+                        case_insensitive: *case_insensitive,
                         origin_expr: Box::new(rebase_expr(
                             origin_expr,
                             &analyze_state.expressions,
@@ -124,6 +160,10 @@ impl DfQueryStatement {
             }
         }
 
+        if ir.distinct {
+            Self::analyze_distinct(&ir, &mut analyze_state)?;
+        }
+
         if !ir.aggregate_expressions.is_empty() || !ir.group_by_expressions.is_empty() {
             // Rebase expressions using aggregate expressions and group by expressions
             let mut expressions = Vec::with_capacity(analyze_state.expressions.len());
@@ -180,6 +220,55 @@ impl DfQueryStatement {
         Ok(())
     }
 
+    // DISTINCT ON (exprs) deduplicates on the exprs, a plain DISTINCT on the whole projection.
+    fn analyze_distinct(ir: &QueryASTIR, state: &mut QueryAnalyzeState) -> Result<()> {
+        if ir.distinct_on_expressions.is_empty() {
+            for item in &state.projection_expressions {
+                let expr = match item {
+                    Expression::Alias(_, expr) => expr.as_ref(),
+                    _ => item,
+                };
+
+                if !state.distinct_expressions.contains(expr) {
+                    state.distinct_expressions.push(expr.clone());
+                }
+            }
+
+            return Ok(());
+        }
+
+        for expr in &ir.distinct_on_expressions {
+            state.add_expression(expr);
+            let rebased_expr = rebase_expr(expr, &state.expressions)?;
+            state.distinct_expressions.push(rebased_expr);
+        }
+
+        Ok(())
+    }
+
+    // Sort the unordered queries by all the output columns, so their results don't depend
+    // on the scheduling of the threads.
+    fn enforce_deterministic_order(ir: &mut QueryASTIR) {
+        if !ir.order_by_expressions.is_empty() {
+            return;
+        }
+
+        for item in &ir.projection_expressions {
+            let expr = match item {
+                Expression::Alias(_, expr) => expr.as_ref(),
+                _ => item,
+            };
+
+            ir.order_by_expressions.push(Expression::Sort {
+                expr: Box::new(expr.clone()),
+                asc: true,
+                nulls_first: true,
+                case_insensitive: false,
+                origin_expr: Box::new(expr.clone()),
+            });
+        }
+    }
+
     fn verify_no_aggregate(expr: &Expression, info: &str) -> Result<()> {
         match find_aggregate_exprs_in_expr(expr).is_empty() {
             true => Ok(()),
@@ -221,6 +310,9 @@ impl DfQueryStatement {
                 // TODO: maybe need reanalyze subquery.
                 state.relation = QueryRelation::Nested(subquery_state);
             }
+            JoinedTableDesc::Values { plan, .. } => {
+                state.relation = QueryRelation::FromValues(Box::new(plan));
+            }
         }
 
         Ok(AnalyzedResult::SelectQuery(Box::new(state)))
@@ -294,6 +386,12 @@ impl DfQueryStatement {
             }
         }
 
+        if !state.distinct_expressions.is_empty() {
+            if let Err(cause) = Self::dry_run_exprs(&state.distinct_expressions, &data_block) {
+                return Err(cause.add_message_back(" (while in select distinct)"));
+            }
+        }
+
         if !state.projection_expressions.is_empty() {
             match Self::dry_run_exprs(&state.projection_expressions, &data_block) {
                 Ok(res) => {
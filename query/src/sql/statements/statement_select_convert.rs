@@ -14,9 +14,12 @@
 
 use std::convert::TryFrom;
 
+use sqlparser::ast::Expr;
+use sqlparser::ast::Fetch;
 use sqlparser::ast::Query;
 use sqlparser::ast::Select;
 use sqlparser::ast::SetExpr;
+use sqlparser::ast::Value;
 use sqlparser::parser::ParserError;
 
 use crate::sql::statements::DfQueryStatement;
@@ -33,11 +36,10 @@ impl TryFrom<Query> for DfQueryStatement {
             )));
         }
 
-        if query.fetch.is_some() {
-            return Err(ParserError::ParserError(String::from(
-                "FETCH is not yet implement",
-            )));
-        }
+        let limit = match &query.fetch {
+            None => query.limit.clone(),
+            Some(fetch) => Some(Self::fetch_to_limit(&query, fetch)?),
+        };
 
         if query_body.top.is_some() {
             return Err(ParserError::ParserError(String::from(
@@ -65,18 +67,44 @@ impl TryFrom<Query> for DfQueryStatement {
 
         Ok(DfQueryStatement {
             from: query_body.from.clone(),
+            distinct: query_body.distinct,
+            distinct_on: vec![],
             projection: query_body.projection.clone(),
             selection: query_body.selection.clone(),
             group_by: query_body.group_by.clone(),
             having: query_body.having.clone(),
             order_by: query.order_by.clone(),
-            limit: query.limit.clone(),
+            limit,
             offset: query.offset.clone(),
+            pivot: None,
+            table_sample: None,
+            wildcard_exclude: vec![],
+            wildcard_replace: vec![],
         })
     }
 }
 
 impl DfQueryStatement {
+    // FETCH { FIRST | NEXT } [n] { ROW | ROWS } ONLY is the standard spelling of LIMIT n.
+    fn fetch_to_limit(query: &Query, fetch: &Fetch) -> Result<Expr, ParserError> {
+        if query.limit.is_some() {
+            return Err(ParserError::ParserError(String::from(
+                "Cannot use both LIMIT and FETCH in the same query",
+            )));
+        }
+
+        if fetch.with_ties || fetch.percent {
+            return Err(ParserError::ParserError(String::from(
+                "FETCH with PERCENT or WITH TIES is unsupported",
+            )));
+        }
+
+        Ok(match &fetch.quantity {
+            Some(quantity) => quantity.clone(),
+            None => Expr::Value(Value::Number(String::from("1"), false)),
+        })
+    }
+
     fn get_body(query: &Query) -> Result<&Select, ParserError> {
         match &query.body {
             SetExpr::Select(query) => Ok(query),
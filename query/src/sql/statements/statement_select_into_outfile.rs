@@ -0,0 +1,82 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::OutfileCompression;
+use common_planners::OutfileFormat;
+use common_planners::PlanNode;
+use common_planners::SelectIntoOutfilePlan;
+use common_tracing::tracing;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+use crate::sql::statements::DfQueryStatement;
+use crate::sql::DfStatement;
+use crate::sql::PlanParser;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfSelectIntoOutfile {
+    pub query: Box<DfQueryStatement>,
+    pub path: String,
+    pub format: String,
+    pub compression: String,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfSelectIntoOutfile {
+    #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        if self.path.is_empty() {
+            return Err(ErrorCode::SyntaxException(
+                "INTO OUTFILE path can not be empty",
+            ));
+        }
+
+        let format = match self.format.is_empty() {
+            true => OutfileFormat::Csv,
+            false => OutfileFormat::from_str(&self.format).map_err(ErrorCode::SyntaxException)?,
+        };
+
+        // A `.gz` or `.zst` file is compressed without an explicit COMPRESSION.
+        let compression = match self.compression.is_empty() {
+            true if self.path.ends_with(".gz") => OutfileCompression::Gzip,
+            true if self.path.ends_with(".zst") => OutfileCompression::Zstd,
+            _ => OutfileCompression::from_str(&self.compression)
+                .map_err(ErrorCode::SyntaxException)?,
+        };
+
+        // Parquet pages are compressed by the writer itself.
+        if format == OutfileFormat::Parquet && compression != OutfileCompression::None {
+            return Err(ErrorCode::SyntaxException(
+                "INTO OUTFILE FORMAT PARQUET can not be used with COMPRESSION",
+            ));
+        }
+
+        let statement = DfStatement::Query(self.query.clone());
+        let input = PlanParser::build_plan(vec![statement], ctx).await?;
+        Ok(AnalyzedResult::SimpleQuery(Box::new(
+            PlanNode::SelectIntoOutfile(SelectIntoOutfilePlan {
+                path: self.path.clone(),
+                format,
+                compression,
+                input: Arc::new(input),
+            }),
+        )))
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::RequireColumnsVisitor;
+use sqlparser::ast::Expr;
+use sqlparser::ast::FunctionArg;
+use sqlparser::ast::FunctionArgExpr;
+use sqlparser::ast::Ident;
+use sqlparser::ast::Statement;
+use sqlparser::ast::TableAlias;
+use sqlparser::ast::TableFactor;
+use sqlparser::ast::TableWithJoins;
+use sqlparser::ast::Value;
+use sqlparser::dialect::GenericDialect;
+use sqlparser::parser::Parser;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::analyzer_expr::ExpressionAnalyzer;
+use crate::sql::statements::query::JoinedSchemaAnalyzer;
+use crate::sql::statements::DfPivot;
+use crate::sql::statements::DfQueryStatement;
+
+impl DfQueryStatement {
+    /// Lower `FROM t PIVOT (agg(expr) FOR column IN (v1, v2, ...))` into
+    /// `FROM (SELECT <others>, aggIf(expr, column = v1) AS v1, ... FROM t GROUP BY <others>)`,
+    /// where `<others>` are the columns of `t` neither pivoted nor aggregated.
+    pub(crate) async fn lower_pivot(
+        &self,
+        pivot: &DfPivot,
+        ctx: Arc<QueryContext>,
+    ) -> Result<DfQueryStatement> {
+        if self.from.is_empty() {
+            return Err(ErrorCode::SyntaxException("PIVOT requires a FROM clause"));
+        }
+
+        let function = match &pivot.aggregate {
+            Expr::Function(function) if !function.distinct => function,
+            other => {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "PIVOT expects an aggregate function call, but got {}",
+                    other
+                )));
+            }
+        };
+
+        let analyzer = ExpressionAnalyzer::create(ctx.clone());
+        let mut args = Vec::with_capacity(function.args.len());
+        let mut aggregated_columns = vec![];
+        for arg in &function.args {
+            match arg {
+                FunctionArg::Unnamed(FunctionArgExpr::Wildcard) if function.args.len() == 1 => {}
+                FunctionArg::Unnamed(FunctionArgExpr::Expr(expr)) => {
+                    let expression = analyzer.analyze(expr).await?;
+                    aggregated_columns.extend(RequireColumnsVisitor::collect_columns_from_expr(
+                        &expression,
+                    )?);
+                    args.push(expr.to_string());
+                }
+                other => {
+                    return Err(ErrorCode::SyntaxException(format!(
+                        "Unsupported PIVOT aggregate argument {}",
+                        other
+                    )));
+                }
+            }
+        }
+
+        let joined_schema = JoinedSchemaAnalyzer::create(ctx).analyze(self).await?;
+        let schema = joined_schema.to_data_schema();
+        let pivot_column = pivot.column.value.as_str();
+        if schema.field_with_name(pivot_column).is_err() {
+            return Err(ErrorCode::UnknownColumn(format!(
+                "Unknown PIVOT column {}",
+                pivot_column
+            )));
+        }
+
+        let group_by = schema
+            .fields()
+            .iter()
+            .map(|field| field.name())
+            .filter(|name| *name != pivot_column && !aggregated_columns.contains(*name))
+            .map(|name| Ident::with_quote('"', name).to_string())
+            .collect::<Vec<_>>();
+
+        let params = match function.params.is_empty() {
+            true => String::new(),
+            false => format!(
+                "({})",
+                function
+                    .params
+                    .iter()
+                    .map(|param| param.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        };
+
+        let mut projection = group_by.clone();
+        for value in &pivot.values {
+            let name = match value {
+                Expr::Value(Value::SingleQuotedString(value)) => value.clone(),
+                other => other.to_string(),
+            };
+
+            let mut args = args.clone();
+            args.push(format!("{} = {}", pivot.column, value));
+            projection.push(format!(
+                "{}If{}({}) AS {}",
+                function.name,
+                params,
+                args.join(", "),
+                Ident::with_quote('"', name)
+            ));
+        }
+
+        let mut sql = format!(
+            "SELECT {} FROM {}",
+            projection.join(", "),
+            self.from
+                .iter()
+                .map(|table| table.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        if !group_by.is_empty() {
+            sql = format!("{} GROUP BY {}", sql, group_by.join(", "));
+        }
+
+        let subquery = match Parser::parse_sql(&GenericDialect {}, &sql)?.pop() {
+            Some(Statement::Query(query)) => query,
+            _ => {
+                return Err(ErrorCode::LogicalError(format!(
+                    "Cannot lower PIVOT into {}, it's a bug",
+                    sql
+                )));
+            }
+        };
+
+        let mut lowered = self.clone();
+        lowered.pivot = None;
+        lowered.from = vec![TableWithJoins {
+            relation: TableFactor::Derived {
+                lateral: false,
+                subquery,
+                alias: pivot.alias.clone().map(|name| TableAlias {
+                    name,
+                    columns: vec![],
+                }),
+            },
+            joins: vec![],
+        }];
+        Ok(lowered)
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use sqlparser::ast::Expr;
+use sqlparser::ast::Ident;
+use sqlparser::ast::SelectItem;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::query::JoinedSchemaAnalyzer;
+use crate::sql::statements::DfQueryStatement;
+
+impl DfQueryStatement {
+    /// Expand the `*` with EXCLUDE/REPLACE modifiers into the columns of the FROM clause, e.g.
+    /// `SELECT * EXCLUDE (b) REPLACE (c + 1 AS c) FROM t(a, b, c)` into `SELECT a, c + 1 AS c FROM t`.
+    pub(crate) async fn expand_wildcard_options(
+        &self,
+        ctx: Arc<QueryContext>,
+    ) -> Result<DfQueryStatement> {
+        let joined_schema = JoinedSchemaAnalyzer::create(ctx).analyze(self).await?;
+
+        let mut columns = vec![];
+        for table_desc in joined_schema.get_tables_desc() {
+            for column_desc in table_desc.get_columns_desc() {
+                let name = column_desc.short_name.clone();
+                let expr = match column_desc.is_ambiguity {
+                    true => Expr::CompoundIdentifier(
+                        table_desc
+                            .get_name_parts()
+                            .iter()
+                            .chain(std::iter::once(&name))
+                            .map(Ident::new)
+                            .collect(),
+                    ),
+                    false => Expr::Identifier(Ident::new(&name)),
+                };
+                columns.push((name, expr));
+            }
+        }
+
+        let replaced = self.wildcard_replace.iter().map(|(_, column)| column);
+        for (column, modifier) in self
+            .wildcard_exclude
+            .iter()
+            .map(|column| (column, "EXCLUDE"))
+            .chain(replaced.map(|column| (column, "REPLACE")))
+        {
+            if !columns.iter().any(|(name, _)| *name == column.value) {
+                return Err(ErrorCode::UnknownColumn(format!(
+                    "Unknown column {} in SELECT * {}",
+                    column, modifier
+                )));
+            }
+        }
+
+        let mut projection = Vec::with_capacity(self.projection.len() + columns.len());
+        for item in &self.projection {
+            if !matches!(item, SelectItem::Wildcard) {
+                projection.push(item.clone());
+                continue;
+            }
+
+            for (name, expr) in &columns {
+                if self.wildcard_exclude.iter().any(|c| c.value == *name) {
+                    continue;
+                }
+
+                match self.wildcard_replace.iter().find(|(_, c)| c.value == *name) {
+                    None => projection.push(SelectItem::UnnamedExpr(expr.clone())),
+                    Some((replacement, alias)) => projection.push(SelectItem::ExprWithAlias {
+                        expr: replacement.clone(),
+                        alias: alias.clone(),
+                    }),
+                }
+            }
+        }
+
+        if projection.is_empty() {
+            return Err(ErrorCode::SyntaxException(
+                "SELECT * EXCLUDE leaves no column to select",
+            ));
+        }
+
+        let mut expanded = self.clone();
+        expanded.projection = projection;
+        expanded.wildcard_exclude = vec![];
+        expanded.wildcard_replace = vec![];
+        Ok(expanded)
+    }
+}
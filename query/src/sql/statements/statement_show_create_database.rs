@@ -24,6 +24,7 @@ use sqlparser::ast::ObjectName;
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
+use crate::sql::SQLCommon;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfShowCreateDatabase {
@@ -32,10 +33,10 @@ pub struct DfShowCreateDatabase {
 
 #[async_trait::async_trait]
 impl AnalyzableStatement for DfShowCreateDatabase {
-    async fn analyze(&self, _ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
         Ok(AnalyzedResult::SimpleQuery(Box::new(
             PlanNode::ShowCreateDatabase(ShowCreateDatabasePlan {
-                db: self.database_name()?,
+                db: self.database_name(&ctx)?,
                 schema: Self::schema(),
             }),
         )))
@@ -50,13 +51,13 @@ impl DfShowCreateDatabase {
         ])
     }
 
-    fn database_name(&self) -> Result<String> {
+    fn database_name(&self, ctx: &QueryContext) -> Result<String> {
         if self.name.0.is_empty() {
             return Err(ErrorCode::SyntaxException(
                 "Show create database name is empty",
             ));
         }
 
-        Ok(self.name.0[0].value.clone())
+        SQLCommon::resolve_identifier(ctx, &self.name.0[0])
     }
 }
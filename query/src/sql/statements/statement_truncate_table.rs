@@ -24,9 +24,11 @@ use sqlparser::ast::ObjectName;
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
+use crate::sql::SQLCommon;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfTruncateTable {
+    pub if_exists: bool,
     pub name: ObjectName,
     pub purge: bool,
 }
@@ -38,6 +40,7 @@ impl AnalyzableStatement for DfTruncateTable {
         let (db, table) = self.resolve_table(ctx)?;
         Ok(AnalyzedResult::SimpleQuery(Box::new(
             PlanNode::TruncateTable(TruncateTablePlan {
+                if_exists: self.if_exists,
                 db,
                 table,
                 purge: self.purge,
@@ -54,8 +57,14 @@ impl DfTruncateTable {
         } = self;
         match idents.len() {
             0 => Err(ErrorCode::SyntaxException("Truncate table name is empty")),
-            1 => Ok((ctx.get_current_database(), idents[0].value.clone())),
-            2 => Ok((idents[0].value.clone(), idents[1].value.clone())),
+            1 => Ok((
+                ctx.get_current_database(),
+                SQLCommon::resolve_identifier(&ctx, &idents[0])?,
+            )),
+            2 => Ok((
+                SQLCommon::resolve_identifier(&ctx, &idents[0])?,
+                SQLCommon::resolve_identifier(&ctx, &idents[1])?,
+            )),
             _ => Err(ErrorCode::SyntaxException(
                 "Truncate table name must be [`db`].`table`",
             )),
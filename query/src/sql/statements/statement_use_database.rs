@@ -24,6 +24,7 @@ use sqlparser::ast::ObjectName;
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
+use crate::sql::SQLCommon;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfUseDatabase {
@@ -32,13 +33,13 @@ pub struct DfUseDatabase {
 
 #[async_trait::async_trait]
 impl AnalyzableStatement for DfUseDatabase {
-    #[tracing::instrument(level = "debug", skip(self, _ctx), fields(ctx.id = _ctx.get_id().as_str()))]
-    async fn analyze(&self, _ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+    #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
         if self.name.0.is_empty() {
             return Result::Err(ErrorCode::SyntaxException("Use database name is empty"));
         }
 
-        let db = self.name.0[0].value.clone();
+        let db = SQLCommon::resolve_identifier(&ctx, &self.name.0[0])?;
         Ok(AnalyzedResult::SimpleQuery(Box::new(
             PlanNode::UseDatabase(UseDatabasePlan { db }),
         )))
@@ -17,6 +17,7 @@ use std::collections::HashSet;
 use lazy_static::lazy_static;
 
 pub const OPT_KEY_DATABASE_ID: &str = "database_id";
+pub const OPT_KEY_COMMENT: &str = "comment";
 
 lazy_static! {
     pub static ref RESERVED_TABLE_OPTION_KEYS: HashSet<&'static str> = {
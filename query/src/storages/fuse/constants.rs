@@ -16,6 +16,8 @@ pub const FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD: &str = "block_size_threshold
 pub const FUSE_OPT_KEY_BLOCK_PER_SEGMENT: &str = "block_per_segment";
 pub const FUSE_OPT_KEY_ROW_PER_BLOCK: &str = "row_per_block";
 pub const FUSE_OPT_KEY_SNAPSHOT_LOC: &str = "snapshot_loc";
+/// The comma separated columns the loaded data is sorted by, see `COPY ... SORT_BY_CLUSTER_KEY`.
+pub const FUSE_OPT_KEY_CLUSTER_KEY: &str = "cluster_key";
 
 pub const FUSE_TBL_BLOCK_PREFIX: &str = "_b";
 pub const FUSE_TBL_SEGMENT_PREFIX: &str = "_sg";
@@ -24,3 +26,10 @@ pub const FUSE_TBL_SNAPSHOT_PREFIX: &str = "_ss";
 pub const DEFAULT_BLOCK_PER_SEGMENT: usize = 1000;
 pub const DEFAULT_ROW_PER_BLOCK: usize = 1000 * 1000;
 pub const DEFAULT_BLOCK_SIZE_IN_MEM_SIZE_THRESHOLD: usize = 100 * 1024 * 1024;
+/// The number of blocks of loaded rows sorted together by the cluster key,
+/// see `BlockStreamWriter::sort_block_stream`.
+pub const DEFAULT_BLOCKS_PER_CLUSTER_KEY_SORT: usize = 8;
+
+/// The max length in bytes of the min/max statistics of the string columns, the longer ones are
+/// cut to their prefixes, see `statistics::string_min_prefix` and `statistics::string_max_prefix`.
+pub const STATS_STRING_PREFIX_LEN: usize = 16;
@@ -42,6 +42,7 @@ use crate::storages::fuse::FUSE_OPT_KEY_SNAPSHOT_LOC;
 use crate::storages::StorageContext;
 use crate::storages::StorageDescription;
 use crate::storages::Table;
+use crate::storages::TableStatistics;
 
 #[derive(Clone)]
 pub struct FuseTable {
@@ -102,6 +103,10 @@ impl Table for FuseTable {
         true
     }
 
+    fn support_sampling(&self) -> bool {
+        true
+    }
+
     #[tracing::instrument(level = "debug", name = "fuse_table_read_partitions", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     async fn read_partitions(
         &self,
@@ -170,6 +175,30 @@ impl Table for FuseTable {
     async fn optimize(&self, ctx: Arc<QueryContext>, keep_last_snapshot: bool) -> Result<()> {
         self.do_optimize(ctx, keep_last_snapshot).await
     }
+
+    async fn statistics(&self, ctx: Arc<QueryContext>) -> Result<Option<TableStatistics>> {
+        let reader = MetaReaders::table_snapshot_reader(ctx.as_ref());
+        let snapshots = reader
+            .read_snapshot_history(
+                self.snapshot_loc().as_ref(),
+                self.snapshot_format_version(),
+                self.meta_location_generator.clone(),
+            )
+            .await?;
+
+        // the history starts from the latest snapshot
+        let mut stats = TableStatistics {
+            number_of_snapshots: Some(snapshots.len() as u64),
+            ..Default::default()
+        };
+        if let Some(latest) = snapshots.first() {
+            stats.num_rows = latest.summary.row_count;
+            stats.data_bytes = latest.summary.uncompressed_byte_size;
+            stats.compressed_data_bytes = latest.summary.compressed_byte_size;
+        }
+        // the range indexes are kept in the segments, there are no index files to count yet
+        Ok(Some(stats))
+    }
 }
 
 impl FuseTable {
@@ -0,0 +1,168 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use aes_gcm::aead::Aead;
+use aes_gcm::aead::NewAead;
+use aes_gcm::Aes256Gcm;
+use aes_gcm::Key;
+use aes_gcm::Nonce;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use parquet_format_async_temp::FileMetaData;
+use rand::RngCore;
+
+/// Leading bytes of an encrypted object, json encoded meta and parquet column chunks (which
+/// start with a thrift page header) never start with them.
+const ENCRYPTED_META_MAGIC: &[u8; 4] = b"DBE1";
+const NONCE_LEN: usize = 12;
+const KEY_LEN: usize = 32;
+
+/// Client-side AES-256-GCM encryption of the table data: the meta (snapshots and segments)
+/// and the column chunks of the blocks.
+///
+/// Encrypted objects are laid out as `magic | nonce | ciphertext`, a random nonce is
+/// generated for every object. Objects written before the key was configured are plain
+/// and are still readable.
+///
+/// The cipher is created once with the storage operator, see `SessionManager::get_meta_cipher`.
+#[derive(Clone)]
+pub struct MetaCipher {
+    cipher: Aes256Gcm,
+}
+
+impl MetaCipher {
+    /// Create the cipher from a base64 encoded 256-bit key, an empty key disables encryption.
+    pub fn try_create(encoded_key: &str) -> Result<Option<MetaCipher>> {
+        if encoded_key.is_empty() {
+            return Ok(None);
+        }
+
+        let key = base64::decode(encoded_key).map_err(|e| {
+            ErrorCode::InvalidConfig(format!("Storage encryption key must be base64: {}", e))
+        })?;
+
+        if key.len() != KEY_LEN {
+            return Err(ErrorCode::InvalidConfig(format!(
+                "Storage encryption key must be {} bytes, but got {} bytes",
+                KEY_LEN,
+                key.len()
+            )));
+        }
+
+        Ok(Some(MetaCipher {
+            cipher: Aes256Gcm::new(Key::from_slice(&key)),
+        }))
+    }
+
+    pub fn encrypt(&self, plain: &[u8]) -> Result<Vec<u8>> {
+        let mut nonce = [0u8; NONCE_LEN];
+        rand::thread_rng().fill_bytes(&mut nonce);
+
+        let ciphertext = self
+            .cipher
+            .encrypt(Nonce::from_slice(&nonce), plain)
+            .map_err(|_| ErrorCode::BadBytes("Cannot encrypt table data"))?;
+
+        let mut encrypted =
+            Vec::with_capacity(ENCRYPTED_META_MAGIC.len() + NONCE_LEN + ciphertext.len());
+        encrypted.extend_from_slice(ENCRYPTED_META_MAGIC);
+        encrypted.extend_from_slice(&nonce);
+        encrypted.extend_from_slice(&ciphertext);
+        Ok(encrypted)
+    }
+
+    pub fn decrypt(&self, encrypted: &[u8]) -> Result<Vec<u8>> {
+        let header_len = ENCRYPTED_META_MAGIC.len() + NONCE_LEN;
+        if encrypted.len() < header_len || !Self::is_encrypted(encrypted) {
+            return Err(ErrorCode::BadBytes("Table data is not encrypted"));
+        }
+
+        let nonce = Nonce::from_slice(&encrypted[ENCRYPTED_META_MAGIC.len()..header_len]);
+        self.cipher
+            .decrypt(nonce, &encrypted[header_len..])
+            .map_err(|_| {
+                ErrorCode::BadBytes("Cannot decrypt table data, the encryption key may be wrong")
+            })
+    }
+
+    pub fn is_encrypted(bytes: &[u8]) -> bool {
+        bytes.starts_with(ENCRYPTED_META_MAGIC)
+    }
+}
+
+/// Encrypt the serialized meta if a cipher is configured.
+pub fn encode_meta(cipher: Option<&MetaCipher>, bytes: Vec<u8>) -> Result<Vec<u8>> {
+    match cipher {
+        None => Ok(bytes),
+        Some(cipher) => cipher.encrypt(&bytes),
+    }
+}
+
+/// Decrypt the meta, or a column chunk of a block, if it was written encrypted, plain ones are
+/// passed through.
+pub fn decode_meta(cipher: Option<&MetaCipher>, bytes: Vec<u8>) -> Result<Vec<u8>> {
+    if !MetaCipher::is_encrypted(&bytes) {
+        return Ok(bytes);
+    }
+
+    match cipher {
+        Some(cipher) => cipher.decrypt(&bytes),
+        None => Err(ErrorCode::BadBytes(
+            "Table data is encrypted, but no storage encryption key is configured",
+        )),
+    }
+}
+
+/// Encrypt the column chunks of the parquet block file in `buf` if a cipher is configured.
+///
+/// Every column chunk is encrypted on its own so that the columns are still read by range,
+/// the encrypted chunks are laid out one after the other and the offsets and sizes of the
+/// columns in `file_meta` are updated to them. The parquet footer is dropped, the blocks are
+/// read with the column metas kept in the segments.
+pub fn encode_block(
+    cipher: Option<&MetaCipher>,
+    buf: Vec<u8>,
+    file_meta: &mut FileMetaData,
+) -> Result<Vec<u8>> {
+    let cipher = match cipher {
+        None => return Ok(buf),
+        Some(cipher) => cipher,
+    };
+
+    let mut encrypted = Vec::with_capacity(buf.len());
+    for row_group in file_meta.row_groups.iter_mut() {
+        for column in row_group.columns.iter_mut() {
+            let meta = column.meta_data.as_mut().ok_or_else(|| {
+                ErrorCode::ParquetError("invalid parquet file, meta data of a column is empty")
+            })?;
+            let start = meta.dictionary_page_offset.unwrap_or(meta.data_page_offset) as usize;
+            let end = start + meta.total_compressed_size as usize;
+            if end > buf.len() {
+                return Err(ErrorCode::ParquetError(format!(
+                    "invalid parquet file, column chunk {}..{} is out of the file of {} bytes",
+                    start,
+                    end,
+                    buf.len()
+                )));
+            }
+
+            let chunk = cipher.encrypt(&buf[start..end])?;
+            meta.dictionary_page_offset = None;
+            meta.data_page_offset = encrypted.len() as i64;
+            meta.total_compressed_size = chunk.len() as i64;
+            encrypted.extend_from_slice(&chunk);
+        }
+    }
+    Ok(encrypted)
+}
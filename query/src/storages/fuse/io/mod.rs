@@ -13,10 +13,15 @@
 // limitations under the License.
 
 mod locations;
+mod meta_cipher;
 mod read;
 mod write;
 
 pub use locations::TableMetaLocationGenerator;
+pub use meta_cipher::decode_meta;
+pub use meta_cipher::encode_block;
+pub use meta_cipher::encode_meta;
+pub use meta_cipher::MetaCipher;
 pub use read::BlockReader;
 pub use read::MetaReaders;
 pub use read::SegmentInfoReader;
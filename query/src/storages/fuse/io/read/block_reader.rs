@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::ops::Range;
 use std::sync::Arc;
 
+use common_arrow::arrow::datatypes::DataType as ArrowType;
 use common_arrow::arrow::datatypes::Field;
 use common_arrow::arrow::datatypes::Schema;
 use common_arrow::arrow::io::parquet::read::column_iter_to_arrays;
@@ -26,6 +28,8 @@ use common_arrow::parquet::metadata::SchemaDescriptor;
 use common_arrow::parquet::read::BasicDecompressor;
 use common_arrow::parquet::read::PageIterator;
 use common_datablocks::DataBlock;
+use common_datavalues::DataField;
+use common_datavalues::DataSchema;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -41,135 +45,262 @@ use opendal::Reader;
 
 use crate::storages::fuse::fuse_part::ColumnMeta;
 use crate::storages::fuse::fuse_part::FusePartInfo;
+use crate::storages::fuse::io::decode_meta;
+use crate::storages::fuse::io::MetaCipher;
 
 #[derive(Clone)]
 pub struct BlockReader {
     operator: Operator,
-    projection: Vec<usize>,
-    arrow_schema: Arc<Schema>,
+    cipher: Option<Arc<MetaCipher>>,
     projected_schema: DataSchemaRef,
     parquet_schema_descriptor: SchemaDescriptor,
+    /// The projected arrow fields, a nested projection keeps only the projected fields
+    /// of the structs on its path.
+    projected_fields: Vec<Field>,
+    /// The parquet leaf columns of every projected field.
+    projected_leaves: Vec<Vec<usize>>,
 }
 
 impl BlockReader {
     pub fn create(
         operator: Operator,
+        cipher: Option<Arc<MetaCipher>>,
         schema: DataSchemaRef,
         projection: Vec<usize>,
     ) -> Result<Arc<BlockReader>> {
-        let projected_schema = DataSchemaRef::new(schema.project(projection.clone()));
+        let paths = projection.into_iter().map(|index| vec![index]).collect();
+        Self::create_with_paths(operator, cipher, schema, paths)
+    }
 
+    /// Creates a reader of nested fields, a path starts with the index of a field in the schema,
+    /// followed by the indices of the fields in the structs inside it, e.g. `[2, 0]` reads only
+    /// the first field of the struct column 2. Only the parquet columns on the paths are read.
+    pub fn create_with_paths(
+        operator: Operator,
+        cipher: Option<Arc<MetaCipher>>,
+        schema: DataSchemaRef,
+        paths: Vec<Vec<usize>>,
+    ) -> Result<Arc<BlockReader>> {
         let arrow_schema = schema.to_arrow();
         let parquet_schema_descriptor = to_parquet_schema(&arrow_schema)?;
+        let leaf_columns = Self::leaf_columns(&arrow_schema);
+
+        let mut projected_fields = Vec::with_capacity(paths.len());
+        let mut projected_leaves = Vec::with_capacity(paths.len());
+        let mut fields = Vec::with_capacity(paths.len());
+        for path in &paths {
+            let index = match path.first() {
+                Some(index) if *index < arrow_schema.fields.len() => *index,
+                _ => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Invalid projection path {:?} of schema {:?}",
+                        path, schema
+                    )))
+                }
+            };
+
+            let (field, leaves) = Self::prune_field(&arrow_schema.fields[index], &path[1..])?;
+            let leaves = leaves
+                .into_iter()
+                .map(|leaf| leaf_columns[index].start + leaf)
+                .collect();
+            fields.push(match path.len() {
+                1 => schema.field(index).clone(),
+                _ => DataField::from(&field),
+            });
+            projected_fields.push(field);
+            projected_leaves.push(leaves);
+        }
+
         Ok(Arc::new(BlockReader {
             operator,
-            projection,
-            projected_schema,
+            cipher,
+            projected_schema: Arc::new(DataSchema::new(fields)),
             parquet_schema_descriptor,
-            arrow_schema: Arc::new(arrow_schema),
+            projected_fields,
+            projected_leaves,
         }))
     }
 
+    /// The parquet leaf columns of every field in the schema, a nested field (list or struct)
+    /// is stored as one leaf column for every primitive field inside it.
+    pub fn leaf_columns(schema: &Schema) -> Vec<Range<usize>> {
+        let mut start = 0;
+        schema
+            .fields
+            .iter()
+            .map(|field| {
+                let range = start..start + Self::leaf_count(field.data_type());
+                start = range.end;
+                range
+            })
+            .collect()
+    }
+
+    fn leaf_count(data_type: &ArrowType) -> usize {
+        match data_type {
+            ArrowType::Struct(fields) => {
+                fields.iter().map(|f| Self::leaf_count(f.data_type())).sum()
+            }
+            ArrowType::List(inner)
+            | ArrowType::LargeList(inner)
+            | ArrowType::FixedSizeList(inner, _)
+            | ArrowType::Map(inner, _) => Self::leaf_count(inner.data_type()),
+            ArrowType::Extension(_, inner, _) => Self::leaf_count(inner),
+            _ => 1,
+        }
+    }
+
+    /// Prune the field to the nested field at the path, the structs on the path keep
+    /// only the field on the path. Returns the pruned field and its leaf columns relative
+    /// to the leaf columns of the field.
+    fn prune_field(field: &Field, path: &[usize]) -> Result<(Field, Vec<usize>)> {
+        let (child, rest) = match path.split_first() {
+            None => {
+                let leaves = (0..Self::leaf_count(field.data_type())).collect();
+                return Ok((field.clone(), leaves));
+            }
+            Some(split) => split,
+        };
+
+        match field.data_type() {
+            ArrowType::Struct(children) if *child < children.len() => {
+                let offset: usize = children[..*child]
+                    .iter()
+                    .map(|f| Self::leaf_count(f.data_type()))
+                    .sum();
+                let (pruned, leaves) = Self::prune_field(&children[*child], rest)?;
+                let pruned = Field::new(
+                    &field.name,
+                    ArrowType::Struct(vec![pruned]),
+                    field.is_nullable,
+                )
+                .with_metadata(field.metadata.clone());
+                Ok((
+                    pruned,
+                    leaves.into_iter().map(|leaf| offset + leaf).collect(),
+                ))
+            }
+            _ => Err(ErrorCode::BadArguments(format!(
+                "Can not project the nested field {} of {:?}",
+                child, field
+            ))),
+        }
+    }
+
     fn to_deserialize(
-        meta: &ColumnMeta,
-        chunk: Vec<u8>,
+        metas: Vec<&ColumnMeta>,
+        chunks: Vec<Vec<u8>>,
         rows: usize,
-        descriptor: &ColumnDescriptor,
+        descriptors: Vec<&ColumnDescriptor>,
         field: Field,
     ) -> Result<ArrayIter<'static>> {
-        let pages = PageIterator::new(
-            std::io::Cursor::new(chunk),
-            meta.num_values as i64,
-            Compression::Lz4,
-            descriptor.clone(),
-            Arc::new(|_, _| true),
-            vec![],
-        );
-
-        let descriptor_type = descriptor.type_();
-        let decompressor = BasicDecompressor::new(pages, vec![]);
+        let mut decompressors = Vec::with_capacity(chunks.len());
+        let mut descriptor_types = Vec::with_capacity(chunks.len());
+        for ((meta, chunk), descriptor) in metas.into_iter().zip(chunks).zip(descriptors) {
+            let pages = PageIterator::new(
+                std::io::Cursor::new(chunk),
+                meta.num_values as i64,
+                Compression::Lz4,
+                descriptor.clone(),
+                Arc::new(|_, _| true),
+                vec![],
+            );
+
+            descriptor_types.push(descriptor.type_());
+            decompressors.push(BasicDecompressor::new(pages, vec![]));
+        }
+
         Ok(column_iter_to_arrays(
-            vec![decompressor],
-            vec![descriptor_type],
+            decompressors,
+            descriptor_types,
             field,
             rows,
         )?)
     }
 
+    /// Deserialize the chunks of the leaf columns of every projected field.
+    fn to_array_iters(
+        &self,
+        part: &FusePartInfo,
+        chunks: Vec<Vec<u8>>,
+    ) -> Result<Vec<ArrayIter<'static>>> {
+        let mut chunks = chunks.into_iter();
+        let mut columns_array_iter = Vec::with_capacity(self.projected_fields.len());
+        for (field, leaves) in self
+            .projected_fields
+            .iter()
+            .zip(self.projected_leaves.iter())
+        {
+            let metas = leaves.iter().map(|leaf| &part.columns_meta[leaf]).collect();
+            let descriptors = leaves
+                .iter()
+                .map(|leaf| self.parquet_schema_descriptor.column(*leaf))
+                .collect();
+            let field_chunks = chunks.by_ref().take(leaves.len()).collect();
+            columns_array_iter.push(Self::to_deserialize(
+                metas,
+                field_chunks,
+                part.nums_rows,
+                descriptors,
+                field.clone(),
+            )?);
+        }
+        Ok(columns_array_iter)
+    }
+
+    fn leaves(&self) -> impl Iterator<Item = &usize> {
+        self.projected_leaves.iter().flatten()
+    }
+
     async fn read_columns(&self, part: PartInfoPtr) -> Result<(usize, Vec<ArrayIter<'static>>)> {
         let part = FusePartInfo::from_part(&part)?;
 
         let rows = part.nums_rows;
         // TODO: add prefetch column data.
-        let num_cols = self.projection.len();
+        let num_cols = self.leaves().count();
         let mut column_chunk_futs = Vec::with_capacity(num_cols);
-        let mut col_idx = Vec::with_capacity(num_cols);
-        for index in &self.projection {
+        for index in self.leaves() {
             let column_meta = &part.columns_meta[index];
             let mut column_reader = self
                 .operator
                 .object(&part.location)
                 .range_reader(column_meta.offset, column_meta.length);
+            let cipher = self.cipher.as_deref();
             let fut = async move {
                 // NOTE: move chunk inside future so that alloc only
                 // happen when future is ready to go.
                 let mut column_chunk = vec![0; column_meta.length as usize];
-                column_reader.read_exact(&mut column_chunk).await?;
-                Ok::<_, ErrorCode>(column_chunk)
+                column_reader
+                    .read_exact(&mut column_chunk)
+                    .await
+                    .map_err(|e| ErrorCode::DalTransportError(e.to_string()))?;
+                decode_meta(cipher, column_chunk)
             }
             .instrument(debug_span!("read_col_chunk"));
             column_chunk_futs.push(fut);
-            col_idx.push(index);
         }
 
         let chunks = futures::stream::iter(column_chunk_futs)
             .buffered(std::cmp::min(10, num_cols))
             .try_collect::<Vec<_>>()
-            .await
-            .map_err(|e| ErrorCode::DalTransportError(e.to_string()))?;
-
-        let mut columns_array_iter = Vec::with_capacity(num_cols);
-        for (i, column_chunk) in chunks.into_iter().enumerate() {
-            let idx = *col_idx[i];
-            let field = self.arrow_schema.fields[idx].clone();
-            let column_descriptor = self.parquet_schema_descriptor.column(idx);
-            let column_meta = &part.columns_meta[&idx];
-            columns_array_iter.push(Self::to_deserialize(
-                column_meta,
-                column_chunk,
-                rows,
-                column_descriptor,
-                field,
-            )?);
-        }
+            .await?;
 
+        let columns_array_iter = self.to_array_iters(part, chunks)?;
         Ok((rows, columns_array_iter))
     }
 
+    /// The chunks are the ones of `read_columns_data`, one for every leaf column.
     pub fn deserialize(&self, part: PartInfoPtr, chunks: Vec<Vec<u8>>) -> Result<DataBlock> {
-        if self.projection.len() != chunks.len() {
+        if self.leaves().count() != chunks.len() {
             return Err(ErrorCode::LogicalError(
                 "Columns chunk len must be equals projections len.",
             ));
         }
 
         let part = FusePartInfo::from_part(&part)?;
-        let mut columns_array_iter = Vec::with_capacity(self.projection.len());
-
         let num_rows = part.nums_rows;
-        for (index, column_chunk) in chunks.into_iter().enumerate() {
-            let index = self.projection[index];
-            let field = self.arrow_schema.fields[index].clone();
-            let column_descriptor = self.parquet_schema_descriptor.column(index);
-            let column_meta = &part.columns_meta[&index];
-            columns_array_iter.push(Self::to_deserialize(
-                column_meta,
-                column_chunk,
-                num_rows,
-                column_descriptor,
-                field,
-            )?);
-        }
+        let columns_array_iter = self.to_array_iters(part, chunks)?;
 
         let mut deserializer = RowGroupDeserializer::new(columns_array_iter, num_rows, None);
 
@@ -182,9 +313,9 @@ impl BlockReader {
 
     pub async fn read_columns_data(&self, part: PartInfoPtr) -> Result<Vec<Vec<u8>>> {
         let part = FusePartInfo::from_part(&part)?;
-        let mut join_handlers = Vec::with_capacity(self.projection.len());
+        let mut join_handlers = Vec::with_capacity(self.leaves().count());
 
-        for index in &self.projection {
+        for index in self.leaves() {
             let column_meta = &part.columns_meta[index];
 
             let column_reader = self
@@ -193,18 +324,26 @@ impl BlockReader {
                 .range_reader(column_meta.offset, column_meta.length);
 
             let column_chunk = vec![0; column_meta.length as usize];
-            join_handlers.push(Self::read_column(column_reader, column_chunk));
+            join_handlers.push(Self::read_column(
+                column_reader,
+                column_chunk,
+                self.cipher.clone(),
+            ));
         }
 
         futures::future::try_join_all(join_handlers).await
     }
 
-    async fn read_column(mut column_reader: Reader, mut chunk: Vec<u8>) -> Result<Vec<u8>> {
+    async fn read_column(
+        mut column_reader: Reader,
+        mut chunk: Vec<u8>,
+        cipher: Option<Arc<MetaCipher>>,
+    ) -> Result<Vec<u8>> {
         let handler = common_base::tokio::spawn(async move {
             tracing::debug!("read_exact | Begin, {:?}", std::thread::current());
             column_reader.read_exact(&mut chunk).await?;
             tracing::debug!("read_exact | End, {:?}", std::thread::current());
-            Result::Ok(chunk)
+            decode_meta(cipher.as_deref(), chunk)
         });
 
         match handler.await {
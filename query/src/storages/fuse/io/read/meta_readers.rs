@@ -26,6 +26,7 @@ use super::cached_reader::Loader;
 use super::versioned_reader::VersionedReader;
 use crate::sessions::QueryContext;
 use crate::storages::fuse::cache::TenantLabel;
+use crate::storages::fuse::io::MetaCipher;
 use crate::storages::fuse::io::TableMetaLocationGenerator;
 use crate::storages::fuse::meta::SegmentInfo;
 use crate::storages::fuse::meta::SegmentInfoVersion;
@@ -39,6 +40,8 @@ use crate::storages::fuse::meta::TableSnapshot;
 #[async_trait::async_trait]
 pub trait BufReaderProvider {
     async fn buf_reader(&self, path: &str, len: Option<u64>) -> Result<BufReader<Reader>>;
+
+    fn meta_cipher(&self) -> Option<Arc<MetaCipher>>;
 }
 
 pub type SegmentInfoReader<'a> = CachedReader<SegmentInfo, &'a QueryContext>;
@@ -112,8 +115,9 @@ where T: BufReaderProvider + Sync
         version: u64,
     ) -> Result<TableSnapshot> {
         let version = SnapshotVersion::try_from(version)?;
+        let cipher = self.meta_cipher();
         let reader = self.buf_reader(key, length_hint).await?;
-        version.read(reader).await
+        version.read(reader, cipher.as_deref()).await
     }
 }
 
@@ -123,8 +127,9 @@ where T: BufReaderProvider + Sync
 {
     async fn load(&self, key: &str, length_hint: Option<u64>, version: u64) -> Result<SegmentInfo> {
         let version = SegmentInfoVersion::try_from(version)?;
+        let cipher = self.meta_cipher();
         let reader = self.buf_reader(key, length_hint).await?;
-        version.read(reader).await
+        version.read(reader, cipher.as_deref()).await
     }
 }
 
@@ -150,6 +155,10 @@ impl BufReaderProvider for &QueryContext {
         let read_buffer_size = self.get_settings().get_storage_read_buffer_size()?;
         Ok(BufReader::with_capacity(read_buffer_size as usize, reader))
     }
+
+    fn meta_cipher(&self) -> Option<Arc<MetaCipher>> {
+        self.get_meta_cipher()
+    }
 }
 
 impl HasTenantLabel for &QueryContext {
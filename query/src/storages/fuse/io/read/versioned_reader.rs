@@ -21,6 +21,8 @@ use futures::AsyncRead;
 use serde::de::DeserializeOwned;
 use serde_json::from_slice;
 
+use crate::storages::fuse::io::decode_meta;
+use crate::storages::fuse::io::MetaCipher;
 use crate::storages::fuse::meta::SegmentInfo;
 use crate::storages::fuse::meta::SegmentInfoVersion;
 use crate::storages::fuse::meta::SnapshotVersion;
@@ -28,17 +30,17 @@ use crate::storages::fuse::meta::TableSnapshot;
 
 #[async_trait::async_trait]
 pub trait VersionedReader<T> {
-    async fn read<R>(&self, read: R) -> Result<T>
+    async fn read<R>(&self, read: R, cipher: Option<&MetaCipher>) -> Result<T>
     where R: AsyncRead + Unpin + Send;
 }
 
 #[async_trait::async_trait]
 impl VersionedReader<TableSnapshot> for SnapshotVersion {
-    async fn read<R>(&self, reader: R) -> Result<TableSnapshot>
+    async fn read<R>(&self, reader: R, cipher: Option<&MetaCipher>) -> Result<TableSnapshot>
     where R: AsyncRead + Unpin + Send {
         let r = match self {
-            SnapshotVersion::V1(v) => load(reader, v).await?,
-            SnapshotVersion::V0(v) => load(reader, v).await?.into(),
+            SnapshotVersion::V1(v) => load(reader, cipher, v).await?,
+            SnapshotVersion::V0(v) => load(reader, cipher, v).await?.into(),
         };
         Ok(r)
     }
@@ -46,17 +48,17 @@ impl VersionedReader<TableSnapshot> for SnapshotVersion {
 
 #[async_trait::async_trait]
 impl VersionedReader<SegmentInfo> for SegmentInfoVersion {
-    async fn read<R>(&self, reader: R) -> Result<SegmentInfo>
+    async fn read<R>(&self, reader: R, cipher: Option<&MetaCipher>) -> Result<SegmentInfo>
     where R: AsyncRead + Unpin + Send {
         let r = match self {
-            SegmentInfoVersion::V1(v) => load(reader, v).await?,
-            SegmentInfoVersion::V0(v) => load(reader, v).await?.into(),
+            SegmentInfoVersion::V1(v) => load(reader, cipher, v).await?,
+            SegmentInfoVersion::V0(v) => load(reader, cipher, v).await?.into(),
         };
         Ok(r)
     }
 }
 
-async fn load<R, T>(mut reader: R, _v: &PhantomData<T>) -> Result<T>
+async fn load<R, T>(mut reader: R, cipher: Option<&MetaCipher>, _v: &PhantomData<T>) -> Result<T>
 where
     T: DeserializeOwned,
     R: AsyncRead + Unpin + Send,
@@ -71,5 +73,6 @@ where
             ErrorCode::DalTransportError(msg)
         }
     })?;
+    let buffer = decode_meta(cipher, buffer)?;
     Ok(from_slice::<T>(&buffer)?)
 }
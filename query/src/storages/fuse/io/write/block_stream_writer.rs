@@ -15,7 +15,9 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use async_stream::try_stream;
 use common_datablocks::DataBlock;
+use common_datablocks::SortColumnDescription;
 use common_datavalues::DataSchema;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -28,6 +30,7 @@ use opendal::Operator;
 use parquet_format_async_temp::FileMetaData;
 
 use super::block_writer;
+use crate::storages::fuse::io::MetaCipher;
 use crate::storages::fuse::io::TableMetaLocationGenerator;
 use crate::storages::fuse::meta::ColumnId;
 use crate::storages::fuse::meta::ColumnMeta;
@@ -45,6 +48,7 @@ pub struct BlockStreamWriter {
     number_of_blocks_accumulated: usize,
     statistics_accumulator: Option<StatisticsAccumulator>,
     meta_locations: TableMetaLocationGenerator,
+    cipher: Option<Arc<MetaCipher>>,
 }
 
 impl BlockStreamWriter {
@@ -55,6 +59,7 @@ impl BlockStreamWriter {
         row_per_block: usize,
         block_per_segment: usize,
         meta_locations: TableMetaLocationGenerator,
+        cipher: Option<Arc<MetaCipher>>,
     ) -> SegmentInfoStream {
         // filter out empty blocks
         let block_stream =
@@ -75,17 +80,67 @@ impl BlockStreamWriter {
             data_accessor,
             data_schema,
             meta_locations,
+            cipher,
         );
         let segments = Self::transform(Box::pin(block_stream), block_writer);
 
         Box::pin(segments)
     }
 
+    /// Sort the rows of the stream in windows of `blocks_per_sort` blocks of `row_per_block` rows,
+    /// and split each sorted window into the blocks to write.
+    ///
+    /// The written blocks of a window divide its range of the sort columns (e.g. the cluster key
+    /// of the table) between them, so their min/max statistics are narrow and prune well.
+    /// Sorting the blocks one by one would not narrow them, the min/max of a block don't depend on
+    /// the order of its rows.
+    pub fn sort_block_stream(
+        mut block_stream: SendableDataBlockStream,
+        row_per_block: usize,
+        blocks_per_sort: usize,
+        sort_columns: Vec<SortColumnDescription>,
+    ) -> SendableDataBlockStream {
+        let rows_per_sort = row_per_block * blocks_per_sort;
+        Box::pin(try_stream! {
+            let mut window = vec![];
+            let mut window_rows = 0;
+            while let Some(block) = block_stream.next().await {
+                let block = block?;
+                window_rows += block.num_rows();
+                window.push(block);
+                if window_rows >= rows_per_sort {
+                    let window = std::mem::take(&mut window);
+                    window_rows = 0;
+                    for block in Self::sort_window(window, row_per_block, &sort_columns)? {
+                        yield block;
+                    }
+                }
+            }
+            for block in Self::sort_window(window, row_per_block, &sort_columns)? {
+                yield block;
+            }
+        })
+    }
+
+    fn sort_window(
+        window: Vec<DataBlock>,
+        row_per_block: usize,
+        sort_columns: &[SortColumnDescription],
+    ) -> Result<Vec<DataBlock>> {
+        if window.iter().all(|block| block.num_rows() == 0) {
+            return Ok(vec![]);
+        }
+        let merged = DataBlock::concat_blocks(&window)?;
+        let sorted = DataBlock::sort_block(&merged, sort_columns, None)?;
+        DataBlock::split_block_by_size(&sorted, row_per_block)
+    }
+
     pub fn new(
         num_block_threshold: usize,
         data_accessor: Operator,
         data_schema: Arc<DataSchema>,
         meta_locations: TableMetaLocationGenerator,
+        cipher: Option<Arc<MetaCipher>>,
     ) -> Self {
         Self {
             num_block_threshold,
@@ -94,6 +149,7 @@ impl BlockStreamWriter {
             number_of_blocks_accumulated: 0,
             statistics_accumulator: None,
             meta_locations,
+            cipher,
         }
     }
 
@@ -130,9 +186,14 @@ impl BlockStreamWriter {
         let partial_acc = acc.begin(&block)?;
         let schema = block.schema().to_arrow();
         let location = self.meta_locations.gen_block_location();
-        let (file_size, file_meta_data) =
-            block_writer::write_block(&schema, block, self.data_accessor.clone(), &location)
-                .await?;
+        let (file_size, file_meta_data) = block_writer::write_block(
+            &schema,
+            block,
+            self.data_accessor.clone(),
+            &location,
+            self.cipher.as_deref(),
+        )
+        .await?;
         let col_metas = Self::column_metas(&file_meta_data)?;
         acc = partial_acc.end(file_size, location, col_metas);
         self.number_of_blocks_accumulated += 1;
@@ -25,14 +25,18 @@ use common_exception::Result;
 use opendal::Operator;
 use parquet_format_async_temp::FileMetaData;
 
+use crate::storages::fuse::io::encode_block;
+use crate::storages::fuse::io::MetaCipher;
+
 pub async fn write_block(
     arrow_schema: &ArrowSchema,
     block: DataBlock,
     data_accessor: Operator,
     location: &str,
+    cipher: Option<&MetaCipher>,
 ) -> Result<(u64, FileMetaData)> {
     let options = WriteOptions {
-        write_statistics: false,
+        write_statistics: true,
         compression: Compression::Lz4, // let's begin with lz4
         version: Version::V2,
     };
@@ -49,10 +53,12 @@ pub async fn write_block(
     // we need a configuration of block size threshold here
     let mut buf = Vec::with_capacity(100 * 1024 * 1024);
 
-    let result =
+    let (_, mut file_meta) =
         common_arrow::write_parquet_file(&mut buf, row_groups, arrow_schema.clone(), options)
             .map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
 
+    let buf = encode_block(cipher, buf, &mut file_meta)?;
+    let file_size = buf.len() as u64;
     data_accessor
         .object(location)
         .writer()
@@ -60,7 +66,7 @@ pub async fn write_block(
         .await
         .map_err(|e| ErrorCode::DalTransportError(e.to_string()))?;
 
-    Ok(result)
+    Ok((file_size, file_meta))
 }
 
 fn col_encoding(_data_type: &ArrowDataType) -> Encoding {
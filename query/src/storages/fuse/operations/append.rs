@@ -24,6 +24,7 @@ use common_streams::SendableDataBlockStream;
 use futures::StreamExt;
 
 use crate::sessions::QueryContext;
+use crate::storages::fuse::io::encode_meta;
 use crate::storages::fuse::io::BlockStreamWriter;
 use crate::storages::fuse::operations::AppendOperationLogEntry;
 use crate::storages::fuse::FuseTable;
@@ -56,9 +57,11 @@ impl FuseTable {
             rows_per_block,
             block_per_seg,
             self.meta_location_generator().clone(),
+            ctx.get_meta_cipher(),
         )
         .await;
 
+        let cipher = ctx.get_meta_cipher();
         let locs = self.meta_location_generator().clone();
         let segment_info_cache = ctx.get_storage_cache_manager().get_table_segment_cache();
 
@@ -67,7 +70,7 @@ impl FuseTable {
                 let log_entry_res = match segment {
                     Ok(seg) => {
                         let seg_loc = locs.gen_segment_info_location();
-                        let bytes = serde_json::to_vec(&seg)?;
+                        let bytes = encode_meta(cipher.as_deref(), serde_json::to_vec(&seg)?)?;
                         da.object(&seg_loc)
                         .writer()
                         .write_bytes(bytes)
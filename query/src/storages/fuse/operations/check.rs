@@ -0,0 +1,138 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::S3File;
+use opendal::error::Kind as DalErrorKind;
+use opendal::Operator;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io::MetaReaders;
+use crate::storages::fuse::meta::Versioned;
+use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::FUSE_TBL_BLOCK_PREFIX;
+use crate::storages::fuse::FUSE_TBL_SEGMENT_PREFIX;
+use crate::storages::fuse::FUSE_TBL_SNAPSHOT_PREFIX;
+
+/// The divergences between the snapshots of a table and the objects in the storage.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ConsistencyReport {
+    /// The latest snapshot recorded in the table meta, if it does not exist.
+    pub missing_snapshot: Option<String>,
+    pub missing_segments: Vec<String>,
+    pub missing_blocks: Vec<String>,
+    /// Objects under the table prefix which are not referenced by any snapshot in the history.
+    pub unreferenced: Vec<String>,
+}
+
+impl ConsistencyReport {
+    pub fn is_consistent(&self) -> bool {
+        self.missing_snapshot.is_none()
+            && self.missing_segments.is_empty()
+            && self.missing_blocks.is_empty()
+            && self.unreferenced.is_empty()
+    }
+}
+
+impl FuseTable {
+    /// Walks the snapshot history and verifies that the referenced segments and blocks exist,
+    /// then lists the objects of the table which are referenced by none of the snapshots.
+    ///
+    /// Nothing is modified, objects of inflight insertions are reported as unreferenced too,
+    /// it is up to the caller to tell them apart (e.g. by their age).
+    pub async fn check_consistency(&self, ctx: Arc<QueryContext>) -> Result<ConsistencyReport> {
+        let operator = ctx.get_storage_operator()?;
+        let mut report = ConsistencyReport::default();
+
+        let snapshot_loc = self.snapshot_loc();
+        if let Some(loc) = &snapshot_loc {
+            // the readers are cached, ask the storage directly
+            if !Self::object_exists(&operator, loc).await? {
+                report.missing_snapshot = Some(loc.clone());
+                return Ok(report);
+            }
+        }
+
+        // snapshots purged by optimize are not reported, the history just ends there
+        let reader = MetaReaders::table_snapshot_reader(ctx.as_ref());
+        let snapshots = reader
+            .read_snapshot_history(
+                snapshot_loc.as_ref(),
+                self.snapshot_format_version(),
+                self.meta_location_generator.clone(),
+            )
+            .await?;
+
+        let locs = self.meta_location_generator();
+        let mut referenced = HashSet::new();
+        for s in &snapshots {
+            referenced
+                .insert(locs.snapshot_location_from_uuid(&s.snapshot_id, s.format_version())?);
+        }
+
+        let segment_reader = MetaReaders::segment_info_reader(ctx.as_ref());
+        for (seg_loc, ver) in snapshots.iter().flat_map(|s| s.segments.iter()) {
+            if !referenced.insert(seg_loc.clone()) {
+                continue;
+            }
+            if !Self::object_exists(&operator, seg_loc).await? {
+                report.missing_segments.push(seg_loc.clone());
+                continue;
+            }
+            let segment = segment_reader.read(seg_loc, None, *ver).await?;
+            for block_meta in &segment.blocks {
+                let block_loc = &block_meta.location.0;
+                if !referenced.insert(block_loc.clone()) {
+                    continue;
+                }
+                if !Self::object_exists(&operator, block_loc).await? {
+                    report.missing_blocks.push(block_loc.clone());
+                }
+            }
+        }
+
+        let prefix = locs.prefix();
+        for dir in [
+            FUSE_TBL_SNAPSHOT_PREFIX,
+            FUSE_TBL_SEGMENT_PREFIX,
+            FUSE_TBL_BLOCK_PREFIX,
+        ] {
+            let dir = format!("{}/{}/", prefix, dir);
+            if !Self::object_exists(&operator, &dir).await? {
+                continue;
+            }
+            for path in S3File::list(&operator, &dir).await? {
+                if !referenced.contains(&path) {
+                    report.unreferenced.push(path);
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+    async fn object_exists(operator: &Operator, location: &str) -> Result<bool> {
+        match operator.object(location).metadata().await {
+            Ok(_) => Ok(true),
+            Err(e) => match e.kind() {
+                DalErrorKind::ObjectNotExist => Ok(false),
+                _ => Err(ErrorCode::DalTransportError(e.to_string())),
+            },
+        }
+    }
+}
@@ -34,6 +34,7 @@ use uuid::Uuid;
 
 use crate::catalogs::Catalog;
 use crate::sessions::QueryContext;
+use crate::storages::fuse::io::encode_meta;
 use crate::storages::fuse::meta::Location;
 use crate::storages::fuse::meta::SegmentInfo;
 use crate::storages::fuse::meta::Statistics;
@@ -175,7 +176,8 @@ impl FuseTable {
         let snapshot_loc = self
             .meta_location_generator()
             .snapshot_location_from_uuid(&uuid, TableSnapshot::VERSION)?;
-        let bytes = serde_json::to_vec(&new_snapshot)?;
+        let cipher = ctx.get_meta_cipher();
+        let bytes = encode_meta(cipher.as_deref(), serde_json::to_vec(&new_snapshot)?)?;
         let operator = ctx.get_storage_operator()?;
         operator
             .object(&snapshot_loc)
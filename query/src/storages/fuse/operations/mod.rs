@@ -13,6 +13,7 @@
 //  limitations under the License.
 
 mod append;
+mod check;
 mod commit;
 mod operation_log;
 mod optimize;
@@ -21,6 +22,7 @@ mod read;
 mod read_partitions;
 mod truncate;
 
+pub use check::ConsistencyReport;
 pub use operation_log::AppendOperationLogEntry;
 pub use operation_log::TableOperationLog;
 pub use part_info::PartInfo;
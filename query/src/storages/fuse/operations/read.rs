@@ -18,11 +18,16 @@ use std::sync::Arc;
 use common_base::Progress;
 use common_base::ProgressValues;
 use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::Series;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::find_subquery_exprs;
 use common_planners::Extras;
 use common_planners::PartInfoPtr;
 use common_planners::ReadDataSourcePlan;
+use common_planners::RequireColumnsVisitor;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing_futures::Instrument;
 use futures::StreamExt;
@@ -33,10 +38,12 @@ use crate::pipelines::new::processors::processor::ProcessorPtr;
 use crate::pipelines::new::processors::Processor;
 use crate::pipelines::new::NewPipeline;
 use crate::pipelines::new::SourcePipeBuilder;
+use crate::pipelines::transforms::ExpressionExecutor;
 use crate::sessions::QueryContext;
 use crate::storages::fuse::io::BlockReader;
 use crate::storages::fuse::operations::read::State::Generated;
 use crate::storages::fuse::FuseTable;
+use crate::storages::Table;
 
 impl FuseTable {
     #[inline]
@@ -46,6 +53,7 @@ impl FuseTable {
         push_downs: &Option<Extras>,
     ) -> Result<SendableDataBlockStream> {
         let block_reader = self.create_block_reader(&ctx, push_downs)?;
+        let prewhere = self.create_prewhere_reader(&ctx, push_downs)?;
 
         let iter = std::iter::from_fn(move || match ctx.clone().try_get_partitions(1) {
             Err(_) => None,
@@ -59,19 +67,21 @@ impl FuseTable {
         let stream = part_stream
             .then(move |part| {
                 let block_reader = block_reader.clone();
-                async move { block_reader.read(part).await }
+                let prewhere = prewhere.clone();
+                async move {
+                    match prewhere {
+                        None => block_reader.read(part).await,
+                        Some(prewhere) => prewhere.read(part).await,
+                    }
+                }
             })
             .instrument(common_tracing::tracing::Span::current());
 
         Ok(Box::pin(stream))
     }
 
-    fn create_block_reader(
-        &self,
-        ctx: &Arc<QueryContext>,
-        push_downs: &Option<Extras>,
-    ) -> Result<Arc<BlockReader>> {
-        let projection = if let Some(Extras {
+    fn projection(&self, push_downs: &Option<Extras>) -> Vec<usize> {
+        if let Some(Extras {
             projection: Some(prj),
             ..
         }) = push_downs
@@ -81,11 +91,107 @@ impl FuseTable {
             (0..self.table_info.schema().fields().len())
                 .into_iter()
                 .collect::<Vec<usize>>()
-        };
+        }
+    }
 
+    fn create_block_reader(
+        &self,
+        ctx: &Arc<QueryContext>,
+        push_downs: &Option<Extras>,
+    ) -> Result<Arc<BlockReader>> {
+        let projection = self.projection(push_downs);
         let operator = ctx.get_storage_operator()?;
         let table_schema = self.table_info.schema();
-        BlockReader::create(operator, table_schema, projection)
+        BlockReader::create(operator, ctx.get_meta_cipher(), table_schema, projection)
+    }
+
+    /// Split the projection into the columns of the pushed down filters and the rest of them.
+    ///
+    /// Returns None if the filters can't be evaluated in the scan, or if they need all the
+    /// projected columns anyway.
+    fn create_prewhere_reader(
+        &self,
+        ctx: &Arc<QueryContext>,
+        push_downs: &Option<Extras>,
+    ) -> Result<Option<Arc<PrewhereReader>>> {
+        let filters = match push_downs {
+            Some(Extras { filters, .. }) if !filters.is_empty() => filters,
+            _ => return Ok(None),
+        };
+
+        // The subqueries are only resolved after the scan by the CreateSets transform.
+        if !find_subquery_exprs(filters).is_empty() {
+            return Ok(None);
+        }
+
+        let table_schema = self.table_info.schema();
+        let predicate = filters[1..]
+            .iter()
+            .fold(filters[0].clone(), |acc, filter| acc.and(filter.clone()));
+
+        let mut filter_columns = Vec::new();
+        for column in RequireColumnsVisitor::collect_columns_from_expr(&predicate)? {
+            match table_schema.index_of(&column) {
+                Ok(index) => filter_columns.push(index),
+                Err(_) => return Ok(None),
+            }
+        }
+
+        let projection = self.projection(push_downs);
+        let (filter_projection, remain_projection): (Vec<usize>, Vec<usize>) = projection
+            .iter()
+            .copied()
+            .partition(|index| filter_columns.contains(index));
+
+        if filter_columns.is_empty()
+            || filter_projection.len() != filter_columns.len()
+            || remain_projection.is_empty()
+        {
+            return Ok(None);
+        }
+
+        let operator = ctx.get_storage_operator()?;
+        let cipher = ctx.get_meta_cipher();
+        let filter_schema = DataSchemaRef::new(table_schema.project(filter_projection.clone()));
+        let executor = ExpressionExecutor::try_create(
+            "prewhere filter executor",
+            filter_schema.clone(),
+            DataSchemaRefExt::create(vec![predicate.to_data_field(&filter_schema)?]),
+            vec![predicate],
+            false,
+        )?;
+        executor.validate()?;
+
+        let positions = projection
+            .iter()
+            .map(
+                |index| match filter_projection.iter().position(|v| v == index) {
+                    Some(pos) => (true, pos),
+                    None => (
+                        false,
+                        remain_projection.iter().position(|v| v == index).unwrap(),
+                    ),
+                },
+            )
+            .collect::<Vec<_>>();
+
+        Ok(Some(Arc::new(PrewhereReader {
+            filter_reader: BlockReader::create(
+                operator.clone(),
+                cipher.clone(),
+                table_schema.clone(),
+                filter_projection,
+            )?,
+            remain_reader: BlockReader::create(
+                operator,
+                cipher,
+                table_schema.clone(),
+                remain_projection,
+            )?,
+            executor: Arc::new(executor),
+            positions,
+            projected_schema: DataSchemaRef::new(table_schema.project(projection)),
+        })))
     }
 
     #[inline]
@@ -96,18 +202,23 @@ impl FuseTable {
         pipeline: &mut NewPipeline,
     ) -> Result<()> {
         let block_reader = self.create_block_reader(&ctx, &plan.push_downs)?;
+        let prewhere = self.create_prewhere_reader(&ctx, &plan.push_downs)?;
 
-        let parts_len = plan.parts.len();
-        let max_threads = ctx.get_settings().get_max_threads()? as usize;
-        let max_threads = std::cmp::min(parts_len, max_threads);
+        let parallelism = self.read_parallelism(&ctx, plan)?;
+        ctx.set_parallelism(parallelism);
 
         let mut source_builder = SourcePipeBuilder::create();
 
-        for _index in 0..std::cmp::max(1, max_threads) {
+        for _index in 0..parallelism {
             let output = OutputPort::create();
             source_builder.add_source(
                 output.clone(),
-                FuseTableSource::create(ctx.clone(), output, block_reader.clone())?,
+                FuseTableSource::create(
+                    ctx.clone(),
+                    output,
+                    block_reader.clone(),
+                    prewhere.clone(),
+                )?,
             );
         }
 
@@ -116,9 +227,58 @@ impl FuseTable {
     }
 }
 
+/// Late materialization of a block: the columns of the filter are read first, and the
+/// other columns are only read for the blocks in which some rows pass the filter.
+///
+/// A block is the smallest unit the fuse engine fetches, the rows of the surviving blocks
+/// are still filtered by the plan above the scan.
+struct PrewhereReader {
+    filter_reader: Arc<BlockReader>,
+    remain_reader: Arc<BlockReader>,
+    executor: Arc<ExpressionExecutor>,
+    // For each projected column, whether it's read by the filter reader and its position there.
+    positions: Vec<(bool, usize)>,
+    projected_schema: DataSchemaRef,
+}
+
+impl PrewhereReader {
+    async fn read(&self, part: PartInfoPtr) -> Result<DataBlock> {
+        let filter_block = self.filter_reader.read(part.clone()).await?;
+        match self.has_passed_rows(&filter_block)? {
+            false => Ok(DataBlock::empty_with_schema(self.projected_schema.clone())),
+            true => {
+                let remain_block = self.remain_reader.read(part).await?;
+                self.merge(filter_block, remain_block)
+            }
+        }
+    }
+
+    fn has_passed_rows(&self, filter_block: &DataBlock) -> Result<bool> {
+        let predicate = self.executor.execute(filter_block)?;
+        let passed = DataBlock::filter_block(filter_block, predicate.column(0))?;
+        Ok(!passed.is_empty())
+    }
+
+    fn merge(&self, filter_block: DataBlock, remain_block: DataBlock) -> Result<DataBlock> {
+        let columns = self
+            .positions
+            .iter()
+            .map(|(is_filter, pos)| match is_filter {
+                true => filter_block.column(*pos).clone(),
+                false => remain_block.column(*pos).clone(),
+            })
+            .collect::<Vec<_>>();
+
+        Ok(DataBlock::create(self.projected_schema.clone(), columns))
+    }
+}
+
 enum State {
     ReadData(PartInfoPtr),
     Deserialize(PartInfoPtr, Vec<Vec<u8>>),
+    PrewhereFilter(PartInfoPtr, Vec<Vec<u8>>),
+    ReadRemain(PartInfoPtr, DataBlock),
+    DeserializeRemain(PartInfoPtr, DataBlock, Vec<Vec<u8>>),
     Generated(Option<PartInfoPtr>, DataBlock),
     Finish,
 }
@@ -128,7 +288,10 @@ struct FuseTableSource {
     ctx: Arc<QueryContext>,
     scan_progress: Arc<Progress>,
     block_reader: Arc<BlockReader>,
+    prewhere: Option<Arc<PrewhereReader>>,
     output: Arc<OutputPort>,
+    /// Dictionary encode the string columns with at most this many distinct values, 0 disables it.
+    dictionary_max_ndv: usize,
 }
 
 impl FuseTableSource {
@@ -136,26 +299,82 @@ impl FuseTableSource {
         ctx: Arc<QueryContext>,
         output: Arc<OutputPort>,
         block_reader: Arc<BlockReader>,
+        prewhere: Option<Arc<PrewhereReader>>,
     ) -> Result<ProcessorPtr> {
         let scan_progress = ctx.get_scan_progress();
+        let dictionary_max_ndv = ctx.get_settings().get_dictionary_encoding_max_ndv()? as usize;
         let mut partitions = ctx.try_get_partitions(1)?;
         match partitions.is_empty() {
             true => Ok(ProcessorPtr::create(Box::new(FuseTableSource {
                 ctx,
                 output,
                 block_reader,
+                prewhere,
                 scan_progress,
+                dictionary_max_ndv,
                 state: State::Finish,
             }))),
             false => Ok(ProcessorPtr::create(Box::new(FuseTableSource {
                 ctx,
                 output,
                 block_reader,
+                prewhere,
                 scan_progress,
+                dictionary_max_ndv,
                 state: State::ReadData(partitions.remove(0)),
             }))),
         }
     }
+
+    // The prewhere filter and the following transforms work on the codes of the encoded columns.
+    fn encode_dictionaries(&self, data_block: DataBlock) -> DataBlock {
+        if self.dictionary_max_ndv == 0 {
+            return data_block;
+        }
+
+        let columns = data_block
+            .columns()
+            .iter()
+            .map(|column| {
+                Series::try_encode_dictionary(column, self.dictionary_max_ndv)
+                    .unwrap_or_else(|| column.clone())
+            })
+            .collect();
+        DataBlock::create(data_block.schema().clone(), columns)
+    }
+
+    fn generate(&mut self, data_block: DataBlock) -> Result<()> {
+        let mut partitions = self.ctx.try_get_partitions(1)?;
+
+        let progress_values = ProgressValues {
+            rows: data_block.num_rows(),
+            bytes: data_block.memory_size(),
+        };
+        self.scan_progress.incr(&progress_values);
+
+        self.state = match partitions.is_empty() {
+            true => State::Generated(None, data_block),
+            false => State::Generated(Some(partitions.remove(0)), data_block),
+        };
+        Ok(())
+    }
+
+    // No row of the part passed the prewhere filter, go on with the next part.
+    fn skip(&mut self, filter_block: DataBlock) -> Result<()> {
+        let mut partitions = self.ctx.try_get_partitions(1)?;
+
+        let progress_values = ProgressValues {
+            rows: filter_block.num_rows(),
+            bytes: filter_block.memory_size(),
+        };
+        self.scan_progress.incr(&progress_values);
+
+        self.state = match partitions.is_empty() {
+            true => State::Finish,
+            false => State::ReadData(partitions.remove(0)),
+        };
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -193,7 +412,10 @@ impl Processor for FuseTableSource {
         match self.state {
             State::Finish => Ok(Event::Finished),
             State::ReadData(_) => Ok(Event::Async),
+            State::ReadRemain(_, _) => Ok(Event::Async),
             State::Deserialize(_, _) => Ok(Event::Sync),
+            State::PrewhereFilter(_, _) => Ok(Event::Sync),
+            State::DeserializeRemain(_, _, _) => Ok(Event::Sync),
             State::Generated(_, _) => Err(ErrorCode::LogicalError("It's a bug.")),
         }
     }
@@ -202,19 +424,27 @@ impl Processor for FuseTableSource {
         match std::mem::replace(&mut self.state, State::Finish) {
             State::Deserialize(part, chunks) => {
                 let data_block = self.block_reader.deserialize(part, chunks)?;
-                let mut partitions = self.ctx.try_get_partitions(1)?;
-
-                let progress_values = ProgressValues {
-                    rows: data_block.num_rows(),
-                    bytes: data_block.memory_size(),
-                };
-                self.scan_progress.incr(&progress_values);
-
-                self.state = match partitions.is_empty() {
-                    true => State::Generated(None, data_block),
-                    false => State::Generated(Some(partitions.remove(0)), data_block),
-                };
-                Ok(())
+                let data_block = self.encode_dictionaries(data_block);
+                self.generate(data_block)
+            }
+            State::PrewhereFilter(part, chunks) => {
+                let prewhere = self.prewhere.clone().unwrap();
+                let filter_block = prewhere.filter_reader.deserialize(part.clone(), chunks)?;
+                let filter_block = self.encode_dictionaries(filter_block);
+                match prewhere.has_passed_rows(&filter_block)? {
+                    false => self.skip(filter_block),
+                    true => {
+                        self.state = State::ReadRemain(part, filter_block);
+                        Ok(())
+                    }
+                }
+            }
+            State::DeserializeRemain(part, filter_block, chunks) => {
+                let prewhere = self.prewhere.clone().unwrap();
+                let remain_block = prewhere.remain_reader.deserialize(part, chunks)?;
+                let remain_block = self.encode_dictionaries(remain_block);
+                let data_block = prewhere.merge(filter_block, remain_block)?;
+                self.generate(data_block)
             }
             _ => Err(ErrorCode::LogicalError("It's a bug.")),
         }
@@ -222,9 +452,26 @@ impl Processor for FuseTableSource {
 
     async fn async_process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::Finish) {
-            State::ReadData(part) => {
-                let chunks = self.block_reader.read_columns_data(part.clone()).await?;
-                self.state = State::Deserialize(part, chunks);
+            State::ReadData(part) => match &self.prewhere {
+                None => {
+                    let chunks = self.block_reader.read_columns_data(part.clone()).await?;
+                    self.state = State::Deserialize(part, chunks);
+                    Ok(())
+                }
+                Some(prewhere) => {
+                    let reader = &prewhere.filter_reader;
+                    let chunks = reader.read_columns_data(part.clone()).await?;
+                    self.state = State::PrewhereFilter(part, chunks);
+                    Ok(())
+                }
+            },
+            State::ReadRemain(part, filter_block) => {
+                let prewhere = self.prewhere.clone().unwrap();
+                let chunks = prewhere
+                    .remain_reader
+                    .read_columns_data(part.clone())
+                    .await?;
+                self.state = State::DeserializeRemain(part, filter_block, chunks);
                 Ok(())
             }
             _ => Err(ErrorCode::LogicalError("It's a bug.")),
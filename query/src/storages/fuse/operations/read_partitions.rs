@@ -16,18 +16,22 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use common_datavalues::DataSchema;
 use common_exception::Result;
 use common_planners::Extras;
 use common_planners::PartInfoPtr;
 use common_planners::Partitions;
 use common_planners::Statistics;
+use rand::Rng;
 
 use crate::sessions::QueryContext;
 use crate::storages::fuse::fuse_part::ColumnMeta;
 use crate::storages::fuse::fuse_part::FusePartInfo;
+use crate::storages::fuse::io::BlockReader;
 use crate::storages::fuse::meta::BlockMeta;
 use crate::storages::fuse::meta::TableSnapshot;
 use crate::storages::fuse::pruning::BlockPruner;
+use crate::storages::fuse::pruning::TopNPruner;
 use crate::storages::fuse::FuseTable;
 
 impl FuseTable {
@@ -45,13 +49,24 @@ impl FuseTable {
                 }
                 let schema = self.table_info.schema();
                 let block_metas = BlockPruner::new(snapshot.clone())
-                    .apply(schema, &push_downs, ctx.as_ref())
+                    .apply(schema.clone(), &push_downs, ctx.as_ref())
                     .await?;
 
+                // For a `TABLESAMPLE (n PERCENT)`, every block is kept with the probability of n%.
+                let block_metas = match push_downs.as_ref().and_then(|extras| extras.sample) {
+                    None => block_metas,
+                    Some(percent) => Self::sample_blocks(block_metas, percent),
+                };
+
+                // For an `ORDER BY ... LIMIT n`, only the leading blocks in the sort order are read.
+                let block_metas = TopNPruner::prune(schema.as_ref(), &push_downs, &block_metas)
+                    .unwrap_or(block_metas);
+
                 let partitions_scanned = block_metas.len();
                 let partitions_total = snapshot.summary.block_count as usize;
 
-                let (mut statistics, parts) = Self::to_partitions(&block_metas, push_downs);
+                let (mut statistics, parts) =
+                    Self::to_partitions(&schema, &block_metas, push_downs);
 
                 // Update planner statistics.
                 statistics.partitions_total = partitions_total;
@@ -72,18 +87,30 @@ impl FuseTable {
     }
 
     pub fn to_partitions(
+        schema: &DataSchema,
         blocks_metas: &[BlockMeta],
         push_downs: Option<Extras>,
     ) -> (Statistics, Partitions) {
+        // The limit of an ordered scan can not be applied to blocks in storage order,
+        // nor can it be applied to the blocks before the rows in them are filtered.
         let limit = push_downs
             .as_ref()
+            .filter(|p| p.order_by.is_empty() && p.filters.is_empty())
             .and_then(|p| p.limit)
             .unwrap_or(usize::MAX);
         let (mut statistics, partitions) = match &push_downs {
             None => Self::all_columns_partitions(blocks_metas, limit),
             Some(extras) => match &extras.projection {
                 None => Self::all_columns_partitions(blocks_metas, limit),
-                Some(projection) => Self::projection_partitions(blocks_metas, projection, limit),
+                Some(projection) => {
+                    // the parts of a nested field are stored as its leaf columns
+                    let leaf_columns = BlockReader::leaf_columns(&schema.to_arrow());
+                    let leaves = projection
+                        .iter()
+                        .flat_map(|index| leaf_columns[*index].clone())
+                        .collect::<Vec<_>>();
+                    Self::projection_partitions(blocks_metas, projection, &leaves, limit)
+                }
             },
         };
 
@@ -95,10 +122,19 @@ impl FuseTable {
         match push_downs {
             None => true,
             // We don't have limit push down in parquet reader
-            Some(extra) => extra.filters.is_empty(),
+            Some(extra) => extra.filters.is_empty() && extra.sample.is_none(),
         }
     }
 
+    pub fn sample_blocks(metas: Vec<BlockMeta>, percent: f64) -> Vec<BlockMeta> {
+        let probability = (percent / 100.0).clamp(0.0, 1.0);
+        let mut rng = rand::thread_rng();
+        metas
+            .into_iter()
+            .filter(|_| rng.gen_bool(probability))
+            .collect()
+    }
+
     fn all_columns_partitions(metas: &[BlockMeta], limit: usize) -> (Statistics, Partitions) {
         let mut statistics = Statistics::default_exact();
         let mut partitions = Partitions::default();
@@ -132,6 +168,7 @@ impl FuseTable {
     fn projection_partitions(
         metas: &[BlockMeta],
         indices: &[usize],
+        leaves: &[usize],
         limit: usize,
     ) -> (Statistics, Partitions) {
         let mut statistics = Statistics::default_exact();
@@ -144,7 +181,7 @@ impl FuseTable {
         let mut remaining = limit;
 
         for block_meta in metas {
-            partitions.push(Self::projection_part(block_meta, indices));
+            partitions.push(Self::projection_part(block_meta, leaves));
 
             let rows = block_meta.row_count as usize;
 
@@ -185,14 +222,14 @@ impl FuseTable {
         FusePartInfo::create(location, format_version, rows_count, columns_meta)
     }
 
-    fn projection_part(meta: &BlockMeta, projections: &[usize]) -> PartInfoPtr {
-        let mut columns_meta = HashMap::with_capacity(projections.len());
+    fn projection_part(meta: &BlockMeta, leaves: &[usize]) -> PartInfoPtr {
+        let mut columns_meta = HashMap::with_capacity(leaves.len());
 
-        for projection in projections {
-            let column_meta = &meta.col_metas[&(*projection as u32)];
+        for leaf in leaves {
+            let column_meta = &meta.col_metas[&(*leaf as u32)];
 
             columns_meta.insert(
-                *projection,
+                *leaf,
                 ColumnMeta::create(column_meta.offset, column_meta.len, column_meta.num_values),
             );
         }
@@ -212,6 +249,7 @@ impl FuseTable {
             Extras {
                 projection: Some(projs),
                 filters,
+                sample: None,
                 ..
             } if projs.is_empty() && filters.is_empty() => {
                 let summary = &snapshot.summary;
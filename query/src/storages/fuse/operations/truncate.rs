@@ -23,6 +23,7 @@ use uuid::Uuid;
 
 use crate::catalogs::Catalog;
 use crate::sessions::QueryContext;
+use crate::storages::fuse::io::encode_meta;
 use crate::storages::fuse::meta::TableSnapshot;
 use crate::storages::fuse::meta::Versioned;
 use crate::storages::fuse::FuseTable;
@@ -45,7 +46,8 @@ impl FuseTable {
             let new_snapshot_loc =
                 loc.snapshot_location_from_uuid(&new_snapshot.snapshot_id, TableSnapshot::VERSION)?;
             let operator = ctx.get_storage_operator()?;
-            let bytes = serde_json::to_vec(&new_snapshot)?;
+            let cipher = ctx.get_meta_cipher();
+            let bytes = encode_meta(cipher.as_deref(), serde_json::to_vec(&new_snapshot)?)?;
             operator
                 .object(&new_snapshot_loc)
                 .writer()
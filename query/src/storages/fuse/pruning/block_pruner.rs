@@ -51,8 +51,14 @@ impl BlockPruner {
     ) -> Result<Vec<BlockMeta>> {
         let block_pred: Pred = match push_down {
             Some(exprs) if !exprs.filters.is_empty() => {
-                // for the time being, we only handle the first expr
-                let verifiable_expression = RangeFilter::try_create(&exprs.filters[0], schema)?;
+                // The pushed down filters are conjunctive, a block is kept only if all of them
+                // may hold for it.
+                let predicate = exprs.filters[1..]
+                    .iter()
+                    .fold(exprs.filters[0].clone(), |acc, filter| {
+                        acc.and(filter.clone())
+                    });
+                let verifiable_expression = RangeFilter::try_create(&predicate, schema)?;
                 Box::new(move |v: &BlockStatistics| verifiable_expression.eval(v))
             }
             _ => Box::new(|_: &BlockStatistics| Ok(true)),
@@ -65,10 +71,13 @@ impl BlockPruner {
             return Ok(vec![]);
         };
 
-        let limit = if let Some(Extras { limit: Some(l), .. }) = push_down {
-            *l
-        } else {
-            usize::MAX
+        let limit = match push_down {
+            Some(Extras {
+                limit: Some(l),
+                order_by,
+                ..
+            }) if order_by.is_empty() => *l,
+            _ => usize::MAX,
         };
 
         // Segments and blocks are accumulated concurrently, thus an atomic counter is used
@@ -13,5 +13,7 @@
 //  limitations under the License.
 
 mod block_pruner;
+mod topn_pruner;
 
 pub use block_pruner::BlockPruner;
+pub use topn_pruner::TopNPruner;
@@ -0,0 +1,133 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+
+use common_datavalues::DataSchema;
+use common_datavalues::DataValue;
+use common_planners::Expression;
+use common_planners::Extras;
+
+use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::ColumnId;
+
+/// Prunes the blocks of an `ORDER BY ... LIMIT n` scan by the statistics of the leading sort column.
+///
+/// Blocks are visited in the order of their lower bound (the min for ASC, the max for DESC), and
+/// once `n` rows are taken, the furthest upper bound of the taken blocks bounds the n-th row, so
+/// every following block that starts beyond it can be skipped.
+pub struct TopNPruner;
+
+impl TopNPruner {
+    /// Returns the blocks that may hold the top `n` rows, in the sort order, or `None` if the
+    /// push downs or the statistics can not tell, e.g. there are filters or NULLs.
+    pub fn prune(
+        schema: &DataSchema,
+        push_down: &Option<Extras>,
+        metas: &[BlockMeta],
+    ) -> Option<Vec<BlockMeta>> {
+        let extras = push_down.as_ref()?;
+        let limit = extras.limit?;
+        if limit == 0 {
+            return Some(vec![]);
+        }
+
+        // Filters may drop any rows of the blocks, the row counts prove nothing then.
+        if !extras.filters.is_empty() {
+            return None;
+        }
+
+        let (column_name, asc) = match extras.order_by.first()? {
+            Expression::Sort {
+                expr,
+                asc,
+                case_insensitive: false,
+                ..
+            } => match expr.as_ref() {
+                Expression::Column(column_name) => (column_name, *asc),
+                _ => return None,
+            },
+            _ => return None,
+        };
+
+        let column_id = schema.index_of(column_name).ok()? as ColumnId;
+
+        let mut bounds = Vec::with_capacity(metas.len());
+        for meta in metas {
+            let stats = meta.col_stats.get(&column_id)?;
+            if stats.null_count != 0 {
+                return None;
+            }
+
+            match asc {
+                true => bounds.push((&stats.min, &stats.max, meta)),
+                false => bounds.push((&stats.max, &stats.min, meta)),
+            }
+        }
+
+        let order = |left: &DataValue, right: &DataValue| match asc {
+            true => compare_values(left, right),
+            false => compare_values(right, left),
+        };
+
+        // Make sure the bounds are totally ordered before sorting them.
+        if let Some((first, _, _)) = bounds.first() {
+            let comparable = bounds.iter().all(|(lower, upper, _)| {
+                order(first, lower).is_some() && order(first, upper).is_some()
+            });
+
+            if !comparable {
+                return None;
+            }
+        }
+
+        bounds.sort_by(|(left, _, _), (right, _, _)| order(left, right).unwrap_or(Ordering::Equal));
+
+        let mut rows = 0;
+        let mut furthest: Option<&DataValue> = None;
+        let mut threshold: Option<&DataValue> = None;
+        let mut pruned = vec![];
+        for (lower, upper, meta) in bounds {
+            if let Some(threshold) = threshold {
+                if order(lower, threshold) == Some(Ordering::Greater) {
+                    break;
+                }
+            }
+
+            rows += meta.row_count as usize;
+            furthest = match furthest {
+                Some(value) if order(upper, value) != Some(Ordering::Greater) => Some(value),
+                _ => Some(upper),
+            };
+            pruned.push(meta.clone());
+
+            if threshold.is_none() && rows >= limit {
+                threshold = furthest;
+            }
+        }
+
+        Some(pruned)
+    }
+}
+
+fn compare_values(left: &DataValue, right: &DataValue) -> Option<Ordering> {
+    match (left, right) {
+        (DataValue::Boolean(left), DataValue::Boolean(right)) => Some(left.cmp(right)),
+        (DataValue::Int64(left), DataValue::Int64(right)) => Some(left.cmp(right)),
+        (DataValue::UInt64(left), DataValue::UInt64(right)) => Some(left.cmp(right)),
+        (DataValue::Float64(left), DataValue::Float64(right)) => left.partial_cmp(right),
+        (DataValue::String(left), DataValue::String(right)) => Some(left.cmp(right)),
+        _ => None,
+    }
+}
@@ -24,6 +24,8 @@ use crate::storages::fuse::meta::BlockMeta;
 use crate::storages::fuse::meta::ColumnId;
 use crate::storages::fuse::meta::ColumnMeta;
 use crate::storages::fuse::meta::Versioned;
+use crate::storages::fuse::statistics::string_max_prefix;
+use crate::storages::fuse::statistics::string_min_prefix;
 use crate::storages::index::BlockStatistics;
 use crate::storages::index::ColumnStatistics;
 
@@ -76,19 +78,25 @@ impl StatisticsAccumulator {
             let mut max = DataValue::Null;
 
             // TODO(b41sh): support max/min aggregate functions for variant
+            // nested columns (array, struct and map) have no min/max either
             let nonull_data_type = remove_nullable(field.data_type());
-            if nonull_data_type.data_type_id() != TypeID::Variant
-                && nonull_data_type.data_type_id() != TypeID::VariantArray
-                && nonull_data_type.data_type_id() != TypeID::VariantObject
-            {
+            if !matches!(
+                nonull_data_type.data_type_id(),
+                TypeID::Variant
+                    | TypeID::VariantArray
+                    | TypeID::VariantObject
+                    | TypeID::Array
+                    | TypeID::Struct
+                    | TypeID::Map
+            ) {
                 let mins = eval_aggr("min", vec![], &[column_field.clone()], rows)?;
                 let maxs = eval_aggr("max", vec![], &[column_field], rows)?;
 
                 if mins.len() > 0 {
-                    min = mins.get(0);
+                    min = string_min_prefix(mins.get(0));
                 }
                 if maxs.len() > 0 {
-                    max = maxs.get(0);
+                    max = string_max_prefix(maxs.get(0));
                 }
             }
             let (is_all_null, bitmap) = col.validity();
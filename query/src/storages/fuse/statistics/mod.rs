@@ -14,8 +14,11 @@
 
 pub mod accumulator;
 pub mod reducers;
+pub mod string_prefix;
 
 pub use accumulator::PartiallyAccumulated;
 pub use accumulator::StatisticsAccumulator;
 pub use reducers::merge_statistics;
 pub use reducers::reduce_block_stats;
+pub use string_prefix::string_max_prefix;
+pub use string_prefix::string_min_prefix;
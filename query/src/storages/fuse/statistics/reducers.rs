@@ -26,6 +26,8 @@ use common_functions::aggregates::eval_aggr;
 
 use crate::storages::fuse::meta::ColumnId;
 use crate::storages::fuse::meta::Statistics;
+use crate::storages::fuse::statistics::string_max_prefix;
+use crate::storages::fuse::statistics::string_min_prefix;
 use crate::storages::index::BlockStatistics;
 use crate::storages::index::ColumnStatistics;
 
@@ -85,9 +87,7 @@ pub fn reduce_block_stats<T: Borrow<BlockStatistics>>(
                 && nonull_data_type.data_type_id() != TypeID::VariantObject
             {
                 let field = schema.field((*id) as usize);
-                // TODO
-                // for some data types, we shall balance the accuracy and the length
-                // e.g. for a string col, which max value is "abcdef....", we record the max as something like "b"
+                // the string stats of the blocks written by the older versions may be long, cut them here as well
                 let min_column = data_type.create_column(&min_stats)?;
                 let max_column = data_type.create_column(&max_stats)?;
 
@@ -105,10 +105,10 @@ pub fn reduce_block_stats<T: Borrow<BlockStatistics>>(
                 )?;
 
                 if mins.len() > 0 {
-                    min = mins.get(0);
+                    min = string_min_prefix(mins.get(0));
                 }
                 if maxs.len() > 0 {
-                    max = maxs.get(0);
+                    max = string_max_prefix(maxs.get(0));
                 }
             }
 
@@ -0,0 +1,47 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::DataValue;
+
+use crate::storages::fuse::STATS_STRING_PREFIX_LEN;
+
+/// Cuts a string min statistic to its prefix, which is still not greater than any value of the column.
+pub fn string_min_prefix(min: DataValue) -> DataValue {
+    match min {
+        DataValue::String(mut bytes) if bytes.len() > STATS_STRING_PREFIX_LEN => {
+            bytes.truncate(STATS_STRING_PREFIX_LEN);
+            DataValue::String(bytes)
+        }
+        other => other,
+    }
+}
+
+/// Cuts a string max statistic to its prefix with the last byte incremented, which is still greater
+/// than any value with the same prefix. The trailing 0xFF bytes can't be incremented and are dropped,
+/// a prefix of 0xFF bytes only keeps the max as it is, there is no shorter string greater than it.
+pub fn string_max_prefix(max: DataValue) -> DataValue {
+    match max {
+        DataValue::String(bytes) if bytes.len() > STATS_STRING_PREFIX_LEN => {
+            let mut prefix = bytes[..STATS_STRING_PREFIX_LEN].to_vec();
+            while let Some(last) = prefix.pop() {
+                if last < u8::MAX {
+                    prefix.push(last + 1);
+                    return DataValue::String(prefix);
+                }
+            }
+            DataValue::String(bytes)
+        }
+        other => other,
+    }
+}
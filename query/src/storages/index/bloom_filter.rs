@@ -444,6 +444,10 @@ impl BloomFilter {
                 | TypeID::DateTime64
                 | TypeID::Interval
                 | TypeID::String
+                | TypeID::Binary
+                | TypeID::Uuid
+                | TypeID::IPv4
+                | TypeID::IPv6
         )
     }
 
@@ -24,9 +24,11 @@ use common_exception::Result;
 use common_functions::scalars::check_pattern_type;
 use common_functions::scalars::FunctionFactory;
 use common_functions::scalars::PatternType;
+use common_functions::scalars::Volatility;
 use common_planners::lit;
 use common_planners::Expression;
 use common_planners::ExpressionMonotonicityVisitor;
+use common_planners::ExpressionVolatilityVisitor;
 use common_planners::Expressions;
 use common_planners::RequireColumnsVisitor;
 
@@ -108,7 +110,20 @@ pub fn build_verifiable_expr(
 
     let (exprs, op) = match expr {
         Expression::Literal { .. } => return expr.clone(),
-        Expression::ScalarFunction { op, args } => (args.clone(), op.clone()),
+        Expression::ScalarFunction { op, args } => match op.to_lowercase().as_str() {
+            // the bounds are verified one by one
+            "between" if args.len() == 3 => {
+                let expr = args[0]
+                    .gt_eq(args[1].clone())
+                    .and(args[0].lt_eq(args[2].clone()));
+                return build_verifiable_expr(&expr, schema, stat_columns);
+            }
+            "not between" if args.len() == 3 => {
+                let expr = args[0].lt(args[1].clone()).or(args[0].gt(args[2].clone()));
+                return build_verifiable_expr(&expr, schema, stat_columns);
+            }
+            _ => (args.clone(), op.clone()),
+        },
         Expression::BinaryExpression { left, op, right } => match op.to_lowercase().as_str() {
             "and" => {
                 let left = build_verifiable_expr(left, schema, stat_columns);
@@ -128,6 +143,16 @@ pub fn build_verifiable_expr(
         _ => return unhandled,
     };
 
+    // A volatile predicate, e.g. `a > rand()`, may give a different answer for every row,
+    // so the statistics of a block tell nothing about it.
+    let is_volatile = exprs.iter().any(|expr| {
+        ExpressionVolatilityVisitor::volatility_of_expr(expr)
+            .map_or(true, |volatility| volatility == Volatility::Volatile)
+    });
+    if is_volatile {
+        return unhandled;
+    }
+
     VerifiableExprBuilder::try_create(exprs, op.to_lowercase().as_str(), schema, stat_columns)
         .map_or(unhandled.clone(), |mut v| v.build().unwrap_or(unhandled))
 }
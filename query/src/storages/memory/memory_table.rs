@@ -43,6 +43,7 @@ use crate::storages::memory::MemoryTableStream;
 use crate::storages::StorageContext;
 use crate::storages::StorageDescription;
 use crate::storages::Table;
+use crate::storages::TableStatistics;
 
 pub struct MemoryTable {
     table_info: TableInfo,
@@ -213,11 +214,13 @@ impl Table for MemoryTable {
         plan: &ReadDataSourcePlan,
         pipeline: &mut NewPipeline,
     ) -> Result<()> {
-        let settings = ctx.get_settings();
+        let parallelism = self.read_parallelism(&ctx, plan)?;
+        ctx.set_parallelism(parallelism);
+
         let mut builder = SourcePipeBuilder::create();
         let read_data_blocks = self.get_read_data_blocks();
 
-        for _index in 0..settings.get_max_threads()? {
+        for _index in 0..parallelism {
             let output = OutputPort::create();
             builder.add_source(
                 output.clone(),
@@ -274,6 +277,20 @@ impl Table for MemoryTable {
         blocks.clear();
         Ok(())
     }
+
+    async fn statistics(&self, _ctx: Arc<QueryContext>) -> Result<Option<TableStatistics>> {
+        let blocks = self.blocks.read();
+        let num_rows = blocks.iter().map(|block| block.num_rows() as u64).sum();
+        let data_bytes = blocks.iter().map(|block| block.memory_size() as u64).sum();
+
+        Ok(Some(TableStatistics {
+            num_rows,
+            data_bytes,
+            compressed_data_bytes: data_bytes,
+            index_data_bytes: 0,
+            number_of_snapshots: None,
+        }))
+    }
 }
 
 struct MemoryTableSource {
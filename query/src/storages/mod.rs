@@ -34,4 +34,5 @@ pub use storage_factory::StorageCreator;
 pub use storage_factory::StorageDescription;
 pub use storage_factory::StorageFactory;
 pub use storage_table::Table;
+pub use storage_table::TableStatistics;
 pub use storage_table_read_plan::ToReadDataSourcePlan;
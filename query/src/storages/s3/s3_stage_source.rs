@@ -20,12 +20,16 @@ use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_io::prelude::S3File;
+use common_meta_types::StageFileCompression;
 use common_meta_types::StageFileFormatType;
 use common_meta_types::StageStorage;
 use common_meta_types::StageType;
 use common_meta_types::UserStageInfo;
 use common_planners::S3StageTableInfo;
+use common_streams::decompress_reader;
+use common_streams::Compression;
 use common_streams::CsvSourceBuilder;
+use common_streams::DecompressReader;
 use common_streams::ParquetSourceBuilder;
 use common_streams::Source;
 use opendal::Operator;
@@ -66,7 +70,7 @@ impl StageSource {
         ctx: Arc<QueryContext>,
         schema: DataSchemaRef,
         stage_info: &UserStageInfo,
-        reader: Reader,
+        reader: DecompressReader,
     ) -> Result<Box<dyn Source>> {
         let mut builder = CsvSourceBuilder::create(schema);
         let size_limit = stage_info.copy_options.size_limit;
@@ -122,6 +126,23 @@ impl StageSource {
         Ok(Box::new(builder.build(reader)?))
     }
 
+    // Get the codec to decompress the file with.
+    fn compression(compression: &StageFileCompression) -> Result<Compression> {
+        match compression {
+            StageFileCompression::Auto => Ok(Compression::Auto),
+            StageFileCompression::None => Ok(Compression::None),
+            StageFileCompression::Gzip => Ok(Compression::Gzip),
+            StageFileCompression::Zstd => Ok(Compression::Zstd),
+            StageFileCompression::Bz2 => Ok(Compression::Bz2),
+            StageFileCompression::Xz => Ok(Compression::Xz),
+            // Unsupported.
+            compression => Err(ErrorCode::LogicalError(format!(
+                "Unsupported file compression: {:?}",
+                compression
+            ))),
+        }
+    }
+
     pub async fn get_op(ctx: &Arc<QueryContext>, stage: &UserStageInfo) -> Result<Operator> {
         if stage.stage_type == StageType::Internal {
             ctx.get_storage_operator()
@@ -150,11 +171,22 @@ impl StageSource {
         let op = Self::get_op(&self.ctx, &self.table_info.stage_info).await?;
         let path = file_name.unwrap_or_else(|| "".to_string());
         let file_reader = op.object(&path).reader();
+        let compression = Self::compression(&stage.file_format_options.compression)?;
 
         // Get the format(CSV, Parquet) source stream.
         let source = match &file_format {
             StageFileFormatType::Csv => {
-                Ok(Self::csv_source(ctx.clone(), self.schema.clone(), stage, file_reader).await?)
+                let reader = decompress_reader(file_reader, compression, &path).await?;
+                Ok(Self::csv_source(ctx.clone(), self.schema.clone(), stage, reader).await?)
+            }
+            // Parquet compresses its pages itself, the file can't be wrapped by a decoder.
+            StageFileFormatType::Parquet
+                if !matches!(compression, Compression::Auto | Compression::None) =>
+            {
+                Err(ErrorCode::LogicalError(format!(
+                    "Unsupported compression {:?} for parquet file: {}",
+                    compression, path
+                )))
             }
             StageFileFormatType::Parquet => {
                 Ok(
@@ -73,6 +73,12 @@ pub trait Table: Sync + Send {
         false
     }
 
+    /// whether table samples its partitions by `Extras::sample` in `read_partitions`,
+    /// otherwise the rows are sampled by a filter after being read.
+    fn support_sampling(&self) -> bool {
+        false
+    }
+
     // defaults to generate one single part and empty statistics
     async fn read_partitions(
         &self,
@@ -82,6 +88,24 @@ pub trait Table: Sync + Send {
         unimplemented!()
     }
 
+    /// The number of streams to read the plan with: one per partition, at most `max_threads`,
+    /// and no more than one per `max_block_size` rows so that small inputs are not spread thin.
+    /// Engines can override it to cap the parallelism with what they know of their data.
+    fn read_parallelism(&self, ctx: &QueryContext, plan: &ReadDataSourcePlan) -> Result<usize> {
+        let settings = ctx.get_settings();
+        let max_threads = settings.get_max_threads()? as usize;
+        let mut parallelism = std::cmp::min(max_threads, plan.parts.len());
+
+        let read_rows = plan.statistics.read_rows;
+        if read_rows > 0 {
+            let max_block_size = std::cmp::max(settings.get_max_block_size()? as usize, 1);
+            let blocks = (read_rows + max_block_size - 1) / max_block_size;
+            parallelism = std::cmp::min(parallelism, blocks);
+        }
+
+        Ok(std::cmp::max(parallelism, 1))
+    }
+
     fn table_args(&self) -> Option<Vec<Expression>> {
         None
     }
@@ -139,4 +163,19 @@ pub trait Table: Sync + Send {
     async fn optimize(&self, _ctx: Arc<QueryContext>, _keep_last_snapshot: bool) -> Result<()> {
         Ok(())
     }
+
+    /// The size of the table data, None if the engine doesn't track it.
+    async fn statistics(&self, _ctx: Arc<QueryContext>) -> Result<Option<TableStatistics>> {
+        Ok(None)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TableStatistics {
+    pub num_rows: u64,
+    pub data_bytes: u64,
+    pub compressed_data_bytes: u64,
+    pub index_data_bytes: u64,
+    /// None if the engine doesn't keep snapshots of the table.
+    pub number_of_snapshots: Option<u64>,
 }
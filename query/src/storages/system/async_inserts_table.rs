@@ -0,0 +1,106 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+
+use crate::sessions::AsyncInsertStatus;
+use crate::sessions::QueryContext;
+use crate::storages::system::table::SyncOneBlockSystemTable;
+use crate::storages::system::table::SyncSystemTable;
+use crate::storages::Table;
+
+pub struct AsyncInsertsTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for AsyncInsertsTable {
+    const NAME: &'static str = "system.async_inserts";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, ctx: Arc<QueryContext>) -> Result<DataBlock> {
+        let entries = ctx.get_async_insert_queue().get_entries();
+
+        let mut ids = Vec::with_capacity(entries.len());
+        let mut databases = Vec::with_capacity(entries.len());
+        let mut tables = Vec::with_capacity(entries.len());
+        let mut rows = Vec::with_capacity(entries.len());
+        let mut statuses = Vec::with_capacity(entries.len());
+        let mut errors = Vec::with_capacity(entries.len());
+        let mut created_ons = Vec::with_capacity(entries.len());
+        let mut flushed_ons = Vec::with_capacity(entries.len());
+        for entry in &entries {
+            ids.push(entry.id.as_bytes());
+            databases.push(entry.database.as_bytes());
+            tables.push(entry.table.as_bytes());
+            rows.push(entry.rows);
+            statuses.push(entry.status.name().as_bytes());
+            errors.push(match &entry.status {
+                AsyncInsertStatus::Failed(error) => Some(error.as_bytes()),
+                _ => None,
+            });
+            created_ons.push(entry.created_on.timestamp() as u32);
+            flushed_ons.push(entry.flushed_on.map(|v| v.timestamp() as u32));
+        }
+
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(ids),
+            Series::from_data(databases),
+            Series::from_data(tables),
+            Series::from_data(rows),
+            Series::from_data(statuses),
+            Series::from_data(errors),
+            Series::from_data(created_ons),
+            Series::from_data(flushed_ons),
+        ]))
+    }
+}
+
+impl AsyncInsertsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("id", Vu8::to_data_type()),
+            DataField::new("database", Vu8::to_data_type()),
+            DataField::new("table", Vu8::to_data_type()),
+            DataField::new("rows", u64::to_data_type()),
+            DataField::new("status", Vu8::to_data_type()),
+            DataField::new_nullable("error", Vu8::to_data_type()),
+            DataField::new("created_on", DateTime32Type::arc(None)),
+            DataField::new_nullable("flushed_on", DateTime32Type::arc(None)),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'async_inserts'".to_string(),
+            name: "async_inserts".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemAsyncInserts".to_string(),
+
+                ..Default::default()
+            },
+        };
+
+        SyncOneBlockSystemTable::create(AsyncInsertsTable { table_info })
+    }
+}
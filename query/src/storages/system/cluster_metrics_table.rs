@@ -0,0 +1,66 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+
+use crate::sessions::QueryContext;
+use crate::storages::system::cluster_table::cluster_table_schema;
+use crate::storages::system::cluster_table::read_cluster_table;
+use crate::storages::system::table::AsyncOneBlockSystemTable;
+use crate::storages::system::table::AsyncSystemTable;
+use crate::storages::system::MetricsTable;
+use crate::storages::Table;
+
+/// The system.metrics of all the nodes of the cluster.
+pub struct ClusterMetricsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for ClusterMetricsTable {
+    const NAME: &'static str = "system.cluster_metrics";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(&self, ctx: Arc<QueryContext>) -> Result<DataBlock> {
+        read_cluster_table(ctx, "metrics", self.table_info.schema()).await
+    }
+}
+
+impl ClusterMetricsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = cluster_table_schema(&MetricsTable::create(table_id).schema());
+
+        let table_info = TableInfo {
+            desc: "'system'.'cluster_metrics'".to_string(),
+            name: "cluster_metrics".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemClusterMetrics".to_string(),
+                ..Default::default()
+            },
+        };
+
+        AsyncOneBlockSystemTable::create(ClusterMetricsTable { table_info })
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_types::NodeInfo;
+use common_tracing::tracing;
+use futures::TryStreamExt;
+
+use crate::api::FlightTicket;
+use crate::api::MIN_SYSTEM_TABLE_PROTOCOL_VERSION;
+use crate::sessions::QueryContext;
+use crate::storages::ToReadDataSourcePlan;
+
+/// The schema of the cluster-wide version of a system table: the id of the node the rows
+/// come from, followed by the columns of the local table.
+pub fn cluster_table_schema(local_schema: &DataSchemaRef) -> DataSchemaRef {
+    let mut fields = vec![DataField::new("node", Vu8::to_data_type())];
+    fields.extend(local_schema.fields().iter().cloned());
+    DataSchemaRefExt::create(fields)
+}
+
+/// Reads the system table `table` of every node of the cluster, the remote nodes are read
+/// concurrently over flight. The nodes which can't be read are skipped with a warning.
+pub async fn read_cluster_table(
+    ctx: Arc<QueryContext>,
+    table: &str,
+    schema: DataSchemaRef,
+) -> Result<DataBlock> {
+    let cluster = ctx.get_cluster();
+    let local_table = ctx.get_table("system", table).await?;
+    let local_schema = local_table.schema();
+
+    let source_plan = local_table.read_plan(ctx.clone(), None).await?;
+    let stream = local_table.read(ctx.clone(), &source_plan).await?;
+    let local_blocks = stream.try_collect::<Vec<_>>().await?;
+
+    let mut blocks = with_node_column(&schema, &cluster.local_id(), local_blocks);
+
+    let remote_nodes = cluster
+        .get_nodes()
+        .into_iter()
+        .filter(|node| !cluster.is_local(node))
+        .filter(|node| {
+            if node.version < MIN_SYSTEM_TABLE_PROTOCOL_VERSION {
+                tracing::warn!(
+                    "Skip cluster node {} of flight protocol version {} while reading system.{}",
+                    node.id,
+                    node.version,
+                    table
+                );
+                return false;
+            }
+            true
+        })
+        .collect::<Vec<_>>();
+
+    let remote_reads = remote_nodes
+        .iter()
+        .map(|node| read_remote_table(ctx.clone(), node, table, local_schema.clone()));
+    // An unreachable node must not hide the rows of the others, e.g. SHOW PROCESSLIST is
+    // needed the most while the cluster is unhealthy.
+    for (node, remote_blocks) in remote_nodes
+        .iter()
+        .zip(futures::future::join_all(remote_reads).await)
+    {
+        match remote_blocks {
+            Ok(remote_blocks) => blocks.extend(with_node_column(&schema, &node.id, remote_blocks)),
+            Err(cause) => tracing::warn!("Skip cluster node {}: {}", node.id, cause),
+        }
+    }
+
+    match blocks.is_empty() {
+        true => Ok(DataBlock::empty_with_schema(schema)),
+        false => DataBlock::concat_blocks(&blocks),
+    }
+}
+
+async fn read_remote_table(
+    ctx: Arc<QueryContext>,
+    node: &NodeInfo,
+    table: &str,
+    schema: DataSchemaRef,
+) -> Result<Vec<DataBlock>> {
+    let fetch_blocks = async {
+        let timeout = ctx.get_settings().get_flight_client_timeout()?;
+        let cluster = ctx.get_cluster();
+        let mut flight_client = cluster
+            .create_node_conn(&node.id, &ctx.get_config())
+            .await?;
+
+        let ticket = FlightTicket::system_table(&ctx.get_id(), table);
+        let stream = flight_client.fetch_stream(ticket, schema, timeout).await?;
+        stream.try_collect::<Vec<_>>().await
+    };
+
+    fetch_blocks.await.map_err(|cause| {
+        cause.add_message_back(format!(
+            " (while reading system.{} of cluster node {})",
+            table, node.id
+        ))
+    })
+}
+
+fn with_node_column(schema: &DataSchemaRef, node: &str, blocks: Vec<DataBlock>) -> Vec<DataBlock> {
+    blocks
+        .into_iter()
+        .map(|block| {
+            let mut columns = Vec::with_capacity(block.num_columns() + 1);
+            columns.push(Series::from_data(vec![node.as_bytes(); block.num_rows()]));
+            columns.extend(block.columns().iter().cloned());
+            DataBlock::create(schema.clone(), columns)
+        })
+        .collect()
+}
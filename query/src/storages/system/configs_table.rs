@@ -82,10 +82,12 @@ impl SyncSystemTable for ConfigsTable {
 
         let masked_access_key_id = mask_string(&config.storage.s3.access_key_id[..], 3);
         let masked_secret_access_key = mask_string(&config.storage.s3.secret_access_key[..], 3);
+        let masked_encryption_key = mask_string(&config.storage.encryption.encryption_key[..], 3);
         let mut storage_config = config.storage;
-        // mask sensitive data in storage.s3
+        // mask sensitive data in storage.s3 and storage.encryption
         storage_config.s3.access_key_id = masked_access_key_id;
         storage_config.s3.secret_access_key = masked_secret_access_key;
+        storage_config.encryption.encryption_key = masked_encryption_key;
 
         let storage_config_value = serde_json::to_value(storage_config)?;
         ConfigsTable::extract_config(
@@ -0,0 +1,90 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+
+use crate::sessions::QueryContext;
+use crate::storages::system::table::SyncOneBlockSystemTable;
+use crate::storages::system::table::SyncSystemTable;
+use crate::storages::Table;
+
+pub struct ConsistencyFindingsTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for ConsistencyFindingsTable {
+    const NAME: &'static str = "system.consistency_findings";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, ctx: Arc<QueryContext>) -> Result<DataBlock> {
+        let findings = ctx.get_consistency_checker().get_findings();
+
+        let mut databases = Vec::with_capacity(findings.len());
+        let mut tables = Vec::with_capacity(findings.len());
+        let mut kinds = Vec::with_capacity(findings.len());
+        let mut locations = Vec::with_capacity(findings.len());
+        let mut detected_ons = Vec::with_capacity(findings.len());
+        for finding in &findings {
+            databases.push(finding.database.as_bytes());
+            tables.push(finding.table.as_bytes());
+            kinds.push(finding.kind.name().as_bytes());
+            locations.push(finding.location.as_bytes());
+            detected_ons.push(finding.detected_on.timestamp() as u32);
+        }
+
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(databases),
+            Series::from_data(tables),
+            Series::from_data(kinds),
+            Series::from_data(locations),
+            Series::from_data(detected_ons),
+        ]))
+    }
+}
+
+impl ConsistencyFindingsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("database", Vu8::to_data_type()),
+            DataField::new("table", Vu8::to_data_type()),
+            DataField::new("kind", Vu8::to_data_type()),
+            DataField::new("location", Vu8::to_data_type()),
+            DataField::new("detected_on", DateTime32Type::arc(None)),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'consistency_findings'".to_string(),
+            name: "consistency_findings".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemConsistencyFindings".to_string(),
+
+                ..Default::default()
+            },
+        };
+
+        SyncOneBlockSystemTable::create(ConsistencyFindingsTable { table_info })
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+
+use crate::sessions::QueryContext;
+use crate::storages::system::table::AsyncOneBlockSystemTable;
+use crate::storages::system::table::AsyncSystemTable;
+use crate::storages::Table;
+
+pub struct CopyHistoryTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for CopyHistoryTable {
+    const NAME: &'static str = "system.copy_history";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(&self, ctx: Arc<QueryContext>) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let files = ctx.get_user_manager().get_copied_files(&tenant).await?;
+
+        let mut databases = Vec::with_capacity(files.len());
+        let mut tables = Vec::with_capacity(files.len());
+        let mut file_names = Vec::with_capacity(files.len());
+        let mut sizes = Vec::with_capacity(files.len());
+        let mut rows_loaded = Vec::with_capacity(files.len());
+        let mut errors = Vec::with_capacity(files.len());
+        let mut first_loaded_ons = Vec::with_capacity(files.len());
+        let mut last_loaded_ons = Vec::with_capacity(files.len());
+        for file in &files {
+            databases.push(file.database.as_bytes());
+            tables.push(file.table.as_bytes());
+            file_names.push(file.file_name.as_bytes());
+            sizes.push(file.size);
+            rows_loaded.push(file.rows_loaded);
+            errors.push(file.error.as_ref().map(|v| v.as_bytes()));
+            first_loaded_ons.push(file.first_loaded_on.timestamp() as u32);
+            last_loaded_ons.push(file.last_loaded_on.timestamp() as u32);
+        }
+
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(databases),
+            Series::from_data(tables),
+            Series::from_data(file_names),
+            Series::from_data(sizes),
+            Series::from_data(rows_loaded),
+            Series::from_data(errors),
+            Series::from_data(first_loaded_ons),
+            Series::from_data(last_loaded_ons),
+        ]))
+    }
+}
+
+impl CopyHistoryTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("database", Vu8::to_data_type()),
+            DataField::new("table", Vu8::to_data_type()),
+            DataField::new("file_name", Vu8::to_data_type()),
+            DataField::new("size", u64::to_data_type()),
+            DataField::new("rows_loaded", u64::to_data_type()),
+            DataField::new_nullable("error", Vu8::to_data_type()),
+            DataField::new("first_loaded_on", DateTime32Type::arc(None)),
+            DataField::new("last_loaded_on", DateTime32Type::arc(None)),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'copy_history'".to_string(),
+            name: "copy_history".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemCopyHistory".to_string(),
+
+                ..Default::default()
+            },
+        };
+
+        AsyncOneBlockSystemTable::create(CopyHistoryTable { table_info })
+    }
+}
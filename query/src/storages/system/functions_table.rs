@@ -131,6 +131,18 @@ impl AsyncSystemTable for FunctionsTable {
             })
             .collect::<Vec<&str>>();
 
+        let func_features = function_factory.registered_features();
+        let volatilities = (0..names.len())
+            .map(|i| {
+                if i < func_names.len() {
+                    func_features[i].volatility.to_string()
+                } else {
+                    "".to_string()
+                }
+            })
+            .collect::<Vec<String>>();
+        let volatilities: Vec<&str> = volatilities.iter().map(|x| x.as_str()).collect();
+
         Ok(DataBlock::create(self.table_info.schema(), vec![
             Series::from_data(names),
             Series::from_data(is_builtin),
@@ -140,6 +152,7 @@ impl AsyncSystemTable for FunctionsTable {
             Series::from_data(descriptions),
             Series::from_data(syntaxs),
             Series::from_data(examples),
+            Series::from_data(volatilities),
         ]))
     }
 }
@@ -155,6 +168,7 @@ impl FunctionsTable {
             DataField::new("description", Vu8::to_data_type()),
             DataField::new("syntax", Vu8::to_data_type()),
             DataField::new("example", Vu8::to_data_type()),
+            DataField::new("volatility", Vu8::to_data_type()),
         ]);
 
         let table_info = TableInfo {
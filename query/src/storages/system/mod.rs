@@ -12,10 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod async_inserts_table;
+mod cluster_metrics_table;
+mod cluster_processes_table;
+mod cluster_table;
 mod clusters_table;
 mod columns_table;
 mod configs_table;
+mod consistency_findings_table;
 mod contributors_table;
+mod copy_history_table;
 mod credits_table;
 mod databases_table;
 mod engines_table;
@@ -27,16 +33,23 @@ mod query_log_table;
 mod roles_table;
 mod settings_table;
 mod table;
+mod table_sizes_table;
 mod tables_table;
+mod text_log_table;
 mod tracing_table;
 mod tracing_table_stream;
 mod users_table;
 mod warehouses_table;
 
+pub use async_inserts_table::AsyncInsertsTable;
+pub use cluster_metrics_table::ClusterMetricsTable;
+pub use cluster_processes_table::ClusterProcessesTable;
 pub use clusters_table::ClustersTable;
 pub use columns_table::ColumnsTable;
 pub use configs_table::ConfigsTable;
+pub use consistency_findings_table::ConsistencyFindingsTable;
 pub use contributors_table::ContributorsTable;
+pub use copy_history_table::CopyHistoryTable;
 pub use credits_table::CreditsTable;
 pub use databases_table::DatabasesTable;
 pub use engines_table::EnginesTable;
@@ -47,7 +60,9 @@ pub use processes_table::ProcessesTable;
 pub use query_log_table::QueryLogTable;
 pub use roles_table::RolesTable;
 pub use settings_table::SettingsTable;
+pub use table_sizes_table::TableSizesTable;
 pub use tables_table::TablesTable;
+pub use text_log_table::TextLogTable;
 pub use tracing_table::TracingTable;
 pub use tracing_table_stream::TracingTableStream;
 pub use users_table::UsersTable;
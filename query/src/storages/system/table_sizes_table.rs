@@ -0,0 +1,152 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_tracing::tracing;
+
+use crate::catalogs::Catalog;
+use crate::sessions::QueryContext;
+use crate::storages::system::table::AsyncOneBlockSystemTable;
+use crate::storages::system::table::AsyncSystemTable;
+use crate::storages::Table;
+use crate::storages::TableStatistics;
+
+/// The size of every table, followed by a rollup row of each database whose `table` is NULL.
+pub struct TableSizesTable {
+    table_info: TableInfo,
+}
+
+#[derive(Default)]
+struct SizeRows {
+    databases: Vec<Vec<u8>>,
+    tables: Vec<Option<Vec<u8>>>,
+    engines: Vec<Option<Vec<u8>>>,
+    row_counts: Vec<Option<u64>>,
+    uncompressed: Vec<Option<u64>>,
+    compressed: Vec<Option<u64>>,
+    index_sizes: Vec<Option<u64>>,
+    snapshot_counts: Vec<Option<u64>>,
+}
+
+impl SizeRows {
+    fn push(&mut self, database: &str, table: Option<&dyn Table>, stats: Option<&TableStatistics>) {
+        self.databases.push(database.as_bytes().to_vec());
+        self.tables
+            .push(table.map(|t| t.name().as_bytes().to_vec()));
+        self.engines
+            .push(table.map(|t| t.engine().as_bytes().to_vec()));
+        self.row_counts.push(stats.map(|s| s.num_rows));
+        self.uncompressed.push(stats.map(|s| s.data_bytes));
+        self.compressed.push(stats.map(|s| s.compressed_data_bytes));
+        self.index_sizes.push(stats.map(|s| s.index_data_bytes));
+        self.snapshot_counts
+            .push(stats.and_then(|s| s.number_of_snapshots));
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for TableSizesTable {
+    const NAME: &'static str = "system.table_sizes";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(&self, ctx: Arc<QueryContext>) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog();
+        let databases = catalog.list_databases(tenant.as_str()).await?;
+
+        let mut rows = SizeRows::default();
+        for database in databases {
+            let name = database.name();
+            let mut total = TableStatistics::default();
+            for table in catalog.list_tables(tenant.as_str(), name).await? {
+                let stats = match table.statistics(ctx.clone()).await {
+                    Ok(stats) => stats,
+                    Err(e) => {
+                        // one broken table should not hide the sizes of the others
+                        tracing::warn!(
+                            "Failed to get the size of table {}.{}: {}",
+                            name,
+                            table.name(),
+                            e
+                        );
+                        None
+                    }
+                };
+
+                if let Some(stats) = &stats {
+                    total.num_rows += stats.num_rows;
+                    total.data_bytes += stats.data_bytes;
+                    total.compressed_data_bytes += stats.compressed_data_bytes;
+                    total.index_data_bytes += stats.index_data_bytes;
+                    if let Some(n) = stats.number_of_snapshots {
+                        total.number_of_snapshots =
+                            Some(total.number_of_snapshots.unwrap_or(0) + n);
+                    }
+                }
+                rows.push(name, Some(table.as_ref()), stats.as_ref());
+            }
+            rows.push(name, None, Some(&total));
+        }
+
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(rows.databases),
+            Series::from_data(rows.tables),
+            Series::from_data(rows.engines),
+            Series::from_data(rows.row_counts),
+            Series::from_data(rows.uncompressed),
+            Series::from_data(rows.compressed),
+            Series::from_data(rows.index_sizes),
+            Series::from_data(rows.snapshot_counts),
+        ]))
+    }
+}
+
+impl TableSizesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("database", Vu8::to_data_type()),
+            DataField::new_nullable("table", Vu8::to_data_type()),
+            DataField::new_nullable("engine", Vu8::to_data_type()),
+            DataField::new_nullable("row_count", u64::to_data_type()),
+            DataField::new_nullable("bytes_uncompressed", u64::to_data_type()),
+            DataField::new_nullable("bytes_compressed", u64::to_data_type()),
+            DataField::new_nullable("index_size", u64::to_data_type()),
+            DataField::new_nullable("snapshot_count", u64::to_data_type()),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'table_sizes'".to_string(),
+            name: "table_sizes".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTableSizes".to_string(),
+
+                ..Default::default()
+            },
+        };
+
+        AsyncOneBlockSystemTable::create(TableSizesTable { table_info })
+    }
+}
@@ -23,6 +23,7 @@ use common_meta_types::TableMeta;
 
 use crate::catalogs::Catalog;
 use crate::sessions::QueryContext;
+use crate::sql::OPT_KEY_COMMENT;
 use crate::storages::system::table::AsyncOneBlockSystemTable;
 use crate::storages::system::table::AsyncSystemTable;
 use crate::storages::Table;
@@ -72,12 +73,22 @@ impl AsyncSystemTable for TablesTable {
             })
             .collect();
         let created_ons: Vec<&[u8]> = created_ons.iter().map(|s| s.as_bytes()).collect();
+        let comments: Vec<&[u8]> = database_tables
+            .iter()
+            .map(|(_, v)| {
+                v.options()
+                    .get(OPT_KEY_COMMENT)
+                    .map(|s| s.as_bytes())
+                    .unwrap_or_default()
+            })
+            .collect();
 
         Ok(DataBlock::create(self.table_info.schema(), vec![
             Series::from_data(databases),
             Series::from_data(names),
             Series::from_data(engines),
             Series::from_data(created_ons),
+            Series::from_data(comments),
         ]))
     }
 }
@@ -89,6 +100,7 @@ impl TablesTable {
             DataField::new("name", Vu8::to_data_type()),
             DataField::new("engine", Vu8::to_data_type()),
             DataField::new("created_on", Vu8::to_data_type()),
+            DataField::new("comment", Vu8::to_data_type()),
         ]);
 
         let table_info = TableInfo {
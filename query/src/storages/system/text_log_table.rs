@@ -0,0 +1,97 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_tracing::TextLogStore;
+
+use crate::sessions::QueryContext;
+use crate::storages::system::table::SyncOneBlockSystemTable;
+use crate::storages::system::table::SyncSystemTable;
+use crate::storages::Table;
+
+/// The tracing events of this node, persisted under the log dir by the `TextLogStore`
+/// and rotated by the `text_log_max_rows` and `text_log_retention_secs` log configs.
+pub struct TextLogTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for TextLogTable {
+    const NAME: &'static str = "system.text_log";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, ctx: Arc<QueryContext>) -> Result<DataBlock> {
+        let records = TextLogStore::instance().records();
+        let local_id = ctx.get_cluster().local_id();
+
+        let mut nodes = Vec::with_capacity(records.len());
+        let mut event_times = Vec::with_capacity(records.len());
+        let mut levels = Vec::with_capacity(records.len());
+        let mut targets = Vec::with_capacity(records.len());
+        let mut thread_names = Vec::with_capacity(records.len());
+        let mut messages = Vec::with_capacity(records.len());
+        for record in &records {
+            nodes.push(local_id.as_bytes());
+            event_times.push(record.event_time);
+            levels.push(record.level.as_bytes());
+            targets.push(record.target.as_bytes());
+            thread_names.push(record.thread_name.as_bytes());
+            messages.push(record.message.as_bytes());
+        }
+
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(nodes),
+            Series::from_data(event_times),
+            Series::from_data(levels),
+            Series::from_data(targets),
+            Series::from_data(thread_names),
+            Series::from_data(messages),
+        ]))
+    }
+}
+
+impl TextLogTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("node", Vu8::to_data_type()),
+            DataField::new("event_time", DateTime64Type::arc(3, None)),
+            DataField::new("level", Vu8::to_data_type()),
+            DataField::new("target", Vu8::to_data_type()),
+            DataField::new("thread_name", Vu8::to_data_type()),
+            DataField::new("message", Vu8::to_data_type()),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'text_log'".to_string(),
+            name: "text_log".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTextLog".to_string(),
+                ..Default::default()
+            },
+        };
+
+        SyncOneBlockSystemTable::create(TextLogTable { table_info })
+    }
+}
@@ -163,7 +163,10 @@ impl Table for NumbersTable {
             fake_partitions as usize,
         );
 
-        let parts = generate_numbers_parts(0, ctx.get_settings().get_max_threads()? as u64, total);
+        // Don't split the numbers into more parts than there are blocks.
+        let max_threads = ctx.get_settings().get_max_threads()?;
+        let workers = std::cmp::min(max_threads, fake_partitions);
+        let parts = generate_numbers_parts(0, workers, total);
         Ok((statistics, parts))
     }
 
@@ -22,12 +22,17 @@ use common_meta_types::UserInfo;
 
 pub use crate::configs::Config;
 use crate::users::auth::jwt::JwtAuthenticator;
+use crate::users::auth::AuthProvider;
+use crate::users::auth::LdapAuthProvider;
+use crate::users::auth::PasswordAuthProvider;
 use crate::users::UserApiProvider;
 
 pub struct AuthMgr {
     tenant: String,
     users: Arc<UserApiProvider>,
     jwt: Option<JwtAuthenticator>,
+    password: PasswordAuthProvider,
+    ldap: Option<LdapAuthProvider>,
 }
 
 pub enum Credential {
@@ -46,6 +51,8 @@ impl AuthMgr {
         Ok(AuthMgr {
             users,
             tenant: cfg.query.tenant_id.clone(),
+            password: PasswordAuthProvider,
+            ldap: LdapAuthProvider::try_create(&cfg)?,
             jwt: JwtAuthenticator::try_create(cfg).await?,
         })
     }
@@ -80,24 +87,22 @@ impl AuthMgr {
                         h.as_ref().unwrap_or(&"%".to_string()),
                     )
                     .await?;
-                match &user.auth_info {
-                    AuthInfo::None => Ok(user),
-                    AuthInfo::Password {
-                        hash_value: h,
-                        hash_method: t,
-                    } => match p {
-                        None => Err(ErrorCode::AuthenticateFailure("password required")),
-                        Some(p) => {
-                            if *h == t.hash(p) {
-                                Ok(user)
-                            } else {
-                                Err(ErrorCode::AuthenticateFailure("wrong password"))
-                            }
-                        }
-                    },
-                    _ => Err(ErrorCode::AuthenticateFailure("wrong auth type")),
-                }
+                self.get_auth_provider(&user.auth_info)?
+                    .authenticate(&user, p.as_deref())
+                    .await?;
+                Ok(user)
             }
         }
     }
+
+    fn get_auth_provider(&self, auth_info: &AuthInfo) -> Result<&dyn AuthProvider> {
+        match auth_info {
+            AuthInfo::None | AuthInfo::Password { .. } => Ok(&self.password),
+            AuthInfo::Ldap { .. } => match &self.ldap {
+                Some(ldap) => Ok(ldap),
+                None => Err(ErrorCode::AuthenticateFailure("ldap auth not configured.")),
+            },
+            AuthInfo::JWT => Err(ErrorCode::AuthenticateFailure("wrong auth type")),
+        }
+    }
 }
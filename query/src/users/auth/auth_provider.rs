@@ -0,0 +1,52 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::AuthInfo;
+use common_meta_types::UserInfo;
+
+/// Checks the password a client presents for a user.
+///
+/// Each provider handles the `AuthInfo` kinds it knows, the user has been looked up already.
+#[async_trait::async_trait]
+pub trait AuthProvider: Sync + Send {
+    async fn authenticate(&self, user: &UserInfo, password: Option<&[u8]>) -> Result<()>;
+}
+
+/// Checks the password against the hash stored in the user info.
+pub struct PasswordAuthProvider;
+
+#[async_trait::async_trait]
+impl AuthProvider for PasswordAuthProvider {
+    async fn authenticate(&self, user: &UserInfo, password: Option<&[u8]>) -> Result<()> {
+        match &user.auth_info {
+            AuthInfo::None => Ok(()),
+            AuthInfo::Password {
+                hash_value: h,
+                hash_method: t,
+            } => match password {
+                None => Err(ErrorCode::AuthenticateFailure("password required")),
+                Some(p) => {
+                    if *h == t.hash(p) {
+                        Ok(())
+                    } else {
+                        Err(ErrorCode::AuthenticateFailure("wrong password"))
+                    }
+                }
+            },
+            _ => Err(ErrorCode::AuthenticateFailure("wrong auth type")),
+        }
+    }
+}
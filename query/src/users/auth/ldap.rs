@@ -0,0 +1,116 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::AuthInfo;
+use common_meta_types::UserInfo;
+use common_tracing::tracing;
+use ldap3::LdapConnAsync;
+use ldap3::LdapConnSettings;
+
+use crate::configs::Config;
+use crate::users::auth::AuthProvider;
+
+const LDAP_USER_PLACEHOLDER: &str = "{user}";
+const LDAP_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Checks the password by binding to the LDAP server as the user.
+pub struct LdapAuthProvider {
+    url: String,
+    bind_dn_template: String,
+}
+
+impl LdapAuthProvider {
+    pub fn try_create(cfg: &Config) -> Result<Option<Self>> {
+        if cfg.query.ldap_url.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(LdapAuthProvider {
+            url: cfg.query.ldap_url.clone(),
+            bind_dn_template: cfg.query.ldap_bind_dn_template.clone(),
+        }))
+    }
+
+    /// The DN to bind as, the one set on the user wins over the configured template.
+    pub fn bind_dn(&self, user: &UserInfo) -> Result<String> {
+        match &user.auth_info {
+            AuthInfo::Ldap {
+                bind_dn: Some(bind_dn),
+            } => Ok(bind_dn.clone()),
+            AuthInfo::Ldap { bind_dn: None } => {
+                if !self.bind_dn_template.contains(LDAP_USER_PLACEHOLDER) {
+                    return Err(ErrorCode::AuthenticateFailure(format!(
+                        "ldap bind dn template must contain {}.",
+                        LDAP_USER_PLACEHOLDER
+                    )));
+                }
+
+                let user_name = ldap3::dn_escape(user.name.as_str());
+                Ok(self
+                    .bind_dn_template
+                    .replace(LDAP_USER_PLACEHOLDER, &user_name))
+            }
+            _ => Err(ErrorCode::AuthenticateFailure("wrong auth type")),
+        }
+    }
+
+    async fn bind(&self, bind_dn: &str, password: &str) -> Result<()> {
+        let settings = LdapConnSettings::new().set_conn_timeout(LDAP_CONNECT_TIMEOUT);
+        let (conn, mut ldap) = LdapConnAsync::with_settings(settings, &self.url)
+            .await
+            .map_err(|cause| {
+                ErrorCode::AuthenticateFailure(format!("cannot connect to ldap server: {}", cause))
+            })?;
+
+        tokio::spawn(async move {
+            if let Err(cause) = conn.drive().await {
+                tracing::warn!("Ldap connection failure: {:?}", cause);
+            }
+        });
+
+        let bind_result = ldap.simple_bind(bind_dn, password).await;
+        if let Err(cause) = ldap.unbind().await {
+            tracing::warn!("Cannot unbind ldap connection: {:?}", cause);
+        }
+
+        match bind_result.and_then(|res| res.success()) {
+            Ok(_) => Ok(()),
+            Err(cause) => {
+                tracing::debug!("Ldap bind as {} failed: {:?}", bind_dn, cause);
+                Err(ErrorCode::AuthenticateFailure("wrong password"))
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AuthProvider for LdapAuthProvider {
+    async fn authenticate(&self, user: &UserInfo, password: Option<&[u8]>) -> Result<()> {
+        let bind_dn = self.bind_dn(user)?;
+
+        // An empty password makes an unauthenticated bind, which most servers accept.
+        let password = match password {
+            None | Some([]) => return Err(ErrorCode::AuthenticateFailure("password required")),
+            Some(password) => std::str::from_utf8(password)
+                .map_err(|_| ErrorCode::AuthenticateFailure("ldap password must be valid utf-8"))?,
+        };
+
+        self.bind(&bind_dn, password).await
+    }
+}
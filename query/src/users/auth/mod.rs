@@ -13,4 +13,10 @@
 // limitations under the License.
 
 pub(crate) mod auth_mgr;
+mod auth_provider;
 mod jwt;
+mod ldap;
+
+pub use auth_provider::AuthProvider;
+pub use auth_provider::PasswordAuthProvider;
+pub use ldap::LdapAuthProvider;
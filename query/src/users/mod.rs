@@ -15,6 +15,7 @@
 mod role_mgr;
 mod user;
 mod user_api;
+mod user_copy_history;
 mod user_mgr;
 mod user_stage;
 mod user_udf;
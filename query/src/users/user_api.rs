@@ -15,6 +15,8 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_management::CopyHistoryApi;
+use common_management::CopyHistoryMgr;
 use common_management::RoleApi;
 use common_management::RoleMgr;
 use common_management::SettingApi;
@@ -61,6 +63,13 @@ impl UserApiProvider {
         Ok(Arc::new(UdfMgr::create(self.client.clone(), tenant)?))
     }
 
+    pub fn get_copy_history_api_client(&self, tenant: &str) -> Result<Arc<dyn CopyHistoryApi>> {
+        Ok(Arc::new(CopyHistoryMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
     pub fn get_setting_api_client(&self, tenant: &str) -> Result<Arc<dyn SettingApi>> {
         Ok(Arc::new(SettingMgr::create(self.client.clone(), tenant)?))
     }
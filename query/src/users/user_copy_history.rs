@@ -0,0 +1,55 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_meta_types::CopiedFileInfo;
+
+use crate::users::UserApiProvider;
+
+/// Copy history operations.
+impl UserApiProvider {
+    // Record the load result of a copied file.
+    pub async fn upsert_copied_file(&self, tenant: &str, info: CopiedFileInfo) -> Result<u64> {
+        let copy_history_api_client = self.get_copy_history_api_client(tenant)?;
+        let upsert_copied_file = copy_history_api_client.upsert_copied_file(info);
+        match upsert_copied_file.await {
+            Ok(res) => Ok(res),
+            Err(e) => Err(e.add_message_back("(while upsert copied file).")),
+        }
+    }
+
+    // Get the load results of the files copied into a table.
+    pub async fn get_table_copied_files(
+        &self,
+        tenant: &str,
+        table_id: u64,
+    ) -> Result<Vec<CopiedFileInfo>> {
+        let copy_history_api_client = self.get_copy_history_api_client(tenant)?;
+        let get_table_copied_files = copy_history_api_client.get_table_copied_files(table_id);
+        match get_table_copied_files.await {
+            Ok(res) => Ok(res),
+            Err(e) => Err(e.add_message_back("(while get table copied files).")),
+        }
+    }
+
+    // Get the load results of all the tables for the tenant.
+    pub async fn get_copied_files(&self, tenant: &str) -> Result<Vec<CopiedFileInfo>> {
+        let copy_history_api_client = self.get_copy_history_api_client(tenant)?;
+        let get_copied_files = copy_history_api_client.get_copied_files();
+        match get_copied_files.await {
+            Ok(res) => Ok(res),
+            Err(e) => Err(e.add_message_back("(while get copied files).")),
+        }
+    }
+}
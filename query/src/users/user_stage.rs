@@ -41,6 +41,16 @@ impl UserApiProvider {
         }
     }
 
+    // Add a new stage, or overwrite the existing one.
+    pub async fn replace_stage(&self, tenant: &str, info: UserStageInfo) -> Result<u64> {
+        let stage_api_provider = self.get_stage_api_client(tenant)?;
+        let replace_stage = stage_api_provider.replace_stage(info);
+        match replace_stage.await {
+            Ok(res) => Ok(res),
+            Err(e) => Err(e.add_message_back("(while replace stage).")),
+        }
+    }
+
     // Get one stage from by tenant.
     pub async fn get_stage(&self, tenant: &str, stage_name: &str) -> Result<UserStageInfo> {
         let stage_api_provider = self.get_stage_api_client(tenant)?;
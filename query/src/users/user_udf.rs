@@ -51,6 +51,16 @@ impl UserApiProvider {
         }
     }
 
+    // Add a new UDF, or overwrite the existing one.
+    pub async fn replace_udf(&self, tenant: &str, info: UserDefinedFunction) -> Result<u64> {
+        let udf_api_client = self.get_udf_api_client(tenant)?;
+        let replace_udf = udf_api_client.replace_udf(info);
+        match replace_udf.await {
+            Ok(res) => Ok(res),
+            Err(e) => Err(e.add_message_back("(while replace UDF).")),
+        }
+    }
+
     // Get a UDF by name.
     pub async fn get_udf(&self, tenant: &str, udf_name: &str) -> Result<UserDefinedFunction> {
         let udf_api_client = self.get_udf_api_client(tenant)?;
@@ -21,6 +21,7 @@ use poem::http::header;
 use poem::http::Method;
 use poem::http::StatusCode;
 use poem::http::Uri;
+use poem::post;
 use poem::Endpoint;
 use poem::EndpointExt;
 use poem::Request;
@@ -34,6 +35,7 @@ async fn test_cluster() -> Result<()> {
     let sessions = SessionManagerBuilder::create().build()?;
     let cluster_router = Route::new()
         .at("/v1/cluster/list", get(cluster_list_handler))
+        .at("/v1/cluster/drain/:node_id", post(cluster_drain_handler))
         .data(sessions);
 
     // List Node
@@ -53,6 +55,62 @@ async fn test_cluster() -> Result<()> {
         let body = response.into_body().into_vec().await.unwrap();
         let nodes = serde_json::from_str::<Vec<NodeInfo>>(&String::from_utf8_lossy(&body))?;
         assert_eq!(nodes.len(), 1);
+        assert!(!nodes[0].draining);
+    }
+
+    // Drain unknown node
+    {
+        let response = cluster_router
+            .call(
+                Request::builder()
+                    .uri(Uri::from_static("/v1/cluster/drain/unknown_node"))
+                    .method(Method::POST)
+                    .finish(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    // Drain the local node, it stays in the list until its queries finish
+    {
+        let response = cluster_router
+            .call(
+                Request::builder()
+                    .uri(Uri::from_static("/v1/cluster/list"))
+                    .method(Method::GET)
+                    .finish(),
+            )
+            .await
+            .unwrap();
+        let body = response.into_body().into_vec().await.unwrap();
+        let nodes = serde_json::from_str::<Vec<NodeInfo>>(&String::from_utf8_lossy(&body))?;
+
+        let uri = format!("/v1/cluster/drain/{}", nodes[0].id);
+        let response = cluster_router
+            .call(
+                Request::builder()
+                    .uri(uri.parse::<Uri>().unwrap())
+                    .method(Method::POST)
+                    .finish(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = cluster_router
+            .call(
+                Request::builder()
+                    .uri(Uri::from_static("/v1/cluster/list"))
+                    .method(Method::GET)
+                    .finish(),
+            )
+            .await
+            .unwrap();
+        let body = response.into_body().into_vec().await.unwrap();
+        let nodes = serde_json::from_str::<Vec<NodeInfo>>(&String::from_utf8_lossy(&body))?;
+        assert_eq!(nodes.len(), 1);
+        assert!(nodes[0].draining);
     }
 
     Ok(())
@@ -16,4 +16,5 @@ mod cluster;
 mod config;
 mod health;
 mod logs;
+mod pipeline;
 mod status;
@@ -0,0 +1,90 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use databend_query::api::http::v1::pipeline::pipeline_handler;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::pipelines::new::executor::PipelineProfile;
+use databend_query::sessions::SessionType;
+use databend_query::sql::PlanParser;
+use poem::get;
+use poem::http::Method;
+use poem::http::StatusCode;
+use poem::http::Uri;
+use poem::Endpoint;
+use poem::EndpointExt;
+use poem::Request;
+use poem::Route;
+use pretty_assertions::assert_eq;
+
+use crate::tests::SessionManagerBuilder;
+
+#[tokio::test]
+async fn test_pipeline() -> Result<()> {
+    let sessions = SessionManagerBuilder::create().build()?;
+    let ep = Route::new()
+        .at("/v1/processes/:session_id/pipeline", get(pipeline_handler))
+        .data(sessions.clone());
+
+    let get = |uri: String| {
+        let ep = &ep;
+        async move {
+            let response = ep
+                .call(
+                    Request::builder()
+                        .uri(Uri::try_from(uri).unwrap())
+                        .method(Method::GET)
+                        .finish(),
+                )
+                .await
+                .unwrap();
+            let status = response.status();
+            let body = response.into_body().into_string().await.unwrap();
+            (status, body)
+        }
+    };
+
+    // no session
+    let (status, _) = get("/v1/processes/unknown/pipeline".to_string()).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let session = sessions.create_session(SessionType::HTTPQuery).await?;
+    let ctx = session.create_query_context().await?;
+    let uri = format!("/v1/processes/{}/pipeline", session.get_id());
+
+    // no running query
+    let (status, _) = get(uri.clone()).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let plan = PlanParser::parse(ctx.clone(), "select number + 1 from numbers_mt(10)").await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+    let _stream = interpreter.execute(None).await?;
+
+    let (status, body) = get(uri.clone()).await;
+    assert_eq!(status, StatusCode::OK);
+    let profile = serde_json::from_str::<PipelineProfile>(&body).unwrap();
+    assert!(!profile.processors.is_empty());
+    assert!(!profile.edges.is_empty());
+
+    let (status, body) = get(format!("{}?format=dot", uri)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.starts_with("digraph {"));
+    assert!(body.contains("PullingExecutorSink"));
+
+    let (status, _) = get(format!("{}?format=svg", uri)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    Ok(())
+}
@@ -19,9 +19,15 @@ use common_base::tokio;
 use common_datavalues::DataValue;
 use common_exception::Result;
 use common_planners::Expression;
+use databend_query::api::is_compatible_protocol_version;
+use databend_query::api::BroadcastAction;
+use databend_query::api::CancelAction;
 use databend_query::api::FlightAction;
 use databend_query::api::ShuffleAction;
+use databend_query::api::FLIGHT_PROTOCOL_VERSION;
+use databend_query::api::MIN_COMPATIBLE_FLIGHT_PROTOCOL_VERSION;
 use databend_query::sql::PlanParser;
+use tonic::Code;
 
 use crate::tests::create_query_context;
 
@@ -60,3 +66,66 @@ async fn test_shuffle_action_try_into() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_plan_fragment_round_trip() -> Result<()> {
+    let ctx = create_query_context().await?;
+
+    let queries = vec![
+        "SELECT number FROM numbers_mt(10) WHERE number > 1 ORDER BY number DESC LIMIT 3",
+        "SELECT number % 3 AS k, sum(number) FROM numbers(10) GROUP BY k HAVING sum(number) > 2",
+        "SELECT CAST(number AS Nullable(String)), toDate(number) FROM numbers(5)",
+        "SELECT number FROM numbers(5) WHERE EXISTS (SELECT name FROM system.settings)",
+    ];
+
+    for query in queries {
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let action = BroadcastAction {
+            query_id: String::from("query_id"),
+            stage_id: String::from("stage_id"),
+            plan: plan.clone(),
+            sinks: vec![String::from("stream_id")],
+        };
+        let body: Vec<u8> = action.try_into()?;
+        let action: BroadcastAction = body.try_into()?;
+        let actual = action.plan;
+
+        assert_eq!(plan, actual, "{}", query);
+        assert_eq!(plan.schema(), actual.schema(), "{}", query);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_flight_action_protocol_version() -> Result<()> {
+    let cancel_action = CancelAction {
+        query_id: String::from("query_id"),
+    };
+
+    let body: Vec<u8> = cancel_action.try_into()?;
+    let value: serde_json::Value = serde_json::from_slice(&body)?;
+    assert_eq!(value["protocol_version"], FLIGHT_PROTOCOL_VERSION);
+
+    let action: CancelAction = body.try_into()?;
+    assert_eq!(action.query_id, "query_id");
+
+    // Nodes that predate the negotiation send no version, they are served during a rolling upgrade.
+    let body = br#"{"query_id":"query_id"}"#.to_vec();
+    let action: CancelAction = body.try_into()?;
+    assert_eq!(action.query_id, "query_id");
+
+    let body = format!(
+        r#"{{"query_id":"query_id","protocol_version":{}}}"#,
+        FLIGHT_PROTOCOL_VERSION + 1
+    );
+    let status = TryInto::<CancelAction>::try_into(body.into_bytes()).unwrap_err();
+    assert_eq!(status.code(), Code::FailedPrecondition);
+
+    assert!(is_compatible_protocol_version(FLIGHT_PROTOCOL_VERSION));
+    assert!(is_compatible_protocol_version(
+        MIN_COMPATIBLE_FLIGHT_PROTOCOL_VERSION
+    ));
+    assert!(!is_compatible_protocol_version(FLIGHT_PROTOCOL_VERSION + 1));
+    Ok(())
+}
@@ -31,6 +31,7 @@ use databend_query::api::FlightTicket;
 use databend_query::api::ShuffleAction;
 use databend_query::api::StreamTicket;
 use databend_query::sql::PlanParser;
+use futures::TryStreamExt;
 use tonic::Request;
 
 use crate::tests::create_query_context;
@@ -151,6 +152,26 @@ async fn test_do_flight_action_with_abort_and_new_session() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_do_get_system_table() -> Result<()> {
+    let sessions = SessionManagerBuilder::create().build()?;
+    let dispatcher = Arc::new(DatabendQueryFlightDispatcher::create());
+    let service = DatabendQueryFlightService::create(dispatcher, sessions);
+
+    let ticket = FlightTicket::system_table("query_id", "one");
+    let response = service.do_get(Request::new(ticket.try_into()?)).await?;
+    let flight_data = response.into_inner().try_collect::<Vec<_>>().await?;
+    assert_eq!(flight_data.len(), 1);
+
+    let ticket = FlightTicket::system_table("query_id", "not_exists");
+    match service.do_get(Request::new(ticket.try_into()?)).await {
+        Ok(_) => panic!("Reading an unknown system table must fail"),
+        Err(status) => assert!(status.message().contains("not_exists")),
+    }
+
+    Ok(())
+}
+
 fn do_get_request(query_id: &str, stage_id: &str) -> Result<Request<Ticket>> {
     let stream_ticket = FlightTicket::StreamTicket(StreamTicket {
         query_id: String::from(query_id),
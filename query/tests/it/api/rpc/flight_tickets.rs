@@ -36,6 +36,24 @@ async fn test_stream_ticket_try_into() -> Result<()> {
             assert_eq!(ticket.stage_id, "stage_id");
             assert_eq!(ticket.stream, "stream");
         }
+        _ => unreachable!(),
+    };
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_system_table_ticket_try_into() -> Result<()> {
+    let from_ticket = FlightTicket::system_table("query_id", "processes");
+
+    let to_ticket: Ticket = from_ticket.try_into()?;
+    let from_ticket: FlightTicket = to_ticket.try_into()?;
+    match from_ticket {
+        FlightTicket::SystemTableTicket(ticket) => {
+            assert_eq!(ticket.query_id, "query_id");
+            assert_eq!(ticket.table, "processes");
+        }
+        _ => unreachable!(),
     };
 
     Ok(())
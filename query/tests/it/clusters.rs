@@ -12,9 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_base::tokio;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::NodeInfo;
+use databend_query::api::FLIGHT_PROTOCOL_VERSION;
+use databend_query::clusters::Cluster;
 use databend_query::clusters::ClusterDiscovery;
+use databend_query::clusters::NodeRoutingRules;
 use pretty_assertions::assert_eq;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -28,6 +35,7 @@ async fn test_single_cluster_discovery() -> Result<()> {
     assert_eq!(discover_cluster_nodes.len(), 1);
     assert!(discover_cluster.is_empty());
     assert!(discover_cluster.is_local(&discover_cluster_nodes[0]));
+    assert_eq!(discover_cluster_nodes[0].version, FLIGHT_PROTOCOL_VERSION);
     Ok(())
 }
 
@@ -62,3 +70,82 @@ async fn test_single_cluster_discovery() -> Result<()> {
 //     assert_eq!(discover_cluster_nodes_1, discover_cluster_nodes_2);
 //     Ok(())
 // }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_cluster_discovery_node_tags() -> Result<()> {
+    let mut conf = crate::tests::ConfigBuilder::create().config();
+    conf.query.node_tags = "ingest, etl,".to_string();
+    let cluster_discovery = ClusterDiscovery::create_global(conf.clone()).await?;
+    cluster_discovery.register_to_metastore(&conf).await?;
+
+    let nodes = cluster_discovery.discover().await?.get_nodes();
+    assert_eq!(nodes[0].tags, vec!["ingest".to_string(), "etl".to_string()]);
+    Ok(())
+}
+
+#[test]
+fn test_cluster_with_tag() -> Result<()> {
+    let node = |id: &str, tags: &[&str]| {
+        let mut node = NodeInfo::create(id.to_string(), 1, "127.0.0.1:9090".to_string());
+        node.tags = tags.iter().map(|tag| tag.to_string()).collect();
+        Arc::new(node)
+    };
+
+    let cluster = Cluster::create(
+        vec![
+            node("local", &[]),
+            node("loader", &["ingest"]),
+            node("adhoc", &["adhoc", "etl"]),
+        ],
+        "local".to_string(),
+    );
+
+    // The local node coordinates the query, it's always kept.
+    let ingest = cluster.with_tag("ingest");
+    let ids = ingest
+        .get_nodes()
+        .iter()
+        .map(|n| n.id.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec!["local", "loader"]);
+    assert!(!ingest.is_empty());
+
+    let unknown = cluster.with_tag("unknown");
+    assert_eq!(unknown.get_nodes().len(), 1);
+    assert!(unknown.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_node_routing_rules() -> Result<()> {
+    let rules = NodeRoutingRules::try_create("")?;
+    assert!(rules.is_empty());
+    assert_eq!(rules.route("root", &[], "insert"), None);
+
+    let rules = NodeRoutingRules::try_create(
+        "statement:INSERT=ingest, statement:select=adhoc, role:etl_role=etl, user:loader=ingest",
+    )?;
+    assert!(!rules.is_empty());
+    assert_eq!(rules.route("root", &[], "insert"), Some("ingest"));
+    assert_eq!(rules.route("root", &[], "select"), Some("adhoc"));
+    assert_eq!(rules.route("root", &[], "other"), None);
+
+    // The user rules win over the role rules, which win over the statement rules.
+    let roles = vec!["etl_role".to_string()];
+    assert_eq!(rules.route("root", &roles, "select"), Some("etl"));
+    assert_eq!(rules.route("loader", &roles, "select"), Some("ingest"));
+
+    // COPY runs on the node receiving it only, it can't be routed.
+    for invalid in [
+        "insert=ingest",
+        "statement:insert",
+        "table:t1=etl",
+        "user:=etl",
+        "statement:copy=ingest",
+        "statement:update=etl",
+    ] {
+        let err = NodeRoutingRules::try_create(invalid).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InvalidConfigCode(), "{}", invalid);
+    }
+    Ok(())
+}
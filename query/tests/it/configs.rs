@@ -75,11 +75,22 @@ table_disk_cache_root = \"_cache\"
 table_disk_cache_mb_size = 1024
 management_mode = false
 jwt_key_file = \"\"
+ldap_url = \"\"
+ldap_bind_dn_template = \"\"
+outfile_allowed_dirs = \"\"
+plan_cache_count = 0
+result_cache_bytes = 67108864
+node_tags = \"\"
+node_routing_rules = \"\"
+consistency_check_interval_secs = 0
+consistency_check_retention_secs = 86400
 
 [log]
 log_level = \"INFO\"
 log_dir = \"./_logs\"
 log_query_enabled = false
+text_log_max_rows = 100000
+text_log_retention_secs = 86400
 
 [meta]
 meta_embedded_dir = \"./_meta_embedded\"
@@ -111,6 +122,9 @@ root = \"\"
 account = \"\"
 master_key = \"\"
 container = \"\"
+
+[storage.encryption]
+encryption_key = \"\"
 ";
 
     let tom_actual = toml::to_string(&actual).unwrap();
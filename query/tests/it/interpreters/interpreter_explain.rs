@@ -52,3 +52,79 @@ async fn test_explain_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_explain_json_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+
+    let query = "EXPLAIN FORMAT JSON SELECT number FROM numbers_mt(10) WHERE (number + 1) = 4";
+
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx, plan)?;
+    assert_eq!(executor.name(), "ExplainInterpreter");
+
+    let stream = executor.execute(None).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = &result[0];
+    assert_eq!(block.num_columns(), 1);
+    assert_eq!(block.column(0).len(), 1);
+
+    let document = block.column(0).get(0).as_string()?;
+    let json: serde_json::Value = serde_json::from_slice(&document)?;
+    assert_eq!(json["kind"], "Projection");
+    assert_eq!(json["schema"][0]["name"], "number");
+    assert_eq!(json["children"][0]["kind"], "Filter");
+    assert_eq!(json["children"][0]["children"][0]["kind"], "ReadDataSource");
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_explain_graphviz_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+
+    let query = "EXPLAIN GRAPHVIZ SELECT number FROM numbers_mt(10) WHERE (number + 1) = 4";
+
+    // Without schema.
+    {
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        assert_eq!(executor.name(), "ExplainInterpreter");
+
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let block = &result[0];
+        assert_eq!(block.num_columns(), 1);
+        assert_eq!(block.column(0).len(), 1);
+
+        let dot = String::from_utf8(block.column(0).get(0).as_string()?)?;
+        assert!(dot.starts_with("// Begin Databend GraphViz Plan"));
+        assert!(dot.contains("node2 [shape=box, label=\"Projection\\nexpr: number\"];"));
+        assert!(
+            dot.contains("node1 [shape=box, label=\"Filter\\npredicate: ((number + 1) = 4)\"];")
+        );
+        assert!(dot.contains("node0 -> node1;"));
+        assert!(dot.contains("node1 -> node2;"));
+        assert!(!dot.contains("schema: "));
+    }
+
+    // With schema.
+    {
+        ctx.get_settings().set_settings(
+            "explain_graphviz_with_schema".to_string(),
+            "1".to_string(),
+            false,
+        )?;
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let dot = String::from_utf8(result[0].column(0).get(0).as_string()?)?;
+        assert!(dot.contains(
+            "node2 [shape=box, label=\"Projection\\nexpr: number\\nschema: [number:UInt64]\"];"
+        ));
+    }
+
+    Ok(())
+}
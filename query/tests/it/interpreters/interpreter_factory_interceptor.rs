@@ -47,7 +47,7 @@ async fn test_interpreter_interceptor() -> Result<()> {
             "| 97     |",
             "| 98     |",
             "| 99     |",
-            "+--------+",
+            "+----------------------------------------+",
         ];
         common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
         interpreter.finish().await?;
@@ -63,12 +63,12 @@ async fn test_interpreter_interceptor() -> Result<()> {
         let result = stream.try_collect::<Vec<_>>().await?;
 
         let expected = vec![
-            "+----------+--------------+-----------+-----------+------------+-----------------+--------------+---------------+-------------+--------------+------------+------------------------------------------------------+----------+--------------------------------+---------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------+",
-            "| log_type | handler_type | cpu_usage | scan_rows | scan_bytes | scan_partitions | written_rows | written_bytes | result_rows | result_bytes | query_kind | query_text                                           | sql_user | sql_user_quota                 | session_settings                                                                                                                                                                                                                                                          |",
-            "+----------+--------------+-----------+-----------+------------+-----------------+--------------+---------------+-------------+--------------+------------+------------------------------------------------------+----------+--------------------------------+---------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------+",
-            "| 1        | Test         | 8         | 0         | 0          | 0               | 0            | 0             | 0           | 0            | SelectPlan | select number from numbers_mt(100) where number > 90 | root     | UserQuota<cpu:0,mem:0,store:0> | enable_new_processor_framework=1, flight_client_timeout=60, max_block_size=10000, max_threads=8, storage_occ_backoff_init_delay_ms=5, storage_occ_backoff_max_delay_ms=20000, storage_occ_backoff_max_elapsed_ms=120000, storage_read_buffer_size=1048576, scope: SESSION |",
-            "| 2        | Test         | 8         | 100       | 800        | 0               | 0            | 0             | 9           | 72           | SelectPlan | select number from numbers_mt(100) where number > 90 | root     | UserQuota<cpu:0,mem:0,store:0> | enable_new_processor_framework=1, flight_client_timeout=60, max_block_size=10000, max_threads=8, storage_occ_backoff_init_delay_ms=5, storage_occ_backoff_max_delay_ms=20000, storage_occ_backoff_max_elapsed_ms=120000, storage_read_buffer_size=1048576, scope: SESSION |",
-            "+----------+--------------+-----------+-----------+------------+-----------------+--------------+---------------+-------------+--------------+------------+------------------------------------------------------+----------+--------------------------------+---------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------+"
+            "+----------+--------------+-----------+-----------+------------+-----------------+--------------+---------------+-------------+--------------+------------+------------------------------------------------------+----------+--------------------------------+--------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------+",
+            "| log_type | handler_type | cpu_usage | scan_rows | scan_bytes | scan_partitions | written_rows | written_bytes | result_rows | result_bytes | query_kind | query_text                                           | sql_user | sql_user_quota                 | session_settings                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                 |",
+            "+----------+--------------+-----------+-----------+------------+-----------------+--------------+---------------+-------------+--------------+------------+------------------------------------------------------+----------+--------------------------------+--------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------+",
+            "| 1        | Test         | 8         | 0         | 0          | 0               | 0            | 0             | 0           | 0            | SelectPlan | select number from numbers_mt(100) where number > 90 | root     | UserQuota<cpu:0,mem:0,store:0> | async_insert=0, dictionary_encoding_max_ndv=0, enable_log_redaction=0, enable_new_processor_framework=1, enable_query_result_cache=0, enforce_deterministic_order=0, executor_abort_on_stall=0, executor_stall_timeout_secs=300, explain_graphviz_with_schema=0, flight_client_timeout=60, ignore_scan_limits=0, json_quote_non_finite_floats=0, max_block_size=10000, max_expression_depth=128, max_scan_bytes=0, max_scan_rows=0, max_threads=8, parquet_row_group_rows=1048576, query_result_cache_max_bytes=1048576, storage_occ_backoff_init_delay_ms=5, storage_occ_backoff_max_delay_ms=20000, storage_occ_backoff_max_elapsed_ms=120000, storage_read_buffer_size=1048576, timezone=UTC, unquoted_ident_case_sensitive=1, scope: SESSION |",
+            "| 2        | Test         | 8         | 100       | 800        | 0               | 0            | 0             | 9           | 72           | SelectPlan | select number from numbers_mt(100) where number > 90 | root     | UserQuota<cpu:0,mem:0,store:0> | async_insert=0, dictionary_encoding_max_ndv=0, enable_log_redaction=0, enable_new_processor_framework=1, enable_query_result_cache=0, enforce_deterministic_order=0, executor_abort_on_stall=0, executor_stall_timeout_secs=300, explain_graphviz_with_schema=0, flight_client_timeout=60, ignore_scan_limits=0, json_quote_non_finite_floats=0, max_block_size=10000, max_expression_depth=128, max_scan_bytes=0, max_scan_rows=0, max_threads=8, parquet_row_group_rows=1048576, query_result_cache_max_bytes=1048576, storage_occ_backoff_init_delay_ms=5, storage_occ_backoff_max_delay_ms=20000, storage_occ_backoff_max_elapsed_ms=120000, storage_read_buffer_size=1048576, timezone=UTC, unquoted_ident_case_sensitive=1, scope: SESSION |",
+            "+----------+--------------+-----------+-----------+------------+-----------------+--------------+---------------+-------------+--------------+------------+------------------------------------------------------+----------+--------------------------------+--------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------+"
         ];
 
         common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
@@ -102,12 +102,52 @@ async fn test_interpreter_interceptor_for_insert() -> Result<()> {
         let result = stream.try_collect::<Vec<_>>().await?;
 
         let expected = vec![
-            "+----------+--------------+-----------+-----------+------------+-----------------+--------------+---------------+-------------+--------------+-----------------+----------------------------------------------------+----------+--------------------------------+---------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------+",
-            "| log_type | handler_type | cpu_usage | scan_rows | scan_bytes | scan_partitions | written_rows | written_bytes | result_rows | result_bytes | query_kind      | query_text                                         | sql_user | sql_user_quota                 | session_settings                                                                                                                                                                                                                                                          |",
-            "+----------+--------------+-----------+-----------+------------+-----------------+--------------+---------------+-------------+--------------+-----------------+----------------------------------------------------+----------+--------------------------------+---------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------+",
-            "| 1        | Test         | 8         | 0         | 0          | 0               | 0            | 0             | 0           | 0            | CreateTablePlan | create table t as select number from numbers_mt(1) | root     | UserQuota<cpu:0,mem:0,store:0> | enable_new_processor_framework=1, flight_client_timeout=60, max_block_size=10000, max_threads=8, storage_occ_backoff_init_delay_ms=5, storage_occ_backoff_max_delay_ms=20000, storage_occ_backoff_max_elapsed_ms=120000, storage_read_buffer_size=1048576, scope: SESSION |",
-            "| 2        | Test         | 8         | 1         | 8          | 0               | 1            | 8             | 0           | 0            | CreateTablePlan | create table t as select number from numbers_mt(1) | root     | UserQuota<cpu:0,mem:0,store:0> | enable_new_processor_framework=1, flight_client_timeout=60, max_block_size=10000, max_threads=8, storage_occ_backoff_init_delay_ms=5, storage_occ_backoff_max_delay_ms=20000, storage_occ_backoff_max_elapsed_ms=120000, storage_read_buffer_size=1048576, scope: SESSION |",
-            "+----------+--------------+-----------+-----------+------------+-----------------+--------------+---------------+-------------+--------------+-----------------+----------------------------------------------------+----------+--------------------------------+---------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------+",
+            "+----------+--------------+-----------+-----------+------------+-----------------+--------------+---------------+-------------+--------------+-----------------+----------------------------------------------------+----------+--------------------------------+--------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------+",
+            "| log_type | handler_type | cpu_usage | scan_rows | scan_bytes | scan_partitions | written_rows | written_bytes | result_rows | result_bytes | query_kind      | query_text                                         | sql_user | sql_user_quota                 | session_settings                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                 |",
+            "+----------+--------------+-----------+-----------+------------+-----------------+--------------+---------------+-------------+--------------+-----------------+----------------------------------------------------+----------+--------------------------------+--------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------+",
+            "| 1        | Test         | 8         | 0         | 0          | 0               | 0            | 0             | 0           | 0            | CreateTablePlan | create table t as select number from numbers_mt(1) | root     | UserQuota<cpu:0,mem:0,store:0> | async_insert=0, dictionary_encoding_max_ndv=0, enable_log_redaction=0, enable_new_processor_framework=1, enable_query_result_cache=0, enforce_deterministic_order=0, executor_abort_on_stall=0, executor_stall_timeout_secs=300, explain_graphviz_with_schema=0, flight_client_timeout=60, ignore_scan_limits=0, json_quote_non_finite_floats=0, max_block_size=10000, max_expression_depth=128, max_scan_bytes=0, max_scan_rows=0, max_threads=8, parquet_row_group_rows=1048576, query_result_cache_max_bytes=1048576, storage_occ_backoff_init_delay_ms=5, storage_occ_backoff_max_delay_ms=20000, storage_occ_backoff_max_elapsed_ms=120000, storage_read_buffer_size=1048576, timezone=UTC, unquoted_ident_case_sensitive=1, scope: SESSION |",
+            "| 2        | Test         | 8         | 1         | 8          | 0               | 1            | 8             | 0           | 0            | CreateTablePlan | create table t as select number from numbers_mt(1) | root     | UserQuota<cpu:0,mem:0,store:0> | async_insert=0, dictionary_encoding_max_ndv=0, enable_log_redaction=0, enable_new_processor_framework=1, enable_query_result_cache=0, enforce_deterministic_order=0, executor_abort_on_stall=0, executor_stall_timeout_secs=300, explain_graphviz_with_schema=0, flight_client_timeout=60, ignore_scan_limits=0, json_quote_non_finite_floats=0, max_block_size=10000, max_expression_depth=128, max_scan_bytes=0, max_scan_rows=0, max_threads=8, parquet_row_group_rows=1048576, query_result_cache_max_bytes=1048576, storage_occ_backoff_init_delay_ms=5, storage_occ_backoff_max_delay_ms=20000, storage_occ_backoff_max_elapsed_ms=120000, storage_read_buffer_size=1048576, timezone=UTC, unquoted_ident_case_sensitive=1, scope: SESSION |",
+            "+----------+--------------+-----------+-----------+------------+-----------------+--------------+---------------+-------------+--------------+-----------------+----------------------------------------------------+----------+--------------------------------+--------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_interpreter_interceptor_with_log_redaction() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+    let ctx = crate::tests::create_query_context().await?;
+    ctx.get_settings()
+        .set_settings("enable_log_redaction".to_string(), "1".to_string(), false)?;
+    {
+        let query = "select number from numbers_mt(100) where number > 90 and 'secret' = 'secret'";
+        ctx.attach_query_str(query);
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+        interpreter.start().await?;
+        let stream = interpreter.execute(None).await?;
+        stream.try_collect::<Vec<_>>().await?;
+        interpreter.finish().await?;
+    }
+
+    // Check.
+    {
+        let query = "select log_type, query_text from system.query_log";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+
+        let stream = interpreter.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+
+        let expected = vec![
+            "+----------+-------------------------------------------------------------+",
+            "| log_type | query_text                                                  |",
+            "+----------+-------------------------------------------------------------+",
+            "| 1        | select number from numbers_mt(?) where number > ? and ? = ? |",
+            "| 2        | select number from numbers_mt(?) where number > ? and ? = ? |",
+            "+----------+-------------------------------------------------------------+",
         ];
         common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
     }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_base::tokio;
+use common_datavalues::prelude::*;
 use common_exception::Result;
 use databend_query::interpreters::*;
 use databend_query::sql::*;
@@ -120,3 +121,67 @@ async fn test_insert_into_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_async_insert_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    ctx.get_settings()
+        .set_settings("async_insert".to_string(), "1".to_string(), false)?;
+
+    {
+        let query = "create table default.async_table(a UInt64) Engine = Memory";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+    }
+
+    // Insert returns the handle of the accepted data.
+    let id = {
+        let query = "insert into default.async_table values(1),(2),(3)";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].num_rows(), 1);
+        result[0].column(0).get_checked(0)?.to_string()
+    };
+
+    // Wait for the flush.
+    let mut status = String::new();
+    for _ in 0..100 {
+        let query = format!(
+            "select status, rows from system.async_inserts where id = '{}'",
+            id
+        );
+        let plan = PlanParser::parse(ctx.clone(), &query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert_eq!(result[0].column(1).get_checked(0)?.as_u64()?, 3);
+        status = result[0].column(0).get_checked(0)?.to_string();
+        if status != "Pending" {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    }
+    assert_eq!(status, "Flushed");
+
+    {
+        let query = "select count() from default.async_table";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+---------+",
+            "| count() |",
+            "+---------+",
+            "| 3       |",
+            "+---------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,111 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+
+use common_datablocks::assert_blocks_eq;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use databend_query::interpreters::*;
+
+fn read_values(values: &str, block_size: usize) -> Result<Vec<DataBlock>> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i64::to_data_type()),
+        DataField::new_nullable("b", Vu8::to_data_type()),
+    ]);
+    ValueSource::new(Cursor::new(values), schema, block_size).collect()
+}
+
+#[test]
+fn test_value_source() -> Result<()> {
+    // The rows are split into blocks.
+    let blocks = read_values("(1, 'a'), (2, NULL),(3,'c');", 2)?;
+    assert_eq!(blocks.len(), 2);
+    assert_eq!(blocks[0].num_rows(), 2);
+    assert_eq!(blocks[1].num_rows(), 1);
+    assert_blocks_eq(
+        vec![
+            "+---+------+",
+            "| a | b    |",
+            "+---+------+",
+            "| 1 | a    |",
+            "| 2 | NULL |",
+            "| 3 | c    |",
+            "+---+------+",
+        ],
+        &blocks,
+    );
+
+    // Quoted strings keep the delimiters, the escaped and doubled quotes.
+    let blocks = read_values(
+        r#" ( -4 , 'x, y)' ) , (5, "it's") , (6, 'a''b\'c\\d'), (7, 'null')"#,
+        1024,
+    )?;
+    assert_blocks_eq(
+        vec![
+            "+----+---------+",
+            "| a  | b       |",
+            "+----+---------+",
+            "| -4 | x, y)   |",
+            "| 5  | it's    |",
+            "| 6  | a'b'c\\d |",
+            "| 7  | null    |",
+            "+----+---------+",
+        ],
+        &blocks,
+    );
+
+    assert!(read_values("", 1024)?.is_empty());
+
+    // The values not in the text format of the type are casted.
+    let schema = DataSchemaRefExt::create(vec![DataField::new("t", DateTime32Type::arc(None))]);
+    let values = "('2021-09-07 21:38:35'), (1631050715)";
+    let blocks = ValueSource::new(Cursor::new(values), schema, 1024).collect::<Result<Vec<_>>>()?;
+    assert_blocks_eq(
+        vec![
+            "+---------------------+",
+            "| t                   |",
+            "+---------------------+",
+            "| 2021-09-07 21:38:35 |",
+            "| 2021-09-07 21:38:35 |",
+            "+---------------------+",
+        ],
+        &blocks,
+    );
+    Ok(())
+}
+
+#[test]
+fn test_value_source_error() -> Result<()> {
+    let cases = [
+        (
+            "(NULL, 'a')",
+            "Can't cast column from null into non-nullable type (column a at row 1)",
+        ),
+        ("(1, 'a'), (2)", "Expected ',' at row 2 of the values"),
+        ("(1, 'a', 3)", "Expected ')' at row 1 of the values"),
+        ("(1, 'a') (2, 'b')", "Expected ',' at row 2 of the values"),
+        ("(1, 'a)", "Unterminated string at row 1 of the values"),
+        ("(, 'a')", "Missing the value of column a at row 1"),
+    ];
+
+    for (values, expected) in cases {
+        match read_values(values, 1024) {
+            Ok(_) => panic!("{} should fail", values),
+            Err(cause) => assert_eq!(cause.message(), expected, "{}", values),
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,81 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::interpreters::*;
+use databend_query::sql::*;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_prepare_execute_deallocate_interpreter() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+    let ctx = crate::tests::create_query_context().await?;
+
+    // Prepare.
+    {
+        let query = "PREPARE p1 FROM 'select number from numbers_mt(10) where number = ?'";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        assert_eq!(executor.name(), "PrepareInterpreter");
+        let stream = executor.execute(None).await?;
+        let _ = stream.try_collect::<Vec<_>>().await?;
+    }
+
+    // Execute.
+    {
+        let plan = PlanParser::parse(ctx.clone(), "EXECUTE p1 USING 3").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        assert_eq!(executor.name(), "SelectInterpreter");
+
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+--------+",
+            "| number |",
+            "+--------+",
+            "| 3      |",
+            "+--------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // Execute with the wrong number of parameters.
+    {
+        let res = PlanParser::parse(ctx.clone(), "EXECUTE p1").await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::NumberArgumentsNotMatch("").code()
+        );
+    }
+
+    // Deallocate.
+    {
+        let plan = PlanParser::parse(ctx.clone(), "DEALLOCATE PREPARE p1").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        assert_eq!(executor.name(), "DeallocateInterpreter");
+        let stream = executor.execute(None).await?;
+        let _ = stream.try_collect::<Vec<_>>().await?;
+
+        let res = PlanParser::parse(ctx.clone(), "EXECUTE p1 USING 3").await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::UnknownPreparedStatement("").code()
+        );
+    }
+
+    Ok(())
+}
@@ -76,3 +76,57 @@ async fn test_select_interpreter() -> Result<()> {
     }
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_select_interpreter_scan_limits() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+    let ctx = crate::tests::create_query_context().await?;
+    let settings = ctx.get_settings();
+    settings.set_settings("max_scan_rows".to_string(), "100".to_string(), false)?;
+
+    // Within the limit.
+    {
+        let query = "select number from numbers_mt(100)";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert_eq!(result.iter().map(|b| b.num_rows()).sum::<usize>(), 100);
+    }
+
+    // Exceeds the limit.
+    {
+        let query = "select number from numbers_mt(1000)";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let res = executor.execute(None).await;
+        assert_eq!(
+            res.err().unwrap().message(),
+            "Query is estimated to scan 1000 rows, which exceeds max_scan_rows = 100. \
+             Add filters or a LIMIT to read less data, or SET ignore_scan_limits = 1 to run it anyway"
+        );
+    }
+
+    // The limit pushed down to the scan lowers the estimate.
+    {
+        let query = "select number from numbers_mt(1000) limit 10";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert_eq!(result.iter().map(|b| b.num_rows()).sum::<usize>(), 10);
+    }
+
+    // Override.
+    {
+        settings.set_settings("ignore_scan_limits".to_string(), "1".to_string(), false)?;
+        let query = "select number from numbers_mt(1000)";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert_eq!(result.iter().map(|b| b.num_rows()).sum::<usize>(), 1000);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,169 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Read;
+
+use common_arrow::arrow::io::parquet::read::read_metadata;
+use common_base::tokio;
+use common_exception::Result;
+use databend_query::interpreters::*;
+use databend_query::sql::PlanParser;
+use flate2::read::GzDecoder;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_select_into_outfile_interpreter() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let mut conf = crate::tests::ConfigBuilder::create().config();
+    conf.query.outfile_allowed_dirs = dir.path().to_string_lossy().to_string();
+    let ctx = crate::tests::create_query_context_with_config(conf, None).await?;
+
+    // Csv.
+    {
+        let path = dir.path().join("numbers.csv");
+        let query = format!(
+            "SELECT number, 'a,b' FROM numbers_mt(3) ORDER BY number INTO OUTFILE '{}'",
+            path.display()
+        );
+        let plan = PlanParser::parse(ctx.clone(), &query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        assert_eq!(executor.name(), "SelectIntoOutfileInterpreter");
+
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert_eq!(result[0].column(1).get_u64(0)?, 3);
+        assert_eq!(
+            std::fs::read_to_string(&path)?,
+            "0,\"a,b\"\n1,\"a,b\"\n2,\"a,b\"\n"
+        );
+
+        // The file exists.
+        let plan = PlanParser::parse(ctx.clone(), &query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        assert!(executor.execute(None).await.is_err());
+    }
+
+    // Tsv with gzip.
+    {
+        let path = dir.path().join("numbers.tsv.gz");
+        let query = format!(
+            "SELECT number, number * 2 FROM numbers_mt(2) ORDER BY number INTO OUTFILE '{}' FORMAT TSV",
+            path.display()
+        );
+        let plan = PlanParser::parse(ctx.clone(), &query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        executor
+            .execute(None)
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        let mut content = String::new();
+        GzDecoder::new(std::fs::File::open(&path)?).read_to_string(&mut content)?;
+        assert_eq!(content, "0\t0\n1\t2\n");
+    }
+
+    // Csv with zstd.
+    {
+        let path = dir.path().join("numbers.csv.zst");
+        let query = format!(
+            "SELECT number FROM numbers_mt(2) ORDER BY number INTO OUTFILE '{}'",
+            path.display()
+        );
+        let plan = PlanParser::parse(ctx.clone(), &query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        executor
+            .execute(None)
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        let content = zstd::decode_all(std::fs::File::open(&path)?)?;
+        assert_eq!(content, b"0\n1\n");
+    }
+
+    // Parquet with statistics, 5 rows in row groups of 2 rows.
+    {
+        ctx.get_settings().set_settings(
+            "parquet_row_group_rows".to_string(),
+            "2".to_string(),
+            false,
+        )?;
+        let path = dir.path().join("numbers.parquet");
+        let query = format!(
+            "SELECT number, to_string(number) FROM numbers_mt(5) ORDER BY number INTO OUTFILE '{}' FORMAT PARQUET",
+            path.display()
+        );
+        let plan = PlanParser::parse(ctx.clone(), &query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert_eq!(result[0].column(1).get_u64(0)?, 5);
+
+        let metadata = read_metadata(&mut std::fs::File::open(&path)?)?;
+        assert_eq!(metadata.num_rows, 5);
+        let group_rows = metadata
+            .row_groups
+            .iter()
+            .map(|group| group.num_rows())
+            .collect::<Vec<_>>();
+        assert_eq!(group_rows, vec![2, 2, 1]);
+        for group in &metadata.row_groups {
+            for column in group.columns() {
+                assert!(column.metadata().statistics.is_some());
+            }
+        }
+
+        // Parquet pages are compressed by the writer.
+        let query = format!(
+            "SELECT 1 INTO OUTFILE '{}' FORMAT PARQUET COMPRESSION 'gzip'",
+            dir.path().join("one.parquet").display()
+        );
+        match PlanParser::parse(ctx.clone(), &query).await {
+            Ok(_) => panic!("INTO OUTFILE FORMAT PARQUET with COMPRESSION must be return Err."),
+            Err(cause) => assert_eq!(cause.code(), 1005),
+        }
+    }
+
+    // Out of the allowed directories.
+    {
+        let query = "SELECT 1 INTO OUTFILE '/etc/databend_outfile.csv'";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        match executor.execute(None).await {
+            Ok(_) => panic!("INTO OUTFILE out of the allowed directories must be return Err."),
+            Err(cause) => assert_eq!(cause.code(), 1063),
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_select_into_outfile_disabled() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+
+    let plan = PlanParser::parse(ctx.clone(), "SELECT 1 INTO OUTFILE 'a.csv'").await?;
+    let executor = InterpreterFactory::get(ctx, plan)?;
+    match executor.execute(None).await {
+        Ok(_) => panic!("INTO OUTFILE without allowed directories must be return Err."),
+        Err(cause) => {
+            assert_eq!(cause.code(), 1063);
+            assert!(cause.message().contains("INTO OUTFILE is disabled"));
+        }
+    }
+
+    Ok(())
+}
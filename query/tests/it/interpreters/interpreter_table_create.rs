@@ -14,6 +14,10 @@
 
 use common_base::tokio;
 use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::UserInfo;
+use common_meta_types::UserPrivilegeSet;
+use common_meta_types::UserPrivilegeType;
 use databend_query::interpreters::*;
 use databend_query::sql::PlanParser;
 use futures::stream::StreamExt;
@@ -102,3 +106,48 @@ async fn test_create_table_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_create_or_replace_requires_drop_privilege() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+
+    for query in [
+        "CREATE TABLE default.t(a int) Engine = Null",
+        "CREATE VIEW default.v AS SELECT 1",
+    ] {
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let _ = executor.execute(None).await?;
+    }
+
+    // A user who can create the objects in the database, but not drop them.
+    let mut user_info = UserInfo::new_no_auth("creator".to_string(), "%".to_string());
+    user_info.grants.grant_privileges(
+        &GrantObject::Database("default".to_string()),
+        UserPrivilegeSet::from(vec![UserPrivilegeType::Create]),
+    );
+    ctx.get_current_session().set_current_user(user_info);
+
+    // Replacing an existing object drops it.
+    for query in [
+        "CREATE OR REPLACE TABLE default.t(b int) Engine = Null",
+        "CREATE OR REPLACE VIEW default.v AS SELECT 2",
+    ] {
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let result = executor.execute(None).await;
+        assert_eq!(result.err().map(|e| e.code()), Some(1063), "{}", query);
+    }
+
+    // Nothing is dropped by CREATE OR REPLACE of a new object.
+    for query in [
+        "CREATE OR REPLACE TABLE default.t2(a int) Engine = Null",
+        "CREATE OR REPLACE VIEW default.v2 AS SELECT 1",
+    ] {
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let _ = executor.execute(None).await?;
+    }
+
+    Ok(())
+}
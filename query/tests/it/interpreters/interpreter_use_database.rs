@@ -33,6 +33,28 @@ async fn test_use_interpreter() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_use_interpreter_changes_session_database() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+
+    let plan = PlanParser::parse(ctx.clone(), "USE system").await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+    let mut stream = interpreter.execute(None).await?;
+    while let Some(_block) = stream.next().await {}
+
+    assert_eq!(ctx.get_current_database(), "system");
+
+    // The session state is shown in system.processes.
+    let processes = ctx.get_processes_info().await;
+    assert!(processes.iter().any(|p| p.database == "system"));
+
+    // Unqualified names are resolved in the new database.
+    let plan = PlanParser::parse(ctx.clone(), "SELECT * FROM tables").await;
+    assert!(plan.is_ok());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_use_database_interpreter_error() -> Result<()> {
     let ctx = crate::tests::create_query_context().await?;
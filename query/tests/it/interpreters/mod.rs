@@ -19,11 +19,14 @@ mod interpreter_database_show_create;
 mod interpreter_explain;
 mod interpreter_factory_interceptor;
 mod interpreter_insert;
+mod interpreter_insert_values;
+mod interpreter_prepare;
 mod interpreter_privilege_grant;
 mod interpreter_privilege_revoke;
 mod interpreter_role_grant;
 mod interpreter_role_revoke;
 mod interpreter_select;
+mod interpreter_select_into_outfile;
 mod interpreter_setting;
 mod interpreter_show_databases;
 mod interpreter_show_engines;
@@ -49,3 +52,4 @@ mod interpreter_user_udf_alter;
 mod interpreter_user_udf_create;
 mod interpreter_user_udf_drop;
 mod plan_schedulers;
+mod result_cache;
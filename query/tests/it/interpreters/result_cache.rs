@@ -0,0 +1,102 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use databend_query::clusters::Cluster;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::QueryContextShared;
+use databend_query::sql::PlanParser;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+// Every query has its own context and scan progress.
+async fn new_query_context(ctx: &Arc<QueryContext>) -> Result<Arc<QueryContext>> {
+    let shared =
+        QueryContextShared::try_create(ctx.get_current_session(), Cluster::empty()).await?;
+    Ok(QueryContext::create_from_shared(shared))
+}
+
+async fn execute(ctx: Arc<QueryContext>, query: &str) -> Result<Vec<DataBlock>> {
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx, plan)?;
+    executor.execute(None).await?.try_collect::<Vec<_>>().await
+}
+
+// Run the query in a new context, returns the first value and the rows scanned.
+async fn query_u64(ctx: &Arc<QueryContext>, query: &str) -> Result<(u64, usize)> {
+    let ctx = new_query_context(ctx).await?;
+    let blocks = execute(ctx.clone(), query).await?;
+    let value = blocks[0].column(0).get_u64(0)?;
+    Ok((value, ctx.get_scan_progress_value().read_rows))
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_result_cache() -> Result<()> {
+    let tmp_dir = tempfile::tempdir()?;
+    let mut conf = crate::tests::ConfigBuilder::create().config();
+    conf.storage.storage_type = "Disk".to_string();
+    conf.storage.disk.data_path = tmp_dir.path().to_str().unwrap().to_string();
+    conf.storage.disk.temp_data_path = tmp_dir.path().to_str().unwrap().to_string();
+    let ctx = crate::tests::create_query_context_with_config(conf, None).await?;
+
+    execute(ctx.clone(), "create table t(a UInt64)").await?;
+    execute(ctx.clone(), "insert into t values(1),(2)").await?;
+
+    // Disabled by default.
+    let query = "select sum(a) from t";
+    assert_eq!(query_u64(&ctx, query).await?, (3, 2));
+    assert_eq!(query_u64(&ctx, query).await?, (3, 2));
+
+    ctx.get_settings().set_settings(
+        "enable_query_result_cache".to_string(),
+        "1".to_string(),
+        false,
+    )?;
+
+    // The second run is served by the cache, nothing is scanned.
+    assert_eq!(query_u64(&ctx, query).await?, (3, 2));
+    assert_eq!(query_u64(&ctx, query).await?, (3, 0));
+
+    // A new snapshot of the table misses the cache.
+    execute(new_query_context(&ctx).await?, "insert into t values(3)").await?;
+    assert_eq!(query_u64(&ctx, query).await?, (6, 3));
+    assert_eq!(query_u64(&ctx, query).await?, (6, 0));
+
+    // The options of the table are hash maps, the key doesn't depend on their order.
+    for _ in 0..10 {
+        assert_eq!(query_u64(&ctx, query).await?, (6, 0));
+    }
+
+    // Non deterministic functions are never cached.
+    let query = "select count(*) from t where rand() >= 0";
+    assert_eq!(query_u64(&ctx, query).await?, (3, 3));
+    assert_eq!(query_u64(&ctx, query).await?, (3, 3));
+
+    // Results larger than query_result_cache_max_bytes are not cached.
+    ctx.get_settings().set_settings(
+        "query_result_cache_max_bytes".to_string(),
+        "1".to_string(),
+        false,
+    )?;
+    let query = "select max(a) from t";
+    assert_eq!(query_u64(&ctx, query).await?, (3, 3));
+    assert_eq!(query_u64(&ctx, query).await?, (3, 3));
+
+    Ok(())
+}
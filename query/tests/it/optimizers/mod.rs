@@ -13,8 +13,12 @@
 // limitations under the License.
 
 mod optimizer;
+mod optimizer_common_subexpression;
 mod optimizer_constant_folding;
 mod optimizer_expression_transform;
+mod optimizer_limit_push_down;
+mod optimizer_projection_pruning;
 mod optimizer_scatters;
 mod optimizer_statistics_exact;
+mod optimizer_top_n;
 mod optimizer_top_n_push_down;
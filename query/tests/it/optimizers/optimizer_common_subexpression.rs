@@ -0,0 +1,61 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use databend_query::optimizers::*;
+use databend_query::sql::PlanParser;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_common_subexpression_optimizer() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        query: &'static str,
+        expect: &'static str,
+    }
+
+    let tests = vec![
+        Test {
+            name: "Subexpression shared by filter and projection",
+            query: "select (number + 1) * 2 as a from numbers(10) where number + 1 > 3",
+            expect: "\
+            Projection: ((number + 1) * 2) as a:UInt64\
+            \n  Expression: ((number + 1) * 2):UInt64 (Before Projection)\
+            \n    Filter: ((number + 1) > 3)\
+            \n      Expression: (number + 1):UInt64 (Common Subexpressions)\
+            \n        ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [((number + 1) > 3)]]",
+        },
+        Test {
+            name: "Nothing shared",
+            query: "select number + 1 from numbers(10) where number > 3",
+            expect: "\
+            Projection: (number + 1):UInt64\
+            \n  Expression: (number + 1):UInt64 (Before Projection)\
+            \n    Filter: (number > 3)\
+            \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [(number > 3)]]",
+        },
+    ];
+
+    for test in tests {
+        let ctx = crate::tests::create_query_context().await?;
+        let plan = PlanParser::parse(ctx.clone(), test.query).await?;
+
+        let mut optimizer = CommonSubexpressionOptimizer::create(ctx);
+        let optimized = optimizer.optimize(&plan)?;
+        let actual = format!("{:?}", optimized);
+        assert_eq!(test.expect, actual, "{:#?}", test.name);
+    }
+
+    Ok(())
+}
@@ -98,6 +98,22 @@ async fn test_constant_folding_optimizer() -> Result<()> {
                 \n  Expression: String:String (Before Projection)\
                 \n    ReadDataSource: scan schema: [dummy:UInt8], statistics: [read_rows: 1, read_bytes: 1, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
             },
+            Test {
+                name: "Projection boolean short circuit",
+                query: "SELECT dummy > 1 AND false, dummy > 1 OR 1 = 1",
+                expect: "\
+                Projection: ((dummy > 1) and false):Boolean, ((dummy > 1) or (1 = 1)):Boolean\
+                \n  Expression: false:Boolean, true:Boolean (Before Projection)\
+                \n    ReadDataSource: scan schema: [dummy:UInt8], statistics: [read_rows: 1, read_bytes: 1, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
+            },
+            Test {
+                name: "Projection volatile function is not folded",
+                query: "SELECT rand() + 1",
+                expect: "\
+                Projection: (rand() + 1):Float64\
+                \n  Expression: (rand() + 1):Float64 (Before Projection)\
+                \n    ReadDataSource: scan schema: [dummy:UInt8], statistics: [read_rows: 1, read_bytes: 1, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
+            },
         ];
 
     for test in tests {
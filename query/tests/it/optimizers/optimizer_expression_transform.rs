@@ -112,8 +112,7 @@ async fn test_expression_transform_optimizer() -> Result<()> {
                 expect: "\
                 Limit: 0\
                 \n  Projection: number:UInt64\
-                \n    Filter: true\
-                \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 0, read_bytes: 0, partitions_scanned: 0, partitions_total: 0], push_downs: [projections: [0], filters: [true], limit: 0]",
+                \n    ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 0, read_bytes: 0, partitions_scanned: 0, partitions_total: 0], push_downs: [projections: [0], filters: [true], limit: 0]",
             },
             Test {
                 name: "Filter true and cond",
@@ -168,16 +167,14 @@ async fn test_expression_transform_optimizer() -> Result<()> {
                 query: "SELECT number from numbers(10) where true OR number > 1",
                 expect: "\
                 Projection: number:UInt64\
-                \n  Filter: true\
-                \n    ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [(true OR (number > 1))]]",
+                \n  ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [(true OR (number > 1))]]",
             },
             Test {
                 name: "Filter cond or true",
                 query: "SELECT number from numbers(10) where number > 1 OR true",
                 expect: "\
                 Projection: number:UInt64\
-                \n  Filter: true\
-                \n    ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [((number > 1) OR true)]]",
+                \n  ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [((number > 1) OR true)]]",
             },
             Test {
                 name: "Projection logics const",
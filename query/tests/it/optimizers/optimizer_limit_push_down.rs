@@ -0,0 +1,69 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use databend_query::optimizers::*;
+use databend_query::sql::PlanParser;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_limit_push_down_optimizer() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        query: &'static str,
+        expect: &'static str,
+    }
+
+    let tests = vec![
+        Test {
+            name: "Limit below projection and expression",
+            query: "select number + 1 as a from numbers(1000) limit 10",
+            expect: "\
+            Projection: (number + 1) as a:UInt64\
+            \n  Expression: (number + 1):UInt64 (Before Projection)\
+            \n    Limit: 10\
+            \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], limit: 10]",
+        },
+        Test {
+            name: "Limit offset stops at filter",
+            query: "select number from numbers(1000) where number > 10 limit 10 offset 5",
+            expect: "\
+            Projection: number:UInt64\
+            \n  Limit: 10, 5\
+            \n    Filter: (number > 10)\
+            \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 1000, read_bytes: 8000, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [(number > 10)], limit: 15]",
+        },
+        Test {
+            name: "Limit stops at sort",
+            query: "select number from numbers(1000) order by number limit 10",
+            expect: "\
+            Projection: number:UInt64\
+            \n  Limit: 10\
+            \n    Sort: number:UInt64\
+            \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 1000, read_bytes: 8000, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], limit: 10, order_by: [number]]",
+        },
+    ];
+
+    for test in tests {
+        let ctx = crate::tests::create_query_context().await?;
+        let plan = PlanParser::parse(ctx.clone(), test.query).await?;
+
+        let mut optimizer = LimitPushDownOptimizer::create(ctx);
+        let optimized = optimizer.optimize(&plan)?;
+        let actual = format!("{:?}", optimized);
+        assert_eq!(test.expect, actual, "{:#?}", test.name);
+    }
+
+    Ok(())
+}
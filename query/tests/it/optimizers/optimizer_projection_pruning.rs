@@ -0,0 +1,86 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use common_planners::PlanNode;
+use common_planners::ReadDataSourcePlan;
+use databend_query::interpreters::*;
+use databend_query::optimizers::*;
+use databend_query::sql::PlanParser;
+
+fn find_read_source(plan: &PlanNode) -> Option<ReadDataSourcePlan> {
+    match plan {
+        PlanNode::ReadSource(read_source) => Some(read_source.clone()),
+        _ => plan
+            .inputs()
+            .iter()
+            .find_map(|input| find_read_source(input.as_ref())),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_projection_pruning_optimizer() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+
+    let query = "create table default.t(a Int32, b Int32, c Int32, d Int32) Engine = Memory";
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let _ = executor.execute(None).await?;
+
+    struct Test {
+        name: &'static str,
+        query: &'static str,
+        expect: Vec<usize>,
+    }
+
+    let tests = vec![
+        Test {
+            name: "Unused subquery columns are not read",
+            query: "select a from (select a, b, c from t)",
+            expect: vec![0],
+        },
+        Test {
+            name: "Filter columns are read",
+            query: "select a from (select a, b, c from t) where c > 1",
+            expect: vec![0, 2],
+        },
+        Test {
+            name: "Columns of a computed alias are read",
+            query: "select x from (select a, b + 1 as x, c from t) order by x",
+            expect: vec![1],
+        },
+        Test {
+            name: "Aggregate arguments and keys are read",
+            query: "select s from (select sum(b) as s, d from (select * from t) group by d)",
+            expect: vec![1, 3],
+        },
+        Test {
+            name: "Keep at least one column",
+            query: "select count(*) from (select a, b from t)",
+            expect: vec![0, 1],
+        },
+    ];
+
+    for test in tests {
+        let plan = PlanParser::parse(ctx.clone(), test.query).await?;
+        let mut optimizer = ProjectionPruningOptimizer::create(ctx.clone());
+        let optimized = optimizer.optimize(&plan)?;
+
+        let read_source = find_read_source(&optimized).unwrap();
+        assert_eq!(test.expect, read_source.projections(), "{:#?}", test.name);
+    }
+
+    Ok(())
+}
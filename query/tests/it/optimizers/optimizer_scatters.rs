@@ -200,6 +200,26 @@ async fn test_scatter_optimizer() -> Result<()> {
             \n            ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]\
             \n        ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [exists(subquery(_subquery_1))]]",
         },
+        Test {
+            name: "Cluster query with limit offset",
+            query: "SELECT number FROM numbers(100) LIMIT 10 OFFSET 5",
+            expect: "\
+            Limit: 10, 5\
+            \n  RedistributeStage[expr: 0]\
+            \n    Limit: 15\
+            \n      Projection: number:UInt64\
+            \n        ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 15, read_bytes: 120, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], limit: 15]",
+        },
+        Test {
+            name: "Cluster query with offset fetch",
+            query: "SELECT number FROM numbers(100) OFFSET 5 ROWS FETCH FIRST 10 ROWS ONLY",
+            expect: "\
+            Limit: 10, 5\
+            \n  RedistributeStage[expr: 0]\
+            \n    Limit: 15\
+            \n      Projection: number:UInt64\
+            \n        ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 15, read_bytes: 120, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], limit: 15]",
+        },
     ];
 
     for test in tests {
@@ -0,0 +1,82 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use databend_query::optimizers::*;
+use databend_query::sql::PlanParser;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_top_n_optimizer() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        query: &'static str,
+        // The sort replaced by the top n, None if the plan is kept as is.
+        top_n: Option<&'static str>,
+    }
+
+    let tests = vec![
+        Test {
+            name: "Sort followed by limit",
+            query: "select number from numbers(1000) order by number limit 10",
+            top_n: Some("TopN: 10, "),
+        },
+        Test {
+            name: "Sort followed by limit offset",
+            query: "select number from numbers(1000) order by number desc limit 10 offset 5",
+            top_n: Some("TopN: 15, "),
+        },
+        Test {
+            name: "Sort on an expression followed by limit",
+            query: "select number % 3 as c from numbers(1000) order by c, number limit 2",
+            top_n: Some("TopN: 2, "),
+        },
+        Test {
+            name: "Sort without limit",
+            query: "select number from numbers(1000) order by number",
+            top_n: None,
+        },
+        Test {
+            name: "Limit without sort",
+            query: "select number from numbers(1000) limit 10 offset 5",
+            top_n: None,
+        },
+        Test {
+            name: "Limit all",
+            query: "select number from numbers(1000) order by number offset 5",
+            top_n: None,
+        },
+    ];
+
+    for test in tests {
+        let ctx = crate::tests::create_query_context().await?;
+        let plan = PlanParser::parse(ctx.clone(), test.query).await?;
+
+        let mut optimizer = TopNOptimizer::create(ctx);
+        let optimized = optimizer.optimize(&plan)?;
+
+        let before = format!("{:?}", plan);
+        let expect = match test.top_n {
+            None => before,
+            Some(top_n) => {
+                assert!(before.contains("Sort: "), "{:#?}", test.name);
+                before.replace("Sort: ", top_n)
+            }
+        };
+        let actual = format!("{:?}", optimized);
+        assert_eq!(expect, actual, "{:#?}", test.name);
+    }
+
+    Ok(())
+}
@@ -131,9 +131,9 @@ async fn test_monotonic_function() -> Result<()> {
             name: "plus-only-function-(number+number+3)",
             query: "select number*number from numbers_mt(100) order by number+number+3 limit 10",
             expect: "\
-            Limit: 10\
-            \n  Projection: (number * number):UInt64\
-            \n    Sort: ((number + number) + 3):UInt64\
+            Projection: (number * number):UInt64\
+            \n  Limit: 10\
+            \n    TopN: 10, ((number + number) + 3):UInt64\
             \n      Expression: (number * number):UInt64, ((number + number) + 3):UInt64 (Before OrderBy)\
             \n        ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 100, read_bytes: 800, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], limit: 10, order_by: [((number + number) + 3)]]",
         },
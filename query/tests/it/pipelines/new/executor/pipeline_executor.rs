@@ -0,0 +1,72 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_base::tokio;
+use common_base::tokio::sync::mpsc::channel;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::pipelines::new::executor::PipelineExecutor;
+use databend_query::pipelines::new::processors::port::InputPort;
+use databend_query::pipelines::new::processors::port::OutputPort;
+use databend_query::pipelines::new::processors::SyncReceiverSource;
+use databend_query::pipelines::new::processors::SyncSenderSink;
+use databend_query::pipelines::new::NewPipe;
+use databend_query::pipelines::new::NewPipeline;
+
+use crate::tests::create_query_context;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_executor_abort_on_stall() -> Result<()> {
+    let ctx = create_query_context().await?;
+
+    // The source waits for the data which never comes.
+    let output = OutputPort::create();
+    let (tx, rx) = channel(1);
+    let source = SyncReceiverSource::create(ctx.clone(), rx, output.clone())?;
+
+    let input = InputPort::create();
+    let (sink_tx, _sink_rx) = channel(1);
+    let sink = SyncSenderSink::create(sink_tx, input.clone());
+
+    let mut pipeline = NewPipeline::create();
+    pipeline.add_pipe(NewPipe::SimplePipe {
+        processors: vec![source],
+        inputs_port: vec![],
+        outputs_port: vec![output],
+    });
+    pipeline.add_pipe(NewPipe::SimplePipe {
+        processors: vec![sink],
+        inputs_port: vec![input],
+        outputs_port: vec![],
+    });
+    pipeline.set_max_threads(1);
+    pipeline.set_stall_timeout(Duration::from_secs(1), true);
+
+    let executor = PipelineExecutor::create(ctx.get_storage_runtime(), pipeline)?;
+    let handle = std::thread::spawn(move || executor.execute());
+
+    // Unblock the source after the watchdog aborted the query.
+    tokio::time::sleep(Duration::from_secs(4)).await;
+    drop(tx);
+
+    let cause = handle.join().unwrap().unwrap_err();
+    assert_eq!(cause.code(), ErrorCode::AbortedQuery("").code());
+    assert!(cause
+        .message()
+        .contains("SyncReceiverSource(0): Processing"));
+
+    Ok(())
+}
@@ -32,12 +32,13 @@ async fn test_local_pipeline_builds() -> Result<()> {
     let tests = vec![
         Test {
             name: "select-alias-pass",
-            query: "select number as c1, number as c2 from numbers_mt(10) order by c1 desc",
+            query: "select number as c1, number as c2 from numbers_mt(100000) where number < 10 order by c1 desc",
 
             plan: "\
             Projection: number as c1:UInt64, number as c2:UInt64\
             \n  Sort: number:UInt64\
-            \n    ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], order_by: [number]]",
+            \n    Filter: (number < 10)\
+            \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 100000, read_bytes: 800000, partitions_scanned: 11, partitions_total: 11], push_downs: [projections: [0], filters: [(number < 10)], order_by: [number]]",
 
             pipeline: "\
             ProjectionTransform × 1 processor\
@@ -45,7 +46,8 @@ async fn test_local_pipeline_builds() -> Result<()> {
             \n    Merge (SortMergeTransform × 8 processors) to (SortMergeTransform × 1)\
             \n      SortMergeTransform × 8 processors\
             \n        SortPartialTransform × 8 processors\
-            \n          SourceTransform × 8 processors",
+            \n          FilterTransform × 8 processors\
+            \n            SourceTransform × 8 processors",
 
 
             block: vec![
@@ -67,12 +69,13 @@ async fn test_local_pipeline_builds() -> Result<()> {
         },
         Test {
             name: "select-order-by-alias-pass",
-            query: "select number as c1, number as c2 from numbers_mt(10) order by c1 desc, c2 asc",
+            query: "select number as c1, number as c2 from numbers_mt(100000) where number < 10 order by c1 desc, c2 asc",
 
             plan: "\
             Projection: number as c1:UInt64, number as c2:UInt64\
             \n  Sort: number:UInt64, number:UInt64\
-            \n    ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], order_by: [number, number]]",
+            \n    Filter: (number < 10)\
+            \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 100000, read_bytes: 800000, partitions_scanned: 11, partitions_total: 11], push_downs: [projections: [0], filters: [(number < 10)], order_by: [number, number]]",
 
 
             pipeline: "\
@@ -81,7 +84,8 @@ async fn test_local_pipeline_builds() -> Result<()> {
             \n    Merge (SortMergeTransform × 8 processors) to (SortMergeTransform × 1)\
             \n      SortMergeTransform × 8 processors\
             \n        SortPartialTransform × 8 processors\
-            \n          SourceTransform × 8 processors",
+            \n          FilterTransform × 8 processors\
+            \n            SourceTransform × 8 processors",
 
             block: vec![
                 "+----+----+",
@@ -103,13 +107,14 @@ async fn test_local_pipeline_builds() -> Result<()> {
         Test {
             name: "select-order-by-alias-expression-pass",
             query:
-                "select number as c1, (number + 1) as c2 from numbers_mt(10) order by c1 desc, c2 asc",
+                "select number as c1, (number + 1) as c2 from numbers_mt(100000) where number < 10 order by c1 desc, c2 asc",
 
             plan: "\
             Projection: number as c1:UInt64, (number + 1) as c2:UInt64\
             \n  Sort: number:UInt64, (number + 1):UInt64\
             \n    Expression: number:UInt64, (number + 1):UInt64 (Before OrderBy)\
-            \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], order_by: [number, (number + 1)]]",
+            \n      Filter: (number < 10)\
+            \n        ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 100000, read_bytes: 800000, partitions_scanned: 11, partitions_total: 11], push_downs: [projections: [0], filters: [(number < 10)], order_by: [number, (number + 1)]]",
 
             pipeline: "\
             ProjectionTransform × 1 processor\
@@ -118,7 +123,8 @@ async fn test_local_pipeline_builds() -> Result<()> {
             \n      SortMergeTransform × 8 processors\
             \n        SortPartialTransform × 8 processors\
             \n          ExpressionTransform × 8 processors\
-            \n            SourceTransform × 8 processors",
+            \n            FilterTransform × 8 processors\
+            \n              SourceTransform × 8 processors",
 
             block: vec![
                 "+----+----+",
@@ -140,13 +146,13 @@ async fn test_local_pipeline_builds() -> Result<()> {
         Test {
             name: "select-order-by-limit-with-offset-pass",
             query:
-                "select number from numbers_mt(100) order by number asc limit 5 offset 5",
+                "select number from numbers_mt(100000) order by number asc limit 5 offset 5",
 
             plan: "\
             Limit: 5, 5\
             \n  Projection: number:UInt64\
             \n    Sort: number:UInt64\
-            \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 100, read_bytes: 800, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], limit: 10, order_by: [number]]",
+            \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 100000, read_bytes: 800000, partitions_scanned: 11, partitions_total: 11], push_downs: [projections: [0], limit: 10, order_by: [number]]",
 
             pipeline: "\
             LimitTransform × 1 processor\
@@ -191,3 +197,20 @@ async fn test_local_pipeline_builds() -> Result<()> {
     }
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_local_pipeline_builds_small_input() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+
+    // Ten rows fit in a single block, so they are read by one source instead of max_threads.
+    let query = "select number from numbers_mt(10) order by number desc";
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let pipeline = PipelineBuilder::create(ctx).build(&plan)?;
+    let expect = "\
+    ProjectionTransform × 1 processor\
+    \n  SortMergeTransform × 1 processor\
+    \n    SortPartialTransform × 1 processor\
+    \n      SourceTransform × 1 processor";
+    assert_eq!(expect, format!("{:?}", pipeline));
+    Ok(())
+}
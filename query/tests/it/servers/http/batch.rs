@@ -0,0 +1,158 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use databend_query::servers::http::middleware::HTTPSessionMiddleware;
+use databend_query::servers::http::v1::batch_handler;
+use databend_query::servers::http::v1::BatchResponse;
+use databend_query::servers::http::v1::ExecuteStateName;
+use poem::http::header;
+use poem::http::Method;
+use poem::http::StatusCode;
+use poem::post;
+use poem::Endpoint;
+use poem::EndpointExt;
+use poem::Request;
+use poem::Route;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+use crate::tests::SessionManagerBuilder;
+
+#[tokio::test]
+async fn test_batch() -> Result<()> {
+    let ep = create_endpoint()?;
+
+    let (status, result) = post_batch(
+        &ep,
+        json!({"statements": [
+            "create table t1(a int)",
+            "insert into t1 values(1),(2)",
+            "select * from t1",
+        ]}),
+    )
+    .await?;
+    assert_eq!(status, StatusCode::OK);
+    assert!(result.error.is_none(), "{:?}", result.error);
+    assert_eq!(result.results.len(), 3);
+    assert_eq!(result.results[2].state, ExecuteStateName::Succeeded);
+    assert_eq!(result.results[2].rows, 2);
+
+    // Stops at the first error.
+    let (status, result) = post_batch(
+        &ep,
+        json!({"statements": ["select 1", "bad sql", "select 2"], "database": "system"}),
+    )
+    .await?;
+    assert_eq!(status, StatusCode::OK);
+    assert!(result.error.is_some());
+    assert!(!result.rolled_back);
+    assert_eq!(result.results.len(), 2);
+    assert_eq!(result.results[1].state, ExecuteStateName::Failed);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_batch_atomic() -> Result<()> {
+    let ep = create_endpoint()?;
+
+    let (status, result) = post_batch(
+        &ep,
+        json!({"atomic": true, "statements": [
+            "create database db1",
+            "create table db1.t1(a int)",
+            "rename table db1.t1 to db1.t2",
+            "create table db1.t2(a int)",
+        ]}),
+    )
+    .await?;
+    assert_eq!(status, StatusCode::OK);
+    assert!(result.error.is_some());
+    assert!(result.rolled_back);
+    assert_eq!(result.results.len(), 4);
+    assert_eq!(result.results[3].state, ExecuteStateName::Failed);
+
+    let (_, result) = post_batch(&ep, json!({"statements": ["show databases like 'db1'"]})).await?;
+    assert!(result.error.is_none(), "{:?}", result.error);
+    assert_eq!(result.results[0].rows, 0);
+
+    // Statements which can not be undone are rejected before execution.
+    let (_, result) = post_batch(
+        &ep,
+        json!({"atomic": true, "statements": [
+            "create table t1(a int)",
+            "insert into t1 values(1)",
+        ]}),
+    )
+    .await?;
+    let error = result.error.unwrap();
+    assert_eq!(error.code, 1006);
+    assert!(result.rolled_back);
+
+    let (_, result) = post_batch(&ep, json!({"statements": ["show tables like 't1'"]})).await?;
+    assert!(result.error.is_none(), "{:?}", result.error);
+    assert_eq!(result.results[0].rows, 0);
+
+    // The table replaced by CREATE OR REPLACE would be lost on rollback.
+    let (_, result) = post_batch(&ep, json!({"statements": ["create table t1(a int)"]})).await?;
+    assert!(result.error.is_none(), "{:?}", result.error);
+    let (_, result) = post_batch(
+        &ep,
+        json!({"atomic": true, "statements": [
+            "create or replace table t1(a int)",
+            "create table t1(a int)",
+        ]}),
+    )
+    .await?;
+    let error = result.error.unwrap();
+    assert_eq!(error.code, 1006);
+    assert_eq!(result.results.len(), 1);
+    assert_eq!(result.results[0].state, ExecuteStateName::Failed);
+
+    let (_, result) = post_batch(&ep, json!({"statements": ["show tables like 't1'"]})).await?;
+    assert!(result.error.is_none(), "{:?}", result.error);
+    assert_eq!(result.results[0].rows, 1);
+
+    Ok(())
+}
+
+fn create_endpoint() -> Result<impl Endpoint> {
+    let session_manager = SessionManagerBuilder::create().build()?;
+    Ok(Route::new()
+        .at("/v1/batch", post(batch_handler))
+        .with(HTTPSessionMiddleware { session_manager }))
+}
+
+async fn post_batch(
+    ep: &impl Endpoint,
+    body: serde_json::Value,
+) -> Result<(StatusCode, BatchResponse)> {
+    let response = ep
+        .call(
+            Request::builder()
+                .uri("/v1/batch".parse().unwrap())
+                .method(Method::POST)
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(serde_json::to_vec(&body)?),
+        )
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let body = response.into_body().into_vec().await.unwrap();
+    let result = serde_json::from_slice::<BatchResponse>(&body)?;
+    Ok((status, result))
+}
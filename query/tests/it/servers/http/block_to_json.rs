@@ -68,7 +68,7 @@ fn test_data_block(is_nullable: bool) -> Result<()> {
         block
     };
 
-    let json_block = block_to_json(&block)?;
+    let json_block = block_to_json(&block, false)?;
     let expect = vec![
         vec![val(1), val("a"), val(true), val(1.1), val("1970-01-02")],
         vec![val(2), val("b"), val(true), val(2.2), val("1970-01-03")],
@@ -88,3 +88,40 @@ fn test_data_block_nullable() -> Result<()> {
 fn test_data_block_not_nullable() -> Result<()> {
     test_data_block(false)
 }
+
+#[test]
+fn test_data_block_non_finite_floats() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("c1", f64::to_data_type()),
+        DataField::new_nullable("c2", f32::to_data_type()),
+    ]);
+
+    let mut validity = MutableBitmap::new();
+    validity.extend_constant(3, true);
+    validity.set(2, false);
+    let block = DataBlock::create(schema, vec![
+        Series::from_data(vec![f64::NAN, f64::INFINITY, 1.5]),
+        NullableColumn::new(
+            Series::from_data(vec![f32::NEG_INFINITY, 2.5, 0.0]),
+            validity.into(),
+        )
+        .arc(),
+    ]);
+
+    let json_block = block_to_json(&block, false)?;
+    let expect = vec![
+        vec![Value::Null, Value::Null],
+        vec![Value::Null, val(2.5)],
+        vec![val(1.5), Value::Null],
+    ];
+    assert_eq!(json_block, expect);
+
+    let json_block = block_to_json(&block, true)?;
+    let expect = vec![
+        vec![val("NaN"), val("-inf")],
+        vec![val("inf"), val(2.5)],
+        vec![val(1.5), Value::Null],
+    ];
+    assert_eq!(json_block, expect);
+    Ok(())
+}
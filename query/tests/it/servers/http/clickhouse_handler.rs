@@ -202,8 +202,43 @@ async fn test_insert_format_ndjson() -> PoemResult<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_database_param() -> PoemResult<()> {
+    let server = Server::new();
+
+    {
+        let req = QueryBuilder::new("select database()")
+            .database("system")
+            .build();
+        let (status, body) = server.get_response(req).await;
+        assert_ok!(status, body);
+        assert_eq!(&body, "system\n");
+    }
+
+    {
+        // Unqualified names are resolved in the given database.
+        let req = QueryBuilder::new("select name from tables where name = 'tables'")
+            .database("system")
+            .body("")
+            .build();
+        let (status, body) = server.get_response(req).await;
+        assert_ok!(status, body);
+        assert_eq!(&body, "tables\n");
+    }
+
+    {
+        let req = QueryBuilder::new("select 1").database("not_exists").build();
+        let (status, body) = server.get_response(req).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_error!(body, "Cannot USE 'not_exists'");
+    }
+
+    Ok(())
+}
+
 struct QueryBuilder {
     sql: String,
+    database: Option<String>,
     body: Option<Body>,
 }
 
@@ -211,10 +246,18 @@ impl QueryBuilder {
     pub fn new(sql: &str) -> Self {
         QueryBuilder {
             sql: sql.to_string(),
+            database: None,
             body: None,
         }
     }
 
+    pub fn database(self, database: &str) -> Self {
+        Self {
+            database: Some(database.to_string()),
+            ..self
+        }
+    }
+
     pub fn body(self, body: impl Into<Body>) -> Self {
         Self {
             body: Some(body.into()),
@@ -223,9 +266,12 @@ impl QueryBuilder {
     }
 
     pub fn build(self) -> Request {
-        let uri = url::form_urlencoded::Serializer::new(String::new())
-            .append_pair("query", &self.sql)
-            .finish();
+        let mut uri = url::form_urlencoded::Serializer::new(String::new());
+        uri.append_pair("query", &self.sql);
+        if let Some(database) = &self.database {
+            uri.append_pair("database", database);
+        }
+        let uri = uri.finish();
         let uri = "/?".to_string() + &uri;
         let uri = uri.parse::<Uri>().unwrap();
         let (method, body) = match self.body {
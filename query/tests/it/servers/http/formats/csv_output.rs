@@ -0,0 +1,44 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use databend_query::servers::http::formats::csv_output::block_to_csv;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_data_block_to_csv() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("c1", i32::to_data_type()),
+        DataField::new("c2", Vu8::to_data_type()),
+        DataField::new("c3", bool::to_data_type()),
+        DataField::new("c4", f64::to_data_type()),
+    ]);
+
+    let block = DataBlock::create(schema, vec![
+        Series::from_data(vec![1, 2, 3]),
+        Series::from_data(vec!["a", "b,c", "say \"hi\"\n"]),
+        Series::from_data(vec![true, true, false]),
+        Series::from_data(vec![1.1, 2.2, 3.3]),
+    ]);
+
+    let csv_block = String::from_utf8(block_to_csv(&block)?)?;
+    let expect = "1,a,1,1.1\n\
+                  2,\"b,c\",1,2.2\n\
+                  3,\"say \"\"hi\"\"\n\",0,3.3\n";
+
+    assert_eq!(&csv_block, expect);
+    Ok(())
+}
@@ -209,6 +209,52 @@ async fn test_http_session() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_http_query_error_redaction() -> Result<()> {
+    let ep = create_endpoint();
+    let sql = "select 1 where 'x' = 'secret' 'more_secret'";
+
+    let json = serde_json::json!({"sql": "select 1", "session": {"max_idle_time": 10}});
+    let (_, result) = post_json_to_endpoint(&ep, &json).await?;
+    assert!(result.error.is_none(), "{:?}", result);
+    let session_id = &result.session_id.unwrap();
+
+    let json = serde_json::json!({"sql": sql, "session": {"id": session_id}});
+    let (status, result) = post_json_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK);
+    let error = result.error.unwrap();
+    assert!(error.message.contains("more_secret"), "{:?}", error);
+
+    let json =
+        serde_json::json!({"sql": "set enable_log_redaction = 1", "session": {"id": session_id}});
+    let (_, result) = post_json_to_endpoint(&ep, &json).await?;
+    assert!(result.error.is_none(), "{:?}", result);
+
+    let json = serde_json::json!({"sql": sql, "session": {"id": session_id}});
+    let (status, result) = post_json_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK);
+    let error = result.error.unwrap();
+    assert!(!error.message.contains("more_secret"), "{:?}", error);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_http_session_database() -> Result<()> {
+    let ep = create_endpoint();
+
+    let json = serde_json::json!({"sql": "select database()", "session": {"database": "system"}});
+    let (status, result) = post_json_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert!(result.error.is_none(), "{:?}", result);
+    assert_eq!(result.data[0][0], "system");
+
+    let json = serde_json::json!({"sql": "select 1", "session": {"database": "not_exists"}});
+    let (status, result) = post_json_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert!(result.error.is_some(), "{:?}", result);
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_result_timeout() -> Result<()> {
     let session_manager = SessionManagerBuilder::create()
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod batch;
 mod block_to_json;
 mod clickhouse_handler;
 mod formats;
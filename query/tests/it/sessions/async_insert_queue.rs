@@ -0,0 +1,95 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use databend_query::sessions::AsyncInsertQueue;
+use databend_query::sessions::AsyncInsertStatus;
+
+fn block(values: Vec<u64>) -> DataBlock {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", u64::to_data_type())]);
+    DataBlock::create(schema, vec![Series::from_data(values)])
+}
+
+fn status(queue: &AsyncInsertQueue, id: &str) -> AsyncInsertStatus {
+    let entries = queue.get_entries();
+    let entry = entries.iter().find(|entry| entry.id == id).unwrap();
+    entry.status.clone()
+}
+
+#[test]
+fn test_async_insert_queue_coalesce() -> Result<()> {
+    let queue = AsyncInsertQueue::create();
+
+    // the inserts into a table join the batch of the first one
+    let (id1, new_batch) = queue.enqueue("db", "t", vec![block(vec![1, 2])])?;
+    assert!(new_batch);
+    let (id2, new_batch) = queue.enqueue("db", "t", vec![block(vec![3])])?;
+    assert!(!new_batch);
+    let (id3, new_batch) = queue.enqueue("db", "t2", vec![block(vec![4])])?;
+    assert!(new_batch);
+    assert!(!queue.is_drained());
+
+    let batch = queue.take_batch("db", "t").unwrap();
+    assert_eq!(batch.ids, vec![id1.clone(), id2.clone()]);
+    assert_eq!(batch.blocks.len(), 2);
+    assert!(queue.take_batch("db", "t").is_none());
+
+    // the next insert starts a new batch while the taken one is flushed
+    let (id4, new_batch) = queue.enqueue("db", "t", vec![block(vec![5])])?;
+    assert!(new_batch);
+
+    queue.finish(&batch.ids, Ok(()));
+    assert_eq!(status(&queue, &id1), AsyncInsertStatus::Flushed);
+    assert_eq!(status(&queue, &id2), AsyncInsertStatus::Flushed);
+    assert_eq!(status(&queue, &id3), AsyncInsertStatus::Pending);
+    assert_eq!(status(&queue, &id4), AsyncInsertStatus::Pending);
+    assert_eq!(queue.get_entries()[0].rows, 2);
+
+    Ok(())
+}
+
+#[test]
+fn test_async_insert_queue_close() -> Result<()> {
+    let queue = AsyncInsertQueue::create();
+    let (id1, _) = queue.enqueue("db", "t", vec![block(vec![1])])?;
+    let (id2, _) = queue.enqueue("db", "t2", vec![block(vec![2])])?;
+    let batch = queue.take_batch("db", "t").unwrap();
+
+    // a closed queue rejects new inserts, and waits for the accepted ones
+    queue.close();
+    assert!(queue.enqueue("db", "t", vec![block(vec![3])]).is_err());
+    assert!(!queue.is_drained());
+
+    // the inserts not flushed in time are failed
+    queue.fail_pending("shut down");
+    assert_eq!(
+        status(&queue, &id1),
+        AsyncInsertStatus::Failed("shut down".to_string())
+    );
+    assert_eq!(
+        status(&queue, &id2),
+        AsyncInsertStatus::Failed("shut down".to_string())
+    );
+    assert!(queue.take_batch("db", "t2").is_none());
+
+    // the flush of the taken batch still ends
+    assert!(!queue.is_drained());
+    queue.finish(&batch.ids, Ok(()));
+    assert!(queue.is_drained());
+    assert_eq!(status(&queue, &id1), AsyncInsertStatus::Flushed);
+
+    Ok(())
+}
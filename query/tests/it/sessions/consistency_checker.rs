@@ -0,0 +1,48 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Duration;
+use databend_query::sessions::ConsistencyChecker;
+use databend_query::sessions::ConsistencyFindingKind;
+use databend_query::storages::fuse::operations::ConsistencyReport;
+
+#[test]
+fn test_consistency_checker_retention() {
+    let checker = ConsistencyChecker::create();
+    let report = ConsistencyReport {
+        missing_blocks: vec!["1/2/_b/a.parquet".to_string()],
+        unreferenced: vec!["1/2/_b/b.parquet".to_string()],
+        ..Default::default()
+    };
+
+    // the unreferenced object is too young to be reported
+    let reports = vec![("db".to_string(), "t".to_string(), report.clone())];
+    checker.update(reports, Duration::hours(1));
+    let findings = checker.get_findings();
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].kind, ConsistencyFindingKind::MissingBlock);
+    assert_eq!(findings[0].location, "1/2/_b/a.parquet");
+
+    let reports = vec![("db".to_string(), "t".to_string(), report)];
+    checker.update(reports, Duration::zero());
+    let findings = checker.get_findings();
+    assert_eq!(findings.len(), 2);
+    assert_eq!(findings[1].kind, ConsistencyFindingKind::Unreferenced);
+    assert_eq!(findings[1].database, "db");
+    assert_eq!(findings[1].table, "t");
+
+    // the findings are replaced by every check
+    checker.update(vec![], Duration::zero());
+    assert!(checker.get_findings().is_empty());
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod async_insert_queue;
+mod consistency_checker;
 mod query_ctx;
 mod session;
 mod session_context;
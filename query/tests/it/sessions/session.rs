@@ -41,6 +41,21 @@ async fn test_session() -> Result<()> {
         assert_eq!(actual, 3);
     }
 
+    // Last query id.
+    {
+        assert_eq!(session.get_last_query_id(), "");
+
+        let ctx = session.create_query_context().await?;
+        let query_id = ctx.get_id();
+        // Still running, it's not the last finished query yet.
+        assert_eq!(ctx.get_last_query_id(), "");
+        drop(ctx);
+
+        let ctx = session.create_query_context().await?;
+        assert_ne!(ctx.get_id(), query_id);
+        assert_eq!(ctx.get_last_query_id(), query_id);
+    }
+
     // Malloc size.
     {
         let session_size = malloc_size(&session);
@@ -14,6 +14,9 @@
 
 mod optimizer;
 mod parsers;
+mod plan_cache;
 mod plan_parser;
 mod sql_parser;
+mod sql_prepared_statement;
+mod sql_redactor;
 mod statements;
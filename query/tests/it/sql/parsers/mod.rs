@@ -13,9 +13,12 @@
 // limitations under the License.
 
 mod parser_call;
+mod parser_cluster;
 mod parser_copy;
 mod parser_database;
 mod parser_optimize;
+mod parser_prepare;
+mod parser_query;
 mod parser_show;
 mod parser_stage;
 mod parser_table;
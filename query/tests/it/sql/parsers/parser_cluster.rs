@@ -0,0 +1,49 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use databend_query::sql::statements::DfDrainNode;
+use databend_query::sql::DfStatement;
+
+use crate::sql::sql_parser::expect_parse_err_contains;
+use crate::sql::sql_parser::expect_parse_ok;
+
+#[test]
+fn test_alter_cluster_drain_node() -> Result<()> {
+    expect_parse_ok(
+        "ALTER CLUSTER DRAIN NODE 'node-1'",
+        DfStatement::DrainNode(DfDrainNode {
+            node_id: "node-1".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "alter cluster drain node 'node-1'",
+        DfStatement::DrainNode(DfDrainNode {
+            node_id: "node-1".to_string(),
+        }),
+    )?;
+
+    expect_parse_err_contains(
+        "ALTER CLUSTER DRAIN",
+        "Expected DRAIN NODE, found: EOF".to_string(),
+    )?;
+
+    expect_parse_err_contains(
+        "ALTER CLUSTER DRAIN NODE",
+        "Expected literal string, found: EOF".to_string(),
+    )?;
+
+    Ok(())
+}
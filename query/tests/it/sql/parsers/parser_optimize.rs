@@ -25,6 +25,7 @@ fn optimize_table() -> Result<()> {
     {
         let sql = "optimize TABLE t1";
         let expected = DfStatement::OptimizeTable(DfOptimizeTable {
+            if_exists: false,
             name: ObjectName(vec![Ident::new("t1")]),
             operation: Optimization::PURGE,
         });
@@ -34,6 +35,7 @@ fn optimize_table() -> Result<()> {
     {
         let sql = "OPTIMIZE tABLE t1";
         let expected = DfStatement::OptimizeTable(DfOptimizeTable {
+            if_exists: false,
             name: ObjectName(vec![Ident::new("t1")]),
             operation: Optimization::PURGE,
         });
@@ -43,6 +45,7 @@ fn optimize_table() -> Result<()> {
     {
         let sql = "optimize TABLE t1 purge";
         let expected = DfStatement::OptimizeTable(DfOptimizeTable {
+            if_exists: false,
             name: ObjectName(vec![Ident::new("t1")]),
             operation: Optimization::PURGE,
         });
@@ -52,6 +55,7 @@ fn optimize_table() -> Result<()> {
     {
         let sql = "optimize TABLE t1 compact";
         let expected = DfStatement::OptimizeTable(DfOptimizeTable {
+            if_exists: false,
             name: ObjectName(vec![Ident::new("t1")]),
             operation: Optimization::COMPACT,
         });
@@ -61,12 +65,23 @@ fn optimize_table() -> Result<()> {
     {
         let sql = "optimize TABLE t1 all";
         let expected = DfStatement::OptimizeTable(DfOptimizeTable {
+            if_exists: false,
             name: ObjectName(vec![Ident::new("t1")]),
             operation: Optimization::ALL,
         });
         expect_parse_ok(sql, expected)?;
     }
 
+    {
+        let sql = "optimize TABLE IF EXISTS t1 compact";
+        let expected = DfStatement::OptimizeTable(DfOptimizeTable {
+            if_exists: true,
+            name: ObjectName(vec![Ident::new("t1")]),
+            operation: Optimization::COMPACT,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
     {
         let sql = "optimize TABLE t1 unacceptable";
         expect_parse_err(
@@ -0,0 +1,68 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use databend_query::sql::statements::DfDeallocate;
+use databend_query::sql::statements::DfExecute;
+use databend_query::sql::statements::DfPrepare;
+use databend_query::sql::*;
+use sqlparser::ast::*;
+
+use crate::sql::sql_parser::*;
+
+#[test]
+fn prepare_test() -> Result<()> {
+    expect_parse_ok(
+        "PREPARE p1 FROM 'SELECT * FROM t WHERE a = ?'",
+        DfStatement::Prepare(DfPrepare {
+            name: "p1".to_string(),
+            query: "SELECT * FROM t WHERE a = ?".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "EXECUTE p1",
+        DfStatement::Execute(DfExecute {
+            name: "p1".to_string(),
+            params: vec![],
+        }),
+    )?;
+
+    expect_parse_ok(
+        "EXECUTE p1 USING 1, 'a'",
+        DfStatement::Execute(DfExecute {
+            name: "p1".to_string(),
+            params: vec![
+                Expr::Value(Value::Number("1".to_string(), false)),
+                Expr::Value(Value::SingleQuotedString("a".to_string())),
+            ],
+        }),
+    )?;
+
+    expect_parse_ok(
+        "DEALLOCATE PREPARE p1",
+        DfStatement::Deallocate(DfDeallocate {
+            name: "p1".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "DEALLOCATE p1",
+        DfStatement::Deallocate(DfDeallocate {
+            name: "p1".to_string(),
+        }),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,155 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use databend_query::sql::*;
+use sqlparser::ast::*;
+
+use crate::sql::sql_parser::*;
+
+#[test]
+fn select_distinct_test() -> Result<()> {
+    let query = verified_query("SELECT DISTINCT a, b FROM t")?;
+    assert!(query.distinct);
+    assert!(query.distinct_on.is_empty());
+
+    let query = verified_query("SELECT DISTINCT ON (a, b + 1) a, c FROM t ORDER BY a, c")?;
+    assert!(query.distinct);
+    assert_eq!(query.distinct_on, vec![
+        Expr::Identifier(Ident::new("a")),
+        Expr::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::new("b"))),
+            op: BinaryOperator::Plus,
+            right: Box::new(Expr::Value(Value::Number("1".to_string(), false))),
+        },
+    ]);
+    assert_eq!(query.projection.len(), 2);
+    assert_eq!(query.order_by.len(), 2);
+
+    // The statements after a DISTINCT ON query are still parsed.
+    let (statements, _) = DfParser::parse_sql("SELECT DISTINCT ON (a) a FROM t; SELECT 1")?;
+    assert_eq!(statements.len(), 2);
+
+    Ok(())
+}
+
+#[test]
+fn select_pivot_test() -> Result<()> {
+    let query = verified_query(
+        "SELECT * FROM sales PIVOT (sum(amount) FOR month IN ('JAN', 'FEB')) AS p WHERE empid > 1",
+    )?;
+    let pivot = query.pivot.as_ref().unwrap();
+    assert_eq!(pivot.aggregate.to_string(), "sum(amount)");
+    assert_eq!(pivot.column, Ident::new("month"));
+    assert_eq!(pivot.values, vec![
+        Expr::Value(Value::SingleQuotedString("JAN".to_string())),
+        Expr::Value(Value::SingleQuotedString("FEB".to_string())),
+    ]);
+    assert_eq!(pivot.alias, Some(Ident::new("p")));
+    assert_eq!(query.from.len(), 1);
+    assert_eq!(query.from[0].to_string(), "sales");
+    assert!(query.selection.is_some());
+
+    let query = verified_query("SELECT * FROM sales")?;
+    assert!(query.pivot.is_none());
+
+    expect_parse_err_contains(
+        "SELECT * FROM sales PIVOT (sum(amount) month IN ('JAN'))",
+        "Expected FOR".to_string(),
+    )?;
+    expect_parse_err_contains(
+        "SELECT * FROM sales UNPIVOT (amount FOR month IN (jan, feb))",
+        "UNPIVOT is unsupported yet".to_string(),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn select_wildcard_options_test() -> Result<()> {
+    let query = verified_query("SELECT * EXCLUDE (a, b) REPLACE (c + 1 AS c) FROM t")?;
+    assert_eq!(query.projection, vec![SelectItem::Wildcard]);
+    assert_eq!(query.wildcard_exclude, vec![
+        Ident::new("a"),
+        Ident::new("b")
+    ]);
+    assert_eq!(query.wildcard_replace.len(), 1);
+    assert_eq!(query.wildcard_replace[0].0.to_string(), "c + 1");
+    assert_eq!(query.wildcard_replace[0].1, Ident::new("c"));
+
+    let query = verified_query("SELECT DISTINCT number * 2, * EXCLUDE a FROM t")?;
+    assert_eq!(query.projection.len(), 2);
+    assert_eq!(query.wildcard_exclude, vec![Ident::new("a")]);
+    assert!(query.wildcard_replace.is_empty());
+
+    let query = verified_query("SELECT * FROM t WHERE a * 2 > 1")?;
+    assert!(query.wildcard_exclude.is_empty());
+    assert!(query.wildcard_replace.is_empty());
+
+    expect_parse_err_contains("SELECT * REPLACE (c + 1) FROM t", "Expected AS".to_string())?;
+
+    Ok(())
+}
+
+#[test]
+fn select_table_sample_test() -> Result<()> {
+    let query = verified_query("SELECT * FROM t TABLESAMPLE (10 PERCENT) WHERE a > 1")?;
+    assert_eq!(query.table_sample, Some(10.0));
+    assert_eq!(query.from[0].to_string(), "t");
+    assert!(query.selection.is_some());
+
+    let query = verified_query("SELECT count() FROM t AS t1 tablesample (0.5)")?;
+    assert_eq!(query.table_sample, Some(0.5));
+
+    let query = verified_query("SELECT * FROM t")?;
+    assert!(query.table_sample.is_none());
+
+    expect_parse_err_contains(
+        "SELECT * FROM t TABLESAMPLE (200 PERCENT)",
+        "TABLESAMPLE expects a percentage in (0, 100]".to_string(),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn select_into_outfile_test() -> Result<()> {
+    let (statements, _) = DfParser::parse_sql(
+        "SELECT a FROM t WHERE a > 1 ORDER BY a INTO OUTFILE '/tmp/t.csv.gz' FORMAT CSV COMPRESSION 'gzip'",
+    )?;
+    match &statements[0] {
+        DfStatement::SelectIntoOutfile(into_outfile) => {
+            assert_eq!(into_outfile.path, "/tmp/t.csv.gz");
+            assert_eq!(into_outfile.format, "CSV");
+            assert_eq!(into_outfile.compression, "gzip");
+            assert!(into_outfile.query.selection.is_some());
+            assert_eq!(into_outfile.query.order_by.len(), 1);
+        }
+        other => panic!("Expect select into outfile statement, but got {:?}", other),
+    }
+
+    // The statements after the clause are still parsed.
+    let (statements, _) =
+        DfParser::parse_sql("SELECT 1 INTO OUTFILE 'a.tsv' FORMAT TSV; SELECT 2")?;
+    assert_eq!(statements.len(), 2);
+    assert!(matches!(statements[0], DfStatement::SelectIntoOutfile(_)));
+    assert!(matches!(statements[1], DfStatement::Query(_)));
+
+    expect_parse_err_contains(
+        "SELECT 1 INTO OUTFILE 'a.csv' WITH HEADER",
+        "Expected FORMAT or COMPRESSION after INTO OUTFILE".to_string(),
+    )?;
+
+    Ok(())
+}
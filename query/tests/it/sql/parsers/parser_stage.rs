@@ -27,6 +27,7 @@ fn create_stage_test() -> Result<()> {
         "CREATE STAGE test_stage url='s3://load/files/' credentials=(aws_key_id='1a2b3c' aws_secret_key='4x5y6z')",
         DfStatement::CreateStage(DfCreateUserStage {
             if_not_exists: false,
+            or_replace: false,
             stage_name: "test_stage".to_string(),
             location: "s3://load/files/".to_string(),
             credential_options: HashMap::from([
@@ -41,6 +42,7 @@ fn create_stage_test() -> Result<()> {
         "CREATE STAGE IF NOT EXISTS test_stage url='s3://load/files/' credentials=(aws_key_id='1a2b3c' aws_secret_key='4x5y6z')",
         DfStatement::CreateStage(DfCreateUserStage {
             if_not_exists: true,
+            or_replace: false,
             stage_name: "test_stage".to_string(),
             location: "s3://load/files/".to_string(),
             credential_options: HashMap::from([
@@ -54,6 +56,7 @@ fn create_stage_test() -> Result<()> {
         "CREATE STAGE IF NOT EXISTS test_stage url='s3://load/files/' credentials=(aws_key_id='1a2b3c' aws_secret_key='4x5y6z') file_format=(FORMAT=CSV compression=GZIP record_delimiter=',')",
         DfStatement::CreateStage(DfCreateUserStage {
             if_not_exists: true,
+            or_replace: false,
             stage_name: "test_stage".to_string(),
             location: "s3://load/files/".to_string(),
             credential_options: HashMap::from([
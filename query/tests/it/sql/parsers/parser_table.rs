@@ -35,6 +35,7 @@ fn create_table() -> Result<()> {
     let sql = "CREATE TABLE t(c1 int) ENGINE = Fuse location = '/data/33.csv' ";
     let expected = DfStatement::CreateTable(DfCreateTable {
         if_not_exists: false,
+        or_replace: false,
         name: ObjectName(vec![Ident::new("t")]),
         columns: vec![make_column_def("c1", None, DataType::Int(None))],
         engine: "Fuse".to_string(),
@@ -47,6 +48,7 @@ fn create_table() -> Result<()> {
     let sql = "CREATE TABLE t(`c1` int) ENGINE = Fuse location = '/data/33.csv' ";
     let expected = DfStatement::CreateTable(DfCreateTable {
         if_not_exists: false,
+        or_replace: false,
         name: ObjectName(vec![Ident::new("t")]),
         columns: vec![make_column_def("c1", Some('`'), DataType::Int(None))],
         engine: "Fuse".to_string(),
@@ -59,6 +61,7 @@ fn create_table() -> Result<()> {
     let sql = "CREATE TABLE t('c1' int) ENGINE = Fuse location = '/data/33.csv' ";
     let expected = DfStatement::CreateTable(DfCreateTable {
         if_not_exists: false,
+        or_replace: false,
         name: ObjectName(vec![Ident::new("t")]),
         columns: vec![make_column_def("c1", Some('\''), DataType::Int(None))],
         engine: "Fuse".to_string(),
@@ -72,6 +75,7 @@ fn create_table() -> Result<()> {
     let sql = "CREATE TABLE t(c1 int, c2 bigint, c3 varchar(255) ) ENGINE = Fuse location = 'foo.parquet' comment = 'foo'";
     let expected = DfStatement::CreateTable(DfCreateTable {
         if_not_exists: false,
+        or_replace: false,
         name: ObjectName(vec![Ident::new("t")]),
         columns: vec![
             make_column_def("c1", None, DataType::Int(None)),
@@ -93,6 +97,7 @@ fn create_table() -> Result<()> {
     let sql = "CREATE TABLE db1.test1 LIKE db2.test2 ENGINE = Parquet location = 'batcave'";
     let expected = DfStatement::CreateTable(DfCreateTable {
         if_not_exists: false,
+        or_replace: false,
         name: ObjectName(vec![Ident::new("db1"), Ident::new("test1")]),
         columns: vec![],
         engine: "Parquet".to_string(),
@@ -107,6 +112,7 @@ fn create_table() -> Result<()> {
     let sql = "CREATE TABLE db1.test1(c1 int, c2 varchar(255)) ENGINE = Parquet location = 'batcave' AS SELECT * FROM t2";
     let expected = DfStatement::CreateTable(DfCreateTable {
         if_not_exists: false,
+        or_replace: false,
         name: ObjectName(vec![Ident::new("db1"), Ident::new("test1")]),
         columns: vec![
             make_column_def("c1", None, DataType::Int(None)),
@@ -126,6 +132,8 @@ fn create_table() -> Result<()> {
                 },
                 joins: vec![],
             }],
+            distinct: false,
+            distinct_on: vec![],
             projection: vec![SelectItem::Wildcard],
             selection: None,
             group_by: vec![],
@@ -133,6 +141,10 @@ fn create_table() -> Result<()> {
             order_by: vec![],
             limit: None,
             offset: None,
+            pivot: None,
+            table_sample: None,
+            wildcard_exclude: vec![],
+            wildcard_replace: vec![],
         })),
     });
     expect_parse_ok(sql, expected)?;
@@ -145,6 +157,7 @@ fn create_table_select() -> Result<()> {
         "CREATE TABLE foo AS SELECT a, b FROM bar",
         DfStatement::CreateTable(DfCreateTable {
             if_not_exists: false,
+            or_replace: false,
             name: ObjectName(vec![Ident::new("foo")]),
             columns: vec![],
             engine: "FUSE".to_string(),
@@ -158,6 +171,7 @@ fn create_table_select() -> Result<()> {
         "CREATE TABLE foo (a INT) SELECT a, b FROM bar",
         DfStatement::CreateTable(DfCreateTable {
             if_not_exists: false,
+            or_replace: false,
             name: ObjectName(vec![Ident::new("foo")]),
             columns: vec![make_column_def("a", None, DataType::Int(None))],
             engine: "FUSE".to_string(),
@@ -170,6 +184,35 @@ fn create_table_select() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn create_or_replace_table() -> Result<()> {
+    expect_parse_ok(
+        "CREATE OR REPLACE TABLE foo AS SELECT a, b FROM bar",
+        DfStatement::CreateTable(DfCreateTable {
+            if_not_exists: false,
+            or_replace: true,
+            name: ObjectName(vec![Ident::new("foo")]),
+            columns: vec![],
+            engine: "FUSE".to_string(),
+            options: maplit::hashmap! {},
+            like: None,
+            query: Some(verified_query("SELECT a, b FROM bar")?),
+        }),
+    )?;
+
+    expect_parse_err_contains(
+        "CREATE OR REPLACE TABLE IF NOT EXISTS foo (a INT)",
+        "OR REPLACE and IF NOT EXISTS cannot be used together".to_string(),
+    )?;
+
+    expect_parse_err_contains(
+        "CREATE OR REPLACE DATABASE foo",
+        "Expected TABLE, VIEW, STAGE or FUNCTION after CREATE OR REPLACE".to_string(),
+    )?;
+
+    Ok(())
+}
+
 #[test]
 fn drop_table() -> Result<()> {
     {
@@ -208,6 +251,43 @@ fn alter_table() -> Result<()> {
         expect_parse_ok(sql, expected)?;
     }
 
+    // alter table swap with
+    {
+        let sql = "ALTER TABLE IF EXISTS t1 SWAP WITH db2.t2";
+        let table_name = ObjectName(vec![Ident::new("t1")]);
+        let other_table_name = ObjectName(vec![Ident::new("db2"), Ident::new("t2")]);
+        let expected = DfStatement::AlterTable(DfAlterTable {
+            if_exists: true,
+            table_name,
+            action: AlterTableAction::SwapTable(other_table_name),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    // alter table comment
+    {
+        let sql = "ALTER TABLE t1 COMMENT = 'daily orders'";
+        let expected = DfStatement::AlterTable(DfAlterTable {
+            if_exists: false,
+            table_name: ObjectName(vec![Ident::new("t1")]),
+            action: AlterTableAction::Comment("daily orders".to_string()),
+        });
+        expect_parse_ok(sql, expected)?;
+
+        let sql = "ALTER TABLE t1 COMMENT ''";
+        let expected = DfStatement::AlterTable(DfAlterTable {
+            if_exists: false,
+            table_name: ObjectName(vec![Ident::new("t1")]),
+            action: AlterTableAction::Comment("".to_string()),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "ALTER TABLE t1 SWAP t2";
+        expect_parse_err_contains(sql, "Expected WITH".to_string())?;
+    }
+
     Ok(())
 }
 
@@ -220,7 +300,24 @@ fn rename_table() -> Result<()> {
             ObjectName(vec![Ident::new("t1")]),
             ObjectName(vec![Ident::new("t2")]),
         );
-        let expected = DfStatement::RenameTable(DfRenameTable { name_map });
+        let expected = DfStatement::RenameTable(DfRenameTable {
+            if_exists: false,
+            name_map,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "RENAME TABLE IF EXISTS t1 TO t2";
+        let mut name_map = HashMap::new();
+        name_map.insert(
+            ObjectName(vec![Ident::new("t1")]),
+            ObjectName(vec![Ident::new("t2")]),
+        );
+        let expected = DfStatement::RenameTable(DfRenameTable {
+            if_exists: true,
+            name_map,
+        });
         expect_parse_ok(sql, expected)?;
     }
 
@@ -264,6 +361,7 @@ fn truncate_table() -> Result<()> {
     {
         let sql = "TRUNCATE TABLE t1";
         let expected = DfStatement::TruncateTable(DfTruncateTable {
+            if_exists: false,
             name: ObjectName(vec![Ident::new("t1")]),
             purge: false,
         });
@@ -273,11 +371,22 @@ fn truncate_table() -> Result<()> {
     {
         let sql = "TRUNCATE TABLE t1 purge";
         let expected = DfStatement::TruncateTable(DfTruncateTable {
+            if_exists: false,
             name: ObjectName(vec![Ident::new("t1")]),
             purge: true,
         });
         expect_parse_ok(sql, expected)?;
     }
 
+    {
+        let sql = "TRUNCATE TABLE IF EXISTS t1";
+        let expected = DfStatement::TruncateTable(DfTruncateTable {
+            if_exists: true,
+            name: ObjectName(vec![Ident::new("t1")]),
+            purge: false,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
     Ok(())
 }
@@ -61,6 +61,19 @@ fn test_create_udf() -> Result<()> {
         "CREATE FUNCTION test_udf AS (p) -> not(isnotnull(p))",
         DfStatement::CreateUDF(DfCreateUDF {
             if_not_exists: false,
+            or_replace: false,
+            udf_name: "test_udf".to_string(),
+            parameters: vec!["p".to_string()],
+            definition: "not(isnotnull(p))".to_string(),
+            description: "".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "CREATE OR REPLACE FUNCTION test_udf AS (p) -> not(isnotnull(p))",
+        DfStatement::CreateUDF(DfCreateUDF {
+            if_not_exists: false,
+            or_replace: true,
             udf_name: "test_udf".to_string(),
             parameters: vec!["p".to_string()],
             definition: "not(isnotnull(p))".to_string(),
@@ -72,6 +85,7 @@ fn test_create_udf() -> Result<()> {
         "CREATE FUNCTION test_udf AS (p, d) -> not(isnotnull(p, d))",
         DfStatement::CreateUDF(DfCreateUDF {
             if_not_exists: false,
+            or_replace: false,
             udf_name: "test_udf".to_string(),
             parameters: vec!["p".to_string(), "d".to_string()],
             definition: "not(isnotnull(p,d))".to_string(),
@@ -93,6 +107,7 @@ fn test_create_udf() -> Result<()> {
         "CREATE FUNCTION test_udf AS (p, d) -> not(isnotnull(p, d)) DESC = 'this is a description'",
         DfStatement::CreateUDF(DfCreateUDF {
             if_not_exists: false,
+            or_replace: false,
             udf_name: "test_udf".to_string(),
             parameters: vec!["p".to_string(), "d".to_string()],
             definition: "not(isnotnull(p,d))".to_string(),
@@ -104,6 +119,7 @@ fn test_create_udf() -> Result<()> {
         "CREATE FUNCTION test_udf as (p, d) -> not(isnotnull(p, d)) DESC = 'this is a description'",
         DfStatement::CreateUDF(DfCreateUDF {
             if_not_exists: false,
+            or_replace: false,
             udf_name: "test_udf".to_string(),
             parameters: vec!["p".to_string(), "d".to_string()],
             definition: "not(isnotnull(p,d))".to_string(),
@@ -111,6 +127,17 @@ fn test_create_udf() -> Result<()> {
         }),
     )?;
 
+    expect_parse_ok(
+        "ALTER FUNCTION IF EXISTS test_udf AS (p) -> not(isnotnull(p))",
+        DfStatement::AlterUDF(DfAlterUDF {
+            if_exists: true,
+            udf_name: "test_udf".to_string(),
+            parameters: vec!["p".to_string()],
+            definition: "not(isnotnull(p))".to_string(),
+            description: "".to_string(),
+        }),
+    )?;
+
     Ok(())
 }
 
@@ -175,6 +202,7 @@ fn test_alter_udf() -> Result<()> {
     expect_parse_ok(
         "ALTER FUNCTION test_udf AS (p) -> not(isnotnull(p))",
         DfStatement::AlterUDF(DfAlterUDF {
+            if_exists: false,
             udf_name: "test_udf".to_string(),
             parameters: vec!["p".to_string()],
             definition: "not(isnotnull(p))".to_string(),
@@ -185,6 +213,7 @@ fn test_alter_udf() -> Result<()> {
     expect_parse_ok(
         "ALTER FUNCTION test_udf AS (p, d) -> not(isnotnull(p, d))",
         DfStatement::AlterUDF(DfAlterUDF {
+            if_exists: false,
             udf_name: "test_udf".to_string(),
             parameters: vec!["p".to_string(), "d".to_string()],
             definition: "not(isnotnull(p,d))".to_string(),
@@ -205,6 +234,7 @@ fn test_alter_udf() -> Result<()> {
     expect_parse_ok(
         "ALTER FUNCTION test_udf AS (p, d) -> not(isnotnull(p, d)) DESC = 'this is a description'",
         DfStatement::AlterUDF(DfAlterUDF {
+            if_exists: false,
             udf_name: "test_udf".to_string(),
             parameters: vec!["p".to_string(), "d".to_string()],
             definition: "not(isnotnull(p,d))".to_string(),
@@ -70,6 +70,7 @@ fn alter_user_auth_test(
     expect_parse_ok(
         &format!("ALTER USER 'test'@'localhost' {}", auth_clause),
         DfStatement::AlterUser(DfAlterUser {
+            if_exists: false,
             if_current_user: false,
             user: UserIdentity::new("test", "localhost"),
             auth_option: Some(DfAuthOption {
@@ -110,6 +111,12 @@ fn create_user_test() -> Result<()> {
     )?;
     create_user_auth_test("NOT IDENTIFIED", Some("no_password".to_string()), None)?;
     create_user_auth_test("", None, None)?;
+    create_user_auth_test("IDENTIFIED WITH ldap", Some("ldap".to_string()), None)?;
+    create_user_auth_test(
+        "IDENTIFIED WITH ldap BY 'uid=test,ou=people,dc=example,dc=com'",
+        Some("ldap".to_string()),
+        Some("uid=test,ou=people,dc=example,dc=com".to_string()),
+    )?;
 
     // username contains '@'
     expect_parse_ok(
@@ -198,6 +205,7 @@ fn alter_user_test() -> Result<()> {
     expect_parse_ok(
         "ALTER USER 'test'@'localhost'",
         DfStatement::AlterUser(DfAlterUser {
+            if_exists: false,
             if_current_user: false,
             user: UserIdentity::new("test", "localhost"),
             auth_option: None,
@@ -205,9 +213,24 @@ fn alter_user_test() -> Result<()> {
         }),
     )?;
 
+    expect_parse_ok(
+        "ALTER USER IF EXISTS 'test'@'localhost' IDENTIFIED BY 'password'",
+        DfStatement::AlterUser(DfAlterUser {
+            if_exists: true,
+            if_current_user: false,
+            user: UserIdentity::new("test", "localhost"),
+            auth_option: Some(DfAuthOption {
+                auth_type: None,
+                by_value: Some(password.clone()),
+            }),
+            with_options: Default::default(),
+        }),
+    )?;
+
     expect_parse_ok(
         "ALTER USER USER() IDENTIFIED BY 'password'",
         DfStatement::AlterUser(DfAlterUser {
+            if_exists: false,
             if_current_user: true,
             user: UserIdentity::new("", ""),
             auth_option: Some(DfAuthOption {
@@ -221,6 +244,7 @@ fn alter_user_test() -> Result<()> {
     expect_parse_ok(
         "ALTER USER 'test@localhost' IDENTIFIED WITH sha256_password BY 'password'",
         DfStatement::AlterUser(DfAlterUser {
+            if_exists: false,
             if_current_user: false,
             user: UserIdentity::new("test@localhost", "%"),
             auth_option: Some(DfAuthOption {
@@ -235,6 +259,7 @@ fn alter_user_test() -> Result<()> {
     expect_parse_ok(
         "ALTER USER 'test'@'%' WITH TENANTSETTING",
         DfStatement::AlterUser(DfAlterUser {
+            if_exists: false,
             if_current_user: false,
             user: UserIdentity::new("test", "%"),
             auth_option: None,
@@ -246,6 +271,7 @@ fn alter_user_test() -> Result<()> {
     expect_parse_ok(
         "ALTER USER 'test'@'%' WITH TENANTSETTING, CONFIGRELOAD IDENTIFIED by 'password'",
         DfStatement::AlterUser(DfAlterUser {
+            if_exists: false,
             if_current_user: false,
             user: UserIdentity::new("test", "%"),
             auth_option: Some(DfAuthOption {
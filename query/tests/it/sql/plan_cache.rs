@@ -0,0 +1,149 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::Result;
+use common_meta_types::UserInfo;
+use databend_query::clusters::Cluster;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::QueryContextShared;
+use databend_query::sql::PlanCache;
+use databend_query::sql::PlanParser;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_plan_cache_normalize() {
+    let tests = vec![
+        ("select a from t", false, Some("select a from t")),
+        ("  SELECT  a\n\tFROM T ; ", false, Some("select a from t")),
+        (
+            "SELECT a FROM T -- comment\n;",
+            false,
+            Some("select a from t"),
+        ),
+        (
+            "SELECT \"A\", 'X  Y' FROM T",
+            false,
+            Some("select \"A\", 'X  Y' from t"),
+        ),
+        ("SELECT A FROM T", true, Some("SELECT A FROM T")),
+        ("SELECT 'a", false, None),
+    ];
+
+    for (query, case_sensitive, expect) in tests {
+        let normalized = PlanCache::normalize(query, case_sensitive);
+        assert_eq!(normalized.as_deref(), expect, "{}", query);
+    }
+}
+
+// Every query has its own context, the tables are fetched again.
+async fn new_query_context(ctx: &Arc<QueryContext>) -> Result<Arc<QueryContext>> {
+    let shared =
+        QueryContextShared::try_create(ctx.get_current_session(), Cluster::empty()).await?;
+    Ok(QueryContext::create_from_shared(shared))
+}
+
+async fn execute(ctx: Arc<QueryContext>, query: &str) -> Result<()> {
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx, plan)?;
+    executor
+        .execute(None)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_plan_cache() -> Result<()> {
+    let tmp_dir = tempfile::tempdir()?;
+    let mut conf = crate::tests::ConfigBuilder::create().config();
+    conf.storage.storage_type = "Disk".to_string();
+    conf.storage.disk.data_path = tmp_dir.path().to_str().unwrap().to_string();
+    conf.storage.disk.temp_data_path = tmp_dir.path().to_str().unwrap().to_string();
+    conf.query.plan_cache_count = 16;
+    let ctx = crate::tests::create_query_context_with_config(conf, None).await?;
+
+    execute(ctx.clone(), "create table t(a Int32)").await?;
+    execute(ctx.clone(), "insert into t values(1)").await?;
+
+    let query = "select a from t where a > 0";
+    let key = PlanCache::key(&ctx, query)?.unwrap();
+    assert_eq!(
+        PlanCache::key(&ctx, "SELECT a  FROM t\nWHERE a > 0;")?,
+        Some(key.clone())
+    );
+
+    // Cached by the first query.
+    let ctx = new_query_context(&ctx).await?;
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let cached = ctx.get_plan_cache().get(&ctx, &key).await?;
+    assert_eq!(
+        cached.map(|plan| format!("{:?}", plan)),
+        Some(format!("{:?}", plan))
+    );
+
+    // The table version changed by the insert, the cached plan is stale.
+    execute(new_query_context(&ctx).await?, "insert into t values(2)").await?;
+    let ctx = new_query_context(&ctx).await?;
+    assert!(ctx.get_plan_cache().get(&ctx, &key).await?.is_none());
+    let new_plan = PlanParser::parse(ctx.clone(), query).await?;
+    assert!(format!("{:?}", new_plan) != format!("{:?}", plan));
+
+    // DDL clears the cache.
+    let ctx = new_query_context(&ctx).await?;
+    assert!(ctx.get_plan_cache().get(&ctx, &key).await?.is_some());
+    execute(ctx.clone(), "create table t2(a Int32)").await?;
+    let ctx = new_query_context(&ctx).await?;
+    assert!(ctx.get_plan_cache().get(&ctx, &key).await?.is_none());
+
+    // The table functions are not cached.
+    let ctx = new_query_context(&ctx).await?;
+    let query = "select number from numbers(10)";
+    PlanParser::parse(ctx.clone(), query).await?;
+    let key = PlanCache::key(&ctx, query)?.unwrap();
+    assert!(ctx.get_plan_cache().get(&ctx, &key).await?.is_none());
+
+    // The context functions are bound while analyzing, the queries calling them are not cached.
+    for query in [
+        "select now(), a from t",
+        "select a from t where a > to_uint32(today())",
+        "SELECT CURRENT_USER()",
+        "select database()",
+    ] {
+        assert!(PlanCache::key(&ctx, query)?.is_none(), "{}", query);
+    }
+
+    // The plans are cached per user and settings.
+    let query = "select a from t where a > 0";
+    let key = PlanCache::key(&ctx, query)?.unwrap();
+    let settings = ctx.get_settings();
+    settings.set_settings("timezone".to_string(), "Asia/Shanghai".to_string(), false)?;
+    assert!(PlanCache::key(&ctx, query)?.unwrap() != key);
+    settings.set_settings("timezone".to_string(), "UTC".to_string(), false)?;
+    settings.set_settings("max_expression_depth".to_string(), "1".to_string(), false)?;
+    assert!(PlanCache::key(&ctx, query)?.unwrap() != key);
+    settings.set_settings("max_expression_depth".to_string(), "128".to_string(), false)?;
+    assert_eq!(PlanCache::key(&ctx, query)?, Some(key.clone()));
+
+    ctx.get_current_session()
+        .set_current_user(UserInfo::new_no_auth("u1".to_string(), "%".to_string()));
+    assert!(PlanCache::key(&ctx, query)?.unwrap() != key);
+
+    Ok(())
+}
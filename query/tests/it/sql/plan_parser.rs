@@ -219,6 +219,82 @@ async fn test_plan_parser() -> Result<()> {
             \n                  ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [(number > 1)]]",
             error: "",
         },
+        Test {
+            name: "select-distinct",
+            sql: "select distinct number % 3 as m from numbers(10)",
+            expect: "\
+            Projection: (number % 3) as m:UInt8\
+            \n  Distinct: (number % 3)\
+            \n    Expression: (number % 3):UInt8 (Before Projection)\
+            \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
+            error: "",
+        },
+        Test {
+            name: "select-distinct-on",
+            sql: "select distinct on (number % 3) number from numbers(10) order by number desc limit 2",
+            expect: "\
+            Limit: 2\
+            \n  Projection: number:UInt64\
+            \n    Distinct: (number % 3)\
+            \n      Sort: number:UInt64\
+            \n        Expression: number:UInt64, (number % 3):UInt8 (Before OrderBy)\
+            \n          ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
+            error: "",
+        },
+        Test {
+            name: "select-wildcard-replace",
+            sql: "select * replace (number + 1 as number) from numbers(10)",
+            expect: "\
+            Projection: (number + 1) as number:UInt64\
+            \n  Expression: (number + 1):UInt64 (Before Projection)\
+            \n    ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
+            error: "",
+        },
+        Test {
+            name: "select-wildcard-exclude-unknown-column",
+            sql: "select * exclude (id) from numbers(10)",
+            expect: "",
+            error: "Code: 1058, displayText = Unknown column id in SELECT * EXCLUDE.",
+        },
+        Test {
+            name: "select-wildcard-exclude-all",
+            sql: "select * exclude number from numbers(10)",
+            expect: "",
+            error: "Code: 1005, displayText = SELECT * EXCLUDE leaves no column to select.",
+        },
+        Test {
+            name: "select-values",
+            sql: "select * from (values (1, 'a'), (2, 'b')) as t(id, name) where id > 1",
+            expect: "\
+            Projection: id:UInt8, name:String\
+            \n  Filter: (id > 1)\
+            \n    Values: schema: [id:UInt8, name:String], rows: 2",
+            error: "",
+        },
+        Test {
+            name: "select-values-mismatched-columns",
+            sql: "select * from (values (1, 'a'), (2)) as t",
+            expect: "",
+            error: "Code: 1005, displayText = VALUES rows must have the same number of columns, expected 2 but got 1.",
+        },
+        Test {
+            name: "unimplemented-join",
+            sql: "select * from numbers(3) a, numbers(2) b",
+            expect: "",
+            error: "Code: 1002, displayText = Unimplemented SELECT JOIN yet.",
+        },
+        Test {
+            name: "unimplemented-lateral",
+            sql: "select * from numbers(3) a, lateral (select a.number) b",
+            expect: "",
+            error: "Code: 1002, displayText = Cannot SELECT LATERAL subquery, SELECT JOIN is unimplemented yet.",
+        },
+        Test {
+            name: "unimplemented-correlated-subquery",
+            sql: "select * from numbers(3) a where exists (select 1 from numbers(2) b where b.number = a.number)",
+            expect: "",
+            error: "Code: 1058, displayText = Unknown column a.number (correlated subqueries are unimplemented, they need SELECT JOIN to be decorrelated).",
+        },
         Test {
             name: "unimplemented-cte",
             sql: "with t as ( select sum(number) n from numbers_mt(1000) )select * from t",
@@ -91,6 +91,65 @@ pub fn parse_sql_to_expr(query_expr: &str) -> Expr {
     parser.parse_expr().unwrap()
 }
 
+#[test]
+fn expression_depth_test() -> Result<()> {
+    let nested = |depth: usize| format!("SELECT {}1{}", "(".repeat(depth), ")".repeat(depth));
+
+    let (statements, _) = DfParser::parse_sql(&nested(50))?;
+    assert_eq!(statements.len(), 1);
+
+    let expected = "Expression is too complex, it is nested deeper than max_expression_depth(128)";
+    expect_parse_err_contains(&nested(100_000), expected.to_string())?;
+    expect_parse_err_contains(
+        &format!("SELECT {}true", "NOT ".repeat(200)),
+        expected.to_string(),
+    )?;
+    expect_parse_err_contains(
+        &format!(
+            "SELECT {}1{}",
+            "CASE WHEN true THEN ".repeat(200),
+            " END".repeat(200)
+        ),
+        expected.to_string(),
+    )?;
+
+    let result = DfParser::parse_sql_with_max_depth(&nested(50), 10);
+    assert!(result.is_err());
+    let result = DfParser::parse_sql_with_max_depth(&nested(10), 10);
+    assert!(result.is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn quoted_identifier_test() -> Result<()> {
+    let query = verified_query("SELECT `a``b`, \"first name\", `名字`, Abc FROM `my table`")?;
+    let idents = query
+        .projection
+        .iter()
+        .map(|item| match item {
+            SelectItem::UnnamedExpr(Expr::Identifier(ident)) => ident.clone(),
+            item => panic!("Unexpected select item {:?}", item),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(idents, vec![
+        Ident::with_quote('`', "a`b"),
+        Ident::with_quote('"', "first name"),
+        Ident::with_quote('`', "名字"),
+        Ident::new("Abc"),
+    ]);
+
+    assert_eq!(SQLCommon::normalize_identifier(&idents[3], true), "Abc");
+    assert_eq!(SQLCommon::normalize_identifier(&idents[3], false), "abc");
+    assert_eq!(
+        SQLCommon::normalize_identifier(&idents[1], false),
+        "first name"
+    );
+    assert_eq!(SQLCommon::quote_identifier("a`b"), "`a``b`");
+
+    Ok(())
+}
+
 #[test]
 fn hint_test() -> Result<()> {
     {
@@ -125,3 +184,37 @@ fn hint_test() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn insert_values_test() -> Result<()> {
+    let insert_values = |sql: &str| -> Result<Option<String>> {
+        let (statements, _) = DfParser::parse_sql(sql)?;
+        assert_eq!(statements.len(), 1);
+        match &statements[0] {
+            DfStatement::InsertQuery(insert) => Ok(insert.values.clone()),
+            _ => panic!("Expect insert statement"),
+        }
+    };
+
+    // Literal values are kept as the text.
+    assert_eq!(
+        insert_values("insert into t(a, b) values (1, 'x'), (-2.5e3, NULL);")?,
+        Some("(1, 'x'), (-2.5e3, NULL)".to_string())
+    );
+    assert_eq!(
+        insert_values("INSERT INTO db.t VALUES ('a''b', \"c\\\"d\"),(true,.5)")?,
+        Some("('a''b', \"c\\\"d\"),(true,.5)".to_string())
+    );
+
+    // Expressions are parsed into the AST.
+    assert_eq!(insert_values("insert into t values (1 + 1)")?, None);
+    assert_eq!(insert_values("insert into t values (now())")?, None);
+    assert_eq!(insert_values("insert into t select * from t2")?, None);
+
+    // Malformed tuples are reported by the SQL parser.
+    expect_parse_err_contains(
+        "insert into t values (1), (2) (3)",
+        "Expected end of statement".to_string(),
+    )?;
+    Ok(())
+}
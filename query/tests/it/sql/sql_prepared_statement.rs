@@ -0,0 +1,119 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::DataValue;
+use common_exception::Result;
+use databend_query::sql::PreparedStatement;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_prepared_statement_placeholders() -> Result<()> {
+    let statement = PreparedStatement::try_create("SELECT ?, '?', `?` FROM t WHERE a = ? -- ?")?;
+    assert_eq!(statement.params_count(), 2);
+    assert_eq!(
+        statement.bind(&[DataValue::Int64(1), DataValue::UInt64(2)])?,
+        "SELECT 1, '?', `?` FROM t WHERE a = 2 -- ?"
+    );
+
+    let statement = PreparedStatement::try_create("SELECT $2, $1, $2 /* $3 */")?;
+    assert_eq!(statement.params_count(), 2);
+    assert_eq!(
+        statement.bind(&[DataValue::Boolean(true), DataValue::Null])?,
+        "SELECT NULL, true, NULL /* $3 */"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_prepared_statement_bind_literals() -> Result<()> {
+    let statement = PreparedStatement::try_create("SELECT a-? FROM t WHERE b = ?")?;
+    assert_eq!(
+        statement.bind(&[
+            DataValue::Int64(-1),
+            DataValue::String("it's a\\b".as_bytes().to_vec())
+        ])?,
+        "SELECT a-(-1) FROM t WHERE b = 'it\\'s a\\\\b'"
+    );
+    assert_eq!(
+        statement.bind(&[
+            DataValue::Float64(-1.5),
+            DataValue::String(vec![0xff, 0x00])
+        ])?,
+        "SELECT a-(-1.5) FROM t WHERE b = unhex('ff00')"
+    );
+
+    let res = statement.bind(&[DataValue::Float64(f64::NAN), DataValue::Null]);
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_prepared_statement_errors() -> Result<()> {
+    let res = PreparedStatement::try_create("SELECT ?, $1");
+    assert_eq!(
+        res.unwrap_err().message(),
+        "Cannot mix ? and $n placeholders in a prepared statement"
+    );
+
+    let res = PreparedStatement::try_create("SELECT $0");
+    assert_eq!(
+        res.unwrap_err().message(),
+        "Invalid placeholder $0, placeholders are numbered from $1 to $65535"
+    );
+
+    // the placeholder numbers are bounded, and the ones too large to parse are rejected as well
+    let res = PreparedStatement::try_create("SELECT $65536");
+    assert_eq!(
+        res.unwrap_err().message(),
+        "Invalid placeholder $65536, placeholders are numbered from $1 to $65535"
+    );
+    let res = PreparedStatement::try_create("SELECT $99999999999999999999999");
+    assert_eq!(
+        res.unwrap_err().message(),
+        "Invalid placeholder $99999999999999999999999, placeholders are numbered from $1 to $65535"
+    );
+    assert_eq!(
+        PreparedStatement::try_create("SELECT $65535")?.params_count(),
+        65535
+    );
+
+    let res = PreparedStatement::try_create(&format!("SELECT 1{}", ", ?".repeat(65536)));
+    assert_eq!(
+        res.unwrap_err().message(),
+        "Too many placeholders, a prepared statement has at most 65535 parameters"
+    );
+
+    let res = PreparedStatement::try_create("SELECT 1; SELECT ?");
+    assert_eq!(
+        res.unwrap_err().message(),
+        "Prepared statement must be a single statement"
+    );
+
+    let res = PreparedStatement::try_create("EXECUTE p USING ?");
+    assert_eq!(
+        res.unwrap_err().message(),
+        "Cannot prepare a prepared statement command"
+    );
+
+    let statement = PreparedStatement::try_create("SELECT ?")?;
+    let res = statement.bind(&[]);
+    assert_eq!(
+        res.unwrap_err().message(),
+        "Prepared statement expects 1 parameters, but got 0"
+    );
+
+    Ok(())
+}
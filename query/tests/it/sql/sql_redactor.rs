@@ -0,0 +1,98 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::sql::SQLRedactor;
+
+#[test]
+fn test_redact_query() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        query: &'static str,
+        expect: &'static str,
+    }
+
+    let tests = vec![
+        Test {
+            name: "no-literals",
+            query: "select a, b from db1.t1 where a > b",
+            expect: "select a, b from db1.t1 where a > b",
+        },
+        Test {
+            name: "string-and-number-literals",
+            query: "select * from t where name = 'alice' and age > 18 limit 10",
+            expect: "select * from t where name = ? and age > ? limit ?",
+        },
+        Test {
+            name: "insert-values",
+            query: "insert into t values(1, 'a@b.com', 3.14), (2, 'c@d.com', 1e10)",
+            expect: "insert into t values(?, ?, ?), (?, ?, ?)",
+        },
+        Test {
+            name: "quoted-identifiers-are-kept",
+            query: "select `name` from t where `id` = X'1F'",
+            expect: "select `name` from t where `id` = ?",
+        },
+        Test {
+            name: "untokenizable-query",
+            query: "select 'unterminated, 123",
+            expect: "select 'unterminated, ?",
+        },
+    ];
+
+    for test in tests {
+        assert_eq!(
+            SQLRedactor::redact_query(test.query),
+            test.expect,
+            "{}",
+            test.name
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_redact_error() -> Result<()> {
+    // Values in cast/parse failures are scrubbed.
+    let error = SQLRedactor::redact_error(ErrorCode::BadDataValueType(
+        "Failed to cast variant value [\"a\",\"b\",12] to OBJECT",
+    ));
+    assert_eq!(error.code(), ErrorCode::BadDataValueTypeCode());
+    assert_eq!(
+        error.message(),
+        "Failed to cast variant value [?,?,?] to OBJECT"
+    );
+
+    let error = SQLRedactor::redact_error(ErrorCode::BadBytes(
+        "Cannot parse value '2022-13-01' to Date type",
+    ));
+    assert_eq!(error.message(), "Cannot parse value ? to Date type");
+
+    // Type names are not mistaken for values.
+    let error = SQLRedactor::redact_error(ErrorCode::BadDataValueType(
+        "Cast error happens in casting from String to Int32",
+    ));
+    assert_eq!(
+        error.message(),
+        "Cast error happens in casting from String to Int32"
+    );
+
+    // Other errors are kept as is.
+    let error = SQLRedactor::redact_error(ErrorCode::UnknownTable("Unknown table 't1'"));
+    assert_eq!(error.message(), "Unknown table 't1'");
+
+    Ok(())
+}
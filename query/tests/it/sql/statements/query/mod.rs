@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod query_collect_push_downs;
 mod query_normalizer;
 mod query_qualified_rewriter;
 mod query_schema_joined_analyzer;
@@ -0,0 +1,66 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_planners::col;
+use common_planners::lit;
+use databend_query::sql::statements::query::QueryCollectPushDowns;
+
+#[test]
+fn test_infer_equal_filters() {
+    struct TestCase {
+        name: &'static str,
+        predicate: common_planners::Expression,
+        expect: &'static str,
+    }
+
+    let tests = vec![
+        TestCase {
+            name: "Equal to a constant",
+            predicate: col("a").eq(col("b")).and(col("b").eq(lit(1i64))),
+            expect: "[(a = 1)]",
+        },
+        TestCase {
+            name: "Constant on the left",
+            predicate: lit(1i64)
+                .eq(col("b"))
+                .and(col("a").eq(col("b")))
+                .and(col("a").gt(lit(3i64))),
+            expect: "[(a = 1)]",
+        },
+        TestCase {
+            name: "Transitive equal",
+            predicate: col("a")
+                .eq(col("b"))
+                .and(col("c").eq(col("d")))
+                .and(col("b").eq(col("c")))
+                .and(col("d").eq(lit(2i64))),
+            expect: "[(a = 2), (b = 2), (c = 2)]",
+        },
+        TestCase {
+            name: "No constant",
+            predicate: col("a").eq(col("b")).and(col("c").eq(lit(1i64))),
+            expect: "[]",
+        },
+        TestCase {
+            name: "Disjunction",
+            predicate: col("a").eq(col("b")).or(col("b").eq(lit(1i64))),
+            expect: "[]",
+        },
+    ];
+
+    for test in tests {
+        let filters = QueryCollectPushDowns::infer_equal_filters(&test.predicate);
+        assert_eq!(format!("{:?}", filters), test.expect, "{:#?}", test.name);
+    }
+}
@@ -84,6 +84,65 @@ async fn test_statement_copy() -> Result<()> {
             err: "",
         },
 
+        TestCase {
+            name: "copy-external-force-ok",
+            query: "copy into system.configs
+        from 's3://mybucket/data/files'
+        credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+        encryption=(master_key = 'my_master_key')
+        files = ('file1.csv', 'file2.csv')
+        file_format = (type = csv field_delimiter = '|' skip_header = 1)
+        on_error = CONTINUE size_limit = 10 force = true
+        VALIDATION_MODE = RETURN_13_ROWS
+        ",
+            expect: r#"Copy into system.configs, ReadDataSourcePlan { source_info: S3StageSource(UserStageInfo { stage_name: "s3://mybucket/data/files", stage_type: External, stage_params: StageParams { storage: S3(StageS3Storage { bucket: "mybucket", path: "/data/files", credentials_aws_key_id: "my_key_id", credentials_aws_secret_key: "my_secret_key", encryption_master_key: "my_master_key" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 1, field_delimiter: "|", record_delimiter: "", compression: None }, copy_options: CopyOptions { on_error: Continue, size_limit: 10 }, comment: "" }), scan_fields: None, parts: [], statistics: Statistics { read_rows: 0, read_bytes: 0, partitions_scanned: 0, partitions_total: 0, is_exact: false }, description: "", tbl_args: None, push_downs: None } ,files:["file1.csv", "file2.csv"] ,force:true ,validation_mode:ReturnNRows(13)"#,
+            err: "",
+        },
+
+        TestCase {
+            name: "copy-external-compression-ok",
+            query: "copy into system.configs
+        from 's3://mybucket/data/files'
+        credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+        encryption=(master_key = 'my_master_key')
+        files = ('file1.csv', 'file2.csv')
+        file_format = (type = csv field_delimiter = '|' skip_header = 1 compression = gzip)
+        on_error = CONTINUE size_limit = 10 force = true
+        VALIDATION_MODE = RETURN_13_ROWS
+        ",
+            expect: r#"Copy into system.configs, ReadDataSourcePlan { source_info: S3StageSource(UserStageInfo { stage_name: "s3://mybucket/data/files", stage_type: External, stage_params: StageParams { storage: S3(StageS3Storage { bucket: "mybucket", path: "/data/files", credentials_aws_key_id: "my_key_id", credentials_aws_secret_key: "my_secret_key", encryption_master_key: "my_master_key" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 1, field_delimiter: "|", record_delimiter: "", compression: Gzip }, copy_options: CopyOptions { on_error: Continue, size_limit: 10 }, comment: "" }), scan_fields: None, parts: [], statistics: Statistics { read_rows: 0, read_bytes: 0, partitions_scanned: 0, partitions_total: 0, is_exact: false }, description: "", tbl_args: None, push_downs: None } ,files:["file1.csv", "file2.csv"] ,force:true ,validation_mode:ReturnNRows(13)"#,
+            err: "",
+        },
+
+        TestCase {
+            name: "copy-external-compression-error",
+            query: "copy into system.configs
+        from 's3://mybucket/data/files'
+        credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+        encryption=(master_key = 'my_master_key')
+        files = ('file1.csv', 'file2.csv')
+        file_format = (type = csv field_delimiter = '|' skip_header = 1 compression = rar)
+        on_error = CONTINUE size_limit = 10 force = true
+        VALIDATION_MODE = RETURN_13_ROWS
+        ",
+            expect: "",
+            err: "Code: 1005, displayText = File compression type error:\"Unknown file compression type, must one of { AUTO | GZIP | BZ2 | BROTLI | ZSTD | DEFLATE | RAW_DEFLATE | LZO | SNAPPY | XZ | NONE }\".",
+        },
+
+        TestCase {
+            name: "copy-external-force-error",
+            query: "copy into system.configs
+        from 's3://mybucket/data/files'
+        credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+        encryption=(master_key = 'my_master_key')
+        files = ('file1.csv', 'file2.csv')
+        file_format = (type = csv field_delimiter = '|' skip_header = 1)
+        force = yes
+        ",
+            expect: "",
+            err: "Code: 1005, displayText = force must be TRUE or FALSE, got: yes.",
+        },
+
         TestCase {
             name: "copy-external-size-limit-error",
             query: "copy into system.configs
@@ -44,7 +44,7 @@ async fn test_statement_select_analyze() -> Result<()> {
         TestCase {
             name: "Simple filter query between",
             query: "SELECT * FROM system.databases WHERE name = 'xxx' AND (name between 'aaa' and 'bbb')",
-            expect: "QueryAnalyzeState { filter: ((name = xxx) AND ((name >= aaa) and (name <= bbb))), before_projection: [name], projection: [name] }",
+            expect: "QueryAnalyzeState { filter: ((name = xxx) AND between(name, aaa, bbb)), before_projection: [name], projection: [name] }",
         },
         TestCase {
             name: "Simple having query",
@@ -153,3 +153,46 @@ async fn test_statement_select_analyze() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_statement_select_enforce_deterministic_order() -> Result<()> {
+    let tests = vec![
+        (
+            "SELECT * FROM system.databases",
+            "QueryAnalyzeState { before_order_by: [name], order_by: [name], projection: [name] }",
+        ),
+        // The order of the query is kept
+        (
+            "SELECT * FROM system.databases ORDER BY name = 'xxx'",
+            "QueryAnalyzeState { before_order_by: [name, (name = xxx)], order_by: [(name = 'xxx')], projection: [name] }",
+        ),
+    ];
+
+    for (sql, expect) in tests {
+        let ctx = create_query_context().await?;
+        ctx.get_settings().set_settings(
+            "enforce_deterministic_order".to_string(),
+            "1".to_string(),
+            false,
+        )?;
+        let (mut statements, _) = DfParser::parse_sql(sql)?;
+
+        match statements.remove(0) {
+            DfStatement::Query(query) => match query.analyze(ctx).await? {
+                AnalyzedResult::SelectQuery(state) => {
+                    assert_eq!(expect, format!("{:?}", state), "{}", sql);
+                }
+                _ => {
+                    return Err(ErrorCode::LogicalError(
+                        "Query analyzed must be return QueryAnalyzeState",
+                    ));
+                }
+            },
+            _ => {
+                return Err(ErrorCode::LogicalError("Cannot get analyze query state."));
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -17,14 +17,21 @@ use std::sync::Arc;
 
 use common_base::tokio;
 use common_datablocks::DataBlock;
+use common_datablocks::SortColumnDescription;
 use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::Extras;
+use databend_query::storages::fuse::io::decode_meta;
+use databend_query::storages::fuse::io::encode_meta;
 use databend_query::storages::fuse::io::BlockCompactor;
+use databend_query::storages::fuse::io::BlockReader;
 use databend_query::storages::fuse::io::BlockStreamWriter;
+use databend_query::storages::fuse::io::MetaCipher;
 use databend_query::storages::fuse::io::TableMetaLocationGenerator;
 use databend_query::storages::fuse::meta::TableSnapshot;
 use databend_query::storages::fuse::meta::Versioned;
+use databend_query::storages::fuse::FuseTable;
 use databend_query::storages::fuse::DEFAULT_BLOCK_PER_SEGMENT;
 use futures::StreamExt;
 use futures::TryStreamExt;
@@ -61,6 +68,7 @@ async fn test_fuse_table_block_appender() {
         DEFAULT_BLOCK_PER_SEGMENT,
         0,
         locs.clone(),
+        None,
     )
     .await
     .collect::<Vec<_>>()
@@ -88,6 +96,7 @@ async fn test_fuse_table_block_appender() {
         max_rows_per_block,
         max_blocks_per_segment,
         locs.clone(),
+        None,
     )
     .await
     .collect::<Vec<_>>()
@@ -108,6 +117,7 @@ async fn test_fuse_table_block_appender() {
         DEFAULT_BLOCK_PER_SEGMENT,
         0,
         locs,
+        None,
     )
     .await
     .collect::<Vec<_>>()
@@ -252,6 +262,100 @@ fn test_block_compactor() -> common_exception::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_block_reader_nested_columns() -> Result<()> {
+    let tmp_dir = TempDir::new().unwrap();
+    let local_fs = Operator::new(
+        fs::Backend::build()
+            .root(tmp_dir.path().to_str().unwrap())
+            .finish()
+            .await
+            .unwrap(),
+    );
+
+    let struct_type = Arc::new(StructType::create(
+        vec!["a".to_owned(), "b".to_owned()],
+        vec![i64::to_data_type(), Vu8::to_data_type()],
+    ));
+    let array_type = Arc::new(ArrayType::create(i64::to_data_type()));
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("s", struct_type.clone()),
+        DataField::new("id", i32::to_data_type()),
+        DataField::new("l", array_type.clone()),
+    ]);
+    let block = DataBlock::create(schema.clone(), vec![
+        Arc::new(StructColumn::from_data(
+            vec![
+                Series::from_data(vec![1i64, 2, 3]),
+                Series::from_data(vec!["x", "y", "z"]),
+            ],
+            struct_type,
+        )),
+        Series::from_data(vec![1i32, 2, 3]),
+        Arc::new(ArrayColumn::from_data(
+            array_type,
+            vec![0, 1, 1, 3].into(),
+            Series::from_data(vec![10i64, 20, 30]),
+        )),
+    ]);
+
+    let locs = TableMetaLocationGenerator::with_prefix(".".to_owned());
+    let segments = BlockStreamWriter::write_block_stream(
+        local_fs.clone(),
+        Box::pin(futures::stream::iter(vec![Ok(block)])),
+        schema.clone(),
+        DEFAULT_BLOCK_PER_SEGMENT,
+        0,
+        locs,
+        None,
+    )
+    .await
+    .try_collect::<Vec<_>>()
+    .await?;
+    let blocks_metas = segments[0].blocks.clone();
+    // the struct column is stored as two leaf columns
+    assert_eq!(blocks_metas[0].col_metas.len(), 4);
+
+    // the whole nested columns, after the struct leaves
+    let push_downs = Some(Extras {
+        projection: Some(vec![1, 2]),
+        filters: vec![],
+        limit: None,
+        order_by: vec![],
+        sample: None,
+    });
+    let (_, parts) = FuseTable::to_partitions(&schema, &blocks_metas, push_downs);
+    let reader = BlockReader::create(local_fs.clone(), None, schema.clone(), vec![1, 2])?;
+    let block = reader.read(parts[0].clone()).await?;
+    assert_eq!(block.num_columns(), 2);
+    assert_eq!(block.column(0).get(2), DataValue::Int64(3));
+    assert_eq!(
+        block.column(1).get(2),
+        DataValue::Array(vec![DataValue::Int64(20), DataValue::Int64(30)])
+    );
+
+    // only the second field of the struct
+    let (_, parts) = FuseTable::to_partitions(&schema, &blocks_metas, None);
+    let reader =
+        BlockReader::create_with_paths(local_fs.clone(), None, schema.clone(), vec![vec![0, 1]])?;
+    let block = reader.read(parts[0].clone()).await?;
+    let expected_type = StructType::create(vec!["b".to_owned()], vec![Vu8::to_data_type()]);
+    assert_eq!(
+        block.schema().field(0).data_type().name(),
+        expected_type.name()
+    );
+    assert_eq!(
+        block.column(0).get(1),
+        DataValue::Struct(vec![DataValue::String(b"y".to_vec())])
+    );
+
+    // invalid path
+    let reader = BlockReader::create_with_paths(local_fs, None, schema, vec![vec![1, 0]]);
+    assert!(reader.is_err());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_block_stream_writer() -> common_exception::Result<()> {
     let schema = DataSchemaRefExt::create(vec![DataField::new("a", i32::to_data_type())]);
@@ -278,6 +382,7 @@ async fn test_block_stream_writer() -> common_exception::Result<()> {
             max_rows_per_block,
             max_blocks_per_segment,
             locs,
+            None,
         )
         .await;
         let segs = stream.try_collect::<Vec<_>>().await?;
@@ -329,6 +434,67 @@ async fn test_block_stream_writer() -> common_exception::Result<()> {
     Ok(())
 }
 
+// The cluster key sort of COPY: the written blocks divide the range of the key between them.
+#[tokio::test]
+async fn test_sort_block_stream() -> Result<()> {
+    let tmp_dir = TempDir::new().unwrap();
+    let local_fs = Operator::new(
+        fs::Backend::build()
+            .root(tmp_dir.path().to_str().unwrap())
+            .finish()
+            .await
+            .unwrap(),
+    );
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", i32::to_data_type())]);
+
+    // every loaded block has the whole range [0, 100) of the key
+    let blocks = (0..10)
+        .map(|i| {
+            let values = (0..10).map(|j| j * 10 + i).collect::<Vec<i32>>();
+            Ok(DataBlock::create(schema.clone(), vec![Series::from_data(
+                values,
+            )]))
+        })
+        .collect::<Vec<_>>();
+    let sort_columns = vec![SortColumnDescription {
+        column_name: "a".to_string(),
+        asc: true,
+        nulls_first: false,
+        case_insensitive: false,
+    }];
+    let block_stream = BlockStreamWriter::sort_block_stream(
+        Box::pin(futures::stream::iter(blocks)),
+        10,
+        10,
+        sort_columns,
+    );
+
+    let locs = TableMetaLocationGenerator::with_prefix(".".to_owned());
+    let segments = BlockStreamWriter::write_block_stream(
+        local_fs,
+        block_stream,
+        schema,
+        10,
+        DEFAULT_BLOCK_PER_SEGMENT,
+        locs,
+        None,
+    )
+    .await
+    .try_collect::<Vec<_>>()
+    .await?;
+
+    assert_eq!(segments.len(), 1);
+    let blocks = &segments[0].blocks;
+    assert_eq!(blocks.len(), 10);
+    for (i, block) in blocks.iter().enumerate() {
+        let stats = &block.col_stats[&0];
+        assert_eq!(stats.min, DataValue::Int64(i as i64 * 10));
+        assert_eq!(stats.max, DataValue::Int64(i as i64 * 10 + 9));
+    }
+
+    Ok(())
+}
+
 #[test]
 fn test_meta_locations() -> Result<()> {
     let test_prefix = "test_pref";
@@ -343,6 +509,93 @@ fn test_meta_locations() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_meta_cipher() -> Result<()> {
+    // empty key disables encryption
+    assert!(MetaCipher::try_create("")?.is_none());
+
+    // key of wrong length or not base64
+    assert!(MetaCipher::try_create(&base64::encode([1u8; 16])).is_err());
+    assert!(MetaCipher::try_create("not base64!").is_err());
+
+    let cipher = MetaCipher::try_create(&base64::encode([7u8; 32]))?.unwrap();
+    let plain = br#"{"format_version":1}"#.to_vec();
+
+    let encrypted = encode_meta(Some(&cipher), plain.clone())?;
+    assert!(MetaCipher::is_encrypted(&encrypted));
+    assert_ne!(encrypted, plain);
+    assert_eq!(decode_meta(Some(&cipher), encrypted.clone())?, plain);
+
+    // encrypted meta can not be read without the key, or with another key
+    assert!(decode_meta(None, encrypted.clone()).is_err());
+    let other = MetaCipher::try_create(&base64::encode([8u8; 32]))?.unwrap();
+    assert!(decode_meta(Some(&other), encrypted).is_err());
+
+    // plain meta is still readable when the key is configured
+    assert_eq!(decode_meta(Some(&cipher), plain.clone())?, plain);
+    assert_eq!(encode_meta(None, plain.clone())?, plain);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_encrypted_block() -> Result<()> {
+    let tmp_dir = TempDir::new().unwrap();
+    let local_fs = Operator::new(
+        fs::Backend::build()
+            .root(tmp_dir.path().to_str().unwrap())
+            .finish()
+            .await
+            .unwrap(),
+    );
+    let cipher = Arc::new(MetaCipher::try_create(&base64::encode([7u8; 32]))?.unwrap());
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i64::to_data_type()),
+        DataField::new("b", Vu8::to_data_type()),
+    ]);
+    let block = DataBlock::create(schema.clone(), vec![
+        Series::from_data(vec![1i64, 2, 3]),
+        Series::from_data(vec!["x", "y", "z"]),
+    ]);
+
+    let locs = TableMetaLocationGenerator::with_prefix(".".to_owned());
+    let segments = BlockStreamWriter::write_block_stream(
+        local_fs.clone(),
+        Box::pin(futures::stream::iter(vec![Ok(block)])),
+        schema.clone(),
+        DEFAULT_BLOCK_PER_SEGMENT,
+        0,
+        locs,
+        Some(cipher.clone()),
+    )
+    .await
+    .try_collect::<Vec<_>>()
+    .await?;
+    let blocks_metas = segments[0].blocks.clone();
+
+    // the column chunks are encrypted one by one, nothing of the data is left in plain
+    let file = std::fs::read(tmp_dir.path().join(&blocks_metas[0].location.0))?;
+    assert_eq!(file.len() as u64, blocks_metas[0].file_size);
+    assert!(MetaCipher::is_encrypted(&file));
+    assert!(!file.windows(4).any(|w| w == b"PAR1"));
+
+    // every column is read by its own range
+    let (_, parts) = FuseTable::to_partitions(&schema, &blocks_metas, None);
+    let reader = BlockReader::create(local_fs.clone(), Some(cipher), schema.clone(), vec![1])?;
+    let block = reader.read(parts[0].clone()).await?;
+    assert_eq!(block.column(0).get(2), DataValue::String(b"z".to_vec()));
+
+    let chunks = reader.read_columns_data(parts[0].clone()).await?;
+    let block = reader.deserialize(parts[0].clone(), chunks)?;
+    assert_eq!(block.column(0).get(0), DataValue::String(b"x".to_vec()));
+
+    // encrypted blocks can not be read without the key
+    let reader = BlockReader::create(local_fs, None, schema, vec![0])?;
+    assert!(reader.read(parts[0].clone()).await.is_err());
+
+    Ok(())
+}
+
 use common_infallible::Mutex;
 
 #[derive(Debug)]
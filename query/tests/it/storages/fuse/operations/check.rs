@@ -0,0 +1,65 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use common_io::prelude::S3File;
+use databend_query::storages::fuse::FuseTable;
+use databend_query::storages::fuse::FUSE_TBL_BLOCK_PREFIX;
+
+use crate::storages::fuse::table_test_fixture::append_sample_data;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_fuse_check_consistency() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    append_sample_data(1, &fixture).await?;
+    append_sample_data(1, &fixture).await?;
+
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let report = fuse_table.check_consistency(ctx.clone()).await?;
+    assert!(report.is_consistent(), "{:?}", report);
+
+    let operator = ctx.get_storage_operator()?;
+    let block_dir = format!(
+        "{}/{}/",
+        fuse_table.meta_location_generator().prefix(),
+        FUSE_TBL_BLOCK_PREFIX
+    );
+    let blocks = S3File::list(&operator, &block_dir).await?;
+    assert_eq!(blocks.len(), 2);
+
+    // an object written by nobody
+    let stray = format!("{}stray.parquet", block_dir);
+    operator
+        .object(&stray)
+        .writer()
+        .write_bytes(vec![0u8; 8])
+        .await
+        .unwrap();
+    // a referenced block lost by the storage
+    operator.object(&blocks[0]).delete().await.unwrap();
+
+    let report = fuse_table.check_consistency(ctx.clone()).await?;
+    assert_eq!(report.missing_snapshot, None);
+    assert!(report.missing_segments.is_empty());
+    assert_eq!(report.missing_blocks, vec![blocks[0].clone()]);
+    assert_eq!(report.unreferenced, vec![stray]);
+
+    Ok(())
+}
@@ -13,9 +13,11 @@
 //  limitations under the License.
 //
 
+mod check;
 mod commit;
 mod optimize;
 mod part_info;
 mod purge_drop;
 mod purge_truncate;
+mod read;
 mod read_plan;
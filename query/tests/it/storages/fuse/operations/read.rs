@@ -0,0 +1,78 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use futures::TryStreamExt;
+
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_fuse_table_prewhere_read() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let db = fixture.default_db_name();
+
+    let create = format!("create table {}.t(a Int32, b String) Engine = Fuse", db);
+    execute_command(ctx.clone(), &create).await?;
+
+    // one block per insertion, `a % 2 = 0` can't prune any of them with the block statistics
+    let inserts = ["(1, 'x1'), (3, 'x3')", "(2, 'x2'), (5, 'x5')", "(7, 'x7')"];
+    for values in inserts.iter() {
+        let insert = format!("insert into {}.t values {}", db, values);
+        execute_command(ctx.clone(), &insert).await?;
+    }
+
+    // the filter column is read first, the column b of the blocks without even number is skipped
+    {
+        let query = format!("select b, a from {}.t where a % 2 = 0", db);
+        let blocks = execute_query(ctx.clone(), &query)
+            .await?
+            .try_collect::<Vec<DataBlock>>()
+            .await?;
+
+        let expected = vec![
+            "+----+---+", //
+            "| b  | a |", //
+            "+----+---+", //
+            "| x2 | 2 |", //
+            "+----+---+", //
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, blocks.as_slice());
+    }
+
+    {
+        let query = format!("select b from {}.t where a % 2 = 1 and a > 1", db);
+        let blocks = execute_query(ctx.clone(), &query)
+            .await?
+            .try_collect::<Vec<DataBlock>>()
+            .await?;
+
+        let expected = vec![
+            "+----+", //
+            "| b  |", //
+            "+----+", //
+            "| x3 |", //
+            "| x5 |", //
+            "| x7 |", //
+            "+----+", //
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, blocks.as_slice());
+    }
+
+    Ok(())
+}
@@ -17,7 +17,7 @@ use std::collections::HashMap;
 use std::iter::Iterator;
 
 use common_base::tokio;
-use common_datavalues::DataValue;
+use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_planners::Extras;
 use databend_query::interpreters::CreateTableInterpreter;
@@ -75,8 +75,14 @@ fn test_to_partitions() -> Result<()> {
         .map(|_| block_meta.clone())
         .collect::<Vec<_>>();
 
+    let schema = DataSchema::new(
+        (0..num_of_col)
+            .map(|col_id| DataField::new(&format!("c{}", col_id), i64::to_data_type()))
+            .collect(),
+    );
+
     // CASE I:  no projection
-    let (s, _) = FuseTable::to_partitions(&blocks_metas, None);
+    let (s, _) = FuseTable::to_partitions(&schema, &blocks_metas, None);
     let expected_block_size: u64 = cols_stats
         .iter()
         .map(|(_, col_stats)| col_stats.in_memory_size)
@@ -103,8 +109,9 @@ fn test_to_partitions() -> Result<()> {
         filters: vec![],
         limit: None,
         order_by: vec![],
+        sample: None,
     });
-    let (stats, _) = FuseTable::to_partitions(&blocks_metas, push_down);
+    let (stats, _) = FuseTable::to_partitions(&schema, &blocks_metas, push_down);
     assert_eq!(expected_block_size * num_of_block, stats.read_bytes as u64);
     Ok(())
 }
@@ -140,6 +147,7 @@ async fn test_fuse_table_exact_statistic() -> Result<()> {
             filters: vec![],
             limit: None,
             order_by: vec![],
+            sample: None,
         };
         let (stats, parts) = table.read_partitions(ctx.clone(), Some(push_downs)).await?;
         assert_eq!(stats.read_rows, num_blocks * rows_per_block);
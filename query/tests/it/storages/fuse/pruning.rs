@@ -13,6 +13,7 @@
 //  limitations under the License.
 //
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_base::tokio;
@@ -23,8 +24,10 @@ use common_meta_types::TableMeta;
 use common_planners::add;
 use common_planners::col;
 use common_planners::lit;
+use common_planners::sort;
 use common_planners::sub;
 use common_planners::CreateTablePlan;
+use common_planners::Expression;
 use common_planners::Extras;
 use databend_query::catalogs::Catalog;
 use databend_query::interpreters::CreateTableInterpreter;
@@ -32,11 +35,14 @@ use databend_query::sessions::QueryContext;
 use databend_query::sql::OPT_KEY_DATABASE_ID;
 use databend_query::storages::fuse::io::MetaReaders;
 use databend_query::storages::fuse::meta::BlockMeta;
+use databend_query::storages::fuse::meta::ColumnMeta;
 use databend_query::storages::fuse::meta::TableSnapshot;
 use databend_query::storages::fuse::pruning::BlockPruner;
+use databend_query::storages::fuse::pruning::TopNPruner;
 use databend_query::storages::fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 use databend_query::storages::fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use databend_query::storages::fuse::FUSE_OPT_KEY_SNAPSHOT_LOC;
+use databend_query::storages::index::ColumnStatistics;
 use futures::TryStreamExt;
 
 use crate::storages::fuse::table_test_fixture::TestFixture;
@@ -70,6 +76,7 @@ async fn test_block_pruner() -> Result<()> {
     // create test table
     let create_table_plan = CreateTablePlan {
         if_not_exists: false,
+        or_replace: false,
         tenant: fixture.default_tenant(),
         db: fixture.default_db_name(),
         table: test_tbl_name.to_string(),
@@ -190,6 +197,20 @@ async fn test_block_pruner() -> Result<()> {
 
     assert_eq!((num_blocks - max_val_of_b as usize - 1), blocks.len());
 
+    // the same predicates pushed down as separate filters
+    let mut extra = Extras::default();
+    extra.filters = vec![col("a").gt(lit(0u64)), col("b").gt(lit(max_val_of_b))];
+
+    let blocks = apply_block_pruning(
+        snapshot.clone(),
+        table.get_table_info().schema(),
+        &Some(extra),
+        ctx.clone(),
+    )
+    .await?;
+
+    assert_eq!((num_blocks - max_val_of_b as usize - 1), blocks.len());
+
     Ok(())
 }
 
@@ -210,6 +231,7 @@ async fn test_block_pruner_monotonic() -> Result<()> {
     // create test table
     let create_table_plan = CreateTablePlan {
         if_not_exists: false,
+        or_replace: false,
         tenant: fixture.default_tenant(),
         db: fixture.default_db_name(),
         table: test_tbl_name.to_string(),
@@ -312,3 +334,88 @@ async fn test_block_pruner_monotonic() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_top_n_pruner() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", u64::to_data_type())]);
+    let block_meta = |id: usize, min: u64, max: u64, null_count: u64| BlockMeta {
+        row_count: 10,
+        block_size: 0,
+        file_size: 0,
+        col_stats: HashMap::from([(0, ColumnStatistics {
+            min: DataValue::UInt64(min),
+            max: DataValue::UInt64(max),
+            null_count,
+            in_memory_size: 0,
+        })]),
+        col_metas: HashMap::from([(0, ColumnMeta {
+            offset: 0,
+            len: 0,
+            num_values: 10,
+        })]),
+        location: (id.to_string(), 0),
+    };
+
+    let metas = vec![
+        block_meta(0, 10, 19, 0),
+        block_meta(1, 0, 9, 0),
+        block_meta(2, 5, 25, 0),
+        block_meta(3, 30, 39, 0),
+    ];
+
+    let top_n = |order_by: Expression, limit: usize, filters: Vec<Expression>| {
+        let push_downs = Some(Extras {
+            projection: None,
+            filters,
+            limit: Some(limit),
+            order_by: vec![order_by],
+            sample: None,
+        });
+
+        TopNPruner::prune(&schema, &push_downs, &metas).map(|metas| {
+            metas
+                .iter()
+                .map(|meta| meta.location.0.clone())
+                .collect::<Vec<_>>()
+        })
+    };
+
+    // ORDER BY a LIMIT 5: [0, 9] holds the first 5 rows, [5, 25] may hold some of them.
+    let pruned = top_n(sort("a", true, false), 5, vec![]);
+    assert_eq!(pruned, Some(vec!["1".to_string(), "2".to_string()]));
+
+    // ORDER BY a DESC LIMIT 5: [30, 39] holds the last 5 rows.
+    let pruned = top_n(sort("a", false, false), 5, vec![]);
+    assert_eq!(pruned, Some(vec!["3".to_string()]));
+
+    // ORDER BY a DESC LIMIT 15: the 15th row may be as small as 5, nothing can be pruned.
+    let pruned = top_n(sort("a", false, false), 15, vec![]);
+    assert_eq!(pruned.map(|v| v.len()), Some(4));
+
+    // Filters and sort expressions other than a column are not handled.
+    let pruned = top_n(sort("a", true, false), 5, vec![col("a").gt(lit(1u64))]);
+    assert_eq!(pruned, None);
+    let expr = add(col("a"), lit(1u64));
+    let sort_by_expr = Expression::Sort {
+        expr: Box::new(expr.clone()),
+        asc: true,
+        nulls_first: false,
+        case_insensitive: false,
+        origin_expr: Box::new(expr),
+    };
+    let pruned = top_n(sort_by_expr, 5, vec![]);
+    assert_eq!(pruned, None);
+
+    // NULLs are out of the min/max statistics.
+    let metas = vec![block_meta(0, 10, 19, 1), block_meta(1, 0, 9, 0)];
+    let push_downs = Some(Extras {
+        projection: None,
+        filters: vec![],
+        limit: Some(5),
+        order_by: vec![sort("a", true, false)],
+        sample: None,
+    });
+    assert!(TopNPruner::prune(&schema, &push_downs, &metas).is_none());
+
+    Ok(())
+}
@@ -19,7 +19,10 @@ use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
 use databend_query::storages::fuse::statistics::accumulator;
 use databend_query::storages::fuse::statistics::reducers;
+use databend_query::storages::fuse::statistics::string_max_prefix;
+use databend_query::storages::fuse::statistics::string_min_prefix;
 use databend_query::storages::fuse::statistics::StatisticsAccumulator;
+use databend_query::storages::index::ColumnStatistics;
 
 use crate::storages::fuse::table_test_fixture::TestFixture;
 
@@ -35,6 +38,71 @@ fn test_ft_stats_block_stats() -> common_exception::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_ft_stats_string_prefix() -> common_exception::Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("s", Vu8::to_data_type())]);
+    let block = DataBlock::create(schema.clone(), vec![Series::from_data(vec![
+        "0123456789abcdef_min",
+        "short",
+        "0123456789abcdeg_max",
+    ])]);
+    let r = StatisticsAccumulator::acc_columns(&block)?;
+    let col_stats = r.get(&0).unwrap();
+    assert_eq!(
+        col_stats.min,
+        DataValue::String(b"0123456789abcdef".to_vec())
+    );
+    assert_eq!(col_stats.max, DataValue::String(b"short".to_vec()));
+
+    let block = DataBlock::create(schema.clone(), vec![Series::from_data(vec![
+        "0123456789abcdef_min",
+        "0123456789abcdeg_max",
+    ])]);
+    let r = StatisticsAccumulator::acc_columns(&block)?;
+    let col_stats = r.get(&0).unwrap();
+    assert_eq!(
+        col_stats.min,
+        DataValue::String(b"0123456789abcdef".to_vec())
+    );
+    assert_eq!(
+        col_stats.max,
+        DataValue::String(b"0123456789abcdeh".to_vec())
+    );
+
+    // the bounds of the blocks written with the full values
+    let full = HashMap::from([(0, ColumnStatistics {
+        min: DataValue::String(b"0123456789abcdef_min".to_vec()),
+        max: DataValue::String(b"0123456789abcdeg_max".to_vec()),
+        null_count: 0,
+        in_memory_size: 0,
+    })]);
+    let r = reducers::reduce_block_stats(&[&r, &full], &schema)?;
+    let col_stats = r.get(&0).unwrap();
+    assert_eq!(
+        col_stats.min,
+        DataValue::String(b"0123456789abcdef".to_vec())
+    );
+    assert_eq!(
+        col_stats.max,
+        DataValue::String(b"0123456789abcdeh".to_vec())
+    );
+
+    // the trailing 0xFF bytes can't be incremented
+    let mut max = b"0123456789abcd".to_vec();
+    max.extend_from_slice(&[0xFF, 0xFF, 0xFF]);
+    assert_eq!(
+        string_max_prefix(DataValue::String(max)),
+        DataValue::String(b"0123456789abce".to_vec())
+    );
+    let max = vec![0xFF; 20];
+    assert_eq!(
+        string_max_prefix(DataValue::String(max.clone())),
+        DataValue::String(max)
+    );
+    assert_eq!(string_min_prefix(DataValue::Int64(1)), DataValue::Int64(1));
+    Ok(())
+}
+
 #[test]
 fn test_ft_stats_col_stats_reduce() -> common_exception::Result<()> {
     let num_of_blocks = 10;
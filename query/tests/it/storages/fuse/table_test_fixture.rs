@@ -112,6 +112,7 @@ impl TestFixture {
     pub fn default_crate_table_plan(&self) -> CreateTablePlan {
         CreateTablePlan {
             if_not_exists: false,
+            or_replace: false,
             tenant: self.default_tenant(),
             db: self.default_db_name(),
             table: self.default_table_name(),
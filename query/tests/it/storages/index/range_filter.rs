@@ -126,6 +126,26 @@ fn test_range_filter() -> Result<()> {
             expect: false,
             error: "",
         },
+        Test {
+            name: "a between 21 and 30",
+            expr: Expression::create_scalar_function("between", vec![
+                col("a"),
+                lit(21i64),
+                lit(30i64),
+            ]),
+            expect: false,
+            error: "",
+        },
+        Test {
+            name: "b not between 0 and 20",
+            expr: Expression::create_scalar_function("not between", vec![
+                col("b"),
+                lit(0i32),
+                lit(20i32),
+            ]),
+            expect: false,
+            error: "",
+        },
         Test {
             name: "c not like 'ac%'",
             expr: Expression::create_binary_expression("not like", vec![
@@ -166,6 +186,15 @@ fn test_range_filter() -> Result<()> {
             expect: false,
             error: "",
         },
+        Test {
+            name: "a > rand() + 100",
+            expr: col("a").gt(add(
+                Expression::create_scalar_function("rand", vec![]),
+                lit(100i64),
+            )),
+            expect: true,
+            error: "",
+        },
     ];
 
     for test in tests {
@@ -180,6 +209,97 @@ fn test_range_filter() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_range_filter_monotonic_functions() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("ts", DateTime32Type::arc(None)),
+        DataField::new("a", i64::to_data_type()),
+    ]);
+
+    // 2022-01-01 00:00:00 ~ 2022-01-01 23:59:59
+    let mut stats: BlockStatistics = HashMap::new();
+    stats.insert(0u32, ColumnStatistics {
+        min: DataValue::UInt64(1640995200),
+        max: DataValue::UInt64(1641081599),
+        null_count: 0,
+        in_memory_size: 0,
+    });
+    stats.insert(1u32, ColumnStatistics {
+        min: DataValue::Int64(100),
+        max: DataValue::Int64(300),
+        null_count: 0,
+        in_memory_size: 0,
+    });
+
+    let to_date = |expr: Expression| Expression::create_scalar_function("toDate", vec![expr]);
+    let date = |value: &str| to_date(lit(value.as_bytes()));
+
+    struct Test {
+        name: &'static str,
+        expr: Expression,
+        expect: bool,
+    }
+
+    let tests: Vec<Test> = vec![
+        Test {
+            name: "toDate(ts) = '2022-01-01'",
+            expr: to_date(col("ts")).eq(date("2022-01-01")),
+            expect: true,
+        },
+        Test {
+            name: "toDate(ts) = '2022-01-02'",
+            expr: to_date(col("ts")).eq(date("2022-01-02")),
+            expect: false,
+        },
+        Test {
+            name: "cast(ts as Date16) > '2022-01-01'",
+            expr: Expression::Cast {
+                expr: Box::new(col("ts")),
+                data_type: Date16Type::arc(),
+                is_nullable: false,
+            }
+            .gt(date("2022-01-01")),
+            expect: false,
+        },
+        Test {
+            name: "addDays(ts, 1) >= '2022-01-02'",
+            expr: to_date(Expression::create_scalar_function("addDays", vec![
+                col("ts"),
+                lit(1i64),
+            ]))
+            .gt_eq(date("2022-01-02")),
+            expect: true,
+        },
+        Test {
+            name: "addDays(ts, 1) >= '2022-01-03'",
+            expr: to_date(Expression::create_scalar_function("addDays", vec![
+                col("ts"),
+                lit(1i64),
+            ]))
+            .gt_eq(date("2022-01-03")),
+            expect: false,
+        },
+        Test {
+            name: "toInt64(a) > 300",
+            expr: Expression::create_scalar_function("toInt64", vec![col("a")]).gt(lit(300i64)),
+            expect: false,
+        },
+        Test {
+            // toInt8 wraps 300 around to 44, which is not monotonic, so nothing is pruned.
+            name: "toInt8(a) > 100",
+            expr: Expression::create_scalar_function("toInt8", vec![col("a")]).gt(lit(100i8)),
+            expect: true,
+        },
+    ];
+
+    for test in tests {
+        let prune = RangeFilter::try_create(&test.expr, schema.clone())?;
+        assert_eq!(test.expect, prune.eval(&stats)?, "{}", test.name);
+    }
+
+    Ok(())
+}
+
 #[test]
 fn test_build_verifiable_function() -> Result<()> {
     let schema = DataSchemaRefExt::create(vec![
@@ -215,6 +335,11 @@ fn test_build_verifiable_function() -> Result<()> {
             expr: Expression::create_scalar_function("isNull", vec![col("a")]),
             expect: "(nulls_a > 0)",
         },
+        Test {
+            name: "a between 1 and 3",
+            expr: Expression::create_scalar_function("between", vec![col("a"), lit(1), lit(3)]),
+            expect: "((max_a >= 1) and (min_a <= 3))",
+        },
         Test {
             name: "a is not null",
             expr: Expression::create_scalar_function("isNotNull", vec![col("a")]),
@@ -78,6 +78,7 @@ async fn test_memorytable() -> Result<()> {
                         filters: vec![],
                         limit: None,
                         order_by: vec![],
+                        sample: None,
                     })
                 })
                 .collect();
@@ -186,6 +187,7 @@ async fn test_memorytable() -> Result<()> {
     // truncate.
     {
         let truncate_plan = TruncateTablePlan {
+            if_exists: false,
             db: "default".to_string(),
             table: "a".to_string(),
             purge: false,
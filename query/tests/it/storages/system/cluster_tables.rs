@@ -0,0 +1,93 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::Result;
+use common_meta_types::NodeInfo;
+use databend_query::api::MIN_SYSTEM_TABLE_PROTOCOL_VERSION;
+use databend_query::clusters::Cluster;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::QueryContextShared;
+use databend_query::sessions::SessionType;
+use databend_query::storages::system::ClusterMetricsTable;
+use databend_query::storages::system::ClusterProcessesTable;
+use databend_query::storages::ToReadDataSourcePlan;
+use futures::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_cluster_processes_table() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    let table = ClusterProcessesTable::create(1);
+    assert_eq!(table.schema().field(0).name(), "node");
+    assert_eq!(table.schema().field(1).name(), "id");
+
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    let stream = table.read(ctx, &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = &result[0];
+    assert_eq!(block.num_columns(), 13);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_cluster_metrics_table() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    let table = ClusterMetricsTable::create(1);
+    let local_id = ctx.get_cluster().local_id();
+
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    let stream = table.read(ctx, &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = &result[0];
+    assert_eq!(block.num_columns(), 5);
+
+    // Without other nodes, all the rows are from the local node.
+    let nodes = block.column(0).to_values();
+    assert!(nodes
+        .iter()
+        .all(|node| node.as_string().unwrap() == local_id.as_bytes()));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_cluster_table_skips_unreachable_nodes() -> Result<()> {
+    let sessions = crate::tests::SessionManagerBuilder::create().build()?;
+    let session = sessions.create_session(SessionType::Test).await?;
+
+    // Nothing listens on the flight address of the remote node.
+    let mut remote = NodeInfo::create("remote".to_string(), 0, "127.0.0.1:1".to_string());
+    remote.version = MIN_SYSTEM_TABLE_PROTOCOL_VERSION;
+    let local = NodeInfo::create("local".to_string(), 0, "127.0.0.1:9090".to_string());
+    let cluster = Cluster::create(vec![Arc::new(remote), Arc::new(local)], "local".to_string());
+    let ctx = QueryContext::create_from_shared(
+        QueryContextShared::try_create(Arc::new(session.as_ref().clone()), cluster).await?,
+    );
+
+    let table = ClusterMetricsTable::create(1);
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    let stream = table.read(ctx, &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+
+    // The rows of the local node are still returned.
+    let nodes = result[0].column(0).to_values();
+    assert!(nodes
+        .iter()
+        .all(|node| node.as_string().unwrap() == b"local".to_vec()));
+
+    Ok(())
+}
@@ -47,9 +47,12 @@ async fn test_configs_table() -> Result<()> {
         "| clickhouse_handler_host              | 127.0.0.1                | query   |             |",
         "| clickhouse_handler_port              | 9000                     | query   |             |",
         "| cluster_id                           |                          | query   |             |",
+        "| consistency_check_interval_secs      | 0                        | query   |             |",
+        "| consistency_check_retention_secs     | 86400                    | query   |             |",
         "| database_engine_github_enabled       | true                     | query   |             |",
         "| disk.data_path                       | _data                    | storage |             |",
         "| disk.temp_data_path                  |                          | storage |             |",
+        "| encryption.encryption_key            |                          | storage |             |",
         "| flight_api_address                   | 127.0.0.1:9090           | query   |             |",
         "| http_handler_host                    | 127.0.0.1                | query   |             |",
         "| http_handler_port                    | 8000                     | query   |             |",
@@ -58,6 +61,8 @@ async fn test_configs_table() -> Result<()> {
         "| http_handler_tls_server_key          |                          | query   |             |",
         "| http_handler_tls_server_root_ca_cert |                          | query   |             |",
         "| jwt_key_file                         |                          | query   |             |",
+        "| ldap_bind_dn_template                |                          | query   |             |",
+        "| ldap_url                             |                          | query   |             |",
         "| log_dir                              | ./_logs                  | log     |             |",
         "| log_level                            | INFO                     | log     |             |",
         "| log_query_enabled                    | false                    | log     |             |",
@@ -72,7 +77,12 @@ async fn test_configs_table() -> Result<()> {
         "| metric_api_address                   | 127.0.0.1:7070           | query   |             |",
         "| mysql_handler_host                   | 127.0.0.1                | query   |             |",
         "| mysql_handler_port                   | 3307                     | query   |             |",
+        "| node_routing_rules                   |                          | query   |             |",
+        "| node_tags                            |                          | query   |             |",
         "| num_cpus                             | 0                        | query   |             |",
+        "| outfile_allowed_dirs                 |                          | query   |             |",
+        "| plan_cache_count                     | 0                        | query   |             |",
+        "| result_cache_bytes                   | 67108864                 | query   |             |",
         "| rpc_tls_meta_server_root_ca_cert     |                          | meta    |             |",
         "| rpc_tls_meta_service_domain_name     | localhost                | meta    |             |",
         "| rpc_tls_query_server_root_ca_cert    |                          | query   |             |",
@@ -99,6 +109,8 @@ async fn test_configs_table() -> Result<()> {
         "| table_engine_parquet_enabled         | false                    | query   |             |",
         "| table_memory_cache_mb_size           | 256                      | query   |             |",
         "| tenant_id                            | test                     | query   |             |",
+        "| text_log_max_rows                    | 100000                   | log     |             |",
+        "| text_log_retention_secs              | 86400                    | log     |             |",
         "| wait_timeout_mills                   | 5000                     | query   |             |",
         "+--------------------------------------+--------------------------+---------+-------------+",
     ];
@@ -136,9 +148,12 @@ async fn test_configs_table_redact() -> Result<()> {
         "| clickhouse_handler_host              | 127.0.0.1                | query   |             |",
         "| clickhouse_handler_port              | 9000                     | query   |             |",
         "| cluster_id                           |                          | query   |             |",
+        "| consistency_check_interval_secs      | 0                        | query   |             |",
+        "| consistency_check_retention_secs     | 86400                    | query   |             |",
         "| database_engine_github_enabled       | true                     | query   |             |",
         "| disk.data_path                       | _data                    | storage |             |",
         "| disk.temp_data_path                  |                          | storage |             |",
+        "| encryption.encryption_key            |                          | storage |             |",
         "| flight_api_address                   | 127.0.0.1:9090           | query   |             |",
         "| http_handler_host                    | 127.0.0.1                | query   |             |",
         "| http_handler_port                    | 8000                     | query   |             |",
@@ -147,6 +162,8 @@ async fn test_configs_table_redact() -> Result<()> {
         "| http_handler_tls_server_key          |                          | query   |             |",
         "| http_handler_tls_server_root_ca_cert |                          | query   |             |",
         "| jwt_key_file                         |                          | query   |             |",
+        "| ldap_bind_dn_template                |                          | query   |             |",
+        "| ldap_url                             |                          | query   |             |",
         "| log_dir                              | ./_logs                  | log     |             |",
         "| log_level                            | INFO                     | log     |             |",
         "| log_query_enabled                    | false                    | log     |             |",
@@ -161,7 +178,12 @@ async fn test_configs_table_redact() -> Result<()> {
         "| metric_api_address                   | 127.0.0.1:7070           | query   |             |",
         "| mysql_handler_host                   | 127.0.0.1                | query   |             |",
         "| mysql_handler_port                   | 3307                     | query   |             |",
+        "| node_routing_rules                   |                          | query   |             |",
+        "| node_tags                            |                          | query   |             |",
         "| num_cpus                             | 0                        | query   |             |",
+        "| outfile_allowed_dirs                 |                          | query   |             |",
+        "| plan_cache_count                     | 0                        | query   |             |",
+        "| result_cache_bytes                   | 67108864                 | query   |             |",
         "| rpc_tls_meta_server_root_ca_cert     |                          | meta    |             |",
         "| rpc_tls_meta_service_domain_name     | localhost                | meta    |             |",
         "| rpc_tls_query_server_root_ca_cert    |                          | query   |             |",
@@ -188,6 +210,8 @@ async fn test_configs_table_redact() -> Result<()> {
         "| table_engine_parquet_enabled         | false                    | query   |             |",
         "| table_memory_cache_mb_size           | 256                      | query   |             |",
         "| tenant_id                            | test                     | query   |             |",
+        "| text_log_max_rows                    | 100000                   | log     |             |",
+        "| text_log_retention_secs              | 86400                    | log     |             |",
         "| wait_timeout_mills                   | 5000                     | query   |             |",
         "+--------------------------------------+--------------------------+---------+-------------+",
     ];
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod cluster_tables;
 mod clusters_table;
 mod columns_table;
 mod configs_table;
@@ -24,7 +25,9 @@ mod metrics_table;
 mod query_log_table;
 mod roles_table;
 mod settings_table;
+mod table_sizes_table;
 mod tables_table;
+mod text_log_table;
 mod tracing_table;
 mod users_table;
 mod warehouses_table;
@@ -33,14 +33,31 @@ async fn test_settings_table() -> Result<()> {
         "+------------------------------------+---------+---------+---------+--------------------------------------------------------------------------------------------------------------------------------------------+--------+",
         "| name                               | value   | default | level   | description                                                                                                                                | type   |",
         "+------------------------------------+---------+---------+---------+--------------------------------------------------------------------------------------------------------------------------------------------+--------+",
+        "| async_insert                       | 0       | 0       | SESSION | Return a handle once INSERT data is accepted and flush it in the background if value != 0, default value: 0                                | UInt64 |",
+        "| dictionary_encoding_max_ndv        | 0       | 0       | SESSION | Dictionary encode the scanned string columns with at most this many distinct values, 0 disables the encoding, default value: 0             | UInt64 |",
+        "| enable_log_redaction               | 0       | 0       | SESSION | Redact literals in logged queries and values in cast/parse errors if value != 0, default value: 0                                          | UInt64 |",
         "| enable_new_processor_framework     | 1       | 1       | SESSION | Enable new processor framework if value != 0, default value: 1                                                                             | UInt64 |",
+        "| enable_query_result_cache          | 0       | 0       | SESSION | Reuse the cached results of identical deterministic queries on unchanged FUSE tables if value != 0, default value: 0                       | UInt64 |",
+        "| enforce_deterministic_order        | 0       | 0       | SESSION | Sort the results of queries without ORDER BY by all the output columns if value != 0, default value: 0                                     | UInt64 |",
+        "| executor_abort_on_stall            | 0       | 0       | SESSION | Abort the query if it makes no progress for executor_stall_timeout_secs if value != 0, default value: 0                                    | UInt64 |",
+        "| executor_stall_timeout_secs        | 300     | 300     | SESSION | Log the processors graph if the query makes no progress for this many seconds, 0 disables the check, default value: 300                    | UInt64 |",
+        "| explain_graphviz_with_schema       | 0       | 0       | SESSION | Show the output schema of every plan node in EXPLAIN GRAPHVIZ if value != 0, default value: 0                                              | UInt64 |",
         "| flight_client_timeout              | 60      | 60      | SESSION | Max duration the flight client request is allowed to take in seconds. By default, it is 60 seconds                                         | UInt64 |",
+        "| ignore_scan_limits                 | 0       | 0       | SESSION | Run queries exceeding max_scan_rows or max_scan_bytes if value != 0, default value: 0                                                      | UInt64 |",
+        "| json_quote_non_finite_floats       | 0       | 0       | SESSION | Output NaN and Infinity floats as strings in JSON if value != 0, otherwise as null, default value: 0                                       | UInt64 |",
         "| max_block_size                     | 10000   | 10000   | SESSION | Maximum block size for reading                                                                                                             | UInt64 |",
+        "| max_expression_depth               | 128     | 128     | SESSION | Reject queries with expressions nested deeper than this, default value: 128                                                                | UInt64 |",
+        "| max_scan_bytes                     | 0       | 0       | SESSION | Reject queries estimated to scan more bytes than this if value != 0, default value: 0                                                      | UInt64 |",
+        "| max_scan_rows                      | 0       | 0       | SESSION | Reject queries estimated to scan more rows than this if value != 0, default value: 0                                                       | UInt64 |",
         "| max_threads                        | 2       | 16      | SESSION | The maximum number of threads to execute the request. By default, it is determined automatically.                                          | UInt64 |",
+        "| parquet_row_group_rows             | 1048576 | 1048576 | SESSION | Max rows of a row group in the parquet files written by INTO OUTFILE, default value: 1048576                                               | UInt64 |",
+        "| query_result_cache_max_bytes       | 1048576 | 1048576 | SESSION | Max bytes of a query result to be cached, default value: 1048576                                                                           | UInt64 |",
         "| storage_occ_backoff_init_delay_ms  | 5       | 5       | SESSION | The initial retry delay in millisecond. By default, it is 5 ms.                                                                            | UInt64 |",
         "| storage_occ_backoff_max_delay_ms   | 20000   | 20000   | SESSION | The maximum  back off delay in millisecond, once the retry interval reaches this value, it stops increasing. By default, it is 20 seconds. | UInt64 |",
         "| storage_occ_backoff_max_elapsed_ms | 120000  | 120000  | SESSION | The maximum elapsed time after the occ starts, beyond which there will be no more retries. By default, it is 2 minutes.                    | UInt64 |",
         "| storage_read_buffer_size           | 1048576 | 1048576 | SESSION | The size of buffer in bytes for buffered reader of dal. By default, it is 1MB.                                                             | UInt64 |",
+        "| timezone                           | UTC     | UTC     | SESSION | The timezone of the timestamps cast from strings without a timezone, default value: UTC                                                    | String |",
+        "| unquoted_ident_case_sensitive      | 1       | 1       | SESSION | Keep the case of unquoted identifiers if value != 0, otherwise fold them to lowercase, default value: 1                                    | UInt64 |",
         "+------------------------------------+---------+---------+---------+--------------------------------------------------------------------------------------------------------------------------------------------+--------+",
     ];
     common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
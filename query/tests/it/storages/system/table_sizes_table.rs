@@ -0,0 +1,62 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sql::PlanParser;
+use databend_query::storages::system::TableSizesTable;
+use databend_query::storages::ToReadDataSourcePlan;
+use futures::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_table_sizes_table() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+
+    for query in [
+        "create table default.a(a UInt64) Engine = Memory",
+        "create table default.b(b UInt64) Engine = Memory",
+        "insert into default.a values(1),(2),(3)",
+        "insert into default.b values(4)",
+    ] {
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let stream = executor.execute(None).await?;
+        stream.try_collect::<Vec<_>>().await?;
+    }
+
+    let table = TableSizesTable::create(1);
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    let stream = table.read(ctx.clone(), &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    assert_eq!(result[0].num_columns(), 8);
+
+    let query = "select database, table, row_count, snapshot_count from system.table_sizes where database = 'default'";
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let stream = executor.execute(None).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let expected = vec![
+        "+----------+-------+-----------+----------------+",
+        "| database | table | row_count | snapshot_count |",
+        "+----------+-------+-----------+----------------+",
+        "| default  | NULL  | 4         | NULL           |",
+        "| default  | a     | 3         | NULL           |",
+        "| default  | b     | 1         | NULL           |",
+        "+----------+-------+-----------+----------------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
@@ -0,0 +1,133 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_base::tokio;
+use common_datablocks::pretty_format_blocks;
+use common_exception::Result;
+use common_tracing::TextLogRecord;
+use common_tracing::TextLogStore;
+use databend_query::storages::system::TextLogTable;
+use databend_query::storages::ToReadDataSourcePlan;
+use futures::TryStreamExt;
+
+fn text_log_record(event_time: i64, message: &str) -> TextLogRecord {
+    TextLogRecord {
+        event_time,
+        level: "WARN".to_string(),
+        target: "text_log_table".to_string(),
+        thread_name: "test".to_string(),
+        message: message.to_string(),
+    }
+}
+
+fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as i64
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_text_log_table() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    let table = TextLogTable::create(1);
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+
+    TextLogStore::instance().append(text_log_record(now_millis(), "test_text_log_table_warning"));
+
+    let stream = table.read(ctx, &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = &result[0];
+    assert_eq!(block.num_columns(), 6);
+    assert!(block.num_rows() >= 1);
+
+    let output = pretty_format_blocks(result.as_slice())?;
+    assert!(output.contains("test_text_log_table_warning"));
+
+    Ok(())
+}
+
+#[test]
+fn test_text_log_store_rotation() -> Result<()> {
+    let now = now_millis();
+
+    // Rotated by rows.
+    let store = TextLogStore::create(2, Duration::from_secs(3600));
+    store.append(text_log_record(now, "a"));
+    store.append(text_log_record(now, "b"));
+    store.append(text_log_record(now, "c"));
+    assert_eq!(store.records(), vec![
+        text_log_record(now, "b"),
+        text_log_record(now, "c")
+    ]);
+
+    // Rotated by retention.
+    let store = TextLogStore::create(10, Duration::from_secs(60));
+    store.append(text_log_record(now - 120_000, "expired"));
+    store.append(text_log_record(now, "kept"));
+    assert_eq!(store.records(), vec![text_log_record(now, "kept")]);
+
+    // Shrinking the limits rotates the existing records.
+    store.append(text_log_record(now, "newest"));
+    store.set_retention(1, Duration::from_secs(60));
+    assert_eq!(store.records(), vec![text_log_record(now, "newest")]);
+
+    store.clear();
+    assert!(store.records().is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_text_log_store_persistence() -> Result<()> {
+    let tmp_dir = tempfile::tempdir()?;
+    let now = now_millis();
+    let hour_millis = 3_600_000;
+
+    // The records logged before the directory is set are persisted too.
+    let store = TextLogStore::create(10, Duration::from_secs(7200));
+    store.append(text_log_record(now - 2 * hour_millis - 1, "expired"));
+    store.append(text_log_record(now, "before"));
+    store.persist_to(tmp_dir.path())?;
+    store.append(text_log_record(now, "after"));
+    assert_eq!(store.records(), vec![
+        text_log_record(now, "before"),
+        text_log_record(now, "after")
+    ]);
+
+    // A new store reads the records persisted by the previous one.
+    let store = TextLogStore::create(1, Duration::from_secs(7200));
+    store.persist_to(tmp_dir.path())?;
+    assert_eq!(store.records(), vec![text_log_record(now, "after")]);
+
+    // The files of the hours before the retention are removed.
+    store.set_retention(10, Duration::from_secs(4 * 3600));
+    store.append(text_log_record(now - 3 * hour_millis, "old"));
+    assert_eq!(std::fs::read_dir(tmp_dir.path())?.count(), 2);
+    store.set_retention(10, Duration::from_secs(3600));
+    assert_eq!(std::fs::read_dir(tmp_dir.path())?.count(), 1);
+    assert_eq!(store.records(), vec![
+        text_log_record(now, "before"),
+        text_log_record(now, "after")
+    ]);
+
+    store.clear();
+    assert_eq!(std::fs::read_dir(tmp_dir.path())?.count(), 0);
+
+    Ok(())
+}
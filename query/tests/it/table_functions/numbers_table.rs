@@ -60,6 +60,36 @@ async fn test_number_table() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_number_table_read_parallelism() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    ctx.get_settings().set_max_threads(8)?;
+
+    // (numbers, parts, streams): small inputs are not split into a stream per thread.
+    let tests = vec![(10, 1, 1), (25000, 3, 3), (1000000, 8, 8)];
+    for (numbers, parts, streams) in tests {
+        let tbl_args = Some(vec![Expression::create_literal(DataValue::UInt64(numbers))]);
+        let table = NumbersTable::create("system", "numbers_mt", 1, tbl_args)?.as_table();
+        let source_plan = table
+            .read_plan(ctx.clone(), Some(Extras::default()))
+            .await?;
+
+        assert_eq!(source_plan.parts.len(), parts, "numbers({})", numbers);
+        assert_eq!(
+            table.read_parallelism(&ctx, &source_plan)?,
+            streams,
+            "numbers({})",
+            numbers
+        );
+    }
+
+    ctx.set_parallelism(3);
+    ctx.set_parallelism(2);
+    assert_eq!(ctx.get_parallelism(), 3);
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_limit_push_down() -> Result<()> {
     struct Test {
@@ -0,0 +1,102 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::AuthInfo;
+use common_meta_types::PasswordHashMethod;
+use common_meta_types::UserInfo;
+use databend_query::users::auth::AuthProvider;
+use databend_query::users::auth::LdapAuthProvider;
+use databend_query::users::auth::PasswordAuthProvider;
+use databend_query::users::User;
+use pretty_assertions::assert_eq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_password_auth_provider() -> Result<()> {
+    let provider = PasswordAuthProvider;
+    let user: UserInfo = User::new("test", "%", AuthInfo::Password {
+        hash_value: PasswordHashMethod::Sha256.hash(b"password"),
+        hash_method: PasswordHashMethod::Sha256,
+    })
+    .into();
+
+    provider.authenticate(&user, Some(b"password")).await?;
+
+    let res = provider.authenticate(&user, Some(b"wrong")).await;
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::AuthenticateFailureCode()
+    );
+
+    let res = provider.authenticate(&user, None).await;
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::AuthenticateFailureCode()
+    );
+
+    let user: UserInfo = User::new("test", "%", AuthInfo::None).into();
+    provider.authenticate(&user, None).await?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_ldap_auth_provider() -> Result<()> {
+    let conf = crate::tests::ConfigBuilder::create().config();
+    assert!(LdapAuthProvider::try_create(&conf)?.is_none());
+
+    let mut conf = conf;
+    conf.query.ldap_url = "ldap://127.0.0.1:1".to_string();
+    conf.query.ldap_bind_dn_template = "uid={user},ou=people,dc=example,dc=com".to_string();
+    let provider = LdapAuthProvider::try_create(&conf)?.unwrap();
+
+    // DN from the template, special characters in the user name are escaped.
+    let user: UserInfo = User::new("a,b", "%", AuthInfo::Ldap { bind_dn: None }).into();
+    let bind_dn = provider.bind_dn(&user)?;
+    assert!(bind_dn.starts_with("uid=a\\"));
+    assert!(bind_dn.ends_with("b,ou=people,dc=example,dc=com"));
+    assert!(!bind_dn.contains("a,b"));
+
+    // DN set on the user.
+    let user: UserInfo = User::new("test", "%", AuthInfo::Ldap {
+        bind_dn: Some("cn=test,dc=corp".to_string()),
+    })
+    .into();
+    assert_eq!(provider.bind_dn(&user)?, "cn=test,dc=corp");
+
+    // Empty password would be an unauthenticated bind.
+    let res = provider.authenticate(&user, Some(b"")).await;
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::AuthenticateFailureCode()
+    );
+
+    let res = provider.authenticate(&user, None).await;
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::AuthenticateFailureCode()
+    );
+
+    // Server unreachable.
+    let res = provider.authenticate(&user, Some(b"password")).await;
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::AuthenticateFailureCode()
+    );
+
+    let user: UserInfo = User::new("test", "%", AuthInfo::None).into();
+    assert!(provider.bind_dn(&user).is_err());
+    Ok(())
+}
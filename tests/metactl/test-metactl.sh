@@ -18,6 +18,15 @@ cat $meta_json \
 
 diff $meta_json $exported
 
+# test export into and import from a file
+./target/debug/databend-metactl --export --raft-dir "$meta_dir" --db $exported
+diff $meta_json $exported
+
+rm -rf "$meta_dir"
+./target/debug/databend-metactl --import --raft-dir "$meta_dir" --db $exported
+./target/debug/databend-metactl --export --raft-dir "$meta_dir" > $exported
+diff $meta_json $exported
+
 # test export from grpc
 chmod +x ./target/debug/databend-meta
 ./target/debug/databend-meta --single &
@@ -26,6 +35,7 @@ echo $METASRV_PID
 sleep 0.5
 
 ./target/debug/databend-metactl --export > $grpc_exported
+grep -Fxq '["header",{"data_version":1}]' $grpc_exported
 grep -Fxq '["raft_state",{"RaftStateKV":{"key":"Id","value":{"NodeId":0}}}]' $grpc_exported
 
 kill $METASRV_PID
@@ -0,0 +1,2 @@
+SELECT name, volatility FROM system.functions WHERE name IN ('abs', 'now', 'database', 'rand', 'sleep', 'generateuuidv4') ORDER BY name;
+SELECT count() FROM system.functions WHERE is_aggregate = 1 AND volatility != '';
@@ -4,6 +4,8 @@ SELECT number ,number-1 , number*100 , 1> 100 ,1 < 10 FROM numbers_mt (10) order
 -- between
 select number from numbers_mt(10) where number not between 4 + 0.1  and 8 - 0.1 order by number;
 select number from numbers_mt(10) where number between 4 + 0.1  and 8 - 0.1  order by number;
+select number from numbers_mt(10) where number between number - 1 and 3 order by number;
+select 'b' between 'abc' and 'bc', 3 not between 1 and 2, NULL between 1 and 2;
 
 -- like
 select * from system.databases where name like '%sys%';
@@ -16,6 +18,12 @@ select * from system.databases where name not like '%sys%' order by name;
 select * from system.databases where name not like '_ef_ul_' order by name;
 select '\%' not like '\%';
 
+-- ilike
+select * from system.databases where name ilike '%SYS%';
+select * from system.databases where name ilike '_EF_UL_';
+select 'Databend' ilike 'data%';
+select * from system.databases where name not ilike '%SYS%' order by name;
+
 select * from numbers(10) where null = true;
 select * from numbers(10) where null and true;
 
@@ -171,3 +179,7 @@ SELECT toDateTime('2021-03-05 00:00:00') = toDate('2021-03-05');
 SELECT toDateTime('2021-03-05 00:00:01') > toDate('2021-03-05');
 SELECT toDateTime('2021-03-04 00:00:01') < toDate('2021-03-05');
 SELECT toDateTime(toDate('2021-03-05')) = toDate('2021-03-05');
+SELECT 1 <=> 1, 1 <=> NULL, NULL <=> NULL;
+SELECT 1 IS DISTINCT FROM 1, 1 IS DISTINCT FROM NULL, NULL IS DISTINCT FROM NULL;
+SELECT 1 IS NOT DISTINCT FROM 2, NULL IS NOT DISTINCT FROM NULL;
+SELECT count(*) FROM numbers(10) WHERE number <=> 3;
@@ -11,7 +11,7 @@ select toTypeName(toDateTime64(1640019661000)) = 'DateTime64(3)';
 select today() + 1 - today() = 1;
 
 select toTypeName(today() - today()) = 'Int32';
-select toTypeName(now() - now()) = 'Int32';
+select toTypeName(now() - now()) = 'Interval(Second)';
 select sum(today() + number - today()) = 45 from numbers(10);
 
 select today() - 1 = yesterday();
@@ -184,6 +184,23 @@ select EXTRACT(MINUTE FROM toDateTime('2022-03-04 22:32:09')) = 32;
 select EXTRACT(SECOND FROM toDateTime('2022-03-04 22:32:09')) = 9;
 select '===EXTRACT===';
 
+select '===date_trunc===';
+select date_trunc('year', toDateTime('2022-05-18 22:32:09')) = toDateTime('2022-01-01 00:00:00');
+select date_trunc('quarter', toDateTime('2022-05-18 22:32:09')) = toDateTime('2022-04-01 00:00:00');
+select date_trunc('month', toDateTime('2022-05-18 22:32:09')) = toDateTime('2022-05-01 00:00:00');
+select date_trunc('week', toDateTime('2022-05-18 22:32:09')) = toDateTime('2022-05-16 00:00:00');
+select date_trunc('day', toDateTime('2022-05-18 22:32:09')) = toDateTime('2022-05-18 00:00:00');
+select date_trunc('HOUR', toDateTime('2022-05-18 22:32:09')) = toDateTime('2022-05-18 22:00:00');
+select date_trunc('minute', toDateTime('2022-05-18 22:32:09')) = toDateTime('2022-05-18 22:32:00');
+select date_trunc('month', toDate('2022-05-18')) = toDateTime('2022-05-01 00:00:00');
+select date_trunc('century', toDate('2022-05-18')); -- {ErrorCode 1006}
+select '===date_trunc===';
+
+select '===snake case===';
+select to_year(toDate('2022-05-18')), to_month(toDate('2022-05-18')), to_day_of_week(toDate('2022-05-18')), to_hour(toDateTime('2022-05-18 22:32:09'));
+select to_start_of_month(toDate('2022-05-18')), to_start_of_quarter(toDate('2022-05-18')), to_start_of_week(toDate('2022-05-18'), 1);
+select '===snake case===';
+
 
 select '===CMP===';
 
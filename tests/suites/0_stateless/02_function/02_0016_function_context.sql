@@ -1 +1,3 @@
 SELECT current_user();
+SELECT database(), version() LIKE 'DatabendQuery%';
+SELECT length(last_query_id()) = 36;
@@ -8,4 +8,10 @@ SELECT isemptyUUID(null);
 SELECT isnotemptyUUID(generateUUIDv4());
 SELECT isnotemptyUUID(zeroUUID());
 SELECT isnotemptyUUID('5');
-SELECT isnotemptyUUID(null);
\ No newline at end of file
+SELECT isnotemptyUUID(null);
+SELECT toTypeName(gen_random_uuid());
+SELECT gen_random_uuid() = gen_random_uuid();
+SELECT to_uuid('936DA01F9ABD4D9D80C702AF85C822A8');
+SELECT CAST('936da01f-9abd-4d9d-80c7-02af85c822a8' AS UUID) = '936DA01F-9ABD-4D9D-80C7-02AF85C822A8';
+SELECT count(DISTINCT to_uuid(if(number % 2 = 0, '936da01f-9abd-4d9d-80c7-02af85c822a8', '936DA01F9ABD4D9D80C702AF85C822A8'))) FROM numbers(4);
+SELECT to_uuid('databend'); -- {ErrorCode 1010}
@@ -10,8 +10,13 @@ select 1 in (1.0, 2);
 select 'aa' in ('aa', 'bb', 'cc');
 select true in (true, false);
 select true in (NULL, false);
+SELECT 1 NOT IN (2, 3, NULL);
+SELECT 2 NOT IN (2, 3, NULL);
+SELECT number FROM numbers(30) WHERE number IN (1, 3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33) AND number > 20 ORDER BY number;
 --
 DROP TABLE IF EXISTS t1;
 CREATE TABLE t1(a UInt64 null, b UInt64 null) Engine = Memory;
 INSERT INTO t1 (a,b) VALUES (1, NULL), (2, 3);
 SELECT a FROM t1 WHERE b IN (NULL,3);
+SELECT a, b, a IN (b, 2) FROM t1 ORDER BY a;
+DROP TABLE t1;
@@ -0,0 +1,5 @@
+SELECT typeof(number), typeof(number + 1.5), typeof('a') FROM numbers(1);
+SELECT humanize_size(1024), humanize_size(10220000000), humanize_size(0);
+SELECT humanize_number(1022), humanize_number(1000000), humanize_number(-2500000000);
+SELECT humanize_size(NULL) IS NULL;
+SELECT count() FROM numbers(100) WHERE NOT ignore(humanize_size(number), typeof(number));
@@ -0,0 +1,6 @@
+-- now() is the start time of the query for every row
+SELECT count(DISTINCT now()) FROM numbers_mt(100000);
+SELECT count() FROM numbers(3) WHERE now() = now();
+SELECT today() = toDate(now());
+SELECT now_per_row() >= now() FROM numbers(3);
+SELECT current_timestamp() = now();
@@ -0,0 +1,8 @@
+SELECT typeof(x'ABCD');
+SELECT hex(x'ABCD');
+SELECT to_string(x'616263');
+SELECT x'616263' = 'abc';
+SELECT typeof(to_binary('abc'));
+SELECT hex(to_binary('abc'));
+SELECT to_string(x'FF'); -- {ErrorCode 1010}
+SELECT length(x'FF'); -- {ErrorCode 1007}
@@ -0,0 +1,5 @@
+SELECT map('a', 1, 'b', 2);
+SELECT map_size(map('a', 1, 'b', 2)), map_contains_key(map('a', 1), 'b');
+SELECT m['b'], m['c'] FROM (SELECT map('a', 1, 'b', 2) AS m);
+SELECT map_size(m), m[3] FROM (SELECT map_agg(number, number * 2) AS m FROM numbers(5));
+SELECT map('a', 1, 'b'); -- {ErrorCode 1028}
@@ -0,0 +1,13 @@
+select '==get==';
+select get(parse_json('{"a": {"b": [1, "x"]}}'), 'a');
+select get(parse_json('[1, 2]'), 1);
+select get(parse_json('[1, 2]'), 'a');
+select j['a'], j['c'] from (select parse_json('{"a": 1, "b": 2}') as j);
+select get(1, 'a'); -- {ErrorCode 1006}
+select '==get_path==';
+select get_path(parse_json('{"a": {"b": [1, "x"]}}'), 'a.b[1]');
+select get_path(parse_json('{"a": {"b": [1, "x"]}}'), 'a["b"][2]');
+select get_path(parse_json('{"a": 1}'), 'a..b'); -- {ErrorCode 1006}
+select '==to_json==';
+select to_json(parse_json('{"a": [1, "b"]}'));
+select cast(parse_json('"abc"') as varchar), cast(parse_json('12') as bigint);
@@ -0,0 +1,3 @@
+SELECT array(1, 2, 3);
+SELECT a[0], a[2], a[5] FROM (SELECT array(1, 2, 3) AS a);
+SELECT get(array(number, number * 10), 1) FROM numbers(3);
@@ -0,0 +1,4 @@
+SELECT (1, 'a') = (1, 'a'), (1, 'a') <> (1, 'b'), (1, 2) < (1, 3), (2, 1) <= (1, 9), (1, 2) > (0, 9), (1, 2) >= (1, 2);
+SELECT number FROM numbers(5) WHERE (number, number % 2) > (1, 0) ORDER BY number;
+SELECT number FROM numbers(5) WHERE (number, number * 2) IN ((1, 2), (3, 5), (4, 8)) ORDER BY number;
+SELECT number FROM numbers(3) WHERE (number, 'a') NOT IN ((1, 'a')) ORDER BY number;
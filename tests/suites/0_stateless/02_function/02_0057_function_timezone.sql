@@ -0,0 +1,10 @@
+select cast(cast('2022-01-01 08:00:00' as timestamp) as UInt32);
+set timezone='Asia/Shanghai';
+select cast(cast('2022-01-01 08:00:00' as timestamp) as UInt32);
+select cast(cast('2022-01-01 08:00:00' as timestamp) as String);
+select cast(cast('2022-01-01 00:30:00' as timestamp) as Date16);
+select toYYYYMMDD(cast('2022-01-01 00:30:00' as timestamp)), toHour(cast('2022-01-01 00:30:00' as timestamp));
+select cast('2022-01-01 08:00:00' as timestamp) = cast(1640995200 as timestamp);
+set timezone='UTC';
+select cast(cast('2022-01-01 08:00:00' as timestamp) as UInt32);
+set timezone='Mars/Olympus';  -- {ErrorCode 1006}
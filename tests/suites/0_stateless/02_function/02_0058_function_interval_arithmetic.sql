@@ -0,0 +1,7 @@
+select toDateTime(1582970400) - toDateTime(1582970340);
+select toTypeName(toDateTime(1582970400) - toDateTime(1582970340));
+select toDateTime(1582970400) - toDateTime(1582970340) = interval '1' minute;
+select interval '1' hour > interval '59' minute;
+select interval '1' day = interval '24' hour;
+select interval '1' year = interval '12' month;
+select interval '1' year < interval '1' day; -- {ErrorCode 1007}
@@ -0,0 +1,7 @@
+select bool_and(number < 1000), bool_or(number = 999), count_if(number % 3 = 0) from numbers(1000);
+select bool_and(number < 999), bool_or(number > 1000), count_if(number > 1000) from numbers(1000);
+select number % 2 as k, bool_and(number < 8), bool_or(number = 9), count_if(number > 5) from numbers(10) group by k order by k;
+select bool_and(if(number = 0, null, number > 0)), count_if(if(number = 0, null, true)) from numbers(10);
+select bool_and(null), bool_or(null), count_if(null) from numbers(10);
+select bool_and(number < 1), count_if(number < 1) from numbers(0);
+select bool_and(number) from numbers(10); -- {ErrorCode 1010}
@@ -0,0 +1,14 @@
+SELECT coalesce(NULL, 1, 2), coalesce(NULL, NULL), toTypeName(coalesce(NULL, toUInt8(1), toUInt32(2)));
+SELECT coalesce(NULL, number, 100) FROM numbers(3) ORDER BY number;
+SELECT coalesce('a', 1); -- {ErrorCode 1010}
+
+SELECT greatest(1, 3, 2), least(1, 3, 2), greatest('b', 'abc'), least(toDate('2022-01-02'), '2022-01-01');
+SELECT greatest(1, NULL);
+SELECT greatest('a', true); -- {ErrorCode 1007}
+
+SELECT to_json(json_object('a', 1, 'b', 'x', 'c', NULL));
+SELECT to_json(json_object());
+SELECT json_object('a', 1, 'b'); -- {ErrorCode 1028}
+
+SELECT concat('a', 1, 2.5);
+SELECT locate('b', 'abc', true); -- {ErrorCode 1007}
@@ -0,0 +1,8 @@
+SELECT to_ipv4('192.168.1.1') AS ip, toTypeName(ip), to_ipv4(3232235777), inet_ntoa(ip), ipv4_string_to_num('192.168.1.1'), ipv4_num_to_string(3232235777);
+SELECT to_ipv6('2001:DB8::1'), to_ipv6('192.168.1.1'), toTypeName(to_ipv6('::1')), CAST(to_ipv6('::ffff:10.0.0.1') AS IPv4);
+SELECT to_ipv4('10.0.0.1') << '10.0.0.0/8', to_ipv4('11.0.0.1') << '10.0.0.0/8', to_ipv6('2001:db8::1') << '2001:db8::/32', '192.168.1.200' << '192.168.1.0/25';
+SELECT is_ip_address_in_range(to_ipv4('127.0.0.1'), '127.0.0.1');
+SELECT to_ipv4('10.0.0.1') = '10.0.0.1', to_ipv4('10.0.0.1') = to_ipv6('::ffff:10.0.0.1'), to_ipv4('9.0.0.1') < to_ipv4('10.0.0.1');
+SELECT to_ipv4(if(number % 2 = 0, '10.0.0.1', '9.0.0.1')) AS ip, count() FROM numbers(4) GROUP BY ip ORDER BY ip;
+SELECT to_ipv4('192.168.1.256'); -- {ErrorCode 1010}
+SELECT to_ipv4('10.0.0.1') << '10.0.0.0/33'; -- {ErrorCode 1006}
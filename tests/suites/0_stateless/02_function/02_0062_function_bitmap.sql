@@ -0,0 +1,8 @@
+SELECT to_bitmap('3,1,2'), toTypeName(to_bitmap('1'));
+SELECT bitmap_and(to_bitmap('1,2,3'), to_bitmap('2,3,4')), bitmap_or(to_bitmap('1,2'), to_bitmap('4')), bitmap_count(to_bitmap(''));
+SELECT bitmap_count(bitmap_agg(number)) FROM numbers(10);
+SELECT number % 2 AS k, bitmap_agg(number % 4) FROM numbers(10) GROUP BY k ORDER BY k;
+SELECT bitmap_count(bitmap_and(bitmap_agg(number), to_bitmap('1,5,100'))) FROM numbers(10);
+SELECT to_bitmap('a'); -- {ErrorCode 1010}
+SELECT bitmap_count('1'); -- {ErrorCode 1007}
+SELECT bitmap_agg(number - 20) FROM numbers(2); -- {ErrorCode 1010}
@@ -0,0 +1,15 @@
+SELECT REGEXP_REPLACE('a b c', 'b', 'X');
+SELECT REGEXP_REPLACE('abc def ghi', '[a-z]+', 'X', 1, 3);
+SELECT REGEXP_REPLACE('abc def ghi', '[a-z]+', 'X', 4);
+SELECT REGEXP_REPLACE('ABC', 'b', 'x', 1, 0, 'c');
+SELECT REGEXP_REPLACE('john smith', '(\\w+) (\\w+)', '$2 $1');
+SELECT REGEXP_REPLACE('a b c', NULL, 'X');
+SELECT REGEXP_REPLACE('a b c', 'b', 'X', NULL);
+--
+DROP TABLE IF EXISTS t1;
+CREATE TABLE t1(s String NULL, pat String NULL, repl String NULL) Engine = Memory;
+INSERT INTO t1 (s, pat, repl) VALUES (NULL, 'dog', 'fox'), ('dog cat dog', 'dog', 'fox'), ('dog cat dog', 'c.t', 'owl'), ('dog cat dog', NULL, 'owl');
+select regexp_replace(s, pat, repl) from t1 where s is not null and pat is not null order by pat;
+select count(*) from t1 where regexp_replace(s, pat, repl) is null;
+select regexp_replace(s, 'dog', 'fox') from t1 where s is not null limit 1;
+DROP TABLE t1;
@@ -0,0 +1,13 @@
+SELECT REGEXP_SUBSTR('abc def ghi', '[a-z]+');
+SELECT REGEXP_SUBSTR('abc def ghi', '[a-z]+', 1, 3);
+SELECT REGEXP_SUBSTR('abc def ghi', '[a-z]+', 6);
+SELECT REGEXP_SUBSTR('abc def ghi', 'x');
+SELECT REGEXP_SUBSTR('ABC def', 'abc', 1, 1, 'c');
+SELECT REGEXP_SUBSTR('abc def ghi', NULL);
+--
+DROP TABLE IF EXISTS t1;
+CREATE TABLE t1(s String NULL, pat String NULL) Engine = Memory;
+INSERT INTO t1 (s, pat) VALUES (NULL, 'dog'), ('dog cat dog', 'c.t'), ('dog cat dog', NULL), ('dog cat dog', 'fox');
+select regexp_substr(s, pat) from t1 where s is not null and pat is not null order by pat;
+select count(*) from t1 where regexp_substr(s, pat) is null;
+DROP TABLE t1;
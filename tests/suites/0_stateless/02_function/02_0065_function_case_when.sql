@@ -0,0 +1,9 @@
+SELECT CASE WHEN number = 0 THEN 'zero' WHEN number % 2 = 1 THEN 'odd' ELSE 'even' END FROM numbers(5) ORDER BY number;
+SELECT CASE number WHEN 1 THEN 'one' WHEN 2 THEN 'two' END FROM numbers(4) ORDER BY number;
+SELECT CASE WHEN number > 1 THEN number END FROM numbers(3) ORDER BY number;
+SELECT toTypeName(CASE WHEN number > 1 THEN toUInt8(number) ELSE toInt64(-1) END) FROM numbers(1);
+-- the results are evaluated only on the rows selected by their conditions
+SELECT if(number = 0, 0, CAST(if(number = 0, 'x', toString(number)) AS UInt64)) FROM numbers(3) ORDER BY number;
+SELECT CASE WHEN number = 0 THEN 0 ELSE CAST(CASE WHEN number = 0 THEN 'x' ELSE toString(number) END AS UInt64) END FROM numbers(3) ORDER BY number;
+SELECT ifNull(NULL, 1), ifNull(2, 1), nullIf(1, 1), nullIf(1, 2), nullIf(NULL, 1);
+SELECT nullIf(number % 2, 0), ifNull(nullIf(number, 1), 10) FROM numbers(3) ORDER BY number;
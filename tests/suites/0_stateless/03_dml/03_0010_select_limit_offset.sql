@@ -11,3 +11,7 @@ select number from numbers(10) order by number asc offset 5;
 select '===================';
 select number from numbers(10) order by number+number asc offset 5;
 select number from numbers(10000000000000) limit 1;
+select '=== Test fetch ===';
+select number from numbers(100) order by number asc offset 10 rows fetch next 3 rows only;
+select '==================';
+select number from numbers(100) order by number asc fetch first row only;
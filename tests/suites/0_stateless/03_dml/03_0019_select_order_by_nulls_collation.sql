@@ -0,0 +1,19 @@
+DROP DATABASE IF EXISTS db_order_by;
+CREATE DATABASE db_order_by;
+USE db_order_by;
+
+CREATE TABLE t(s String null) Engine = Memory;
+INSERT INTO t VALUES('b'), (NULL), ('C'), ('a'), ('B0');
+
+SELECT '==NULLS==';
+SELECT s FROM t ORDER BY s;
+SELECT s FROM t ORDER BY s NULLS LAST;
+SELECT s FROM t ORDER BY s DESC;
+SELECT s FROM t ORDER BY s DESC NULLS FIRST;
+
+SELECT '==COLLATE==';
+SELECT s FROM t ORDER BY s COLLATE utf8_general_ci NULLS LAST;
+SELECT s FROM t ORDER BY s COLLATE utf8mb4_0900_ai_ci DESC;
+SELECT s FROM t ORDER BY s COLLATE utf8_bin NULLS LAST;
+
+DROP DATABASE db_order_by;
@@ -0,0 +1,16 @@
+SELECT DISTINCT number % 3 AS c FROM numbers_mt(1000) ORDER BY c;
+SELECT DISTINCT number % 7 AS a, number % 3 AS b FROM numbers_mt(100000) ORDER BY a DESC, b DESC LIMIT 3;
+SELECT DISTINCT number % 3 AS c FROM numbers(10) ORDER BY c LIMIT 2;
+
+DROP DATABASE IF EXISTS db_distinct;
+CREATE DATABASE db_distinct;
+USE db_distinct;
+
+CREATE TABLE t(a Int32, b String, c Int32 null) Engine = Memory;
+INSERT INTO t VALUES (1, 'x', 10), (1, 'x', 20), (2, 'y', NULL), (2, 'y', NULL), (1, 'z', 30);
+SELECT DISTINCT a, b FROM t ORDER BY a, b;
+SELECT DISTINCT c FROM t ORDER BY c;
+SELECT DISTINCT ON (a) a, c FROM t ORDER BY a, c DESC;
+SELECT DISTINCT ON (a, b) a, b, c FROM t ORDER BY a, b, c;
+
+DROP DATABASE db_distinct;
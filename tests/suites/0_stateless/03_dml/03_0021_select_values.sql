@@ -0,0 +1,5 @@
+SELECT * FROM (VALUES (1, 'a'), (2, 'b'), (3, 'c')) AS t(id, name) WHERE id > 1 ORDER BY id;
+SELECT t.name FROM (VALUES (1, 'a'), (2, 'b')) AS t(id, name) ORDER BY t.name DESC;
+SELECT column1, column2 FROM (VALUES (1, 1.5), (300, 2.5)) ORDER BY column1;
+SELECT typeof(column1) FROM (VALUES (1), (NULL)) LIMIT 1;
+SELECT sum(id) FROM (VALUES (1), (2), (3)) AS t(id);
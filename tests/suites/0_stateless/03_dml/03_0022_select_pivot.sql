@@ -0,0 +1,3 @@
+SELECT * FROM (SELECT number % 3 AS k, number % 2 AS m, number AS v FROM numbers(6)) PIVOT (sum(v) FOR m IN (0, 1)) ORDER BY k;
+SELECT * FROM (SELECT number % 2 AS k, if(number < 3, 'a', 'b') AS c FROM numbers(5)) PIVOT (count(*) FOR c IN ('a', 'b')) AS p ORDER BY k;
+SELECT k FROM (SELECT number % 3 AS k, number % 2 AS m, number AS v FROM numbers(6)) PIVOT (sum(v) FOR m IN (0, 1)) AS p WHERE k > 0 ORDER BY k DESC;
@@ -0,0 +1,4 @@
+SELECT * EXCLUDE (name) FROM (VALUES (1, 'a', 10), (2, 'b', 20)) AS t(id, name, score) ORDER BY id;
+SELECT * EXCLUDE id REPLACE (upper(name) AS name) FROM (VALUES (1, 'a', 10), (2, 'b', 20)) AS t(id, name, score) ORDER BY name;
+SELECT * REPLACE (score * 2 AS score) FROM (VALUES (1, 'a', 10), (2, 'b', 20)) AS t(id, name, score) WHERE id > 1;
+SELECT number * 2, * EXCLUDE (number) REPLACE (name AS name) FROM (SELECT number, 'x' AS name FROM numbers(2)) ORDER BY number;
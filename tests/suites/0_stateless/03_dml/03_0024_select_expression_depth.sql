@@ -0,0 +1,6 @@
+SELECT ((((((((((1))))))))));
+SELECT NOT NOT NOT NOT NOT true;
+SET max_expression_depth = 5;
+SELECT ((((((((((1)))))))))); -- {ErrorCode 1005}
+SELECT NOT NOT NOT NOT NOT NOT true; -- {ErrorCode 1005}
+SELECT ((((1))));
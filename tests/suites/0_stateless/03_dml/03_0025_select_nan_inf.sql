@@ -0,0 +1,19 @@
+DROP TABLE IF EXISTS t;
+CREATE TABLE t(a Float64) Engine = Memory;
+
+INSERT INTO t VALUES (1), (-1), (0);
+INSERT INTO t SELECT 1 / 0;
+INSERT INTO t SELECT -1 / 0;
+INSERT INTO t SELECT 0 / 0;
+INSERT INTO t SELECT -(0 / 0);
+INSERT INTO t SELECT -0.0;
+
+SELECT '==ORDER BY==';
+SELECT a FROM t WHERE a != 0 ORDER BY a;
+SELECT a FROM t WHERE a != 0 ORDER BY a DESC;
+
+SELECT '==GROUP BY==';
+SELECT count() FROM (SELECT a FROM t GROUP BY a);
+SELECT count(DISTINCT a) FROM t;
+
+DROP TABLE t;
@@ -0,0 +1,4 @@
+set enforce_deterministic_order = 1;
+select number % 3 as a, number from numbers_mt(6);
+select number % 3 as a, count() from numbers_mt(100) group by a;
+select number from numbers_mt(10) order by number desc limit 3;
@@ -0,0 +1,12 @@
+DROP TABLE IF EXISTS t_dict;
+CREATE TABLE t_dict(id Int32, s String null);
+INSERT INTO t_dict VALUES (1, 'apple'), (2, 'banana'), (3, 'apple'), (4, NULL), (5, 'cherry'), (6, 'banana');
+
+set dictionary_encoding_max_ndv = 16;
+SELECT id, s FROM t_dict WHERE s = 'apple' ORDER BY id;
+SELECT id FROM t_dict WHERE s > 'apple' ORDER BY id;
+SELECT s, count() FROM t_dict WHERE s IS NOT NULL GROUP BY s ORDER BY s;
+SELECT id, upper(s), length(s) FROM t_dict ORDER BY id;
+SELECT min(s), max(s), count(s) FROM t_dict;
+
+DROP TABLE t_dict;
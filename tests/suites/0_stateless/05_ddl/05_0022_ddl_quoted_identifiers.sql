@@ -0,0 +1,20 @@
+DROP DATABASE IF EXISTS `db 1`;
+CREATE DATABASE `db 1`;
+
+CREATE TABLE `db 1`.`my table` (`first name` String, `名字` Int32, `a``b` Int32, "Mixed" Int32) Engine = Memory;
+INSERT INTO `db 1`.`my table` VALUES ('x', 1, 2, 3);
+SELECT `first name`, `名字`, `a``b`, Mixed FROM `db 1`.`my table`;
+SHOW CREATE TABLE `db 1`.`my table`;
+
+SET unquoted_ident_case_sensitive = 0;
+SELECT "Mixed" FROM `db 1`.`my table`;
+SELECT Mixed FROM `db 1`.`my table`; -- {ErrorCode 1058}
+CREATE TABLE `db 1`.Events (Id Int32) Engine = Memory;
+INSERT INTO `db 1`.EVENTS (ID) VALUES (1);
+SELECT ID FROM `db 1`.events;
+SHOW CREATE TABLE `db 1`.EvEnTs;
+
+SET unquoted_ident_case_sensitive = 1;
+SELECT id FROM `db 1`.events;
+
+DROP DATABASE `db 1`;
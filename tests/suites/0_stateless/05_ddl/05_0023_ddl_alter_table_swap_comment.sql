@@ -0,0 +1,22 @@
+DROP DATABASE IF EXISTS db_05_0023;
+CREATE DATABASE db_05_0023;
+USE db_05_0023;
+
+CREATE TABLE t1(a int) Engine = Memory;
+CREATE TABLE t2(b varchar) Engine = Memory;
+INSERT INTO t1 VALUES(1);
+INSERT INTO t2 VALUES('x');
+
+ALTER TABLE t1 SWAP WITH t2;
+SELECT * FROM t1;
+SELECT * FROM t2;
+
+ALTER TABLE t1 SWAP WITH t3; -- {ErrorCode 1025}
+ALTER TABLE IF EXISTS t1 SWAP WITH t3;
+
+ALTER TABLE t1 COMMENT = 'staging relation';
+SELECT name, comment FROM system.tables WHERE database = 'db_05_0023' ORDER BY name;
+ALTER TABLE t1 COMMENT '';
+SELECT name, comment FROM system.tables WHERE database = 'db_05_0023' ORDER BY name;
+
+DROP DATABASE db_05_0023;
@@ -0,0 +1,31 @@
+DROP DATABASE IF EXISTS db_05_0024;
+CREATE DATABASE db_05_0024;
+USE db_05_0024;
+
+CREATE OR REPLACE TABLE t(a int) Engine = Memory;
+INSERT INTO t VALUES(1);
+CREATE OR REPLACE TABLE t(b varchar) Engine = Memory AS SELECT 'replaced' AS b;
+SELECT * FROM t;
+CREATE OR REPLACE TABLE t AS SELECT concat(b, '_again') AS c FROM t;
+SELECT * FROM t;
+SELECT count() FROM system.tables WHERE database = 'db_05_0024';
+CREATE OR REPLACE TABLE IF NOT EXISTS t(a int); -- {ErrorCode 1005}
+
+CREATE OR REPLACE VIEW v AS SELECT 1 AS x;
+SELECT * FROM v;
+CREATE OR REPLACE VIEW v AS SELECT 2 AS x;
+SELECT * FROM v;
+CREATE OR REPLACE VIEW t AS SELECT 3 AS x; -- {ErrorCode 1054}
+CREATE OR REPLACE TABLE v(a int); -- {ErrorCode 1054}
+
+CREATE OR REPLACE FUNCTION f_05_0024 AS (p) -> not(isnull(p));
+CREATE OR REPLACE FUNCTION f_05_0024 AS (p) -> isnull(p);
+SELECT f_05_0024(NULL);
+
+CREATE OR REPLACE STAGE s_05_0024 comments='first';
+CREATE OR REPLACE STAGE s_05_0024 comments='second';
+CREATE STAGE s_05_0024; -- {ErrorCode 2502}
+
+DROP STAGE s_05_0024;
+DROP FUNCTION f_05_0024;
+DROP DATABASE db_05_0024;
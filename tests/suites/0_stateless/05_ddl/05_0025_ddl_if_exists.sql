@@ -0,0 +1,33 @@
+DROP DATABASE IF EXISTS db_05_0025;
+CREATE DATABASE db_05_0025;
+USE db_05_0025;
+
+TRUNCATE TABLE IF EXISTS t_missing;
+OPTIMIZE TABLE IF EXISTS t_missing;
+RENAME TABLE IF EXISTS t_missing TO t_other;
+TRUNCATE TABLE t_missing; -- {ErrorCode 1025}
+
+CREATE TABLE t(a int) Engine = Memory;
+INSERT INTO t VALUES(1);
+TRUNCATE TABLE IF EXISTS t;
+SELECT count() FROM t;
+RENAME TABLE IF EXISTS t TO t_renamed;
+SELECT count() FROM system.tables WHERE database = 'db_05_0025' AND name = 't_renamed';
+
+ALTER VIEW IF EXISTS v_missing AS SELECT 1;
+CREATE VIEW v AS SELECT 1 AS x;
+CREATE VIEW IF NOT EXISTS v AS SELECT 2 AS x;
+SELECT * FROM v;
+
+ALTER FUNCTION IF EXISTS f_05_0025_missing AS (p) -> isnull(p);
+
+CREATE USER IF NOT EXISTS 'u_05_0025'@'%' IDENTIFIED BY 'password';
+CREATE USER IF NOT EXISTS 'u_05_0025'@'%' IDENTIFIED BY 'password';
+ALTER USER IF EXISTS 'u_05_0025_missing'@'%' IDENTIFIED BY 'password';
+DROP USER 'u_05_0025'@'%';
+
+CREATE ROLE IF NOT EXISTS 'r_05_0025';
+CREATE ROLE IF NOT EXISTS 'r_05_0025';
+DROP ROLE 'r_05_0025';
+
+DROP DATABASE db_05_0025;
@@ -0,0 +1,13 @@
+DROP TABLE IF EXISTS t_cluster_key;
+DROP TABLE IF EXISTS t_cluster_key_2;
+DROP TABLE IF EXISTS t_cluster_key_3;
+
+CREATE TABLE t_cluster_key(a int, b int) cluster_key = 'a, c'; -- {ErrorCode 1022}
+CREATE TABLE t_cluster_key(a int, b int) cluster_key = 'b, a';
+CREATE TABLE t_cluster_key_3 cluster_key = 'c' AS SELECT a FROM t_cluster_key; -- {ErrorCode 1022}
+CREATE TABLE t_cluster_key_2 cluster_key = 'b' AS SELECT a, b FROM t_cluster_key;
+
+SELECT name FROM system.tables WHERE name LIKE 't_cluster_key%' ORDER BY name;
+
+DROP TABLE t_cluster_key;
+DROP TABLE t_cluster_key_2;
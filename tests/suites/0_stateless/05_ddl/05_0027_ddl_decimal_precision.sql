@@ -0,0 +1,10 @@
+DROP TABLE IF EXISTS t_decimal;
+
+CREATE TABLE t_decimal(a DECIMAL(18, 2), b DECIMAL(38, 10), c DECIMAL);
+INSERT INTO t_decimal VALUES (1.5, 2.5, 3);
+SELECT toTypeName(a), toTypeName(b), toTypeName(c) FROM t_decimal;
+SELECT a, b, c FROM t_decimal;
+
+CREATE TABLE t_decimal_2(a DECIMAL(0, 0)); -- {ErrorCode 1006}
+
+DROP TABLE t_decimal;
@@ -0,0 +1,18 @@
+create table t09_0013(c int null);
+
+-- 3 blocks, which are not in the order of c
+insert into t09_0013 values(5), (6);
+insert into t09_0013 values(1), (2);
+insert into t09_0013 values(9), (10);
+
+select c from t09_0013 order by c limit 3;
+select c from t09_0013 order by c desc limit 3;
+select c from t09_0013 order by c limit 1 offset 2;
+select c from t09_0013 order by c + 1 desc limit 1;
+
+-- NULLs are not in the block statistics, all blocks are read
+insert into t09_0013 values(null), (3);
+select c from t09_0013 order by c nulls last limit 2;
+select c from t09_0013 order by c limit 2;
+
+drop table t09_0013;
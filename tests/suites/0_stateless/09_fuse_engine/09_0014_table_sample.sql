@@ -0,0 +1,15 @@
+create table t09_0014(c int);
+
+insert into t09_0014 values(1), (2);
+insert into t09_0014 values(3), (4);
+insert into t09_0014 values(5), (6);
+
+select count() from t09_0014 tablesample (100 percent);
+select count() <= 6 from t09_0014 tablesample (50 percent);
+select sum(c) from t09_0014 tablesample (100) where c > 2;
+
+-- tables which can not sample their blocks filter the rows read
+select count() from numbers(1000) tablesample (100 percent);
+select count() < 1000 from numbers(1000) tablesample (1 percent);
+
+drop table t09_0014;
@@ -12,11 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::io::Write;
+
 use common_meta_grpc::MetaGrpcClient;
 use common_meta_types::protobuf::Empty;
 use tokio_stream::StreamExt;
 
-pub async fn export_meta(addr: &str) -> anyhow::Result<()> {
+pub async fn export_meta(addr: &str, out: &mut dyn Write) -> anyhow::Result<()> {
     let client = MetaGrpcClient::try_create(addr, "root", "xxx", None, None).await?;
 
     let mut grpc_client = client.make_client().await?;
@@ -29,9 +31,10 @@ pub async fn export_meta(addr: &str) -> anyhow::Result<()> {
         let chunk = chunk_res?;
 
         for line in &chunk.data {
-            println!("{}", line);
+            writeln!(out, "{}", line)?;
         }
     }
 
+    out.flush()?;
     Ok(())
 }
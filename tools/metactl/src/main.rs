@@ -12,12 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-#![feature(stdin_forwarders)]
-
 mod grpc;
 
 use std::collections::BTreeMap;
+use std::fs::File;
 use std::io;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Write;
 use std::net::SocketAddr;
 
 use clap::Parser;
@@ -30,6 +32,7 @@ use common_tracing::init_global_tracing;
 use databend_meta::configs::config::METASRV_GRPC_API_ADDRESS;
 use databend_meta::export::deserialize_to_kv_variant;
 use databend_meta::export::serialize_kv_variant;
+use databend_meta::export::ExportHeader;
 use serde::Deserialize;
 use serde::Serialize;
 use tokio::net::TcpSocket;
@@ -46,6 +49,10 @@ struct Config {
     #[clap(long)]
     pub export: bool,
 
+    /// The file to export meta data into or import meta data from, stdout/stdin is used if it is empty.
+    #[clap(long, default_value = "")]
+    pub db: String,
+
     #[clap(long, env = METASRV_GRPC_API_ADDRESS, default_value = "127.0.0.1:9191")]
     pub grpc_api_address: String,
 
@@ -62,6 +69,8 @@ struct Config {
 ///   ["global-local-kvstate_machine/0",7,"sledks::Sequences","tables",1]
 ///   ["global-local-kvstate_machine/0",8,"sledks::Databases",1,{"seq":1,"meta":null,"data":{"engine":"","engine_options":{},"options":{},"created_on":"2022-02-16T03:20:26.007286Z"}}]
 ///   ```
+/// - To export a consistent snapshot from a running metasrv into a file: `$0 --export --grpc-api-address 127.0.0.1:9191 --db ./meta.db`
+/// - To restore the exported file into the raft dir of a fresh node: `$0 --import --raft-dir ./_your_meta_dir/ --db ./meta.db`
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let config = Config::parse();
@@ -94,7 +103,7 @@ async fn main() -> anyhow::Result<()> {
         };
         if service_is_running(grpc_api_addr).await? {
             eprintln!("export meta from: {}", &config.grpc_api_address);
-            grpc::export_meta(&config.grpc_api_address).await?;
+            grpc::export_meta(&config.grpc_api_address, &mut output(&config.db)?).await?;
             return Ok(());
         }
     }
@@ -103,11 +112,11 @@ async fn main() -> anyhow::Result<()> {
 
     if config.export {
         eprintln!("export meta dir from: {}", raft_config.raft_dir);
-        print_meta()?;
+        print_meta(&mut output(&config.db)?)?;
     } else if config.import {
         eprintln!("import meta dir into: {}", raft_config.raft_dir);
         clear()?;
-        import_from_stdin()?;
+        import_lines(input(&config.db)?)?;
     }
 
     Ok(())
@@ -132,16 +141,42 @@ fn clear() -> anyhow::Result<()> {
     Ok(())
 }
 
-/// Read every line from stdin, deserialize it into tree_name, key and value. Insert them into sled db and flush.
-fn import_from_stdin() -> anyhow::Result<()> {
+/// Open the file to export into, or stdout if `db` is empty.
+fn output(db: &str) -> anyhow::Result<Box<dyn Write>> {
+    if db.is_empty() {
+        return Ok(Box::new(io::stdout()));
+    }
+    Ok(Box::new(File::create(db)?))
+}
+
+/// Open the file to import from, or stdin if `db` is empty.
+fn input(db: &str) -> anyhow::Result<Box<dyn BufRead>> {
+    if db.is_empty() {
+        return Ok(Box::new(BufReader::new(io::stdin())));
+    }
+    Ok(Box::new(BufReader::new(File::open(db)?)))
+}
+
+/// Read every line, deserialize it into tree_name, key and value. Insert them into sled db and flush.
+///
+/// The leading header line, if there is one, is checked to ensure the data format is supported.
+fn import_lines(reader: Box<dyn BufRead>) -> anyhow::Result<()> {
     let db = get_sled_db();
 
     let mut trees = BTreeMap::new();
 
-    let lines = io::stdin().lines();
     let mut n = 0;
-    for line in lines {
+    for (i, line) in reader.lines().enumerate() {
         let l = line?;
+
+        if i == 0 {
+            if let Some(header) = ExportHeader::from_json(&l) {
+                header.check_compatible()?;
+                eprintln!("Import data version: {}", header.data_version);
+                continue;
+            }
+        }
+
         let (tree_name, kv_variant): (String, KeySpaceKV) = serde_json::from_str(&l)?;
         // eprintln!("line: {}", l);
 
@@ -169,13 +204,16 @@ fn import_from_stdin() -> anyhow::Result<()> {
 
 /// Print the entire sled db.
 ///
-/// The output encodes every key-value into one line:
+/// The output starts with a header line that tags the data format version: `["header",{"data_version":1}]`,
+/// followed by every key-value encoded into one line:
 /// `[sled_tree_name, {key_space: {key, value}}]`
 /// E.g.:
 /// `["test-29000-state_machine/0",{"GenericKV":{"key":"wow","value":{"seq":3,"meta":null,"data":[119,111,119]}}}`
-fn print_meta() -> anyhow::Result<()> {
+fn print_meta(out: &mut dyn Write) -> anyhow::Result<()> {
     let db = get_sled_db();
 
+    writeln!(out, "{}", ExportHeader::current().to_json()?)?;
+
     let tree_names = db.tree_names();
     for n in tree_names.iter() {
         let name = String::from_utf8(n.to_vec())?;
@@ -190,10 +228,11 @@ fn print_meta() -> anyhow::Result<()> {
 
             let line = serde_json::to_string(&tree_kv)?;
 
-            println!("{}", line);
+            writeln!(out, "{}", line)?;
         }
     }
 
+    out.flush()?;
     Ok(())
 }
 